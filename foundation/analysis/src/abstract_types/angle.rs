@@ -98,27 +98,57 @@ impl<T: Scalar> Angle<T> {
 
     /// 角度を正規化（0 <= angle < 2π）
     pub fn normalize(self) -> Self {
-        let tau = T::TAU;
-        let mut rad = self.radians;
-        while rad < T::ZERO {
-            rad += tau;
-        }
-        while rad >= tau {
-            rad -= tau;
-        }
-        Self { radians: rad }
+        self.normalized_positive()
     }
 
-    /// 角度を署名付き正規化（-π <= angle < π）
+    /// 角度を署名付き正規化（-π < angle <= π）
     pub fn normalize_signed(self) -> Self {
-        let normalized = self.normalize();
-        if normalized.radians > T::PI {
+        self.normalized_signed()
+    }
+
+    /// `[0, 2π)` へ正規化
+    ///
+    /// 反復ではなく剰余で計算するため、大きな角度でも一定時間で終わる。
+    pub fn normalized_positive(self) -> Self {
+        let tau = T::TAU;
+        let rad = self.radians - tau * (self.radians / tau).floor();
+        // 負の微小値は丸めで 2π ちょうどになりうる
+        let radians = if rad >= tau || rad < T::ZERO {
+            T::ZERO
+        } else {
+            rad
+        };
+        Self { radians }
+    }
+
+    /// `(-π, π]` へ正規化
+    pub fn normalized_signed(self) -> Self {
+        let positive = self.normalized_positive();
+        if positive.radians > T::PI {
             Self {
-                radians: normalized.radians - T::TAU,
+                radians: positive.radians - T::TAU,
             }
         } else {
-            normalized
+            positive
+        }
+    }
+
+    /// `self` から `other` への最短の符号付き差分（`(-π, π]`、反時計回りが正）
+    pub fn shortest_diff(&self, other: &Self) -> Self {
+        (*other - *self).normalized_signed()
+    }
+
+    /// `start` から反時計回りに `end` までの範囲に `angle` が含まれるか（境界を含む）
+    ///
+    /// 350°〜10° のように 2π をまたぐ範囲も扱える。`end - start` が 2π 以上なら全周とみなし、
+    /// それ以外は差を `[0, 2π)` に正規化した幅で判定する（`start == end` は1点のみ）。
+    pub fn contains_angle(start: Self, end: Self, angle: Self) -> bool {
+        if end.radians - start.radians >= T::TAU {
+            return true;
         }
+        let span = (end - start).normalized_positive();
+        let offset = (angle - start).normalized_positive();
+        offset.radians <= span.radians
     }
 
     // === 定数メソッド（単精度・倍精度両対応） ===
@@ -316,6 +346,70 @@ mod tests {
         let angle4 = Angle::from_degrees(-270.0f64); // -270° = 90°
         let signed_normalized2 = angle4.normalize_signed();
         assert!((signed_normalized2.to_degrees() - 90.0).abs() < 1e-10);
+
+        // π ちょうどはどちらの正規化でも π のまま
+        let half_turn = Angle::from_degrees(180.0f64);
+        assert!((half_turn.normalize_signed().to_degrees() - 180.0).abs() < 1e-10);
+        assert!((half_turn.normalized_signed().to_degrees() - 180.0).abs() < 1e-10);
+        let minus_half_turn = Angle::from_degrees(-180.0f64);
+        assert!((minus_half_turn.normalize_signed().to_degrees() - 180.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_normalized_ranges() {
+        use std::f64::consts::PI;
+
+        let cases = [
+            (0.0, 0.0, 0.0),
+            (2.0 * PI, 0.0, 0.0),
+            (PI, PI, PI),
+            (-PI, PI, PI),
+            (3.0 * PI / 2.0, 3.0 * PI / 2.0, -PI / 2.0),
+            (-1e-20, 0.0, 0.0),
+            (1000.0 * PI + 0.5, 0.5, 0.5),
+        ];
+        for (input, positive, signed) in cases {
+            let angle = Angle::from_radians(input);
+            assert!((angle.normalized_positive().to_radians() - positive).abs() < 1e-9);
+            assert!((angle.normalized_signed().to_radians() - signed).abs() < 1e-9);
+        }
+
+        let huge = Angle::from_radians(1e12f64)
+            .normalized_positive()
+            .to_radians();
+        assert!((0.0..2.0 * PI).contains(&huge));
+    }
+
+    #[test]
+    fn test_shortest_diff() {
+        let a = Angle::from_degrees(350.0f64);
+        let b = Angle::from_degrees(10.0f64);
+        assert!((a.shortest_diff(&b).to_degrees() - 20.0).abs() < 1e-10);
+        assert!((b.shortest_diff(&a).to_degrees() + 20.0).abs() < 1e-10);
+
+        let c = Angle::from_degrees(-170.0f64);
+        assert!((b.shortest_diff(&c).to_degrees() - 180.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_contains_angle() {
+        let deg = Angle::from_degrees;
+
+        // 2π をまたぐ範囲
+        assert!(Angle::contains_angle(deg(350.0f64), deg(10.0), deg(0.0)));
+        assert!(Angle::contains_angle(deg(350.0), deg(10.0), deg(355.0)));
+        assert!(Angle::contains_angle(deg(350.0), deg(10.0), deg(-5.0)));
+        assert!(Angle::contains_angle(deg(350.0), deg(10.0), deg(370.0)));
+        assert!(!Angle::contains_angle(deg(350.0), deg(10.0), deg(180.0)));
+
+        // 通常の範囲と境界
+        assert!(Angle::contains_angle(deg(0.0), deg(90.0), deg(90.0)));
+        assert!(Angle::contains_angle(deg(0.0), deg(90.0), deg(-360.0)));
+        assert!(!Angle::contains_angle(deg(0.0), deg(90.0), deg(-10.0)));
+
+        // 全周と1点
+        assert!(Angle::contains_angle(deg(0.0), deg(360.0), deg(200.0)));
+        assert!(!Angle::contains_angle(deg(30.0), deg(30.0), deg(31.0)));
     }
 
     #[test]
@@ -159,6 +159,38 @@ pub trait Scalar:
     /// 有限値かの判定
     fn is_finite(self) -> bool;
 
+    /// 有限値なら `Some`、NaN / 無限大なら `None`
+    ///
+    /// 非有限値の混入を呼び出し元のエラーに変換するための起点。
+    fn checked(self) -> Option<Self> {
+        if self.is_finite() {
+            Some(self)
+        } else {
+            None
+        }
+    }
+
+    /// 平方根（負数・非有限値の入力で結果が有限でなければ `None`）
+    fn checked_sqrt(self) -> Option<Self> {
+        self.sqrt().checked()
+    }
+
+    /// 除算（ゼロ除算などで結果が有限でなければ `None`）
+    fn checked_div(self, rhs: Self) -> Option<Self> {
+        (self / rhs).checked()
+    }
+
+    /// デバッグビルドで有限値であることを検査して値をそのまま返す
+    ///
+    /// 計算途中の値を `debug_assert_finite()` で包むと、NaN / 無限大が最初に
+    /// 生成された箇所で panic する（リリースビルドでは何もしない）。
+    #[track_caller]
+    #[inline]
+    fn debug_assert_finite(self) -> Self {
+        debug_assert!(self.is_finite(), "非有限値が生成されました: {}", self);
+        self
+    }
+
     /// f64に変換
     fn to_f64(self) -> f64;
 
@@ -522,6 +554,35 @@ mod tests {
         assert!(!a.approx_eq(c));
     }
 
+    #[test]
+    fn test_checked_operations() {
+        assert_eq!(2.0f64.checked(), Some(2.0));
+        assert_eq!(f64::NAN.checked(), None);
+        assert_eq!(f32::INFINITY.checked(), None);
+
+        assert_eq!(9.0f64.checked_sqrt(), Some(3.0));
+        assert_eq!((-1.0f64).checked_sqrt(), None);
+        assert_eq!(f32::NAN.checked_sqrt(), None);
+
+        assert_eq!(1.0f64.checked_div(4.0), Some(0.25));
+        assert_eq!(1.0f64.checked_div(0.0), None);
+        assert_eq!(0.0f32.checked_div(0.0), None);
+        assert_eq!(f64::MAX.checked_div(0.5), None);
+    }
+
+    #[test]
+    fn test_debug_assert_finite_passes_finite_values() {
+        assert_eq!(1.5f64.debug_assert_finite(), 1.5);
+        assert_eq!((-2.0f32).debug_assert_finite(), -2.0);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "非有限値")]
+    fn test_debug_assert_finite_panics_on_nan() {
+        let _ = f64::NAN.debug_assert_finite();
+    }
+
     #[test]
     fn test_type_conversion() {
         let f32_val = std::f32::consts::PI;
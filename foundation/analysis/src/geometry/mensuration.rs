@@ -0,0 +1,99 @@
+//! 回転体の求積公式
+//!
+//! トーラス・切頂円錐の体積と表面積を寸法パラメータから計算する。
+//! 退化したパラメータ（負・ゼロの半径、自己交差するトーラスなど）では `None` を返す。
+
+use crate::Scalar;
+
+/// トーラスの体積 `2π²Rr²`
+///
+/// 副半径が正で、主半径が副半径以上（自己交差しない）の場合のみ `Some`。
+pub fn torus_volume<T: Scalar>(major_radius: T, minor_radius: T) -> Option<T> {
+    is_valid_torus(major_radius, minor_radius)
+        .then(|| T::from_f64(2.0) * T::PI * T::PI * major_radius * minor_radius * minor_radius)
+}
+
+/// トーラスの表面積 `4π²Rr`
+///
+/// 副半径が正で、主半径が副半径以上（自己交差しない）の場合のみ `Some`。
+pub fn torus_surface_area<T: Scalar>(major_radius: T, minor_radius: T) -> Option<T> {
+    is_valid_torus(major_radius, minor_radius)
+        .then(|| T::from_f64(4.0) * T::PI * T::PI * major_radius * minor_radius)
+}
+
+/// 切頂円錐の体積 `πh(r₁² + r₁r₂ + r₂²)/3`
+///
+/// 一方の半径がゼロなら円錐、両方が等しければ円柱になる。
+/// 半径が負、両方ゼロ、または高さが正でない場合は `None`。
+pub fn frustum_volume<T: Scalar>(bottom_radius: T, top_radius: T, height: T) -> Option<T> {
+    is_valid_frustum(bottom_radius, top_radius, height).then(|| {
+        T::PI
+            * height
+            * (bottom_radius * bottom_radius + bottom_radius * top_radius + top_radius * top_radius)
+            / T::from_f64(3.0)
+    })
+}
+
+/// 切頂円錐の側面積 `π(r₁ + r₂)√((r₁ - r₂)² + h²)`（上下の端面は含まない）
+///
+/// 半径が負、両方ゼロ、または高さが正でない場合は `None`。
+pub fn frustum_lateral_area<T: Scalar>(bottom_radius: T, top_radius: T, height: T) -> Option<T> {
+    is_valid_frustum(bottom_radius, top_radius, height).then(|| {
+        let radius_difference = bottom_radius - top_radius;
+        let slant_height = (radius_difference * radius_difference + height * height).sqrt();
+        T::PI * (bottom_radius + top_radius) * slant_height
+    })
+}
+
+fn is_valid_torus<T: Scalar>(major_radius: T, minor_radius: T) -> bool {
+    minor_radius > T::ZERO && major_radius >= minor_radius
+}
+
+fn is_valid_frustum<T: Scalar>(bottom_radius: T, top_radius: T, height: T) -> bool {
+    bottom_radius >= T::ZERO
+        && top_radius >= T::ZERO
+        && (bottom_radius > T::ZERO || top_radius > T::ZERO)
+        && height > T::ZERO
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f64::consts::PI;
+
+    #[test]
+    fn test_torus() {
+        assert!((torus_volume(3.0, 1.0).unwrap() - 6.0 * PI * PI).abs() < 1e-10);
+        assert!((torus_surface_area(3.0, 1.0).unwrap() - 12.0 * PI * PI).abs() < 1e-10);
+        // ホーントーラス（R = r）は有効
+        assert!(torus_volume(1.0, 1.0).is_some());
+
+        assert_eq!(torus_volume(1.0, 2.0), None);
+        assert_eq!(torus_volume(3.0, 0.0), None);
+        assert_eq!(torus_surface_area(-3.0, 1.0), None);
+    }
+
+    #[test]
+    fn test_frustum() {
+        // 一方の半径がゼロなら円錐、等しければ円柱
+        let cone = frustum_volume(2.0, 0.0, 3.0).unwrap();
+        assert!((cone - PI * 4.0 * 3.0 / 3.0).abs() < 1e-10);
+        let cylinder = frustum_volume(2.0, 2.0, 3.0).unwrap();
+        assert!((cylinder - PI * 4.0 * 3.0).abs() < 1e-10);
+
+        // 大円錐から小円錐を引いた体積と一致（r: 2 → 1、h = 3、頂点まで 6）
+        let frustum = frustum_volume(2.0, 1.0, 3.0).unwrap();
+        assert!((frustum - (PI * 4.0 * 6.0 - PI * 3.0) / 3.0).abs() < 1e-10);
+
+        // 側面積：母線長 5 の切頂円錐
+        let lateral = frustum_lateral_area(4.0, 1.0, 4.0).unwrap();
+        assert!((lateral - PI * 5.0 * 5.0).abs() < 1e-10);
+        assert!(
+            (frustum_lateral_area(2.0, 2.0, 3.0).unwrap() - 2.0 * PI * 2.0 * 3.0).abs() < 1e-10
+        );
+
+        assert_eq!(frustum_volume(0.0, 0.0, 1.0), None);
+        assert_eq!(frustum_volume(-1.0, 1.0, 1.0), None);
+        assert_eq!(frustum_lateral_area(1.0, 1.0, 0.0), None);
+    }
+}
@@ -2,6 +2,8 @@
 //!
 //! geo_primitivesのCAD用図形とは区別される、数学計算専用の図形定義
 
+pub mod mensuration;
 pub mod plane3;
 
+pub use mensuration::{frustum_lateral_area, frustum_volume, torus_surface_area, torus_volume};
 pub use plane3::Plane3;
@@ -0,0 +1,177 @@
+//! 固定サイズ行列の分解アルゴリズム
+//!
+//! Matrix3x3 / Matrix4x4 の分解メソッドが共有する、`[[T; N]; N]` 上の実装
+
+use crate::abstract_types::Scalar;
+
+/// Householder 変換による QR 分解（`a = q · r`）
+///
+/// q は直交行列、r は上三角行列。r の対角成分は非負に揃える
+/// （a が正則なら分解は一意になる）。
+pub(crate) fn householder_qr<T: Scalar, const N: usize>(
+    a: &[[T; N]; N],
+) -> ([[T; N]; N], [[T; N]; N]) {
+    let mut r = *a;
+    let mut q = [[T::ZERO; N]; N];
+    for (i, row) in q.iter_mut().enumerate() {
+        row[i] = T::ONE;
+    }
+
+    for k in 0..N.saturating_sub(1) {
+        // 第k列の対角以下を e_k 方向へ写す鏡映 H = I - 2vvᵀ/vᵀv
+        let norm = (k..N)
+            .map(|i| r[i][k] * r[i][k])
+            .fold(T::ZERO, |acc, x| acc + x)
+            .sqrt();
+        if norm == T::ZERO {
+            continue;
+        }
+        let alpha = if r[k][k] > T::ZERO { -norm } else { norm };
+        let mut v = [T::ZERO; N];
+        for i in k..N {
+            v[i] = r[i][k];
+        }
+        v[k] -= alpha;
+        let v_norm_squared = (k..N).map(|i| v[i] * v[i]).fold(T::ZERO, |acc, x| acc + x);
+        if v_norm_squared == T::ZERO {
+            continue;
+        }
+        let scale = (T::ONE + T::ONE) / v_norm_squared;
+
+        // r ← H r
+        let mut w = [T::ZERO; N];
+        for (row, &vi) in r.iter().zip(v.iter()).skip(k) {
+            for (wj, &rij) in w.iter_mut().zip(row.iter()) {
+                *wj += vi * rij;
+            }
+        }
+        for (row, &vi) in r.iter_mut().zip(v.iter()).skip(k) {
+            for (rij, &wj) in row.iter_mut().zip(w.iter()) {
+                *rij -= scale * wj * vi;
+            }
+        }
+        // q ← q H
+        for row in q.iter_mut() {
+            let dot = (k..N)
+                .map(|j| row[j] * v[j])
+                .fold(T::ZERO, |acc, x| acc + x)
+                * scale;
+            for j in k..N {
+                row[j] -= dot * v[j];
+            }
+        }
+        for row in r.iter_mut().skip(k + 1) {
+            row[k] = T::ZERO;
+        }
+    }
+
+    // 対角成分を非負に揃える（r の行と q の列の符号を同時に反転）
+    for k in 0..N {
+        if r[k][k] < T::ZERO {
+            for value in r[k].iter_mut() {
+                *value = -*value;
+            }
+            for row in q.iter_mut() {
+                row[k] = -row[k];
+            }
+        }
+    }
+
+    (q, r)
+}
+
+/// 巡回 Jacobi 法による対称行列の固有値分解
+///
+/// 入力は `(a + aᵀ) / 2` に対称化してから回転を適用する。固有値は昇順に並べ、
+/// 固有ベクトル行列の第 i 列が i 番目の固有値に対応する。直交回転の積として
+/// 求めるため、重解（縮退）があっても固有ベクトルは正規直交基底になる。
+#[allow(clippy::needless_range_loop)] // 行・列を対で回転する Jacobi 法の標準実装
+pub(crate) fn jacobi_eigen_symmetric<T: Scalar, const N: usize>(
+    a: &[[T; N]; N],
+) -> ([T; N], [[T; N]; N]) {
+    const MAX_SWEEPS: usize = 64;
+    let half = T::ONE / (T::ONE + T::ONE);
+
+    let mut m = [[T::ZERO; N]; N];
+    let mut v = [[T::ZERO; N]; N];
+    for i in 0..N {
+        for j in 0..N {
+            m[i][j] = (a[i][j] + a[j][i]) * half;
+        }
+        v[i][i] = T::ONE;
+    }
+
+    let frobenius_squared = m
+        .iter()
+        .flat_map(|row| row.iter())
+        .map(|&x| x * x)
+        .fold(T::ZERO, |acc, x| acc + x);
+    let threshold = T::EPSILON * T::EPSILON * frobenius_squared;
+
+    for _ in 0..MAX_SWEEPS {
+        let mut off_diagonal = T::ZERO;
+        for p in 0..N {
+            for q in (p + 1)..N {
+                off_diagonal += m[p][q] * m[p][q];
+            }
+        }
+        if off_diagonal <= threshold {
+            break;
+        }
+
+        for p in 0..N {
+            for q in (p + 1)..N {
+                let apq = m[p][q];
+                if apq == T::ZERO {
+                    continue;
+                }
+                // m[p][q] を消去する回転角: t = tanφ は |φ| ≤ π/4 側の根
+                let theta = (m[q][q] - m[p][p]) / (apq + apq);
+                let t = T::ONE / (theta.abs() + (theta * theta + T::ONE).sqrt());
+                let t = if theta < T::ZERO { -t } else { t };
+                let c = T::ONE / (t * t + T::ONE).sqrt();
+                let s = t * c;
+
+                for k in 0..N {
+                    let (mkp, mkq) = (m[k][p], m[k][q]);
+                    m[k][p] = c * mkp - s * mkq;
+                    m[k][q] = s * mkp + c * mkq;
+                }
+                for k in 0..N {
+                    let (mpk, mqk) = (m[p][k], m[q][k]);
+                    m[p][k] = c * mpk - s * mqk;
+                    m[q][k] = s * mpk + c * mqk;
+                }
+                m[p][q] = T::ZERO;
+                m[q][p] = T::ZERO;
+
+                for row in v.iter_mut() {
+                    let (vp, vq) = (row[p], row[q]);
+                    row[p] = c * vp - s * vq;
+                    row[q] = s * vp + c * vq;
+                }
+            }
+        }
+    }
+
+    // 固有値の昇順に並べ替え（固有ベクトルの列も同じ順序で入れ替える）
+    let mut order = [0; N];
+    for (i, index) in order.iter_mut().enumerate() {
+        *index = i;
+    }
+    order.sort_by(|&i, &j| {
+        m[i][i]
+            .partial_cmp(&m[j][j])
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+
+    let mut values = [T::ZERO; N];
+    let mut vectors = [[T::ZERO; N]; N];
+    for (column, &source) in order.iter().enumerate() {
+        values[column] = m[source][source];
+        for (target, row) in vectors.iter_mut().zip(v.iter()) {
+            target[column] = row[source];
+        }
+    }
+    (values, vectors)
+}
@@ -127,6 +127,26 @@ impl<T: Scalar> Matrix3x3<T> {
         result
     }
 
+    /// Householder 変換による QR 分解
+    ///
+    /// `self = q * r` となる直交行列 q と上三角行列 r を返す。
+    /// r の対角成分は非負に揃える。
+    pub fn qr(&self) -> (Self, Self) {
+        let (q, r) = super::decomposition::householder_qr(&self.data);
+        (Self { data: q }, Self { data: r })
+    }
+
+    /// 対称行列の固有値分解（Jacobi 法）
+    ///
+    /// 固有値を昇順に並べ、対応する正規直交な固有ベクトルを返す。
+    /// 対称でない入力は `(self + selfᵀ) / 2` に対称化して扱う。
+    /// 重解がある場合も固有ベクトルは互いに直交する。
+    pub fn eigen_symmetric(&self) -> ([T; 3], [Vector3<T>; 3]) {
+        let (values, vectors) = super::decomposition::jacobi_eigen_symmetric(&self.data);
+        let column = |j: usize| Vector3::new(vectors[0][j], vectors[1][j], vectors[2][j]);
+        (values, [column(0), column(1), column(2)])
+    }
+
     /// 行列の要素にアクセス
     pub fn get(&self, row: usize, col: usize) -> T {
         self.data[row][col]
@@ -152,6 +152,15 @@ impl<T: Scalar> Matrix4x4<T> {
         result
     }
 
+    /// Householder 変換による QR 分解
+    ///
+    /// `self = q * r` となる直交行列 q と上三角行列 r を返す。
+    /// r の対角成分は非負に揃える。
+    pub fn qr(&self) -> (Self, Self) {
+        let (q, r) = super::decomposition::householder_qr(&self.data);
+        (Self { data: q }, Self { data: r })
+    }
+
     /// 要素アクセス
     pub fn get(&self, row: usize, col: usize) -> T {
         self.data[row][col]
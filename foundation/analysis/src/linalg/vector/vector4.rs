@@ -63,6 +63,18 @@ impl<T: Scalar> Vector4<T> {
         Self::new(x, y, z, T::ZERO)
     }
 
+    /// 3D点を同次座標へ変換（w=1、平行移動の影響を受ける）
+    ///
+    /// [`Self::from_euclidean`] と同じ。[`Self::from_vector3`] と対で使う。
+    pub fn from_point3(p: super::vector3::Vector3<T>) -> Self {
+        Self::from_euclidean(p)
+    }
+
+    /// 3Dベクトルを同次座標へ変換（w=0、平行移動の影響を受けない）
+    pub fn from_vector3(v: super::vector3::Vector3<T>) -> Self {
+        Self::new(v.x(), v.y(), v.z(), T::ZERO)
+    }
+
     /// X軸方向の単位ベクトル（1, 0, 0, 0）- X_AXIS定数のエイリアス
     pub fn x_axis() -> Self {
         // 型変換を通じて定数を任意のScalar型で利用可能にする
@@ -195,6 +207,16 @@ impl<T: Scalar> Vector4<T> {
         ))
     }
 
+    /// 透視除算で3D点へ戻す
+    ///
+    /// [`Self::to_euclidean`] と同じ除算だが、w≈0（無限遠点・方向ベクトル）の場合は `None`
+    pub fn to_point3(&self) -> Option<super::vector3::Vector3<T>> {
+        if !self.is_valid_homogeneous() {
+            return None;
+        }
+        self.to_euclidean().ok()
+    }
+
     /// ユークリッド座標から同次座標への変換（静的メソッド）
     pub fn from_euclidean(v: super::vector3::Vector3<T>) -> Self {
         Self::new(v.x(), v.y(), v.z(), T::ONE)
@@ -0,0 +1,134 @@
+#[cfg(test)]
+mod tests {
+    use crate::numerics::integration::{
+        adaptive_simpson, composite_gauss_legendre, compute_gauss_legendre_rule, gauss_legendre,
+        gauss_legendre_rule,
+    };
+    use std::cell::Cell;
+
+    #[test]
+    fn test_polynomials_are_exact_up_to_degree_2n_minus_1() {
+        for &n in &[5usize, 7, 10] {
+            let degree = 2 * n as i32 - 1;
+            // ∫_0^2 x^k dx = 2^{k+1} / (k+1)
+            let exact = 2.0f64.powi(degree + 1) / f64::from(degree + 1);
+            let result = gauss_legendre(|x| x.powi(degree), 0.0, 2.0, n);
+            assert!((result - exact).abs() < 1e-9 * exact, "n = {n}");
+        }
+
+        // 3次多項式は2点則で厳密
+        let result = gauss_legendre(|x| 4.0 * x * x * x - x + 2.0, -1.0, 3.0, 2);
+        assert!((result - 84.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_trigonometric_integrals() {
+        let pi = std::f64::consts::PI;
+        assert!((gauss_legendre(f64::sin, 0.0, pi, 10) - 2.0).abs() < 1e-12);
+        assert!((gauss_legendre(f64::cos, 0.0, pi / 2.0, 7) - 1.0).abs() < 1e-11);
+        // ∫_0^π sin²x dx = π/2
+        let sin_squared = gauss_legendre(|x| x.sin().powi(2), 0.0, pi, 10);
+        assert!((sin_squared - pi / 2.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_composite_gauss_legendre() {
+        let pi = std::f64::consts::PI;
+        // 1区間なら単一の則と同じ
+        let single = gauss_legendre(f64::sin, 0.0, pi, 5);
+        assert_eq!(composite_gauss_legendre(f64::sin, 0.0, pi, 5, 1), single);
+        assert_eq!(composite_gauss_legendre(f64::sin, 0.0, pi, 5, 0), single);
+
+        // 区間を分けると、全体では近似しにくい被積分関数でも精度が上がる
+        let exact = 1.0 - (-20.0f64).exp();
+        let f = |x: f64| 20.0 * (-20.0 * x).exp();
+        let coarse = (gauss_legendre(f, 0.0, 1.0, 5) - exact).abs();
+        let fine = (composite_gauss_legendre(f, 0.0, 1.0, 5, 8) - exact).abs();
+        assert!(fine < 1e-8);
+        assert!(fine < coarse);
+    }
+
+    #[test]
+    fn test_ellipse_perimeter_with_few_evaluations() {
+        // 長半径2・短半径1の楕円周長（1/4周を4倍）
+        let (a, b) = (2.0f64, 1.0f64);
+        let integrand = |t: f64| (a * a * t.sin().powi(2) + b * b * t.cos().powi(2)).sqrt();
+        let perimeter = 4.0 * gauss_legendre(integrand, 0.0, std::f64::consts::FRAC_PI_2, 10);
+        assert!((perimeter - 9.688_448_220_547_675).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_tables_match_computed_rules() {
+        for &n in &[5usize, 7, 10] {
+            let table = gauss_legendre_rule(n);
+            assert_eq!(table.len(), n);
+            // 重みの和は区間長 2
+            assert!((table.iter().map(|(_, w)| w).sum::<f64>() - 2.0).abs() < 1e-14);
+
+            // 表にない n と同じ方法で計算した値と一致する
+            let computed = compute_gauss_legendre_rule(n);
+            for ((node, weight), (expected_node, expected_weight)) in computed.iter().zip(&table) {
+                assert!((node - expected_node).abs() < 1e-14);
+                assert!((weight - expected_weight).abs() < 1e-14);
+            }
+        }
+    }
+
+    #[test]
+    fn test_reversed_interval_and_zero_points() {
+        let forward = gauss_legendre(|x| x.exp(), 0.0, 1.0, 5);
+        let backward = gauss_legendre(|x| x.exp(), 1.0, 0.0, 5);
+        assert!((forward + backward).abs() < 1e-15);
+        assert!((forward - (1.0f64.exp() - 1.0)).abs() < 1e-9);
+        assert_eq!(gauss_legendre(|x| x, 0.0, 1.0, 0), 0.0);
+    }
+
+    #[test]
+    fn test_adaptive_simpson_smooth_functions() {
+        let pi = std::f64::consts::PI;
+        assert!((adaptive_simpson(f64::sin, 0.0, pi, 1e-10) - 2.0).abs() < 1e-10);
+        // 3次以下の多項式はシンプソン則で厳密
+        let cubic = adaptive_simpson(|x| x * x * x - 2.0 * x, 0.0, 2.0, 1e-12);
+        assert!(cubic.abs() < 1e-14);
+        assert!((adaptive_simpson(f64::exp, 1.0, 0.0, 1e-10) + (1.0f64.exp() - 1.0)).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_adaptive_simpson_refines_locally() {
+        // x = 0 付近だけ急峻な √x：∫_0^1 √x dx = 2/3
+        let evaluations = Cell::new(0usize);
+        let result = adaptive_simpson(
+            |x: f64| {
+                evaluations.set(evaluations.get() + 1);
+                x.sqrt()
+            },
+            0.0,
+            1.0,
+            1e-9,
+        );
+        assert!((result - 2.0 / 3.0).abs() < 1e-8);
+        // 一様分割なら数万回必要な精度を、より少ない評価回数で達成する
+        assert!(
+            evaluations.get() < 2_000,
+            "evaluations = {}",
+            evaluations.get()
+        );
+    }
+
+    #[test]
+    fn test_adaptive_simpson_evaluation_guard() {
+        // 原点付近で激しく振動し収束しない関数でも評価回数の上限で停止する
+        let evaluations = Cell::new(0usize);
+        let result = adaptive_simpson(
+            |x: f64| {
+                evaluations.set(evaluations.get() + 1);
+                (1.0 / x).sin()
+            },
+            1e-9,
+            1.0,
+            1e-15,
+        );
+        assert!(evaluations.get() >= 1_000_000 && evaluations.get() <= 1_000_002);
+        assert!(result.is_finite(), "result = {result}");
+    }
+}
@@ -1,8 +1,14 @@
 //! 数値計算基盤モジュール
 //!
-//! ベクトル・距離計算、数値解法ソルバーを提供します。
+//! ベクトル・距離計算、数値解法ソルバー、数値積分、常微分方程式ソルバーを提供します。
 //! 特殊数学定数は `crate::consts::special` モジュールを使用してください。
 
+pub mod integration;
+#[cfg(test)]
+pub mod integration_tests;
+pub mod ode;
+#[cfg(test)]
+pub mod ode_tests;
 pub mod solver;
 #[cfg(test)]
 pub mod solver_tests;
@@ -10,8 +16,19 @@ pub mod vector_distance;
 #[cfg(test)]
 pub mod vector_distance_tests;
 
+// 数値積分の再エクスポート
+pub use integration::{
+    adaptive_simpson, composite_gauss_legendre, gauss_legendre, gauss_legendre_rule,
+};
+
+// 常微分方程式ソルバーの再エクスポート
+pub use ode::{rk4, rk45};
+
 // 数値解法ソルバーの再エクスポート
-pub use solver::{newton_arc_length, newton_inverse, newton_solve, NormedVector};
+pub use solver::{
+    brent_solve, newton_arc_length, newton_inverse, newton_solve, newton_solve_numeric,
+    NormedVector,
+};
 
 // ベクトル・距離計算の再エクスポート
 pub use vector_distance::{
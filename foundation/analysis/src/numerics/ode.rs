@@ -0,0 +1,197 @@
+//! 常微分方程式ソルバーモジュール
+//!
+//! 連立常微分方程式 y' = f(t, y) の初期値問題を解く。
+//! - 固定ステップの古典的4次Runge-Kutta法（`rk4`）
+//! - 適応ステップのDormand-Prince法 RK5(4)（`rk45`）
+
+/// `rk45` の最大ステップ数（無限ループ防止）
+const RK45_MAX_STEPS: usize = 100_000;
+
+/// Dormand-Prince法のノード c_i
+const DP_C: [f64; 7] = [0.0, 1.0 / 5.0, 3.0 / 10.0, 4.0 / 5.0, 8.0 / 9.0, 1.0, 1.0];
+
+/// Dormand-Prince法の係数 a_ij（下三角）
+const DP_A: [[f64; 6]; 7] = [
+    [0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+    [1.0 / 5.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+    [3.0 / 40.0, 9.0 / 40.0, 0.0, 0.0, 0.0, 0.0],
+    [44.0 / 45.0, -56.0 / 15.0, 32.0 / 9.0, 0.0, 0.0, 0.0],
+    [
+        19372.0 / 6561.0,
+        -25360.0 / 2187.0,
+        64448.0 / 6561.0,
+        -212.0 / 729.0,
+        0.0,
+        0.0,
+    ],
+    [
+        9017.0 / 3168.0,
+        -355.0 / 33.0,
+        46732.0 / 5247.0,
+        49.0 / 176.0,
+        -5103.0 / 18656.0,
+        0.0,
+    ],
+    [
+        35.0 / 384.0,
+        0.0,
+        500.0 / 1113.0,
+        125.0 / 192.0,
+        -2187.0 / 6784.0,
+        11.0 / 84.0,
+    ],
+];
+
+/// 5次解の重み b_i
+const DP_B5: [f64; 7] = [
+    35.0 / 384.0,
+    0.0,
+    500.0 / 1113.0,
+    125.0 / 192.0,
+    -2187.0 / 6784.0,
+    11.0 / 84.0,
+    0.0,
+];
+
+/// 誤差推定用の4次解の重み b*_i
+const DP_B4: [f64; 7] = [
+    5179.0 / 57600.0,
+    0.0,
+    7571.0 / 16695.0,
+    393.0 / 640.0,
+    -92097.0 / 339200.0,
+    187.0 / 2100.0,
+    1.0 / 40.0,
+];
+
+/// 古典的4次Runge-Kutta法で y' = f(t, y) を解く
+///
+/// 区間 [t0, t1] を `steps` 等分した各時刻の状態を返す。
+/// 戻り値の長さは `steps + 1` で、先頭は初期値 `y0`。
+/// `t1 < t0` の場合は時間を遡って積分する。`steps` が0なら初期値のみを返す。
+pub fn rk4<F>(f: F, y0: &[f64], t0: f64, t1: f64, steps: usize) -> Vec<Vec<f64>>
+where
+    F: Fn(f64, &[f64]) -> Vec<f64>,
+{
+    let mut states = Vec::with_capacity(steps + 1);
+    states.push(y0.to_vec());
+    if steps == 0 {
+        return states;
+    }
+
+    let h = (t1 - t0) / steps as f64;
+    let mut y = y0.to_vec();
+    for i in 0..steps {
+        let t = t0 + i as f64 * h;
+        let k1 = f(t, &y);
+        let k2 = f(t + 0.5 * h, &add_scaled(&y, &[(0.5 * h, &k1)]));
+        let k3 = f(t + 0.5 * h, &add_scaled(&y, &[(0.5 * h, &k2)]));
+        let k4 = f(t + h, &add_scaled(&y, &[(h, &k3)]));
+
+        y = add_scaled(
+            &y,
+            &[
+                (h / 6.0, &k1),
+                (h / 3.0, &k2),
+                (h / 3.0, &k3),
+                (h / 6.0, &k4),
+            ],
+        );
+        states.push(y.clone());
+    }
+    states
+}
+
+/// Dormand-Prince法 RK5(4) による適応ステップで y' = f(t, y) を解く
+///
+/// 各成分の局所誤差が `tolerance * (1 + |y_i|)` 以下になるようステップ幅を調整する
+/// （絶対・相対許容誤差の混合）。戻り値は受理した各ステップの `(t, y)` で、
+/// 先頭は `(t0, y0)`、末尾は `t1` に一致する。
+///
+/// ステップ幅が極端に小さくなった場合や、ステップ数が上限を超えた場合は `None` を返す。
+pub fn rk45<F>(f: F, y0: &[f64], t0: f64, t1: f64, tolerance: f64) -> Option<Vec<(f64, Vec<f64>)>>
+where
+    F: Fn(f64, &[f64]) -> Vec<f64>,
+{
+    let mut trajectory = vec![(t0, y0.to_vec())];
+    let span = t1 - t0;
+    if span == 0.0 {
+        return Some(trajectory);
+    }
+
+    let direction = span.signum();
+    let min_step = span.abs() * f64::EPSILON * 16.0;
+    let mut h = span / 100.0;
+    let mut t = t0;
+    let mut y = y0.to_vec();
+
+    for _ in 0..RK45_MAX_STEPS {
+        // 終端を越えないようにステップを切り詰める
+        if (t + h - t1) * direction > 0.0 {
+            h = t1 - t;
+        }
+
+        let mut k: Vec<Vec<f64>> = Vec::with_capacity(7);
+        for stage in 0..7 {
+            let terms: Vec<(f64, &[f64])> = (0..stage)
+                .map(|j| (h * DP_A[stage][j], k[j].as_slice()))
+                .collect();
+            k.push(f(t + DP_C[stage] * h, &add_scaled(&y, &terms)));
+        }
+
+        let weighted = |weights: &[f64; 7]| -> Vec<f64> {
+            let terms: Vec<(f64, &[f64])> = weights
+                .iter()
+                .zip(&k)
+                .map(|(&b, stage)| (h * b, stage.as_slice()))
+                .collect();
+            add_scaled(&y, &terms)
+        };
+        let y5 = weighted(&DP_B5);
+        let y4 = weighted(&DP_B4);
+
+        // 許容誤差で正規化した誤差（1以下なら受理）
+        let error = y5
+            .iter()
+            .zip(&y4)
+            .zip(&y)
+            .map(|((a, b), c)| (a - b).abs() / (tolerance * (1.0 + a.abs().max(c.abs()))))
+            .fold(0.0, f64::max);
+
+        if error <= 1.0 {
+            t += h;
+            y = y5;
+            trajectory.push((t, y.clone()));
+            if (t - t1) * direction >= 0.0 {
+                // 丸め誤差を除いて終端時刻に揃える
+                if let Some(last) = trajectory.last_mut() {
+                    last.0 = t1;
+                }
+                return Some(trajectory);
+            }
+        }
+
+        // 5次法の誤差は h^5 に比例する
+        let factor = if error == 0.0 {
+            5.0
+        } else {
+            (0.9 * error.powf(-0.2)).clamp(0.2, 5.0)
+        };
+        h *= factor;
+        if h.abs() < min_step || !h.is_finite() {
+            return None;
+        }
+    }
+    None
+}
+
+/// y + Σ scale_i * v_i
+fn add_scaled(y: &[f64], terms: &[(f64, &[f64])]) -> Vec<f64> {
+    let mut result = y.to_vec();
+    for (scale, values) in terms {
+        for (r, v) in result.iter_mut().zip(values.iter()) {
+            *r += scale * v;
+        }
+    }
+    result
+}
@@ -0,0 +1,78 @@
+#[cfg(test)]
+mod tests {
+    use crate::numerics::ode::{rk4, rk45};
+
+    #[test]
+    fn test_rk4_exponential_decay() {
+        // y' = -y, y(0) = 1 → y(t) = e^{-t}
+        let states = rk4(|_, y| vec![-y[0]], &[1.0], 0.0, 2.0, 100);
+        assert_eq!(states.len(), 101);
+        assert_eq!(states[0], vec![1.0]);
+        assert!((states[100][0] - (-2.0f64).exp()).abs() < 1e-9);
+        assert!((states[50][0] - (-1.0f64).exp()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_rk4_harmonic_oscillator_system() {
+        // x'' = -x を (x, v) の連立系として1周期積分
+        let period = std::f64::consts::TAU;
+        let states = rk4(|_, y| vec![y[1], -y[0]], &[1.0, 0.0], 0.0, period, 200);
+        let last = states.last().unwrap();
+        assert!((last[0] - 1.0).abs() < 1e-7);
+        assert!(last[1].abs() < 1e-7);
+    }
+
+    #[test]
+    fn test_rk4_fourth_order_convergence() {
+        // ステップ数を倍にすると誤差は約 1/16
+        let f = |t: f64, y: &[f64]| vec![y[0] * t.cos()];
+        let exact = 1.0f64.sin().exp();
+        let coarse = rk4(f, &[1.0], 0.0, 1.0, 10).last().unwrap()[0] - exact;
+        let fine = rk4(f, &[1.0], 0.0, 1.0, 20).last().unwrap()[0] - exact;
+        let ratio = coarse.abs() / fine.abs();
+        assert!(ratio > 12.0 && ratio < 20.0, "ratio = {ratio}");
+    }
+
+    #[test]
+    fn test_rk4_backward_and_zero_steps() {
+        let states = rk4(|_, y| vec![y[0]], &[1.0], 1.0, 0.0, 50);
+        assert!((states[50][0] - (-1.0f64).exp()).abs() < 1e-8);
+
+        let only_initial = rk4(|_, y| vec![y[0]], &[3.0, 4.0], 0.0, 1.0, 0);
+        assert_eq!(only_initial, vec![vec![3.0, 4.0]]);
+    }
+
+    #[test]
+    fn test_rk45_meets_tolerance_and_ends_at_t1() {
+        let trajectory = rk45(|_, y| vec![y[1], -y[0]], &[1.0, 0.0], 0.0, 10.0, 1e-10).unwrap();
+        let (t_end, y_end) = trajectory.last().unwrap();
+        assert_eq!(*t_end, 10.0);
+        assert!((y_end[0] - 10.0f64.cos()).abs() < 1e-8);
+        assert!((y_end[1] + 10.0f64.sin()).abs() < 1e-8);
+
+        // 時刻は単調増加
+        assert!(trajectory.windows(2).all(|w| w[1].0 > w[0].0));
+    }
+
+    #[test]
+    fn test_rk45_adapts_step_count_to_tolerance() {
+        let f = |_: f64, y: &[f64]| vec![y[1], -y[0]];
+        let loose = rk45(f, &[1.0, 0.0], 0.0, 10.0, 1e-4).unwrap();
+        let tight = rk45(f, &[1.0, 0.0], 0.0, 10.0, 1e-10).unwrap();
+        assert!(loose.len() < tight.len());
+
+        let loose_error = (loose.last().unwrap().1[0] - 10.0f64.cos()).abs();
+        assert!(loose_error < 1e-2);
+    }
+
+    #[test]
+    fn test_rk45_backward_and_empty_interval() {
+        let trajectory = rk45(|_, y| vec![-y[0]], &[1.0], 2.0, 0.0, 1e-9).unwrap();
+        let (t_end, y_end) = trajectory.last().unwrap();
+        assert_eq!(*t_end, 0.0);
+        assert!((y_end[0] - 2.0f64.exp()).abs() < 1e-7);
+
+        let single = rk45(|_, y| vec![y[0]], &[1.0], 1.0, 1.0, 1e-9).unwrap();
+        assert_eq!(single, vec![(1.0, vec![1.0])]);
+    }
+}
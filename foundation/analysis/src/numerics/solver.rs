@@ -55,6 +55,157 @@ where
     newton_solve(g, df, initial, max_iter, tol)
 }
 
+/// 導関数なしのニュートン法による方程式求解
+///
+/// 導関数を中心差分で近似してニュートン法を行う。差分幅は
+/// `ε^{1/3} * max(1, |x|)`（中心差分の丸め誤差と打ち切り誤差の釣り合う幅）。
+/// 近似導関数が極小の場合は、直前の反復点とのセカント法にフォールバックする
+/// （初回は `x` から少しずらした点を直前の点として使う）。
+///
+/// 増分 `|x_{k+1} - x_k|` と関数値 `|f(x_{k+1})|` の両方が `tol` 以下で収束とする。
+pub fn newton_solve_numeric<F>(f: F, initial: f64, max_iter: usize, tol: f64) -> Option<f64>
+where
+    F: Fn(f64) -> f64,
+{
+    let mut x = initial;
+    let mut fx = f(x);
+    if fx == 0.0 {
+        return Some(x);
+    }
+
+    let mut previous: Option<(f64, f64)> = None;
+    for _ in 0..max_iter {
+        let h = f64::EPSILON.cbrt() * x.abs().max(1.0);
+        let mut slope = (f(x + h) - f(x - h)) / (2.0 * h);
+
+        if slope.abs() < DERIVATIVE_ZERO_THRESHOLD {
+            // セカント法: 直前の点（なければ少しずらした点）との傾き
+            let (x_prev, f_prev) = previous.unwrap_or_else(|| {
+                let offset = x + 1e-3 * x.abs().max(1.0);
+                (offset, f(offset))
+            });
+            slope = (fx - f_prev) / (x - x_prev);
+            if !slope.is_finite() || slope.abs() < DERIVATIVE_ZERO_THRESHOLD {
+                return None;
+            }
+        }
+
+        let next = x - fx / slope;
+        let f_next = f(next);
+        if !next.is_finite() || !f_next.is_finite() {
+            return None;
+        }
+        if (next - x).abs() <= tol && f_next.abs() <= tol {
+            return Some(next);
+        }
+
+        previous = Some((x, fx));
+        x = next;
+        fx = f_next;
+    }
+    None
+}
+
+/// Brent法による1次元求根
+///
+/// 区間 `[a, b]` の両端で `f` の符号が異なることを前提に、逆二次補間・セカント法・
+/// 二分法を組み合わせて根を求める。補間が不適切な場合は二分法に切り替えるため、
+/// 二分法と同等以上の確実さで収束する。
+///
+/// 両端で符号が変わらない場合は `None` を返す（端点がちょうど根ならその端点を返す）。
+/// 根を囲む区間幅が `tol` 程度になった時点で収束とする。途中で関数値が非有限になった場合や、
+/// 反復回数の上限までに収束しなかった場合も `None` を返す。
+pub fn brent_solve<F>(f: F, a: f64, b: f64, tol: f64) -> Option<f64>
+where
+    F: Fn(f64) -> f64,
+{
+    const MAX_ITERATIONS: usize = 200;
+
+    let (mut a, mut b) = (a, b);
+    let (mut fa, mut fb) = (f(a), f(b));
+    if fa == 0.0 {
+        return Some(a);
+    }
+    if fb == 0.0 {
+        return Some(b);
+    }
+    if fa.signum() == fb.signum() || !fa.is_finite() || !fb.is_finite() {
+        return None;
+    }
+
+    // b: 最良の近似、a: 直前の b、c: b と符号の異なる対点
+    let (mut c, mut fc) = (a, fa);
+    let mut d = b - a;
+    let mut e = d;
+    for _ in 0..MAX_ITERATIONS {
+        if fb.signum() == fc.signum() {
+            c = a;
+            fc = fa;
+            d = b - a;
+            e = d;
+        }
+        if fc.abs() < fb.abs() {
+            a = b;
+            b = c;
+            c = a;
+            fa = fb;
+            fb = fc;
+            fc = fa;
+        }
+
+        let tolerance = 2.0 * f64::EPSILON * b.abs() + 0.5 * tol;
+        let midpoint = 0.5 * (c - b);
+        if midpoint.abs() <= tolerance || fb == 0.0 {
+            return Some(b);
+        }
+
+        if e.abs() >= tolerance && fa.abs() > fb.abs() {
+            // 補間を試みる（a == c ならセカント法、それ以外は逆二次補間）
+            let s = fb / fa;
+            let (mut p, mut q) = if a == c {
+                (2.0 * midpoint * s, 1.0 - s)
+            } else {
+                let q = fa / fc;
+                let r = fb / fc;
+                (
+                    s * (2.0 * midpoint * q * (q - r) - (b - a) * (r - 1.0)),
+                    (q - 1.0) * (r - 1.0) * (s - 1.0),
+                )
+            };
+            if p > 0.0 {
+                q = -q;
+            } else {
+                p = -p;
+            }
+
+            // 補間点が区間内にあり、十分に縮小する場合のみ採用
+            if 2.0 * p < (3.0 * midpoint * q - (tolerance * q).abs()).min((e * q).abs()) {
+                e = d;
+                d = p / q;
+            } else {
+                d = midpoint;
+                e = d;
+            }
+        } else {
+            d = midpoint;
+            e = d;
+        }
+
+        a = b;
+        fa = fb;
+        b += if d.abs() > tolerance {
+            d
+        } else {
+            tolerance.copysign(midpoint)
+        };
+        fb = f(b);
+        if !fb.is_finite() {
+            return None;
+        }
+    }
+    None
+}
+
 /// 曲線の弧長を数値積分で近似計算（汎用ベクトル向け）
 ///
 /// パラメトリック曲線 r(t) の弧長を台形公式により数値積分で計算。
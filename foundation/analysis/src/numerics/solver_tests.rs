@@ -4,7 +4,7 @@
 
 #[cfg(test)]
 mod tests {
-    use crate::numerics::solver::newton_solve;
+    use crate::numerics::solver::{brent_solve, newton_solve, newton_solve_numeric};
 
     #[test]
     fn test_newton_solver() {
@@ -44,6 +44,84 @@ mod tests {
         assert!(result.is_none()); // 収束しないことを確認
     }
 
+    #[test]
+    fn test_newton_numeric_matches_analytic() {
+        // x^3 - 8 = 0（導関数なし）
+        let root = newton_solve_numeric(|x| x * x * x - 8.0, 1.5, 100, 1e-12).unwrap();
+        assert!((root - 2.0).abs() < 1e-12);
+
+        // cos(x) = x（暗黙的な交点計算の典型例）
+        let root = newton_solve_numeric(|x: f64| x.cos() - x, 0.5, 100, 1e-12).unwrap();
+        assert!((root - 0.739_085_133_215_160_6).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_newton_numeric_secant_fallback_at_stationary_point() {
+        // x = 0 で導関数ゼロ：セカント法で抜け出して x = 2 に収束
+        let root = newton_solve_numeric(|x| x * x - 4.0, 0.0, 100, 1e-10).unwrap();
+        assert!((root.abs() - 2.0).abs() < 1e-10);
+
+        // 初期値がすでに根
+        assert_eq!(newton_solve_numeric(|x| x - 3.0, 3.0, 10, 1e-12), Some(3.0));
+    }
+
+    #[test]
+    fn test_newton_numeric_requires_small_residual() {
+        // 根を持たない関数は増分が小さくなっても関数値が収束しない
+        assert!(newton_solve_numeric(|x| x * x + 1.0, 0.5, 50, 1e-10).is_none());
+        // 定数関数は傾きゼロでフォールバックも失敗
+        assert!(newton_solve_numeric(|_| 1.0, 0.0, 10, 1e-10).is_none());
+    }
+
+    #[test]
+    fn test_brent_polynomial_and_transcendental() {
+        let root = brent_solve(|x| x * x * x - 2.0 * x - 5.0, 2.0, 3.0, 1e-14).unwrap();
+        assert!((root - 2.094_551_481_542_326_5).abs() < 1e-13);
+
+        let root = brent_solve(|x: f64| x.cos() - x, 0.0, 1.0, 1e-14).unwrap();
+        assert!((root - 0.739_085_133_215_160_6).abs() < 1e-13);
+
+        // 区間の向きは問わない
+        let root = brent_solve(|x| x * x - 2.0, 2.0, 0.0, 1e-14).unwrap();
+        assert!((root - std::f64::consts::SQRT_2).abs() < 1e-13);
+    }
+
+    #[test]
+    fn test_brent_converges_where_newton_struggles() {
+        // 原点で導関数が無限大になる立方根：ニュートン法は発散するがBrent法は収束する
+        let f = |x: f64| x.cbrt();
+        let root = brent_solve(f, -1.0, 2.0, 1e-12).unwrap();
+        assert!(root.abs() < 1e-11);
+
+        // 根の近くで平坦な関数
+        let root = brent_solve(|x: f64| (x - 1.0).powi(5), 0.0, 3.0, 1e-12).unwrap();
+        assert!((root - 1.0).abs() < 1e-12);
+        assert!((root - 1.0).powi(5).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_brent_requires_sign_change() {
+        assert!(brent_solve(|x| x * x + 1.0, -1.0, 1.0, 1e-12).is_none());
+        assert!(brent_solve(|x| x * x - 4.0, -1.0, 1.0, 1e-12).is_none());
+        // 端点がちょうど根
+        assert_eq!(brent_solve(|x| x - 1.0, 1.0, 5.0, 1e-12), Some(1.0));
+    }
+
+    #[test]
+    fn test_brent_reports_non_convergence() {
+        // 区間内部で関数値が NaN になる場合は区間が縮んでも根とはみなさない
+        let f = |x: f64| {
+            if x <= 0.0 {
+                -1.0
+            } else if x >= 1.0 {
+                1.0
+            } else {
+                f64::NAN
+            }
+        };
+        assert!(brent_solve(f, 0.0, 1.0, 1e-12).is_none());
+    }
+
     // === 統計計算テスト（numerical_methods の一部として） ===
 
     #[test]
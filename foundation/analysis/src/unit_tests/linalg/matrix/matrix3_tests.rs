@@ -220,4 +220,90 @@ mod tests {
         assert!(trs.is_affine_transform());
         assert!(rst.is_affine_transform());
     }
+
+    fn assert_qr_3(a: &Matrix3) {
+        let (q, r) = a.qr();
+        let reconstructed = q * r;
+        let q_t_q = q.transpose() * q;
+        let identity = Matrix3::identity();
+        for i in 0..3 {
+            for j in 0..3 {
+                assert!((reconstructed.get(i, j) - a.get(i, j)).abs() < TOLERANCE);
+                assert!((q_t_q.get(i, j) - identity.get(i, j)).abs() < TOLERANCE);
+                if i > j {
+                    assert_eq!(r.get(i, j), 0.0);
+                }
+            }
+            assert!(r.get(i, i) >= 0.0);
+        }
+    }
+
+    #[test]
+    fn test_qr_decomposition() {
+        assert_qr_3(&Matrix3::new(
+            12.0, -51.0, 4.0, 6.0, 167.0, -68.0, -4.0, 24.0, -41.0,
+        ));
+        // 既知の分解: R の対角は (14, 175, 35)
+        let (_, r) = Matrix3::new(12.0, -51.0, 4.0, 6.0, 167.0, -68.0, -4.0, 24.0, -41.0).qr();
+        assert!((r.get(0, 0) - 14.0).abs() < 1e-9);
+        assert!((r.get(1, 1) - 175.0).abs() < 1e-9);
+        assert!((r.get(2, 2) - 35.0).abs() < 1e-9);
+
+        // 負の対角を持つ上三角行列、ゼロ列を含む特異行列
+        assert_qr_3(&Matrix3::new(-2.0, 1.0, 3.0, 0.0, -1.0, 4.0, 0.0, 0.0, 5.0));
+        assert_qr_3(&Matrix3::new(0.0, 1.0, 2.0, 0.0, 3.0, 4.0, 0.0, 5.0, 6.0));
+        assert_qr_3(&Matrix3::zeros());
+    }
+
+    fn assert_eigen_symmetric(a: &Matrix3, expected: [f64; 3]) {
+        let (values, vectors) = a.eigen_symmetric();
+        for i in 0..3 {
+            assert!((values[i] - expected[i]).abs() < 1e-9, "{values:?}");
+            // A v = λ v
+            let residual = a.mul_vector(&vectors[i]) - vectors[i] * values[i];
+            assert!(residual.norm() < 1e-9);
+            for j in 0..3 {
+                let expected_dot = if i == j { 1.0 } else { 0.0 };
+                assert!((vectors[i].dot(&vectors[j]) - expected_dot).abs() < TOLERANCE);
+            }
+        }
+    }
+
+    #[test]
+    fn test_eigen_symmetric() {
+        // 対角行列は昇順に並べ替えられる
+        assert_eigen_symmetric(
+            &Matrix3::new(3.0, 0.0, 0.0, 0.0, -1.0, 0.0, 0.0, 0.0, 2.0),
+            [-1.0, 2.0, 3.0],
+        );
+        let (_, vectors) =
+            Matrix3::new(3.0, 0.0, 0.0, 0.0, -1.0, 0.0, 0.0, 0.0, 2.0).eigen_symmetric();
+        assert!((vectors[0].y().abs() - 1.0).abs() < TOLERANCE);
+        assert!((vectors[2].x().abs() - 1.0).abs() < TOLERANCE);
+
+        // 一般の対称行列（固有値 2-√2, 2, 2+√2）
+        let sqrt2 = 2.0_f64.sqrt();
+        assert_eigen_symmetric(
+            &Matrix3::new(2.0, -1.0, 0.0, -1.0, 2.0, -1.0, 0.0, -1.0, 2.0),
+            [2.0 - sqrt2, 2.0, 2.0 + sqrt2],
+        );
+
+        // 重解（縮退）でも正規直交基底を返す
+        assert_eigen_symmetric(
+            &Matrix3::new(2.0, 1.0, 1.0, 1.0, 2.0, 1.0, 1.0, 1.0, 2.0),
+            [1.0, 1.0, 4.0],
+        );
+        assert_eigen_symmetric(&Matrix3::identity(), [1.0, 1.0, 1.0]);
+    }
+
+    #[test]
+    fn test_eigen_symmetric_symmetrizes_input() {
+        let asymmetric = Matrix3::new(4.0, 3.0, 0.0, 1.0, 4.0, 0.0, 0.0, 0.0, 1.0);
+        let symmetric = Matrix3::new(4.0, 2.0, 0.0, 2.0, 4.0, 0.0, 0.0, 0.0, 1.0);
+        let (values, _) = asymmetric.eigen_symmetric();
+        assert_eigen_symmetric(&symmetric, [1.0, 2.0, 6.0]);
+        for (value, expected) in values.iter().zip([1.0, 2.0, 6.0]) {
+            assert!((value - expected).abs() < 1e-9);
+        }
+    }
 }
@@ -356,4 +356,65 @@ mod tests {
         assert!(trs.is_affine_transform_3d());
         assert!(rst.is_affine_transform_3d());
     }
+
+    #[test]
+    fn test_qr_decomposition() {
+        let matrices = [
+            Matrix4::new(
+                4.0, 1.0, -2.0, 2.0, 1.0, 2.0, 0.0, 1.0, -2.0, 0.0, 3.0, -2.0, 2.0, 1.0, -2.0, -1.0,
+            ),
+            Matrix4::rotation_z_3d(PI / 3.0) * Matrix4::scale_3d(&Vec3::new(2.0, -1.0, 0.5)),
+            // ランク落ち（第3行 = 第1行 + 第2行）
+            Matrix4::new(
+                1.0, 2.0, 3.0, 4.0, 2.0, 0.0, 1.0, 1.0, 3.0, 2.0, 4.0, 5.0, 0.0, 1.0, 0.0, 1.0,
+            ),
+        ];
+        for a in matrices {
+            let (q, r) = a.qr();
+            let reconstructed = q * r;
+            let q_t_q = q.transpose() * q;
+            for i in 0..4 {
+                for j in 0..4 {
+                    assert!((reconstructed.get(i, j) - a.get(i, j)).abs() < TOLERANCE);
+                    let expected = if i == j { 1.0 } else { 0.0 };
+                    assert!((q_t_q.get(i, j) - expected).abs() < TOLERANCE);
+                    if i > j {
+                        assert_eq!(r.get(i, j), 0.0);
+                    }
+                }
+                assert!(r.get(i, i) >= 0.0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_homogeneous_point_and_vector() {
+        let transform = Matrix4::translation_3d(&Vec3::new(1.0, 2.0, 3.0))
+            * Matrix4::scale_3d(&Vec3::new(2.0, 2.0, 2.0));
+        let p = Vec3::new(1.0, 1.0, 1.0);
+
+        // 点は平行移動の影響を受け、ベクトルは受けない
+        let point = (transform * Vec4::from_point3(p)).to_point3().unwrap();
+        assert!((point - Vec3::new(3.0, 4.0, 5.0)).norm() < TOLERANCE);
+        let vector = transform * Vec4::from_vector3(p);
+        assert_eq!(vector.w(), 0.0);
+        assert!((vector.xyz() - Vec3::new(2.0, 2.0, 2.0)).norm() < TOLERANCE);
+
+        // 方向ベクトル（w=0）は3D点へ戻せない
+        assert!(vector.to_point3().is_none());
+    }
+
+    #[test]
+    fn test_homogeneous_perspective_division() {
+        let projection = Matrix4::perspective(PI / 2.0, 1.0, 1.0, 100.0);
+        let clip = projection * Vec4::from_point3(Vec3::new(1.0, 1.0, -2.0));
+        assert!((clip.w() - 2.0).abs() < TOLERANCE);
+        let ndc = clip.to_point3().unwrap();
+        assert!((ndc.x() - 0.5).abs() < TOLERANCE);
+        assert!((ndc.y() - 0.5).abs() < TOLERANCE);
+
+        // 視点と同じ奥行きの点は w=0 となり除算できない
+        let at_eye = projection * Vec4::from_point3(Vec3::new(1.0, 0.0, 0.0));
+        assert!(at_eye.to_point3().is_none());
+    }
 }
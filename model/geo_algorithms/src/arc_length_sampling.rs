@@ -0,0 +1,242 @@
+//! 弧長等間隔サンプリング
+//!
+//! 任意の `ParametricCurve3D` について、定義域を細かく分けた各区間の弧長を
+//! Gauss-Legendre 求積で求めて「累積弧長 → パラメータ」の対応表を作る。
+//! 各サンプルの目標弧長を含む区間を対応表の二分探索で見つけ、区間内を線形補間した
+//! パラメータを初期値として、速さ `|tangent_at(t)|` を使うニュートン法で詰める。
+//!
+//! 始点と終点が一致する曲線は閉曲線として扱い、終点（= 始点）を重複させない。
+
+use analysis::numerics::gauss_legendre;
+use geo_foundation::ParametricCurve3D;
+use geo_primitives::{Point3D, Vector3D};
+
+/// 区間ごとの弧長の求積に使う Gauss-Legendre 則の点数
+///
+/// `gauss_legendre` は呼び出しごとに節点を求めるため、表を持つ点数（10）を使う。
+const QUADRATURE_POINTS: usize = 10;
+
+/// 対応表の区間数の下限
+const MIN_TABLE_INTERVALS: usize = 256;
+
+/// 出力サンプル1つあたりの対応表の区間数
+const TABLE_INTERVALS_PER_SAMPLE: usize = 4;
+
+/// 補間したパラメータを詰めるニュートン法の反復回数
+const NEWTON_ITERATIONS: usize = 4;
+
+/// 閉曲線とみなす始点・終点間の距離（曲線長に対する比）
+const CLOSED_CURVE_RELATIVE_TOLERANCE: f64 = 1e-9;
+
+/// 曲線を弧長について等間隔にサンプリングする
+///
+/// 間隔は `spacing` を超えない範囲で曲線長を等分する値に揃える（端数を残さない）。
+/// 開曲線は始点と終点を含む `n + 1` 点、閉曲線は始点を含み終点を重複させない
+/// `n` 点を返す（`n = ceil(長さ / spacing)`、閉曲線では3以上）。
+/// `spacing` が正の有限値でなければ空、長さが0なら始点だけを返す。
+pub fn sample_equal_arc_length<C>(curve: &C, spacing: f64) -> Vec<Point3D<f64>>
+where
+    C: ParametricCurve3D<f64, Point = Point3D<f64>, Vector = Vector3D<f64>>,
+{
+    if !(spacing > 0.0 && spacing.is_finite()) {
+        return Vec::new();
+    }
+    let (start, end) = curve.domain();
+    let first = curve.point_at(start);
+    let table = ArcLengthTable::build(curve, start, end, MIN_TABLE_INTERVALS);
+    let length = table.total();
+    if length <= 0.0 {
+        return vec![first];
+    }
+
+    let closed =
+        first.distance_to(&curve.point_at(end)) <= CLOSED_CURVE_RELATIVE_TOLERANCE * length;
+    let mut count = (length / spacing).ceil().max(1.0) as usize;
+    if closed {
+        count = count.max(3);
+    }
+
+    // 対応表が粗いと補間の初期値が悪くなるので、サンプル数に応じて作り直す
+    let table = if count * TABLE_INTERVALS_PER_SAMPLE > MIN_TABLE_INTERVALS {
+        ArcLengthTable::build(curve, start, end, count * TABLE_INTERVALS_PER_SAMPLE)
+    } else {
+        table
+    };
+    let step = table.total() / count as f64;
+
+    let mut points = Vec::with_capacity(count + 1);
+    points.push(first);
+    points.extend((1..count).map(|i| curve.point_at(table.parameter_at(curve, step * i as f64))));
+    if !closed {
+        points.push(curve.point_at(end));
+    }
+    points
+}
+
+/// 累積弧長とパラメータの対応表
+struct ArcLengthTable {
+    /// 区間の境界のパラメータ（昇順）
+    parameters: Vec<f64>,
+    /// 各境界までの累積弧長（先頭は0）
+    lengths: Vec<f64>,
+}
+
+impl ArcLengthTable {
+    /// 定義域 `[start, end]` を `intervals` 等分した対応表
+    fn build<C>(curve: &C, start: f64, end: f64, intervals: usize) -> Self
+    where
+        C: ParametricCurve3D<f64, Point = Point3D<f64>, Vector = Vector3D<f64>>,
+    {
+        let parameters: Vec<f64> = (0..=intervals)
+            .map(|i| start + (end - start) * i as f64 / intervals as f64)
+            .collect();
+        let mut lengths = Vec::with_capacity(intervals + 1);
+        lengths.push(0.0);
+        let mut total = 0.0;
+        for pair in parameters.windows(2) {
+            total += integrate_speed(curve, pair[0], pair[1]);
+            lengths.push(total);
+        }
+        Self {
+            parameters,
+            lengths,
+        }
+    }
+
+    fn total(&self) -> f64 {
+        self.lengths[self.lengths.len() - 1]
+    }
+
+    /// 始点からの弧長が `target` となるパラメータ
+    fn parameter_at<C>(&self, curve: &C, target: f64) -> f64
+    where
+        C: ParametricCurve3D<f64, Point = Point3D<f64>, Vector = Vector3D<f64>>,
+    {
+        // target を含む区間 [lengths[i], lengths[i + 1]]
+        let i = self
+            .lengths
+            .partition_point(|&length| length <= target)
+            .clamp(1, self.lengths.len() - 1)
+            - 1;
+        let (t0, t1) = (self.parameters[i], self.parameters[i + 1]);
+        let (s0, s1) = (self.lengths[i], self.lengths[i + 1]);
+        if s1 <= s0 {
+            return t0;
+        }
+
+        // 区間内の線形補間を初期値に、s(t) = s0 + ∫[t0, t] |C'| = target をニュートン法で解く
+        let mut t = t0 + (t1 - t0) * (target - s0) / (s1 - s0);
+        for _ in 0..NEWTON_ITERATIONS {
+            let speed = curve.tangent_at(t).length();
+            if speed <= 0.0 {
+                break;
+            }
+            let residual = s0 + integrate_speed(curve, t0, t) - target;
+            t = (t - residual / speed).clamp(t0, t1);
+        }
+        t
+    }
+}
+
+/// `[a, b]` 上の速さ `|tangent_at(t)|` の Gauss-Legendre 求積
+fn integrate_speed<C>(curve: &C, a: f64, b: f64) -> f64
+where
+    C: ParametricCurve3D<f64, Point = Point3D<f64>, Vector = Vector3D<f64>>,
+{
+    gauss_legendre(|t| curve.tangent_at(t).length(), a, b, QUADRATURE_POINTS)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use geo_foundation::Angle;
+    use geo_primitives::{Arc3D, Ellipse3D, EllipseArc3D, LineSegment3D};
+
+    /// 隣り合う点の距離（閉曲線なら末尾から先頭への距離も含む）
+    fn gaps(points: &[Point3D<f64>], closed: bool) -> Vec<f64> {
+        let mut gaps: Vec<f64> = points
+            .windows(2)
+            .map(|pair| pair[0].distance_to(&pair[1]))
+            .collect();
+        if closed {
+            gaps.push(points[points.len() - 1].distance_to(&points[0]));
+        }
+        gaps
+    }
+
+    #[test]
+    fn test_line_segment_is_divided_evenly() {
+        let segment =
+            LineSegment3D::new(Point3D::new(0.0, 0.0, 0.0), Point3D::new(0.0, 3.0, 4.0)).unwrap();
+        // 長さ5を間隔1.2以下で等分 → 5区間（間隔1）
+        let points = sample_equal_arc_length(&segment, 1.2);
+        assert_eq!(points.len(), 6);
+        assert_eq!(points[0], segment.start());
+        assert_eq!(points[5], segment.end());
+        for gap in gaps(&points, false) {
+            assert!((gap - 1.0).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_full_circle_is_closed() {
+        let circle = Arc3D::xy_arc(
+            Point3D::origin(),
+            1.0,
+            Angle::from_degrees(0.0),
+            Angle::from_degrees(360.0),
+        )
+        .unwrap();
+        let spacing = std::f64::consts::TAU / 12.0;
+        let points = sample_equal_arc_length(&circle, spacing * 1.0001);
+        // 終点（= 始点）は重複させない
+        assert_eq!(points.len(), 12);
+        let chord = 2.0 * (spacing / 2.0).sin();
+        for gap in gaps(&points, true) {
+            assert!((gap - chord).abs() < 1e-9, "{gap} != {chord}");
+        }
+    }
+
+    #[test]
+    fn test_ellipse_arc_has_equal_arc_lengths() {
+        // パラメータについて等間隔では弧長が偏る扁平な楕円弧
+        let ellipse = Ellipse3D::new(
+            Point3D::new(1.0, -2.0, 0.5),
+            5.0,
+            1.0,
+            Vector3D::new(0.0, 1.0, 1.0),
+            Vector3D::unit_x(),
+        )
+        .unwrap();
+        let arc = EllipseArc3D::new(
+            ellipse,
+            Angle::from_degrees(-30.0),
+            Angle::from_degrees(150.0),
+        );
+        let length = ParametricCurve3D::length(&arc);
+        let points = sample_equal_arc_length(&arc, 0.01);
+        let segments = points.len() - 1;
+        assert_eq!(segments, (length / 0.01).ceil() as usize);
+        assert_eq!(points[0], arc.start_point());
+        assert!(points[segments].distance_to(&arc.end_point()) < 1e-12);
+
+        // 間隔が小さいので弦長は弧長にほぼ等しく、どれも揃う
+        let step = length / segments as f64;
+        for gap in gaps(&points, false) {
+            assert!((gap - step).abs() < 1e-5, "{gap} != {step}");
+        }
+    }
+
+    #[test]
+    fn test_degenerate_inputs() {
+        let segment =
+            LineSegment3D::new(Point3D::new(0.0, 0.0, 0.0), Point3D::new(1.0, 0.0, 0.0)).unwrap();
+        assert!(sample_equal_arc_length(&segment, 0.0).is_empty());
+        assert!(sample_equal_arc_length(&segment, f64::NAN).is_empty());
+        // 間隔が長さより長ければ両端だけ
+        assert_eq!(
+            sample_equal_arc_length(&segment, 10.0),
+            vec![segment.start(), segment.end()]
+        );
+    }
+}
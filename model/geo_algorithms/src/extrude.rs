@@ -0,0 +1,148 @@
+//! 2Dプロファイルの押し出しによる閉じた三角形メッシュ生成
+//!
+//! 平面のローカル座標 (u, v) で与えたプロファイルを、平面の法線方向へ押し出す。
+//! 上下の蓋は耳刈り三角形分割、側面は各辺の四角形を三角形2枚で張る。
+//! プロファイルの向きを揃えてから面を張るため、全ての面の法線は外向きになる。
+
+use geo_primitives::{Plane3D, Point2D, TriangleMesh3D};
+
+use crate::polygon_triangulation::{
+    remove_collinear_vertices, signed_area, triangulate_polygon_2d,
+};
+
+/// 単純多角形のプロファイルを `plane` の法線方向へ `distance` だけ押し出す
+///
+/// `distance` が負なら法線の逆方向へ押し出す。プロファイルの向き（CCW/CW）は問わない。
+/// 一直線上に並ぶ頂点は取り除いてから面を張るため、結果は閉じた2-多様体になる。
+/// 頂点 `i` が平面上の蓋、`n + i` が押し出した側の蓋（n は残った頂点数）。
+/// プロファイルが単純多角形でない場合や `distance` が0・非有限の場合は `Err`。
+pub fn extrude_profile(
+    profile: &[Point2D<f64>],
+    plane: &Plane3D<f64>,
+    distance: f64,
+) -> Result<TriangleMesh3D<f64>, String> {
+    if distance == 0.0 || !distance.is_finite() {
+        return Err(format!("Invalid extrusion distance: {}", distance));
+    }
+
+    let profile = remove_collinear_vertices(profile);
+    let caps = triangulate_polygon_2d(&profile)?;
+    let n = profile.len();
+
+    // 蓋の三角形は反時計回り（平面の法線側が表）。押し出し方向の蓋をそのまま使い、
+    // 平面上の蓋は裏返す。負方向の押し出しでは役割が入れ替わる。
+    let forward = distance > 0.0;
+    let flip = |[a, b, c]: [usize; 3]| [a, c, b];
+
+    let offset = plane.normal().as_vector() * distance;
+    let bottom: Vec<_> = profile
+        .iter()
+        .map(|p| plane.local_to_world(p.x(), p.y()))
+        .collect();
+    let extruded = bottom.iter().map(|&p| p + offset);
+    let vertices: Vec<_> = bottom.iter().copied().chain(extruded).collect();
+
+    let mut indices = Vec::with_capacity(2 * caps.len() + 2 * n);
+    for &triangle in &caps {
+        let [a, b, c] = triangle;
+        let top = [a + n, b + n, c + n];
+        if forward {
+            indices.push(flip(triangle));
+            indices.push(top);
+        } else {
+            indices.push(triangle);
+            indices.push(flip(top));
+        }
+    }
+
+    // 側面: 押し出し方向から見て反時計回りの辺 i→j について (i, j, j', i')
+    let ccw = signed_area(&profile) > 0.0;
+    for i in 0..n {
+        let j = (i + 1) % n;
+        let (i, j) = if ccw == forward { (i, j) } else { (j, i) };
+        indices.push([i, j, j + n]);
+        indices.push([i, j + n, i + n]);
+    }
+
+    TriangleMesh3D::new(vertices, indices)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::half_edge::HalfEdgeMesh;
+    use crate::test_support::{polygon, signed_volume};
+    use geo_primitives::{Point3D, Vector3D};
+
+    fn assert_closed_manifold(mesh: &TriangleMesh3D<f64>) {
+        let half_edges = HalfEdgeMesh::from_triangle_mesh(mesh).unwrap();
+        assert!(half_edges.boundary_loops().is_empty());
+        // 穴のない立体なのでオイラー標数は2
+        let edge_count = mesh.triangle_count() * 3 / 2;
+        assert_eq!(mesh.vertex_count() + mesh.triangle_count() - edge_count, 2);
+    }
+
+    #[test]
+    fn test_extrude_concave_profile() {
+        // L字型（面積3）、時計回りで与える
+        let mut profile = polygon(&[
+            (0.0, 0.0),
+            (2.0, 0.0),
+            (2.0, 1.0),
+            (1.0, 1.0),
+            (1.0, 2.0),
+            (0.0, 2.0),
+        ]);
+        profile.reverse();
+        let mesh = extrude_profile(&profile, &Plane3D::xy_plane(0.0), 2.0).unwrap();
+
+        assert_eq!(mesh.vertex_count(), 12);
+        assert_eq!(mesh.triangle_count(), 2 * 4 + 2 * 6);
+        assert_closed_manifold(&mesh);
+        assert!((signed_volume(&mesh) - 6.0).abs() < 1e-12);
+
+        let (min, max) = mesh.bounding_box().unwrap();
+        assert_eq!(min, Point3D::new(0.0, 0.0, 0.0));
+        assert_eq!(max, Point3D::new(2.0, 2.0, 2.0));
+    }
+
+    #[test]
+    fn test_extrude_negative_distance_on_tilted_plane() {
+        let plane = Plane3D::from_origin_and_axes(
+            Point3D::new(1.0, 2.0, 3.0),
+            Vector3D::new(1.0, 1.0, 0.0),
+            Vector3D::new(0.0, 0.0, 1.0),
+        )
+        .unwrap();
+        // 辺上の頂点と閉じるための重複点を含む正方形
+        let profile = polygon(&[
+            (0.0, 0.0),
+            (0.5, 0.0),
+            (1.0, 0.0),
+            (1.0, 1.0),
+            (0.0, 1.0),
+            (0.0, 0.0),
+        ]);
+        let mesh = extrude_profile(&profile, &plane, -3.0).unwrap();
+
+        assert_eq!(mesh.vertex_count(), 8);
+        assert_closed_manifold(&mesh);
+        assert!((signed_volume(&mesh) - 3.0).abs() < 1e-9);
+        // 押し出した側の蓋は法線の逆方向にある
+        for i in 4..8 {
+            let (_, _, w) = plane.world_to_local(mesh.vertex(i).unwrap());
+            assert!((w + 3.0).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_extrude_invalid_input() {
+        let plane = Plane3D::xy_plane(0.0);
+        let square = polygon(&[(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)]);
+        assert!(extrude_profile(&square, &plane, 0.0).is_err());
+        assert!(extrude_profile(&square, &plane, f64::NAN).is_err());
+
+        let bowtie = polygon(&[(0.0, 0.0), (1.0, 1.0), (1.0, 0.0), (0.0, 1.0)]);
+        assert!(extrude_profile(&bowtie, &plane, 1.0).is_err());
+    }
+}
@@ -0,0 +1,427 @@
+//! 三角形メッシュの half-edge データ構造
+//!
+//! 面 f の k 番目の half-edge をインデックス `3f + k` に置き、`next` / `prev` は同じ面内の
+//! 添字計算で求める。対向する half-edge（`twin`）は有向辺 (始点, 終点) の対応表で結び、
+//! 境界の half-edge は `twin` を持たない。
+//!
+//! 頂点の1-ring・面の隣接面・境界ループを局所的な巡回で列挙する。構築時に多様体性
+//! （各辺の隣接面が2枚以下で向きが揃っていること、各頂点の周りの面が1つの扇で繋がること）を
+//! 検査し、満たさない入力は `Err` を返す。
+
+use std::collections::HashMap;
+use std::fmt;
+
+use geo_primitives::{Point3D, TriangleMesh3D};
+
+/// half-edge（面の境界を反時計回りに辿る有向辺）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HalfEdge {
+    /// 始点の頂点インデックス
+    pub origin: usize,
+    /// 逆向きの half-edge（境界なら `None`）
+    pub twin: Option<usize>,
+    /// 所属する面のインデックス
+    pub face: usize,
+}
+
+/// half-edge メッシュ構築時のエラー
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HalfEdgeError {
+    /// 同じ頂点を2回以上含む面
+    DegenerateFace(usize),
+    /// 3枚以上の面が共有する辺（頂点インデックスの組）
+    NonManifoldEdge(usize, usize),
+    /// 隣接面が辺を同じ向きで辿っている（面の向きが揃っていない）
+    InconsistentOrientation(usize, usize),
+    /// 周りの面が1つの扇として繋がらない頂点（蝶ネクタイ状の接続など）
+    NonManifoldVertex(usize),
+}
+
+impl fmt::Display for HalfEdgeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HalfEdgeError::DegenerateFace(face) => {
+                write!(f, "face {} repeats a vertex", face)
+            }
+            HalfEdgeError::NonManifoldEdge(a, b) => {
+                write!(f, "edge ({}, {}) is shared by more than two faces", a, b)
+            }
+            HalfEdgeError::InconsistentOrientation(a, b) => {
+                write!(
+                    f,
+                    "faces sharing edge ({}, {}) have opposite orientation",
+                    a, b
+                )
+            }
+            HalfEdgeError::NonManifoldVertex(vertex) => {
+                write!(f, "faces around vertex {} do not form a single fan", vertex)
+            }
+        }
+    }
+}
+
+impl std::error::Error for HalfEdgeError {}
+
+/// 多様体三角形メッシュの half-edge 表現
+#[derive(Debug, Clone)]
+pub struct HalfEdgeMesh {
+    vertices: Vec<Point3D<f64>>,
+    half_edges: Vec<HalfEdge>,
+    /// 各頂点から出る half-edge の1つ（境界頂点では `twin` を持たないもの、孤立頂点は `None`）
+    vertex_half_edge: Vec<Option<usize>>,
+}
+
+impl HalfEdgeMesh {
+    /// 三角形メッシュから構築（非多様体なら `Err`）
+    pub fn from_triangle_mesh(mesh: &TriangleMesh3D<f64>) -> Result<Self, HalfEdgeError> {
+        let indices = mesh.indices();
+        if let Some(face) = indices
+            .iter()
+            .position(|&[a, b, c]| a == b || b == c || c == a)
+        {
+            return Err(HalfEdgeError::DegenerateFace(face));
+        }
+
+        let undirected = undirected_edges(indices);
+        for h in 0..indices.len() * 3 {
+            let (origin, destination) = endpoints(indices, h);
+            let key = (origin.min(destination), origin.max(destination));
+            if undirected[&key].len() > 2 {
+                return Err(HalfEdgeError::NonManifoldEdge(key.0, key.1));
+            }
+        }
+
+        let mut half_edges = Vec::with_capacity(indices.len() * 3);
+        let mut directed: HashMap<(usize, usize), usize> = HashMap::new();
+        for h in 0..indices.len() * 3 {
+            let (origin, destination) = endpoints(indices, h);
+            if directed.insert((origin, destination), h).is_some() {
+                return Err(HalfEdgeError::InconsistentOrientation(
+                    origin.min(destination),
+                    origin.max(destination),
+                ));
+            }
+            half_edges.push(HalfEdge {
+                origin,
+                twin: None,
+                face: h / 3,
+            });
+        }
+
+        for h in 0..half_edges.len() {
+            let destination = half_edges[next(h)].origin;
+            half_edges[h].twin = directed.get(&(destination, half_edges[h].origin)).copied();
+        }
+
+        // 境界の half-edge があればそれを、なければ任意の出る half-edge を代表にする
+        let mut vertex_half_edge = vec![None; mesh.vertex_count()];
+        let mut outgoing_count = vec![0usize; mesh.vertex_count()];
+        for (h, half_edge) in half_edges.iter().enumerate() {
+            outgoing_count[half_edge.origin] += 1;
+            let slot = &mut vertex_half_edge[half_edge.origin];
+            if slot.is_none() || half_edge.twin.is_none() {
+                *slot = Some(h);
+            }
+        }
+
+        let result = Self {
+            vertices: mesh.vertices().to_vec(),
+            half_edges,
+            vertex_half_edge,
+        };
+
+        // 代表 half-edge からの巡回で全ての出る half-edge に届かなければ複数の扇がある
+        for (vertex, &count) in outgoing_count.iter().enumerate() {
+            if result.outgoing_half_edges(vertex).map_or(0, |h| h.len()) != count {
+                return Err(HalfEdgeError::NonManifoldVertex(vertex));
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// 頂点数
+    pub fn vertex_count(&self) -> usize {
+        self.vertices.len()
+    }
+
+    /// 面数
+    pub fn face_count(&self) -> usize {
+        self.half_edges.len() / 3
+    }
+
+    /// 頂点座標
+    pub fn vertex(&self, index: usize) -> Option<Point3D<f64>> {
+        self.vertices.get(index).copied()
+    }
+
+    /// 全ての half-edge（面 f の k 番目がインデックス `3f + k`）
+    pub fn half_edges(&self) -> &[HalfEdge] {
+        &self.half_edges
+    }
+
+    /// 同じ面で次の half-edge（範囲外の half-edge なら `None`）
+    pub fn next(&self, half_edge: usize) -> Option<usize> {
+        (half_edge < self.half_edges.len()).then(|| next(half_edge))
+    }
+
+    /// 同じ面で前の half-edge（範囲外の half-edge なら `None`）
+    pub fn prev(&self, half_edge: usize) -> Option<usize> {
+        (half_edge < self.half_edges.len()).then(|| prev(half_edge))
+    }
+
+    /// half-edge の終点（範囲外の half-edge なら `None`）
+    pub fn destination(&self, half_edge: usize) -> Option<usize> {
+        self.next(half_edge).map(|h| self.half_edges[h].origin)
+    }
+
+    /// 頂点が境界上にあるか（孤立頂点・範囲外の頂点は境界とみなさない）
+    pub fn is_boundary_vertex(&self, vertex: usize) -> bool {
+        self.vertex_half_edge
+            .get(vertex)
+            .copied()
+            .flatten()
+            .is_some_and(|h| self.half_edges[h].twin.is_none())
+    }
+
+    /// 頂点から出る half-edge を反時計回りに列挙
+    ///
+    /// 境界頂点では境界の half-edge から始まる。孤立頂点では空、範囲外の頂点では `None`。
+    fn outgoing_half_edges(&self, vertex: usize) -> Option<Vec<usize>> {
+        let Some(start) = *self.vertex_half_edge.get(vertex)? else {
+            return Some(Vec::new());
+        };
+        let mut result = vec![start];
+        let mut current = start;
+        // 前の half-edge（頂点へ入る辺）の twin が、反時計回りに隣の面で頂点から出る辺
+        while let Some(rotated) = self.half_edges[prev(current)].twin {
+            if rotated == start || result.len() > self.half_edges.len() {
+                break;
+            }
+            result.push(rotated);
+            current = rotated;
+        }
+        Some(result)
+    }
+
+    /// 頂点の1-ring 近傍（隣接頂点）を反時計回りに列挙
+    ///
+    /// 境界頂点では一方の境界辺の相手から始まり、もう一方の境界辺の相手で終わる。
+    /// 孤立頂点では空、範囲外の頂点では `None`。
+    pub fn vertex_neighbors(&self, vertex: usize) -> Option<Vec<usize>> {
+        let outgoing = self.outgoing_half_edges(vertex)?;
+        let mut neighbors: Vec<usize> = outgoing
+            .iter()
+            .map(|&h| self.half_edges[next(h)].origin)
+            .collect();
+        if let Some(&last) = outgoing.last() {
+            if self.half_edges[prev(last)].twin.is_none() {
+                neighbors.push(self.half_edges[prev(last)].origin);
+            }
+        }
+        Some(neighbors)
+    }
+
+    /// 頂点に接する面を反時計回りに列挙（範囲外の頂点では `None`）
+    pub fn vertex_faces(&self, vertex: usize) -> Option<Vec<usize>> {
+        let outgoing = self.outgoing_half_edges(vertex)?;
+        Some(
+            outgoing
+                .into_iter()
+                .map(|h| self.half_edges[h].face)
+                .collect(),
+        )
+    }
+
+    /// 面と辺を共有する隣接面（境界辺の分は含まない、範囲外の面では `None`）
+    pub fn face_neighbors(&self, face: usize) -> Option<Vec<usize>> {
+        if face >= self.face_count() {
+            return None;
+        }
+        Some(
+            (3 * face..3 * face + 3)
+                .filter_map(|h| self.half_edges[h].twin)
+                .map(|twin| self.half_edges[twin].face)
+                .collect(),
+        )
+    }
+
+    /// 境界ループを頂点インデックスの列で抽出
+    ///
+    /// 各ループは面の向きに沿った順序（面の反時計回りと同じ向き）で並ぶ。
+    pub fn boundary_loops(&self) -> Vec<Vec<usize>> {
+        let mut visited = vec![false; self.half_edges.len()];
+        let mut loops = Vec::new();
+        for start in 0..self.half_edges.len() {
+            if visited[start] || self.half_edges[start].twin.is_some() {
+                continue;
+            }
+            let mut boundary = Vec::new();
+            let mut current = start;
+            while !visited[current] {
+                visited[current] = true;
+                boundary.push(self.half_edges[current].origin);
+                // 多様体なので終点から出る境界 half-edge は1つだけ
+                match self.vertex_half_edge[self.half_edges[next(current)].origin] {
+                    Some(h) if self.half_edges[h].twin.is_none() => current = h,
+                    _ => break,
+                }
+            }
+            loops.push(boundary);
+        }
+        loops
+    }
+}
+
+/// half-edge `3f + k` の (始点, 終点)
+pub(crate) fn endpoints(indices: &[[usize; 3]], half_edge: usize) -> (usize, usize) {
+    let face = indices[half_edge / 3];
+    (face[half_edge % 3], face[next(half_edge) % 3])
+}
+
+/// 無向辺（頂点番号の小さい順）→ その辺を辿る half-edge の一覧
+///
+/// 向きの揃っていない面や3枚以上の面が共有する辺もそのまま集める（始点と終点が同じ
+/// half-edge は除く）。`HalfEdgeMesh` の多様体検査と、向きを揃える前の隣接判定で共有する。
+pub(crate) fn undirected_edges(indices: &[[usize; 3]]) -> HashMap<(usize, usize), Vec<usize>> {
+    let mut edges: HashMap<(usize, usize), Vec<usize>> = HashMap::new();
+    for h in 0..indices.len() * 3 {
+        let (origin, destination) = endpoints(indices, h);
+        if origin != destination {
+            let key = (origin.min(destination), origin.max(destination));
+            edges.entry(key).or_default().push(h);
+        }
+    }
+    edges
+}
+
+fn next(half_edge: usize) -> usize {
+    half_edge - half_edge % 3 + (half_edge + 1) % 3
+}
+
+fn prev(half_edge: usize) -> usize {
+    half_edge - half_edge % 3 + (half_edge + 2) % 3
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use geo_primitives::triangle_mesh_3d_fixtures::tetrahedron;
+
+    /// 中心頂点 0 の周りに6枚の三角形を並べた六角形（境界は外周）
+    fn hexagon_fan() -> TriangleMesh3D<f64> {
+        let mut vertices = vec![Point3D::new(0.0, 0.0, 0.0)];
+        for i in 0..6 {
+            let angle = std::f64::consts::PI / 3.0 * i as f64;
+            vertices.push(Point3D::new(angle.cos(), angle.sin(), 0.0));
+        }
+        let indices = (0..6).map(|i| [0, 1 + i, 1 + (i + 1) % 6]).collect();
+        TriangleMesh3D::new(vertices, indices).unwrap()
+    }
+
+    #[test]
+    fn test_one_ring_and_boundary_loop() {
+        let mesh = HalfEdgeMesh::from_triangle_mesh(&hexagon_fan()).unwrap();
+        assert_eq!(mesh.face_count(), 6);
+
+        // 内部頂点の1-ringは外周6頂点（反時計回り）
+        let mut ring = mesh.vertex_neighbors(0).unwrap();
+        assert_eq!(ring.len(), 6);
+        let offset = ring.iter().position(|&v| v == 1).unwrap();
+        ring.rotate_left(offset);
+        assert_eq!(ring, vec![1, 2, 3, 4, 5, 6]);
+        assert!(!mesh.is_boundary_vertex(0));
+
+        // 境界頂点は境界辺の相手から始まり、もう一方の境界辺の相手で終わる
+        assert!(mesh.is_boundary_vertex(1));
+        assert_eq!(mesh.vertex_neighbors(1), Some(vec![2, 0, 6]));
+        assert_eq!(mesh.vertex_faces(1).map(|faces| faces.len()), Some(2));
+
+        // 隣接面は前後の2枚
+        let mut neighbors = mesh.face_neighbors(0).unwrap();
+        neighbors.sort();
+        assert_eq!(neighbors, vec![1, 5]);
+
+        let loops = mesh.boundary_loops();
+        assert_eq!(loops.len(), 1);
+        let mut boundary = loops[0].clone();
+        let offset = boundary.iter().position(|&v| v == 1).unwrap();
+        boundary.rotate_left(offset);
+        assert_eq!(boundary, vec![1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn test_closed_mesh() {
+        let mesh = HalfEdgeMesh::from_triangle_mesh(&tetrahedron()).unwrap();
+        assert!(mesh.boundary_loops().is_empty());
+        for vertex in 0..4 {
+            let mut ring = mesh.vertex_neighbors(vertex).unwrap();
+            ring.sort();
+            let expected: Vec<usize> = (0..4).filter(|&v| v != vertex).collect();
+            assert_eq!(ring, expected);
+        }
+        for face in 0..4 {
+            assert_eq!(mesh.face_neighbors(face).map(|faces| faces.len()), Some(3));
+        }
+        for (h, half_edge) in mesh.half_edges().iter().enumerate() {
+            let twin = half_edge.twin.unwrap();
+            assert_eq!(mesh.half_edges()[twin].twin, Some(h));
+            assert_eq!(mesh.destination(twin), Some(half_edge.origin));
+            assert_eq!(mesh.prev(h).and_then(|p| mesh.next(p)), Some(h));
+        }
+    }
+
+    #[test]
+    fn test_out_of_range_ids() {
+        let mesh = HalfEdgeMesh::from_triangle_mesh(&tetrahedron()).unwrap();
+        let half_edge_count = mesh.half_edges().len();
+
+        assert_eq!(mesh.next(half_edge_count), None);
+        assert_eq!(mesh.prev(half_edge_count), None);
+        assert_eq!(mesh.destination(half_edge_count), None);
+        assert_eq!(mesh.vertex_neighbors(4), None);
+        assert_eq!(mesh.vertex_faces(4), None);
+        assert_eq!(mesh.face_neighbors(4), None);
+        assert!(!mesh.is_boundary_vertex(4));
+    }
+
+    #[test]
+    fn test_non_manifold_input() {
+        let points = vec![
+            Point3D::new(0.0, 0.0, 0.0),
+            Point3D::new(1.0, 0.0, 0.0),
+            Point3D::new(0.0, 1.0, 0.0),
+            Point3D::new(0.0, -1.0, 0.0),
+            Point3D::new(0.0, 0.0, 1.0),
+            Point3D::new(-1.0, 0.0, 0.0),
+        ];
+        let build = |indices: Vec<[usize; 3]>| {
+            HalfEdgeMesh::from_triangle_mesh(&TriangleMesh3D::new(points.clone(), indices).unwrap())
+        };
+
+        // 3枚の面が辺 (0, 1) を共有
+        assert_eq!(
+            build(vec![[0, 1, 2], [1, 0, 3], [0, 1, 4]]).unwrap_err(),
+            HalfEdgeError::NonManifoldEdge(0, 1)
+        );
+        // 向きが揃っていない
+        assert_eq!(
+            build(vec![[0, 1, 2], [0, 1, 3]]).unwrap_err(),
+            HalfEdgeError::InconsistentOrientation(0, 1)
+        );
+        // 頂点 0 だけで接する2つの扇（蝶ネクタイ）
+        assert_eq!(
+            build(vec![[0, 1, 2], [0, 5, 3]]).unwrap_err(),
+            HalfEdgeError::NonManifoldVertex(0)
+        );
+        assert_eq!(
+            build(vec![[0, 1, 1]]).unwrap_err(),
+            HalfEdgeError::DegenerateFace(0)
+        );
+
+        // 孤立頂点は許容する
+        let mesh = build(vec![[0, 1, 2]]).unwrap();
+        assert_eq!(mesh.vertex_neighbors(5), Some(Vec::new()));
+        assert!(!mesh.is_boundary_vertex(5));
+    }
+}
@@ -1,26 +1,29 @@
-/// 補間、曲線近似用関数群
-/// 線形、ベジェ、Catmull-Rom、NURBS風の補間、近似
-use geo_foundation::ToleranceContext;
+//! 補間、曲線近似用関数群
+//!
+//! 線形補間、任意次数のベジエ曲線（de Casteljau 分割、次数昇格）、Catmull-Rom スプラインを提供する。
+//! Catmull-Rom は 2D・3D とも、ノット間隔を制御点間距離の `alpha` 乗で決める一般形で評価する。
+
+use geo_foundation::ToleranceSettings;
+use geo_primitives::{Point2D, Point3D, Vector2D};
 
 /// 線形補間器
 pub struct LinearInterpolator {
-    tolerance: ToleranceContext,
+    tolerance: ToleranceSettings<f64>,
 }
 
 impl LinearInterpolator {
-    pub fn new(tolerance: ToleranceContext) -> Self {
+    /// 許容誤差を指定して作成
+    pub fn new(tolerance: ToleranceSettings<f64>) -> Self {
         Self { tolerance }
     }
 
     /// 2点間の線形補間
-    pub fn interpolate(&self, p0: &Point2D, p1: &Point2D, t: f64) -> Point2D {
-        let x = p0.x().value() * (1.0 - t) + p1.x().value() * t;
-        let y = p0.y().value() * (1.0 - t) + p1.y().value() * t;
-        Point2D::from_f64(x, y)
+    pub fn interpolate(&self, p0: &Point2D<f64>, p1: &Point2D<f64>, t: f64) -> Point2D<f64> {
+        lerp(*p0, *p1, t)
     }
 
     /// 点列の区分線形補間
-    pub fn interpolate_polyline(&self, points: &[Point2D], t: f64) -> Option<Point2D> {
+    pub fn interpolate_polyline(&self, points: &[Point2D<f64>], t: f64) -> Option<Point2D<f64>> {
         if points.len() < 2 {
             return None;
         }
@@ -37,7 +40,7 @@ impl LinearInterpolator {
         let mut total_length = 0.0;
 
         for i in 1..points.len() {
-            let dist = points[i - 1].distance_to(&points[i]).value();
+            let dist = points[i - 1].distance_to(&points[i]);
             total_length += dist;
             lengths.push(total_length);
         }
@@ -50,7 +53,7 @@ impl LinearInterpolator {
                 let segment_start = lengths[i - 1];
                 let segment_length = lengths[i] - segment_start;
 
-                if segment_length < self.tolerance.linear {
+                if segment_length < self.tolerance.distance_tolerance {
                     return Some(points[i - 1]);
                 }
 
@@ -63,85 +66,160 @@ impl LinearInterpolator {
     }
 }
 
-/// 3次ベジエ曲線
+/// 2Dベジエ曲線（次数 = 制御点数 - 1）
+#[derive(Debug, Clone, PartialEq)]
 pub struct BezierCurve {
-    p0: Point2D,
-    p1: Point2D,
-    p2: Point2D,
-    p3: Point2D,
+    control_points: Vec<Point2D<f64>>,
 }
 
 impl BezierCurve {
-    pub fn new(p0: Point2D, p1: Point2D, p2: Point2D, p3: Point2D) -> Self {
-        Self { p0, p1, p2, p3 }
+    /// 制御点からベジエ曲線を作成（制御点が空なら `None`）
+    pub fn new(control_points: Vec<Point2D<f64>>) -> Option<Self> {
+        if control_points.is_empty() {
+            return None;
+        }
+        Some(Self { control_points })
     }
 
-    /// ベジエ曲線上の点を評価
-    pub fn evaluate(&self, t: f64) -> Point2D {
-        let u = 1.0 - t;
-        let tt = t * t;
-        let uu = u * u;
-        let uuu = uu * u;
-        let ttt = tt * t;
+    /// 4つの制御点から3次ベジエ曲線を作成
+    pub fn cubic(p0: Point2D<f64>, p1: Point2D<f64>, p2: Point2D<f64>, p3: Point2D<f64>) -> Self {
+        Self {
+            control_points: vec![p0, p1, p2, p3],
+        }
+    }
 
-        let x = uuu * self.p0.x().value()
-            + 3.0 * uu * t * self.p1.x().value()
-            + 3.0 * u * tt * self.p2.x().value()
-            + ttt * self.p3.x().value();
+    /// 制御点
+    pub fn control_points(&self) -> &[Point2D<f64>] {
+        &self.control_points
+    }
 
-        let y = uuu * self.p0.y().value()
-            + 3.0 * uu * t * self.p1.y().value()
-            + 3.0 * u * tt * self.p2.y().value()
-            + ttt * self.p3.y().value();
+    /// 次数
+    pub fn degree(&self) -> usize {
+        self.control_points.len() - 1
+    }
 
-        Point2D::from_f64(x, y)
+    /// ベジエ曲線上の点を評価（de Casteljau）
+    pub fn evaluate(&self, t: f64) -> Point2D<f64> {
+        let mut points = self.control_points.clone();
+        for level in (1..points.len()).rev() {
+            for i in 0..level {
+                points[i] = lerp(points[i], points[i + 1], t);
+            }
+        }
+        points[0]
     }
 
     /// 1次導関数（接線ベクトル）
-    pub fn derivative(&self, t: f64) -> Vector2D {
-        let u = 1.0 - t;
-        let uu = u * u;
-        let tt = t * t;
-
-        let dx = -3.0 * uu * self.p0.x().value()
-            + 3.0 * (uu - 2.0 * u * t) * self.p1.x().value()
-            + 3.0 * (2.0 * u * t - tt) * self.p2.x().value()
-            + 3.0 * tt * self.p3.x().value();
-
-        let dy = -3.0 * uu * self.p0.y().value()
-            + 3.0 * (uu - 2.0 * u * t) * self.p1.y().value()
-            + 3.0 * (2.0 * u * t - tt) * self.p2.y().value()
-            + 3.0 * tt * self.p3.y().value();
+    pub fn derivative(&self, t: f64) -> Vector2D<f64> {
+        let degree = self.degree();
+        if degree == 0 {
+            return Vector2D::new(0.0, 0.0);
+        }
 
-        Vector2D::from_f64(dx, dy)
+        // 導関数は差分制御点による (n-1) 次ベジエ
+        let scale = degree as f64;
+        let hodograph: Vec<Point2D<f64>> = self
+            .control_points
+            .windows(2)
+            .map(|pair| {
+                Point2D::new(
+                    (pair[1].x() - pair[0].x()) * scale,
+                    (pair[1].y() - pair[0].y()) * scale,
+                )
+            })
+            .collect();
+        let point = Self {
+            control_points: hodograph,
+        }
+        .evaluate(t);
+        Vector2D::new(point.x(), point.y())
     }
 
     /// 曲線を指定した分割数でサンプリング
-    pub fn sample(&self, divisions: usize) -> Vec<Point2D> {
-        let mut points = Vec::with_capacity(divisions + 1);
+    pub fn sample(&self, divisions: usize) -> Vec<Point2D<f64>> {
+        let divisions = divisions.max(1);
+        (0..=divisions)
+            .map(|i| self.evaluate(i as f64 / divisions as f64))
+            .collect()
+    }
 
-        for i in 0..=divisions {
-            let t = i as f64 / divisions as f64;
-            points.push(self.evaluate(t));
+    /// パラメータ `t` で2本に分割（de Casteljau）
+    ///
+    /// 前半は元の [0, t]、後半は [t, 1] をそれぞれ [0, 1] に再パラメータ化した、
+    /// 元と同じ次数の曲線になる。
+    pub fn split(&self, t: f64) -> (BezierCurve, BezierCurve) {
+        let n = self.control_points.len();
+        let mut points = self.control_points.clone();
+        let mut left = Vec::with_capacity(n);
+        let mut right = Vec::with_capacity(n);
+
+        // 各段の先頭が前半、末尾が後半の制御点になる
+        left.push(points[0]);
+        right.push(points[n - 1]);
+        for level in (1..n).rev() {
+            for i in 0..level {
+                points[i] = lerp(points[i], points[i + 1], t);
+            }
+            left.push(points[0]);
+            right.push(points[level - 1]);
         }
+        right.reverse();
+
+        (
+            Self {
+                control_points: left,
+            },
+            Self {
+                control_points: right,
+            },
+        )
+    }
 
-        points
+    /// 次数を1つ上げる（曲線形状は変わらない）
+    ///
+    /// 新しい制御点 Q_i = (i / (n+1)) P_{i-1} + (1 - i / (n+1)) P_i
+    pub fn elevate_degree(&self) -> BezierCurve {
+        let points = &self.control_points;
+        let n = points.len();
+        let mut elevated = Vec::with_capacity(n + 1);
+        elevated.push(points[0]);
+        for i in 1..n {
+            let alpha = i as f64 / n as f64;
+            elevated.push(lerp(points[i], points[i - 1], alpha));
+        }
+        elevated.push(points[n - 1]);
+
+        Self {
+            control_points: elevated,
+        }
     }
 }
 
+fn lerp(a: Point2D<f64>, b: Point2D<f64>, t: f64) -> Point2D<f64> {
+    Point2D::new(a.x() + (b.x() - a.x()) * t, a.y() + (b.y() - a.y()) * t)
+}
+
 /// カットマル・ロム・スプライン
+///
+/// 先頭と末尾の制御点は接線を決めるためだけに使い、曲線は2番目から最後の1つ手前の制御点を通る。
 pub struct CatmullRomSpline {
-    points: Vec<Point2D>,
+    points: Vec<Point2D<f64>>,
     tension: f64, // 張力パラメータ（0.0 = カットマル・ロム、0.5 = Centripetal）
 }
 
 impl CatmullRomSpline {
-    pub fn new(points: Vec<Point2D>, tension: f64) -> Self {
+    /// 制御点と張力パラメータ（ノット間隔の指数 alpha）から作成
+    pub fn new(points: Vec<Point2D<f64>>, tension: f64) -> Self {
         Self { points, tension }
     }
 
+    /// 張力パラメータ
+    pub fn tension(&self) -> f64 {
+        self.tension
+    }
+
     /// スプライン曲線上の点を評価
-    pub fn evaluate(&self, t: f64) -> Option<Point2D> {
+    pub fn evaluate(&self, t: f64) -> Option<Point2D<f64>> {
         if self.points.len() < 4 {
             return None;
         }
@@ -155,48 +233,16 @@ impl CatmullRomSpline {
             return Some(self.points[self.points.len() - 2]);
         }
 
-        let p0 = &self.points[segment_index];
-        let p1 = &self.points[segment_index + 1];
-        let p2 = &self.points[segment_index + 2];
-        let p3 = &self.points[segment_index + 3];
-
-        Some(self.catmull_rom_interpolate(p0, p1, p2, p3, local_t))
-    }
-
-    fn catmull_rom_interpolate(
-        &self,
-        p0: &Point2D,
-        p1: &Point2D,
-        p2: &Point2D,
-        p3: &Point2D,
-        t: f64,
-    ) -> Point2D {
-        let t2 = t * t;
-        let t3 = t2 * t;
-
-        let x = 0.5
-            * ((2.0 * p1.x().value())
-                + (-p0.x().value() + p2.x().value()) * t
-                + (2.0 * p0.x().value() - 5.0 * p1.x().value() + 4.0 * p2.x().value()
-                    - p3.x().value())
-                    * t2
-                + (-p0.x().value() + 3.0 * p1.x().value() - 3.0 * p2.x().value() + p3.x().value())
-                    * t3);
-
-        let y = 0.5
-            * ((2.0 * p1.y().value())
-                + (-p0.y().value() + p2.y().value()) * t
-                + (2.0 * p0.y().value() - 5.0 * p1.y().value() + 4.0 * p2.y().value()
-                    - p3.y().value())
-                    * t2
-                + (-p0.y().value() + 3.0 * p1.y().value() - 3.0 * p2.y().value() + p3.y().value())
-                    * t3);
-
-        Point2D::from_f64(x, y)
+        let controls = [0, 1, 2, 3].map(|i| {
+            let point = self.points[segment_index + i];
+            [point.x(), point.y()]
+        });
+        let [x, y] = catmull_rom_segment(controls, self.tension, local_t);
+        Some(Point2D::new(x, y))
     }
 
     /// スプライン曲線を均等にサンプリング
-    pub fn sample(&self, divisions: usize) -> Vec<Point2D> {
+    pub fn sample(&self, divisions: usize) -> Vec<Point2D<f64>> {
         let mut result = Vec::with_capacity(divisions + 1);
 
         for i in 0..=divisions {
@@ -209,3 +255,367 @@ impl CatmullRomSpline {
         result
     }
 }
+
+/// ノット間隔の決め方（張力パラメータ）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CatmullRomKind {
+    /// 等間隔ノット（alpha = 0）。古典的な Catmull-Rom
+    Uniform,
+    /// 距離の平方根（alpha = 0.5）。カスプや自己交差が生じにくい
+    #[default]
+    Centripetal,
+    /// 距離そのもの（alpha = 1）。制御点間距離に比例
+    Chordal,
+}
+
+impl CatmullRomKind {
+    /// ノット間隔の指数 alpha
+    pub fn alpha(&self) -> f64 {
+        match self {
+            CatmullRomKind::Uniform => 0.0,
+            CatmullRomKind::Centripetal => 0.5,
+            CatmullRomKind::Chordal => 1.0,
+        }
+    }
+}
+
+/// 3D制御点を通過する Catmull-Rom スプライン
+///
+/// 制御点をすべて通過する。両端には端点を反転させた仮想点（phantom point）を補い、
+/// 最初と最後の区間も補間する。
+#[derive(Debug, Clone)]
+pub struct CatmullRomSpline3D {
+    points: Vec<Point3D<f64>>,
+    kind: CatmullRomKind,
+}
+
+impl CatmullRomSpline3D {
+    /// 通過点と種類からスプラインを作成
+    pub fn new(points: Vec<Point3D<f64>>, kind: CatmullRomKind) -> Self {
+        Self { points, kind }
+    }
+
+    /// 通過点
+    pub fn points(&self) -> &[Point3D<f64>] {
+        &self.points
+    }
+
+    /// ノット間隔の種類
+    pub fn kind(&self) -> CatmullRomKind {
+        self.kind
+    }
+
+    /// 区間数（通過点数 - 1）
+    pub fn segment_count(&self) -> usize {
+        self.points.len().saturating_sub(1)
+    }
+
+    /// 全体パラメータ `t` ∈ [0, 1] で評価
+    ///
+    /// 各区間に等しいパラメータ幅を割り当てる。`t` は [0, 1] にクランプする。
+    /// 通過点が2未満の場合は `None` を返す。
+    pub fn evaluate(&self, t: f64) -> Option<Point3D<f64>> {
+        let segment_count = self.segment_count();
+        if segment_count == 0 {
+            return None;
+        }
+
+        let scaled = t.clamp(0.0, 1.0) * segment_count as f64;
+        let segment = (scaled.floor() as usize).min(segment_count - 1);
+        Some(self.evaluate_segment(segment, scaled - segment as f64))
+    }
+
+    /// 等パラメータ間隔で `n` 点をサンプリング（両端を含む）
+    ///
+    /// `n` が区間数の倍数 + 1 のとき、すべての通過点がサンプルに含まれる。
+    pub fn sample(&self, n: usize) -> Vec<Point3D<f64>> {
+        if self.segment_count() == 0 || n == 0 {
+            return Vec::new();
+        }
+        if n == 1 {
+            return vec![self.points[0]];
+        }
+
+        (0..n)
+            .filter_map(|i| self.evaluate(i as f64 / (n - 1) as f64))
+            .collect()
+    }
+
+    /// 区間 `segment`（points[segment] → points[segment + 1]）を局所パラメータ `u` ∈ [0, 1] で評価
+    fn evaluate_segment(&self, segment: usize, u: f64) -> Point3D<f64> {
+        let p1 = coords(&self.points[segment]);
+        let p2 = coords(&self.points[segment + 1]);
+        // 端では端点を反転させた仮想点を使う
+        let p0 = if segment > 0 {
+            coords(&self.points[segment - 1])
+        } else {
+            reflect(p1, p2)
+        };
+        let p3 = if segment + 2 < self.points.len() {
+            coords(&self.points[segment + 2])
+        } else {
+            reflect(p2, p1)
+        };
+
+        let [x, y, z] = catmull_rom_segment([p0, p1, p2, p3], self.kind.alpha(), u);
+        Point3D::new(x, y, z)
+    }
+}
+
+fn coords(point: &Point3D<f64>) -> [f64; 3] {
+    [point.x(), point.y(), point.z()]
+}
+
+/// `pivot` を中心に `other` を反転した点（2 * pivot - other）
+fn reflect(pivot: [f64; 3], other: [f64; 3]) -> [f64; 3] {
+    [
+        2.0 * pivot[0] - other[0],
+        2.0 * pivot[1] - other[1],
+        2.0 * pivot[2] - other[2],
+    ]
+}
+
+/// 区間 p1 → p2 を局所パラメータ `u` ∈ [0, 1] で評価する（Barry-Goldman のピラミッド形式）
+///
+/// ノット間隔は制御点間距離の `alpha` 乗で、0 で uniform、0.5 で centripetal、1 で chordal。
+/// 2D・3D のスプラインで共有する。
+fn catmull_rom_segment<const N: usize>(
+    [p0, p1, p2, p3]: [[f64; N]; 4],
+    alpha: f64,
+    u: f64,
+) -> [f64; N] {
+    let t0 = 0.0;
+    let t1 = t0 + knot_interval(p0, p1, alpha);
+    let t2 = t1 + knot_interval(p1, p2, alpha);
+    let t3 = t2 + knot_interval(p2, p3, alpha);
+    let t = t1 + (t2 - t1) * u;
+
+    let a1 = blend(p0, p1, t0, t1, t);
+    let a2 = blend(p1, p2, t1, t2, t);
+    let a3 = blend(p2, p3, t2, t3, t);
+    let b1 = blend(a1, a2, t0, t2, t);
+    let b2 = blend(a2, a3, t1, t3, t);
+    blend(b1, b2, t1, t2, t)
+}
+
+/// 距離の alpha 乗によるノット間隔（重複点では等間隔にフォールバック）
+fn knot_interval<const N: usize>(a: [f64; N], b: [f64; N], alpha: f64) -> f64 {
+    let distance_squared = (0..N).map(|i| (b[i] - a[i]).powi(2)).sum::<f64>();
+    let interval = distance_squared.powf(alpha * 0.5);
+    if interval > 1e-12 {
+        interval
+    } else {
+        1.0
+    }
+}
+
+/// ノット [ta, tb] 上の線形ブレンド
+fn blend<const N: usize>(a: [f64; N], b: [f64; N], ta: f64, tb: f64, t: f64) -> [f64; N] {
+    let w = (t - ta) / (tb - ta);
+    std::array::from_fn(|i| a[i] + (b[i] - a[i]) * w)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_curve() -> BezierCurve {
+        BezierCurve::cubic(
+            Point2D::new(0.0, 0.0),
+            Point2D::new(1.0, 3.0),
+            Point2D::new(4.0, 3.0),
+            Point2D::new(5.0, -1.0),
+        )
+    }
+
+    fn assert_close(a: Point2D<f64>, b: Point2D<f64>) {
+        assert!(a.distance_to(&b) < 1e-12, "{a:?} != {b:?}");
+    }
+
+    #[test]
+    fn test_evaluate_matches_bernstein_form() {
+        let curve = sample_curve();
+        let p = curve.control_points();
+        for i in 0..=10 {
+            let t = i as f64 / 10.0;
+            let u = 1.0 - t;
+            let weights = [u * u * u, 3.0 * u * u * t, 3.0 * u * t * t, t * t * t];
+            let x: f64 = (0..4).map(|k| weights[k] * p[k].x()).sum();
+            let y: f64 = (0..4).map(|k| weights[k] * p[k].y()).sum();
+            assert_close(curve.evaluate(t), Point2D::new(x, y));
+        }
+        assert!(BezierCurve::new(Vec::new()).is_none());
+    }
+
+    #[test]
+    fn test_split_preserves_shape() {
+        let curve = sample_curve();
+        let split_t = 0.3;
+        let (left, right) = curve.split(split_t);
+        assert_eq!(left.degree(), 3);
+        assert_eq!(right.degree(), 3);
+        assert_close(left.evaluate(1.0), curve.evaluate(split_t));
+        assert_close(right.evaluate(0.0), curve.evaluate(split_t));
+
+        for i in 0..=20 {
+            let s = i as f64 / 20.0;
+            assert_close(left.evaluate(s), curve.evaluate(s * split_t));
+            assert_close(
+                right.evaluate(s),
+                curve.evaluate(split_t + s * (1.0 - split_t)),
+            );
+        }
+    }
+
+    #[test]
+    fn test_elevate_degree_preserves_shape() {
+        let curve = sample_curve();
+        let elevated = curve.elevate_degree();
+        assert_eq!(elevated.degree(), 4);
+        assert_eq!(elevated.control_points().len(), 5);
+
+        let twice = elevated.elevate_degree();
+        for i in 0..=20 {
+            let t = i as f64 / 20.0;
+            assert_close(elevated.evaluate(t), curve.evaluate(t));
+            assert_close(twice.evaluate(t), curve.evaluate(t));
+        }
+    }
+
+    #[test]
+    fn test_derivative_matches_finite_difference() {
+        let curve = sample_curve();
+        let h = 1e-6;
+        for &t in &[0.1, 0.5, 0.9] {
+            let d = curve.derivative(t);
+            let a = curve.evaluate(t - h);
+            let b = curve.evaluate(t + h);
+            assert!((d.x() - (b.x() - a.x()) / (2.0 * h)).abs() < 1e-6);
+            assert!((d.y() - (b.y() - a.y()) / (2.0 * h)).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_catmull_rom_2d_passes_through_inner_points() {
+        let points = vec![
+            Point2D::new(0.0, 0.0),
+            Point2D::new(1.0, 2.0),
+            Point2D::new(3.0, 2.5),
+            Point2D::new(4.0, 0.0),
+            Point2D::new(6.0, -1.0),
+        ];
+        for tension in [0.0, 0.5, 1.0] {
+            let spline = CatmullRomSpline::new(points.clone(), tension);
+            // 2区間（points[1] → points[2] → points[3]）
+            assert_close(spline.evaluate(0.0).unwrap(), points[1]);
+            assert_close(spline.evaluate(0.5).unwrap(), points[2]);
+            assert_close(spline.evaluate(1.0).unwrap(), points[3]);
+        }
+        assert!(CatmullRomSpline::new(points[..3].to_vec(), 0.0)
+            .evaluate(0.5)
+            .is_none());
+    }
+
+    const KINDS: [CatmullRomKind; 3] = [
+        CatmullRomKind::Uniform,
+        CatmullRomKind::Centripetal,
+        CatmullRomKind::Chordal,
+    ];
+
+    fn path() -> Vec<Point3D<f64>> {
+        vec![
+            Point3D::new(0.0, 0.0, 0.0),
+            Point3D::new(1.0, 2.0, 0.5),
+            Point3D::new(4.0, 2.5, 1.0),
+            Point3D::new(5.0, 0.0, 3.0),
+            Point3D::new(5.5, -0.2, 3.1),
+        ]
+    }
+
+    fn assert_close_3d(a: &Point3D<f64>, b: &Point3D<f64>) {
+        assert!(a.distance_to(b) < 1e-9, "{a:?} != {b:?}");
+    }
+
+    #[test]
+    fn test_sample_passes_through_control_points() {
+        let points = path();
+        for kind in KINDS {
+            let spline = CatmullRomSpline3D::new(points.clone(), kind);
+            let per_segment = 8;
+            let samples = spline.sample(spline.segment_count() * per_segment + 1);
+            assert_eq!(samples.len(), 33);
+            for (i, point) in points.iter().enumerate() {
+                assert_close_3d(&samples[i * per_segment], point);
+            }
+        }
+    }
+
+    #[test]
+    fn test_uniform_matches_classic_formula() {
+        let points = path();
+        let spline = CatmullRomSpline3D::new(points.clone(), CatmullRomKind::Uniform);
+
+        // 内部区間 1（points[1] → points[2]）の中点
+        let t = 0.5;
+        let expected: Vec<f64> = (0..3)
+            .map(|axis| {
+                let [p0, p1, p2, p3] = [0, 1, 2, 3].map(|i| coords(&points[i])[axis]);
+                0.5 * (2.0 * p1
+                    + (-p0 + p2) * t
+                    + (2.0 * p0 - 5.0 * p1 + 4.0 * p2 - p3) * t * t
+                    + (-p0 + 3.0 * p1 - 3.0 * p2 + p3) * t * t * t)
+            })
+            .collect();
+        let actual = spline.evaluate(1.5 / 4.0).unwrap();
+        assert_close_3d(
+            &actual,
+            &Point3D::new(expected[0], expected[1], expected[2]),
+        );
+    }
+
+    #[test]
+    fn test_collinear_points_stay_on_line() {
+        // 仮想点も同じ直線上に置かれるため、曲線は直線から外れない
+        let points = vec![
+            Point3D::new(0.0, 0.0, 0.0),
+            Point3D::new(1.0, 1.0, 1.0),
+            Point3D::new(3.0, 3.0, 3.0),
+        ];
+        for kind in KINDS {
+            let spline = CatmullRomSpline3D::new(points.clone(), kind);
+            for point in spline.sample(21) {
+                assert!((point.x() - point.y()).abs() < 1e-9);
+                assert!((point.y() - point.z()).abs() < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn test_degenerate_inputs() {
+        let empty = CatmullRomSpline3D::new(Vec::new(), CatmullRomKind::default());
+        assert!(empty.evaluate(0.5).is_none());
+        assert!(empty.sample(10).is_empty());
+
+        let single =
+            CatmullRomSpline3D::new(vec![Point3D::new(1.0, 2.0, 3.0)], CatmullRomKind::Chordal);
+        assert!(single.sample(10).is_empty());
+
+        // 重複点があっても有限な値を返す
+        let duplicated = CatmullRomSpline3D::new(
+            vec![
+                Point3D::new(0.0, 0.0, 0.0),
+                Point3D::new(0.0, 0.0, 0.0),
+                Point3D::new(1.0, 0.0, 0.0),
+            ],
+            CatmullRomKind::Centripetal,
+        );
+        for point in duplicated.sample(9) {
+            assert!(point.x().is_finite() && point.y().is_finite() && point.z().is_finite());
+        }
+
+        let line = CatmullRomSpline3D::new(path(), CatmullRomKind::Uniform);
+        assert_eq!(line.sample(1), vec![path()[0]]);
+        assert_close_3d(&line.evaluate(-1.0).unwrap(), &path()[0]);
+        assert_close_3d(&line.evaluate(2.0).unwrap(), &path()[4]);
+    }
+}
@@ -0,0 +1,331 @@
+//! 3D点群のKD木
+//!
+//! 座標軸を深さ順（x → y → z）に切り替えながら中央値で分割して一括構築する。
+//! ノードは点インデックスの配列上に暗黙的に配置し（区間の中央がそのノード）、
+//! 探索時はクエリと分割面の距離で反対側の部分木を枝刈りする。動的な挿入は扱わない。
+//!
+//! 最近傍・k近傍・半径内探索の結果はいずれも（元の点群での）インデックスと距離で返す。
+
+use geo_primitives::Point3D;
+
+/// 3D点群のKD木
+#[derive(Debug, Clone)]
+pub struct KdTree3D {
+    points: Vec<Point3D<f64>>,
+    /// 区間 [lo, hi) の中央 `(lo + hi) / 2` がその部分木の根となるよう並べた点インデックス
+    order: Vec<usize>,
+}
+
+impl KdTree3D {
+    /// 点群から中央値分割でKD木を構築
+    pub fn build(points: &[Point3D<f64>]) -> Self {
+        let mut order: Vec<usize> = (0..points.len()).collect();
+        partition(points, &mut order, 0);
+        Self {
+            points: points.to_vec(),
+            order,
+        }
+    }
+
+    /// 点の数
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    /// 点が1つもないか
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// 構築に使った点群
+    pub fn points(&self) -> &[Point3D<f64>] {
+        &self.points
+    }
+
+    /// 最近傍点の（元の点群での）インデックスと距離
+    ///
+    /// 空の木では `(usize::MAX, f64::INFINITY)` を返す。
+    pub fn nearest(&self, query: Point3D<f64>) -> (usize, f64) {
+        let mut best = (usize::MAX, f64::INFINITY);
+        self.search_nearest(&query, 0, self.order.len(), 0, &mut best);
+        (best.0, best.1.sqrt())
+    }
+
+    /// 近い順に最大 `k` 個の点のインデックスと距離
+    ///
+    /// 点数が `k` 未満なら全点を返す。
+    pub fn k_nearest(&self, query: Point3D<f64>, k: usize) -> Vec<(usize, f64)> {
+        let mut found = Vec::with_capacity(k.min(self.len()) + 1);
+        if k > 0 {
+            self.search_k_nearest(&query, k, 0, self.order.len(), 0, &mut found);
+        }
+        found
+            .into_iter()
+            .map(|(index, distance_squared)| (index, distance_squared.sqrt()))
+            .collect()
+    }
+
+    /// 距離 `radius` 以内（境界を含む）の全点のインデックスと距離（近い順）
+    pub fn within_radius(&self, query: Point3D<f64>, radius: f64) -> Vec<(usize, f64)> {
+        let mut found = Vec::new();
+        if radius >= 0.0 {
+            self.search_within_radius(&query, radius * radius, 0, self.order.len(), 0, &mut found);
+        }
+        found.sort_by(|a, b| a.1.total_cmp(&b.1));
+        found
+            .into_iter()
+            .map(|(index, distance_squared)| (index, distance_squared.sqrt()))
+            .collect()
+    }
+
+    /// 区間 [lo, hi) の部分木を探索（`best` は距離の2乗で保持）
+    fn search_nearest(
+        &self,
+        query: &Point3D<f64>,
+        lo: usize,
+        hi: usize,
+        depth: usize,
+        best: &mut (usize, f64),
+    ) {
+        if lo >= hi {
+            return;
+        }
+        let mid = (lo + hi) / 2;
+        let index = self.order[mid];
+        let point = &self.points[index];
+
+        let distance_squared = point.distance_squared_to(query);
+        if distance_squared < best.1 {
+            *best = (index, distance_squared);
+        }
+
+        let offset = coordinate(query, depth) - coordinate(point, depth);
+        let (near, far) = if offset < 0.0 {
+            ((lo, mid), (mid + 1, hi))
+        } else {
+            ((mid + 1, hi), (lo, mid))
+        };
+        self.search_nearest(query, near.0, near.1, depth + 1, best);
+        // 分割面までの距離が現在の最良より遠ければ反対側は調べない
+        if offset * offset < best.1 {
+            self.search_nearest(query, far.0, far.1, depth + 1, best);
+        }
+    }
+
+    /// k近傍探索（`found` は距離の2乗の昇順で最大 `k` 個を保持）
+    fn search_k_nearest(
+        &self,
+        query: &Point3D<f64>,
+        k: usize,
+        lo: usize,
+        hi: usize,
+        depth: usize,
+        found: &mut Vec<(usize, f64)>,
+    ) {
+        if lo >= hi {
+            return;
+        }
+        let mid = (lo + hi) / 2;
+        let index = self.order[mid];
+        let point = &self.points[index];
+
+        let distance_squared = point.distance_squared_to(query);
+        let position = found.partition_point(|&(_, d)| d <= distance_squared);
+        if position < k {
+            found.insert(position, (index, distance_squared));
+            found.truncate(k);
+        }
+
+        let offset = coordinate(query, depth) - coordinate(point, depth);
+        let (near, far) = if offset < 0.0 {
+            ((lo, mid), (mid + 1, hi))
+        } else {
+            ((mid + 1, hi), (lo, mid))
+        };
+        self.search_k_nearest(query, k, near.0, near.1, depth + 1, found);
+        // k個揃っていて、分割面が k番目より遠ければ反対側は調べない
+        let worst = if found.len() < k {
+            f64::INFINITY
+        } else {
+            found[k - 1].1
+        };
+        if offset * offset < worst {
+            self.search_k_nearest(query, k, far.0, far.1, depth + 1, found);
+        }
+    }
+
+    /// 半径内探索（`found` に距離の2乗で追加）
+    fn search_within_radius(
+        &self,
+        query: &Point3D<f64>,
+        radius_squared: f64,
+        lo: usize,
+        hi: usize,
+        depth: usize,
+        found: &mut Vec<(usize, f64)>,
+    ) {
+        if lo >= hi {
+            return;
+        }
+        let mid = (lo + hi) / 2;
+        let index = self.order[mid];
+        let point = &self.points[index];
+
+        let distance_squared = point.distance_squared_to(query);
+        if distance_squared <= radius_squared {
+            found.push((index, distance_squared));
+        }
+
+        let offset = coordinate(query, depth) - coordinate(point, depth);
+        // 分割面が半径内にかかる側だけを調べる
+        if offset <= 0.0 || offset * offset <= radius_squared {
+            self.search_within_radius(query, radius_squared, lo, mid, depth + 1, found);
+        }
+        if offset >= 0.0 || offset * offset <= radius_squared {
+            self.search_within_radius(query, radius_squared, mid + 1, hi, depth + 1, found);
+        }
+    }
+}
+
+/// 深さに対応する分割軸の座標
+fn coordinate(point: &Point3D<f64>, depth: usize) -> f64 {
+    match depth % 3 {
+        0 => point.x(),
+        1 => point.y(),
+        _ => point.z(),
+    }
+}
+
+/// 中央値で再帰的に並べ替え、各区間の中央に分割ノードを置く
+fn partition(points: &[Point3D<f64>], order: &mut [usize], depth: usize) {
+    if order.len() <= 1 {
+        return;
+    }
+    let mid = order.len() / 2;
+    order.select_nth_unstable_by(mid, |&a, &b| {
+        coordinate(&points[a], depth).total_cmp(&coordinate(&points[b], depth))
+    });
+    let (left, right) = order.split_at_mut(mid);
+    partition(points, left, depth + 1);
+    partition(points, &mut right[1..], depth + 1);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 決定的な擬似ランダム点群
+    fn scattered_points(count: usize) -> Vec<Point3D<f64>> {
+        let mut state = 12345u64;
+        let mut next = move || {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (state >> 11) as f64 / (1u64 << 53) as f64
+        };
+        (0..count)
+            .map(|_| Point3D::new(next() * 10.0, next() * 10.0, next() * 10.0))
+            .collect()
+    }
+
+    fn brute_force_nearest(points: &[Point3D<f64>], query: &Point3D<f64>) -> f64 {
+        points
+            .iter()
+            .map(|p| p.distance_to(query))
+            .fold(f64::INFINITY, f64::min)
+    }
+
+    #[test]
+    fn test_nearest_matches_brute_force() {
+        let points = scattered_points(500);
+        let tree = KdTree3D::build(&points);
+        assert_eq!(tree.len(), 500);
+
+        for query in scattered_points(50)
+            .iter()
+            .map(|p| *p + (p.to_vector() * 0.1))
+        {
+            let (index, distance) = tree.nearest(query);
+            assert!((distance - brute_force_nearest(&points, &query)).abs() < 1e-12);
+            assert!((points[index].distance_to(&query) - distance).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_nearest_exact_hit_and_duplicates() {
+        let points = vec![
+            Point3D::new(1.0, 1.0, 1.0),
+            Point3D::new(1.0, 1.0, 1.0),
+            Point3D::new(-2.0, 0.0, 3.0),
+        ];
+        let tree = KdTree3D::build(&points);
+        let (index, distance) = tree.nearest(Point3D::new(-2.0, 0.0, 3.0));
+        assert_eq!(index, 2);
+        assert_eq!(distance, 0.0);
+
+        let (index, _) = tree.nearest(Point3D::new(1.0, 1.2, 1.0));
+        assert!(index < 2);
+    }
+
+    #[test]
+    fn test_k_nearest_matches_brute_force() {
+        let points = scattered_points(300);
+        let tree = KdTree3D::build(&points);
+        let query = Point3D::new(5.0, 4.0, 6.0);
+
+        let mut expected: Vec<f64> = points.iter().map(|p| p.distance_to(&query)).collect();
+        expected.sort_by(f64::total_cmp);
+
+        let result = tree.k_nearest(query, 10);
+        assert_eq!(result.len(), 10);
+        for ((index, distance), expected) in result.iter().zip(&expected) {
+            assert!((distance - expected).abs() < 1e-12);
+            assert!((points[*index].distance_to(&query) - distance).abs() < 1e-12);
+        }
+
+        // k が点数を超える場合は全点、0 なら空
+        assert_eq!(tree.k_nearest(query, 1000).len(), 300);
+        assert!(tree.k_nearest(query, 0).is_empty());
+        assert_eq!(tree.k_nearest(query, 1)[0], tree.nearest(query));
+    }
+
+    #[test]
+    fn test_within_radius_matches_brute_force() {
+        let points = scattered_points(300);
+        let tree = KdTree3D::build(&points);
+        let query = Point3D::new(2.0, 7.0, 3.0);
+        let radius = 2.5;
+
+        let result = tree.within_radius(query, radius);
+        let expected = points
+            .iter()
+            .filter(|p| p.distance_to(&query) <= radius)
+            .count();
+        assert_eq!(result.len(), expected);
+        assert!(result.windows(2).all(|pair| pair[0].1 <= pair[1].1));
+        assert!(result.iter().all(|&(_, distance)| distance <= radius));
+
+        assert!(tree.within_radius(query, -1.0).is_empty());
+    }
+
+    #[test]
+    fn test_within_radius_includes_boundary() {
+        let points: Vec<Point3D<f64>> = (0..5).map(|i| Point3D::new(i as f64, 0.0, 0.0)).collect();
+        let tree = KdTree3D::build(&points);
+        let result = tree.within_radius(Point3D::new(2.0, 0.0, 0.0), 1.0);
+        let mut indices: Vec<usize> = result.iter().map(|&(index, _)| index).collect();
+        indices.sort_unstable();
+        assert_eq!(indices, vec![1, 2, 3]);
+        assert_eq!(result[0], (2, 0.0));
+    }
+
+    #[test]
+    fn test_empty_tree() {
+        let tree = KdTree3D::build(&[]);
+        assert!(tree.is_empty());
+        let origin = Point3D::new(0.0, 0.0, 0.0);
+        assert_eq!(tree.nearest(origin), (usize::MAX, f64::INFINITY));
+        assert!(tree.k_nearest(origin, 3).is_empty());
+        assert!(tree.within_radius(origin, 10.0).is_empty());
+    }
+}
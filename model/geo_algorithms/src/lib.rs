@@ -27,20 +27,79 @@
 //! ## モジュール構成
 //!
 //! - `numerical`: 数値解析アルゴリズム (Newton法、最適化、数値積分)
-//! - `statistics`: 統計解析 (基本統計量、回帰分析、主成分分析)
-//! - `sampling`: サンプリング手法 (適応サンプリング、パターン解析)
-//! - `interpolation`: 補間・近似 (スプライン、ベジエ、NURBS基盤)
+//! - `statistics`: 統計解析 (基本統計量、分位数・五数要約、ヒストグラム、回帰分析・平面回帰、主成分分析、k-means)
+//! - `sampling`: サンプリング手法 (適応サンプリング、パターン解析、メッシュ表面の一様サンプリング)
+//! - `interpolation`: 補間・近似 (線形補間、任意次数の2Dベジエ曲線、2D/3D Catmull-Rom スプライン)
+//! - `polygon_self_intersection`: 2Dポリゴンの自己交差検出 (Bentley-Ottmann)
+//! - `polygon_offset`: 2Dポリゴンオフセット (工具径補正、輪郭の肉付け)
+//! - `polygon_triangulation`: 2D多角形の耳刈り三角形分割 (穴あきプロファイルは橋で接続)
+//! - `point_in_polygon`: 2D点の多角形内包含判定 (巻き数、境界判定)
+//! - `extrude`: 2Dプロファイルの押し出しによる閉じた三角形メッシュ生成
+//! - `revolve`: 2Dプロファイルの軸周り回転による回転体メッシュ生成
+//! - `mesh_quality`: 三角形メッシュの品質メトリクス (角度・アスペクト比・退化三角形)
+//! - `mesh_boolean`: 閉じた三角形メッシュ同士のブーリアン演算 (和・差・積)
+//! - `mesh_clip`: 平面による三角形メッシュのクリッピング (切断三角形の再生成・切り口の蓋)
+//! - `mesh_edges`: 三角形メッシュの輪郭線抽出 (シルエット・フィーチャー・境界エッジ)
+//! - `half_edge`: 多様体三角形メッシュの half-edge 構造 (1-ring・隣接面・境界ループ)
+//! - `mesh_orientation`: 三角形メッシュの面の向きの統一 (隣接面への伝播・閉メッシュの外向き化)
+//! - `kd_tree`: 3D点群のKD木 (最近傍・k近傍・半径内探索)
+//! - `point_cloud_distance`: 2点群間の Chamfer / Hausdorff 距離
+//! - `arc_length_sampling`: `ParametricCurve3D` の弧長等間隔サンプリング (開曲線・閉曲線)
 
 // Point2D API互換性問題により一時的にコメントアウト
 // pub mod numerical;
-// pub mod statistics;
-// pub mod sampling;
-// pub mod interpolation;// 主要な型とトレイトの再エクスポート
+
+pub mod arc_length_sampling;
+pub mod extrude;
+pub mod half_edge;
+pub mod interpolation;
+pub mod kd_tree;
+pub mod mesh_boolean;
+pub mod mesh_clip;
+pub mod mesh_edges;
+pub mod mesh_orientation;
+pub mod mesh_quality;
+pub mod point_cloud_distance;
+pub mod point_in_polygon;
+pub mod polygon_offset;
+pub mod polygon_self_intersection;
+pub mod polygon_triangulation;
+pub mod revolve;
+pub mod sampling;
+pub mod statistics;
+
+#[cfg(test)]
+mod test_support;
+
+// 主要な型とトレイトの再エクスポート
 // Point2D API互換性問題により一時的にコメントアウト
 // pub use numerical::{NewtonSolver, ConvergenceInfo};
-// pub use statistics::{BasicStats, PointCluster, RegressionResult};
-// pub use sampling::{SamplingResult, QualityMetrics, IntersectionCandidate};
-// pub use interpolation::{LinearInterpolator, BezierCurve, CatmullRomSpline};
+pub use arc_length_sampling::sample_equal_arc_length;
+pub use extrude::extrude_profile;
+pub use half_edge::{HalfEdge, HalfEdgeError, HalfEdgeMesh};
+pub use interpolation::{
+    BezierCurve, CatmullRomKind, CatmullRomSpline, CatmullRomSpline3D, LinearInterpolator,
+};
+pub use kd_tree::KdTree3D;
+pub use mesh_boolean::{mesh_boolean, BooleanOp};
+pub use mesh_clip::{clip_mesh_by_plane, clip_mesh_by_plane_capped};
+pub use mesh_edges::{
+    detect_feature_edges, extract_feature_edges, extract_outline_edges, extract_silhouette_edges,
+    EdgeSegment, FeatureEdgeOptions, MeshEdges,
+};
+pub use mesh_orientation::{orient_consistently, orient_outward};
+pub use mesh_quality::{mesh_quality, MeshQualityReport};
+pub use point_cloud_distance::{chamfer_distance, hausdorff_distance};
+pub use point_in_polygon::{point_in_polygon, point_in_polygon_with_tolerance, Containment};
+pub use polygon_offset::{offset_polygon, offset_polygon_with_join, OffsetJoin};
+pub use polygon_self_intersection::{self_intersections, SelfIntersection};
+pub use polygon_triangulation::{triangulate_polygon_2d, triangulate_with_holes};
+pub use revolve::revolve_profile;
+pub use sampling::{sample_surface_uniform, IntersectionCandidate, QualityMetrics, SamplingResult};
+pub use statistics::{
+    fit_plane_least_squares, fit_plane_regression, fit_plane_total_least_squares, kmeans_3d,
+    BasicStats, FiveNumberSummary, Histogram, PointCluster, PointCluster3D, RegressionResult,
+};
 
 // geo_foundationからの基本型の再エクスポート
 pub use geo_foundation::Scalar;
@@ -0,0 +1,794 @@
+//! 閉じた三角形メッシュ同士のブーリアン演算（和・差・積）
+//!
+//! 1. 境界ボックスが重なる三角形の組ごとに交差線分を求める。交点は辺と相手の平面の交点を
+//!    辺の頂点番号の小さい側から計算するため、辺を共有する隣接三角形では同じ座標になる。
+//!    共面の組では、相手の三角形の辺を自分の三角形で切り取ったものを切断線とする。
+//! 2. 各三角形を切断線による平面グラフの面に分け、面ごとに耳刈りで三角形分割する。
+//! 3. 分割した面を、代表点が相手のメッシュの内側か外側か（一般化巻き数）、相手の面上に
+//!    同じ向き・逆向きで重なるかで分類し、演算に応じて取捨選択する。差では B から残す面を反転する。
+//! 4. 許容誤差内で一致する頂点を併合して1つのメッシュにする。
+//!
+//! 入力は外向きに向きの揃った閉じたメッシュを想定する。三角形の組の探索と内外判定は
+//! 総当たりのため、計算量は両メッシュの三角形数の積に比例する。
+
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::f64::consts::PI;
+
+use geo_primitives::{Point2D, Point3D, TriangleMesh3D, Vector3D};
+
+use crate::point_in_polygon::{point_in_polygon, Containment};
+use crate::polygon_triangulation::{signed_area, triangulate_polygon_2d, triangulate_with_holes};
+
+/// ブーリアン演算の種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BooleanOp {
+    /// 和（A ∪ B）
+    Union,
+    /// 差（A − B）
+    Difference,
+    /// 積（A ∩ B）
+    Intersection,
+}
+
+/// 2つの閉じた三角形メッシュのブーリアン演算
+///
+/// 結果の面は外向きに揃う。共面で重なる面は、和・積では同じ向きなら A の面を1枚だけ残し、
+/// 逆向き（接して隣り合う）なら両方除く。差では A の面のうち B の面と逆向きに重なる部分を残す。
+/// 面積が許容誤差以下の退化三角形は入力・出力とも無視する。
+pub fn mesh_boolean(
+    a: &TriangleMesh3D<f64>,
+    b: &TriangleMesh3D<f64>,
+    op: BooleanOp,
+) -> TriangleMesh3D<f64> {
+    let extent = a
+        .vertices()
+        .iter()
+        .chain(b.vertices())
+        .fold(0.0_f64, |acc, p| {
+            acc.max(p.x().abs()).max(p.y().abs()).max(p.z().abs())
+        });
+    let tolerance = 1e-9 * extent.max(1.0);
+
+    let facets_a = facets(a, tolerance);
+    let facets_b = facets(b, tolerance);
+    let mut cuts_a = vec![Vec::new(); facets_a.len()];
+    let mut cuts_b = vec![Vec::new(); facets_b.len()];
+    for (i, facet_a) in facets_a.iter().enumerate() {
+        let Some(facet_a) = facet_a else {
+            continue;
+        };
+        for (j, facet_b) in facets_b.iter().enumerate() {
+            let Some(facet_b) = facet_b else {
+                continue;
+            };
+            if !facet_a.overlaps(facet_b, tolerance) {
+                continue;
+            }
+            match intersect(facet_a, facet_b, tolerance) {
+                Contact::Segment(p, q) => {
+                    cuts_a[i].push([p, q]);
+                    cuts_b[j].push([p, q]);
+                }
+                // 辺上で接する点は隣接三角形との辺の分割を揃えるために節点として残す
+                Contact::Point(p) => {
+                    cuts_a[i].push([p, p]);
+                    cuts_b[j].push([p, p]);
+                }
+                Contact::Coplanar => {
+                    cuts_a[i].extend(
+                        facet_b
+                            .edges()
+                            .filter_map(|[p, q]| facet_a.clip_segment(p, q, tolerance)),
+                    );
+                    cuts_b[j].extend(
+                        facet_a
+                            .edges()
+                            .filter_map(|[p, q]| facet_b.clip_segment(p, q, tolerance)),
+                    );
+                }
+                Contact::None => {}
+            }
+        }
+    }
+
+    let mut output = MeshBuilder::new(tolerance);
+    for (facet, cuts) in facets_a.iter().zip(&cuts_a) {
+        let Some(facet) = facet else {
+            continue;
+        };
+        for region in facet.split(cuts, tolerance) {
+            let keep = match (op, classify(&region, facet, &facets_b, tolerance)) {
+                (BooleanOp::Union, location) => {
+                    matches!(location, Location::Outside | Location::SameSurface)
+                }
+                (BooleanOp::Intersection, location) => {
+                    matches!(location, Location::Inside | Location::SameSurface)
+                }
+                (BooleanOp::Difference, location) => {
+                    matches!(location, Location::Outside | Location::OppositeSurface)
+                }
+            };
+            if keep {
+                region.into_iter().for_each(|piece| output.push(piece));
+            }
+        }
+    }
+    for (facet, cuts) in facets_b.iter().zip(&cuts_b) {
+        let Some(facet) = facet else {
+            continue;
+        };
+        for region in facet.split(cuts, tolerance) {
+            // 共面で重なる部分は A 側で扱う
+            let location = classify(&region, facet, &facets_a, tolerance);
+            match (op, location) {
+                (BooleanOp::Union, Location::Outside)
+                | (BooleanOp::Intersection, Location::Inside) => {
+                    region.into_iter().for_each(|piece| output.push(piece));
+                }
+                (BooleanOp::Difference, Location::Inside) => {
+                    for [p, q, r] in region {
+                        output.push([p, r, q]);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+    output.into_mesh()
+}
+
+/// 三角形の組の交わり方
+enum Contact {
+    None,
+    /// 交差線分（両端は異なる）
+    Segment(Point3D<f64>, Point3D<f64>),
+    /// 1点でのみ接する
+    Point(Point3D<f64>),
+    /// 同一平面上
+    Coplanar,
+}
+
+/// 分割した三角形の相手メッシュに対する位置
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Location {
+    Inside,
+    Outside,
+    /// 相手の面上で法線が同じ向き
+    SameSurface,
+    /// 相手の面上で法線が逆向き
+    OppositeSurface,
+}
+
+/// 三角形と、その平面・境界ボックス
+struct Facet {
+    /// 頂点インデックス（交点計算の向きを辺ごとに固定するために使う）
+    indices: [usize; 3],
+    points: [Point3D<f64>; 3],
+    /// 単位法線
+    normal: Vector3D<f64>,
+    min: [f64; 3],
+    max: [f64; 3],
+}
+
+/// メッシュの各三角形（退化三角形は `None`）
+fn facets(mesh: &TriangleMesh3D<f64>, tolerance: f64) -> Vec<Option<Facet>> {
+    let vertices = mesh.vertices();
+    mesh.indices()
+        .iter()
+        .map(|&indices| {
+            let points = indices.map(|i| vertices[i]);
+            let [p, q, r] = points;
+            let cross = (q - p).cross(&(r - p));
+            let longest = p
+                .distance_to(&q)
+                .max(q.distance_to(&r))
+                .max(r.distance_to(&p));
+            // 外積の長さは「最長辺 × 高さ」なので、高さが許容誤差以下なら退化
+            if cross.length() <= tolerance * longest {
+                return None;
+            }
+            let coordinates = points.map(|p| [p.x(), p.y(), p.z()]);
+            let mut min = coordinates[0];
+            let mut max = coordinates[0];
+            for c in &coordinates[1..] {
+                for axis in 0..3 {
+                    min[axis] = min[axis].min(c[axis]);
+                    max[axis] = max[axis].max(c[axis]);
+                }
+            }
+            Some(Facet {
+                indices,
+                points,
+                normal: cross.normalize(),
+                min,
+                max,
+            })
+        })
+        .collect()
+}
+
+impl Facet {
+    fn overlaps(&self, other: &Facet, tolerance: f64) -> bool {
+        (0..3).all(|axis| {
+            self.min[axis] <= other.max[axis] + tolerance
+                && other.min[axis] <= self.max[axis] + tolerance
+        })
+    }
+
+    /// 平面からの符号付き距離（許容誤差内は0に丸める）
+    fn signed_distance(&self, point: Point3D<f64>, tolerance: f64) -> f64 {
+        let d = self.normal.dot(&(point - self.points[0]));
+        if d.abs() <= tolerance {
+            0.0
+        } else {
+            d
+        }
+    }
+
+    /// 辺 k の、平面内で三角形の内側を向く単位法線
+    fn inward(&self, k: usize) -> Vector3D<f64> {
+        self.normal
+            .cross(&(self.points[(k + 1) % 3] - self.points[k]))
+            .normalize()
+    }
+
+    /// 点が三角形上（境界を含む）にあるか
+    fn contains_point(&self, point: Point3D<f64>, tolerance: f64) -> bool {
+        self.signed_distance(point, tolerance) == 0.0
+            && (0..3).all(|k| self.inward(k).dot(&(point - self.points[k])) >= -tolerance)
+    }
+
+    fn edges(&self) -> impl Iterator<Item = [Point3D<f64>; 2]> + '_ {
+        (0..3).map(|k| [self.points[k], self.points[(k + 1) % 3]])
+    }
+
+    /// 同じ平面上の線分を三角形で切り取る（Cyrus-Beck）
+    fn clip_segment(
+        &self,
+        start: Point3D<f64>,
+        end: Point3D<f64>,
+        tolerance: f64,
+    ) -> Option<[Point3D<f64>; 2]> {
+        let direction = end - start;
+        let (mut t0, mut t1) = (0.0_f64, 1.0_f64);
+        for k in 0..3 {
+            let inward = self.inward(k);
+            let offset = inward.dot(&(start - self.points[k]));
+            let rate = inward.dot(&direction);
+            // 辺とほぼ平行な線分は、辺の直線からの距離だけで判定する
+            if rate.abs() <= tolerance {
+                if offset < -tolerance {
+                    return None;
+                }
+                continue;
+            }
+            let t = -offset / rate;
+            if rate > 0.0 {
+                t0 = t0.max(t);
+            } else {
+                t1 = t1.min(t);
+            }
+        }
+        ((t1 - t0) * direction.length() > tolerance)
+            .then(|| [start + direction * t0, start + direction * t1])
+    }
+
+    /// 点から見た三角形の符号付き立体角（Van Oosterom–Strackee）
+    fn solid_angle(&self, point: Point3D<f64>) -> f64 {
+        let [a, b, c] = self.points.map(|p| p - point);
+        let (la, lb, lc) = (a.length(), b.length(), c.length());
+        let numerator = a.dot(&b.cross(&c));
+        let denominator = la * lb * lc + a.dot(&b) * lc + a.dot(&c) * lb + b.dot(&c) * la;
+        2.0 * numerator.atan2(denominator)
+    }
+
+    /// 辺と平面の交点（`distances` は各頂点の平面からの符号付き距離）
+    ///
+    /// 平面上の頂点はそのまま交点とする。
+    fn edge_crossings(&self, distances: [f64; 3]) -> Vec<Point3D<f64>> {
+        let mut crossings = Vec::new();
+        for k in 0..3 {
+            // 隣接三角形と同じ結果になるよう、頂点番号の小さい側から補間する
+            let (i, j) = if self.indices[k] < self.indices[(k + 1) % 3] {
+                (k, (k + 1) % 3)
+            } else {
+                ((k + 1) % 3, k)
+            };
+            let (di, dj) = (distances[i], distances[j]);
+            if di == 0.0 {
+                crossings.push(self.points[i]);
+            } else if di * dj < 0.0 {
+                let (p, q) = (self.points[i], self.points[j]);
+                crossings.push(p + (q - p) * (di / (di - dj)));
+            }
+        }
+        crossings
+    }
+
+    /// 切断線で三角形を領域に分割し、領域ごとに元と同じ向きの三角形を返す
+    ///
+    /// 両端が一致する切断線は節点だけを加える（辺上にあればその辺を分割する）。
+    fn split(&self, cuts: &[[Point3D<f64>; 2]], tolerance: f64) -> Vec<Vec<[Point3D<f64>; 3]>> {
+        if cuts.is_empty() {
+            return vec![vec![self.points]];
+        }
+        let mut graph = FacetGraph::new(self, tolerance);
+        for &[p, q] in cuts {
+            graph.add_cut(p, q);
+        }
+        graph.triangulate()
+    }
+}
+
+/// 2つの三角形の交わりを求める
+fn intersect(a: &Facet, b: &Facet, tolerance: f64) -> Contact {
+    let distances_a = a.points.map(|p| b.signed_distance(p, tolerance));
+    if distances_a.iter().all(|&d| d == 0.0) {
+        return Contact::Coplanar;
+    }
+    if distances_a.iter().all(|&d| d > 0.0) || distances_a.iter().all(|&d| d < 0.0) {
+        return Contact::None;
+    }
+    let distances_b = b.points.map(|p| a.signed_distance(p, tolerance));
+    if distances_b.iter().all(|&d| d == 0.0) {
+        return Contact::Coplanar;
+    }
+    if distances_b.iter().all(|&d| d > 0.0) || distances_b.iter().all(|&d| d < 0.0) {
+        return Contact::None;
+    }
+
+    let points: Vec<Point3D<f64>> = a
+        .edge_crossings(distances_a)
+        .into_iter()
+        .filter(|&p| b.contains_point(p, tolerance))
+        .chain(
+            b.edge_crossings(distances_b)
+                .into_iter()
+                .filter(|&p| a.contains_point(p, tolerance)),
+        )
+        .collect();
+
+    // 交差線分は2つの平面の交線上にあるので、最も離れた2点が両端
+    let mut farthest = None;
+    let mut longest = tolerance;
+    for (i, &p) in points.iter().enumerate() {
+        for &q in &points[i + 1..] {
+            let length = p.distance_to(&q);
+            if length > longest {
+                longest = length;
+                farthest = Some((p, q));
+            }
+        }
+    }
+    match (farthest, points.first()) {
+        (Some((p, q)), _) => Contact::Segment(p, q),
+        (None, Some(&p)) => Contact::Point(p),
+        (None, None) => Contact::None,
+    }
+}
+
+/// 分割した領域の、相手メッシュに対する位置
+///
+/// 領域は切断線で区切られているので全体が同じ側にある。切断線に沿った細い三角形で
+/// 判定がぶれないよう、面積が最大の三角形の重心で調べる。
+fn classify(
+    region: &[[Point3D<f64>; 3]],
+    facet: &Facet,
+    others: &[Option<Facet>],
+    tolerance: f64,
+) -> Location {
+    let area = |&[p, q, r]: &[Point3D<f64>; 3]| (q - p).cross(&(r - p)).length();
+    let Some(&[p, q, r]) = region.iter().max_by(|x, y| area(x).total_cmp(&area(y))) else {
+        return Location::Outside;
+    };
+    let centroid = p + ((q - p) + (r - p)) * (1.0 / 3.0);
+    let mut solid_angle = 0.0;
+    for other in others.iter().flatten() {
+        if other.contains_point(centroid, tolerance) {
+            return if other.normal.dot(&facet.normal) > 0.0 {
+                Location::SameSurface
+            } else {
+                Location::OppositeSurface
+            };
+        }
+        solid_angle += other.solid_angle(centroid);
+    }
+    // 巻き数は内側で1、外側で0
+    if solid_angle / (4.0 * PI) > 0.5 {
+        Location::Inside
+    } else {
+        Location::Outside
+    }
+}
+
+/// 三角形内の切断線がなす平面グラフ
+///
+/// 節点 0〜2 は三角形の頂点。面内の2D座標は三角形の向きが反時計回りになるように取る。
+struct FacetGraph<'a> {
+    facet: &'a Facet,
+    tolerance: f64,
+    nodes: Vec<Point3D<f64>>,
+    plane: Vec<Point2D<f64>>,
+    cuts: Vec<(usize, usize)>,
+    /// 面内の座標軸
+    axes: (Vector3D<f64>, Vector3D<f64>),
+}
+
+impl<'a> FacetGraph<'a> {
+    fn new(facet: &'a Facet, tolerance: f64) -> Self {
+        let u = (facet.points[1] - facet.points[0]).normalize();
+        let v = facet.normal.cross(&u);
+        let mut graph = Self {
+            facet,
+            tolerance,
+            nodes: Vec::new(),
+            plane: Vec::new(),
+            cuts: Vec::new(),
+            axes: (u, v),
+        };
+        for point in facet.points {
+            graph.push_node(point);
+        }
+        graph
+    }
+
+    fn push_node(&mut self, point: Point3D<f64>) -> usize {
+        let offset = point - self.facet.points[0];
+        self.plane.push(Point2D::new(
+            offset.dot(&self.axes.0),
+            offset.dot(&self.axes.1),
+        ));
+        self.nodes.push(point);
+        self.nodes.len() - 1
+    }
+
+    /// 許容誤差内の既存の節点、なければ追加した節点
+    fn node(&mut self, point: Point3D<f64>) -> usize {
+        match self
+            .nodes
+            .iter()
+            .position(|node| node.distance_to(&point) <= self.tolerance)
+        {
+            Some(index) => index,
+            None => self.push_node(point),
+        }
+    }
+
+    fn add_cut(&mut self, p: Point3D<f64>, q: Point3D<f64>) {
+        let (a, b) = (self.node(p), self.node(q));
+        if a != b {
+            self.cuts.push((a, b));
+        }
+    }
+
+    /// 切断線どうしの交点を節点として加える
+    fn add_crossings(&mut self) {
+        let mut crossings = Vec::new();
+        for (i, &(a, b)) in self.cuts.iter().enumerate() {
+            for &(c, d) in &self.cuts[i + 1..] {
+                if a == c || a == d || b == c || b == d {
+                    continue;
+                }
+                let (pa, pb, pc, pd) = (self.plane[a], self.plane[b], self.plane[c], self.plane[d]);
+                let r = (pb.x() - pa.x(), pb.y() - pa.y());
+                let s = (pd.x() - pc.x(), pd.y() - pc.y());
+                let denominator = r.0 * s.1 - r.1 * s.0;
+                if denominator.abs() <= f64::EPSILON {
+                    continue; // 平行（重なりは辺の分割で扱う）
+                }
+                let w = (pc.x() - pa.x(), pc.y() - pa.y());
+                let t = (w.0 * s.1 - w.1 * s.0) / denominator;
+                let u = (w.0 * r.1 - w.1 * r.0) / denominator;
+                if (0.0..=1.0).contains(&t) && (0.0..=1.0).contains(&u) {
+                    let (p, q) = (self.nodes[a], self.nodes[b]);
+                    crossings.push(p + (q - p) * t);
+                }
+            }
+        }
+        for point in crossings {
+            self.node(point);
+        }
+    }
+
+    /// 三角形の辺と切断線を、途中にある節点で区切った無向辺の集合
+    fn edges(&self) -> BTreeSet<(usize, usize)> {
+        let mut edges = BTreeSet::new();
+        let boundary = [(0, 1), (1, 2), (2, 0)];
+        for &(a, b) in boundary.iter().chain(&self.cuts) {
+            let (pa, pb) = (self.plane[a], self.plane[b]);
+            let direction = (pb.x() - pa.x(), pb.y() - pa.y());
+            let length_squared = direction.0 * direction.0 + direction.1 * direction.1;
+            let mut on_segment: Vec<(f64, usize)> = self
+                .plane
+                .iter()
+                .enumerate()
+                .filter(|&(n, _)| n != a && n != b)
+                .filter_map(|(n, p)| {
+                    let offset = (p.x() - pa.x(), p.y() - pa.y());
+                    let t = (offset.0 * direction.0 + offset.1 * direction.1) / length_squared;
+                    let distance = (offset.0 * direction.1 - offset.1 * direction.0).abs()
+                        / length_squared.sqrt();
+                    (t > 0.0 && t < 1.0 && distance <= self.tolerance).then_some((t, n))
+                })
+                .collect();
+            on_segment.sort_by(|x, y| x.0.total_cmp(&y.0));
+
+            let mut previous = a;
+            for n in on_segment.into_iter().map(|(_, n)| n).chain([b]) {
+                if previous != n {
+                    edges.insert((previous.min(n), previous.max(n)));
+                }
+                previous = n;
+            }
+        }
+        edges
+    }
+
+    fn triangulate(mut self) -> Vec<Vec<[Point3D<f64>; 3]>> {
+        self.add_crossings();
+        let mut neighbors: Vec<Vec<usize>> = vec![Vec::new(); self.nodes.len()];
+        for (a, b) in self.edges() {
+            neighbors[a].push(b);
+            neighbors[b].push(a);
+        }
+
+        // 三角形の内部で途切れた切断線を取り除く
+        let mut dangling: Vec<usize> = (0..neighbors.len())
+            .filter(|&n| neighbors[n].len() == 1)
+            .collect();
+        while let Some(n) = dangling.pop() {
+            let Some(other) = neighbors[n].pop() else {
+                continue;
+            };
+            neighbors[other].retain(|&m| m != n);
+            if neighbors[other].len() == 1 {
+                dangling.push(other);
+            }
+        }
+
+        // 各節点の隣接節点を反時計回りに並べる
+        for (n, around) in neighbors.iter_mut().enumerate() {
+            let origin = self.plane[n];
+            let angle = |m: &usize| {
+                let p = self.plane[*m];
+                (p.y() - origin.y()).atan2(p.x() - origin.x())
+            };
+            around.sort_by(|x, y| angle(x).total_cmp(&angle(y)));
+        }
+
+        // 有向辺の左側を辿って面を列挙する（内側の面は反時計回り、外周と穴は時計回り）
+        let mut visited = HashSet::new();
+        let mut faces = Vec::new();
+        let mut holes = Vec::new();
+        for start in 0..neighbors.len() {
+            for &first in &neighbors[start] {
+                if visited.contains(&(start, first)) {
+                    continue;
+                }
+                let mut cycle = Vec::new();
+                let (mut from, mut to) = (start, first);
+                loop {
+                    visited.insert((from, to));
+                    cycle.push(from);
+                    let around = &neighbors[to];
+                    let back = around.iter().position(|&m| m == from).unwrap_or(0);
+                    let next = around[(back + around.len() - 1) % around.len()];
+                    (from, to) = (to, next);
+                    if (from, to) == (start, first) || visited.contains(&(from, to)) {
+                        break;
+                    }
+                }
+                let area = signed_area(&self.polygon(&cycle));
+                if area > 0.0 {
+                    faces.push((cycle, area));
+                } else if area < 0.0 && !cycle.iter().any(|&n| n < 3) {
+                    holes.push(cycle);
+                }
+            }
+        }
+
+        // 穴は、それを内側に含む面のうち最小のものに属する
+        let mut face_holes: Vec<Vec<Vec<usize>>> = vec![Vec::new(); faces.len()];
+        for hole in holes {
+            let probe = self.plane[hole[0]];
+            let owner = faces
+                .iter()
+                .enumerate()
+                .filter(|(_, (face, _))| {
+                    point_in_polygon(probe, &self.polygon(face)) == Containment::Inside
+                })
+                .min_by(|x, y| x.1 .1.total_cmp(&y.1 .1))
+                .map(|(i, _)| i);
+            if let Some(owner) = owner {
+                face_holes[owner].push(hole);
+            }
+        }
+
+        let mut regions = Vec::new();
+        for ((face, _), holes) in faces.iter().zip(&face_holes) {
+            let outer = self.polygon(face);
+            let result = if holes.is_empty() {
+                triangulate_polygon_2d(&outer)
+            } else {
+                let hole_polygons: Vec<Vec<Point2D<f64>>> =
+                    holes.iter().map(|hole| self.polygon(hole)).collect();
+                triangulate_with_holes(&outer, &hole_polygons)
+            };
+            let nodes: Vec<usize> = face.iter().chain(holes.iter().flatten()).copied().collect();
+            regions.push(match result {
+                Ok(indices) => indices
+                    .into_iter()
+                    .map(|triangle| triangle.map(|k| self.nodes[nodes[k]]))
+                    .collect(),
+                // 分割に失敗した面（許容誤差内の自己接触など）は外形の扇形で埋める
+                Err(_) => face[1..]
+                    .windows(2)
+                    .map(|pair| [face[0], pair[0], pair[1]].map(|n| self.nodes[n]))
+                    .collect(),
+            });
+        }
+        regions
+    }
+
+    fn polygon(&self, cycle: &[usize]) -> Vec<Point2D<f64>> {
+        cycle.iter().map(|&n| self.plane[n]).collect()
+    }
+}
+
+/// 出力メッシュの組み立て（許容誤差内の頂点を格子で併合）
+struct MeshBuilder {
+    tolerance: f64,
+    vertices: Vec<Point3D<f64>>,
+    indices: Vec<[usize; 3]>,
+    cells: HashMap<[i64; 3], Vec<usize>>,
+}
+
+impl MeshBuilder {
+    fn new(tolerance: f64) -> Self {
+        Self {
+            tolerance,
+            vertices: Vec::new(),
+            indices: Vec::new(),
+            cells: HashMap::new(),
+        }
+    }
+
+    fn vertex(&mut self, point: Point3D<f64>) -> usize {
+        let cell = [point.x(), point.y(), point.z()].map(|c| (c / self.tolerance).floor() as i64);
+        for dx in -1..=1 {
+            for dy in -1..=1 {
+                for dz in -1..=1 {
+                    let neighbor = [cell[0] + dx, cell[1] + dy, cell[2] + dz];
+                    if let Some(&index) = self.cells.get(&neighbor).and_then(|candidates| {
+                        candidates.iter().find(|&&index| {
+                            self.vertices[index].distance_to(&point) <= self.tolerance
+                        })
+                    }) {
+                        return index;
+                    }
+                }
+            }
+        }
+        self.vertices.push(point);
+        let index = self.vertices.len() - 1;
+        self.cells.entry(cell).or_default().push(index);
+        index
+    }
+
+    fn push(&mut self, triangle: [Point3D<f64>; 3]) {
+        let [a, b, c] = triangle.map(|p| self.vertex(p));
+        if a != b && b != c && c != a {
+            self.indices.push([a, b, c]);
+        }
+    }
+
+    fn into_mesh(self) -> TriangleMesh3D<f64> {
+        TriangleMesh3D::new(self.vertices, self.indices).expect("indices refer to pushed vertices")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::half_edge::HalfEdgeMesh;
+    use crate::test_support::{cuboid, signed_volume};
+
+    /// 穴のない閉じた多様体で、体積が期待値に一致する
+    fn assert_solid(mesh: &TriangleMesh3D<f64>, volume: f64) {
+        let half_edges = HalfEdgeMesh::from_triangle_mesh(mesh).unwrap();
+        assert!(half_edges.boundary_loops().is_empty());
+        assert!(
+            (signed_volume(mesh) - volume).abs() < 1e-9,
+            "volume {} != {}",
+            signed_volume(mesh),
+            volume
+        );
+    }
+
+    #[test]
+    fn test_overlapping_cubes() {
+        let a = cuboid([0.0, 0.0, 0.0], [2.0, 2.0, 2.0]);
+        let b = cuboid([1.0, 1.0, 1.0], [3.0, 3.0, 3.0]);
+
+        assert_solid(&mesh_boolean(&a, &b, BooleanOp::Union), 15.0);
+        assert_solid(&mesh_boolean(&a, &b, BooleanOp::Intersection), 1.0);
+        assert_solid(&mesh_boolean(&a, &b, BooleanOp::Difference), 7.0);
+        assert_solid(&mesh_boolean(&b, &a, BooleanOp::Difference), 7.0);
+    }
+
+    #[test]
+    fn test_coplanar_faces() {
+        // y・z 方向の面が同一平面上で重なる
+        let a = cuboid([0.0, 0.0, 0.0], [2.0, 2.0, 2.0]);
+        let b = cuboid([1.0, 0.0, 0.0], [3.0, 2.0, 2.0]);
+
+        assert_solid(&mesh_boolean(&a, &b, BooleanOp::Union), 12.0);
+        assert_solid(&mesh_boolean(&a, &b, BooleanOp::Intersection), 4.0);
+        assert_solid(&mesh_boolean(&a, &b, BooleanOp::Difference), 4.0);
+
+        // 面で接する2つの立方体の和は1つの直方体
+        let c = cuboid([2.0, 0.0, 0.0], [4.0, 2.0, 2.0]);
+        assert_solid(&mesh_boolean(&a, &c, BooleanOp::Union), 16.0);
+        assert_solid(&mesh_boolean(&a, &c, BooleanOp::Difference), 8.0);
+    }
+
+    #[test]
+    fn test_pocket_inside_single_triangle() {
+        // 上面の1枚の三角形の内側だけに交線のループができる
+        let a = cuboid([0.0, 0.0, 0.0], [4.0, 4.0, 4.0]);
+        let b = cuboid([0.5, 0.5, 3.0], [1.5, 1.5, 5.0]);
+
+        assert_solid(&mesh_boolean(&a, &b, BooleanOp::Difference), 63.0);
+        assert_solid(&mesh_boolean(&a, &b, BooleanOp::Union), 65.0);
+        assert_solid(&mesh_boolean(&a, &b, BooleanOp::Intersection), 1.0);
+    }
+
+    #[test]
+    fn test_disjoint_and_contained() {
+        let a = cuboid([0.0, 0.0, 0.0], [1.0, 1.0, 1.0]);
+        let far = cuboid([5.0, 5.0, 5.0], [6.0, 6.0, 6.0]);
+        let union = mesh_boolean(&a, &far, BooleanOp::Union);
+        assert_eq!(union.triangle_count(), 24);
+        assert!((signed_volume(&union) - 2.0).abs() < 1e-12);
+        assert!(mesh_boolean(&a, &far, BooleanOp::Intersection).is_empty());
+        assert_solid(&mesh_boolean(&a, &far, BooleanOp::Difference), 1.0);
+
+        // 内部に完全に含まれる立方体を引くと空洞ができる
+        let outer = cuboid([0.0, 0.0, 0.0], [3.0, 3.0, 3.0]);
+        let inner = cuboid([1.0, 1.0, 1.0], [2.0, 2.0, 2.0]);
+        let hollow = mesh_boolean(&outer, &inner, BooleanOp::Difference);
+        assert_eq!(hollow.triangle_count(), 24);
+        assert!((signed_volume(&hollow) - 26.0).abs() < 1e-12);
+        assert_solid(&mesh_boolean(&outer, &inner, BooleanOp::Intersection), 1.0);
+    }
+
+    #[test]
+    fn test_rotated_cube() {
+        // 斜めに交わる立方体（交線が辺や頂点の近くを通り、細い分割片ができる）
+        let a = cuboid([0.0, 0.0, 0.0], [2.0, 2.0, 2.0]);
+        let unit = cuboid([-0.8, -0.8, -0.8], [0.8, 0.8, 0.8]);
+        let axis = Vector3D::new(1.0, 3.0, 0.5).normalize();
+        let (sin, cos) = 0.7_f64.sin_cos();
+        let vertices = unit
+            .vertices()
+            .iter()
+            .map(|p| {
+                // ロドリゲスの回転公式
+                let v = Vector3D::new(p.x(), p.y(), p.z());
+                let r = v * cos + axis.cross(&v) * sin + axis * (axis.dot(&v) * (1.0 - cos));
+                Point3D::new(r.x() + 1.7, r.y() + 1.4, r.z() + 1.9)
+            })
+            .collect();
+        let b = TriangleMesh3D::new(vertices, unit.indices().to_vec()).unwrap();
+
+        let intersection = mesh_boolean(&a, &b, BooleanOp::Intersection);
+        let shared = signed_volume(&intersection);
+        assert!(shared > 0.5 && shared < 1.6_f64.powi(3));
+        assert_solid(&intersection, shared);
+        assert_solid(
+            &mesh_boolean(&a, &b, BooleanOp::Union),
+            8.0 + 1.6_f64.powi(3) - shared,
+        );
+        assert_solid(&mesh_boolean(&a, &b, BooleanOp::Difference), 8.0 - shared);
+    }
+}
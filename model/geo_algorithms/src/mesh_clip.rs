@@ -0,0 +1,382 @@
+//! 平面による三角形メッシュのクリッピング
+//!
+//! 平面の法線側（符号付き距離が0以上）を残し、平面をまたぐ三角形は切断して
+//! 部分三角形を作り直す。GPU の断面表示（`MeshStage::set_clip_plane`）と同じ側を残す。
+//! 辺上の切断点は辺ごとに1つだけ作り、隣接する三角形で共有する。
+//! 蓋を張る場合は、切り口に残った開いた境界辺をループに繋ぎ、平面上で三角形分割する。
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use geo_primitives::{Plane3D, Point2D, Point3D, TriangleMesh3D};
+
+use crate::point_in_polygon::{point_in_polygon, Containment};
+use crate::polygon_triangulation::{signed_area, triangulate_with_holes};
+
+/// メッシュを平面で切断し、法線側の部分を返す
+///
+/// 平面上の頂点は残す側に含める。平面をまたぐ三角形は三角形または四角形（三角形2枚）に
+/// 切り詰める。反対側を残したい場合は法線を反転した平面を渡す。切り口は開いたまま。
+pub fn clip_mesh_by_plane(mesh: &TriangleMesh3D<f64>, plane: &Plane3D<f64>) -> TriangleMesh3D<f64> {
+    let clipper = Clipper::clip(mesh, plane);
+    clipper.into_mesh()
+}
+
+/// [`clip_mesh_by_plane`] に加えて、切り口に蓋を張る
+///
+/// 蓋の法線は平面の法線と逆向き（残した側から見て外向き）。外向きに向きの揃った閉じた
+/// メッシュで、切断位置の頂点が共有されていれば結果も閉じたメッシュになる。
+/// 切り口が複数の領域や穴を持つ場合も、ループの向きから外形と穴を判別して分割する。
+/// ループに繋がらない境界や、三角形分割できないループには蓋を張らない。
+pub fn clip_mesh_by_plane_capped(
+    mesh: &TriangleMesh3D<f64>,
+    plane: &Plane3D<f64>,
+) -> TriangleMesh3D<f64> {
+    let mut clipper = Clipper::clip(mesh, plane);
+    clipper.add_caps(plane);
+    clipper.into_mesh()
+}
+
+/// クリッピングの途中状態
+struct Clipper<'a> {
+    mesh: &'a TriangleMesh3D<f64>,
+    /// 入力頂点の、法線と逆向きに測った符号付き距離（残す側が0以下、許容誤差内は0に丸める）
+    distances: Vec<f64>,
+    tolerance: f64,
+    vertices: Vec<Point3D<f64>>,
+    indices: Vec<[usize; 3]>,
+    /// 入力頂点 → 出力頂点
+    kept: Vec<Option<usize>>,
+    /// 切断した辺（頂点番号の小さい順）→ 出力頂点
+    edge_points: HashMap<(usize, usize), usize>,
+    /// 平面上にある出力頂点
+    on_plane: Vec<bool>,
+    /// 平面上の辺の向き付き出現数（(a, b) で a < b なら a→b を +1、b→a を -1）
+    plane_edges: BTreeMap<(usize, usize), isize>,
+}
+
+impl<'a> Clipper<'a> {
+    fn clip(mesh: &'a TriangleMesh3D<f64>, plane: &Plane3D<f64>) -> Self {
+        let extent = mesh.vertices().iter().fold(0.0_f64, |acc, p| {
+            acc.max(p.x().abs()).max(p.y().abs()).max(p.z().abs())
+        });
+        let tolerance = 1e-9 * extent.max(1.0);
+        // 残す法線側が負になるよう、法線と逆向きに測る
+        let normal = -plane.normal().as_vector();
+        let distances = mesh
+            .vertices()
+            .iter()
+            .map(|&p| {
+                let d = (p - plane.point()).dot(&normal);
+                if d.abs() <= tolerance {
+                    0.0
+                } else {
+                    d
+                }
+            })
+            .collect();
+
+        let mut clipper = Self {
+            mesh,
+            distances,
+            tolerance,
+            vertices: Vec::new(),
+            indices: Vec::new(),
+            kept: vec![None; mesh.vertex_count()],
+            edge_points: HashMap::new(),
+            on_plane: Vec::new(),
+            plane_edges: BTreeMap::new(),
+        };
+        for &triangle in mesh.indices() {
+            clipper.clip_triangle(triangle);
+        }
+        clipper
+    }
+
+    /// 三角形を切り詰め、残った多角形を扇形に分割して追加
+    fn clip_triangle(&mut self, triangle: [usize; 3]) {
+        let mut polygon = Vec::with_capacity(4);
+        for k in 0..3 {
+            let (i, j) = (triangle[k], triangle[(k + 1) % 3]);
+            let (di, dj) = (self.distances[i], self.distances[j]);
+            if di <= 0.0 {
+                polygon.push(self.kept_vertex(i));
+            }
+            if di * dj < 0.0 {
+                polygon.push(self.edge_vertex(i, j));
+            }
+        }
+        if polygon.len() < 3 {
+            return;
+        }
+
+        for k in 0..polygon.len() {
+            let (a, b) = (polygon[k], polygon[(k + 1) % polygon.len()]);
+            if self.on_plane[a] && self.on_plane[b] {
+                let (key, sign) = if a < b { ((a, b), 1) } else { ((b, a), -1) };
+                *self.plane_edges.entry(key).or_insert(0) += sign;
+            }
+        }
+        for k in 1..polygon.len() - 1 {
+            self.indices.push([polygon[0], polygon[k], polygon[k + 1]]);
+        }
+    }
+
+    fn kept_vertex(&mut self, index: usize) -> usize {
+        if let Some(id) = self.kept[index] {
+            return id;
+        }
+        let id = self.push_vertex(self.mesh.vertices()[index], self.distances[index] == 0.0);
+        self.kept[index] = Some(id);
+        id
+    }
+
+    /// 辺 ij と平面の交点（両端の頂点番号の順に依らず同じ点になる）
+    fn edge_vertex(&mut self, i: usize, j: usize) -> usize {
+        let key = (i.min(j), i.max(j));
+        if let Some(&id) = self.edge_points.get(&key) {
+            return id;
+        }
+        let (a, b) = key;
+        let t = self.distances[a] / (self.distances[a] - self.distances[b]);
+        let (pa, pb) = (self.mesh.vertices()[a], self.mesh.vertices()[b]);
+        let id = self.push_vertex(pa + (pb - pa) * t, true);
+        self.edge_points.insert(key, id);
+        id
+    }
+
+    fn push_vertex(&mut self, point: Point3D<f64>, on_plane: bool) -> usize {
+        self.vertices.push(point);
+        self.on_plane.push(on_plane);
+        self.vertices.len() - 1
+    }
+
+    /// 切り口の開いた境界をループに繋いで蓋を張る
+    fn add_caps(&mut self, plane: &Plane3D<f64>) {
+        // 逆向きの辺と打ち消し合わずに残った辺が開いた境界。蓋はその逆向きに辺を持つ
+        let mut next = BTreeMap::new();
+        for (&(a, b), &count) in &self.plane_edges {
+            match count {
+                c if c > 0 => next.insert(b, a),
+                c if c < 0 => next.insert(a, b),
+                _ => None,
+            };
+        }
+        let loops = chain_loops(&next);
+
+        // 蓋の外向き（法線と逆向き）から見た座標にするため、v を反転する
+        let uv = |id: usize| {
+            let (u, v) = plane.project_to_uv(self.vertices[id]);
+            Point2D::new(u, -v)
+        };
+        let loops: Vec<(Vec<usize>, Vec<Point2D<f64>>, f64)> = loops
+            .into_iter()
+            .map(|ids| {
+                let points: Vec<_> = ids.iter().map(|&id| uv(id)).collect();
+                let area = signed_area(&points);
+                (ids, points, area)
+            })
+            .collect();
+
+        // 蓋の外側から見て反時計回りのループが外形、時計回りが穴。
+        // 穴はそれを含む最小の外形に割り当てる
+        let mut holes_of: Vec<Vec<usize>> = vec![Vec::new(); loops.len()];
+        for (h, (_, hole, area)) in loops.iter().enumerate() {
+            if *area >= 0.0 {
+                continue;
+            }
+            let owner = loops
+                .iter()
+                .enumerate()
+                .filter(|(_, (_, outer, outer_area))| {
+                    *outer_area > 0.0 && point_in_polygon(hole[0], outer) == Containment::Inside
+                })
+                .min_by(|(_, a), (_, b)| a.2.total_cmp(&b.2));
+            if let Some((o, _)) = owner {
+                holes_of[o].push(h);
+            }
+        }
+
+        for (o, (outer_ids, outer, area)) in loops.iter().enumerate() {
+            if *area <= 0.0 {
+                continue;
+            }
+            let holes: Vec<Vec<Point2D<f64>>> =
+                holes_of[o].iter().map(|&h| loops[h].1.clone()).collect();
+            let Ok(triangles) = triangulate_with_holes(outer, &holes) else {
+                continue;
+            };
+
+            let mut ids = outer_ids.clone();
+            let mut points = outer.clone();
+            for &h in &holes_of[o] {
+                ids.extend_from_slice(&loops[h].0);
+                points.extend_from_slice(&loops[h].1);
+            }
+            let mut split = Vec::with_capacity(triangles.len());
+            for triangle in triangles {
+                split_at_boundary_points(triangle, &points, self.tolerance, &mut split);
+            }
+            self.indices
+                .extend(split.into_iter().map(|triangle| triangle.map(|k| ids[k])));
+        }
+    }
+
+    fn into_mesh(self) -> TriangleMesh3D<f64> {
+        TriangleMesh3D::new(self.vertices, self.indices).unwrap_or_else(|_| TriangleMesh3D::empty())
+    }
+}
+
+/// 「頂点 → 次の頂点」の対応を閉ループに繋ぐ（閉じない経路は捨てる）
+fn chain_loops(next: &BTreeMap<usize, usize>) -> Vec<Vec<usize>> {
+    let mut visited = HashSet::new();
+    let mut loops = Vec::new();
+    for &start in next.keys() {
+        if visited.contains(&start) {
+            continue;
+        }
+        let mut path = vec![start];
+        visited.insert(start);
+        let mut current = start;
+        while let Some(&following) = next.get(&current) {
+            if following == start {
+                if path.len() >= 3 {
+                    loops.push(path);
+                }
+                break;
+            }
+            if !visited.insert(following) {
+                break;
+            }
+            path.push(following);
+            current = following;
+        }
+    }
+    loops
+}
+
+/// 三角形の辺上に乗るループ頂点で三角形を分割する
+///
+/// 耳刈りは一直線上に並ぶ頂点を三角形に含めないことがあるため、そのままでは
+/// 切り口側の三角形との間に T 字の接続が残る。辺の内部にある頂点ごとに、
+/// 対頂点からの扇形に分けて辺を共有させる。対頂点自身が辺の内部にある面積0の三角形は、
+/// 隣の三角形の分割で辺が共有されるので出力しない。
+fn split_at_boundary_points(
+    triangle: [usize; 3],
+    points: &[Point2D<f64>],
+    tolerance: f64,
+    output: &mut Vec<[usize; 3]>,
+) {
+    for k in 0..3 {
+        let (a, b, c) = (triangle[k], triangle[(k + 1) % 3], triangle[(k + 2) % 3]);
+        let (pa, pb) = (points[a], points[b]);
+        let (dx, dy) = (pb.x() - pa.x(), pb.y() - pa.y());
+        let length_squared = dx * dx + dy * dy;
+        let on_edge = (0..points.len()).find(|&p| {
+            let (qx, qy) = (points[p].x() - pa.x(), points[p].y() - pa.y());
+            let t = (qx * dx + qy * dy) / length_squared;
+            let cross = qx * dy - qy * dx;
+            p != a
+                && p != b
+                && points[p] != pa
+                && points[p] != pb
+                && t > 0.0
+                && t < 1.0
+                && cross.abs() <= tolerance * length_squared.sqrt()
+        });
+        if on_edge == Some(c) {
+            return;
+        }
+        if let Some(p) = on_edge {
+            split_at_boundary_points([c, a, p], points, tolerance, output);
+            split_at_boundary_points([c, p, b], points, tolerance, output);
+            return;
+        }
+    }
+    output.push(triangle);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::half_edge::HalfEdgeMesh;
+    use crate::revolve::revolve_profile;
+    use crate::test_support::{closed_euler_characteristic, cuboid, polygon, signed_volume};
+    use geo_primitives::{Angle, InfiniteLine3D, Vector3D};
+
+    #[test]
+    fn test_clip_cube_open() {
+        let clipped = clip_mesh_by_plane(&cuboid([0.0; 3], [1.0; 3]), &Plane3D::xy_plane(0.25));
+
+        // 法線（+Z）側が残る
+        let (min, max) = clipped.bounding_box().unwrap();
+        assert!((min.z() - 0.25).abs() < 1e-12);
+        assert_eq!(max, Point3D::new(1.0, 1.0, 1.0));
+        // 切り口が1本の境界ループとして開いている
+        let half_edges = HalfEdgeMesh::from_triangle_mesh(&clipped).unwrap();
+        assert_eq!(half_edges.boundary_loops().len(), 1);
+    }
+
+    #[test]
+    fn test_clip_cube_capped() {
+        // 斜めの平面の法線側（x + z ≥ 1）を残す
+        let plane = Plane3D::from_point_and_normal(
+            Point3D::new(0.5, 0.0, 0.5),
+            Vector3D::new(1.0, 0.0, 1.0),
+        )
+        .unwrap();
+        let clipped = clip_mesh_by_plane_capped(&cuboid([0.0; 3], [1.0; 3]), &plane);
+
+        assert_eq!(closed_euler_characteristic(&clipped), 2);
+        assert!((signed_volume(&clipped) - 0.5).abs() < 1e-12);
+        for p in clipped.vertices() {
+            assert!(p.x() + p.z() >= 1.0 - 1e-12);
+        }
+
+        // 法線を反転すると反対側（x + z ≤ 1）が残る
+        let flipped = Plane3D::from_point_and_normal(
+            Point3D::new(0.5, 0.0, 0.5),
+            Vector3D::new(-1.0, 0.0, -1.0),
+        )
+        .unwrap();
+        let other = clip_mesh_by_plane_capped(&cuboid([0.0; 3], [1.0; 3]), &flipped);
+        assert_eq!(closed_euler_characteristic(&other), 2);
+        assert!((signed_volume(&other) - 0.5).abs() < 1e-12);
+        for p in other.vertices() {
+            assert!(p.x() + p.z() <= 1.0 + 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_clip_torus_cap_with_hole() {
+        // 正方形断面のトーラス状メッシュを高さの中央で切ると、切り口は穴あきの環になる
+        let axis =
+            InfiniteLine3D::new(Point3D::new(0.0, 0.0, 0.0), Vector3D::new(0.0, 0.0, 1.0)).unwrap();
+        let profile = polygon(&[(2.0, 0.0), (3.0, 0.0), (3.0, 1.0), (2.0, 1.0)]);
+        let torus = revolve_profile(&profile, &axis, Angle::from_degrees(360.0), 16).unwrap();
+
+        // 上半分・下半分のどちらも閉じる（穴の直線部に並ぶ頂点で面積0の耳が出ても蓋は壊れない）
+        let lower_plane = Plane3D::from_point_and_normal(
+            Point3D::new(0.0, 0.0, 0.5),
+            Vector3D::new(0.0, 0.0, -1.0),
+        )
+        .unwrap();
+        for plane in [Plane3D::xy_plane(0.5), lower_plane] {
+            let clipped = clip_mesh_by_plane_capped(&torus, &plane);
+            assert_eq!(closed_euler_characteristic(&clipped), 0);
+            assert!((signed_volume(&clipped) - signed_volume(&torus) / 2.0).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_clip_without_crossing() {
+        let cube = cuboid([0.0; 3], [1.0; 3]);
+        // 全体が残る側：そのまま。底面が平面上にあっても蓋は増えない
+        let kept = clip_mesh_by_plane_capped(&cube, &Plane3D::xy_plane(0.0));
+        assert_eq!(kept.triangle_count(), cube.triangle_count());
+        assert_eq!(kept.vertex_count(), cube.vertex_count());
+
+        // 全体が捨てる側
+        let removed = clip_mesh_by_plane(&cube, &Plane3D::xy_plane(2.0));
+        assert!(removed.is_empty());
+    }
+}
@@ -0,0 +1,332 @@
+//! 三角形メッシュの輪郭線抽出（シルエット・フィーチャーエッジ）
+//!
+//! 頂点インデックスの組で辺を同一視し、各辺に隣接する面の法線を比べて抽出する。
+//! - シルエットエッジ: 隣接2面の一方が視線に対して表、もう一方が裏を向く辺
+//! - フィーチャーエッジ: 隣接2面の二面角（法線のなす角）が閾値を超える辺
+//! - 境界エッジ: 隣接面が1枚だけの辺（3枚以上の非多様体辺はどの抽出にも含めない）
+//!
+//! 法線を持たない退化三角形は判定から除外する。抽出結果は端点の組で返す。
+//! 視点に依存しない隣接情報は `MeshEdges` に前計算でき、視点が変わるたびに
+//! 絞り込みだけをやり直せる。
+
+use std::collections::BTreeMap;
+
+use geo_primitives::{Angle, Point3D, TriangleMesh3D, Vector3D};
+
+/// フィーチャーエッジ抽出の設定
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FeatureEdgeOptions {
+    /// フィーチャーエッジとみなす二面角の閾値（法線のなす角）
+    pub dihedral_threshold: Angle<f64>,
+    /// 境界エッジを含めるか
+    pub include_boundary: bool,
+}
+
+impl Default for FeatureEdgeOptions {
+    /// 二面角 30° 超と境界エッジを抽出
+    fn default() -> Self {
+        Self {
+            dihedral_threshold: Angle::from_degrees(30.0),
+            include_boundary: true,
+        }
+    }
+}
+
+/// 端点の組で表した辺
+pub type EdgeSegment = (Point3D<f64>, Point3D<f64>);
+
+/// 辺（頂点インデックスの昇順の組）と、その辺に隣接する面の法線
+///
+/// 退化三角形も隣接面として数え、法線は `None` とする。
+fn edge_adjacency(
+    mesh: &TriangleMesh3D<f64>,
+) -> BTreeMap<(usize, usize), Vec<Option<Vector3D<f64>>>> {
+    let mut edges: BTreeMap<(usize, usize), Vec<Option<Vector3D<f64>>>> = BTreeMap::new();
+    for (index, &[a, b, c]) in mesh.indices().iter().enumerate() {
+        let normal = mesh.triangle(index).and_then(|triangle| triangle.normal());
+        for (i, j) in [(a, b), (b, c), (c, a)] {
+            if i != j {
+                edges.entry((i.min(j), i.max(j))).or_default().push(normal);
+            }
+        }
+    }
+    edges
+}
+
+/// 隣接面による辺の分類
+#[derive(Debug, Clone, Copy)]
+enum EdgeKind {
+    /// 隣接面が1枚
+    Boundary,
+    /// 法線を持つ2面が隣接（二面角の余弦を前計算）
+    Manifold {
+        normals: [Vector3D<f64>; 2],
+        cos_dihedral: f64,
+    },
+    /// 退化三角形に接する2面の辺、または3面以上が共有する非多様体辺
+    Other,
+}
+
+impl EdgeKind {
+    /// フィーチャーエッジ（二面角が閾値超、または設定により境界）か
+    ///
+    /// `cos_threshold` は二面角の閾値の余弦。非多様体辺と退化三角形に接する辺は含めない。
+    fn is_feature(&self, cos_threshold: f64, include_boundary: bool) -> bool {
+        match self {
+            EdgeKind::Boundary => include_boundary,
+            EdgeKind::Manifold { cos_dihedral, .. } => *cos_dihedral < cos_threshold,
+            EdgeKind::Other => false,
+        }
+    }
+}
+
+/// 輪郭線抽出用に前計算した辺の隣接情報
+///
+/// 辺の端点・隣接面の法線・二面角はメッシュごとに一度だけ計算し、
+/// 視点が変わったときは `outline_edges` などで絞り込みだけをやり直す。
+#[derive(Debug, Clone)]
+pub struct MeshEdges {
+    /// 頂点インデックスの昇順の組・端点・分類（組の昇順）
+    edges: Vec<((usize, usize), EdgeSegment, EdgeKind)>,
+}
+
+impl MeshEdges {
+    /// メッシュの辺の隣接情報を計算
+    pub fn new(mesh: &TriangleMesh3D<f64>) -> Self {
+        let edges = edge_adjacency(mesh)
+            .into_iter()
+            .filter_map(|((i, j), normals)| {
+                let kind = match normals.as_slice() {
+                    [_] => EdgeKind::Boundary,
+                    [Some(n1), Some(n2)] => EdgeKind::Manifold {
+                        normals: [*n1, *n2],
+                        cos_dihedral: n1.dot(n2),
+                    },
+                    _ => EdgeKind::Other,
+                };
+                Some(((i, j), (mesh.vertex(i)?, mesh.vertex(j)?), kind))
+            })
+            .collect();
+        Self { edges }
+    }
+
+    /// 視線方向 `view_dir` に対するシルエットエッジ
+    ///
+    /// 平行投影を想定し、面の表裏は法線と `view_dir` の内積の符号で判定する
+    /// （内積が負なら表）。隣接面が2枚でない辺は対象外。
+    pub fn silhouette_edges(&self, view_dir: &Vector3D<f64>) -> Vec<EdgeSegment> {
+        self.outline_edges(view_dir, None)
+    }
+
+    /// 二面角が閾値を超えるフィーチャーエッジ（と境界エッジ）
+    pub fn feature_edges(&self, options: &FeatureEdgeOptions) -> Vec<EdgeSegment> {
+        let cos_threshold = options.dihedral_threshold.cos();
+        self.select(|kind| kind.is_feature(cos_threshold, options.include_boundary))
+            .map(|(_, segment)| segment)
+            .collect()
+    }
+
+    /// フィーチャーエッジ（と境界エッジ）を頂点インデックスの組 `(小さい方, 大きい方)` で返す
+    ///
+    /// 辺の並びは組の昇順。
+    pub fn feature_edge_indices(&self, options: &FeatureEdgeOptions) -> Vec<(usize, usize)> {
+        let cos_threshold = options.dihedral_threshold.cos();
+        self.select(|kind| kind.is_feature(cos_threshold, options.include_boundary))
+            .map(|(edge, _)| edge)
+            .collect()
+    }
+
+    /// 技術図面風の輪郭線（シルエット ∪ フィーチャーエッジ）を重複なく抽出
+    ///
+    /// `features` が `None` ならシルエットエッジのみ。
+    pub fn outline_edges(
+        &self,
+        view_dir: &Vector3D<f64>,
+        features: Option<&FeatureEdgeOptions>,
+    ) -> Vec<EdgeSegment> {
+        let cos_threshold = features.map(|options| options.dihedral_threshold.cos());
+        let include_boundary = features.is_some_and(|options| options.include_boundary);
+        self.select(|kind| {
+            let silhouette = match kind {
+                EdgeKind::Manifold {
+                    normals: [n1, n2], ..
+                } => (n1.dot(view_dir) < 0.0) != (n2.dot(view_dir) < 0.0),
+                _ => false,
+            };
+            silhouette || cos_threshold.is_some_and(|cos| kind.is_feature(cos, include_boundary))
+        })
+        .map(|(_, segment)| segment)
+        .collect()
+    }
+
+    /// 分類が条件を満たす辺を、頂点インデックスの組と端点で列挙
+    fn select<'a>(
+        &'a self,
+        mut keep: impl FnMut(&EdgeKind) -> bool + 'a,
+    ) -> impl Iterator<Item = ((usize, usize), EdgeSegment)> + 'a {
+        self.edges
+            .iter()
+            .filter(move |(_, _, kind)| keep(kind))
+            .map(|(edge, segment, _)| (*edge, *segment))
+    }
+}
+
+/// 視線方向 `view_dir` に対するシルエットエッジを抽出（`MeshEdges::silhouette_edges` を参照）
+pub fn extract_silhouette_edges(
+    mesh: &TriangleMesh3D<f64>,
+    view_dir: &Vector3D<f64>,
+) -> Vec<EdgeSegment> {
+    MeshEdges::new(mesh).silhouette_edges(view_dir)
+}
+
+/// 二面角が閾値を超えるフィーチャーエッジ（と境界エッジ）を抽出
+pub fn extract_feature_edges(
+    mesh: &TriangleMesh3D<f64>,
+    options: &FeatureEdgeOptions,
+) -> Vec<EdgeSegment> {
+    MeshEdges::new(mesh).feature_edges(options)
+}
+
+/// 二面角が閾値を超えるエッジと境界エッジを頂点インデックスの組で検出
+///
+/// 重複頂点を統合（weld）済みのメッシュを前提とする。組は `(小さい方, 大きい方)` の昇順で、
+/// 辺の並びも昇順。判定は `FeatureEdgeOptions`（閾値 `angle_threshold`、境界を含む）による
+/// `MeshEdges::feature_edge_indices` と同じで、隣接面が3枚以上の非多様体エッジはスキップする。
+/// 退化三角形に接する辺は二面角を決められないため、境界でない限り含めない。
+pub fn detect_feature_edges(
+    mesh: &TriangleMesh3D<f64>,
+    angle_threshold: Angle<f64>,
+) -> Vec<(usize, usize)> {
+    let options = FeatureEdgeOptions {
+        dihedral_threshold: angle_threshold,
+        include_boundary: true,
+    };
+    MeshEdges::new(mesh).feature_edge_indices(&options)
+}
+
+/// 技術図面風の輪郭線（シルエット ∪ フィーチャーエッジ）を重複なく抽出
+///
+/// `features` が `None` ならシルエットエッジのみ。視点を変えて繰り返し抽出する場合は
+/// `MeshEdges` を一度作って `MeshEdges::outline_edges` を呼ぶ。
+pub fn extract_outline_edges(
+    mesh: &TriangleMesh3D<f64>,
+    view_dir: &Vector3D<f64>,
+    features: Option<&FeatureEdgeOptions>,
+) -> Vec<EdgeSegment> {
+    MeshEdges::new(mesh).outline_edges(view_dir, features)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::cuboid;
+
+    #[test]
+    fn test_cube_silhouette_along_axis() {
+        // 面に垂直な視線では、視線と平行な側面は裏とみなされ手前の正方形の縁が輪郭になる
+        let mesh = cuboid([-1.0; 3], [1.0; 3]);
+        let silhouette = extract_silhouette_edges(&mesh, &Vector3D::new(0.0, 0.0, -1.0));
+        assert_eq!(silhouette.len(), 4);
+        assert!(silhouette
+            .iter()
+            .all(|(start, end)| start.z() == 1.0 && end.z() == 1.0));
+
+        // 斜めからの視線では六角形の輪郭になる
+        let silhouette = extract_silhouette_edges(&mesh, &Vector3D::new(-1.0, -1.0, -1.0));
+        assert_eq!(silhouette.len(), 6);
+        for (start, end) in &silhouette {
+            // 輪郭辺は (1,1,1) と (-1,-1,-1) のどちらの頂点にも接しない
+            for corner in [1.0, -1.0] {
+                let p = Point3D::new(corner, corner, corner);
+                assert!(*start != p && *end != p);
+            }
+        }
+    }
+
+    #[test]
+    fn test_cube_feature_edges() {
+        let mesh = cuboid([-1.0; 3], [1.0; 3]);
+        // 立方体の12本の稜は二面角 90°、面の対角線は 0°
+        let features = extract_feature_edges(&mesh, &FeatureEdgeOptions::default());
+        assert_eq!(features.len(), 12);
+        for (start, end) in &features {
+            assert!((start.distance_to(end) - 2.0).abs() < 1e-12);
+        }
+
+        let options = FeatureEdgeOptions {
+            dihedral_threshold: Angle::from_degrees(100.0),
+            include_boundary: true,
+        };
+        assert!(extract_feature_edges(&mesh, &options).is_empty());
+
+        // シルエットと重なる稜は1本として数える
+        let outline = extract_outline_edges(
+            &mesh,
+            &Vector3D::new(-1.0, -1.0, -1.0),
+            Some(&FeatureEdgeOptions::default()),
+        );
+        assert_eq!(outline.len(), 12);
+    }
+
+    #[test]
+    fn test_open_mesh_boundary() {
+        let mesh = TriangleMesh3D::new(
+            vec![
+                Point3D::new(0.0, 0.0, 0.0),
+                Point3D::new(1.0, 0.0, 0.0),
+                Point3D::new(1.0, 1.0, 0.0),
+                Point3D::new(0.0, 1.0, 0.0),
+            ],
+            vec![[0, 1, 2], [0, 2, 3]],
+        )
+        .unwrap();
+
+        let boundary = extract_feature_edges(&mesh, &FeatureEdgeOptions::default());
+        assert_eq!(boundary.len(), 4);
+
+        let options = FeatureEdgeOptions {
+            include_boundary: false,
+            ..FeatureEdgeOptions::default()
+        };
+        assert!(extract_feature_edges(&mesh, &options).is_empty());
+
+        // 境界辺はシルエットとはみなさない
+        let view = Vector3D::new(0.0, 0.0, -1.0);
+        assert!(extract_silhouette_edges(&mesh, &view).is_empty());
+        assert!(extract_outline_edges(&mesh, &view, None).is_empty());
+    }
+
+    #[test]
+    fn test_detect_feature_edges() {
+        let mesh = cuboid([-1.0; 3], [1.0; 3]);
+        let edges = detect_feature_edges(&mesh, Angle::from_degrees(45.0));
+        assert_eq!(edges.len(), 12);
+        // 稜は1ビットだけ異なる頂点の組（面の対角線は含まない）
+        for &(i, j) in &edges {
+            assert!(i < j);
+            assert_eq!((i ^ j).count_ones(), 1);
+        }
+        assert!(detect_feature_edges(&mesh, Angle::from_degrees(100.0)).is_empty());
+
+        // 非多様体エッジ（3面が共有）はスキップし、境界エッジは残す
+        let fan = TriangleMesh3D::new(
+            vec![
+                Point3D::new(0.0, 0.0, 0.0),
+                Point3D::new(1.0, 0.0, 0.0),
+                Point3D::new(0.0, 1.0, 0.0),
+                Point3D::new(0.0, -1.0, 0.0),
+                Point3D::new(0.0, 0.0, 1.0),
+            ],
+            vec![[0, 1, 2], [1, 0, 3], [0, 1, 4]],
+        )
+        .unwrap();
+        let edges = detect_feature_edges(&fan, Angle::from_degrees(45.0));
+        assert!(!edges.contains(&(0, 1)));
+        // 端点版の抽出も同じく非多様体エッジを含めない
+        let shared = (Point3D::new(0.0, 0.0, 0.0), Point3D::new(1.0, 0.0, 0.0));
+        let features = extract_feature_edges(&fan, &FeatureEdgeOptions::default());
+        assert_eq!(features.len(), 6);
+        assert!(!features.contains(&shared));
+        assert_eq!(edges, vec![(0, 2), (0, 3), (0, 4), (1, 2), (1, 3), (1, 4)]);
+    }
+}
@@ -0,0 +1,340 @@
+//! 三角形メッシュの面の向き（巻き順）の統一
+//!
+//! `half_edge` と同じく面 f の k 番目の辺を half-edge `3f + k` とし、無向辺ごとに接する
+//! half-edge の表（向きの揃っていない面も含む）から隣接面を求める。
+//! 連結成分ごとに最初の面の向きを基準として幅優先探索で向きを伝播し、
+//! 共有辺を同じ向きで辿っている隣接面を反転する。3枚以上の面が共有する辺は伝播に使わない。
+//!
+//! 外向きに揃える場合は、閉じた連結成分ごとに符号付き体積（発散定理）の符号を調べ、
+//! 負なら成分全体を反転する。
+//!
+//! 頂点法線を持つメッシュでは、反転した面だけに接する頂点の法線を反転する。
+//! 反転した面と反転しない面の両方に接する頂点の法線は、新しい巻き順の面法線
+//! （面積重み付き）から計算し直す。
+
+use std::collections::VecDeque;
+
+use crate::half_edge::{endpoints, undirected_edges};
+use geo_primitives::{Point3D, TriangleMesh3D, Vector3D};
+
+/// 面の向きを隣接面と揃える
+///
+/// 連結成分ごとに、インデックスの最も小さい面の向きに揃える。反転した面の数を返す。
+/// メビウスの帯のような向き付け不可能な成分では、先に到達した隣接面に合わせる。
+pub fn orient_consistently(mesh: &mut TriangleMesh3D<f64>) -> usize {
+    let orientation = Orientation::propagate(mesh);
+    apply_flips(mesh, &orientation.flipped)
+}
+
+/// 面の向きを揃えたうえで、閉じた連結成分の法線を外向きにする
+///
+/// 境界を持つ（閉じていない）成分は内外が決まらないため [`orient_consistently`] と同じ扱い。
+/// 成分は個別に判定するため、中空の立体の内側の殻も空洞ではなく材料側を向く。
+/// 反転した面の数を返す。
+pub fn orient_outward(mesh: &mut TriangleMesh3D<f64>) -> usize {
+    let mut orientation = Orientation::propagate(mesh);
+    for component in orientation
+        .components
+        .iter()
+        .filter(|component| component.closed)
+    {
+        if orientation.signed_volume(mesh, &component.faces) < 0.0 {
+            for &face in &component.faces {
+                orientation.flipped[face] = !orientation.flipped[face];
+            }
+        }
+    }
+    apply_flips(mesh, &orientation.flipped)
+}
+
+/// 辺で繋がった面の集まり
+struct Component {
+    faces: Vec<usize>,
+    /// 全ての辺がちょうど2枚の面に共有されている
+    closed: bool,
+}
+
+/// 向きの伝播結果
+struct Orientation {
+    /// 面ごとに反転するか
+    flipped: Vec<bool>,
+    components: Vec<Component>,
+}
+
+impl Orientation {
+    fn propagate(mesh: &TriangleMesh3D<f64>) -> Self {
+        let indices = mesh.indices();
+        let edges = undirected_edges(indices);
+
+        let mut flipped = vec![false; indices.len()];
+        let mut visited = vec![false; indices.len()];
+        let mut components = Vec::new();
+        for seed in 0..indices.len() {
+            if visited[seed] {
+                continue;
+            }
+            visited[seed] = true;
+            let mut component = Component {
+                faces: Vec::new(),
+                closed: true,
+            };
+            let mut queue = VecDeque::from([seed]);
+            while let Some(face) = queue.pop_front() {
+                component.faces.push(face);
+                for h in 3 * face..3 * face + 3 {
+                    let (origin, destination) = endpoints(indices, h);
+                    let key = (origin.min(destination), origin.max(destination));
+                    let shared = edges.get(&key).map_or(&[][..], Vec::as_slice);
+                    // 境界辺と3枚以上の面が共有する辺は伝播に使わない
+                    if shared.len() != 2 {
+                        component.closed = false;
+                        continue;
+                    }
+                    for &twin in shared.iter().filter(|&&twin| twin != h) {
+                        let neighbor = twin / 3;
+                        if visited[neighbor] {
+                            continue;
+                        }
+                        visited[neighbor] = true;
+                        // 元の向きで同じ向きに辿っていれば、反転の有無を逆にすると揃う
+                        let same_direction = endpoints(indices, twin) == (origin, destination);
+                        flipped[neighbor] = flipped[face] ^ same_direction;
+                        queue.push_back(neighbor);
+                    }
+                }
+            }
+            components.push(component);
+        }
+
+        Self {
+            flipped,
+            components,
+        }
+    }
+
+    /// 反転を反映した向きでの成分の符号付き体積（外向きなら正）
+    ///
+    /// 閉じた成分では基準点によらないため、桁落ちを避けて成分内の頂点を基準にする。
+    fn signed_volume(&self, mesh: &TriangleMesh3D<f64>, faces: &[usize]) -> f64 {
+        let indices = mesh.indices();
+        let vertices = mesh.vertices();
+        let Some(&first) = faces.first() else {
+            return 0.0;
+        };
+        let reference = vertices[indices[first][0]];
+        let relative = |i: usize| Vector3D::from_points(&reference, &vertices[i]);
+        faces
+            .iter()
+            .map(|&face| {
+                let [a, b, c] = oriented(indices[face], self.flipped[face]);
+                relative(a).dot(&relative(b).cross(&relative(c)))
+            })
+            .sum::<f64>()
+            / 6.0
+    }
+}
+
+/// 反転指定に従った三角形の頂点順
+fn oriented([a, b, c]: [usize; 3], flipped: bool) -> [usize; 3] {
+    if flipped {
+        [a, c, b]
+    } else {
+        [a, b, c]
+    }
+}
+
+/// 反転指定をメッシュへ反映し、反転した面の数を返す
+///
+/// 頂点は変えない。頂点法線は新しい巻き順に合わせる（モジュールのドキュメントを参照）。
+fn apply_flips(mesh: &mut TriangleMesh3D<f64>, flipped: &[bool]) -> usize {
+    let count = flipped.iter().filter(|&&f| f).count();
+    if count == 0 {
+        return 0;
+    }
+    let indices: Vec<[usize; 3]> = mesh
+        .indices()
+        .iter()
+        .zip(flipped)
+        .map(|(&triangle, &f)| oriented(triangle, f))
+        .collect();
+    let vertices: Vec<Point3D<f64>> = mesh.vertices().to_vec();
+    let normals = mesh
+        .normals()
+        .map(|normals| reoriented_normals(&vertices, &indices, flipped, normals));
+    let mut oriented_mesh =
+        TriangleMesh3D::new(vertices, indices).expect("reordering keeps indices valid");
+    if let Some(normals) = normals {
+        oriented_mesh = oriented_mesh
+            .with_normals(normals)
+            .expect("normal count is unchanged");
+    }
+    *mesh = oriented_mesh;
+    count
+}
+
+/// 反転後の巻き順（`indices`）に合わせた頂点法線
+fn reoriented_normals(
+    vertices: &[Point3D<f64>],
+    indices: &[[usize; 3]],
+    flipped: &[bool],
+    normals: &[Vector3D<f64>],
+) -> Vec<Vector3D<f64>> {
+    // 頂点ごとに (反転した面に接するか, 反転しない面に接するか)
+    let mut touched = vec![(false, false); vertices.len()];
+    for (triangle, &f) in indices.iter().zip(flipped) {
+        for &v in triangle {
+            if f {
+                touched[v].0 = true;
+            } else {
+                touched[v].1 = true;
+            }
+        }
+    }
+
+    // 境界の頂点用に、新しい巻き順の面法線（外積の長さが面積の2倍）を足し合わせる
+    let mut face_sums = vec![Vector3D::zero(); vertices.len()];
+    for &[a, b, c] in indices {
+        let normal = Vector3D::from_points(&vertices[a], &vertices[b])
+            .cross(&Vector3D::from_points(&vertices[a], &vertices[c]));
+        for v in [a, b, c] {
+            face_sums[v] = face_sums[v] + normal;
+        }
+    }
+
+    normals
+        .iter()
+        .zip(touched)
+        .zip(face_sums)
+        .map(|((&normal, touched), sum)| match touched {
+            (true, false) => -normal,
+            (true, true) => sum.try_normalize().unwrap_or(normal),
+            _ => normal,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::half_edge::HalfEdgeMesh;
+    use crate::test_support::{cuboid, signed_volume};
+
+    fn flip_faces(mesh: &TriangleMesh3D<f64>, faces: &[usize]) -> TriangleMesh3D<f64> {
+        let indices = mesh
+            .indices()
+            .iter()
+            .enumerate()
+            .map(|(i, &triangle)| oriented(triangle, faces.contains(&i)))
+            .collect();
+        TriangleMesh3D::new(mesh.vertices().to_vec(), indices).unwrap()
+    }
+
+    #[test]
+    fn test_cube_is_outward() {
+        assert!((signed_volume(&cuboid([0.0; 3], [1.0; 3])) - 1.0).abs() < 1e-12);
+        assert!(HalfEdgeMesh::from_triangle_mesh(&cuboid([0.0; 3], [1.0; 3])).is_ok());
+    }
+
+    #[test]
+    fn test_orient_consistently_follows_first_face() {
+        let mut mesh = flip_faces(&cuboid([0.0; 3], [1.0; 3]), &[3, 6, 11]);
+        assert!(HalfEdgeMesh::from_triangle_mesh(&mesh).is_err());
+
+        assert_eq!(orient_consistently(&mut mesh), 3);
+        assert!(HalfEdgeMesh::from_triangle_mesh(&mesh).is_ok());
+        assert_eq!(mesh.indices(), cuboid([0.0; 3], [1.0; 3]).indices());
+
+        // 既に揃っていれば何もしない
+        assert_eq!(orient_consistently(&mut mesh), 0);
+    }
+
+    #[test]
+    fn test_orient_outward_flips_inward_shell() {
+        // 面 0 を含む大半が内向き
+        let inward: Vec<usize> = (0..12).filter(|&f| f != 5 && f != 8).collect();
+        let mut mesh = flip_faces(&cuboid([0.0; 3], [1.0; 3]), &inward);
+
+        // 面 0 の向きに合わせると内向きのまま
+        let mut consistent = mesh.clone();
+        assert_eq!(orient_consistently(&mut consistent), 2);
+        assert!((signed_volume(&consistent) + 1.0).abs() < 1e-12);
+
+        assert_eq!(orient_outward(&mut mesh), 10);
+        assert!((signed_volume(&mesh) - 1.0).abs() < 1e-12);
+        assert_eq!(mesh.indices(), cuboid([0.0; 3], [1.0; 3]).indices());
+    }
+
+    #[test]
+    fn test_flipped_faces_reorient_vertex_normals() {
+        // 大半が内向きの立方体に、内向き（中心向き）の頂点法線を付ける
+        let center = Point3D::new(0.5, 0.5, 0.5);
+        let inward: Vec<usize> = (0..12).filter(|&f| f != 5 && f != 8).collect();
+        let mesh = flip_faces(&cuboid([0.0; 3], [1.0; 3]), &inward);
+        let normals: Vec<Vector3D<f64>> = mesh
+            .vertices()
+            .iter()
+            .map(|v| Vector3D::from_points(v, &center).normalize())
+            .collect();
+        let mut mesh = mesh.with_normals(normals.clone()).unwrap();
+
+        assert_eq!(orient_outward(&mut mesh), 10);
+        let oriented = mesh.normals().unwrap();
+
+        // 反転した面だけに接する頂点 3, 6, 7 は符号を反転
+        for v in [3, 6, 7] {
+            assert_eq!(oriented[v], -normals[v]);
+        }
+        // 全ての頂点法線が、接する各面の新しい法線と同じ側を向く
+        for &[a, b, c] in mesh.indices() {
+            let vertices = mesh.vertices();
+            let face_normal = Vector3D::from_points(&vertices[a], &vertices[b])
+                .cross(&Vector3D::from_points(&vertices[a], &vertices[c]));
+            for v in [a, b, c] {
+                assert!(oriented[v].dot(&face_normal) > 0.0, "vertex {v}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_components_and_open_surfaces() {
+        // 内向きの立方体と、向きの混在した開いた帯（四角形2枚分）
+        let mut vertices = cuboid([0.0; 3], [1.0; 3]).vertices().to_vec();
+        vertices.extend([
+            Point3D::new(3.0, 0.0, 0.0),
+            Point3D::new(4.0, 0.0, 0.0),
+            Point3D::new(3.0, 1.0, 0.0),
+            Point3D::new(4.0, 1.0, 0.0),
+        ]);
+        let mut indices: Vec<[usize; 3]> =
+            flip_faces(&cuboid([0.0; 3], [1.0; 3]), &(0..12).collect::<Vec<_>>())
+                .indices()
+                .to_vec();
+        indices.extend([[8, 9, 10], [9, 10, 11]]);
+        let mut mesh = TriangleMesh3D::new(vertices, indices).unwrap();
+
+        // 立方体は反転、開いた帯は面 12 に合わせて面 13 だけ反転
+        assert_eq!(orient_outward(&mut mesh), 13);
+        assert_eq!(mesh.indices()[..12], *cuboid([0.0; 3], [1.0; 3]).indices());
+        assert_eq!(mesh.indices()[12], [8, 9, 10]);
+        assert_eq!(mesh.indices()[13], [9, 11, 10]);
+        assert!(HalfEdgeMesh::from_triangle_mesh(&mesh).is_ok());
+    }
+
+    #[test]
+    fn test_non_manifold_edge_does_not_propagate() {
+        // 辺 (0, 1) を3枚の面が共有する（面 1 は面 0 と同じ向きに辿る）
+        let vertices = vec![
+            Point3D::new(0.0, 0.0, 0.0),
+            Point3D::new(1.0, 0.0, 0.0),
+            Point3D::new(0.5, 1.0, 0.0),
+            Point3D::new(0.5, 0.0, 1.0),
+            Point3D::new(0.5, -1.0, 0.0),
+        ];
+        let indices = vec![[0, 1, 2], [0, 1, 3], [1, 0, 4]];
+        let mut mesh = TriangleMesh3D::new(vertices, indices.clone()).unwrap();
+
+        assert_eq!(orient_consistently(&mut mesh), 0);
+        assert_eq!(orient_outward(&mut mesh), 0);
+        assert_eq!(mesh.indices(), &indices[..]);
+    }
+}
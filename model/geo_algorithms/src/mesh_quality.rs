@@ -0,0 +1,186 @@
+//! 三角形メッシュの品質メトリクス
+//!
+//! 各三角形の最小角・最大角・アスペクト比・面積を集計し、ヒストグラムと
+//! 退化三角形（面積ゼロ・細長い）の数をまとめた `MeshQualityReport` を返す。
+//! 三角形ごとの指標は `Triangle3D` のメソッドで計算する。
+
+use crate::statistics::Histogram;
+use geo_primitives::TriangleMesh3D;
+
+/// ヒストグラムのビン数
+pub const MESH_QUALITY_HISTOGRAM_BINS: usize = 10;
+
+/// 細長い三角形（sliver）とみなすアスペクト比の閾値
+pub const SLIVER_ASPECT_RATIO: f64 = 10.0;
+
+/// メッシュ品質の集計結果
+///
+/// ヒストグラムと最小・最大値は退化していない（面積ゼロでない）三角形のみを対象とする。
+/// 角度はラジアン。
+#[derive(Debug, Clone, PartialEq)]
+pub struct MeshQualityReport {
+    /// 三角形の総数
+    pub triangle_count: usize,
+    /// 面積ゼロの三角形の数（頂点の重複・一直線上・不正なインデックス）
+    pub zero_area_count: usize,
+    /// アスペクト比が `SLIVER_ASPECT_RATIO` を超える細長い三角形の数
+    pub sliver_count: usize,
+    /// 最小内角の最小値
+    pub min_angle: f64,
+    /// 最大内角の最大値
+    pub max_angle: f64,
+    /// アスペクト比の最大値
+    pub max_aspect_ratio: f64,
+    /// 各三角形の最小内角の分布（[0, π/3]）
+    pub min_angle_histogram: Histogram,
+    /// 各三角形の最大内角の分布（[π/3, π]）
+    pub max_angle_histogram: Histogram,
+    /// アスペクト比の分布（[1, 最大値]）
+    pub aspect_ratio_histogram: Histogram,
+    /// 面積の分布（[最小値, 最大値]）
+    pub area_histogram: Histogram,
+}
+
+impl MeshQualityReport {
+    /// 退化三角形（面積ゼロ＋細長い）の数
+    pub fn degenerate_count(&self) -> usize {
+        self.zero_area_count + self.sliver_count
+    }
+}
+
+/// メッシュ各三角形の品質を評価
+pub fn mesh_quality(mesh: &TriangleMesh3D<f64>) -> MeshQualityReport {
+    let triangles: Vec<_> = (0..mesh.triangle_count())
+        .filter_map(|index| mesh.triangle(index))
+        .collect();
+
+    // (最小角, 最大角, アスペクト比, 面積)
+    let metrics: Vec<(f64, f64, f64, f64)> = triangles
+        .iter()
+        .map(|triangle| {
+            (
+                triangle.min_angle(),
+                triangle.max_angle(),
+                triangle.aspect_ratio(),
+                triangle.area(),
+            )
+        })
+        .collect();
+
+    let min_angle = metrics.iter().map(|m| m.0).fold(f64::INFINITY, f64::min);
+    let max_angle = metrics.iter().map(|m| m.1).fold(0.0, f64::max);
+    let max_aspect_ratio = metrics.iter().map(|m| m.2).fold(1.0, f64::max);
+    let min_area = metrics.iter().map(|m| m.3).fold(f64::INFINITY, f64::min);
+    let max_area = metrics.iter().map(|m| m.3).fold(0.0, f64::max);
+
+    let third = std::f64::consts::FRAC_PI_3;
+    let bins = MESH_QUALITY_HISTOGRAM_BINS;
+    let mut report = MeshQualityReport {
+        triangle_count: mesh.triangle_count(),
+        zero_area_count: mesh.triangle_count() - triangles.len(),
+        sliver_count: 0,
+        min_angle: if metrics.is_empty() { 0.0 } else { min_angle },
+        max_angle,
+        max_aspect_ratio,
+        min_angle_histogram: Histogram::with_range(0.0, third, bins),
+        max_angle_histogram: Histogram::with_range(third, std::f64::consts::PI, bins),
+        aspect_ratio_histogram: Histogram::with_range(1.0, max_aspect_ratio, bins),
+        area_histogram: Histogram::with_range(min_area.min(max_area), max_area, bins),
+    };
+
+    for &(min_angle, max_angle, aspect_ratio, area) in &metrics {
+        report.min_angle_histogram.add(min_angle);
+        report.max_angle_histogram.add(max_angle);
+        report.aspect_ratio_histogram.add(aspect_ratio);
+        report.area_histogram.add(area);
+        if aspect_ratio > SLIVER_ASPECT_RATIO {
+            report.sliver_count += 1;
+        }
+    }
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use geo_primitives::Point3D;
+
+    /// 単位正方形を対角線で2分割したメッシュ
+    fn unit_square() -> TriangleMesh3D<f64> {
+        TriangleMesh3D::new(
+            vec![
+                Point3D::new(0.0, 0.0, 0.0),
+                Point3D::new(1.0, 0.0, 0.0),
+                Point3D::new(1.0, 1.0, 0.0),
+                Point3D::new(0.0, 1.0, 0.0),
+            ],
+            vec![[0, 1, 2], [0, 2, 3]],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_right_isosceles_mesh() {
+        let report = mesh_quality(&unit_square());
+        assert_eq!(report.triangle_count, 2);
+        assert_eq!(report.degenerate_count(), 0);
+        assert!((report.min_angle - std::f64::consts::FRAC_PI_4).abs() < 1e-12);
+        assert!((report.max_angle - std::f64::consts::FRAC_PI_2).abs() < 1e-12);
+
+        for histogram in [
+            &report.min_angle_histogram,
+            &report.max_angle_histogram,
+            &report.aspect_ratio_histogram,
+            &report.area_histogram,
+        ] {
+            assert_eq!(histogram.total(), 2);
+            assert_eq!(histogram.bin_count(), MESH_QUALITY_HISTOGRAM_BINS);
+        }
+        // 同一形状なので全て同じビン（範囲の幅0は先頭ビン）
+        assert_eq!(report.area_histogram.counts()[0], 2);
+        // π/4 は [0, π/3] の 10 分割で 8 番目のビン
+        assert_eq!(report.min_angle_histogram.counts()[7], 2);
+    }
+
+    #[test]
+    fn test_counts_zero_area_and_slivers() {
+        let mesh = TriangleMesh3D::new(
+            vec![
+                Point3D::new(0.0, 0.0, 0.0),
+                Point3D::new(1.0, 0.0, 0.0),
+                Point3D::new(0.5, 0.8, 0.0),
+                Point3D::new(2.0, 0.0, 0.0),
+                Point3D::new(20.0, 0.01, 0.0),
+            ],
+            vec![
+                [0, 1, 2], // 良好
+                [0, 1, 3], // 一直線上
+                [1, 1, 2], // 頂点の重複
+                [0, 3, 4], // 細長い
+            ],
+        )
+        .unwrap();
+
+        let report = mesh_quality(&mesh);
+        assert_eq!(report.triangle_count, 4);
+        assert_eq!(report.zero_area_count, 2);
+        assert_eq!(report.sliver_count, 1);
+        assert_eq!(report.degenerate_count(), 3);
+        assert!(report.max_aspect_ratio > SLIVER_ASPECT_RATIO);
+        assert_eq!(report.aspect_ratio_histogram.total(), 2);
+        // 最大値のアスペクト比は最後のビン
+        assert_eq!(
+            report.aspect_ratio_histogram.counts()[MESH_QUALITY_HISTOGRAM_BINS - 1],
+            1
+        );
+    }
+
+    #[test]
+    fn test_empty_mesh() {
+        let report = mesh_quality(&TriangleMesh3D::empty());
+        assert_eq!(report.triangle_count, 0);
+        assert_eq!(report.degenerate_count(), 0);
+        assert_eq!(report.min_angle, 0.0);
+        assert_eq!(report.area_histogram.total(), 0);
+    }
+}
@@ -0,0 +1,92 @@
+//! 2点群間の距離指標
+//!
+//! 各点から相手の点群の最近傍点までの距離を `KdTree3D` で求めて集計する。
+//! 点数が異なっていてもよく、どちらかが空なら `None` を返す。
+
+use crate::kd_tree::KdTree3D;
+use geo_primitives::Point3D;
+
+/// Chamfer距離
+///
+/// a の各点から b への最近傍距離の平均と、b の各点から a への最近傍距離の平均の和。
+/// 外れ値に引きずられにくく、全体的な形状の近さを表す。
+pub fn chamfer_distance(a: &[Point3D<f64>], b: &[Point3D<f64>]) -> Option<f64> {
+    let (a_to_b, b_to_a) = nearest_distances(a, b)?;
+    Some(mean(&a_to_b) + mean(&b_to_a))
+}
+
+/// 対称Hausdorff距離
+///
+/// 一方の点群の点から他方への最近傍距離のうち最大のもの（両方向の大きい方）。
+/// 最も離れた箇所の誤差を表す。
+pub fn hausdorff_distance(a: &[Point3D<f64>], b: &[Point3D<f64>]) -> Option<f64> {
+    let (a_to_b, b_to_a) = nearest_distances(a, b)?;
+    Some(a_to_b.into_iter().chain(b_to_a).fold(0.0, f64::max))
+}
+
+/// 両方向の最近傍距離（どちらかが空なら `None`）
+fn nearest_distances(a: &[Point3D<f64>], b: &[Point3D<f64>]) -> Option<(Vec<f64>, Vec<f64>)> {
+    if a.is_empty() || b.is_empty() {
+        return None;
+    }
+    let directed = |from: &[Point3D<f64>], to: &[Point3D<f64>]| -> Vec<f64> {
+        let tree = KdTree3D::build(to);
+        from.iter().map(|&point| tree.nearest(point).1).collect()
+    };
+    Some((directed(a, b), directed(b, a)))
+}
+
+fn mean(values: &[f64]) -> f64 {
+    values.iter().sum::<f64>() / values.len() as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid(offset_z: f64) -> Vec<Point3D<f64>> {
+        (0..5)
+            .flat_map(|i| (0..5).map(move |j| Point3D::new(i as f64, j as f64, offset_z)))
+            .collect()
+    }
+
+    #[test]
+    fn test_identical_clouds_have_zero_distance() {
+        let points = grid(0.0);
+        assert_eq!(chamfer_distance(&points, &points), Some(0.0));
+        assert_eq!(hausdorff_distance(&points, &points), Some(0.0));
+    }
+
+    #[test]
+    fn test_translated_cloud() {
+        // z方向に 0.5 ずらした同じ格子：全ての最近傍距離が 0.5
+        let a = grid(0.0);
+        let b = grid(0.5);
+        assert!((chamfer_distance(&a, &b).unwrap() - 1.0).abs() < 1e-12);
+        assert!((hausdorff_distance(&a, &b).unwrap() - 0.5).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_different_sizes_and_outlier() {
+        let a = grid(0.0);
+        let mut b = a.clone();
+        b.push(Point3D::new(2.0, 2.0, 10.0));
+
+        // Hausdorff は外れ値までの距離、Chamfer は平均なので小さい
+        assert!((hausdorff_distance(&a, &b).unwrap() - 10.0).abs() < 1e-12);
+        let chamfer = chamfer_distance(&a, &b).unwrap();
+        assert!((chamfer - 10.0 / 26.0).abs() < 1e-12);
+
+        // 対称
+        assert_eq!(chamfer_distance(&b, &a), Some(chamfer));
+        assert_eq!(hausdorff_distance(&b, &a), hausdorff_distance(&a, &b));
+    }
+
+    #[test]
+    fn test_empty_cloud_returns_none() {
+        let points = grid(0.0);
+        assert_eq!(chamfer_distance(&points, &[]), None);
+        assert_eq!(hausdorff_distance(&[], &points), None);
+        assert_eq!(chamfer_distance(&[], &[]), None);
+    }
+}
@@ -0,0 +1,241 @@
+//! 2D点の多角形内包含判定
+//!
+//! 巻き数（winding number）で判定する。巻き数が0でなければ内側（非ゼロ規則）とし、
+//! 凹多角形・自己交差多角形・向き（CCW/CW）に関わらず一貫した結果を返す。
+//! エッジ上・頂点上の点は巻き数より先に判定し、`OnBoundary` として区別する。
+
+use geo_primitives::Point2D;
+
+/// 点と多角形の位置関係
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Containment {
+    /// 多角形の内側（巻き数が0でない）
+    Inside,
+    /// 多角形の外側（巻き数が0）
+    Outside,
+    /// エッジ上または頂点上
+    OnBoundary,
+}
+
+impl Containment {
+    /// 内側または境界上なら true
+    pub fn is_inside_or_on_boundary(&self) -> bool {
+        !matches!(self, Containment::Outside)
+    }
+}
+
+/// 点が閉2Dポリゴンの内側にあるか判定
+///
+/// 点列は閉じたポリゴンとして扱い、最後の点から最初の点への辺も含む。
+/// 境界判定の許容誤差は座標範囲に応じて自動で決める。
+/// 頂点が3未満の場合は、境界上でなければ `Outside` を返す。
+pub fn point_in_polygon(point: Point2D<f64>, polygon: &[Point2D<f64>]) -> Containment {
+    let extent = polygon
+        .iter()
+        .fold(point.x().abs().max(point.y().abs()), |acc, p| {
+            acc.max(p.x().abs()).max(p.y().abs())
+        });
+    point_in_polygon_with_tolerance(point, polygon, 1e-9 * extent.max(1.0))
+}
+
+/// 境界判定の許容誤差を指定して包含判定
+///
+/// エッジまでの距離が `tolerance` 以下の点を `OnBoundary` とする。
+pub fn point_in_polygon_with_tolerance(
+    point: Point2D<f64>,
+    polygon: &[Point2D<f64>],
+    tolerance: f64,
+) -> Containment {
+    let n = polygon.len();
+    if n == 0 {
+        return Containment::Outside;
+    }
+
+    let (px, py) = (point.x(), point.y());
+    let mut winding = 0i32;
+    for i in 0..n {
+        let a = polygon[i];
+        let b = polygon[(i + 1) % n];
+
+        if distance_to_segment_squared(point, a, b) <= tolerance * tolerance {
+            return Containment::OnBoundary;
+        }
+
+        // 点から +x 方向へのレイを上向きに横切れば +1、下向きなら -1
+        let cross = (b.x() - a.x()) * (py - a.y()) - (px - a.x()) * (b.y() - a.y());
+        if a.y() <= py {
+            if b.y() > py && cross > 0.0 {
+                winding += 1;
+            }
+        } else if b.y() <= py && cross < 0.0 {
+            winding -= 1;
+        }
+    }
+
+    if n >= 3 && winding != 0 {
+        Containment::Inside
+    } else {
+        Containment::Outside
+    }
+}
+
+fn distance_to_segment_squared(point: Point2D<f64>, a: Point2D<f64>, b: Point2D<f64>) -> f64 {
+    let (dx, dy) = (b.x() - a.x(), b.y() - a.y());
+    let (wx, wy) = (point.x() - a.x(), point.y() - a.y());
+    let length_squared = dx * dx + dy * dy;
+    let t = if length_squared > 0.0 {
+        ((wx * dx + wy * dy) / length_squared).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    let (ex, ey) = (wx - t * dx, wy - t * dy);
+    ex * ex + ey * ey
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::polygon;
+
+    fn at(x: f64, y: f64) -> Point2D<f64> {
+        Point2D::new(x, y)
+    }
+
+    #[test]
+    fn test_convex_square() {
+        let square = polygon(&[(0.0, 0.0), (4.0, 0.0), (4.0, 4.0), (0.0, 4.0)]);
+        assert_eq!(point_in_polygon(at(2.0, 2.0), &square), Containment::Inside);
+        assert_eq!(
+            point_in_polygon(at(5.0, 2.0), &square),
+            Containment::Outside
+        );
+        assert_eq!(
+            point_in_polygon(at(-1.0, 4.0), &square),
+            Containment::Outside
+        );
+
+        // 時計回りでも同じ結果
+        let mut clockwise = square.clone();
+        clockwise.reverse();
+        assert_eq!(
+            point_in_polygon(at(2.0, 2.0), &clockwise),
+            Containment::Inside
+        );
+        assert_eq!(
+            point_in_polygon(at(5.0, 2.0), &clockwise),
+            Containment::Outside
+        );
+    }
+
+    #[test]
+    fn test_boundary_edges_and_vertices() {
+        let square = polygon(&[(0.0, 0.0), (4.0, 0.0), (4.0, 4.0), (0.0, 4.0)]);
+        assert_eq!(
+            point_in_polygon(at(2.0, 0.0), &square),
+            Containment::OnBoundary
+        );
+        assert_eq!(
+            point_in_polygon(at(4.0, 3.0), &square),
+            Containment::OnBoundary
+        );
+        assert_eq!(
+            point_in_polygon(at(0.0, 2.0), &square),
+            Containment::OnBoundary
+        );
+        assert_eq!(
+            point_in_polygon(at(4.0, 4.0), &square),
+            Containment::OnBoundary
+        );
+        assert_eq!(
+            point_in_polygon(at(0.0, 0.0), &square),
+            Containment::OnBoundary
+        );
+        assert!(point_in_polygon(at(0.0, 0.0), &square).is_inside_or_on_boundary());
+    }
+
+    #[test]
+    fn test_concave_polygon_with_ray_through_vertices() {
+        // U字型：凹部の点と、レイが頂点を通る点
+        let u_shape = polygon(&[
+            (0.0, 0.0),
+            (6.0, 0.0),
+            (6.0, 6.0),
+            (4.0, 6.0),
+            (4.0, 2.0),
+            (2.0, 2.0),
+            (2.0, 6.0),
+            (0.0, 6.0),
+        ]);
+        assert_eq!(
+            point_in_polygon(at(3.0, 4.0), &u_shape),
+            Containment::Outside
+        );
+        assert_eq!(
+            point_in_polygon(at(1.0, 4.0), &u_shape),
+            Containment::Inside
+        );
+        assert_eq!(
+            point_in_polygon(at(5.0, 4.0), &u_shape),
+            Containment::Inside
+        );
+        // y = 2 のレイは凹部の頂点 (2, 2), (4, 2) を通る
+        assert_eq!(
+            point_in_polygon(at(1.0, 2.0), &u_shape),
+            Containment::Inside
+        );
+        assert_eq!(
+            point_in_polygon(at(-1.0, 2.0), &u_shape),
+            Containment::Outside
+        );
+        assert_eq!(
+            point_in_polygon(at(3.0, 1.0), &u_shape),
+            Containment::Inside
+        );
+        assert_eq!(
+            point_in_polygon(at(3.0, 2.0), &u_shape),
+            Containment::OnBoundary
+        );
+    }
+
+    #[test]
+    fn test_self_intersecting_polygons_use_nonzero_rule() {
+        // 一筆書きの五芒星：中央の五角形は巻き数2で内側
+        let star: Vec<Point2D<f64>> = (0..5)
+            .map(|i| {
+                let angle =
+                    std::f64::consts::FRAC_PI_2 + (i * 2) as f64 * std::f64::consts::TAU / 5.0;
+                at(angle.cos(), angle.sin())
+            })
+            .collect();
+        assert_eq!(point_in_polygon(at(0.0, 0.0), &star), Containment::Inside);
+        assert_eq!(point_in_polygon(at(0.0, 0.8), &star), Containment::Inside);
+        assert_eq!(point_in_polygon(at(2.0, 0.0), &star), Containment::Outside);
+
+        // 蝶ネクタイ：両方の三角形が内側、交点は境界上
+        let bowtie = polygon(&[(0.0, 0.0), (2.0, 2.0), (2.0, 0.0), (0.0, 2.0)]);
+        assert_eq!(point_in_polygon(at(0.2, 1.0), &bowtie), Containment::Inside);
+        assert_eq!(point_in_polygon(at(1.8, 1.0), &bowtie), Containment::Inside);
+        assert_eq!(
+            point_in_polygon(at(1.0, 1.8), &bowtie),
+            Containment::Outside
+        );
+        assert_eq!(
+            point_in_polygon(at(1.0, 1.0), &bowtie),
+            Containment::OnBoundary
+        );
+    }
+
+    #[test]
+    fn test_degenerate_input() {
+        assert_eq!(point_in_polygon(at(0.0, 0.0), &[]), Containment::Outside);
+        let segment = polygon(&[(0.0, 0.0), (2.0, 0.0)]);
+        assert_eq!(
+            point_in_polygon(at(1.0, 0.0), &segment),
+            Containment::OnBoundary
+        );
+        assert_eq!(
+            point_in_polygon(at(1.0, 1.0), &segment),
+            Containment::Outside
+        );
+    }
+}
@@ -0,0 +1,359 @@
+//! 2Dポリゴンオフセット
+//!
+//! 閉ポリゴンの各エッジを法線方向に平行移動し、角を接続して輪郭を作る。
+//! 外側へ開く角は円弧（または面取り）で接続し、内側へ閉じる角は隣接する
+//! オフセット線の交点でトリムする。トリム後に生じた自己交差ループは分割し、
+//! 向きが反転したループや元の輪郭に近すぎるループを取り除く。
+
+use geo_primitives::{LineSegment2D, Point2D};
+
+use crate::polygon_self_intersection::self_intersections;
+
+/// 円弧接続を折れ線化するときの最大角度（10度）
+const MAX_ARC_STEP: f64 = std::f64::consts::PI / 18.0;
+/// 自己交差ループ分割の最大再帰深さ
+const MAX_SPLIT_DEPTH: usize = 64;
+
+/// オフセット時に外側へ開く角の接続方法
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OffsetJoin {
+    /// 元の頂点を中心とする円弧で接続（工具径補正向け）
+    #[default]
+    Round,
+    /// 隣接エッジのオフセット端点を直線で結ぶ面取り
+    Chamfer,
+}
+
+/// 閉2Dポリゴンを指定距離だけオフセット（円弧接続）
+///
+/// `distance` が正なら外側へ、負なら内側へオフセットする。ポリゴンの向き
+/// （CCW/CW）は問わず、結果の各ループは入力と同じ向きで返す。
+/// 内側オフセットで輪郭が分離した場合は複数のループを返し、
+/// 完全に消滅した場合は空を返す。
+pub fn offset_polygon(polygon: &[Point2D<f64>], distance: f64) -> Vec<Vec<Point2D<f64>>> {
+    offset_polygon_with_join(polygon, distance, OffsetJoin::Round)
+}
+
+/// 閉2Dポリゴンを指定距離だけオフセット（角の接続方法を指定）
+pub fn offset_polygon_with_join(
+    polygon: &[Point2D<f64>],
+    distance: f64,
+    join: OffsetJoin,
+) -> Vec<Vec<Point2D<f64>>> {
+    let mut vertices = clean_polygon(polygon);
+    if vertices.len() < 3 {
+        return Vec::new();
+    }
+
+    let area = signed_area(&vertices);
+    if area == 0.0 {
+        return Vec::new();
+    }
+    if distance == 0.0 {
+        return vec![vertices];
+    }
+
+    // 内部ではCCWに揃えて計算し、最後に元の向きへ戻す
+    let clockwise = area < 0.0;
+    if clockwise {
+        vertices.reverse();
+    }
+
+    let raw = raw_offset_loop(&vertices, distance, join);
+    let tolerance = 1e-9 * polygon_extent(&vertices).max(1.0);
+
+    let mut loops = Vec::new();
+    split_self_intersections(raw, 0, &mut loops);
+
+    loops
+        .into_iter()
+        .filter(|candidate| is_valid_offset_loop(candidate, &vertices, distance, join, tolerance))
+        .map(|mut result| {
+            if clockwise {
+                result.reverse();
+            }
+            result
+        })
+        .collect()
+}
+
+/// 重複点（連続する同一点・末尾の始点重複）を除去
+fn clean_polygon(polygon: &[Point2D<f64>]) -> Vec<Point2D<f64>> {
+    let mut vertices: Vec<Point2D<f64>> = Vec::with_capacity(polygon.len());
+    for &point in polygon {
+        if vertices.last() != Some(&point) {
+            vertices.push(point);
+        }
+    }
+    while vertices.len() > 1 && vertices.first() == vertices.last() {
+        vertices.pop();
+    }
+    vertices
+}
+
+/// 符号付き面積（CCWで正）
+fn signed_area(vertices: &[Point2D<f64>]) -> f64 {
+    let n = vertices.len();
+    let twice_area: f64 = (0..n)
+        .map(|i| {
+            let a = vertices[i];
+            let b = vertices[(i + 1) % n];
+            a.x() * b.y() - b.x() * a.y()
+        })
+        .sum();
+    twice_area * 0.5
+}
+
+fn polygon_extent(vertices: &[Point2D<f64>]) -> f64 {
+    vertices
+        .iter()
+        .fold(0.0f64, |acc, p| acc.max(p.x().abs()).max(p.y().abs()))
+}
+
+/// 単位ベクトル化したエッジ方向と外向き法線（CCW前提で右手側）
+fn edge_frame(a: Point2D<f64>, b: Point2D<f64>) -> ((f64, f64), (f64, f64)) {
+    let dx = b.x() - a.x();
+    let dy = b.y() - a.y();
+    let length = (dx * dx + dy * dy).sqrt();
+    let direction = (dx / length, dy / length);
+    (direction, (direction.1, -direction.0))
+}
+
+/// 角の接続を含む、トリム前のオフセットループを生成
+fn raw_offset_loop(
+    vertices: &[Point2D<f64>],
+    distance: f64,
+    join: OffsetJoin,
+) -> Vec<Point2D<f64>> {
+    let n = vertices.len();
+    let frames: Vec<_> = (0..n)
+        .map(|i| edge_frame(vertices[i], vertices[(i + 1) % n]))
+        .collect();
+
+    let mut result = Vec::new();
+    for i in 0..n {
+        let vertex = vertices[i];
+        let (d_prev, n_prev) = frames[(i + n - 1) % n];
+        let (d_next, n_next) = frames[i];
+
+        let offset_prev = Point2D::new(
+            vertex.x() + n_prev.0 * distance,
+            vertex.y() + n_prev.1 * distance,
+        );
+        let offset_next = Point2D::new(
+            vertex.x() + n_next.0 * distance,
+            vertex.y() + n_next.1 * distance,
+        );
+
+        let turn = d_prev.0 * d_next.1 - d_prev.1 * d_next.0;
+        let cos_turn = d_prev.0 * d_next.0 + d_prev.1 * d_next.1;
+
+        if turn.abs() < 1e-12 && cos_turn > 0.0 {
+            // 直進する頂点はそのまま平行移動
+            result.push(offset_next);
+        } else if turn * distance > 0.0 || cos_turn <= -1.0 + 1e-12 {
+            // 外側へ開く角（CCWの凸角を外側へ、または凹角を内側へ）: 隙間を接続
+            result.push(offset_prev);
+            if join == OffsetJoin::Round {
+                push_arc(&mut result, vertex, n_prev, n_next, distance);
+            }
+            result.push(offset_next);
+        } else {
+            // 内側へ閉じる角: 隣接オフセット線の交点でトリム
+            // offset_prev + s * d_prev = offset_next - u * d_next
+            let denominator = d_prev.0 * d_next.1 - d_prev.1 * d_next.0;
+            let wx = offset_next.x() - offset_prev.x();
+            let wy = offset_next.y() - offset_prev.y();
+            let s = (wx * d_next.1 - wy * d_next.0) / denominator;
+            result.push(Point2D::new(
+                offset_prev.x() + s * d_prev.0,
+                offset_prev.y() + s * d_prev.1,
+            ));
+        }
+    }
+
+    result
+}
+
+/// 頂点を中心とする円弧の中間点を追加（両端点は含まない）
+fn push_arc(
+    result: &mut Vec<Point2D<f64>>,
+    center: Point2D<f64>,
+    from_normal: (f64, f64),
+    to_normal: (f64, f64),
+    distance: f64,
+) {
+    let start = from_normal.1.atan2(from_normal.0);
+    let mut sweep = to_normal.1.atan2(to_normal.0) - start;
+    // 外側オフセット（CCW凸角）は反時計回り、内側オフセット（凹角）は時計回りに回る
+    if distance > 0.0 {
+        while sweep <= 0.0 {
+            sweep += std::f64::consts::TAU;
+        }
+    } else {
+        while sweep >= 0.0 {
+            sweep -= std::f64::consts::TAU;
+        }
+    }
+
+    let steps = (sweep.abs() / MAX_ARC_STEP).ceil() as usize;
+    for k in 1..steps {
+        let angle = start + sweep * k as f64 / steps as f64;
+        result.push(Point2D::new(
+            center.x() + angle.cos() * distance,
+            center.y() + angle.sin() * distance,
+        ));
+    }
+}
+
+/// 自己交差点でループを再帰的に分割
+fn split_self_intersections(
+    polygon: Vec<Point2D<f64>>,
+    depth: usize,
+    loops: &mut Vec<Vec<Point2D<f64>>>,
+) {
+    let polygon = clean_polygon(&polygon);
+    if polygon.len() < 3 {
+        return;
+    }
+
+    let intersections = self_intersections(&polygon);
+    let Some(first) = intersections.first() else {
+        loops.push(polygon);
+        return;
+    };
+    if depth >= MAX_SPLIT_DEPTH {
+        loops.push(polygon);
+        return;
+    }
+
+    let (a, b, point) = (first.edge_a, first.edge_b, first.point);
+
+    // 交点 → エッジaの終点〜エッジbの始点 → 交点
+    let mut inner = vec![point];
+    inner.extend_from_slice(&polygon[a + 1..=b]);
+
+    // 交点 → エッジbの終点〜末尾 → 先頭〜エッジaの始点 → 交点
+    let mut outer = vec![point];
+    outer.extend_from_slice(&polygon[b + 1..]);
+    outer.extend_from_slice(&polygon[..=a]);
+
+    split_self_intersections(inner, depth + 1, loops);
+    split_self_intersections(outer, depth + 1, loops);
+}
+
+/// 分割後のループがオフセット結果として有効か判定
+///
+/// 向きが元の輪郭（CCW）と同じで、すべての頂点が元の輪郭から
+/// オフセット距離以上離れているものを有効とする。
+fn is_valid_offset_loop(
+    candidate: &[Point2D<f64>],
+    original: &[Point2D<f64>],
+    distance: f64,
+    join: OffsetJoin,
+    tolerance: f64,
+) -> bool {
+    if candidate.len() < 3 || signed_area(candidate) <= tolerance * tolerance {
+        return false;
+    }
+
+    let edges: Vec<LineSegment2D<f64>> = (0..original.len())
+        .filter_map(|i| LineSegment2D::new(original[i], original[(i + 1) % original.len()]))
+        .collect();
+    let distance_to_original = |point: &Point2D<f64>| {
+        edges
+            .iter()
+            .map(|edge| edge.distance_to_point(point))
+            .fold(f64::INFINITY, f64::min)
+    };
+
+    // 分割点は折れ線化した円弧・面取り上にあり、弦のたわみ分だけ元の輪郭に近づく。
+    // それを超えて近い頂点を含むループは、自己交差で生じた不要な部分とみなす
+    let sag_factor = match join {
+        OffsetJoin::Round => (MAX_ARC_STEP * 0.5).cos(),
+        OffsetJoin::Chamfer => 0.5,
+    };
+    let required = distance.abs() * sag_factor - tolerance * 10.0;
+    candidate
+        .iter()
+        .all(|point| distance_to_original(point) >= required)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::polygon;
+
+    fn square() -> Vec<Point2D<f64>> {
+        polygon(&[(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (0.0, 10.0)])
+    }
+
+    #[test]
+    fn test_inward_offset_of_square() {
+        let result = offset_polygon(&square(), -1.0);
+        assert_eq!(result.len(), 1);
+
+        // 内側オフセットでは凸角はトリムされ、8x8 の正方形になる
+        let loop_ = &result[0];
+        assert_eq!(loop_.len(), 4);
+        assert!((signed_area(loop_) - 64.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_outward_offset_round_and_chamfer() {
+        let round = offset_polygon(&square(), 1.0);
+        assert_eq!(round.len(), 1);
+        // 正方形 + 辺の帯 + 四隅の1/4円 ≒ 100 + 40 + π
+        let expected = 100.0 + 40.0 + std::f64::consts::PI;
+        assert!((signed_area(&round[0]) - expected).abs() < 0.05);
+
+        let chamfer = offset_polygon_with_join(&square(), 1.0, OffsetJoin::Chamfer);
+        assert_eq!(chamfer[0].len(), 8);
+        // 四隅の直角二等辺三角形（面積 0.5）
+        assert!((signed_area(&chamfer[0]) - 142.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_preserves_clockwise_orientation() {
+        let mut clockwise = square();
+        clockwise.reverse();
+
+        let result = offset_polygon(&clockwise, -1.0);
+        assert_eq!(result.len(), 1);
+        assert!((signed_area(&result[0]) + 64.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_inward_offset_splits_dumbbell() {
+        // 細い首でつながった2つの部屋（首の幅は 2）
+        let dumbbell = polygon(&[
+            (0.0, 0.0),
+            (10.0, 0.0),
+            (10.0, 4.0),
+            (14.0, 4.0),
+            (14.0, 0.0),
+            (24.0, 0.0),
+            (24.0, 10.0),
+            (14.0, 10.0),
+            (14.0, 6.0),
+            (10.0, 6.0),
+            (10.0, 10.0),
+            (0.0, 10.0),
+        ]);
+
+        let result = offset_polygon(&dumbbell, -1.5);
+        assert_eq!(result.len(), 2);
+        // 各部屋は 7x7 の正方形に、首の開口部へのわずかな膨らみが加わる
+        for loop_ in &result {
+            assert!(self_intersections(loop_).is_empty());
+            let area = signed_area(loop_);
+            assert!(area > 49.0 && area < 49.5, "area = {area}");
+        }
+        assert!((signed_area(&result[0]) - signed_area(&result[1])).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_offset_collapses_to_nothing() {
+        assert!(offset_polygon(&square(), -6.0).is_empty());
+    }
+}
@@ -0,0 +1,532 @@
+//! 2Dポリゴンの自己交差検出
+//!
+//! Bentley-Ottmann のスイープラインで、閉ポリゴンのエッジ同士の交差を列挙する。
+//! 共有頂点で接する隣接エッジ同士は交差として扱わない。
+
+use std::cell::Cell;
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, BTreeSet};
+use std::ops::Bound;
+
+use geo_primitives::{LineSegment2D, Point2D, SegmentIntersection};
+
+/// 自己交差の1件分
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SelfIntersection {
+    /// 交差するエッジのインデックス（`edge_a < edge_b`）
+    ///
+    /// エッジ `i` は `polygon[i]` から `polygon[(i + 1) % n]` への辺
+    pub edge_a: usize,
+    /// 交差するもう一方のエッジのインデックス
+    pub edge_b: usize,
+    /// 交点座標（共線で重なる場合は重なり区間の最初の点）
+    pub point: Point2D<f64>,
+}
+
+/// 閉2Dポリゴンの自己交差を検出
+///
+/// 点列は閉じたポリゴンとして扱い、最後の点から最初の点への辺も含む
+/// （末尾に始点と同じ点が重複していれば取り除く）。
+/// 結果はエッジの組ごとに1件で、`(edge_a, edge_b)` の昇順に並ぶ。
+pub fn self_intersections(polygon: &[Point2D<f64>]) -> Vec<SelfIntersection> {
+    let mut vertex_count = polygon.len();
+    if vertex_count > 1 && same_point(polygon[0], polygon[vertex_count - 1], 0.0) {
+        vertex_count -= 1;
+    }
+    if vertex_count < 3 {
+        return Vec::new();
+    }
+
+    let vertices = &polygon[..vertex_count];
+    let tolerance = sweep_tolerance(vertices);
+    let edges: Vec<SweepEdge> = (0..vertex_count)
+        .map(|i| SweepEdge::new(i, vertices[i], vertices[(i + 1) % vertex_count]))
+        .collect();
+
+    let sweep = Cell::new(SweepState {
+        point: vertices[0],
+        before: false,
+        tolerance,
+    });
+    BentleyOttmann::new(vertices, &edges, &sweep).run()
+}
+
+/// 座標範囲に応じた許容誤差
+fn sweep_tolerance(vertices: &[Point2D<f64>]) -> f64 {
+    let extent = vertices
+        .iter()
+        .fold(0.0f64, |acc, p| acc.max(p.x().abs()).max(p.y().abs()));
+    1e-9 * extent.max(1.0)
+}
+
+fn same_point(a: Point2D<f64>, b: Point2D<f64>, tolerance: f64) -> bool {
+    (a.x() - b.x()).abs() <= tolerance && (a.y() - b.y()).abs() <= tolerance
+}
+
+/// 辞書式順序（x, y）で比較するイベント点
+#[derive(Debug, Clone, Copy)]
+struct EventPoint(Point2D<f64>);
+
+impl PartialEq for EventPoint {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for EventPoint {}
+
+impl PartialOrd for EventPoint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for EventPoint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0
+            .x()
+            .total_cmp(&other.0.x())
+            .then(self.0.y().total_cmp(&other.0.y()))
+    }
+}
+
+/// スイープ用に左端点（辞書式で小さい方）を始点に揃えたエッジ
+#[derive(Debug, Clone, Copy)]
+struct SweepEdge {
+    index: usize,
+    left: Point2D<f64>,
+    right: Point2D<f64>,
+}
+
+impl SweepEdge {
+    fn new(index: usize, a: Point2D<f64>, b: Point2D<f64>) -> Self {
+        if EventPoint(a) <= EventPoint(b) {
+            Self {
+                index,
+                left: a,
+                right: b,
+            }
+        } else {
+            Self {
+                index,
+                left: b,
+                right: a,
+            }
+        }
+    }
+
+    fn is_degenerate(&self, tolerance: f64) -> bool {
+        same_point(self.left, self.right, tolerance)
+    }
+
+    /// 左から右への傾き（垂直は +∞）
+    fn slope(&self) -> f64 {
+        let dx = self.right.x() - self.left.x();
+        if dx == 0.0 {
+            f64::INFINITY
+        } else {
+            (self.right.y() - self.left.y()) / dx
+        }
+    }
+
+    /// スイープ点でのエッジの y 座標（垂直エッジはスイープ点の y を端点の範囲に収めた値）
+    fn y_at(&self, sweep: Point2D<f64>) -> f64 {
+        let dx = self.right.x() - self.left.x();
+        if dx == 0.0 {
+            sweep.y().max(self.left.y()).min(self.right.y())
+        } else {
+            let t = ((sweep.x() - self.left.x()) / dx).clamp(0.0, 1.0);
+            self.left.y() + t * (self.right.y() - self.left.y())
+        }
+    }
+
+    /// 点がエッジ上（端点を含む）にあるか
+    fn contains(&self, point: Point2D<f64>, tolerance: f64) -> bool {
+        let dx = self.right.x() - self.left.x();
+        let dy = self.right.y() - self.left.y();
+        let length_squared = dx * dx + dy * dy;
+        let px = point.x() - self.left.x();
+        let py = point.y() - self.left.y();
+        let t = ((px * dx + py * dy) / length_squared).clamp(0.0, 1.0);
+        let ex = px - t * dx;
+        let ey = py - t * dy;
+        ex * ex + ey * ey <= tolerance * tolerance
+    }
+
+    fn segment(&self) -> Option<LineSegment2D<f64>> {
+        LineSegment2D::new(self.left, self.right)
+    }
+}
+
+/// ステータスの比較に使うスイープ位置
+#[derive(Debug, Clone, Copy)]
+struct SweepState {
+    point: Point2D<f64>,
+    /// イベント点の直前（左側）での上下関係で比較するか
+    before: bool,
+    tolerance: f64,
+}
+
+/// スイープラインのステータスに載るエッジ
+///
+/// 現在のスイープ点でのエッジの上下で順序付ける。スイープ点はエッジ同士が交差しない範囲で
+/// 次のイベントまで進み、交差点では関与するエッジを取り除いてから挿入し直すため、
+/// 格納済みの要素の相対順序は保たれる。
+#[derive(Debug, Clone, Copy)]
+struct StatusEntry<'a> {
+    edge: SweepEdge,
+    sweep: &'a Cell<SweepState>,
+}
+
+impl StatusEntry<'_> {
+    fn y(&self) -> f64 {
+        self.edge.y_at(self.sweep.get().point)
+    }
+}
+
+impl PartialEq for StatusEntry<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for StatusEntry<'_> {}
+
+impl PartialOrd for StatusEntry<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for StatusEntry<'_> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let sweep = self.sweep.get();
+        let (y_a, y_b) = (self.y(), other.y());
+        if (y_a - y_b).abs() > sweep.tolerance {
+            return y_a.total_cmp(&y_b);
+        }
+
+        // 同じ点を通るエッジは傾きで並べる（点の右側では傾きの小さい順、左側ではその逆）
+        let by_slope = self.edge.slope().total_cmp(&other.edge.slope());
+        let by_slope = if sweep.before {
+            by_slope.reverse()
+        } else {
+            by_slope
+        };
+        by_slope.then(self.edge.index.cmp(&other.edge.index))
+    }
+}
+
+/// Bentley-Ottmann スイープラインの状態
+struct BentleyOttmann<'a> {
+    vertices: &'a [Point2D<f64>],
+    edges: &'a [SweepEdge],
+    tolerance: f64,
+    sweep: &'a Cell<SweepState>,
+    /// イベントキュー（点 → その点を左端点とするエッジ）
+    events: BTreeMap<EventPoint, Vec<usize>>,
+    /// スイープライン直下から上へ並んだ処理中のエッジ
+    status: BTreeSet<StatusEntry<'a>>,
+    /// 報告済みのエッジの組 → 交点
+    found: BTreeMap<(usize, usize), Point2D<f64>>,
+}
+
+impl<'a> BentleyOttmann<'a> {
+    fn new(
+        vertices: &'a [Point2D<f64>],
+        edges: &'a [SweepEdge],
+        sweep: &'a Cell<SweepState>,
+    ) -> Self {
+        let tolerance = sweep.get().tolerance;
+        let mut events: BTreeMap<EventPoint, Vec<usize>> = BTreeMap::new();
+        for edge in edges.iter().filter(|e| !e.is_degenerate(tolerance)) {
+            events
+                .entry(EventPoint(edge.left))
+                .or_default()
+                .push(edge.index);
+            events.entry(EventPoint(edge.right)).or_default();
+        }
+
+        Self {
+            vertices,
+            edges,
+            tolerance,
+            sweep,
+            events,
+            status: BTreeSet::new(),
+            found: BTreeMap::new(),
+        }
+    }
+
+    fn run(mut self) -> Vec<SelfIntersection> {
+        while let Some((EventPoint(point), starting)) = self.events.pop_first() {
+            self.handle_event(point, starting);
+        }
+
+        self.found
+            .into_iter()
+            .map(|((edge_a, edge_b), point)| SelfIntersection {
+                edge_a,
+                edge_b,
+                point,
+            })
+            .collect()
+    }
+
+    fn entry(&self, edge: usize) -> StatusEntry<'a> {
+        StatusEntry {
+            edge: self.edges[edge],
+            sweep: self.sweep,
+        }
+    }
+
+    fn handle_event(&mut self, point: Point2D<f64>, starting: Vec<usize>) {
+        let edges = self.edges;
+        let tolerance = self.tolerance;
+
+        // 点の直前の順序で、点を通過中（内部を含む）または終了するエッジを探す
+        // （点の高さのエッジは垂直な探索用エッジより後ろに並ぶ）
+        self.sweep.set(SweepState {
+            point,
+            before: true,
+            tolerance,
+        });
+        let probe = StatusEntry {
+            edge: SweepEdge::new(0, point, point),
+            sweep: self.sweep,
+        };
+        let (through, ending): (Vec<usize>, Vec<usize>) = self
+            .status
+            .range(probe..)
+            .take_while(|entry| (entry.y() - point.y()).abs() <= tolerance)
+            .map(|entry| entry.edge.index)
+            .filter(|&e| edges[e].contains(point, tolerance))
+            .partition(|&e| !same_point(edges[e].right, point, tolerance));
+
+        let involved: BTreeSet<usize> = starting
+            .iter()
+            .chain(&through)
+            .chain(&ending)
+            .copied()
+            .collect();
+        if involved.len() > 1 {
+            self.report(point, &involved);
+        }
+
+        // 終了・通過エッジを取り除き、開始・通過エッジを点の直後の順序で挿入し直す
+        for &e in through.iter().chain(&ending) {
+            let entry = self.entry(e);
+            let removed = self.status.remove(&entry);
+            debug_assert!(removed, "edge {e} is missing from the sweep status");
+        }
+        self.sweep.set(SweepState {
+            before: false,
+            ..self.sweep.get()
+        });
+        let inserted: Vec<StatusEntry<'a>> = starting
+            .iter()
+            .chain(&through)
+            .map(|&e| self.entry(e))
+            .collect();
+        self.status.extend(inserted.iter().copied());
+
+        // 挿入した範囲（なければ点の位置）の上下で隣り合うエッジの組を調べる
+        let lowest = inserted.iter().min().copied().unwrap_or(probe);
+        let highest = inserted.iter().max().copied().unwrap_or(probe);
+        let below = self.status.range(..lowest).next_back().copied();
+        let above = self
+            .status
+            .range((Bound::Excluded(highest), Bound::Unbounded))
+            .next()
+            .copied();
+        if inserted.is_empty() {
+            if let (Some(below), Some(above)) = (below, above) {
+                self.find_new_event(below.edge.index, above.edge.index, point);
+            }
+        } else {
+            if let Some(below) = below {
+                self.find_new_event(below.edge.index, lowest.edge.index, point);
+            }
+            if let Some(above) = above {
+                self.find_new_event(highest.edge.index, above.edge.index, point);
+            }
+        }
+    }
+
+    /// 隣り合うエッジの交点がスイープ位置より右にあればイベントに追加
+    fn find_new_event(&mut self, a: usize, b: usize, current: Point2D<f64>) {
+        let (Some(segment_a), Some(segment_b)) = (self.edges[a].segment(), self.edges[b].segment())
+        else {
+            return;
+        };
+
+        // 共線の重なりは端点イベントで検出されるため、ここでは1点交差のみ扱う
+        if let SegmentIntersection::Point(point) =
+            segment_a.intersect_segment_with_tolerance(&segment_b, self.tolerance)
+        {
+            if EventPoint(point) > EventPoint(current)
+                && !same_point(point, current, self.tolerance)
+            {
+                self.events.entry(EventPoint(point)).or_default();
+            }
+        }
+    }
+
+    /// 点に関与するエッジの組を交差として記録（共有頂点で接する隣接エッジは除外）
+    fn report(&mut self, point: Point2D<f64>, involved: &BTreeSet<usize>) {
+        let involved: Vec<usize> = involved.iter().copied().collect();
+        for (i, &a) in involved.iter().enumerate() {
+            for &b in &involved[i + 1..] {
+                if self.is_shared_vertex(a, b, point) {
+                    continue;
+                }
+                self.found.entry((a, b)).or_insert(point);
+            }
+        }
+    }
+
+    /// 隣接エッジ a, b (a < b) が共有頂点で接しているだけか
+    fn is_shared_vertex(&self, a: usize, b: usize, point: Point2D<f64>) -> bool {
+        let shared_vertex = if b == a + 1 {
+            b
+        } else if a == 0 && b == self.vertices.len() - 1 {
+            0
+        } else {
+            return false;
+        };
+
+        same_point(point, self.vertices[shared_vertex], self.tolerance)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::polygon;
+
+    #[test]
+    fn test_simple_polygons_have_no_intersections() {
+        let square = polygon(&[(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)]);
+        assert!(self_intersections(&square).is_empty());
+
+        // 凹多角形（垂直エッジを含む）
+        let concave = polygon(&[(0.0, 0.0), (4.0, 0.0), (4.0, 4.0), (2.0, 1.0), (0.0, 4.0)]);
+        assert!(self_intersections(&concave).is_empty());
+
+        // 末尾に始点を重複させた閉じた点列
+        let closed = polygon(&[(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 0.0)]);
+        assert!(self_intersections(&closed).is_empty());
+    }
+
+    #[test]
+    fn test_bowtie_intersection() {
+        let bowtie = polygon(&[(0.0, 0.0), (2.0, 2.0), (2.0, 0.0), (0.0, 2.0)]);
+        let result = self_intersections(&bowtie);
+
+        assert_eq!(result.len(), 1);
+        assert_eq!((result[0].edge_a, result[0].edge_b), (0, 2));
+        assert!((result[0].point.x() - 1.0).abs() < 1e-9);
+        assert!((result[0].point.y() - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_star_pentagram_intersections() {
+        // 五芒星（一筆書き）は5箇所で自己交差
+        let star: Vec<Point2D<f64>> = (0..5)
+            .map(|i| {
+                let angle =
+                    std::f64::consts::FRAC_PI_2 + (i * 2) as f64 * 2.0 * std::f64::consts::PI / 5.0;
+                Point2D::new(angle.cos(), angle.sin())
+            })
+            .collect();
+        let result = self_intersections(&star);
+
+        assert_eq!(result.len(), 5);
+        for intersection in &result {
+            let a = LineSegment2D::new(
+                star[intersection.edge_a],
+                star[(intersection.edge_a + 1) % 5],
+            )
+            .unwrap();
+            assert!(a.distance_to_point(&intersection.point) < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_touching_at_vertex_is_reported_for_non_adjacent_edges() {
+        // 頂点 (1, 1) で自分自身に接する8の字
+        let figure_eight = polygon(&[
+            (0.0, 0.0),
+            (1.0, 1.0),
+            (2.0, 0.0),
+            (2.0, 2.0),
+            (1.0, 1.0),
+            (0.0, 2.0),
+        ]);
+        let result = self_intersections(&figure_eight);
+
+        assert!(!result.is_empty());
+        for intersection in &result {
+            assert!((intersection.point.x() - 1.0).abs() < 1e-9);
+            assert!((intersection.point.y() - 1.0).abs() < 1e-9);
+            // 隣接エッジの共有頂点は含まない
+            assert_ne!(intersection.edge_b, intersection.edge_a + 1);
+        }
+    }
+
+    #[test]
+    fn test_matches_brute_force_on_random_polygons() {
+        // 線形合同法による決定的な乱数
+        let mut seed: u64 = 0x2545_f491_4f6c_dd1d;
+        let mut next = || {
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            ((seed >> 33) as f64) / (1u64 << 31) as f64
+        };
+
+        for round in 0..100 {
+            // 後半は整数格子上の点で、垂直エッジ・頂点上の交差・共線の重なりを含む
+            let points: Vec<Point2D<f64>> = (0..12)
+                .map(|_| {
+                    let (x, y) = (next() * 10.0, next() * 10.0);
+                    if round < 50 {
+                        Point2D::new(x, y)
+                    } else {
+                        Point2D::new(x.floor(), y.floor())
+                    }
+                })
+                .collect();
+            let n = points.len();
+            // 長さ0のエッジは参照実装で扱えないので除く
+            if (0..n).any(|i| points[i] == points[(i + 1) % n]) {
+                continue;
+            }
+
+            let mut expected = Vec::new();
+            for a in 0..n {
+                for b in a + 1..n {
+                    let sa = LineSegment2D::new(points[a], points[(a + 1) % n]).unwrap();
+                    let sb = LineSegment2D::new(points[b], points[(b + 1) % n]).unwrap();
+                    let intersection = sa.intersect_segment(&sb);
+                    // 隣接エッジは共有頂点での接触を除き、折り返しの重なりだけを数える
+                    let adjacent = b == a + 1 || (a == 0 && b == n - 1);
+                    let counted = if adjacent {
+                        matches!(intersection, SegmentIntersection::Overlap(_))
+                    } else {
+                        intersection.is_intersecting()
+                    };
+                    if counted {
+                        expected.push((a, b));
+                    }
+                }
+            }
+
+            let found: Vec<(usize, usize)> = self_intersections(&points)
+                .iter()
+                .map(|i| (i.edge_a, i.edge_b))
+                .collect();
+            assert_eq!(found, expected);
+        }
+    }
+}
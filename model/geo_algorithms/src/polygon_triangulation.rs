@@ -0,0 +1,520 @@
+//! 2D単純多角形の三角形分割（耳刈り法）
+//!
+//! 頂点列を反時計回りに正規化し、凸頂点のうち三角形内に他の頂点を含まないもの（耳）を
+//! 逐次切り落とす。凹多角形に対応し、頂点数 n の多角形から n - 2 枚の三角形を得る
+//! （一直線上に並ぶ頂点は耳が見つからないときに三角形を作らず取り除く）。
+//! 自己交差する多角形は事前に検出してエラーとする。
+//!
+//! 穴あきプロファイルは、穴を x 最大の頂点から見える外形の頂点へ橋（往復の辺）で
+//! 繋いで1本の単純多角形にしてから耳刈りする。
+
+use geo_primitives::{LineSegment2D, Point2D};
+
+use crate::point_in_polygon::{point_in_polygon, Containment};
+use crate::polygon_self_intersection::self_intersections;
+
+/// 単純多角形を三角形分割し、入力の頂点インデックスの組を返す
+///
+/// 入力の向き（CCW/CW）は問わず、各三角形は反時計回りに並ぶ。
+/// 末尾に始点と同じ点が重複していれば取り除く。
+/// 頂点数が3未満、面積が0、または自己交差がある場合は `Err`。
+pub fn triangulate_polygon_2d(polygon: &[Point2D<f64>]) -> Result<Vec<[usize; 3]>, String> {
+    let vertices = strip_closing_point(polygon);
+    let area = validate_loop(vertices, "Polygon")?;
+
+    // 反時計回りに正規化した頂点の列
+    let mut order: Vec<usize> = (0..vertices.len()).collect();
+    if area < 0.0 {
+        order.reverse();
+    }
+    ear_clip(vertices, order)
+}
+
+/// 外形と穴（複数）からなるプロファイルを三角形分割
+///
+/// 返すインデックスは外形の頂点、続いて各穴の頂点を順に連結した列に対するもの
+/// （末尾の始点重複は取り除いた後の頂点数で数える）。外形・穴の向きは問わない。
+/// 各ループが単純多角形でない場合、穴が外形の外にはみ出す・外形や他の穴と交差する・
+/// 他の穴の内側にある場合は `Err`。
+pub fn triangulate_with_holes(
+    outer: &[Point2D<f64>],
+    holes: &[Vec<Point2D<f64>>],
+) -> Result<Vec<[usize; 3]>, String> {
+    let outer = strip_closing_point(outer);
+    let outer_area = validate_loop(outer, "Outer boundary")?;
+    let holes: Vec<&[Point2D<f64>]> = holes.iter().map(|h| strip_closing_point(h)).collect();
+    let mut hole_areas = Vec::with_capacity(holes.len());
+    for (i, hole) in holes.iter().enumerate() {
+        hole_areas.push(validate_loop(hole, &format!("Hole {}", i))?);
+    }
+    validate_hole_placement(outer, &holes)?;
+
+    let mut vertices = outer.to_vec();
+    let mut polygon: Vec<usize> = (0..outer.len()).collect();
+    if outer_area < 0.0 {
+        polygon.reverse();
+    }
+
+    // 外形は反時計回り、穴は時計回りに揃える
+    let mut hole_loops = Vec::with_capacity(holes.len());
+    for (hole, area) in holes.iter().zip(&hole_areas) {
+        let offset = vertices.len();
+        vertices.extend_from_slice(hole);
+        let mut order: Vec<usize> = (offset..offset + hole.len()).collect();
+        if *area > 0.0 {
+            order.reverse();
+        }
+        hole_loops.push(order);
+    }
+
+    // x 最大の頂点が右にある穴から順に繋ぐと、橋が未処理の穴を横切りにくい
+    let rightmost = |order: &[usize]| {
+        order
+            .iter()
+            .map(|&i| vertices[i].x())
+            .fold(f64::NEG_INFINITY, f64::max)
+    };
+    hole_loops.sort_by(|a, b| rightmost(b).total_cmp(&rightmost(a)));
+
+    for k in 0..hole_loops.len() {
+        let (bridged, pending) = hole_loops.split_at(k + 1);
+        polygon = bridge_hole(&vertices, &polygon, &bridged[k], pending)?;
+    }
+
+    ear_clip(&vertices, polygon)
+}
+
+/// 末尾に始点と同じ点が重複していれば取り除く
+fn strip_closing_point(polygon: &[Point2D<f64>]) -> &[Point2D<f64>] {
+    match polygon {
+        [first, .., last] if first == last => &polygon[..polygon.len() - 1],
+        _ => polygon,
+    }
+}
+
+/// ループが単純多角形か検査し、符号付き面積を返す
+fn validate_loop(vertices: &[Point2D<f64>], name: &str) -> Result<f64, String> {
+    if vertices.len() < 3 {
+        return Err(format!(
+            "{} needs at least 3 vertices, got {}",
+            name,
+            vertices.len()
+        ));
+    }
+
+    if let Some(intersection) = self_intersections(vertices).first() {
+        return Err(format!(
+            "{} is self-intersecting: edges {} and {} meet at ({}, {})",
+            name,
+            intersection.edge_a,
+            intersection.edge_b,
+            intersection.point.x(),
+            intersection.point.y()
+        ));
+    }
+
+    let area = signed_area(vertices);
+    if area == 0.0 {
+        return Err(format!("{} has zero area", name));
+    }
+    Ok(area)
+}
+
+/// 穴が外形の内側にあり、外形・他の穴と交差も包含もしないか検査
+fn validate_hole_placement(
+    outer: &[Point2D<f64>],
+    holes: &[&[Point2D<f64>]],
+) -> Result<(), String> {
+    for (i, hole) in holes.iter().enumerate() {
+        if point_in_polygon(hole[0], outer) != Containment::Inside || loops_touch(hole, outer) {
+            return Err(format!(
+                "Hole {} is not strictly inside the outer boundary",
+                i
+            ));
+        }
+        for (j, other) in holes.iter().enumerate().skip(i + 1) {
+            if loops_touch(hole, other)
+                || point_in_polygon(hole[0], other) != Containment::Outside
+                || point_in_polygon(other[0], hole) != Containment::Outside
+            {
+                return Err(format!("Holes {} and {} overlap", i, j));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// 2つの閉ループの辺同士が交差・接触するか
+fn loops_touch(a: &[Point2D<f64>], b: &[Point2D<f64>]) -> bool {
+    let edges = |points: &[Point2D<f64>]| -> Vec<LineSegment2D<f64>> {
+        (0..points.len())
+            .filter_map(|i| LineSegment2D::new(points[i], points[(i + 1) % points.len()]))
+            .collect()
+    };
+    let b_edges = edges(b);
+    edges(a).iter().any(|ea| {
+        b_edges
+            .iter()
+            .any(|eb| ea.intersect_segment(eb).is_intersecting())
+    })
+}
+
+/// 穴（時計回り）を、その x 最大の頂点から見える多角形の頂点へ橋で繋ぐ
+///
+/// 橋の両端は複製され、`… P, M, 穴の残り …, M, P …` の順に多角形へ挿入される。
+/// 見える頂点は、橋が多角形・未処理の穴の辺と交わらず、橋の向きが頂点の内角側に
+/// あるもののうち最も近いものを選ぶ。
+fn bridge_hole(
+    vertices: &[Point2D<f64>],
+    polygon: &[usize],
+    hole: &[usize],
+    pending: &[Vec<usize>],
+) -> Result<Vec<usize>, String> {
+    let start = (0..hole.len())
+        .max_by(|&a, &b| {
+            let (pa, pb) = (vertices[hole[a]], vertices[hole[b]]);
+            pa.x().total_cmp(&pb.x()).then(pb.y().total_cmp(&pa.y()))
+        })
+        .expect("hole has at least 3 vertices");
+    let m = vertices[hole[start]];
+
+    let obstacles = std::iter::once(polygon)
+        .chain(std::iter::once(hole))
+        .chain(pending.iter().map(Vec::as_slice));
+    let edges: Vec<(Point2D<f64>, Point2D<f64>)> = obstacles
+        .flat_map(|ring| {
+            (0..ring.len()).map(move |i| (vertices[ring[i]], vertices[ring[(i + 1) % ring.len()]]))
+        })
+        .collect();
+
+    let count = polygon.len();
+    let target = (0..count)
+        .filter(|&k| {
+            let prev = vertices[polygon[(k + count - 1) % count]];
+            let p = vertices[polygon[k]];
+            let next = vertices[polygon[(k + 1) % count]];
+            in_interior_wedge(prev, p, next, m)
+                && edges.iter().all(|&(u, v)| {
+                    [u, v].iter().any(|&e| e == p || e == m) || !segments_touch(m, p, u, v)
+                })
+        })
+        .min_by(|&a, &b| {
+            let distance = |k: usize| {
+                let p = vertices[polygon[k]];
+                (p.x() - m.x()).powi(2) + (p.y() - m.y()).powi(2)
+            };
+            distance(a).total_cmp(&distance(b))
+        })
+        .ok_or_else(|| "Failed to find a bridge to hole".to_string())?;
+
+    let mut bridged = Vec::with_capacity(count + hole.len() + 2);
+    bridged.extend_from_slice(&polygon[..=target]);
+    bridged.extend((0..=hole.len()).map(|i| hole[(start + i) % hole.len()]));
+    bridged.extend_from_slice(&polygon[target..]);
+    Ok(bridged)
+}
+
+/// 反時計回り多角形の頂点 p（前 prev、次 next）の内角側へ、p から点 q が見えるか
+fn in_interior_wedge(
+    prev: Point2D<f64>,
+    p: Point2D<f64>,
+    next: Point2D<f64>,
+    q: Point2D<f64>,
+) -> bool {
+    let left_of_incoming = cross(prev, p, q) > 0.0;
+    let left_of_outgoing = cross(p, next, q) > 0.0;
+    if cross(prev, p, next) >= 0.0 {
+        left_of_incoming && left_of_outgoing
+    } else {
+        left_of_incoming || left_of_outgoing
+    }
+}
+
+/// 線分 ab と cd が交差・接触するか（端点・共線の重なりを含む）
+fn segments_touch(a: Point2D<f64>, b: Point2D<f64>, c: Point2D<f64>, d: Point2D<f64>) -> bool {
+    let (d1, d2) = (cross(a, b, c), cross(a, b, d));
+    let (d3, d4) = (cross(c, d, a), cross(c, d, b));
+    if d1 == 0.0 && d2 == 0.0 {
+        // 共線: 軸ごとの区間が重なるか
+        let overlaps = |p0: f64, p1: f64, q0: f64, q1: f64| {
+            p0.min(p1) <= q0.max(q1) && q0.min(q1) <= p0.max(p1)
+        };
+        return overlaps(a.x(), b.x(), c.x(), d.x()) && overlaps(a.y(), b.y(), c.y(), d.y());
+    }
+    d1 * d2 <= 0.0 && d3 * d4 <= 0.0
+}
+
+/// 反時計回りの頂点列 `remaining` を耳刈りで三角形分割
+///
+/// 橋で繋いだ多角形では同じ位置の頂点が複数現れるため、耳の判定では
+/// 三角形の頂点と同じ位置の点を除外する。
+fn ear_clip(
+    vertices: &[Point2D<f64>],
+    mut remaining: Vec<usize>,
+) -> Result<Vec<[usize; 3]>, String> {
+    let mut triangles = Vec::with_capacity(remaining.len().saturating_sub(2));
+    while remaining.len() > 3 {
+        let count = remaining.len();
+        let corner = |i: usize| {
+            [
+                remaining[(i + count - 1) % count],
+                remaining[i],
+                remaining[(i + 1) % count],
+            ]
+        };
+
+        if let Some(ear) = (0..count).find(|&i| is_ear(vertices, &remaining, corner(i))) {
+            triangles.push(corner(ear));
+            remaining.remove(ear);
+            continue;
+        }
+
+        // 耳がないのは一直線上の頂点が残っている場合のみ（面積0なので三角形は作らない）
+        let collinear = (0..count).find(|&i| {
+            let [a, b, c] = corner(i);
+            cross(vertices[a], vertices[b], vertices[c]) == 0.0
+        });
+        match collinear {
+            Some(i) => {
+                remaining.remove(i);
+            }
+            None => return Err("Failed to find an ear in polygon".to_string()),
+        }
+    }
+
+    if let [a, b, c] = remaining[..] {
+        if cross(vertices[a], vertices[b], vertices[c]) > 0.0 {
+            triangles.push([a, b, c]);
+        }
+    }
+    Ok(triangles)
+}
+
+/// 末尾の始点重複と、前後の頂点と一直線上に並ぶ頂点を取り除く
+pub(crate) fn remove_collinear_vertices(profile: &[Point2D<f64>]) -> Vec<Point2D<f64>> {
+    let mut points = profile.to_vec();
+    if points.len() > 1 && points.first() == points.last() {
+        points.pop();
+    }
+    let mut i = 0;
+    while points.len() > 3 && i < points.len() {
+        let n = points.len();
+        if cross(points[(i + n - 1) % n], points[i], points[(i + 1) % n]) == 0.0 {
+            points.remove(i);
+            i = i.saturating_sub(1);
+        } else {
+            i += 1;
+        }
+    }
+    points
+}
+
+/// 符号付き面積（反時計回りで正）
+pub(crate) fn signed_area(vertices: &[Point2D<f64>]) -> f64 {
+    let n = vertices.len();
+    (0..n)
+        .map(|i| {
+            let (a, b) = (vertices[i], vertices[(i + 1) % n]);
+            a.x() * b.y() - b.x() * a.y()
+        })
+        .sum::<f64>()
+        * 0.5
+}
+
+/// (b - a) × (c - a)（a→b→c が左回りなら正）
+fn cross(a: Point2D<f64>, b: Point2D<f64>, c: Point2D<f64>) -> f64 {
+    (b.x() - a.x()) * (c.y() - a.y()) - (b.y() - a.y()) * (c.x() - a.x())
+}
+
+/// 頂点 b が耳か（凸で、三角形 abc の内部・辺上に他の残り頂点がない）
+fn is_ear(vertices: &[Point2D<f64>], remaining: &[usize], [a, b, c]: [usize; 3]) -> bool {
+    let (pa, pb, pc) = (vertices[a], vertices[b], vertices[c]);
+    if cross(pa, pb, pc) <= 0.0 {
+        return false;
+    }
+    remaining
+        .iter()
+        .filter(|&&i| i != a && i != b && i != c)
+        .map(|&i| vertices[i])
+        .filter(|&p| p != pa && p != pb && p != pc)
+        .all(|p| !(cross(pa, pb, p) >= 0.0 && cross(pb, pc, p) >= 0.0 && cross(pc, pa, p) >= 0.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::polygon;
+
+    fn triangulated_area(points: &[Point2D<f64>], triangles: &[[usize; 3]]) -> f64 {
+        triangles
+            .iter()
+            .map(|&[a, b, c]| {
+                let doubled = cross(points[a], points[b], points[c]);
+                assert!(doubled > 0.0, "triangle is not counter-clockwise");
+                doubled * 0.5
+            })
+            .sum()
+    }
+
+    #[test]
+    fn test_convex_polygon() {
+        let square = polygon(&[(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)]);
+        let triangles = triangulate_polygon_2d(&square).unwrap();
+        assert_eq!(triangles.len(), 2);
+        assert!((triangulated_area(&square, &triangles) - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_concave_polygon_in_both_orientations() {
+        // L字型（面積3）
+        let mut shape = polygon(&[
+            (0.0, 0.0),
+            (2.0, 0.0),
+            (2.0, 1.0),
+            (1.0, 1.0),
+            (1.0, 2.0),
+            (0.0, 2.0),
+        ]);
+        for _ in 0..2 {
+            let triangles = triangulate_polygon_2d(&shape).unwrap();
+            assert_eq!(triangles.len(), 4);
+            assert!((triangulated_area(&shape, &triangles) - 3.0).abs() < 1e-12);
+            shape.reverse();
+        }
+
+        // 櫛形（凹頂点が複数）
+        let comb = polygon(&[
+            (0.0, 0.0),
+            (5.0, 0.0),
+            (5.0, 3.0),
+            (4.0, 3.0),
+            (4.0, 1.0),
+            (3.0, 1.0),
+            (3.0, 3.0),
+            (2.0, 3.0),
+            (2.0, 1.0),
+            (1.0, 1.0),
+            (1.0, 3.0),
+            (0.0, 3.0),
+        ]);
+        let triangles = triangulate_polygon_2d(&comb).unwrap();
+        assert_eq!(triangles.len(), comb.len() - 2);
+        assert!((triangulated_area(&comb, &triangles) - 11.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_collinear_vertices_and_closing_point() {
+        let shape = polygon(&[
+            (0.0, 0.0),
+            (1.0, 0.0),
+            (2.0, 0.0),
+            (2.0, 2.0),
+            (0.0, 2.0),
+            (0.0, 0.0),
+        ]);
+        let triangles = triangulate_polygon_2d(&shape).unwrap();
+        assert!((triangulated_area(&shape, &triangles) - 4.0).abs() < 1e-12);
+        assert!(triangles.iter().flatten().all(|&i| i < 5));
+    }
+
+    fn square(center: (f64, f64), half: f64) -> Vec<Point2D<f64>> {
+        let (x, y) = center;
+        polygon(&[
+            (x - half, y - half),
+            (x + half, y - half),
+            (x + half, y + half),
+            (x - half, y + half),
+        ])
+    }
+
+    /// どの三角形も点を内部に含まないか
+    fn uncovered(points: &[Point2D<f64>], triangles: &[[usize; 3]], p: Point2D<f64>) -> bool {
+        triangles.iter().all(|&[a, b, c]| {
+            let (pa, pb, pc) = (points[a], points[b], points[c]);
+            !(cross(pa, pb, p) > 0.0 && cross(pb, pc, p) > 0.0 && cross(pc, pa, p) > 0.0)
+        })
+    }
+
+    #[test]
+    fn test_washer_profile() {
+        // 外形と穴が同じ向きでも穴として扱う
+        let outer = square((0.0, 0.0), 2.0);
+        let hole = square((0.0, 0.0), 1.0);
+        let triangles = triangulate_with_holes(&outer, std::slice::from_ref(&hole)).unwrap();
+
+        let points: Vec<Point2D<f64>> = outer.iter().chain(&hole).copied().collect();
+        assert_eq!(triangles.len(), 8);
+        assert!((triangulated_area(&points, &triangles) - 12.0).abs() < 1e-12);
+        assert!(uncovered(&points, &triangles, Point2D::new(0.0, 0.0)));
+        assert!(uncovered(&points, &triangles, Point2D::new(0.5, -0.5)));
+    }
+
+    #[test]
+    fn test_multiple_holes() {
+        let outer = polygon(&[(0.0, 0.0), (10.0, 0.0), (10.0, 4.0), (0.0, 4.0), (0.0, 0.0)]);
+        let mut left = square((2.0, 2.0), 1.0);
+        left.reverse();
+        let holes = vec![left, square((5.0, 2.0), 1.0), square((8.0, 2.0), 0.5)];
+        let triangles = triangulate_with_holes(&outer, &holes).unwrap();
+
+        // 外形の末尾の重複点は数えない
+        let points: Vec<Point2D<f64>> = outer[..4]
+            .iter()
+            .chain(holes.iter().flatten())
+            .copied()
+            .collect();
+        assert!(triangles.iter().flatten().all(|&i| i < points.len()));
+        assert_eq!(triangles.len(), 4 + 3 * 6 - 2);
+        assert!((triangulated_area(&points, &triangles) - (40.0 - 4.0 - 4.0 - 1.0)).abs() < 1e-9);
+        for center in [(2.0, 2.0), (5.0, 2.0), (8.0, 2.0)] {
+            assert!(uncovered(
+                &points,
+                &triangles,
+                Point2D::new(center.0, center.1)
+            ));
+        }
+
+        // 穴がなければ単純多角形の分割と同じ
+        assert_eq!(
+            triangulate_with_holes(&outer, &[]).unwrap(),
+            triangulate_polygon_2d(&outer).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_invalid_holes() {
+        let outer = square((0.0, 0.0), 2.0);
+        let cases = [
+            // 外形の外
+            vec![square((5.0, 0.0), 1.0)],
+            // 外形と交差
+            vec![square((2.0, 0.0), 1.0)],
+            // 穴同士が交差
+            vec![square((-0.5, 0.0), 1.0), square((0.5, 0.0), 1.0)],
+            // 穴の中の穴
+            vec![square((0.0, 0.0), 1.5), square((0.0, 0.0), 0.5)],
+            // 自己交差する穴
+            vec![polygon(&[
+                (-1.0, -1.0),
+                (1.0, 1.0),
+                (1.0, -1.0),
+                (-1.0, 1.0),
+            ])],
+        ];
+        for holes in cases {
+            assert!(triangulate_with_holes(&outer, &holes).is_err(), "{holes:?}");
+        }
+    }
+
+    #[test]
+    fn test_invalid_polygons() {
+        let bowtie = polygon(&[(0.0, 0.0), (2.0, 2.0), (2.0, 0.0), (0.0, 2.0)]);
+        assert!(triangulate_polygon_2d(&bowtie).is_err());
+
+        let line = polygon(&[(0.0, 0.0), (1.0, 0.0)]);
+        assert!(triangulate_polygon_2d(&line).is_err());
+
+        let flat = polygon(&[(0.0, 0.0), (1.0, 0.0), (2.0, 0.0)]);
+        assert!(triangulate_polygon_2d(&flat).is_err());
+    }
+}
@@ -0,0 +1,200 @@
+//! 2Dプロファイルの回転による回転体メッシュ生成
+//!
+//! プロファイルの点 (x, y) を「軸からの距離 x、軸方向の位置 y」として、軸周りに
+//! `segments` 分割で回転させる。プロファイルは閉じた多角形として扱い、各辺が側面の帯になる。
+//! 軸上の点（x = 0）は全ての角度で1つの頂点を共有し、そこで潰れる三角形は取り除く。
+//! フル回転では側面だけで閉じ、部分回転では回転の始端・終端にプロファイル形状の蓋を付ける。
+
+use std::f64::consts::TAU;
+
+use geo_primitives::{Angle, InfiniteLine3D, Plane3D, Point2D, TriangleMesh3D, Vector3D};
+
+use crate::polygon_triangulation::{
+    remove_collinear_vertices, signed_area, triangulate_polygon_2d,
+};
+
+/// フル回転とみなす角度の許容誤差（ラジアン）
+const FULL_TURN_TOLERANCE: f64 = 1e-9;
+
+/// プロファイルを軸周りに `angle` だけ回転させた回転体メッシュを生成
+///
+/// 回転の基準方向（角度0でプロファイルの x 軸が向く方向）は、軸方向とのなす角が大きい方の
+/// ワールド X 軸または Y 軸を軸に直交化したもの。回転は軸方向を親指とする右ねじの向きで、
+/// `angle` が負なら逆向き。向きに関わらず面の法線は外向きに揃える。
+/// プロファイルが x < 0 を含む場合、`segments` が0の場合、フル回転で `segments` が3未満の場合、
+/// `angle` が0または1回転を超える場合、部分回転でプロファイルが単純多角形でない場合は `Err`。
+pub fn revolve_profile(
+    profile: &[Point2D<f64>],
+    axis: &InfiniteLine3D<f64>,
+    angle: Angle<f64>,
+    segments: usize,
+) -> Result<TriangleMesh3D<f64>, String> {
+    let sweep = angle.to_radians();
+    if segments == 0 {
+        return Err("Revolve needs at least one segment".to_string());
+    }
+    if sweep == 0.0 || !sweep.is_finite() || sweep.abs() > TAU + FULL_TURN_TOLERANCE {
+        return Err(format!("Invalid revolve angle: {} rad", sweep));
+    }
+    let full_turn = sweep.abs() >= TAU - FULL_TURN_TOLERANCE;
+    if full_turn && segments < 3 {
+        // 1・2分割では周方向の列が重なり、側面が面積ゼロになる
+        return Err(format!(
+            "Full revolve needs at least 3 segments, got {}",
+            segments
+        ));
+    }
+
+    let profile = remove_collinear_vertices(profile);
+    if profile.len() < 3 {
+        return Err("Revolve profile needs at least 3 vertices".to_string());
+    }
+    if let Some(p) = profile.iter().find(|p| p.x() < 0.0) {
+        return Err(format!(
+            "Profile point ({}, {}) is on the negative side of the axis",
+            p.x(),
+            p.y()
+        ));
+    }
+
+    // 軸を法線、基準方向を u 軸とする平面（v 軸 = 軸方向 × u 軸が回転の進む向き）
+    let direction = axis.direction().as_vector();
+    let reference = if direction.x().abs() < 0.9 {
+        Vector3D::new(1.0, 0.0, 0.0)
+    } else {
+        Vector3D::new(0.0, 1.0, 0.0)
+    };
+    let frame = Plane3D::from_origin_and_axes(axis.point(), direction, reference)
+        .ok_or_else(|| "Failed to build revolve frame".to_string())?;
+
+    // 列 s は角度 sweep * s / segments。フル回転では最後の列が最初の列に重なる
+    let columns = if full_turn { segments } else { segments + 1 };
+    let mut vertices = Vec::new();
+    let mut vertex_ids = Vec::with_capacity(profile.len());
+    for p in &profile {
+        let axial = direction * p.y();
+        let first = vertices.len();
+        let count = if p.x() == 0.0 { 1 } else { columns };
+        for s in 0..count {
+            let theta = sweep * s as f64 / segments as f64;
+            vertices.push(frame.local_to_world(p.x() * theta.cos(), p.x() * theta.sin()) + axial);
+        }
+        vertex_ids.push((first, count));
+    }
+    let vertex = |i: usize, s: usize| {
+        let (first, count) = vertex_ids[i];
+        first + (s % columns).min(count - 1)
+    };
+
+    // 反時計回りのプロファイルを正方向に回すと (a, b, c) は内向きになる
+    let inward = (signed_area(&profile) > 0.0) == (sweep > 0.0);
+    let mut indices = Vec::new();
+    let mut push = |[a, b, c]: [usize; 3], reverse: bool| {
+        if a != b && b != c && c != a {
+            indices.push(if reverse { [a, c, b] } else { [a, b, c] });
+        }
+    };
+
+    let n = profile.len();
+    for i in 0..n {
+        let j = (i + 1) % n;
+        for s in 0..segments {
+            let (a, b) = (vertex(i, s), vertex(j, s));
+            let (c, d) = (vertex(j, s + 1), vertex(i, s + 1));
+            push([a, b, c], inward);
+            push([a, c, d], inward);
+        }
+    }
+
+    if !full_turn {
+        // 反時計回りの蓋の三角形は、正方向の回転では始端で外向き、終端で内向き
+        for triangle in triangulate_polygon_2d(&profile)? {
+            let at = |s: usize| triangle.map(|i| vertex(i, s));
+            push(at(0), sweep < 0.0);
+            push(at(segments), sweep > 0.0);
+        }
+    }
+
+    TriangleMesh3D::new(vertices, indices)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{closed_euler_characteristic, polygon, signed_volume};
+    use geo_primitives::Point3D;
+
+    fn z_axis() -> InfiniteLine3D<f64> {
+        InfiniteLine3D::new(Point3D::new(0.0, 0.0, 0.0), Vector3D::new(0.0, 0.0, 1.0)).unwrap()
+    }
+
+    #[test]
+    fn test_full_revolve_cylinder() {
+        // 軸に接する長方形 → 円柱（軸上の辺は潰れて消える）
+        let profile = polygon(&[(0.0, 0.0), (1.0, 0.0), (1.0, 2.0), (0.0, 2.0)]);
+        let segments = 16;
+        let mesh =
+            revolve_profile(&profile, &z_axis(), Angle::from_degrees(360.0), segments).unwrap();
+
+        // 軸上の2頂点 + 外周2列
+        assert_eq!(mesh.vertex_count(), 2 + 2 * segments);
+        assert_eq!(closed_euler_characteristic(&mesh), 2);
+        let polygon_area = segments as f64 / 2.0 * (TAU / segments as f64).sin();
+        assert!((signed_volume(&mesh) - polygon_area * 2.0).abs() < 1e-9);
+
+        // 角度0の列は X 軸方向
+        assert_eq!(mesh.vertex(1), Some(Point3D::new(1.0, 0.0, 0.0)));
+    }
+
+    #[test]
+    fn test_full_revolve_torus_in_both_directions() {
+        // 軸から離れた正方形（時計回り） → トーラス状の閉曲面
+        let profile = polygon(&[(2.0, 0.0), (2.0, 1.0), (3.0, 1.0), (3.0, 0.0)]);
+        for degrees in [360.0, -360.0] {
+            let mesh =
+                revolve_profile(&profile, &z_axis(), Angle::from_degrees(degrees), 12).unwrap();
+            assert_eq!(mesh.vertex_count(), 4 * 12);
+            assert_eq!(closed_euler_characteristic(&mesh), 0);
+            assert!(signed_volume(&mesh) > 0.0);
+        }
+    }
+
+    #[test]
+    fn test_partial_revolve_has_caps() {
+        let axis =
+            InfiniteLine3D::new(Point3D::new(1.0, 1.0, 1.0), Vector3D::new(1.0, 0.0, 0.0)).unwrap();
+        let profile = polygon(&[(0.0, 0.0), (1.0, 0.0), (1.0, 2.0), (0.0, 2.0)]);
+        let segments = 6;
+        for degrees in [90.0, -90.0] {
+            let mesh =
+                revolve_profile(&profile, &axis, Angle::from_degrees(degrees), segments).unwrap();
+
+            assert_eq!(mesh.vertex_count(), 2 + 2 * (segments + 1));
+            assert_eq!(closed_euler_characteristic(&mesh), 2);
+            // 扇形柱の体積（弦で近似した扇形の面積 × 高さ）
+            let step = std::f64::consts::FRAC_PI_2 / segments as f64;
+            let expected = segments as f64 * 0.5 * step.sin() * 2.0;
+            assert!((signed_volume(&mesh) - expected).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_revolve_invalid_input() {
+        let profile = polygon(&[(0.0, 0.0), (1.0, 0.0), (1.0, 1.0)]);
+        let full = Angle::from_degrees(360.0);
+        assert!(revolve_profile(&profile, &z_axis(), full, 0).is_err());
+        // フル回転は3分割以上が必要（部分回転なら1分割でもよい）
+        assert!(revolve_profile(&profile, &z_axis(), full, 1).is_err());
+        assert!(revolve_profile(&profile, &z_axis(), full, 2).is_err());
+        assert!(revolve_profile(&profile, &z_axis(), full, 3).is_ok());
+        assert!(revolve_profile(&profile, &z_axis(), Angle::from_degrees(90.0), 1).is_ok());
+        assert!(revolve_profile(&profile, &z_axis(), Angle::from_degrees(0.0), 8).is_err());
+        assert!(revolve_profile(&profile, &z_axis(), Angle::from_degrees(400.0), 8).is_err());
+
+        let crossing = polygon(&[(-1.0, 0.0), (1.0, 0.0), (1.0, 1.0)]);
+        assert!(revolve_profile(&crossing, &z_axis(), full, 8).is_err());
+
+        let bowtie = polygon(&[(0.0, 0.0), (1.0, 1.0), (1.0, 0.0), (0.0, 1.0)]);
+        assert!(revolve_profile(&bowtie, &z_axis(), Angle::from_degrees(90.0), 8).is_err());
+    }
+}
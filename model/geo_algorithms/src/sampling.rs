@@ -1,7 +1,7 @@
 /// 幾何サンプリングとパターン解析
 ///
 /// 2D/3D幾何要素のサンプリング、交差検出、パターン抽出機能
-use geo_foundation::ToleranceContext;
+use geo_primitives::{Point2D, Point3D, TriangleMesh3D};
 
 /// サンプリング結果
 #[derive(Debug, Clone)]
@@ -22,7 +22,7 @@ pub struct QualityMetrics {
 /// 交差候補
 #[derive(Debug, Clone)]
 pub struct IntersectionCandidate {
-    pub point: Point2D,
+    pub point: Point2D<f64>,
     pub parameter: f64,
     pub distance: f64,
     pub confidence: f64,
@@ -30,15 +30,16 @@ pub struct IntersectionCandidate {
 
 /// 適応的サンプリング
 pub struct AdaptiveSampler {
-    tolerance: ToleranceContext,
+    curvature_tolerance: f64,
     max_recursion: usize,
     min_samples: usize,
 }
 
 impl AdaptiveSampler {
-    pub fn new(tolerance: ToleranceContext) -> Self {
+    /// `curvature_tolerance` の逆数を超える曲率の区間を細分する
+    pub fn new(curvature_tolerance: f64) -> Self {
         Self {
-            tolerance,
+            curvature_tolerance,
             max_recursion: 8,
             min_samples: 10,
         }
@@ -51,9 +52,9 @@ impl AdaptiveSampler {
         curvature_fn: G,
         start: f64,
         end: f64,
-    ) -> SamplingResult<Point2D>
+    ) -> SamplingResult<Point2D<f64>>
     where
-        F: Fn(f64) -> Point2D,
+        F: Fn(f64) -> Point2D<f64>,
         G: Fn(f64) -> f64,
     {
         let mut points = Vec::new();
@@ -78,6 +79,7 @@ impl AdaptiveSampler {
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn sample_recursive<F, G>(
         &self,
         evaluator: &F,
@@ -85,10 +87,10 @@ impl AdaptiveSampler {
         start: f64,
         end: f64,
         depth: usize,
-        points: &mut Vec<Point2D>,
+        points: &mut Vec<Point2D<f64>>,
         parameters: &mut Vec<f64>,
     ) where
-        F: Fn(f64) -> Point2D,
+        F: Fn(f64) -> Point2D<f64>,
         G: Fn(f64) -> f64,
     {
         // 常に開始点を追加（初回のみ）
@@ -109,7 +111,7 @@ impl AdaptiveSampler {
 
         // 高曲率領域では細かくサンプリング
         // 許容誤差を調整：曲率の逆数を使用してより実用的な閾値にする
-        let curvature_threshold = 1.0 / self.tolerance.curvature; // 1000.0 相当
+        let curvature_threshold = 1.0 / self.curvature_tolerance;
         let parametric_threshold = (end - start) > (2.0 * std::f64::consts::PI / 10.0); // パラメータ範囲の1/10
 
         let should_subdivide = curvature.abs() > curvature_threshold || parametric_threshold;
@@ -143,7 +145,11 @@ impl AdaptiveSampler {
         }
     }
 
-    fn calculate_quality_metrics(&self, points: &[Point2D], parameters: &[f64]) -> QualityMetrics {
+    fn calculate_quality_metrics(
+        &self,
+        points: &[Point2D<f64>],
+        parameters: &[f64],
+    ) -> QualityMetrics {
         if points.len() < 2 {
             return QualityMetrics {
                 uniformity_score: 0.0,
@@ -155,7 +161,7 @@ impl AdaptiveSampler {
         // 距離の均一性を計算
         let mut distances = Vec::new();
         for i in 1..points.len() {
-            let dist = points[i].distance_to(&points[i - 1]).value();
+            let dist = points[i].distance_to(&points[i - 1]);
             distances.push(dist);
         }
 
@@ -195,23 +201,23 @@ impl MonteCarloSampler {
     /// 領域内のランダムサンプリング
     pub fn sample_region_2d(
         &self,
-        bounds: (Point2D, Point2D), // min, max
+        bounds: (Point2D<f64>, Point2D<f64>), // min, max
         sample_count: usize,
-        filter: impl Fn(&Point2D) -> bool,
-    ) -> Vec<Point2D> {
+        filter: impl Fn(&Point2D<f64>) -> bool,
+    ) -> Vec<Point2D<f64>> {
         let mut rng = SimpleRng::new(self.seed);
         let mut results = Vec::new();
 
-        let min_x = bounds.0.x().value();
-        let min_y = bounds.0.y().value();
-        let max_x = bounds.1.x().value();
-        let max_y = bounds.1.y().value();
+        let min_x = bounds.0.x();
+        let min_y = bounds.0.y();
+        let max_x = bounds.1.x();
+        let max_y = bounds.1.y();
 
         let mut attempts = 0;
         while results.len() < sample_count && attempts < sample_count * 10 {
             let x = min_x + rng.next_f64() * (max_x - min_x);
             let y = min_y + rng.next_f64() * (max_y - min_y);
-            let point = Point2D::from_f64(x, y);
+            let point = Point2D::new(x, y);
 
             if filter(&point) {
                 results.push(point);
@@ -223,13 +229,59 @@ impl MonteCarloSampler {
     }
 }
 
+/// 三角形メッシュ表面から面積比例で一様にランダムな点をサンプリング
+///
+/// 累積面積分布から三角形を選び、重心座標の平方根法
+/// `P = (1-√r1)A + √r1(1-r2)B + √r1·r2·C` で三角形内の一様な点を生成する。
+/// 同じ `seed` なら同じ点列を返す。面積ゼロの三角形は選ばれず、
+/// 表面積が0のメッシュや `n` が0の場合は空を返す。
+pub fn sample_surface_uniform(
+    mesh: &TriangleMesh3D<f64>,
+    n: usize,
+    seed: u64,
+) -> Vec<Point3D<f64>> {
+    let triangles: Vec<_> = (0..mesh.triangle_count())
+        .filter_map(|index| mesh.triangle(index))
+        .collect();
+
+    let mut cumulative_areas = Vec::with_capacity(triangles.len());
+    let mut total_area = 0.0;
+    for triangle in &triangles {
+        total_area += triangle.area();
+        cumulative_areas.push(total_area);
+    }
+    if n == 0 || total_area <= 0.0 {
+        return Vec::new();
+    }
+
+    let mut rng = SimpleRng::new(seed);
+    (0..n)
+        .map(|_| {
+            let target = rng.next_f64() * total_area;
+            let index = cumulative_areas
+                .partition_point(|&area| area <= target)
+                .min(triangles.len() - 1);
+            let [a, b, c] = triangles[index].vertices();
+
+            let sqrt_r1 = rng.next_f64().sqrt();
+            let r2 = rng.next_f64();
+            let (wa, wb, wc) = (1.0 - sqrt_r1, sqrt_r1 * (1.0 - r2), sqrt_r1 * r2);
+            Point3D::new(
+                wa * a.x() + wb * b.x() + wc * c.x(),
+                wa * a.y() + wb * b.y() + wc * c.y(),
+                wa * a.z() + wb * b.z() + wc * c.z(),
+            )
+        })
+        .collect()
+}
+
 /// シンプルな擬似乱数生成器
-struct SimpleRng {
+pub(crate) struct SimpleRng {
     state: u64,
 }
 
 impl SimpleRng {
-    fn new(seed: u64) -> Self {
+    pub(crate) fn new(seed: u64) -> Self {
         Self { state: seed }
     }
 
@@ -238,7 +290,7 @@ impl SimpleRng {
         self.state
     }
 
-    fn next_f64(&mut self) -> f64 {
+    pub(crate) fn next_f64(&mut self) -> f64 {
         (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
     }
 }
@@ -257,18 +309,18 @@ impl PoissonDiskSampler {
     /// 均等分布サンプリング
     pub fn sample_uniform_2d(
         &self,
-        bounds: (Point2D, Point2D),
-        initial_seed: Option<Point2D>,
-    ) -> Vec<Point2D> {
+        bounds: (Point2D<f64>, Point2D<f64>),
+        initial_seed: Option<Point2D<f64>>,
+    ) -> Vec<Point2D<f64>> {
         let mut rng = SimpleRng::new(42);
         let mut points = Vec::new();
         let mut active_list = Vec::new();
 
         // 初期点
         let first_point = initial_seed.unwrap_or_else(|| {
-            let center_x = (bounds.0.x().value() + bounds.1.x().value()) * 0.5;
-            let center_y = (bounds.0.y().value() + bounds.1.y().value()) * 0.5;
-            Point2D::from_f64(center_x, center_y)
+            let center_x = (bounds.0.x() + bounds.1.x()) * 0.5;
+            let center_y = (bounds.0.y() + bounds.1.y()) * 0.5;
+            Point2D::new(center_x, center_y)
         });
 
         points.push(first_point);
@@ -284,9 +336,9 @@ impl PoissonDiskSampler {
                 let angle = rng.next_f64() * 2.0 * std::f64::consts::PI;
                 let distance = self.radius * (1.0 + rng.next_f64());
 
-                let new_x = point.x().value() + distance * angle.cos();
-                let new_y = point.y().value() + distance * angle.sin();
-                let new_point = Point2D::from_f64(new_x, new_y);
+                let new_x = point.x() + distance * angle.cos();
+                let new_y = point.y() + distance * angle.sin();
+                let new_point = Point2D::new(new_x, new_y);
 
                 if self.is_valid_point(&new_point, &points, bounds) {
                     points.push(new_point);
@@ -306,22 +358,22 @@ impl PoissonDiskSampler {
 
     fn is_valid_point(
         &self,
-        point: &Point2D,
-        existing_points: &[Point2D],
-        bounds: (Point2D, Point2D),
+        point: &Point2D<f64>,
+        existing_points: &[Point2D<f64>],
+        bounds: (Point2D<f64>, Point2D<f64>),
     ) -> bool {
         // 境界チェック
-        if point.x().value() < bounds.0.x().value()
-            || point.x().value() > bounds.1.x().value()
-            || point.y().value() < bounds.0.y().value()
-            || point.y().value() > bounds.1.y().value()
+        if point.x() < bounds.0.x()
+            || point.x() > bounds.1.x()
+            || point.y() < bounds.0.y()
+            || point.y() > bounds.1.y()
         {
             return false;
         }
 
         // 距離チェック
         for existing in existing_points {
-            if existing.distance_to(point).value() < self.radius {
+            if existing.distance_to(point) < self.radius {
                 return false;
             }
         }
@@ -329,3 +381,84 @@ impl PoissonDiskSampler {
         true
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// z=0 上の [0,1]×[0,1] と、z=1 上の [0,3]×[0,1] の2枚の長方形
+    fn two_rectangles() -> TriangleMesh3D<f64> {
+        TriangleMesh3D::new(
+            vec![
+                Point3D::new(0.0, 0.0, 0.0),
+                Point3D::new(1.0, 0.0, 0.0),
+                Point3D::new(1.0, 1.0, 0.0),
+                Point3D::new(0.0, 1.0, 0.0),
+                Point3D::new(0.0, 0.0, 1.0),
+                Point3D::new(3.0, 0.0, 1.0),
+                Point3D::new(3.0, 1.0, 1.0),
+                Point3D::new(0.0, 1.0, 1.0),
+            ],
+            vec![[0, 1, 2], [0, 2, 3], [4, 5, 6], [4, 6, 7]],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_surface_samples_are_reproducible_and_on_surface() {
+        let mesh = two_rectangles();
+        let samples = sample_surface_uniform(&mesh, 500, 7);
+        assert_eq!(samples.len(), 500);
+        assert_eq!(samples, sample_surface_uniform(&mesh, 500, 7));
+        assert_ne!(samples, sample_surface_uniform(&mesh, 500, 8));
+
+        // 重心座標の丸め誤差を許容する
+        let eps = 1e-12;
+        for point in &samples {
+            let on_upper = (point.z() - 1.0).abs() < eps;
+            assert!(on_upper || point.z().abs() < eps);
+            let max_x = if on_upper { 3.0 } else { 1.0 };
+            assert!(point.x() > -eps && point.x() < max_x + eps);
+            assert!(point.y() > -eps && point.y() < 1.0 + eps);
+        }
+    }
+
+    #[test]
+    fn test_surface_samples_are_area_weighted_and_uniform() {
+        let samples = sample_surface_uniform(&two_rectangles(), 20_000, 42);
+
+        // 面積比 1:3
+        let lower = samples.iter().filter(|p| p.z() < 0.5).count() as f64;
+        assert!(
+            (lower / 20_000.0 - 0.25).abs() < 0.02,
+            "ratio = {}",
+            lower / 20_000.0
+        );
+
+        // 三角形内でも一様：下側の正方形の左半分には約半数
+        let left_half = samples
+            .iter()
+            .filter(|p| p.z() < 0.5 && p.x() < 0.5)
+            .count() as f64;
+        assert!((left_half / lower - 0.5).abs() < 0.04);
+    }
+
+    #[test]
+    fn test_surface_sampling_degenerate_inputs() {
+        let mesh = two_rectangles();
+        assert!(sample_surface_uniform(&mesh, 0, 1).is_empty());
+        assert!(sample_surface_uniform(&TriangleMesh3D::empty(), 10, 1).is_empty());
+
+        // 面積ゼロの三角形だけのメッシュ
+        let flat = TriangleMesh3D::new(
+            vec![
+                Point3D::new(0.0, 0.0, 0.0),
+                Point3D::new(1.0, 0.0, 0.0),
+                Point3D::new(2.0, 0.0, 0.0),
+            ],
+            vec![[0, 1, 2]],
+        )
+        .unwrap();
+        assert!(sample_surface_uniform(&flat, 10, 1).is_empty());
+    }
+}
@@ -1,9 +1,14 @@
 /// 統計処理機能
 ///
 /// 基本統計量の計算、分布解析、回帰分析を提供する
-use geo_foundation::{Scalar, ToleranceContext};
+use crate::sampling::SimpleRng;
+use analysis::linalg::matrix::Matrix3x3;
+use geo_foundation::Scalar;
+use geo_primitives::{Plane3D, Point2D, Point3D, Vector2D, Vector3D};
 
 /// 基本統計量
+///
+/// 分散は母分散（n で割る）。分位数計算のためにソート済みの値を保持する。
 #[derive(Debug, Clone)]
 pub struct BasicStats {
     pub mean: f64,
@@ -12,12 +17,47 @@ pub struct BasicStats {
     pub min: f64,
     pub max: f64,
     pub count: usize,
+    sorted: Vec<f64>,
+}
+
+/// 五数要約と外れ値閾値（Tukey のフェンス）
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FiveNumberSummary {
+    pub min: f64,
+    /// 第1四分位数
+    pub q1: f64,
+    pub median: f64,
+    /// 第3四分位数
+    pub q3: f64,
+    pub max: f64,
+    /// 四分位範囲 `q3 - q1`
+    pub iqr: f64,
+    /// 下側外れ値閾値 `q1 - 1.5 * iqr`
+    pub lower_fence: f64,
+    /// 上側外れ値閾値 `q3 + 1.5 * iqr`
+    pub upper_fence: f64,
+}
+
+impl FiveNumberSummary {
+    /// フェンスの外側にある値か
+    pub fn is_outlier(&self, value: f64) -> bool {
+        value < self.lower_fence || value > self.upper_fence
+    }
 }
 
 impl BasicStats {
     /// スカラー値の配列から統計量を計算
-    pub fn from_scalars(values: &[Scalar]) -> Self {
-        if values.is_empty() {
+    pub fn from_scalars<T: Scalar>(values: &[T]) -> Self {
+        let vals: Vec<f64> = values.iter().map(|s| s.to_f64()).collect();
+        Self::from_f64_slice(&vals)
+    }
+
+    /// f64配列から統計量を計算
+    ///
+    /// 空配列ではすべての統計量を0とする。
+    pub fn from_f64_slice(values: &[f64]) -> Self {
+        let count = values.len();
+        if count == 0 {
             return Self {
                 mean: 0.0,
                 variance: 0.0,
@@ -25,16 +65,10 @@ impl BasicStats {
                 min: 0.0,
                 max: 0.0,
                 count: 0,
+                sorted: Vec::new(),
             };
         }
 
-        let vals: Vec<f64> = values.iter().map(|s| s.value()).collect();
-        Self::from_f64_slice(&vals)
-    }
-
-    /// f64配列から統計量を計算
-    pub fn from_f64_slice(values: &[f64]) -> Self {
-        let count = values.len();
         let sum: f64 = values.iter().sum();
         let mean = sum / count as f64;
 
@@ -44,6 +78,9 @@ impl BasicStats {
         let min = values.iter().copied().fold(f64::INFINITY, f64::min);
         let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
 
+        let mut sorted = values.to_vec();
+        sorted.sort_by(f64::total_cmp);
+
         Self {
             mean,
             variance,
@@ -51,40 +88,239 @@ impl BasicStats {
             min,
             max,
             count,
+            sorted,
+        }
+    }
+
+    /// 任意分位数（Hyndman-Fan type 7、R / NumPy の既定と同じ線形補間）
+    ///
+    /// ソート済みの値 x_0..x_{n-1} に対し h = (n-1)q として
+    /// x_⌊h⌋ + (h - ⌊h⌋)(x_⌊h⌋+1 - x_⌊h⌋) を返す。`q` は [0, 1] に丸める。
+    /// 空データでは0、単一要素ではその値を返す。
+    pub fn quantile(&self, q: f64) -> f64 {
+        let Some(&last) = self.sorted.last() else {
+            return 0.0;
+        };
+        let q = if q.is_nan() { 0.5 } else { q.clamp(0.0, 1.0) };
+
+        let h = (self.sorted.len() - 1) as f64 * q;
+        let lower = h.floor() as usize;
+        if lower + 1 >= self.sorted.len() {
+            return last;
+        }
+        let fraction = h - lower as f64;
+        self.sorted[lower] + fraction * (self.sorted[lower + 1] - self.sorted[lower])
+    }
+
+    /// 中央値
+    pub fn median(&self) -> f64 {
+        self.quantile(0.5)
+    }
+
+    /// 五数要約（最小・四分位・中央値・最大）と 1.5×IQR の外れ値閾値
+    pub fn five_number_summary(&self) -> FiveNumberSummary {
+        let q1 = self.quantile(0.25);
+        let q3 = self.quantile(0.75);
+        let iqr = q3 - q1;
+        FiveNumberSummary {
+            min: self.min,
+            q1,
+            median: self.median(),
+            q3,
+            max: self.max,
+            iqr,
+            lower_fence: q1 - 1.5 * iqr,
+            upper_fence: q3 + 1.5 * iqr,
         }
     }
 }
 
+/// `Histogram::auto` のビン数の上限（外れ値でビン数が爆発するのを防ぐ）
+const MAX_AUTO_BINS: usize = 1024;
+
+/// 数値列のヒストグラム
+///
+/// ビンは範囲 [lower, upper] を等幅に分割し、各ビンは下端を含み上端を含まない
+/// （最後のビンのみ上端を含む）。NaN・無限大は集計から除く。
+///
+/// [`Histogram::new`]・[`Histogram::auto`] はデータの最小値から最大値までを範囲とし、
+/// 有効な値がない場合は範囲 [0, 1]、全値が等しい場合はその値を中心とした幅1の範囲を使う。
+/// 範囲を固定して値を逐次追加する場合は [`Histogram::with_range`] と [`Histogram::add`] を使う。
+#[derive(Debug, Clone, PartialEq)]
+pub struct Histogram {
+    edges: Vec<f64>,
+    counts: Vec<usize>,
+    total: usize,
+}
+
+impl Histogram {
+    /// ビン数を指定してヒストグラムを構築（`bins` が0なら1）
+    pub fn new(data: &[f64], bins: usize) -> Self {
+        let values: Vec<f64> = data.iter().copied().filter(|v| v.is_finite()).collect();
+        Self::from_finite(&values, bins.max(1))
+    }
+
+    /// Freedman-Diaconis 則でビン数を自動決定して構築
+    ///
+    /// ビン幅 h = 2·IQR·n^(-1/3)、ビン数 = ⌈(max - min) / h⌉（上限 1024）。
+    /// IQR が0（値の大半が等しい）の場合は Sturges 則 ⌈log2 n⌉ + 1 に切り替える。
+    pub fn auto(data: &[f64]) -> Self {
+        let values: Vec<f64> = data.iter().copied().filter(|v| v.is_finite()).collect();
+        let bins = freedman_diaconis_bins(&values);
+        Self::from_finite(&values, bins)
+    }
+
+    /// 範囲 [lower, upper] を `bins` 等分した空のヒストグラム（`bins` が0なら1）
+    ///
+    /// `upper <= lower`（幅0の範囲）の場合、追加した値はすべて先頭ビンに入る。
+    pub fn with_range(lower: f64, upper: f64, bins: usize) -> Self {
+        let bins = bins.max(1);
+        let width = (upper - lower) / bins as f64;
+        let mut edges: Vec<f64> = (0..bins).map(|i| lower + width * i as f64).collect();
+        edges.push(upper);
+        Self {
+            edges,
+            counts: vec![0; bins],
+            total: 0,
+        }
+    }
+
+    /// 値を1つ追加
+    ///
+    /// 範囲外の値は両端のビンに数える。NaN・無限大は数えない。
+    pub fn add(&mut self, value: f64) {
+        if !value.is_finite() {
+            return;
+        }
+        let last = self.counts.len() - 1;
+        let width = self.bin_width();
+        let index = if width > 0.0 {
+            ((value - self.edges[0]) / width)
+                .floor()
+                .clamp(0.0, last as f64) as usize
+        } else {
+            0
+        };
+        self.counts[index] += 1;
+        self.total += 1;
+    }
+
+    fn from_finite(values: &[f64], bins: usize) -> Self {
+        let (min, max) = values
+            .iter()
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), &v| {
+                (lo.min(v), hi.max(v))
+            });
+        let (lower, upper) = if values.is_empty() {
+            (0.0, 1.0)
+        } else if max > min {
+            (min, max)
+        } else {
+            (min - 0.5, min + 0.5)
+        };
+
+        let mut histogram = Self::with_range(lower, upper, bins);
+        for &v in values {
+            histogram.add(v);
+        }
+        histogram
+    }
+
+    /// ビン数
+    pub fn bin_count(&self) -> usize {
+        self.counts.len()
+    }
+
+    /// ビン境界（ビン数 + 1 個、昇順）
+    pub fn bin_edges(&self) -> &[f64] {
+        &self.edges
+    }
+
+    /// 各ビンの中央値
+    pub fn bin_centers(&self) -> Vec<f64> {
+        self.edges.windows(2).map(|e| (e[0] + e[1]) / 2.0).collect()
+    }
+
+    /// ビン幅（全ビン共通）
+    pub fn bin_width(&self) -> f64 {
+        self.edges[1] - self.edges[0]
+    }
+
+    /// 各ビンの頻度
+    pub fn counts(&self) -> &[usize] {
+        &self.counts
+    }
+
+    /// 集計した値の総数（NaN・無限大を除く）
+    pub fn total(&self) -> usize {
+        self.total
+    }
+
+    /// 相対頻度（合計1、データがなければすべて0）
+    pub fn normalized(&self) -> Vec<f64> {
+        if self.total == 0 {
+            return vec![0.0; self.counts.len()];
+        }
+        let total = self.total as f64;
+        self.counts.iter().map(|&c| c as f64 / total).collect()
+    }
+
+    /// 確率密度（相対頻度 / ビン幅、面積の合計1）
+    pub fn density(&self) -> Vec<f64> {
+        let width = self.bin_width();
+        self.normalized().into_iter().map(|f| f / width).collect()
+    }
+}
+
+/// Freedman-Diaconis 則によるビン数（IQR が0なら Sturges 則）
+fn freedman_diaconis_bins(values: &[f64]) -> usize {
+    let n = values.len();
+    if n < 2 {
+        return 1;
+    }
+    let stats = BasicStats::from_f64_slice(values);
+    let range = stats.max - stats.min;
+    if range <= 0.0 {
+        return 1;
+    }
+
+    let iqr = stats.quantile(0.75) - stats.quantile(0.25);
+    let bins = if iqr > 0.0 {
+        let width = 2.0 * iqr / (n as f64).cbrt();
+        (range / width).ceil()
+    } else {
+        (n as f64).log2().ceil() + 1.0
+    };
+    (bins as usize).clamp(1, MAX_AUTO_BINS)
+}
+
 /// 2D点群の統計解析
 pub struct PointCluster {
-    tolerance: ToleranceContext,
+    tolerance: f64,
 }
 
 impl PointCluster {
-    pub fn new(tolerance: ToleranceContext) -> Self {
+    pub fn new(tolerance: f64) -> Self {
         Self { tolerance }
     }
 
     /// 重心の計算
-    pub fn centroid(&self, points: &[Point2D]) -> Option<Point2D> {
+    pub fn centroid(&self, points: &[Point2D<f64>]) -> Option<Point2D<f64>> {
         if points.is_empty() {
             return None;
         }
 
-        let sum_x: f64 = points.iter().map(|p| p.x().value()).sum();
-        let sum_y: f64 = points.iter().map(|p| p.y().value()).sum();
+        let sum_x: f64 = points.iter().map(|p| p.x()).sum();
+        let sum_y: f64 = points.iter().map(|p| p.y()).sum();
         let count = points.len() as f64;
 
-        Some(Point2D::from_f64(sum_x / count, sum_y / count))
+        Some(Point2D::new(sum_x / count, sum_y / count))
     }
 
     /// 点群の分散（重心からの距離の分散）
-    pub fn variance(&self, points: &[Point2D]) -> f64 {
+    pub fn variance(&self, points: &[Point2D<f64>]) -> f64 {
         if let Some(centroid) = self.centroid(points) {
-            let distances: Vec<f64> = points
-                .iter()
-                .map(|p| centroid.distance_to(p).value())
-                .collect();
+            let distances: Vec<f64> = points.iter().map(|p| centroid.distance_to(p)).collect();
 
             BasicStats::from_f64_slice(&distances).variance
         } else {
@@ -93,7 +329,7 @@ impl PointCluster {
     }
 
     /// 主成分分析による主軸方向
-    pub fn principal_axes(&self, points: &[Point2D]) -> Option<(Point2D, f64)> {
+    pub fn principal_axes(&self, points: &[Point2D<f64>]) -> Option<(Vector2D<f64>, f64)> {
         if points.len() < 2 {
             return None;
         }
@@ -106,8 +342,8 @@ impl PointCluster {
         let mut cxy = 0.0;
 
         for point in points {
-            let dx = point.x().value() - centroid.x().value();
-            let dy = point.y().value() - centroid.y().value();
+            let dx = point.x() - centroid.x();
+            let dy = point.y() - centroid.y();
             cxx += dx * dx;
             cyy += dy * dy;
             cxy += dx * dy;
@@ -128,22 +364,206 @@ impl PointCluster {
         }
 
         let lambda1 = (trace + discriminant.sqrt()) / 2.0;
-        let _lambda2 = (trace - discriminant.sqrt()) / 2.0;
 
         // 第1主成分方向
-        let direction = if cxy.abs() > self.tolerance.linear {
-            let angle = (2.0 * cxy / (cxx - cyy)).atan() / 2.0;
-            Point2D::from_f64(angle.cos(), angle.sin())
+        let direction = if cxy.abs() > self.tolerance {
+            let angle = (2.0 * cxy).atan2(cxx - cyy) / 2.0;
+            Vector2D::new(angle.cos(), angle.sin())
         } else if cxx > cyy {
-            Point2D::from_f64(1.0, 0.0)
+            Vector2D::new(1.0, 0.0)
         } else {
-            Point2D::from_f64(0.0, 1.0)
+            Vector2D::new(0.0, 1.0)
         };
 
         Some((direction, lambda1))
     }
 }
 
+/// k-means の反復を打ち切る中心移動量（点群の広がりに対する比）
+const KMEANS_CONVERGENCE_RATIO: f64 = 1e-9;
+
+/// 3D点群のクラスタ（`kmeans_3d` の結果）
+#[derive(Debug, Clone, PartialEq)]
+pub struct PointCluster3D {
+    /// クラスタ中心（所属点の重心）
+    pub centroid: Point3D<f64>,
+    /// 所属する点の（元の点群での）インデックス
+    pub indices: Vec<usize>,
+}
+
+/// 3D点群の k-means クラスタリング
+///
+/// k-means++ で初期中心を選び、全中心の移動量が点群の広がりの
+/// `KMEANS_CONVERGENCE_RATIO` 倍未満になるか `max_iter` 回に達するまで
+/// 割り当てと重心更新を繰り返す。空になったクラスタは、自分の中心から最も遠い点で
+/// 中心を置き直す。`k` は点数で頭打ちにし、点群が空か `k` が0なら空を返す。
+/// 同じ `seed` なら同じ結果を返す。
+pub fn kmeans_3d(
+    points: &[Point3D<f64>],
+    k: usize,
+    max_iter: usize,
+    seed: u64,
+) -> Vec<PointCluster3D> {
+    let k = k.min(points.len());
+    if k == 0 {
+        return Vec::new();
+    }
+
+    let mut rng = SimpleRng::new(seed);
+    let mut centers = kmeans_plus_plus(points, k, &mut rng);
+    let mut assignment = assign_to_centers(points, &centers);
+
+    let threshold = KMEANS_CONVERGENCE_RATIO * point_cloud_extent(points).max(1.0);
+    for _ in 0..max_iter {
+        let mut sums = vec![(0.0, 0.0, 0.0, 0usize); k];
+        for (point, &cluster) in points.iter().zip(&assignment) {
+            let sum = &mut sums[cluster];
+            sum.0 += point.x();
+            sum.1 += point.y();
+            sum.2 += point.z();
+            sum.3 += 1;
+        }
+
+        // 空クラスタ：現在の割り当てで中心から遠い点に、それぞれ別の点を選んで置き直す
+        let empty_count = sums.iter().filter(|sum| sum.3 == 0).count();
+        let mut reseeds = reseed_indices(points, &centers, &assignment, empty_count).into_iter();
+
+        let mut max_shift: f64 = 0.0;
+        for (cluster, &(x, y, z, count)) in sums.iter().enumerate() {
+            let center = if count == 0 {
+                points[reseeds.next().expect("one reseed point per empty cluster")]
+            } else {
+                let count = count as f64;
+                Point3D::new(x / count, y / count, z / count)
+            };
+            max_shift = max_shift.max(center.distance_to(&centers[cluster]));
+            centers[cluster] = center;
+        }
+
+        assignment = assign_to_centers(points, &centers);
+        if max_shift < threshold {
+            break;
+        }
+    }
+
+    let mut clusters: Vec<PointCluster3D> = centers
+        .into_iter()
+        .map(|centroid| PointCluster3D {
+            centroid,
+            indices: Vec::new(),
+        })
+        .collect();
+    for (index, &cluster) in assignment.iter().enumerate() {
+        clusters[cluster].indices.push(index);
+    }
+    clusters
+}
+
+/// k-means++ による初期中心（既存の中心からの距離の2乗に比例した確率で選ぶ）
+fn kmeans_plus_plus(points: &[Point3D<f64>], k: usize, rng: &mut SimpleRng) -> Vec<Point3D<f64>> {
+    let first = ((rng.next_f64() * points.len() as f64) as usize).min(points.len() - 1);
+    let mut centers = vec![points[first]];
+    let mut nearest_squared: Vec<f64> = points
+        .iter()
+        .map(|p| p.distance_squared_to(&points[first]))
+        .collect();
+
+    while centers.len() < k {
+        let total: f64 = nearest_squared.iter().sum();
+        let chosen = if total > 0.0 {
+            let mut target = rng.next_f64() * total;
+            nearest_squared
+                .iter()
+                .position(|&d| {
+                    target -= d;
+                    target < 0.0 && d > 0.0
+                })
+                .unwrap_or_else(|| farthest_index(&nearest_squared))
+        } else {
+            // 全点が既存の中心と一致（重複点のみ）
+            centers.len()
+        };
+
+        let center = points[chosen];
+        for (d, point) in nearest_squared.iter_mut().zip(points) {
+            *d = d.min(point.distance_squared_to(&center));
+        }
+        centers.push(center);
+    }
+    centers
+}
+
+/// 各点を最も近い中心に割り当てる
+fn assign_to_centers(points: &[Point3D<f64>], centers: &[Point3D<f64>]) -> Vec<usize> {
+    points
+        .iter()
+        .map(|point| {
+            centers
+                .iter()
+                .map(|center| point.distance_squared_to(center))
+                .enumerate()
+                .fold(
+                    (0, f64::INFINITY),
+                    |best, (i, d)| if d < best.1 { (i, d) } else { best },
+                )
+                .0
+        })
+        .collect()
+}
+
+/// 割り当て先の中心から最も遠い点
+/// 割り当て先の中心から遠い順に `count` 個の点のインデックスを選ぶ（同じ点は2度選ばない）
+fn reseed_indices(
+    points: &[Point3D<f64>],
+    centers: &[Point3D<f64>],
+    assignment: &[usize],
+    count: usize,
+) -> Vec<usize> {
+    if count == 0 {
+        return Vec::new();
+    }
+    let mut distances: Vec<f64> = points
+        .iter()
+        .zip(assignment)
+        .map(|(point, &cluster)| point.distance_squared_to(&centers[cluster]))
+        .collect();
+    (0..count)
+        .map(|_| {
+            let chosen = farthest_index(&distances);
+            distances[chosen] = f64::NEG_INFINITY;
+            chosen
+        })
+        .collect()
+}
+
+fn farthest_index(distances: &[f64]) -> usize {
+    distances
+        .iter()
+        .enumerate()
+        .fold((0, f64::NEG_INFINITY), |best, (i, &d)| {
+            if d > best.1 {
+                (i, d)
+            } else {
+                best
+            }
+        })
+        .0
+}
+
+/// 点群のバウンディングボックスの対角長
+fn point_cloud_extent(points: &[Point3D<f64>]) -> f64 {
+    let (min, max) = points.iter().fold(
+        ([f64::INFINITY; 3], [f64::NEG_INFINITY; 3]),
+        |(min, max), p| {
+            (
+                [min[0].min(p.x()), min[1].min(p.y()), min[2].min(p.z())],
+                [max[0].max(p.x()), max[1].max(p.y()), max[2].max(p.z())],
+            )
+        },
+    );
+    Point3D::new(min[0], min[1], min[2]).distance_to(&Point3D::new(max[0], max[1], max[2]))
+}
+
 /// 回帰分析結果
 #[derive(Debug, Clone)]
 pub struct RegressionResult {
@@ -151,3 +571,464 @@ pub struct RegressionResult {
     pub r_squared: f64,
     pub residual_sum_squares: f64,
 }
+
+impl RegressionResult {
+    /// 係数 `[a, b, c]` を平面 `z = ax + by + c` として返す
+    ///
+    /// `fit_plane_least_squares` の結果に対して使う。係数が3つでなければ `None`。
+    /// 法線は `(-a, -b, 1)` 方向（z成分が正）に揃える。
+    pub fn to_plane(&self) -> Option<Plane3D<f64>> {
+        let &[a, b, c] = self.coefficients.as_slice() else {
+            return None;
+        };
+        Plane3D::from_point_and_normal(Point3D::new(0.0, 0.0, c), Vector3D::new(-a, -b, 1.0))
+    }
+}
+
+/// 平面フィッティングの退化判定に使う相対許容誤差
+const PLANE_FIT_RELATIVE_TOLERANCE: f64 = 1e-12;
+
+/// 3D点群への平面回帰（推奨：全軸対称な Total Least Squares）
+///
+/// `fit_plane_total_least_squares` と同じ。垂直な平面も扱えるため、
+/// 計測面のフィッティングには通常こちらを使う。
+pub fn fit_plane_regression(points: &[Point3D<f64>]) -> Option<(Plane3D<f64>, f64)> {
+    fit_plane_total_least_squares(points)
+}
+
+/// 最小二乗法で `z = ax + by + c` を当てはめる
+///
+/// z方向の残差の2乗和を最小化する。係数は `[a, b, c]`、決定係数は
+/// `1 - SS_res / SS_tot`（z が一定なら残差0で1、そうでなければ0）。
+/// 点が3未満、または xy 平面への射影が1直線上に並ぶ（垂直な平面を含む）場合は `None`。
+pub fn fit_plane_least_squares(points: &[Point3D<f64>]) -> Option<RegressionResult> {
+    if points.len() < 3 {
+        return None;
+    }
+    let (centroid, covariance) = centroid_and_covariance(points);
+    let [[sxx, sxy, sxz], [_, syy, syz], [_, _, szz]] = covariance;
+
+    let det = sxx * syy - sxy * sxy;
+    if det <= PLANE_FIT_RELATIVE_TOLERANCE * (sxx + syy).powi(2) {
+        return None;
+    }
+    let a = (syy * sxz - sxy * syz) / det;
+    let b = (sxx * syz - sxy * sxz) / det;
+    let c = centroid.z() - a * centroid.x() - b * centroid.y();
+
+    let residual_sum_squares: f64 = points
+        .iter()
+        .map(|p| (p.z() - (a * p.x() + b * p.y() + c)).powi(2))
+        .sum();
+    let total_sum_squares = szz * points.len() as f64;
+    let r_squared = if total_sum_squares > 0.0 {
+        1.0 - residual_sum_squares / total_sum_squares
+    } else if residual_sum_squares <= 0.0 {
+        1.0
+    } else {
+        0.0
+    };
+
+    Some(RegressionResult {
+        coefficients: vec![a, b, c],
+        r_squared,
+        residual_sum_squares,
+    })
+}
+
+/// 主成分分析による Total Least Squares 平面フィッティング
+///
+/// 点から平面への直交距離の2乗和を最小化する。平面は重心を通り、法線は
+/// 共分散行列の最小固有値に対応する固有ベクトル。軸の取り方に依存しないため
+/// 垂直な平面も扱える。決定係数は平面内に含まれる分散の割合
+/// `1 - λ_min / (λ_0 + λ_1 + λ_2)`（全点が平面上なら1）。
+///
+/// 法線の向きは z, y, x 成分の順に最初の非零成分が正になるよう揃える
+/// （水平に近い平面では上向き）。点が3未満、全点が一致、または1直線上に並ぶ場合は `None`。
+pub fn fit_plane_total_least_squares(points: &[Point3D<f64>]) -> Option<(Plane3D<f64>, f64)> {
+    if points.len() < 3 {
+        return None;
+    }
+    let (centroid, c) = centroid_and_covariance(points);
+    let covariance = Matrix3x3::new(
+        c[0][0], c[0][1], c[0][2], c[1][0], c[1][1], c[1][2], c[2][0], c[2][1], c[2][2],
+    );
+    let (values, vectors) = covariance.eigen_symmetric();
+
+    let trace: f64 = values.iter().sum();
+    if trace <= 0.0 || values[1] <= PLANE_FIT_RELATIVE_TOLERANCE * trace {
+        return None;
+    }
+
+    let normal = Vector3D::new(vectors[0].x(), vectors[0].y(), vectors[0].z());
+    let sign = [normal.z(), normal.y(), normal.x()]
+        .into_iter()
+        .find(|v| v.abs() > PLANE_FIT_RELATIVE_TOLERANCE)
+        .map_or(1.0, f64::signum);
+    let plane = Plane3D::from_point_and_normal(centroid, normal * sign)?;
+    let r_squared = 1.0 - values[0].max(0.0) / trace;
+    Some((plane, r_squared))
+}
+
+/// 重心と共分散行列（n で割る）
+fn centroid_and_covariance(points: &[Point3D<f64>]) -> (Point3D<f64>, [[f64; 3]; 3]) {
+    let n = points.len() as f64;
+    let (sx, sy, sz) = points.iter().fold((0.0, 0.0, 0.0), |(x, y, z), p| {
+        (x + p.x(), y + p.y(), z + p.z())
+    });
+    let centroid = Point3D::new(sx / n, sy / n, sz / n);
+
+    let mut covariance = [[0.0; 3]; 3];
+    for p in points {
+        let d = [
+            p.x() - centroid.x(),
+            p.y() - centroid.y(),
+            p.z() - centroid.z(),
+        ];
+        for i in 0..3 {
+            for j in 0..3 {
+                covariance[i][j] += d[i] * d[j] / n;
+            }
+        }
+    }
+    (centroid, covariance)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quantile_type7_matches_reference() {
+        // R: quantile(c(1, 3, 4, 7, 10), type = 7)
+        let stats = BasicStats::from_f64_slice(&[7.0, 1.0, 10.0, 3.0, 4.0]);
+        assert_eq!(stats.quantile(0.0), 1.0);
+        assert_eq!(stats.quantile(0.25), 3.0);
+        assert_eq!(stats.quantile(0.5), 4.0);
+        assert_eq!(stats.quantile(0.75), 7.0);
+        assert_eq!(stats.quantile(1.0), 10.0);
+        // h = 4 * 0.1 = 0.4 → 1 + 0.4 * (3 - 1)
+        assert!((stats.quantile(0.1) - 1.8).abs() < 1e-12);
+
+        // 偶数個では中央の2値の平均
+        let even = BasicStats::from_f64_slice(&[1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(even.median(), 2.5);
+        assert_eq!(even.quantile(0.25), 1.75);
+
+        // 範囲外の q は端に丸める
+        assert_eq!(even.quantile(-1.0), 1.0);
+        assert_eq!(even.quantile(2.0), 4.0);
+    }
+
+    #[test]
+    fn test_degenerate_inputs() {
+        let empty = BasicStats::from_f64_slice(&[]);
+        assert_eq!(empty.count, 0);
+        assert_eq!(empty.mean, 0.0);
+        assert_eq!(empty.quantile(0.5), 0.0);
+        let summary = empty.five_number_summary();
+        assert_eq!(summary.iqr, 0.0);
+        assert!(!summary.is_outlier(0.0));
+
+        let single = BasicStats::from_f64_slice(&[3.5]);
+        for q in [0.0, 0.3, 0.5, 1.0] {
+            assert_eq!(single.quantile(q), 3.5);
+        }
+        let summary = single.five_number_summary();
+        assert_eq!((summary.min, summary.median, summary.max), (3.5, 3.5, 3.5));
+        assert_eq!((summary.lower_fence, summary.upper_fence), (3.5, 3.5));
+    }
+
+    #[test]
+    fn test_five_number_summary_and_outliers() {
+        let stats = BasicStats::from_f64_slice(&[2.0, 4.0, 4.0, 5.0, 6.0, 7.0, 8.0, 30.0]);
+        let summary = stats.five_number_summary();
+        assert_eq!(summary.min, 2.0);
+        assert_eq!(summary.max, 30.0);
+        assert_eq!(summary.q1, 4.0);
+        assert_eq!(summary.median, 5.5);
+        assert_eq!(summary.q3, 7.25);
+        assert_eq!(summary.iqr, 3.25);
+        assert!((summary.lower_fence + 0.875).abs() < 1e-12);
+        assert!((summary.upper_fence - 12.125).abs() < 1e-12);
+        assert!(summary.is_outlier(30.0));
+        assert!(!summary.is_outlier(2.0));
+    }
+
+    #[test]
+    fn test_consistency_with_mean_and_variance() {
+        let values = [1.5f64, -2.0, 3.25, 0.0, 8.0, 4.5, -1.0];
+        let stats = BasicStats::from_f64_slice(&values);
+        let from_scalars = BasicStats::from_scalars(&values.map(|v| v as f32));
+        assert!((stats.mean - from_scalars.mean).abs() < 1e-6);
+        assert!((stats.variance - from_scalars.variance).abs() < 1e-5);
+
+        // 分位数の端は最小・最大と一致し、中央値は範囲内
+        assert_eq!(stats.quantile(0.0), stats.min);
+        assert_eq!(stats.quantile(1.0), stats.max);
+        assert!(stats.min <= stats.median() && stats.median() <= stats.max);
+
+        // 対称分布では中央値と平均が一致
+        let symmetric = BasicStats::from_f64_slice(&[1.0, 2.0, 3.0, 4.0, 5.0]);
+        assert_eq!(symmetric.median(), symmetric.mean);
+        assert_eq!(symmetric.variance, 2.0);
+        assert!((symmetric.std_dev - 2.0f64.sqrt()).abs() < 1e-15);
+
+        // 分位数は q について単調非減少
+        let quantiles: Vec<f64> = (0..=20).map(|i| stats.quantile(i as f64 / 20.0)).collect();
+        assert!(quantiles.windows(2).all(|w| w[0] <= w[1]));
+    }
+
+    #[test]
+    fn test_point_cluster_principal_axis() {
+        let points: Vec<Point2D<f64>> = (0..5)
+            .map(|i| Point2D::new(i as f64, i as f64 + if i % 2 == 0 { 0.1 } else { -0.1 }))
+            .collect();
+        let cluster = PointCluster::new(1e-12);
+        let centroid = cluster.centroid(&points).unwrap();
+        assert!((centroid.x() - 2.0).abs() < 1e-12);
+
+        let (direction, _) = cluster.principal_axes(&points).unwrap();
+        let diagonal = std::f64::consts::FRAC_1_SQRT_2;
+        assert!((direction.x().abs() - diagonal).abs() < 0.05);
+        assert!((direction.y().abs() - diagonal).abs() < 0.05);
+    }
+
+    /// 3つの離れた塊（各30点）
+    fn three_blobs() -> Vec<Point3D<f64>> {
+        let centers = [(0.0, 0.0, 0.0), (10.0, 0.0, 0.0), (0.0, 10.0, 5.0)];
+        centers
+            .iter()
+            .flat_map(|&(cx, cy, cz)| {
+                (0..30).map(move |i| {
+                    let t = i as f64;
+                    Point3D::new(
+                        cx + (t * 0.7).sin() * 0.5,
+                        cy + (t * 1.3).cos() * 0.5,
+                        cz + (t * 0.9).sin() * 0.5,
+                    )
+                })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_kmeans_separates_blobs() {
+        let points = three_blobs();
+        let clusters = kmeans_3d(&points, 3, 100, 1);
+        assert_eq!(clusters.len(), 3);
+
+        let mut sizes: Vec<usize> = clusters.iter().map(|c| c.indices.len()).collect();
+        sizes.sort_unstable();
+        assert_eq!(sizes, vec![30, 30, 30]);
+
+        for cluster in &clusters {
+            // 同じ塊の点だけを含む
+            let blob = cluster.indices[0] / 30;
+            assert!(cluster.indices.iter().all(|&i| i / 30 == blob));
+            // 中心は所属点の重心
+            let count = cluster.indices.len() as f64;
+            let mean_x = cluster.indices.iter().map(|&i| points[i].x()).sum::<f64>() / count;
+            assert!((cluster.centroid.x() - mean_x).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_kmeans_is_reproducible_with_seed() {
+        let points = three_blobs();
+        assert_eq!(kmeans_3d(&points, 4, 50, 9), kmeans_3d(&points, 4, 50, 9));
+    }
+
+    #[test]
+    fn test_reseed_two_empty_clusters_at_different_points() {
+        // 全点がクラスタ 0 に属し、クラスタ 1, 2 が同時に空になった状態
+        let points: Vec<Point3D<f64>> = (0..5).map(|i| Point3D::new(i as f64, 0.0, 0.0)).collect();
+        let centers = vec![Point3D::new(1.0, 0.0, 0.0); 3];
+        let assignment = vec![0; points.len()];
+
+        // 最も遠い点（x = 4）と、それを除いて次に遠い点（x = 3）
+        assert_eq!(
+            reseed_indices(&points, &centers, &assignment, 2),
+            vec![4, 3]
+        );
+        assert!(reseed_indices(&points, &centers, &assignment, 0).is_empty());
+    }
+
+    #[test]
+    fn test_kmeans_degenerate_inputs() {
+        assert!(kmeans_3d(&[], 3, 10, 0).is_empty());
+
+        let points = vec![Point3D::new(1.0, 2.0, 3.0), Point3D::new(4.0, 5.0, 6.0)];
+        assert!(kmeans_3d(&points, 0, 10, 0).is_empty());
+
+        // k が点数を超える場合は点数で頭打ち
+        let clusters = kmeans_3d(&points, 5, 10, 0);
+        assert_eq!(clusters.len(), 2);
+        assert!(clusters.iter().all(|c| c.indices.len() == 1));
+
+        // 重複点だけでも全点がいずれかのクラスタに属する
+        let duplicates = vec![Point3D::new(1.0, 1.0, 1.0); 4];
+        let clusters = kmeans_3d(&duplicates, 2, 10, 3);
+        assert_eq!(clusters.iter().map(|c| c.indices.len()).sum::<usize>(), 4);
+    }
+
+    #[test]
+    fn test_histogram_counts_and_edges() {
+        let data = [0.0, 0.5, 1.0, 1.5, 2.0, 2.5, 3.0, 4.0];
+        let histogram = Histogram::new(&data, 4);
+
+        assert_eq!(histogram.bin_count(), 4);
+        assert_eq!(histogram.bin_edges(), &[0.0, 1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(histogram.bin_centers(), vec![0.5, 1.5, 2.5, 3.5]);
+        // 下端を含み上端を含まない。最大値は最後のビンに入る
+        assert_eq!(histogram.counts(), &[2, 2, 2, 2]);
+        assert_eq!(histogram.total(), 8);
+
+        assert_eq!(histogram.normalized(), vec![0.25; 4]);
+        let area: f64 = histogram.density().iter().sum::<f64>() * histogram.bin_width();
+        assert!((area - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_histogram_degenerate_inputs() {
+        // 空データ：範囲 [0, 1]、頻度はすべて0
+        let empty = Histogram::new(&[], 3);
+        assert_eq!(empty.bin_count(), 3);
+        assert_eq!(empty.bin_edges().first(), Some(&0.0));
+        assert_eq!(empty.bin_edges().last(), Some(&1.0));
+        assert_eq!(empty.counts(), &[0, 0, 0]);
+        assert_eq!(empty.normalized(), vec![0.0; 3]);
+        assert!(empty.density().iter().all(|d| d.is_finite()));
+
+        // 全同値：値を中心とした幅1の範囲
+        let constant = Histogram::new(&[2.0; 5], 2);
+        assert_eq!(constant.bin_edges(), &[1.5, 2.0, 2.5]);
+        assert_eq!(constant.counts(), &[0, 5]);
+
+        // ビン数0は1、NaN・無限大は除外
+        let single = Histogram::new(&[1.0, f64::NAN, 3.0, f64::INFINITY], 0);
+        assert_eq!(single.counts(), &[2]);
+        assert_eq!(single.total(), 2);
+
+        assert_eq!(Histogram::auto(&[]).bin_count(), 1);
+        assert_eq!(Histogram::auto(&[7.0; 10]).counts(), &[10]);
+    }
+
+    #[test]
+    fn test_histogram_auto_freedman_diaconis() {
+        // 0..=99：IQR = 49.5、h = 2 * 49.5 / 100^(1/3) ≈ 21.3 → ⌈99 / 21.3⌉ = 5
+        let data: Vec<f64> = (0..100).map(f64::from).collect();
+        let histogram = Histogram::auto(&data);
+        assert_eq!(histogram.bin_count(), 5);
+        assert_eq!(histogram.counts().iter().sum::<usize>(), 100);
+
+        // IQR が0なら Sturges 則：n = 16 → ⌈log2 16⌉ + 1 = 5
+        let mut spiky = vec![1.0; 15];
+        spiky.push(9.0);
+        assert_eq!(Histogram::auto(&spiky).bin_count(), 5);
+    }
+
+    #[test]
+    fn test_histogram_with_range_add() {
+        let mut histogram = Histogram::with_range(0.0, 10.0, 5);
+        assert_eq!(histogram.bin_edges(), &[0.0, 2.0, 4.0, 6.0, 8.0, 10.0]);
+        for v in [-3.0, 1.0, 5.0, 10.0, 42.0, f64::NAN] {
+            histogram.add(v);
+        }
+        // 範囲外は両端のビン、NaN は数えない
+        assert_eq!(histogram.counts(), &[2, 0, 1, 0, 2]);
+        assert_eq!(histogram.total(), 5);
+
+        // 幅0の範囲ではすべて先頭ビン
+        let mut flat = Histogram::with_range(1.0, 1.0, 3);
+        flat.add(1.0);
+        flat.add(2.0);
+        assert_eq!(flat.counts(), &[2, 0, 0]);
+    }
+
+    /// z = 2x - y + 3 上の格子点（`noise` で z に決定的な揺らぎを加える）
+    fn tilted_plane_points(noise: f64) -> Vec<Point3D<f64>> {
+        let mut points = Vec::new();
+        for i in 0..6 {
+            for j in 0..5 {
+                let (x, y) = (f64::from(i) * 0.5, f64::from(j) * 0.7 - 1.0);
+                let wobble = noise * if (i + j) % 2 == 0 { 1.0 } else { -1.0 };
+                points.push(Point3D::new(x, y, 2.0 * x - y + 3.0 + wobble));
+            }
+        }
+        points
+    }
+
+    #[test]
+    fn test_fit_plane_least_squares_recovers_coefficients() {
+        let result = fit_plane_least_squares(&tilted_plane_points(0.0)).unwrap();
+        let expected = [2.0, -1.0, 3.0];
+        for (c, e) in result.coefficients.iter().zip(expected) {
+            assert!((c - e).abs() < 1e-10);
+        }
+        assert!((result.r_squared - 1.0).abs() < 1e-12);
+        assert!(result.residual_sum_squares < 1e-20);
+
+        let plane = result.to_plane().unwrap();
+        assert!(plane.normal().z() > 0.0);
+        assert!(plane.distance_to_point(Point3D::new(1.0, 2.0, 3.0)).abs() < 1e-10);
+
+        // ノイズがあると R² は1未満
+        let noisy = fit_plane_least_squares(&tilted_plane_points(0.05)).unwrap();
+        assert!(noisy.r_squared < 1.0 && noisy.r_squared > 0.99);
+    }
+
+    #[test]
+    fn test_fit_plane_tls_matches_least_squares_on_exact_data() {
+        let (plane, r_squared) = fit_plane_regression(&tilted_plane_points(0.0)).unwrap();
+        assert!((r_squared - 1.0).abs() < 1e-12);
+
+        let expected = Vector3D::new(-2.0, 1.0, 1.0).normalize();
+        let normal = plane.normal().as_vector();
+        assert!((normal - expected).length() < 1e-10);
+        for p in tilted_plane_points(0.0) {
+            assert!(plane.distance_to_point(p).abs() < 1e-10);
+        }
+
+        let (_, noisy_r_squared) = fit_plane_regression(&tilted_plane_points(0.05)).unwrap();
+        assert!(noisy_r_squared < 1.0 && noisy_r_squared > 0.99);
+    }
+
+    #[test]
+    fn test_fit_plane_vertical_only_with_tls() {
+        // 平面 x = 1（z = ax + by + c では表せない）
+        let points: Vec<Point3D<f64>> = (0..12)
+            .map(|i| Point3D::new(1.0, f64::from(i % 4), f64::from(i / 4)))
+            .collect();
+        assert!(fit_plane_least_squares(&points).is_none());
+
+        let (plane, r_squared) = fit_plane_total_least_squares(&points).unwrap();
+        assert!((r_squared - 1.0).abs() < 1e-12);
+        assert!((plane.normal().x() - 1.0).abs() < 1e-10);
+        assert!(plane.distance_to_point(Point3D::new(1.0, 9.0, -4.0)).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_fit_plane_degenerate_inputs() {
+        let two = [Point3D::new(0.0, 0.0, 0.0), Point3D::new(1.0, 1.0, 1.0)];
+        assert!(fit_plane_regression(&two).is_none());
+        assert!(fit_plane_least_squares(&two).is_none());
+
+        let collinear: Vec<Point3D<f64>> = (0..5)
+            .map(|i| Point3D::new(f64::from(i), 2.0 * f64::from(i), -f64::from(i)))
+            .collect();
+        assert!(fit_plane_regression(&collinear).is_none());
+
+        let coincident = vec![Point3D::new(1.0, 2.0, 3.0); 4];
+        assert!(fit_plane_regression(&coincident).is_none());
+
+        // 水平面は R² = 1、法線は上向き
+        let flat: Vec<Point3D<f64>> = (0..9)
+            .map(|i| Point3D::new(f64::from(i % 3), f64::from(i / 3), 5.0))
+            .collect();
+        let result = fit_plane_least_squares(&flat).unwrap();
+        assert_eq!(result.r_squared, 1.0);
+        let (plane, _) = fit_plane_regression(&flat).unwrap();
+        assert!((plane.normal().z() - 1.0).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,65 @@
+//! テスト共通のフィクスチャ（多角形・直方体メッシュ）と検証用ヘルパー
+
+use crate::half_edge::HalfEdgeMesh;
+use geo_primitives::{Point2D, Point3D, TriangleMesh3D, Vector3D};
+
+/// 座標の組から2D多角形の頂点列を作る
+pub(crate) fn polygon(points: &[(f64, f64)]) -> Vec<Point2D<f64>> {
+    points.iter().map(|&(x, y)| Point2D::new(x, y)).collect()
+}
+
+/// 軸に平行な直方体（外向き、12枚の三角形）
+///
+/// 頂点 i の各軸の座標は、i のビット 0/1/2 が立っていれば `max`、そうでなければ `min`。
+pub(crate) fn cuboid(min: [f64; 3], max: [f64; 3]) -> TriangleMesh3D<f64> {
+    let vertices = (0..8)
+        .map(|i| {
+            let pick = |axis: usize| {
+                if (i >> axis) & 1 == 0 {
+                    min[axis]
+                } else {
+                    max[axis]
+                }
+            };
+            Point3D::new(pick(0), pick(1), pick(2))
+        })
+        .collect();
+    let indices = vec![
+        [0, 2, 1],
+        [1, 2, 3],
+        [4, 5, 6],
+        [5, 7, 6],
+        [0, 1, 4],
+        [1, 5, 4],
+        [2, 6, 3],
+        [3, 6, 7],
+        [0, 4, 2],
+        [2, 4, 6],
+        [1, 3, 5],
+        [3, 7, 5],
+    ];
+    TriangleMesh3D::new(vertices, indices).unwrap()
+}
+
+/// 発散定理による符号付き体積（外向きの閉メッシュなら正）
+pub(crate) fn signed_volume(mesh: &TriangleMesh3D<f64>) -> f64 {
+    mesh.indices()
+        .iter()
+        .map(|&[a, b, c]| {
+            let p = |i: usize| {
+                let v = mesh.vertex(i).unwrap();
+                Vector3D::new(v.x(), v.y(), v.z())
+            };
+            p(a).dot(&p(b).cross(&p(c))) / 6.0
+        })
+        .sum()
+}
+
+/// 閉じた多様体であることを確認し、オイラー標数を返す
+pub(crate) fn closed_euler_characteristic(mesh: &TriangleMesh3D<f64>) -> isize {
+    let half_edges = HalfEdgeMesh::from_triangle_mesh(mesh).unwrap();
+    assert!(half_edges.boundary_loops().is_empty());
+    assert_eq!(mesh.degenerate_triangle_count(), 0);
+    let edge_count = mesh.triangle_count() * 3 / 2;
+    mesh.vertex_count() as isize + mesh.triangle_count() as isize - edge_count as isize
+}
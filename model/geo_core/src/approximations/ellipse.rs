@@ -161,6 +161,83 @@ pub fn ellipse_circumference_numerical<T: Scalar>(
     T::from_f64(4.0) * a * sum
 }
 
+/// 周長計算の手法（`ellipse_circumference_best` が選択）
+///
+/// 閉じた近似式の相対誤差上限は `h = ((a - b) / (a + b))²` の関数で、
+/// 全離心率で数値積分と比較して求めた値を切り上げたもの。
+/// `ellipse_perimeter_ramanujan_ii` は高離心率で誤差が大きい（b → 0 で約20%）ため候補に含めない。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EllipsePerimeterMethod {
+    /// `ellipse_perimeter_padé`（級数3項）：相対誤差 ≤ 6e-3·h³
+    Pade,
+    /// `ellipse_perimeter_cantrell`（級数4項）：相対誤差 ≤ 3e-3·h⁴
+    Cantrell,
+    /// `ellipse_perimeter_ramanujan_i`：相対誤差 ≤ 4.1e-4·h⁵
+    Ramanujan,
+    /// 分点数を倍増させる数値積分（指定相対誤差まで収束させる）
+    Numerical,
+}
+
+impl EllipsePerimeterMethod {
+    /// 閉じた近似式の相対誤差上限（数値積分は `None`）
+    pub fn relative_error_bound(&self, h: f64) -> Option<f64> {
+        match self {
+            Self::Pade => Some(6e-3 * h.powi(3)),
+            Self::Cantrell => Some(3e-3 * h.powi(4)),
+            Self::Ramanujan => Some(4.1e-4 * h.powi(5)),
+            Self::Numerical => None,
+        }
+    }
+
+    /// 相対誤差 `max_rel_error` を保証できる最も計算の軽い手法
+    ///
+    /// 近似式は計算量の小さい順（Padé → Cantrell → Ramanujan）に誤差上限を確認し、
+    /// いずれも満たさない高離心率・高精度の要求では数値積分を選ぶ。
+    pub fn select(semi_major: f64, semi_minor: f64, max_rel_error: f64) -> Self {
+        let h = ((semi_major - semi_minor) / (semi_major + semi_minor)).powi(2);
+        [Self::Pade, Self::Cantrell, Self::Ramanujan]
+            .into_iter()
+            .find(|method| {
+                method
+                    .relative_error_bound(h)
+                    .is_some_and(|bound| bound <= max_rel_error)
+            })
+            .unwrap_or(Self::Numerical)
+    }
+}
+
+/// 指定相対誤差を満たす楕円周長
+///
+/// 離心率から `EllipsePerimeterMethod::select` で手法を選び、閉じた近似式で足りなければ
+/// 数値積分の分点数を倍増させ、連続する2回の値の相対差が `max_rel_error` 以下になるまで
+/// 繰り返す（中点則は周期的な被積分関数に対して急速に収束するため、差は誤差の上限になる）。
+/// 短軸半径がゼロの退化した楕円は線分の往復として `4a` を返す。
+pub fn ellipse_circumference_best(a: f64, b: f64, max_rel_error: f64) -> f64 {
+    let (a, b) = (a.abs().max(b.abs()), a.abs().min(b.abs()));
+    if b == 0.0 {
+        return 4.0 * a;
+    }
+
+    match EllipsePerimeterMethod::select(a, b, max_rel_error) {
+        EllipsePerimeterMethod::Pade => ellipse_perimeter_padé(a, b),
+        EllipsePerimeterMethod::Cantrell => ellipse_perimeter_cantrell(a, b),
+        EllipsePerimeterMethod::Ramanujan => ellipse_perimeter_ramanujan_i(a, b),
+        EllipsePerimeterMethod::Numerical => {
+            const MAX_POINTS: usize = 1 << 22;
+            let mut n_points = 16;
+            let mut previous = ellipse_circumference_numerical(a, b, n_points);
+            loop {
+                n_points *= 2;
+                let current = ellipse_circumference_numerical(a, b, n_points);
+                if (current - previous).abs() <= max_rel_error * current || n_points >= MAX_POINTS {
+                    return current;
+                }
+                previous = current;
+            }
+        }
+    }
+}
+
 /// 楕円の面積を計算
 pub fn ellipse_area<T: Scalar>(major_radius: T, minor_radius: T) -> T {
     T::PI * major_radius * minor_radius
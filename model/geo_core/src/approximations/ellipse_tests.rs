@@ -38,4 +38,99 @@ mod tests {
         let expected_c = (semi_major.powi(2) - semi_minor.powi(2)).sqrt();
         assert!((focal_distance - expected_c).abs() < 1e-10);
     }
+
+    type PerimeterFormula = fn(f64, f64) -> f64;
+
+    /// 算術幾何平均による楕円周長（参照値）
+    fn reference_perimeter(a: f64, b: f64) -> f64 {
+        let (mut an, mut bn) = (a, b);
+        let mut sum = (a * a - b * b) / 2.0;
+        let mut weight = 1.0;
+        for _ in 0..40 {
+            let c = (an - bn) / 2.0;
+            (an, bn) = ((an + bn) / 2.0, (an * bn).sqrt());
+            sum += weight * c * c;
+            weight *= 2.0;
+            if c <= 1e-17 * an {
+                break;
+            }
+        }
+        std::f64::consts::TAU / an * (a * a - sum)
+    }
+
+    fn relative_error(value: f64, a: f64, b: f64) -> f64 {
+        let reference = reference_perimeter(a, b);
+        (value - reference).abs() / reference
+    }
+
+    #[test]
+    fn test_documented_error_bounds() {
+        let closed_forms: [(EllipsePerimeterMethod, PerimeterFormula); 3] = [
+            (EllipsePerimeterMethod::Pade, ellipse_perimeter_padé),
+            (EllipsePerimeterMethod::Cantrell, ellipse_perimeter_cantrell),
+            (
+                EllipsePerimeterMethod::Ramanujan,
+                ellipse_perimeter_ramanujan_i,
+            ),
+        ];
+        for i in 1..200 {
+            let b = i as f64 / 200.0;
+            let h = ((1.0 - b) / (1.0 + b)).powi(2);
+            for (method, formula) in closed_forms {
+                let bound = method.relative_error_bound(h).unwrap();
+                let error = relative_error(formula(1.0, b), 1.0, b);
+                assert!(
+                    error <= bound + 1e-15,
+                    "{method:?} b={b}: {error} > {bound}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_best_meets_requested_error() {
+        for b in [0.999, 0.9, 0.5, 0.1, 0.01, 0.001] {
+            for tolerance in [1e-3, 1e-6, 1e-9, 1e-12] {
+                let value = ellipse_circumference_best(1.0, b, tolerance);
+                let error = relative_error(value, 1.0, b);
+                assert!(
+                    error <= tolerance,
+                    "b={b} tolerance={tolerance}: error {error}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_best_method_selection() {
+        // 円に近ければ最も軽い式で十分
+        assert_eq!(
+            EllipsePerimeterMethod::select(1.0, 0.99, 1e-9),
+            EllipsePerimeterMethod::Pade
+        );
+        assert_eq!(
+            EllipsePerimeterMethod::select(1.0, 0.5, 1e-6),
+            EllipsePerimeterMethod::Cantrell
+        );
+        assert_eq!(
+            EllipsePerimeterMethod::select(1.0, 0.5, 1e-8),
+            EllipsePerimeterMethod::Ramanujan
+        );
+        // 高離心率・高精度では数値積分へフォールバック
+        assert_eq!(
+            EllipsePerimeterMethod::select(1.0, 0.01, 1e-9),
+            EllipsePerimeterMethod::Numerical
+        );
+        assert_eq!(
+            EllipsePerimeterMethod::Numerical.relative_error_bound(0.5),
+            None
+        );
+
+        // 引数の順序によらず、退化した楕円は線分の往復
+        assert_eq!(
+            ellipse_circumference_best(0.5, 2.0, 1e-9),
+            ellipse_circumference_best(2.0, 0.5, 1e-9)
+        );
+        assert_eq!(ellipse_circumference_best(3.0, 0.0, 1e-9), 12.0);
+    }
 }
@@ -0,0 +1,72 @@
+//! 3D円と平面の交差計算
+
+use geo_foundation::tolerance_migration::DefaultTolerances;
+use geo_primitives::{Circle3D, Plane3D, Point3D};
+
+/// 円と平面の交差結果
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CirclePlaneIntersection {
+    /// 交差しない（平行で離れている場合を含む）
+    None,
+    /// 円が平面に接する（接点）
+    Tangent(Point3D<f64>),
+    /// 円が平面を横切る（2交点）
+    TwoPoints(Point3D<f64>, Point3D<f64>),
+    /// 円が平面上にある
+    Coplanar,
+}
+
+impl CirclePlaneIntersection {
+    /// 交点（接点）の一覧。`Coplanar` は点として表せないため空
+    pub fn points(&self) -> Vec<Point3D<f64>> {
+        match *self {
+            CirclePlaneIntersection::Tangent(p) => vec![p],
+            CirclePlaneIntersection::TwoPoints(p, q) => vec![p, q],
+            CirclePlaneIntersection::None | CirclePlaneIntersection::Coplanar => Vec::new(),
+        }
+    }
+}
+
+/// 円と平面の交差
+///
+/// 円の平面と `plane` の交線を求め、その交線と円の交点を返す。
+/// 円中心から交線までの距離が半径と許容誤差以内で一致すれば接触とみなす。
+/// 2点の場合の順序は交線方向（円の法線 × 平面の法線）に沿った順。
+/// 2つの平面が平行なら、円中心が平面上にあれば `Coplanar`、そうでなければ `None`。
+pub fn intersect_circle_plane(
+    circle: &Circle3D<f64>,
+    plane: &Plane3D<f64>,
+) -> CirclePlaneIntersection {
+    let tolerance = DefaultTolerances::distance::<f64>();
+    let circle_normal = circle.normal().as_vector();
+    let plane_normal = plane.normal().as_vector();
+    let center = circle.center();
+    let offset = (center - plane.point()).dot(&plane_normal);
+
+    let line_direction = circle_normal.cross(&plane_normal);
+    // 平面法線を円の平面へ射影したもの（長さは2平面のなす角の正弦）
+    let in_plane = plane_normal - circle_normal * plane_normal.dot(&circle_normal);
+    let sine_squared = in_plane.length_squared();
+    if sine_squared.sqrt() <= tolerance {
+        return if offset.abs() <= tolerance {
+            CirclePlaneIntersection::Coplanar
+        } else {
+            CirclePlaneIntersection::None
+        };
+    }
+
+    // 円中心から交線への垂線の足と、その距離
+    let foot = center - in_plane * (offset / sine_squared);
+    let distance = offset.abs() / sine_squared.sqrt();
+    let radius = circle.radius();
+
+    if distance > radius + tolerance {
+        return CirclePlaneIntersection::None;
+    }
+    if (distance - radius).abs() <= tolerance {
+        return CirclePlaneIntersection::Tangent(foot);
+    }
+
+    let half_chord = line_direction.normalize() * (radius * radius - distance * distance).sqrt();
+    CirclePlaneIntersection::TwoPoints(foot - half_chord, foot + half_chord)
+}
@@ -0,0 +1,82 @@
+//! 円と平面の交差のテスト
+
+use crate::intersections::*;
+use geo_primitives::{Circle3D, Plane3D, Point3D, Vector3D};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 中心 (0, 0, 1)、半径 2 の xy 平面上の円
+    fn circle() -> Circle3D<f64> {
+        Circle3D::new_xy_plane(Point3D::new(0.0, 0.0, 1.0), 2.0).unwrap()
+    }
+
+    fn plane(point: (f64, f64, f64), normal: (f64, f64, f64)) -> Plane3D<f64> {
+        Plane3D::from_point_and_normal(
+            Point3D::new(point.0, point.1, point.2),
+            Vector3D::new(normal.0, normal.1, normal.2),
+        )
+        .unwrap()
+    }
+
+    fn assert_point(actual: Point3D<f64>, expected: (f64, f64, f64)) {
+        let expected = Point3D::new(expected.0, expected.1, expected.2);
+        assert!(
+            (actual - expected).length() < 1e-12,
+            "{actual:?} != {expected:?}"
+        );
+    }
+
+    #[test]
+    fn test_two_points() {
+        // x = 1 の平面は円と (1, ±√3, 1) で交わる
+        let result = intersect_circle_plane(&circle(), &plane((1.0, 0.0, 0.0), (1.0, 0.0, 0.0)));
+        match result {
+            CirclePlaneIntersection::TwoPoints(p, q) => {
+                let root3 = 3.0_f64.sqrt();
+                assert_point(p, (1.0, -root3, 1.0));
+                assert_point(q, (1.0, root3, 1.0));
+            }
+            other => panic!("expected two points, got {other:?}"),
+        }
+        assert_eq!(result.points().len(), 2);
+    }
+
+    #[test]
+    fn test_oblique_plane() {
+        // 円中心を通る斜めの平面 → 直径の両端
+        let result = intersect_circle_plane(&circle(), &plane((0.0, 0.0, 1.0), (1.0, 0.0, 1.0)));
+        let points = result.points();
+        assert_eq!(points.len(), 2);
+        for p in points {
+            assert!((p.y().abs() - 2.0).abs() < 1e-12);
+            assert!(p.x().abs() < 1e-12 && (p.z() - 1.0).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_tangent_and_miss() {
+        let tangent = intersect_circle_plane(&circle(), &plane((0.0, 2.0, 1.0), (0.0, 1.0, 1.0)));
+        match tangent {
+            CirclePlaneIntersection::Tangent(p) => assert_point(p, (0.0, 2.0, 1.0)),
+            other => panic!("expected tangent, got {other:?}"),
+        }
+
+        let miss = intersect_circle_plane(&circle(), &plane((3.0, 0.0, 0.0), (1.0, 0.0, 0.0)));
+        assert_eq!(miss, CirclePlaneIntersection::None);
+        assert!(miss.points().is_empty());
+    }
+
+    #[test]
+    fn test_parallel_planes() {
+        assert_eq!(
+            intersect_circle_plane(&circle(), &Plane3D::xy_plane(1.0)),
+            CirclePlaneIntersection::Coplanar
+        );
+        assert_eq!(
+            intersect_circle_plane(&circle(), &Plane3D::xy_plane(0.0)),
+            CirclePlaneIntersection::None
+        );
+    }
+}
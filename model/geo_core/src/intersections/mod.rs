@@ -0,0 +1,20 @@
+//! 交差計算モジュール
+//!
+//! レイ・線分と幾何プリミティブの交差判定・交点パラメータの計算と、
+//! 円と平面の交点計算を提供します。
+//! ピッキングや光線追跡、工具と素材の干渉チェックなどで使用します。
+
+pub mod circle_plane;
+pub mod ray_sphere;
+pub mod segment_cylinder;
+
+#[cfg(test)]
+mod circle_plane_tests;
+#[cfg(test)]
+mod ray_sphere_tests;
+#[cfg(test)]
+mod segment_cylinder_tests;
+
+pub use circle_plane::*;
+pub use ray_sphere::*;
+pub use segment_cylinder::*;
@@ -0,0 +1,78 @@
+//! レイと球の交差計算
+
+use geo_foundation::tolerance_migration::DefaultTolerances;
+use geo_primitives::{Ray3D, SphericalSolid3D};
+
+/// レイと球の交差結果
+///
+/// パラメータ `t` はレイ上の距離（`Ray3D::point_at_parameter` の引数）。
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SphereHit {
+    /// 交差しない（球がレイの後方にある場合を含む）
+    None,
+    /// 球面に接する（接点のパラメータ）
+    Tangent(f64),
+    /// 球を貫通する（入口・出口のパラメータ、`t0 < t1`）
+    ///
+    /// レイ原点が球の内部にある場合、入口はレイの後方となり `t0 < 0` になる。
+    EntryExit(f64, f64),
+}
+
+impl SphereHit {
+    /// 交差するか
+    pub fn is_hit(&self) -> bool {
+        !matches!(self, SphereHit::None)
+    }
+
+    /// レイ原点が球の内部にあるか（入口がレイの後方）
+    pub fn is_origin_inside(&self) -> bool {
+        matches!(self, SphereHit::EntryExit(t0, _) if *t0 < 0.0)
+    }
+
+    /// レイ前方で最初に球面と交わるパラメータ
+    ///
+    /// 原点が内部にある場合は出口のパラメータを返す。
+    pub fn first_hit(&self) -> Option<f64> {
+        match *self {
+            SphereHit::None => None,
+            SphereHit::Tangent(t) => Some(t),
+            SphereHit::EntryExit(t0, t1) => Some(if t0 >= 0.0 { t0 } else { t1 }),
+        }
+    }
+}
+
+/// レイと球の交差
+///
+/// 方向が単位ベクトルのレイについて `|o + t·d - c|² = r²` を解く。
+/// 球中心からレイの直線までの距離が半径と許容誤差以内で一致すれば接触とみなす。
+/// 交点がすべてレイの後方（`t < 0`）にある場合は `None`。
+pub fn intersect_sphere(ray: &Ray3D<f64>, sphere: &SphericalSolid3D<f64>) -> SphereHit {
+    let tolerance = DefaultTolerances::distance::<f64>();
+    let radius = sphere.radius();
+    let to_origin = ray.origin() - sphere.center();
+    let direction = ray.direction_vector();
+
+    // 中心から最も近いレイ直線上の点のパラメータと、そこまでの距離
+    let closest = -direction.dot(&to_origin);
+    let distance_squared = (to_origin.length_squared() - closest * closest).max(0.0);
+    let distance = distance_squared.sqrt();
+
+    if distance > radius + tolerance {
+        return SphereHit::None;
+    }
+    if (distance - radius).abs() <= tolerance {
+        return if closest >= 0.0 {
+            SphereHit::Tangent(closest)
+        } else {
+            SphereHit::None
+        };
+    }
+
+    let half_chord = (radius * radius - distance_squared).sqrt();
+    let (t0, t1) = (closest - half_chord, closest + half_chord);
+    if t1 < 0.0 {
+        SphereHit::None
+    } else {
+        SphereHit::EntryExit(t0, t1)
+    }
+}
@@ -0,0 +1,86 @@
+//! レイと球の交差のテスト
+
+use crate::intersections::*;
+use geo_primitives::{Point3D, Ray3D, SphericalSolid3D, Vector3D};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 中心 (0, 0, 5)、半径 2 の球
+    fn sphere() -> SphericalSolid3D<f64> {
+        SphericalSolid3D::new_standard(Point3D::new(0.0, 0.0, 5.0), 2.0).unwrap()
+    }
+
+    fn ray(origin: (f64, f64, f64), direction: (f64, f64, f64)) -> Ray3D<f64> {
+        Ray3D::new(
+            Point3D::new(origin.0, origin.1, origin.2),
+            Vector3D::new(direction.0, direction.1, direction.2),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_entry_exit_through_center() {
+        let hit = intersect_sphere(&ray((0.0, 0.0, 0.0), (0.0, 0.0, 1.0)), &sphere());
+        match hit {
+            SphereHit::EntryExit(t0, t1) => {
+                assert!((t0 - 3.0).abs() < 1e-12);
+                assert!((t1 - 7.0).abs() < 1e-12);
+            }
+            other => panic!("expected entry/exit, got {other:?}"),
+        }
+        assert!(!hit.is_origin_inside());
+        assert_eq!(hit.first_hit(), Some(3.0));
+    }
+
+    #[test]
+    fn test_off_center_chord_points_lie_on_sphere() {
+        let sphere = sphere();
+        let ray = ray((1.0, -4.0, 4.5), (0.0, 1.0, 0.1));
+        let SphereHit::EntryExit(t0, t1) = intersect_sphere(&ray, &sphere) else {
+            panic!("expected entry/exit");
+        };
+        for t in [t0, t1] {
+            let distance = ray.point_at_parameter(t).distance_to(&sphere.center());
+            assert!((distance - 2.0).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_origin_inside_sphere() {
+        let hit = intersect_sphere(&ray((0.0, 0.0, 5.0), (1.0, 0.0, 0.0)), &sphere());
+        assert_eq!(hit, SphereHit::EntryExit(-2.0, 2.0));
+        assert!(hit.is_origin_inside());
+        assert_eq!(hit.first_hit(), Some(2.0));
+    }
+
+    #[test]
+    fn test_tangent() {
+        // x = 2 の直線は球面に (2, 0, 5) で接する
+        let hit = intersect_sphere(&ray((2.0, 0.0, 0.0), (0.0, 0.0, 1.0)), &sphere());
+        assert_eq!(hit, SphereHit::Tangent(5.0));
+        assert!(hit.is_hit());
+        assert!(!hit.is_origin_inside());
+    }
+
+    #[test]
+    fn test_miss_and_behind() {
+        let sphere = sphere();
+        assert_eq!(
+            intersect_sphere(&ray((3.0, 0.0, 0.0), (0.0, 0.0, 1.0)), &sphere),
+            SphereHit::None
+        );
+        // 球がレイの後方
+        assert_eq!(
+            intersect_sphere(&ray((0.0, 0.0, 0.0), (0.0, 0.0, -1.0)), &sphere),
+            SphereHit::None
+        );
+        assert_eq!(
+            intersect_sphere(&ray((2.0, 0.0, 10.0), (0.0, 0.0, 1.0)), &sphere),
+            SphereHit::None
+        );
+        assert!(!SphereHit::None.is_hit());
+        assert_eq!(SphereHit::None.first_hit(), None);
+    }
+}
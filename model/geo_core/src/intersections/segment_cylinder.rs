@@ -0,0 +1,69 @@
+//! 線分と円柱ソリッドの干渉判定
+
+use geo_foundation::tolerance_migration::DefaultTolerances;
+use geo_primitives::{CylindricalSolid3D, LineSegment3D};
+
+/// 線分が円柱ソリッド（側面・端面・内部）と干渉するか
+pub fn segment_intersects_cylinder(
+    segment: &LineSegment3D<f64>,
+    cylinder: &CylindricalSolid3D<f64>,
+) -> bool {
+    segment_cylinder_interval(segment, cylinder).is_some()
+}
+
+/// 線分のうち円柱ソリッド内部にある区間
+///
+/// 戻り値は線分パラメータ（始点 0、終点 1、`LineSegment3D::point_at_parameter` の引数）の
+/// 区間 `(t0, t1)`。円柱は凸なので内部にある部分は常に1区間になる。
+/// 無限円筒との交差を二次方程式で求め、軸方向の高さ範囲（上下の端面）と
+/// 線分のパラメータ範囲でクランプする。接するだけの場合は `t0 == t1`。
+pub fn segment_cylinder_interval(
+    segment: &LineSegment3D<f64>,
+    cylinder: &CylindricalSolid3D<f64>,
+) -> Option<(f64, f64)> {
+    let tolerance = DefaultTolerances::distance::<f64>();
+    let axis = cylinder.axis().as_vector();
+    let start = segment.start() - cylinder.center();
+    let delta = segment.end() - segment.start();
+
+    let mut t0: f64 = 0.0;
+    let mut t1: f64 = 1.0;
+
+    // 端面：軸方向の高さ 0 ≤ h(t) ≤ height
+    let height_start = start.dot(&axis);
+    let height_delta = delta.dot(&axis);
+    if height_delta.abs() <= tolerance {
+        if height_start < -tolerance || height_start > cylinder.height() + tolerance {
+            return None;
+        }
+    } else {
+        let a = -height_start / height_delta;
+        let b = (cylinder.height() - height_start) / height_delta;
+        t0 = t0.max(a.min(b));
+        t1 = t1.min(a.max(b));
+    }
+
+    // 側面：軸からの距離 |m + t·n| ≤ radius（m, n は軸に垂直な成分）
+    let radial_start = start - axis * height_start;
+    let radial_delta = delta - axis * height_delta;
+    let radius = cylinder.radius();
+    let a = radial_delta.length_squared();
+    let b = radial_start.dot(&radial_delta);
+    let c = radial_start.length_squared() - radius * radius;
+    if a.sqrt() <= tolerance {
+        // 軸に平行な線分
+        if radial_start.length() > radius + tolerance {
+            return None;
+        }
+    } else {
+        let discriminant = b * b - a * c;
+        if discriminant < 0.0 {
+            return None;
+        }
+        let root = discriminant.sqrt();
+        t0 = t0.max((-b - root) / a);
+        t1 = t1.min((-b + root) / a);
+    }
+
+    (t0 <= t1).then_some((t0, t1))
+}
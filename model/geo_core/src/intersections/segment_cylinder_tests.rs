@@ -0,0 +1,109 @@
+//! 線分と円柱ソリッドの干渉判定のテスト
+
+use crate::intersections::*;
+use geo_primitives::{CylindricalSolid3D, LineSegment3D, Point3D};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 底面中心が原点、半径 1、高さ 4 の z 軸円柱
+    fn cylinder() -> CylindricalSolid3D<f64> {
+        CylindricalSolid3D::new_z_axis(Point3D::new(0.0, 0.0, 0.0), 1.0, 4.0).unwrap()
+    }
+
+    fn segment(start: (f64, f64, f64), end: (f64, f64, f64)) -> LineSegment3D<f64> {
+        LineSegment3D::new(
+            Point3D::new(start.0, start.1, start.2),
+            Point3D::new(end.0, end.1, end.2),
+        )
+        .unwrap()
+    }
+
+    fn assert_interval(actual: Option<(f64, f64)>, expected: (f64, f64)) {
+        let (t0, t1) = actual.expect("expected an interval");
+        assert!(
+            (t0 - expected.0).abs() < 1e-10 && (t1 - expected.1).abs() < 1e-10,
+            "({t0}, {t1}) != {expected:?}"
+        );
+    }
+
+    #[test]
+    fn test_through_side() {
+        let cylinder = cylinder();
+        let segment = segment((-3.0, 0.0, 2.0), (3.0, 0.0, 2.0));
+        assert!(segment_intersects_cylinder(&segment, &cylinder));
+        assert_interval(
+            segment_cylinder_interval(&segment, &cylinder),
+            (1.0 / 3.0, 2.0 / 3.0),
+        );
+    }
+
+    #[test]
+    fn test_through_end_caps() {
+        let cylinder = cylinder();
+        // 軸に平行：上下の端面で切られる
+        assert_interval(
+            segment_cylinder_interval(&segment((0.5, 0.0, -1.0), (0.5, 0.0, 5.0)), &cylinder),
+            (1.0 / 6.0, 5.0 / 6.0),
+        );
+        // 上端面から入り側面から出る
+        assert_interval(
+            segment_cylinder_interval(&segment((-0.5, 0.0, 5.0), (1.5, 0.0, 3.0)), &cylinder),
+            (0.5, 0.75),
+        );
+    }
+
+    #[test]
+    fn test_clamped_to_segment() {
+        let cylinder = cylinder();
+        // 全体が内部
+        assert_interval(
+            segment_cylinder_interval(&segment((0.0, 0.0, 1.0), (0.5, 0.5, 2.0)), &cylinder),
+            (0.0, 1.0),
+        );
+        // 内部から外へ
+        assert_interval(
+            segment_cylinder_interval(&segment((0.0, 0.0, 2.0), (2.0, 0.0, 2.0)), &cylinder),
+            (0.0, 0.5),
+        );
+    }
+
+    #[test]
+    fn test_rotated_cylinder_endpoints_on_surface() {
+        let cylinder =
+            CylindricalSolid3D::new_x_axis(Point3D::new(1.0, 0.0, 0.0), 1.0, 2.0).unwrap();
+        let segment = segment((2.0, -3.0, 0.5), (2.5, 3.0, -0.5));
+        let (t0, t1) = segment_cylinder_interval(&segment, &cylinder).unwrap();
+        for t in [t0, t1] {
+            let point = segment.point_at_parameter(t);
+            let radial = (point.y() * point.y() + point.z() * point.z()).sqrt();
+            assert!((radial - 1.0).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_miss() {
+        let cylinder = cylinder();
+        // 上端面より上
+        assert!(!segment_intersects_cylinder(
+            &segment((-3.0, 0.0, 5.0), (3.0, 0.0, 5.0)),
+            &cylinder
+        ));
+        // 側面の外を通過
+        assert!(!segment_intersects_cylinder(
+            &segment((-3.0, 2.0, 2.0), (3.0, 2.0, 2.0)),
+            &cylinder
+        ));
+        // 円柱の手前で終わる
+        assert!(!segment_intersects_cylinder(
+            &segment((-3.0, 0.0, 2.0), (-2.0, 0.0, 2.0)),
+            &cylinder
+        ));
+        // 軸に平行だが半径の外
+        assert_eq!(
+            segment_cylinder_interval(&segment((1.5, 0.0, 0.0), (1.5, 0.0, 4.0)), &cylinder),
+            None
+        );
+    }
+}
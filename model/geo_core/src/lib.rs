@@ -6,6 +6,7 @@
 //! ## 主要機能
 //! - **metrics**: 面積、体積、距離等の計量計算
 //! - **approximations**: 楕円・曲線の近似計算
+//! - **intersections**: レイとプリミティブ、円と平面の交差計算
 //! - **bridge**: Foundation パターン準拠のプリミティブアクセス
 //!
 //! ## Foundation パターンでの役割
@@ -19,6 +20,7 @@
 
 // 主要モジュール
 pub mod approximations;
+pub mod intersections;
 pub mod metrics;
 
 // テストモジュール
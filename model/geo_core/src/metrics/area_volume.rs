@@ -2,6 +2,11 @@
 
 use analysis::abstract_types::Scalar;
 
+// 回転体の求積公式（退化パラメータのガード付き）
+pub use analysis::geometry::{
+    frustum_lateral_area, frustum_volume, torus_surface_area, torus_volume,
+};
+
 /// 円の面積計算
 pub fn circle_area<T: Scalar>(radius: T) -> T {
     T::PI * radius * radius
@@ -0,0 +1,127 @@
+//! 2D断面特性の計算
+//!
+//! 多角形断面の面積・重心・断面二次モーメントをグリーンの定理に基づく
+//! 多角形公式で求めます。構造解析の断面特性計算に使用します。
+
+use geo_primitives::Point2D;
+
+/// 面積ゼロとみなす `|2 × 面積| / 周長²` の上限（丸め誤差で残る面積を退化として扱う）
+const DEGENERATE_AREA_RATIO: f64 = 1e-12;
+
+/// 多角形断面の断面二次モーメント
+///
+/// `ixx = ∫y² dA`、`iyy = ∫x² dA`、`ixy = ∫xy dA` を `reference` を原点とする座標で表す。
+/// 頂点の向き（時計回り/反時計回り）によらず `area`・`ixx`・`iyy` は非負になる。
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SecondMoment {
+    /// 断面積
+    pub area: f64,
+    /// 図心
+    pub centroid: Point2D<f64>,
+    /// モーメントの基準点
+    pub reference: Point2D<f64>,
+    /// x軸まわりの断面二次モーメント
+    pub ixx: f64,
+    /// y軸まわりの断面二次モーメント
+    pub iyy: f64,
+    /// 断面相乗モーメント
+    pub ixy: f64,
+}
+
+impl SecondMoment {
+    /// 基準点を `point` へ移した断面二次モーメント（平行軸の定理）
+    pub fn about_point(&self, point: Point2D<f64>) -> Self {
+        // 現在の基準点から図心へ、図心から新しい基準点へ移す
+        let (cx, cy) = (
+            self.centroid.x() - self.reference.x(),
+            self.centroid.y() - self.reference.y(),
+        );
+        let (px, py) = (self.centroid.x() - point.x(), self.centroid.y() - point.y());
+        Self {
+            reference: point,
+            ixx: self.ixx - self.area * cy * cy + self.area * py * py,
+            iyy: self.iyy - self.area * cx * cx + self.area * px * px,
+            ixy: self.ixy - self.area * cx * cy + self.area * px * py,
+            ..*self
+        }
+    }
+
+    /// 図心まわりの断面二次モーメント
+    pub fn about_centroid(&self) -> Self {
+        self.about_point(self.centroid)
+    }
+
+    /// 極断面二次モーメント `ixx + iyy`
+    pub fn polar(&self) -> f64 {
+        self.ixx + self.iyy
+    }
+}
+
+/// 多角形断面の断面二次モーメント（原点まわり）
+///
+/// 頂点は閉じた多角形として扱い、最後の頂点と最初の頂点を結ぶ（始点の重複は不要）。
+/// 桁落ちを避けるため最初の頂点を基準に積分し、平行軸の定理で原点まわりに移す。
+/// 頂点が3未満、または面積が周長の2乗に対して無視できるほど小さい（一直線上に並ぶなど）場合は、
+/// 頂点の平均を図心とする全てゼロの値を返す。
+pub fn polygon_second_moment(polygon: &[Point2D<f64>]) -> SecondMoment {
+    let origin = Point2D::origin();
+    let Some(&base) = polygon.first() else {
+        return zero_moment(origin, origin);
+    };
+
+    let local: Vec<(f64, f64)> = polygon
+        .iter()
+        .map(|p| (p.x() - base.x(), p.y() - base.y()))
+        .collect();
+
+    let (mut area2, mut sx, mut sy, mut sxx, mut syy, mut sxy) = (0.0, 0.0, 0.0, 0.0, 0.0, 0.0);
+    let mut perimeter = 0.0;
+    for (i, &(x0, y0)) in local.iter().enumerate() {
+        let (x1, y1) = local[(i + 1) % local.len()];
+        let cross = x0 * y1 - x1 * y0;
+        perimeter += (x1 - x0).hypot(y1 - y0);
+        area2 += cross;
+        sx += (x0 + x1) * cross;
+        sy += (y0 + y1) * cross;
+        syy += (y0 * y0 + y0 * y1 + y1 * y1) * cross;
+        sxx += (x0 * x0 + x0 * x1 + x1 * x1) * cross;
+        sxy += (x0 * y1 + 2.0 * x0 * y0 + 2.0 * x1 * y1 + x1 * y0) * cross;
+    }
+
+    if polygon.len() < 3 || area2.abs() <= DEGENERATE_AREA_RATIO * perimeter * perimeter {
+        let count = polygon.len() as f64;
+        let (sum_x, sum_y) = polygon
+            .iter()
+            .fold((0.0, 0.0), |(ax, ay), p| (ax + p.x(), ay + p.y()));
+        return zero_moment(Point2D::new(sum_x / count, sum_y / count), origin);
+    }
+
+    // 時計回りなら符号を反転して反時計回りに揃える
+    let sign = area2.signum();
+    let area = area2 * sign / 2.0;
+    let centroid = Point2D::new(
+        base.x() + sx * sign / (6.0 * area),
+        base.y() + sy * sign / (6.0 * area),
+    );
+
+    SecondMoment {
+        area,
+        centroid,
+        reference: base,
+        ixx: syy * sign / 12.0,
+        iyy: sxx * sign / 12.0,
+        ixy: sxy * sign / 24.0,
+    }
+    .about_point(origin)
+}
+
+fn zero_moment(centroid: Point2D<f64>, reference: Point2D<f64>) -> SecondMoment {
+    SecondMoment {
+        area: 0.0,
+        centroid,
+        reference,
+        ixx: 0.0,
+        iyy: 0.0,
+        ixy: 0.0,
+    }
+}
@@ -0,0 +1,98 @@
+//! 断面特性計算のテスト
+
+use crate::metrics::*;
+use geo_primitives::Point2D;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn points(coords: &[(f64, f64)]) -> Vec<Point2D<f64>> {
+        coords.iter().map(|&(x, y)| Point2D::new(x, y)).collect()
+    }
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!((actual - expected).abs() < 1e-9, "{actual} != {expected}");
+    }
+
+    #[test]
+    fn test_rectangle_section() {
+        // 幅 4・高さ 2 の矩形、図心 (3, 2)
+        let rectangle = points(&[(1.0, 1.0), (5.0, 1.0), (5.0, 3.0), (1.0, 3.0)]);
+        let moment = polygon_second_moment(&rectangle);
+        assert_close(moment.area, 8.0);
+        assert_close(moment.centroid.x(), 3.0);
+        assert_close(moment.centroid.y(), 2.0);
+
+        // 原点まわり = 図心まわり + A·d²
+        assert_close(moment.ixx, 8.0 / 3.0 + 8.0 * 4.0);
+        assert_close(moment.iyy, 32.0 / 3.0 + 8.0 * 9.0);
+        assert_close(moment.ixy, 8.0 * 3.0 * 2.0);
+
+        // 図心まわり: bh³/12, hb³/12
+        let centroidal = moment.about_centroid();
+        assert_close(centroidal.ixx, 8.0 / 3.0);
+        assert_close(centroidal.iyy, 32.0 / 3.0);
+        assert_close(centroidal.ixy, 0.0);
+        assert_close(centroidal.polar(), 8.0 / 3.0 + 32.0 / 3.0);
+    }
+
+    #[test]
+    fn test_orientation_is_normalized() {
+        let ccw = points(&[(0.0, 0.0), (3.0, 0.0), (0.0, 6.0)]);
+        let cw: Vec<_> = ccw.iter().rev().copied().collect();
+        let a = polygon_second_moment(&ccw);
+        let b = polygon_second_moment(&cw);
+        for (x, y) in [
+            (a.area, b.area),
+            (a.ixx, b.ixx),
+            (a.iyy, b.iyy),
+            (a.ixy, b.ixy),
+        ] {
+            assert_close(x, y);
+        }
+        assert!(b.area > 0.0 && b.ixx > 0.0 && b.iyy > 0.0);
+    }
+
+    #[test]
+    fn test_right_triangle_centroidal() {
+        // 直角を原点に持つ直角三角形（底辺 3、高さ 6）
+        let triangle = points(&[(0.0, 0.0), (3.0, 0.0), (0.0, 6.0)]);
+        let centroidal = polygon_second_moment(&triangle).about_centroid();
+        assert_close(centroidal.area, 9.0);
+        assert_close(centroidal.centroid.x(), 1.0);
+        assert_close(centroidal.centroid.y(), 2.0);
+        assert_close(centroidal.ixx, 3.0 * 216.0 / 36.0);
+        assert_close(centroidal.iyy, 6.0 * 27.0 / 36.0);
+        assert_close(centroidal.ixy, -9.0 * 36.0 / 72.0);
+    }
+
+    #[test]
+    fn test_parallel_axis_round_trip() {
+        let polygon = points(&[(2.0, -1.0), (6.0, 0.0), (5.0, 4.0), (1.0, 3.0)]);
+        let moment = polygon_second_moment(&polygon);
+        let shifted = moment.about_point(Point2D::new(-3.0, 7.0));
+        let back = shifted.about_point(Point2D::origin());
+        assert_close(back.ixx, moment.ixx);
+        assert_close(back.iyy, moment.iyy);
+        assert_close(back.ixy, moment.ixy);
+    }
+
+    #[test]
+    fn test_degenerate_polygons() {
+        let empty = polygon_second_moment(&[]);
+        assert_eq!(empty.area, 0.0);
+
+        let segment = polygon_second_moment(&points(&[(0.0, 0.0), (2.0, 2.0)]));
+        assert_eq!((segment.area, segment.ixx, segment.iyy), (0.0, 0.0, 0.0));
+        assert_close(segment.centroid.x(), 1.0);
+
+        let collinear = polygon_second_moment(&points(&[(0.0, 0.0), (1.0, 1.0), (2.0, 2.0)]));
+        assert_eq!(collinear.area, 0.0);
+
+        // 丸め誤差で外積の和がわずかにゼロでない一直線上の点列も退化として扱う
+        let rounded = polygon_second_moment(&points(&[(0.0, 0.0), (0.1, 0.3), (0.7, 2.1)]));
+        assert_eq!((rounded.area, rounded.ixx, rounded.iyy), (0.0, 0.0, 0.0));
+        assert!(rounded.centroid.x().is_finite());
+    }
+}
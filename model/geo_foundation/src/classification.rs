@@ -3,12 +3,15 @@
 //! すべてのプリミティブ形状を階層的に分類し、
 //! 型安全な識別と処理を可能にする
 
+use std::collections::HashMap;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum PrimitiveKind {
     // 0次元: 点
     Point,
 
     // 1次元: 線形要素
+    Line, // 無限直線
     LineSegment,
     PolyLine,
     BezierCurve,
@@ -27,6 +30,7 @@ pub enum PrimitiveKind {
     Sphere,
     SphericalSolid,     // 新式球ソリッド
     SphericalSurface,   // 新式球サーフェス
+    EllipsoidalSurface, // 楕円体サーフェス
     Cylinder,           // 旧式（互換性のため残存）
     CylindricalSolid,   // 新式ソリッド
     CylindricalSurface, // 新式サーフェス
@@ -65,7 +69,8 @@ impl PrimitiveKind {
         match self {
             PrimitiveKind::Point => DimensionClass::Zero,
 
-            PrimitiveKind::LineSegment
+            PrimitiveKind::Line
+            | PrimitiveKind::LineSegment
             | PrimitiveKind::PolyLine
             | PrimitiveKind::BezierCurve
             | PrimitiveKind::NurbsCurve
@@ -80,6 +85,7 @@ impl PrimitiveKind {
             | PrimitiveKind::Plane
             | PrimitiveKind::CylindricalSurface  // サーフェスは2次元
             | PrimitiveKind::SphericalSurface    // 球サーフェスは2次元
+            | PrimitiveKind::EllipsoidalSurface  // 楕円体サーフェスは2次元
             | PrimitiveKind::ConicalSurface      // 円錐サーフェスは2次元
             | PrimitiveKind::TorusSurface        // トーラスサーフェスは2次元
             | PrimitiveKind::NurbsSurface => DimensionClass::Two,
@@ -133,6 +139,7 @@ impl PrimitiveKind {
                 | PrimitiveKind::Sphere
                 | PrimitiveKind::SphericalSolid
                 | PrimitiveKind::SphericalSurface
+                | PrimitiveKind::EllipsoidalSurface
                 | PrimitiveKind::Cylinder
                 | PrimitiveKind::CylindricalSolid
                 | PrimitiveKind::CylindricalSurface
@@ -155,12 +162,26 @@ impl PrimitiveKind {
 }
 
 /// 幾何プリミティブの基本トレイト
+///
+/// オブジェクト安全なので、`Vec<Box<dyn GeometryPrimitive>>` のような
+/// 異種コレクションを種別・次元で振り分けられる。
 pub trait GeometryPrimitive {
     /// プリミティブの種類を返す
-    fn kind(&self) -> PrimitiveKind;
+    fn primitive_kind(&self) -> PrimitiveKind;
 
     /// 次元を返す
-    fn dimension(&self) -> DimensionClass {
-        self.kind().dimension()
+    fn dimension_class(&self) -> DimensionClass {
+        self.primitive_kind().dimension()
+    }
+}
+
+/// プリミティブを種類ごとに数える（シーン統計表示用）
+pub fn count_by_kind<'a>(
+    primitives: impl IntoIterator<Item = &'a dyn GeometryPrimitive>,
+) -> HashMap<PrimitiveKind, usize> {
+    let mut counts = HashMap::new();
+    for primitive in primitives {
+        *counts.entry(primitive.primitive_kind()).or_insert(0) += 1;
     }
+    counts
 }
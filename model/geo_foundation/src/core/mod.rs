@@ -8,6 +8,7 @@
 // ============================================================================
 pub mod point_core_traits;
 pub mod transform; // extensionsから移動した共通Transformトレイト群
+pub mod transform3d; // Transform3D（合成・逆変換付きアフィン変換）
 pub mod transform_error;
 pub mod vector_core_traits; // Vector Core traits (Constructor/Properties/Measure) // extensionsから移動したTransformError(段階的移行中)
 
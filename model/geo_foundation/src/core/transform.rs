@@ -3,8 +3,12 @@
 //! Analysis Matrix/Vectorクレートを基盤とした高効率変換トレイト群
 //! geo_nurbsのmatrix_transformパターンを基盤とする統一実装
 
+use crate::core::transform3d::Transform3D;
 use crate::Scalar;
-use analysis::linalg::vector::{Vector2, Vector3};
+use analysis::linalg::{
+    matrix::Matrix4x4,
+    vector::{Vector2, Vector3},
+};
 
 /// 3D Analysis Matrix変換トレイト（座標点用）
 ///
@@ -78,6 +82,14 @@ pub trait AnalysisTransform3D<T: Scalar> {
     ) -> Result<Self::Output, crate::TransformError>
     where
         Self: Sized;
+
+    /// Transform3Dによる変換（`transform_point_matrix` に委譲）
+    fn apply_transform(&self, transform: &Transform3D<T>) -> Self::Output
+    where
+        Self: AnalysisTransform3D<T, Matrix4x4 = Matrix4x4<T>>,
+    {
+        self.transform_point_matrix(transform.matrix())
+    }
 }
 
 /// 3D Analysis Vector変換トレイト（方向ベクトル用）
@@ -144,6 +156,14 @@ pub trait AnalysisTransformVector3D<T: Scalar> {
     fn normalize_analysis(&self) -> Result<Self::Output, crate::TransformError>
     where
         Self: Sized;
+
+    /// Transform3Dによる方向ベクトル変換（`transform_vector_matrix` に委譲）
+    fn apply_vector_transform(&self, transform: &Transform3D<T>) -> Self::Output
+    where
+        Self: AnalysisTransformVector3D<T, Matrix4x4 = Matrix4x4<T>>,
+    {
+        self.transform_vector_matrix(transform.matrix())
+    }
 }
 
 /// 2D Analysis Matrix変換トレイト（座標点用）
@@ -0,0 +1,263 @@
+//! Transform3D - 3Dアフィン変換の値型
+//!
+//! 各プリミティブの `AnalysisTransform3D` / `AnalysisTransformVector3D` 実装が受け取る
+//! Matrix4x4 を、平行移動・回転・スケールのビルダーと合成・逆変換付きで扱う。
+//! ビルダーは呼び出し順に変換を積み重ねる（後から呼んだ変換が後から適用される）。
+
+use crate::{Angle, Scalar, TransformError};
+use analysis::linalg::{matrix::Matrix4x4, vector::Vector3};
+
+/// 3Dアフィン変換（内部は同次座標の Matrix4x4）
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Transform3D<T: Scalar = f64> {
+    matrix: Matrix4x4<T>,
+}
+
+impl<T: Scalar> Transform3D<T> {
+    /// 恒等変換
+    pub fn identity() -> Self {
+        Self {
+            matrix: Matrix4x4::identity(),
+        }
+    }
+
+    /// 既存の変換行列から作成
+    pub fn from_matrix(matrix: Matrix4x4<T>) -> Self {
+        Self { matrix }
+    }
+
+    /// 変換行列
+    pub fn matrix(&self) -> &Matrix4x4<T> {
+        &self.matrix
+    }
+
+    /// 現在の変換の後に平行移動を追加
+    pub fn translate(self, translation: &Vector3<T>) -> Self {
+        self.then_matrix(Matrix4x4::translation_3d(translation))
+    }
+
+    /// 現在の変換の後に原点を通る軸周りの回転を追加
+    ///
+    /// 軸がゼロベクトルの場合はエラー。
+    pub fn rotate(self, axis: &Vector3<T>, angle: Angle<T>) -> Result<Self, TransformError> {
+        let axis = axis.normalize().map_err(|_| {
+            TransformError::ZeroVector("Rotation axis cannot be zero vector".to_string())
+        })?;
+        Ok(self.then_matrix(Matrix4x4::rotation_axis(&axis, angle.to_radians())))
+    }
+
+    /// 現在の変換の後に原点基準のスケールを追加
+    pub fn scale(self, scale_x: T, scale_y: T, scale_z: T) -> Self {
+        self.then_matrix(Matrix4x4::scale(scale_x, scale_y, scale_z))
+    }
+
+    /// 現在の変換の後に原点基準の均等スケールを追加
+    pub fn uniform_scale(self, scale_factor: T) -> Self {
+        self.scale(scale_factor, scale_factor, scale_factor)
+    }
+
+    /// `self` を適用した後に `other` を適用する合成変換
+    pub fn compose(&self, other: &Self) -> Self {
+        Self {
+            matrix: other.matrix * self.matrix,
+        }
+    }
+
+    /// 逆変換（線形部分が特異、または射影成分を持つ場合は `None`）
+    ///
+    /// 特異判定は `check_non_singular` と同じ行列の大きさに対する相対判定
+    /// （相対許容誤差 `T::EPSILON`）なので、小さな一様スケールも逆変換できる。
+    pub fn inverse(&self) -> Option<Self> {
+        if !self.matrix.is_affine_transform_3d() {
+            return None;
+        }
+        crate::check_non_singular(&self.matrix, T::EPSILON).ok()?;
+        let m = self.matrix.linear_part_3d();
+        let cofactor = |r0: usize, r1: usize, c0: usize, c1: usize| {
+            m[r0][c0] * m[r1][c1] - m[r0][c1] * m[r1][c0]
+        };
+        // 余因子行列の転置（随伴行列）
+        let adjugate = [
+            [
+                cofactor(1, 2, 1, 2),
+                -cofactor(0, 2, 1, 2),
+                cofactor(0, 1, 1, 2),
+            ],
+            [
+                -cofactor(1, 2, 0, 2),
+                cofactor(0, 2, 0, 2),
+                -cofactor(0, 1, 0, 2),
+            ],
+            [
+                cofactor(1, 2, 0, 1),
+                -cofactor(0, 2, 0, 1),
+                cofactor(0, 1, 0, 1),
+            ],
+        ];
+        let determinant =
+            m[0][0] * adjugate[0][0] + m[0][1] * adjugate[1][0] + m[0][2] * adjugate[2][0];
+
+        let linear = adjugate.map(|row| row.map(|value| value / determinant));
+        let translation = self.matrix.extract_translation_3d();
+        let inverse_translation = Vector3::new(
+            -(linear[0][0] * translation.x()
+                + linear[0][1] * translation.y()
+                + linear[0][2] * translation.z()),
+            -(linear[1][0] * translation.x()
+                + linear[1][1] * translation.y()
+                + linear[1][2] * translation.z()),
+            -(linear[2][0] * translation.x()
+                + linear[2][1] * translation.y()
+                + linear[2][2] * translation.z()),
+        );
+        Some(Self {
+            matrix: Matrix4x4::affine_3d(linear, inverse_translation),
+        })
+    }
+
+    /// 特異でないことを確認（`check_non_singular` を参照）
+    pub fn ensure_non_singular(&self, tolerance: T) -> Result<(), TransformError> {
+        crate::check_non_singular(&self.matrix, tolerance)
+    }
+
+    /// 等方形状に適用できる相似変換なら一様スケール倍率を返す（`check_uniform_scale` を参照）
+    pub fn uniform_scale_factor(&self, tolerance: T) -> Result<T, TransformError> {
+        crate::check_uniform_scale(&self.matrix, tolerance)
+    }
+
+    /// 点を変換（平行移動を含む）
+    pub fn apply_point(&self, point: &Vector3<T>) -> Vector3<T> {
+        self.matrix.transform_point_3d(point)
+    }
+
+    /// ベクトルを変換（平行移動は無視）
+    pub fn apply_vector(&self, vector: &Vector3<T>) -> Vector3<T> {
+        self.matrix.transform_vector_3d(vector)
+    }
+
+    /// 方向を変換して正規化（変換後にゼロベクトルとなる場合は `None`）
+    pub fn apply_direction(&self, direction: &Vector3<T>) -> Option<Vector3<T>> {
+        self.apply_vector(direction).normalize().ok()
+    }
+
+    fn then_matrix(self, next: Matrix4x4<T>) -> Self {
+        Self {
+            matrix: next * self.matrix,
+        }
+    }
+}
+
+impl<T: Scalar> Default for Transform3D<T> {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_vector_eq(actual: Vector3<f64>, expected: Vector3<f64>) {
+        assert!(
+            (actual - expected).norm() < 1e-10,
+            "{actual:?} != {expected:?}"
+        );
+    }
+
+    #[test]
+    fn test_builder_applies_in_call_order() {
+        // 2倍 → z軸90度回転 → (1, 0, 0) 移動
+        let transform = Transform3D::identity()
+            .uniform_scale(2.0)
+            .rotate(&Vector3::new(0.0, 0.0, 1.0), Angle::from_degrees(90.0))
+            .unwrap()
+            .translate(&Vector3::new(1.0, 0.0, 0.0));
+
+        let point = transform.apply_point(&Vector3::new(1.0, 0.0, 0.0));
+        assert_vector_eq(point, Vector3::new(1.0, 2.0, 0.0));
+
+        // ベクトルには平行移動が効かない
+        let vector = transform.apply_vector(&Vector3::new(1.0, 0.0, 0.0));
+        assert_vector_eq(vector, Vector3::new(0.0, 2.0, 0.0));
+
+        let direction = transform
+            .apply_direction(&Vector3::new(1.0, 0.0, 0.0))
+            .unwrap();
+        assert_vector_eq(direction, Vector3::new(0.0, 1.0, 0.0));
+    }
+
+    #[test]
+    fn test_compose_order() {
+        let scale = Transform3D::identity().uniform_scale(3.0);
+        let shift = Transform3D::identity().translate(&Vector3::new(1.0, 2.0, 3.0));
+        let point = Vector3::new(1.0, 1.0, 1.0);
+
+        assert_vector_eq(
+            scale.compose(&shift).apply_point(&point),
+            Vector3::new(4.0, 5.0, 6.0),
+        );
+        assert_vector_eq(
+            shift.compose(&scale).apply_point(&point),
+            Vector3::new(6.0, 9.0, 12.0),
+        );
+    }
+
+    #[test]
+    fn test_inverse_round_trip() {
+        // 回転後の非均等スケールでせん断を含む一般のアフィン変換
+        let transform = Transform3D::identity()
+            .rotate(&Vector3::new(1.0, 1.0, 0.0), Angle::from_degrees(30.0))
+            .unwrap()
+            .scale(2.0, 0.5, 3.0)
+            .translate(&Vector3::new(-4.0, 5.0, 1.5));
+        let inverse = transform.inverse().unwrap();
+
+        let point = Vector3::new(0.3, -1.2, 2.0);
+        assert_vector_eq(inverse.apply_point(&transform.apply_point(&point)), point);
+        assert!(transform
+            .compose(&inverse)
+            .matrix()
+            .is_approximately_equal_3d(&Matrix4x4::identity(), 1e-10));
+    }
+
+    #[test]
+    fn test_singular_and_invalid() {
+        let flat = Transform3D::identity().scale(1.0, 0.0, 1.0);
+        assert!(flat.inverse().is_none());
+        // 行列式は 1e-18 と小さいが、相対判定なので逆変換できる
+        let tiny = Transform3D::<f64>::identity().uniform_scale(1e-6);
+        let inverse = tiny.inverse().unwrap();
+        assert_vector_eq(
+            inverse.apply_point(&Vector3::new(1e-6, 0.0, 0.0)),
+            Vector3::new(1.0, 0.0, 0.0),
+        );
+        assert!(flat.apply_direction(&Vector3::new(0.0, 1.0, 0.0)).is_none());
+
+        let result = Transform3D::<f64>::identity()
+            .rotate(&Vector3::new(0.0, 0.0, 0.0), Angle::from_degrees(45.0));
+        assert!(matches!(result, Err(TransformError::ZeroVector(_))));
+    }
+
+    #[test]
+    fn test_isotropy_guards() {
+        let similarity = Transform3D::identity()
+            .rotate(&Vector3::new(1.0, 2.0, 3.0), Angle::from_degrees(40.0))
+            .unwrap()
+            .uniform_scale(0.5)
+            .translate(&Vector3::new(1.0, 1.0, 1.0));
+        assert!(similarity.ensure_non_singular(1e-10).is_ok());
+        assert!((similarity.uniform_scale_factor(1e-10).unwrap() - 0.5).abs() < 1e-12);
+
+        let stretched = similarity.scale(1.0, 1.0, 2.0);
+        assert!(matches!(
+            stretched.uniform_scale_factor(1e-10),
+            Err(TransformError::NonUniformScale(_))
+        ));
+
+        let flat = similarity.scale(0.0, 1.0, 1.0);
+        assert!(matches!(
+            flat.ensure_non_singular(1e-10),
+            Err(TransformError::SingularTransform(_))
+        ));
+    }
+}
@@ -15,6 +15,10 @@ pub enum TransformError {
     InvalidScaleFactor(String),
     /// 不正な回転パラメータ
     InvalidRotation(String),
+    /// 特異な変換行列（行列式が許容誤差以下で、形状が潰れる）
+    SingularTransform(String),
+    /// 非一様スケール（等方形状が等方のままでいられない。例：円 → 楕円）
+    NonUniformScale(String),
 }
 
 impl fmt::Display for TransformError {
@@ -26,6 +30,8 @@ impl fmt::Display for TransformError {
             TransformError::ZeroVector(msg) => write!(f, "ゼロベクトルは正規化できません: {}", msg),
             TransformError::InvalidScaleFactor(msg) => write!(f, "不正なスケール倍率です: {}", msg),
             TransformError::InvalidRotation(msg) => write!(f, "不正な回転パラメータです: {}", msg),
+            TransformError::SingularTransform(msg) => write!(f, "特異な変換行列です: {}", msg),
+            TransformError::NonUniformScale(msg) => write!(f, "非一様スケールです: {}", msg),
         }
     }
 }
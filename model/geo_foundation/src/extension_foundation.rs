@@ -131,6 +131,7 @@
 //! この設計により、コンパイル時の型チェックで適切なエラー処理が保証されます。
 
 use crate::classification::PrimitiveKind;
+use crate::core::nurbs_traits::ParametricGeometry;
 use crate::{Angle, Scalar};
 
 // AbstractBBoxを抽象境界ボックスとして定義（より柔軟な実装）
@@ -141,6 +142,100 @@ pub trait AbstractBBox<T: Scalar> {
     fn max(&self) -> Self::Point;
 }
 
+/// 有界な3Dプリミティブの軸平行バウンディングボックス
+///
+/// ExtensionFoundation::bounding_box は保守的な近似を含むが、こちらは形状に接する
+/// タイトなボックスを返す（BVH 構築・ビューフィット用）。無限平面・直線・レイや
+/// 無限長の曲面など、有界でないプリミティブは実装しない。
+pub trait Bounded3D<T: Scalar> {
+    /// 境界ボックスの型（geo_primitives では BBox3D<T>）
+    type BBox: AbstractBBox<T>;
+
+    /// 軸平行バウンディングボックス
+    fn bbox(&self) -> Self::BBox;
+}
+
+/// 3Dパラメトリック曲線の統一インターフェース
+///
+/// 曲線種に依存しない経路処理（サンプリング・長さ計算など）のための共通トレイト。
+/// パラメータ `t` は各曲線の自然なパラメータをそのまま使い、[0, 1] には正規化しない。
+/// [0, 1] への正規化が必要な場合は、全実装に付く `ParametricGeometry` を使う。
+///
+/// | 曲線 | `domain()` |
+/// |------|------------|
+/// | 線分 | 始点からの距離 `(0, 長さ)` |
+/// | 円弧・楕円弧 | 角度（ラジアン、楕円は離心角） `(開始角, 開始角 + 角度スパン)` |
+/// | NURBS 曲線 | ノットベクトルの有効定義域 `(u_p, u_{m-p})` |
+///
+/// 2πをまたぐ弧では終了値が2πを超える（開始角から単調に増加する）。
+/// `tangent_at` はパラメータによる1階微分で正規化しないため、`length` は
+/// 定義域上の `|tangent_at(t)|` の積分に一致する。
+pub trait ParametricCurve3D<T: Scalar> {
+    /// 点の型（geo_primitives では Point3D<T>）
+    type Point;
+    /// ベクトルの型（geo_primitives では Vector3D<T>）
+    type Vector;
+
+    /// パラメータ `t` での曲線上の点
+    fn point_at(&self, t: T) -> Self::Point;
+
+    /// パラメータ `t` での1階微分（正規化しない）
+    fn tangent_at(&self, t: T) -> Self::Vector;
+
+    /// パラメータの定義域 `(開始, 終了)`
+    fn domain(&self) -> (T, T);
+
+    /// 曲線の長さ
+    fn length(&self) -> T;
+
+    /// 定義域をパラメータについて等間隔に `count` 点サンプリング（両端を含む）
+    ///
+    /// `count` が1なら開始点のみ、0なら空。
+    fn sample_uniform(&self, count: usize) -> Vec<Self::Point> {
+        let (start, end) = self.domain();
+        match count {
+            0 => Vec::new(),
+            1 => vec![self.point_at(start)],
+            _ => {
+                let last = T::from_usize(count - 1);
+                (0..count)
+                    .map(|i| self.point_at(start + (end - start) * T::from_usize(i) / last))
+                    .collect()
+            }
+        }
+    }
+}
+
+/// `ParametricCurve3D` の定義域に基づくパラメータ操作
+///
+/// 正規化パラメータは `domain()` の開始を0、終了を1とする。定義域の幅が0なら常に0を返す。
+impl<T: Scalar, C: ParametricCurve3D<T>> ParametricGeometry<T> for C {
+    fn normalize_parameter(&self, parameter: T) -> T {
+        let (start, end) = self.domain();
+        let width = end - start;
+        if width == T::ZERO {
+            T::ZERO
+        } else {
+            (parameter - start) / width
+        }
+    }
+
+    fn denormalize_parameter(&self, normalized_parameter: T) -> T {
+        let (start, end) = self.domain();
+        start + (end - start) * normalized_parameter
+    }
+
+    fn is_parameter_valid(&self, parameter: T) -> bool {
+        let (start, end) = self.domain();
+        start <= parameter && parameter <= end
+    }
+
+    fn clamp_parameter(&self, parameter: T) -> T {
+        let (start, end) = self.domain();
+        parameter.clamp(start, end)
+    }
+}
+
 /// 全ての幾何プリミティブが実装する拡張基盤トレイト（ジェネリック版）
 pub trait ExtensionFoundation<T: Scalar = f64> {
     /// 境界ボックスの型（BBoxに統一）
@@ -155,6 +155,17 @@ pub trait PointDistance<T: Scalar> {
     /// # 戻り値
     /// オブジェクト上の最近点
     fn closest_point(&self, point: &Self::Point2D) -> Self::Point2D;
+
+    /// 最短距離と最近点を同時に取得
+    ///
+    /// # 引数
+    /// * `point` - 基準となる点
+    ///
+    /// # 戻り値
+    /// (点までの最短距離, オブジェクト上の最近点)
+    fn distance_to_with_closest(&self, point: &Self::Point2D) -> (T, Self::Point2D) {
+        (self.distance_to_point(point), self.closest_point(point))
+    }
 }
 
 /// BBox による高速事前スクリーニング
@@ -15,4 +15,4 @@ pub use collision::*;
 pub use intersection::*;
 pub use nurbs::*;
 // pub use transform::*; // → core::transformでre-export
-pub use transform_error::{SafeTransform, TransformError};
+pub use transform_error::{check_non_singular, check_uniform_scale, SafeTransform, TransformError};
@@ -2,6 +2,7 @@
 //!
 //! 幾何変換操作で発生する可能性のあるエラーを定義します。
 
+use analysis::linalg::matrix::Matrix4x4;
 use std::fmt;
 
 /// Transform 操作で発生する可能性のあるエラー
@@ -15,6 +16,10 @@ pub enum TransformError {
     InvalidScaleFactor(String),
     /// 不正な回転パラメータ
     InvalidRotation(String),
+    /// 特異な変換行列（行列式が許容誤差以下で、形状が潰れる）
+    SingularTransform(String),
+    /// 非一様スケール（等方形状が等方のままでいられない。例：円 → 楕円）
+    NonUniformScale(String),
 }
 
 impl fmt::Display for TransformError {
@@ -26,6 +31,8 @@ impl fmt::Display for TransformError {
             TransformError::ZeroVector(msg) => write!(f, "ゼロベクトルは正規化できません: {}", msg),
             TransformError::InvalidScaleFactor(msg) => write!(f, "不正なスケール倍率です: {}", msg),
             TransformError::InvalidRotation(msg) => write!(f, "不正な回転パラメータです: {}", msg),
+            TransformError::SingularTransform(msg) => write!(f, "特異な変換行列です: {}", msg),
+            TransformError::NonUniformScale(msg) => write!(f, "非一様スケールです: {}", msg),
         }
     }
 }
@@ -56,3 +63,129 @@ pub trait SafeTransform<T: crate::Scalar> {
     where
         Self: Sized;
 }
+
+/// 変換行列が特異でないことを確認する（適用前のガード）
+///
+/// 線形部分（左上3x3）の行列式の絶対値が `tolerance × s³`（`s` は列ベクトルの最大長）
+/// 以下なら `TransformError::SingularTransform` を返す。スケールゼロなどで形状が潰れるのを防ぐ。
+/// 行列の大きさに対する相対判定なので、一様スケール 1e-4 のような小さな相似変換は通す。
+pub fn check_non_singular<T: crate::Scalar>(
+    matrix: &Matrix4x4<T>,
+    tolerance: T,
+) -> Result<(), TransformError> {
+    let m = matrix.linear_part_3d();
+    let determinant = m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+        - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+        + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0]);
+    let scale = (0..3)
+        .map(|j| (m[0][j] * m[0][j] + m[1][j] * m[1][j] + m[2][j] * m[2][j]).sqrt())
+        .fold(T::ZERO, |max, norm| max.max(norm));
+    let threshold = tolerance * scale * scale * scale;
+    if determinant.abs() <= threshold {
+        return Err(TransformError::SingularTransform(format!(
+            "determinant {} is within tolerance {} (relative {})",
+            determinant.to_f64(),
+            threshold.to_f64(),
+            tolerance.to_f64()
+        )));
+    }
+    Ok(())
+}
+
+/// 等方形状（円・球など）に適用できる相似変換かを確認し、一様スケール倍率を返す
+///
+/// 特異なら `SingularTransform`、線形部分が「一様スケール × 回転（鏡映を含む）」で
+/// 表せなければ `NonUniformScale` を返す。後者の場合、呼び出し側は楕円・楕円体への
+/// 変換を選べる。`tolerance` は行列式の判定と、各軸スケールの相対差・直交性の判定に使う。
+pub fn check_uniform_scale<T: crate::Scalar>(
+    matrix: &Matrix4x4<T>,
+    tolerance: T,
+) -> Result<T, TransformError> {
+    check_non_singular(matrix, tolerance)?;
+
+    let m = matrix.linear_part_3d();
+    let column = |j: usize| [m[0][j], m[1][j], m[2][j]];
+    let dot = |a: [T; 3], b: [T; 3]| a[0] * b[0] + a[1] * b[1] + a[2] * b[2];
+    let columns = [column(0), column(1), column(2)];
+    let scales = columns.map(|c| dot(c, c).sqrt());
+    let max_scale = scales[0].max(scales[1]).max(scales[2]);
+    let min_scale = scales[0].min(scales[1]).min(scales[2]);
+
+    let squared = max_scale * max_scale;
+    let orthogonal = [(0, 1), (0, 2), (1, 2)]
+        .iter()
+        .all(|&(i, j)| dot(columns[i], columns[j]).abs() <= tolerance * squared);
+    if max_scale - min_scale > tolerance * max_scale || !orthogonal {
+        return Err(TransformError::NonUniformScale(format!(
+            "axis scales ({}, {}, {})",
+            scales[0].to_f64(),
+            scales[1].to_f64(),
+            scales[2].to_f64()
+        )));
+    }
+    Ok(max_scale)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use analysis::linalg::vector::Vector3;
+
+    #[test]
+    fn test_check_non_singular() {
+        assert!(check_non_singular(&Matrix4x4::<f64>::identity(), 1e-10).is_ok());
+        assert!(check_non_singular(&Matrix4x4::translation(1.0, 2.0, 3.0), 1e-10).is_ok());
+
+        let flattened = Matrix4x4::scale(2.0, 0.0, 2.0);
+        assert!(matches!(
+            check_non_singular(&flattened, 1e-10),
+            Err(TransformError::SingularTransform(_))
+        ));
+
+        // 行列の大きさに対する相対判定：小さな相似変換は特異ではない
+        assert!(check_non_singular(&Matrix4x4::<f64>::uniform_scale_3d(1e-4), 1e-10).is_ok());
+        assert!(
+            (check_uniform_scale(&Matrix4x4::uniform_scale_3d(1e-4_f64), 1e-10).unwrap() - 1e-4)
+                .abs()
+                < 1e-16
+        );
+        // 大きな行列でも潰れた軸があれば特異
+        assert!(matches!(
+            check_non_singular(&Matrix4x4::scale(1e6, 1e-6, 1e6), 1e-10),
+            Err(TransformError::SingularTransform(_))
+        ));
+    }
+
+    #[test]
+    fn test_check_uniform_scale() {
+        let rotation = Matrix4x4::<f64>::rotation_axis(&Vector3::new(0.0, 0.0, 1.0), 0.7);
+        let similarity =
+            Matrix4x4::translation(1.0, -1.0, 0.0) * rotation * Matrix4x4::scale(3.0, 3.0, 3.0);
+        let scale = check_uniform_scale(&similarity, 1e-10).unwrap();
+        assert!((scale - 3.0).abs() < 1e-12);
+
+        // 鏡映も等方性を保つ
+        let mirror = Matrix4x4::<f64>::scale(-2.0, 2.0, 2.0);
+        assert!((check_uniform_scale(&mirror, 1e-10).unwrap() - 2.0).abs() < 1e-12);
+
+        let stretched = Matrix4x4::scale(1.0, 2.0, 1.0);
+        assert!(matches!(
+            check_uniform_scale(&stretched, 1e-10),
+            Err(TransformError::NonUniformScale(_))
+        ));
+
+        // 各軸の長さが等しくても直交していなければ非一様
+        let mut skewed = Matrix4x4::<f64>::identity();
+        skewed.set(0, 1, 0.5);
+        skewed.set(1, 1, 0.75f64.sqrt());
+        assert!(matches!(
+            check_uniform_scale(&skewed, 1e-10),
+            Err(TransformError::NonUniformScale(_))
+        ));
+
+        assert!(matches!(
+            check_uniform_scale(&Matrix4x4::uniform_scale_3d(0.0), 1e-10),
+            Err(TransformError::SingularTransform(_))
+        ));
+    }
+}
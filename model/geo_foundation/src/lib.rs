@@ -13,7 +13,7 @@ pub mod extension_foundation;
 pub mod classification;
 
 // Classification - 幾何プリミティブの分類
-pub use classification::{DimensionClass, GeometryPrimitive, PrimitiveKind};
+pub use classification::{count_by_kind, DimensionClass, GeometryPrimitive, PrimitiveKind};
 
 // Core Traits - 基本機能抽象化（主要インターフェース）
 pub mod core;
@@ -54,8 +54,8 @@ pub use core::{
 
 // Extension Foundation Traitsを再エクスポート
 pub use extension_foundation::{
-    CollectionExtension, ExtensionFoundation, MeasurableExtension, SpatialExtension,
-    TransformableExtension,
+    Bounded3D, CollectionExtension, ExtensionFoundation, MeasurableExtension, ParametricCurve3D,
+    SpatialExtension, TransformableExtension,
 };
 
 // Core Transform Traitsを再エクスポート（extensions → core移動）
@@ -63,6 +63,7 @@ pub use core::transform::{
     AnalysisTransform2D, AnalysisTransform3D, AnalysisTransformSupport, AnalysisTransformVector2D,
     AnalysisTransformVector3D,
 };
+pub use core::transform3d::Transform3D;
 
 // Core Transform Errorを再エクスポート（段階的移行: 両方からアクセス可能）
 pub use core::transform_error::{
@@ -71,6 +72,8 @@ pub use core::transform_error::{
 
 // Extension Traitsを再エクスポート(既存互換性維持)
 pub use extensions::{
+    check_non_singular,
+    check_uniform_scale,
     AdvancedCollision,
     BasicCollision,
     BasicIntersection,
@@ -87,7 +90,7 @@ pub use extensions::{
 // };
 
 // 許容誤差管理を再エクスポート
-pub use tolerance::{GeometryContext, ToleranceSettings};
+pub use tolerance::{ContextTolerantEq, GeometryContext, ToleranceSettings};
 
 // Note: 具体的な型は geo_primitives から直接 import してください
 // 循環依存を避けるため、geo_foundation では型の再エクスポートは行いません
@@ -97,13 +100,13 @@ pub mod prelude {
     // Core Transform Traits（core::transformから）
     pub use crate::{
         AnalysisTransform2D, AnalysisTransform3D, AnalysisTransformSupport,
-        AnalysisTransformVector2D, AnalysisTransformVector3D,
+        AnalysisTransformVector2D, AnalysisTransformVector3D, Transform3D,
     };
     // Extension機能(既存互換性維持)
     pub use crate::{
-        GeometryContext, SafeTransform, ToleranceSettings, TransformError, DEG_TO_RAD, E,
-        GEOMETRIC_ANGLE_TOLERANCE, GEOMETRIC_DISTANCE_TOLERANCE, PI, PI_2, PI_3, PI_4, PI_6,
-        RAD_TO_DEG, TAU,
+        ContextTolerantEq, GeometryContext, SafeTransform, ToleranceSettings, TransformError,
+        DEG_TO_RAD, E, GEOMETRIC_ANGLE_TOLERANCE, GEOMETRIC_DISTANCE_TOLERANCE, PI, PI_2, PI_3,
+        PI_4, PI_6, RAD_TO_DEG, TAU,
     };
     // Core Transform Errorルート(段階的移行用)
     pub use crate::{CoreSafeTransform, CoreTransformError};
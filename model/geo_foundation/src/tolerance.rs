@@ -1,8 +1,12 @@
 //! 幾何計算のための許容誤差管理
 //!
 //! アプリケーションレベルでの許容誤差制御を提供
+//!
+//! 距離の比較には絶対許容誤差に加えて相対許容誤差を指定でき、値の大きさ `m` に対して
+//! `max(絶対, 相対 × m)` を使う（地理座標のように原点から遠いモデルでも、丸め誤差に
+//! 見合った許容誤差で比較できる）。相対許容誤差が0なら絶対許容誤差だけの比較になる。
 
-use crate::Scalar;
+use crate::{Scalar, TolerantEq};
 
 /// アプリケーション固有の許容誤差設定
 ///
@@ -20,6 +24,9 @@ pub struct ToleranceSettings<T: Scalar> {
 
     /// 長さ計算用の許容誤差
     pub length_tolerance: T,
+
+    /// 距離・長さの比較に使う相対許容誤差（値の大きさに対する比、0なら絶対許容誤差のみ）
+    pub relative_tolerance: T,
 }
 
 impl<T: Scalar> ToleranceSettings<T> {
@@ -30,6 +37,7 @@ impl<T: Scalar> ToleranceSettings<T> {
             angle_tolerance: T::from_f64(1e-10),
             area_tolerance: T::from_f64(1e-10),
             length_tolerance: T::from_f64(1e-12),
+            relative_tolerance: T::ZERO,
         }
     }
 
@@ -40,6 +48,7 @@ impl<T: Scalar> ToleranceSettings<T> {
             angle_tolerance: T::from_f64(1e-4),
             area_tolerance: T::from_f64(1e-6),
             length_tolerance: T::from_f64(1e-6),
+            relative_tolerance: T::ZERO,
         }
     }
 
@@ -50,18 +59,41 @@ impl<T: Scalar> ToleranceSettings<T> {
             angle_tolerance: T::from_f64(1e-2),
             area_tolerance: T::from_f64(1e-3),
             length_tolerance: T::from_f64(1e-3),
+            relative_tolerance: T::ZERO,
         }
     }
 
-    /// カスタム設定
+    /// カスタム設定（相対許容誤差は0）
     pub fn custom(distance: T, angle: T, area: T, length: T) -> Self {
         Self {
             distance_tolerance: distance,
             angle_tolerance: angle,
             area_tolerance: area,
             length_tolerance: length,
+            relative_tolerance: T::ZERO,
         }
     }
+
+    /// 相対許容誤差を設定した設定を返す
+    pub fn with_relative_tolerance(mut self, relative: T) -> Self {
+        self.relative_tolerance = relative;
+        self
+    }
+
+    /// 大きさ `magnitude` の値の比較に使う許容誤差 `max(absolute, relative × |magnitude|)`
+    pub fn scaled_tolerance(&self, absolute: T, magnitude: T) -> T {
+        absolute.max(self.relative_tolerance * magnitude.abs())
+    }
+
+    /// 大きさ `magnitude` の値の比較に使う距離許容誤差
+    pub fn distance_tolerance_at(&self, magnitude: T) -> T {
+        self.scaled_tolerance(self.distance_tolerance, magnitude)
+    }
+
+    /// 大きさ `magnitude` の値の比較に使う長さ許容誤差
+    pub fn length_tolerance_at(&self, magnitude: T) -> T {
+        self.scaled_tolerance(self.length_tolerance, magnitude)
+    }
 }
 
 /// デフォルトは標準設定
@@ -99,6 +131,18 @@ impl<T: Scalar> GeometryContext<T> {
     pub fn relaxed() -> Self {
         Self::new(ToleranceSettings::relaxed())
     }
+
+    /// 2つの距離（座標値）が距離許容誤差の範囲で等しいか
+    ///
+    /// 許容誤差は大きい方の絶対値に応じて相対許容誤差で広げる。
+    pub fn distance_eq(&self, a: T, b: T) -> bool {
+        (a - b).abs() <= self.tolerances.distance_tolerance_at(a.abs().max(b.abs()))
+    }
+
+    /// 2つの長さが長さ許容誤差の範囲で等しいか（大きい方に応じて相対許容誤差で広げる）
+    pub fn length_eq(&self, a: T, b: T) -> bool {
+        (a - b).abs() <= self.tolerances.length_tolerance_at(a.abs().max(b.abs()))
+    }
 }
 
 impl<T: Scalar> Default for GeometryContext<T> {
@@ -106,3 +150,27 @@ impl<T: Scalar> Default for GeometryContext<T> {
         Self::standard()
     }
 }
+
+/// GeometryContext の許容誤差で比較する TolerantEq
+///
+/// 型ごとに使う許容誤差（点・ベクトルは距離、方向は角度）を
+/// `context_tolerance` で選び、`tolerant_eq` に渡す。
+/// `context_magnitude` が値の大きさを返す型では、相対許容誤差で許容誤差を広げる。
+pub trait ContextTolerantEq<T: Scalar>: TolerantEq<T> {
+    /// この型の比較に使う許容誤差をコンテキストから取り出す
+    fn context_tolerance(context: &GeometryContext<T>) -> T;
+
+    /// 相対許容誤差の基準にする値の大きさ（既定は0で、絶対許容誤差のみ）
+    fn context_magnitude(&self) -> T {
+        T::ZERO
+    }
+
+    /// コンテキストの許容誤差で等価比較
+    fn context_eq(&self, other: &Self, context: &GeometryContext<T>) -> bool {
+        let magnitude = self.context_magnitude().max(other.context_magnitude());
+        let tolerance = context
+            .tolerances
+            .scaled_tolerance(Self::context_tolerance(context), magnitude);
+        self.tolerant_eq(other, tolerance)
+    }
+}
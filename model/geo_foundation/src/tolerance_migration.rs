@@ -48,6 +48,7 @@ impl DefaultTolerances {
             angle_tolerance: Self::angle(),
             area_tolerance: Self::distance(),
             length_tolerance: Self::distance(),
+            relative_tolerance: T::ZERO,
         }
     }
 }
@@ -40,6 +40,38 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_relative_tolerance() {
+        // 既定では相対許容誤差は0で、大きさによらず絶対許容誤差
+        let standard = ToleranceSettings::<f64>::standard();
+        assert_eq!(standard.relative_tolerance, 0.0);
+        assert_eq!(
+            standard.distance_tolerance_at(1e9),
+            standard.distance_tolerance
+        );
+
+        let settings = standard.with_relative_tolerance(1e-10);
+        assert_eq!(settings.distance_tolerance_at(1.0), 1e-6);
+        assert!((settings.distance_tolerance_at(-1e7) - 1e-3).abs() < 1e-15);
+        assert_eq!(
+            settings.length_tolerance_at(1e7),
+            settings.distance_tolerance_at(1e7)
+        );
+
+        // 大きな座標値は相対許容誤差でのみ等しいとみなされる
+        let absolute = GeometryContext::<f64>::standard();
+        let relative = GeometryContext::new(settings);
+        assert!(!absolute.distance_eq(1e7, 1e7 + 5e-4));
+        assert!(relative.distance_eq(1e7, 1e7 + 5e-4));
+        assert!(!relative.distance_eq(1e7, 1e7 + 5e-3));
+
+        // 小さな値では絶対許容誤差と同じ判定
+        for context in [&absolute, &relative] {
+            assert!(context.length_eq(1.0, 1.0 + 5e-7));
+            assert!(!context.length_eq(1.0, 1.0 + 2e-6));
+        }
+    }
+
     #[test]
     fn test_f32_compatibility() {
         // f32での使用例
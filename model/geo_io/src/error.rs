@@ -41,6 +41,7 @@ impl From<std::io::Error> for IoError {
 
 /// STL固有のエラー
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum StlError {
     /// ファイルI/Oエラー
     Io(IoError),
@@ -54,6 +55,8 @@ pub enum StlError {
     FormatDetection(String),
     /// 精度変換エラー
     PrecisionConversion(String),
+    /// 進捗コールバックによる読み込みの中断
+    Cancelled,
 }
 
 impl fmt::Display for StlError {
@@ -65,6 +68,7 @@ impl fmt::Display for StlError {
             StlError::InvalidTriangle(msg) => write!(f, "Invalid triangle: {}", msg),
             StlError::FormatDetection(msg) => write!(f, "Format detection failed: {}", msg),
             StlError::PrecisionConversion(msg) => write!(f, "Precision conversion failed: {}", msg),
+            StlError::Cancelled => write!(f, "STL loading cancelled"),
         }
     }
 }
@@ -89,3 +93,49 @@ impl From<std::io::Error> for StlError {
         StlError::Io(IoError::FileSystem(err))
     }
 }
+
+/// OFF固有のエラー
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum OffError {
+    /// ファイルI/Oエラー
+    Io(IoError),
+    /// 無効なヘッダー（キーワード・要素数の行）
+    InvalidHeader(String),
+    /// 無効な頂点行
+    InvalidVertex(String),
+    /// 無効な面行
+    InvalidFace(String),
+}
+
+impl fmt::Display for OffError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OffError::Io(err) => write!(f, "OFF I/O error: {}", err),
+            OffError::InvalidHeader(msg) => write!(f, "Invalid OFF header: {}", msg),
+            OffError::InvalidVertex(msg) => write!(f, "Invalid vertex: {}", msg),
+            OffError::InvalidFace(msg) => write!(f, "Invalid face: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for OffError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            OffError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<IoError> for OffError {
+    fn from(err: IoError) -> Self {
+        OffError::Io(err)
+    }
+}
+
+impl From<std::io::Error> for OffError {
+    fn from(err: std::io::Error) -> Self {
+        OffError::Io(IoError::FileSystem(err))
+    }
+}
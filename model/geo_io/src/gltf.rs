@@ -0,0 +1,288 @@
+//! glTF 2.0 バイナリ（GLB）形式でのメッシュ書き出し
+//!
+//! メッシュごとに頂点（`POSITION`）・法線（`NORMAL`）・頂点カラー（`COLOR_0`、ある場合のみ）・
+//! インデックスをそれぞれ1つのバッファビューとアクセサに格納し、メッシュ1つにつき
+//! ノードを1つ持つシーンを作る。座標・法線・カラーは `f32`、インデックスは `u32`。
+//!
+//! GLB は12バイトのヘッダーに続けて JSON チャンクとバイナリチャンクを並べたもので、
+//! 各チャンクは4バイト境界に揃える（JSON は空白、バイナリは0で詰める）。
+//! メッシュに法線がなければ面積加重の頂点法線を計算して出力する。
+
+use crate::error::IoError;
+use crate::off::VertexColor;
+use geo_primitives::{TriangleMesh3D, Vector3D};
+use serde_json::{json, Value};
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+/// GLB ヘッダーのマジック（"glTF"）
+const GLB_MAGIC: u32 = 0x4654_6C67;
+
+/// GLB のバージョン
+const GLB_VERSION: u32 = 2;
+
+/// JSON チャンクの種別（"JSON"）
+const CHUNK_JSON: u32 = 0x4E4F_534A;
+
+/// バイナリチャンクの種別（"BIN\0"）
+const CHUNK_BIN: u32 = 0x004E_4942;
+
+/// アクセサの成分型 FLOAT
+const COMPONENT_FLOAT: u32 = 5126;
+
+/// アクセサの成分型 `UNSIGNED_INT`
+const COMPONENT_UNSIGNED_INT: u32 = 5125;
+
+/// バッファビューの用途 `ARRAY_BUFFER`（頂点属性）
+const TARGET_ARRAY_BUFFER: u32 = 34962;
+
+/// バッファビューの用途 `ELEMENT_ARRAY_BUFFER`（インデックス）
+const TARGET_ELEMENT_ARRAY_BUFFER: u32 = 34963;
+
+/// メッシュ群を GLB ファイルとして保存
+///
+/// メッシュごとに1つのノードになる。メッシュが1つもない場合や、三角形を持たないメッシュは
+/// `IoError::Conversion`。
+pub fn export_glb(meshes: &[TriangleMesh3D<f64>], path: &Path) -> Result<(), IoError> {
+    export_glb_with_colors(meshes, &vec![None; meshes.len()], path)
+}
+
+/// 頂点カラー付きでメッシュ群を GLB ファイルとして保存
+///
+/// `vertex_colors[i]` が `Some` なら `meshes[i]` の `COLOR_0`（RGBA）として出力する。
+/// 色の数がメッシュ数・頂点数と一致しない場合は `IoError::Conversion`。
+pub fn export_glb_with_colors(
+    meshes: &[TriangleMesh3D<f64>],
+    vertex_colors: &[Option<&[VertexColor]>],
+    path: &Path,
+) -> Result<(), IoError> {
+    let glb = build_glb(meshes, vertex_colors)?;
+    let mut file = BufWriter::new(File::create(path)?);
+    file.write_all(&glb)?;
+    file.flush()?;
+    Ok(())
+}
+
+/// GLB のバイト列を組み立てる
+fn build_glb(
+    meshes: &[TriangleMesh3D<f64>],
+    vertex_colors: &[Option<&[VertexColor]>],
+) -> Result<Vec<u8>, IoError> {
+    if meshes.is_empty() {
+        return Err(IoError::Conversion("no meshes to export".to_string()));
+    }
+    if vertex_colors.len() != meshes.len() {
+        return Err(IoError::Conversion(format!(
+            "vertex color count {} does not match mesh count {}",
+            vertex_colors.len(),
+            meshes.len()
+        )));
+    }
+
+    let mut builder = BufferBuilder::default();
+    let mut gltf_meshes = Vec::with_capacity(meshes.len());
+    for (index, (mesh, colors)) in meshes.iter().zip(vertex_colors).enumerate() {
+        if mesh.triangle_count() == 0 {
+            return Err(IoError::Conversion(format!(
+                "mesh {} has no triangles",
+                index
+            )));
+        }
+        if let Some(colors) = colors {
+            if colors.len() != mesh.vertex_count() {
+                return Err(IoError::Conversion(format!(
+                    "mesh {}: vertex color count {} does not match vertex count {}",
+                    index,
+                    colors.len(),
+                    mesh.vertex_count()
+                )));
+            }
+        }
+        gltf_meshes.push(builder.push_mesh(mesh, *colors)?);
+    }
+
+    let node_count = meshes.len();
+    let document = json!({
+        "asset": { "version": "2.0", "generator": "RedRing geo_io" },
+        "scene": 0,
+        "scenes": [{ "nodes": (0..node_count).collect::<Vec<_>>() }],
+        "nodes": (0..node_count).map(|mesh| json!({ "mesh": mesh })).collect::<Vec<_>>(),
+        "meshes": gltf_meshes,
+        "accessors": builder.accessors,
+        "bufferViews": builder.buffer_views,
+        "buffers": [{ "byteLength": builder.data.len() }],
+    });
+
+    let mut json_chunk =
+        serde_json::to_vec(&document).map_err(|err| IoError::Format(Box::new(err)))?;
+    pad_to_four(&mut json_chunk, b' ');
+    let mut bin_chunk = builder.data;
+    pad_to_four(&mut bin_chunk, 0);
+
+    let total_length = 12 + 8 + json_chunk.len() + 8 + bin_chunk.len();
+    let mut glb = Vec::with_capacity(total_length);
+    glb.extend_from_slice(&GLB_MAGIC.to_le_bytes());
+    glb.extend_from_slice(&GLB_VERSION.to_le_bytes());
+    glb.extend_from_slice(&to_u32(total_length, "file size")?.to_le_bytes());
+    for (kind, chunk) in [(CHUNK_JSON, &json_chunk), (CHUNK_BIN, &bin_chunk)] {
+        glb.extend_from_slice(&to_u32(chunk.len(), "chunk size")?.to_le_bytes());
+        glb.extend_from_slice(&kind.to_le_bytes());
+        glb.extend_from_slice(chunk);
+    }
+    Ok(glb)
+}
+
+/// バイナリバッファと、それを参照するバッファビュー・アクセサ
+#[derive(Default)]
+struct BufferBuilder {
+    data: Vec<u8>,
+    buffer_views: Vec<Value>,
+    accessors: Vec<Value>,
+}
+
+impl BufferBuilder {
+    /// メッシュの属性とインデックスを追加し、glTF の mesh オブジェクトを返す
+    fn push_mesh(
+        &mut self,
+        mesh: &TriangleMesh3D<f64>,
+        colors: Option<&[VertexColor]>,
+    ) -> Result<Value, IoError> {
+        let positions: Vec<[f32; 3]> = mesh
+            .vertices()
+            .iter()
+            .map(|vertex| [vertex.x() as f32, vertex.y() as f32, vertex.z() as f32])
+            .collect();
+        // glTF の NORMAL は単位ベクトルでなければならない
+        let normals: Vec<[f32; 3]> = match mesh.normals() {
+            Some(normals) => normals.iter().copied().map(unit_normal).collect(),
+            None => vertex_normals(mesh),
+        };
+
+        // POSITION は min / max が必須
+        let mut min = [f32::INFINITY; 3];
+        let mut max = [f32::NEG_INFINITY; 3];
+        for position in &positions {
+            for axis in 0..3 {
+                min[axis] = min[axis].min(position[axis]);
+                max[axis] = max[axis].max(position[axis]);
+            }
+        }
+
+        let position = self.push_accessor(
+            &positions.concat(),
+            positions.len(),
+            "VEC3",
+            Some((&min, &max)),
+        )?;
+        let normal = self.push_accessor(&normals.concat(), normals.len(), "VEC3", None)?;
+        let mut attributes = json!({ "POSITION": position, "NORMAL": normal });
+        if let Some(colors) = colors {
+            attributes["COLOR_0"] =
+                json!(self.push_accessor(&colors.concat(), colors.len(), "VEC4", None)?);
+        }
+
+        let indices = mesh
+            .indices()
+            .iter()
+            .flatten()
+            .map(|&index| to_u32(index, "vertex index"))
+            .collect::<Result<Vec<u32>, _>>()?;
+        let view = self.push_view(
+            indices.iter().flat_map(|index| index.to_le_bytes()),
+            TARGET_ELEMENT_ARRAY_BUFFER,
+        )?;
+        self.accessors.push(json!({
+            "bufferView": view,
+            "componentType": COMPONENT_UNSIGNED_INT,
+            "count": indices.len(),
+            "type": "SCALAR",
+        }));
+        let indices = self.accessors.len() - 1;
+
+        Ok(json!({
+            "primitives": [{ "attributes": attributes, "indices": indices, "mode": 4 }],
+        }))
+    }
+
+    /// `f32` の頂点属性をバッファビューとアクセサに追加し、アクセサの番号を返す
+    fn push_accessor(
+        &mut self,
+        values: &[f32],
+        count: usize,
+        kind: &str,
+        bounds: Option<(&[f32; 3], &[f32; 3])>,
+    ) -> Result<usize, IoError> {
+        let view = self.push_view(
+            values.iter().flat_map(|value| value.to_le_bytes()),
+            TARGET_ARRAY_BUFFER,
+        )?;
+        let mut accessor = json!({
+            "bufferView": view,
+            "componentType": COMPONENT_FLOAT,
+            "count": count,
+            "type": kind,
+        });
+        if let Some((min, max)) = bounds {
+            accessor["min"] = json!(min);
+            accessor["max"] = json!(max);
+        }
+        self.accessors.push(accessor);
+        Ok(self.accessors.len() - 1)
+    }
+
+    /// バイト列をバッファの末尾に追加してバッファビューの番号を返す
+    ///
+    /// 成分はすべて4バイトなので、追加後もバッファは4バイト境界に揃っている。
+    fn push_view(
+        &mut self,
+        bytes: impl Iterator<Item = u8>,
+        target: u32,
+    ) -> Result<usize, IoError> {
+        let offset = self.data.len();
+        self.data.extend(bytes);
+        self.buffer_views.push(json!({
+            "buffer": 0,
+            "byteOffset": to_u32(offset, "buffer offset")?,
+            "byteLength": self.data.len() - offset,
+            "target": target,
+        }));
+        Ok(self.buffer_views.len() - 1)
+    }
+}
+
+/// 面積加重の頂点法線（面積ゼロの三角形にしか接しない頂点は +Z）
+fn vertex_normals(mesh: &TriangleMesh3D<f64>) -> Vec<[f32; 3]> {
+    let vertices = mesh.vertices();
+    let mut accumulated = vec![Vector3D::new(0.0, 0.0, 0.0); vertices.len()];
+    for triangle in mesh.indices() {
+        let [a, b, c] = triangle.map(|index| vertices[index]);
+        // 外積の長さは面積の2倍なので、そのまま足すと面積加重になる
+        let cross = Vector3D::from_points(&a, &b).cross(&Vector3D::from_points(&a, &c));
+        for &index in triangle {
+            accumulated[index] = accumulated[index] + cross;
+        }
+    }
+    accumulated.into_iter().map(unit_normal).collect()
+}
+
+/// 法線を正規化して `f32` にする（長さゼロや非有限なら +Z）
+fn unit_normal(normal: Vector3D<f64>) -> [f32; 3] {
+    match normal.try_normalize() {
+        Some(normal) => [normal.x() as f32, normal.y() as f32, normal.z() as f32],
+        None => [0.0, 0.0, 1.0],
+    }
+}
+
+/// チャンクの長さを4の倍数に揃える
+fn pad_to_four(chunk: &mut Vec<u8>, padding: u8) {
+    while !chunk.len().is_multiple_of(4) {
+        chunk.push(padding);
+    }
+}
+
+/// glTF が扱える `u32` の範囲に収まるか確かめて変換
+fn to_u32(value: usize, what: &str) -> Result<u32, IoError> {
+    u32::try_from(value)
+        .map_err(|_| IoError::Conversion(format!("{} {} exceeds the glTF limit", what, value)))
+}
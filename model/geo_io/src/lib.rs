@@ -4,7 +4,11 @@
 //!
 //! # サポートフォーマット
 //! - STL (ASCII/Binary)
-//! - OBJ (予定)
+//! - OFF / COFF（頂点カラー付き）
+//! - 3MF（読み込みのみ、単位・変換行列を反映）
+//! - OBJ / DXF（曲線群のワイヤーフレーム書き出しのみ）
+//! - glTF 2.0 バイナリ（GLB、書き出しのみ、頂点カラー対応）
+//! - OBJ メッシュ (予定)
 //! - PLY (予定)
 //!
 //! # 使用例
@@ -22,7 +26,12 @@
 //! ```
 
 pub mod error;
+pub mod gltf;
+pub mod off;
 pub mod stl;
+pub mod threemf;
+pub mod unit;
+pub mod wireframe;
 
 // Re-exports
-pub use error::{IoError, StlError};
+pub use error::{IoError, OffError, StlError};
@@ -0,0 +1,220 @@
+//! OFF（Object File Format）ファイルの読み書き機能
+//!
+//! 行単位で `OFF` キーワード、要素数（頂点数・面数・エッジ数）、頂点座標、面の順に解析する。
+//! 面は「頂点数 i0 i1 …」の形式で、4角形以上の多角形面は先頭頂点からのファンで三角形化する。
+//! `COFF` の頂点カラー（整数なら 0〜255、小数なら 0〜1）は `load_off_with_colors` で取得できる。
+//! `#` 以降はコメントとして無視する。
+
+use crate::error::OffError;
+use geo_foundation::Scalar;
+use geo_primitives::{Point3D, TriangleMesh3D};
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::Path;
+use std::str::FromStr;
+
+/// RGBA 頂点カラー（各成分 0〜1）
+pub type VertexColor = [f32; 4];
+
+/// 頂点カラー付きで読み込んだOFFデータ
+#[derive(Debug, Clone)]
+pub struct OffData<T: Scalar> {
+    /// 三角形化したメッシュ
+    pub mesh: TriangleMesh3D<T>,
+    /// 頂点カラー（`COFF` の場合のみ、頂点と同じ順序）
+    pub vertex_colors: Option<Vec<VertexColor>>,
+}
+
+/// OFFファイルを読み込む
+///
+/// `COFF` の頂点行の4列目以降（カラー）は解析せずに読み飛ばすため、
+/// カラーが欠けている・不正な `COFF` でも座標と面が正しければ読み込める。
+pub fn load_off<T: Scalar + FromStr>(path: &Path) -> Result<TriangleMesh3D<T>, OffError> {
+    read_off(path, false).map(|data| data.mesh)
+}
+
+/// OFFファイルを頂点カラー付きで読み込む
+///
+/// `COFF` の頂点にカラーがない、または解析できない場合は `InvalidVertex` を返す。
+pub fn load_off_with_colors<T: Scalar + FromStr>(path: &Path) -> Result<OffData<T>, OffError> {
+    read_off(path, true)
+}
+
+/// OFFファイルを読み込む（`read_colors` が偽なら `COFF` のカラーを解析しない）
+fn read_off<T: Scalar + FromStr>(path: &Path, read_colors: bool) -> Result<OffData<T>, OffError> {
+    let reader = BufReader::new(File::open(path)?);
+    let mut lines = Vec::new();
+    for (number, line) in reader.lines().enumerate() {
+        let line = line?;
+        let content = line.split('#').next().unwrap_or("").trim();
+        if !content.is_empty() {
+            lines.push((number + 1, content.to_string()));
+        }
+    }
+    parse_off(&lines, read_colors)
+}
+
+/// コメント・空行を除いた (行番号, 内容) の列を解析
+fn parse_off<T: Scalar + FromStr>(
+    lines: &[(usize, String)],
+    read_colors: bool,
+) -> Result<OffData<T>, OffError> {
+    let mut lines = lines.iter();
+    let (_, header) = lines
+        .next()
+        .ok_or_else(|| OffError::InvalidHeader("empty file".to_string()))?;
+
+    // キーワードと要素数は同じ行にあってもよい（例: "OFF 8 6 0"）
+    let mut header_tokens = header.split_whitespace();
+    let with_colors = match header_tokens.next() {
+        Some("OFF") => false,
+        Some("COFF") => read_colors,
+        Some(keyword) => {
+            return Err(OffError::InvalidHeader(format!(
+                "unsupported keyword: {}",
+                keyword
+            )))
+        }
+        None => unreachable!("blank lines are skipped"),
+    };
+    let mut count_tokens: Vec<&str> = header_tokens.collect();
+    if count_tokens.is_empty() {
+        let (_, counts) = lines
+            .next()
+            .ok_or_else(|| OffError::InvalidHeader("missing element counts".to_string()))?;
+        count_tokens = counts.split_whitespace().collect();
+    }
+    if count_tokens.len() < 2 {
+        return Err(OffError::InvalidHeader(format!(
+            "expected vertex and face counts, found: {}",
+            count_tokens.join(" ")
+        )));
+    }
+    let parse_count = |token: &str| {
+        token
+            .parse::<usize>()
+            .map_err(|_| OffError::InvalidHeader(format!("invalid element count: {}", token)))
+    };
+    let vertex_count = parse_count(count_tokens[0])?;
+    let face_count = parse_count(count_tokens[1])?;
+
+    // 要素数はヘッダの値なので、残りの行数を超える確保はしない
+    let vertex_capacity = vertex_count.min(lines.len());
+    let mut vertices = Vec::with_capacity(vertex_capacity);
+    let mut colors = Vec::with_capacity(if with_colors { vertex_capacity } else { 0 });
+    for _ in 0..vertex_count {
+        let (number, line) = lines.next().ok_or_else(|| {
+            OffError::InvalidVertex(format!(
+                "expected {} vertices, found {}",
+                vertex_count,
+                vertices.len()
+            ))
+        })?;
+        let tokens: Vec<&str> = line.split_whitespace().collect();
+        if tokens.len() < 3 {
+            return Err(OffError::InvalidVertex(format!(
+                "line {}: expected 3 coordinates: {}",
+                number, line
+            )));
+        }
+        let coordinate = |token: &str| {
+            token.parse::<T>().map_err(|_| {
+                OffError::InvalidVertex(format!("line {}: invalid coordinate: {}", number, token))
+            })
+        };
+        vertices.push(Point3D::new(
+            coordinate(tokens[0])?,
+            coordinate(tokens[1])?,
+            coordinate(tokens[2])?,
+        ));
+        if with_colors {
+            colors.push(parse_color(&tokens[3..]).ok_or_else(|| {
+                OffError::InvalidVertex(format!("line {}: invalid color: {}", number, line))
+            })?);
+        }
+    }
+
+    let mut indices = Vec::with_capacity(face_count.min(lines.len()));
+    for face in 0..face_count {
+        let (number, line) = lines.next().ok_or_else(|| {
+            OffError::InvalidFace(format!("expected {} faces, found {}", face_count, face))
+        })?;
+        let invalid = || OffError::InvalidFace(format!("line {}: {}", number, line));
+        let mut tokens = line.split_whitespace();
+        let size: usize = tokens
+            .next()
+            .and_then(|token| token.parse().ok())
+            .ok_or_else(invalid)?;
+        if size < 3 {
+            return Err(OffError::InvalidFace(format!(
+                "line {}: face must have at least 3 vertices: {}",
+                number, line
+            )));
+        }
+        // 頂点インデックスの後に続く面カラーは無視する
+        let polygon: Vec<usize> = tokens
+            .by_ref()
+            .take(size)
+            .map(str::parse)
+            .collect::<Result<_, _>>()
+            .map_err(|_| invalid())?;
+        if polygon.len() < size {
+            return Err(invalid());
+        }
+        if let Some(&index) = polygon.iter().find(|&&index| index >= vertex_count) {
+            return Err(OffError::InvalidFace(format!(
+                "line {}: vertex index {} out of range (vertex count {})",
+                number, index, vertex_count
+            )));
+        }
+        // ファン三角形化: (v0, vi, vi+1)
+        for i in 1..size - 1 {
+            indices.push([polygon[0], polygon[i], polygon[i + 1]]);
+        }
+    }
+
+    let mesh = TriangleMesh3D::new(vertices, indices).map_err(OffError::InvalidFace)?;
+    Ok(OffData {
+        mesh,
+        vertex_colors: with_colors.then_some(colors),
+    })
+}
+
+/// `COFF` の色成分（RGB または RGBA）を解析
+///
+/// すべて整数表記なら 0〜255、それ以外は 0〜1 の範囲とみなす。アルファ省略時は不透明。
+fn parse_color(tokens: &[&str]) -> Option<VertexColor> {
+    if tokens.len() != 3 && tokens.len() != 4 {
+        return None;
+    }
+    let integer = tokens.iter().all(|token| token.parse::<u32>().is_ok());
+    let scale = if integer { 255.0 } else { 1.0 };
+    let mut color = [1.0; 4];
+    for (component, token) in color.iter_mut().zip(tokens) {
+        *component = token.parse::<f32>().ok()? / scale;
+    }
+    Some(color)
+}
+
+/// OFFファイルとして保存（面はすべて三角形）
+pub fn save_off<T: Scalar>(mesh: &TriangleMesh3D<T>, path: &Path) -> Result<(), OffError> {
+    let mut file = BufWriter::new(File::create(path)?);
+
+    writeln!(file, "OFF")?;
+    writeln!(file, "{} {} 0", mesh.vertex_count(), mesh.triangle_count())?;
+    for vertex in mesh.vertices() {
+        writeln!(
+            file,
+            "{} {} {}",
+            vertex.x().to_f64(),
+            vertex.y().to_f64(),
+            vertex.z().to_f64()
+        )?;
+    }
+    for [a, b, c] in mesh.indices() {
+        writeln!(file, "3 {} {} {}", a, b, c)?;
+    }
+
+    file.flush()?;
+    Ok(())
+}
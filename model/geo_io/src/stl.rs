@@ -2,15 +2,31 @@
 //!
 //! ASCII STLとBinary STLの両方に対応。
 //! 自動フォーマット判定機能付き。
+//! 巨大なファイル向けに、進捗通知と中断に対応したストリーミング読み込みも提供する。
+//! 壊れたファイル向けに、健全性検査と軽微な修復を行う読み込み（`load_stl_repaired`）も提供する。
+//! 退化三角形だけを読み飛ばす軽量な読み込み（`load_stl_with_options`）もある。
+//! STLは単位情報を持たないため、単位（3MF と共通の [`LengthUnit`]）を指定して
+//! メートルと相互変換する読み書きも提供する。
+//! ASCII STLが複数の `solid ... endsolid` ブロックを含む場合、単一メッシュ版の読み込みは
+//! 全ブロックを1つのメッシュに結合し、`load_stl_multi` はブロックごとに別のメッシュとして読む。
 
 use crate::error::StlError;
+pub use crate::unit::LengthUnit;
 use geo_foundation::Scalar;
 use geo_primitives::{Point3D, TriangleMesh3D, Vector3D};
+use std::collections::HashMap;
 use std::fs::File;
 use std::io::{BufRead, BufReader, Read, Write};
+use std::ops::ControlFlow;
 use std::path::Path;
 use std::str::FromStr;
 
+/// ストリーミング読み込みで進捗を通知する三角形数の間隔
+pub const STREAMING_CHUNK_TRIANGLES: usize = 65_536;
+
+/// Binary STL の1三角形のバイト数（法線12 + 頂点36 + 属性2）
+const BINARY_TRIANGLE_SIZE: usize = 50;
+
 /// STLファイルを読み込む（自動フォーマット判定）
 pub fn load_stl<T: Scalar + FromStr>(path: &Path) -> Result<TriangleMesh3D<T>, StlError>
 where
@@ -28,148 +44,649 @@ pub fn load_ascii_stl<T: Scalar + FromStr>(path: &Path) -> Result<TriangleMesh3D
 where
     T::Err: std::fmt::Debug,
 {
-    let file = File::open(path)?;
-    let reader = BufReader::new(file);
+    let mut builder = MeshBuilder::with_capacity(0);
+    read_ascii_facets(path, &mut no_progress, |_, facet| {
+        builder.facet(facet.vertices)
+    })?;
+    builder.into_mesh()
+}
+
+/// Binary STLファイルを読み込む
+pub fn load_binary_stl<T: Scalar>(path: &Path) -> Result<TriangleMesh3D<T>, StlError> {
+    let mut builder = MeshBuilder::with_capacity(0);
+    read_binary_facets(path, &mut no_progress, |_, facet| {
+        builder.facet(facet.vertices)
+    })?;
+    builder.into_mesh()
+}
+
+/// STLファイルをソリッドごとのメッシュとして読み込む（自動フォーマット判定）
+///
+/// ASCII STLの `solid ... endsolid` ブロックを、ファイル内の順に `(ソリッド名, メッシュ)` として返す。
+/// 名前のないブロック（`solid` 行のない先頭のファセットを含む）は、結果の中での位置から
+/// `solid_1`, `solid_2`, ... と命名する。三角形を含まないブロックは返さない。
+/// Binary STLはソリッド名を持たないため、名前なしの1ブロックとして扱う。
+pub fn load_stl_multi(path: &Path) -> Result<Vec<(String, TriangleMesh3D<f64>)>, StlError> {
+    read_solids(path)?
+        .into_iter()
+        .filter(|(_, builder)| !builder.indices.is_empty())
+        .enumerate()
+        .map(|(index, (name, builder))| {
+            let name = if name.is_empty() {
+                format!("solid_{}", index + 1)
+            } else {
+                name
+            };
+            Ok((name, builder.into_mesh()?))
+        })
+        .collect()
+}
+
+/// ファセットをソリッドごとにまとめて読み込む（名前なしは空文字列）
+///
+/// 単一メッシュ版の読み込みは、同じリーダーでソリッド番号を無視して全ソリッドを1つに結合する。
+fn read_solids(path: &Path) -> Result<Vec<(String, MeshBuilder)>, StlError> {
+    let mut builders: Vec<MeshBuilder> = Vec::new();
+    let names = read_facets(path, &mut no_progress, |solid, facet| {
+        if builders.len() <= solid {
+            builders.resize_with(solid + 1, || MeshBuilder::with_capacity(0));
+        }
+        builders[solid].facet(facet.vertices);
+    })?;
+    builders.resize_with(names.len(), || MeshBuilder::with_capacity(0));
+    Ok(names.into_iter().zip(builders).collect())
+}
+
+/// `solid` 行ならソリッド名（名前なしは空文字列）
+fn solid_name(line: &str) -> Option<&str> {
+    let rest = line.strip_prefix("solid")?;
+    if rest.is_empty() || rest.starts_with(char::is_whitespace) {
+        Some(rest.trim())
+    } else {
+        None
+    }
+}
+
+/// 進捗を通知しながらSTLファイルを読み込む（自動フォーマット判定）
+///
+/// 三角形をチャンク単位で処理し、チャンクごとに `progress(current, total)` を呼ぶ。
+/// 通知の形式は `load_stl_streaming_with_control` を参照。
+pub fn load_stl_streaming<F: FnMut(usize, usize)>(
+    path: &Path,
+    mut progress: F,
+) -> Result<TriangleMesh3D<f64>, StlError> {
+    load_stl_streaming_with_control(path, |current, total| {
+        progress(current, total);
+        ControlFlow::Continue(())
+    })
+}
+
+/// 進捗を通知しながらSTLファイルを読み込み、コールバックから中断できる
+///
+/// `progress(current, total)` は読み込み済みの三角形数と総三角形数で、
+/// 開始時・`STREAMING_CHUNK_TRIANGLES` 個ごと・完了時に呼ばれる。
+/// - Binary STL: ヘッダーの三角形数を `total` として正確な割合を通知する
+/// - ASCII STL: 総数は読み終えるまで不明なため途中は `total = 0`、完了時は `(n, n)`
+///
+/// コールバックが `ControlFlow::Break` を返すと読み込みを中断し `StlError::Cancelled` を返す。
+/// 重複頂点はハッシュで併合するため、数百万三角形でも線形時間で読み込める。
+pub fn load_stl_streaming_with_control<F>(
+    path: &Path,
+    mut progress: F,
+) -> Result<TriangleMesh3D<f64>, StlError>
+where
+    F: FnMut(usize, usize) -> ControlFlow<()>,
+{
+    let mut builder = MeshBuilder::with_capacity(0);
+    read_facets(path, &mut progress, |_, facet| {
+        builder.facet(facet.vertices)
+    })?;
+
+    let count = builder.indices.len();
+    notify(&mut progress, count, count)?;
+    builder.into_mesh()
+}
+
+/// 読み込み済みのファセット（保存されていた法線と3頂点）
+struct RawFacet {
+    normal: Vector3D<f64>,
+    vertices: [Point3D<f64>; 3],
+}
+
+/// STLファイルのファセットを順に `visit(ソリッド番号, ファセット)` へ渡す（自動フォーマット判定）
+///
+/// すべての読み込み関数が共有するリーダーで、結合・ソリッドごとの分割・修復は呼び出し側で行う。
+/// 戻り値はソリッド名の列で、ソリッド番号はその添字（名前なしは空文字列）。
+/// 進捗は `load_stl_streaming_with_control` と同じ形式で通知する（完了時の通知は呼び出し側）。
+fn read_facets<P, V>(path: &Path, progress: &mut P, visit: V) -> Result<Vec<String>, StlError>
+where
+    P: FnMut(usize, usize) -> ControlFlow<()>,
+    V: FnMut(usize, RawFacet),
+{
+    if is_binary_stl(path)? {
+        read_binary_facets(path, progress, visit)
+    } else {
+        read_ascii_facets(path, progress, visit)
+    }
+}
+
+/// Binary STL のファセットを読む（ソリッド名を持たないため名前なしの1ソリッドとする）
+fn read_binary_facets<P, V>(
+    path: &Path,
+    progress: &mut P,
+    mut visit: V,
+) -> Result<Vec<String>, StlError>
+where
+    P: FnMut(usize, usize) -> ControlFlow<()>,
+    V: FnMut(usize, RawFacet),
+{
+    let (mut reader, total) = open_binary_stl(path)?;
+    notify(progress, 0, total)?;
+
+    let mut record = [0u8; BINARY_TRIANGLE_SIZE];
+    for loaded in 1..=total {
+        reader.read_exact(&mut record)?;
+        visit(0, binary_facet(&record));
+        if loaded < total && loaded.is_multiple_of(STREAMING_CHUNK_TRIANGLES) {
+            notify(progress, loaded, total)?;
+        }
+    }
+    Ok(vec![String::new()])
+}
+
+/// Binary STL の1三角形分のレコードを解釈する（末尾2バイトの属性は使わない）
+fn binary_facet(record: &[u8; BINARY_TRIANGLE_SIZE]) -> RawFacet {
+    let read = |offset: usize| {
+        let value = |start: usize| {
+            f64::from(f32::from_le_bytes([
+                record[start],
+                record[start + 1],
+                record[start + 2],
+                record[start + 3],
+            ]))
+        };
+        (value(offset), value(offset + 4), value(offset + 8))
+    };
+    let point = |offset: usize| {
+        let (x, y, z) = read(offset);
+        Point3D::new(x, y, z)
+    };
+    let (nx, ny, nz) = read(0);
+    RawFacet {
+        normal: Vector3D::new(nx, ny, nz),
+        vertices: [point(12), point(24), point(36)],
+    }
+}
 
-    let mut vertices = Vec::new();
-    let mut indices = Vec::new();
-    let mut current_triangle_vertices = Vec::new();
+/// Binary STL を開いてヘッダーを読み、三角形数を返す
+///
+/// ヘッダーの三角形数が壊れていても、ファイルサイズを超える確保はしない。
+fn open_binary_stl(path: &Path) -> Result<(BufReader<File>, usize), StlError> {
+    let file_len = std::fs::metadata(path)?.len();
+    let mut reader = BufReader::new(File::open(path)?);
+
+    let mut header = [0u8; 84];
+    reader.read_exact(&mut header)?;
+    let total = u32::from_le_bytes([header[80], header[81], header[82], header[83]]) as usize;
+
+    let available = (file_len.saturating_sub(84) / BINARY_TRIANGLE_SIZE as u64) as usize;
+    if available < total {
+        return Err(StlError::InvalidFacetCount(format!(
+            "Header declares {} triangles but file holds only {}",
+            total, available
+        )));
+    }
+    Ok((reader, total))
+}
+
+/// ASCII STL のファセットを行単位で読む
+///
+/// `solid` 行の外にあるファセットは名前なしのソリッドにまとめる。
+/// 法線が読めなくても頂点は使えるため、不正な法線はNaNとして返す。
+fn read_ascii_facets<P, V>(
+    path: &Path,
+    progress: &mut P,
+    mut visit: V,
+) -> Result<Vec<String>, StlError>
+where
+    P: FnMut(usize, usize) -> ControlFlow<()>,
+    V: FnMut(usize, RawFacet),
+{
+    let reader = BufReader::new(File::open(path)?);
+    let mut names: Vec<String> = Vec::new();
+    let mut inside_solid = false;
+    let mut normal = Vector3D::new(0.0, 0.0, 0.0);
+    let mut current_triangle_vertices = Vec::with_capacity(3);
+    let mut loaded = 0usize;
+    notify(progress, 0, 0)?;
 
     for line in reader.lines() {
         let line = line?;
         let line = line.trim();
 
-        // Skip empty lines and comments
-        if line.is_empty() || line.starts_with('#') {
+        if let Some(name) = solid_name(line) {
+            names.push(name.to_string());
+            inside_solid = true;
+            continue;
+        } else if line.starts_with("endsolid") {
+            inside_solid = false;
             continue;
         }
 
-        if line.starts_with("solid") {
-            // STL header - continue
-            continue;
-        } else if line.starts_with("endsolid") {
-            // End of STL
-            break;
-        } else if line.starts_with("facet normal") {
-            // Start of new triangle - reset vertex collection
+        if !inside_solid && (line.starts_with("facet") || line.starts_with("vertex")) {
+            names.push(String::new());
+            inside_solid = true;
+        }
+
+        if let Some(components) = line.strip_prefix("facet normal") {
+            let values: Vec<f64> = components
+                .split_whitespace()
+                .map(|value| value.parse().unwrap_or(f64::NAN))
+                .collect();
+            normal = match values[..] {
+                [x, y, z] => Vector3D::new(x, y, z),
+                _ => Vector3D::new(f64::NAN, f64::NAN, f64::NAN),
+            };
             current_triangle_vertices.clear();
         } else if line.starts_with("vertex") {
-            // Parse vertex coordinates
-            let parts: Vec<&str> = line.split_whitespace().collect();
-            if parts.len() != 4 {
-                return Err(StlError::InvalidTriangle(format!(
-                    "Invalid vertex line: {}",
-                    line
-                )));
-            }
-
-            let x = parts[1].parse::<T>().map_err(|_| {
-                StlError::PrecisionConversion(format!("Failed to parse X coordinate: {}", parts[1]))
-            })?;
-            let y = parts[2].parse::<T>().map_err(|_| {
-                StlError::PrecisionConversion(format!("Failed to parse Y coordinate: {}", parts[2]))
-            })?;
-            let z = parts[3].parse::<T>().map_err(|_| {
-                StlError::PrecisionConversion(format!("Failed to parse Z coordinate: {}", parts[3]))
-            })?;
-
-            let vertex = Point3D::new(x, y, z);
-            let vertex_index = add_or_find_vertex(&mut vertices, vertex);
-            current_triangle_vertices.push(vertex_index);
+            current_triangle_vertices.push(parse_vertex_line(line)?);
         } else if line.starts_with("endfacet") {
-            // End of triangle - create triangle indices
-            if current_triangle_vertices.len() != 3 {
+            let [a, b, c] = current_triangle_vertices[..] else {
                 return Err(StlError::InvalidTriangle(format!(
                     "Triangle must have exactly 3 vertices, found {}",
                     current_triangle_vertices.len()
                 )));
+            };
+            visit(
+                names.len() - 1,
+                RawFacet {
+                    normal,
+                    vertices: [a, b, c],
+                },
+            );
+            loaded += 1;
+            if loaded.is_multiple_of(STREAMING_CHUNK_TRIANGLES) {
+                notify(progress, loaded, 0)?;
             }
+        }
+    }
+    Ok(names)
+}
+
+/// 進捗を通知しない読み込み用のコールバック
+fn no_progress(_current: usize, _total: usize) -> ControlFlow<()> {
+    ControlFlow::Continue(())
+}
 
-            indices.push([
-                current_triangle_vertices[0],
-                current_triangle_vertices[1],
-                current_triangle_vertices[2],
-            ]);
+/// 進捗を通知し、中断要求ならエラーにする
+fn notify<F>(progress: &mut F, current: usize, total: usize) -> Result<(), StlError>
+where
+    F: FnMut(usize, usize) -> ControlFlow<()>,
+{
+    match progress(current, total) {
+        ControlFlow::Continue(()) => Ok(()),
+        ControlFlow::Break(()) => Err(StlError::Cancelled),
+    }
+}
+
+/// 読み込み中のメッシュ（座標のビット列で重複頂点を併合）
+struct MeshBuilder {
+    vertices: Vec<Point3D<f64>>,
+    indices: Vec<[usize; 3]>,
+    lookup: HashMap<[u64; 3], usize>,
+}
+
+impl MeshBuilder {
+    fn with_capacity(triangles: usize) -> Self {
+        Self {
+            // 閉じた三角形メッシュの頂点数はおよそ三角形数の半分
+            vertices: Vec::with_capacity(triangles / 2),
+            indices: Vec::with_capacity(triangles),
+            lookup: HashMap::with_capacity(triangles / 2),
         }
     }
 
-    TriangleMesh3D::new(vertices, indices).map_err(StlError::InvalidTriangle)
+    /// 頂点を追加し、そのインデックスを返す（同一座標なら既存のインデックス）
+    fn vertex(&mut self, vertex: Point3D<f64>) -> usize {
+        // +0.0 を足して -0.0 と 0.0 を同じキーにする
+        let key = [
+            (vertex.x() + 0.0).to_bits(),
+            (vertex.y() + 0.0).to_bits(),
+            (vertex.z() + 0.0).to_bits(),
+        ];
+        *self.lookup.entry(key).or_insert_with(|| {
+            self.vertices.push(vertex);
+            self.vertices.len() - 1
+        })
+    }
+
+    /// 3頂点を併合しながら三角形を追加する
+    fn facet(&mut self, vertices: [Point3D<f64>; 3]) {
+        let triangle = vertices.map(|vertex| self.vertex(vertex));
+        self.indices.push(triangle);
+    }
+
+    /// 座標を `T` に変換してメッシュにする
+    fn into_mesh<T: Scalar>(self) -> Result<TriangleMesh3D<T>, StlError> {
+        let vertices = self
+            .vertices
+            .into_iter()
+            .map(|v| Point3D::new(T::from_f64(v.x()), T::from_f64(v.y()), T::from_f64(v.z())))
+            .collect();
+        TriangleMesh3D::new(vertices, self.indices).map_err(StlError::InvalidTriangle)
+    }
 }
 
-/// Binary STLファイルを読み込む
-pub fn load_binary_stl<T: Scalar>(path: &Path) -> Result<TriangleMesh3D<T>, StlError> {
-    let mut file = File::open(path)?;
+/// 修復読み込みで法線が一致しているとみなす、保存法線と再計算法線の内積の下限
+const NORMAL_AGREEMENT_COS: f64 = 0.999;
+
+/// `load_stl_repaired` の修復オプション
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RepairOptions {
+    /// NaN/Inf 座標を含む三角形を除去する
+    pub remove_non_finite: bool,
+    /// 面積ゼロ（頂点の重複・一直線上）の三角形を除去する
+    pub remove_degenerate: bool,
+    /// 頂点の並び順から法線を再計算し、頂点法線を設定する
+    pub recompute_normals: bool,
+    /// 距離 `weld_tolerance` 以内の頂点を1つにまとめる（無効なら三角形ごとに頂点を持つ）
+    pub weld_vertices: bool,
+    /// weld の距離閾値
+    pub weld_tolerance: f64,
+}
 
-    // Skip 80-byte header
-    let mut header = [0u8; 80];
-    file.read_exact(&mut header)?;
-
-    // Read triangle count (4 bytes, little endian)
-    let mut triangle_count_bytes = [0u8; 4];
-    file.read_exact(&mut triangle_count_bytes)?;
-    let triangle_count = u32::from_le_bytes(triangle_count_bytes);
-
-    let mut vertices = Vec::new();
-    let mut indices = Vec::new();
-
-    // Read triangles
-    for _ in 0..triangle_count {
-        // Normal vector (12 bytes, 3 f32s) - we'll ignore this and recalculate
-        let mut normal_bytes = [0u8; 12];
-        file.read_exact(&mut normal_bytes)?;
-
-        // Three vertices (36 bytes, 9 f32s)
-        let mut triangle_vertices = Vec::new();
-
-        for _ in 0..3 {
-            let mut vertex_bytes = [0u8; 12]; // 3 f32s
-            file.read_exact(&mut vertex_bytes)?;
-
-            let x_bytes = [
-                vertex_bytes[0],
-                vertex_bytes[1],
-                vertex_bytes[2],
-                vertex_bytes[3],
-            ];
-            let y_bytes = [
-                vertex_bytes[4],
-                vertex_bytes[5],
-                vertex_bytes[6],
-                vertex_bytes[7],
-            ];
-            let z_bytes = [
-                vertex_bytes[8],
-                vertex_bytes[9],
-                vertex_bytes[10],
-                vertex_bytes[11],
-            ];
-
-            let x_f32 = f32::from_le_bytes(x_bytes);
-            let y_f32 = f32::from_le_bytes(y_bytes);
-            let z_f32 = f32::from_le_bytes(z_bytes);
-
-            // Convert f32 to T
-            let x = T::from_f32(x_f32);
-            let y = T::from_f32(y_f32);
-            let z = T::from_f32(z_f32);
-
-            let vertex = Point3D::new(x, y, z);
-            let vertex_index = add_or_find_vertex(&mut vertices, vertex);
-            triangle_vertices.push(vertex_index);
+impl Default for RepairOptions {
+    /// すべての修復を有効にし、weld の閾値を 1e-6 とする
+    fn default() -> Self {
+        Self {
+            remove_non_finite: true,
+            remove_degenerate: true,
+            recompute_normals: true,
+            weld_vertices: true,
+            weld_tolerance: 1e-6,
         }
+    }
+}
+
+/// `load_stl_repaired` の修復件数
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RepairReport {
+    /// ファイルから読んだ三角形数
+    pub triangles_read: usize,
+    /// NaN/Inf 座標により除去した三角形数
+    pub non_finite_removed: usize,
+    /// 面積ゼロにより除去した三角形数
+    pub degenerate_removed: usize,
+    /// 保存されていた法線が欠落・不正・向き違いで、再計算により置き換えた三角形数
+    pub normals_recomputed: usize,
+    /// weld で併合した頂点数
+    pub vertices_welded: usize,
+}
+
+/// 健全性検査と軽微な修復を行いながらSTLファイルを読み込む（自動フォーマット判定）
+///
+/// 修復は NaN/Inf 除去 → 頂点 weld → 面積ゼロ除去 → 法線再計算 の順に適用する。
+/// weld 後の判定なので、weld で潰れた三角形も面積ゼロとして除去される。
+/// 除去によって参照されなくなった頂点は取り除く。
+pub fn load_stl_repaired(
+    path: &Path,
+    options: RepairOptions,
+) -> Result<(TriangleMesh3D<f64>, RepairReport), StlError> {
+    let mut facets = Vec::new();
+    read_facets(path, &mut no_progress, |_, facet| facets.push(facet))?;
+    let mut report = RepairReport {
+        triangles_read: facets.len(),
+        ..RepairReport::default()
+    };
+
+    if options.remove_non_finite {
+        facets.retain(|facet| is_finite_facet(&facet.vertices));
+        report.non_finite_removed = report.triangles_read - facets.len();
+    }
+
+    let mut vertices = Vec::with_capacity(facets.len() * 3);
+    let mut welder = VertexWelder::new(options.weld_tolerance);
+    let mut triangles: Vec<([usize; 3], Vector3D<f64>)> = facets
+        .iter()
+        .map(|facet| {
+            let mut triangle = [0usize; 3];
+            for (index, vertex) in triangle.iter_mut().zip(facet.vertices) {
+                *index = if options.weld_vertices {
+                    welder.vertex(&mut vertices, vertex)
+                } else {
+                    vertices.push(vertex);
+                    vertices.len() - 1
+                };
+            }
+            (triangle, facet.normal)
+        })
+        .collect();
+    report.vertices_welded = facets.len() * 3 - vertices.len();
+
+    if options.remove_degenerate {
+        let before = triangles.len();
+        triangles.retain(|(triangle, _)| {
+            let corners = triangle.map(|index| vertices[index]);
+            !is_finite_facet(&corners) || !is_degenerate_facet(&corners, 0.0)
+        });
+        report.degenerate_removed = before - triangles.len();
+    }
 
-        // Attribute byte count (2 bytes) - usually 0
-        let mut attr_bytes = [0u8; 2];
-        file.read_exact(&mut attr_bytes)?;
+    // 参照されている頂点だけを残して詰める
+    let mut remap = vec![usize::MAX; vertices.len()];
+    let mut used_vertices = Vec::with_capacity(vertices.len());
+    let indices: Vec<[usize; 3]> = triangles
+        .iter()
+        .map(|(triangle, _)| {
+            triangle.map(|index| {
+                if remap[index] == usize::MAX {
+                    remap[index] = used_vertices.len();
+                    used_vertices.push(vertices[index]);
+                }
+                remap[index]
+            })
+        })
+        .collect();
+
+    let mut normals = None;
+    if options.recompute_normals {
+        let mut accumulated = vec![Vector3D::new(0.0, 0.0, 0.0); used_vertices.len()];
+        for (triangle, (_, stored)) in indices.iter().zip(&triangles) {
+            let [a, b, c] = triangle.map(|index| used_vertices[index]);
+            // 外積の長さは面積の2倍なので、そのまま足すと面積加重になる
+            let cross = Vector3D::from_points(&a, &b).cross(&Vector3D::from_points(&a, &c));
+            let length = cross.length();
+            let stored_length = stored.length();
+            let agrees = length > 0.0
+                && stored_length.is_finite()
+                && stored_length > 0.0
+                && cross.dot(stored) / (length * stored_length) >= NORMAL_AGREEMENT_COS;
+            if !agrees {
+                report.normals_recomputed += 1;
+            }
+            for &index in triangle {
+                accumulated[index] = accumulated[index] + cross;
+            }
+        }
+        normals = Some(
+            accumulated
+                .into_iter()
+                .map(|normal| {
+                    if normal.length() > 0.0 {
+                        normal.normalize()
+                    } else {
+                        Vector3D::new(0.0, 0.0, 1.0) // 面積ゼロのみに接する頂点はZ-up
+                    }
+                })
+                .collect(),
+        );
+    }
 
-        indices.push([
-            triangle_vertices[0],
-            triangle_vertices[1],
-            triangle_vertices[2],
-        ]);
+    let mut mesh =
+        TriangleMesh3D::new(used_vertices, indices).map_err(StlError::InvalidTriangle)?;
+    if let Some(normals) = normals {
+        mesh = mesh
+            .with_normals(normals)
+            .map_err(StlError::InvalidTriangle)?;
     }
+    Ok((mesh, report))
+}
+
+/// `load_stl_with_options` の読み込みオプション
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LoadStlOptions {
+    /// 退化三角形（面積が閾値以下、または NaN/Inf 座標を含む）を読み飛ばす
+    pub skip_degenerate: bool,
+    /// 退化とみなす面積の上限（この値以下の面積の三角形を読み飛ばす）
+    pub degenerate_area_eps: f64,
+}
 
-    TriangleMesh3D::new(vertices, indices).map_err(StlError::InvalidTriangle)
+impl Default for LoadStlOptions {
+    /// 退化三角形を読み飛ばし、面積ゼロ（同一頂点・一直線上）のみを退化とする
+    fn default() -> Self {
+        Self {
+            skip_degenerate: true,
+            degenerate_area_eps: 0.0,
+        }
+    }
+}
+
+/// 退化三角形を読み飛ばしながらSTLファイルを読み込む（自動フォーマット判定）
+///
+/// 読み込んだメッシュと、読み飛ばした三角形数を返す。重複頂点は座標が完全に一致するもののみ
+/// 併合する。法線は設定しないため、後段で計算しても退化三角形による NaN は生じない。
+pub fn load_stl_with_options(
+    path: &Path,
+    options: LoadStlOptions,
+) -> Result<(TriangleMesh3D<f64>, usize), StlError> {
+    let mut builder = MeshBuilder::with_capacity(0);
+    let mut skipped = 0;
+    read_facets(path, &mut no_progress, |_, RawFacet { vertices, .. }| {
+        if options.skip_degenerate
+            && (!is_finite_facet(&vertices)
+                || is_degenerate_facet(&vertices, options.degenerate_area_eps))
+        {
+            skipped += 1;
+        } else {
+            builder.facet(vertices);
+        }
+    })?;
+    Ok((builder.into_mesh()?, skipped))
+}
+
+/// 3頂点の座標がすべて有限か
+fn is_finite_facet(vertices: &[Point3D<f64>; 3]) -> bool {
+    vertices
+        .iter()
+        .all(|v| v.x().is_finite() && v.y().is_finite() && v.z().is_finite())
+}
+
+/// 有限座標の三角形の面積が `area_eps` 以下か
+fn is_degenerate_facet(vertices: &[Point3D<f64>; 3], area_eps: f64) -> bool {
+    let [a, b, c] = vertices;
+    let cross = Vector3D::from_points(a, b).cross(&Vector3D::from_points(a, c));
+    // 有限座標でも外積がオーバーフローすれば面積は求まらないため、退化とみなす
+    let area = cross.length() * 0.5;
+    !area.is_finite() || area <= area_eps
+}
+
+/// weld のセル番号の絶対値の上限（近傍セルの計算が `i64` で溢れない範囲）
+const MAX_WELD_CELL: f64 = (1u64 << 62) as f64;
+
+/// 距離閾値による頂点の併合（閾値幅の格子で近傍セルのみを調べる）
+struct VertexWelder {
+    tolerance: f64,
+    cells: HashMap<[i64; 3], Vec<usize>>,
+}
+
+impl VertexWelder {
+    fn new(tolerance: f64) -> Self {
+        Self {
+            tolerance: tolerance.max(0.0),
+            cells: HashMap::new(),
+        }
+    }
+
+    /// 閾値以内の既存頂点があればそのインデックス、なければ追加したインデックス
+    ///
+    /// 非有限の座標や、閾値で割るとセル番号が `i64` に収まらない座標は併合せず独立の頂点とする。
+    fn vertex(&mut self, vertices: &mut Vec<Point3D<f64>>, vertex: Point3D<f64>) -> usize {
+        let coordinates = [vertex.x(), vertex.y(), vertex.z()];
+        if !coordinates.iter().all(|c| c.is_finite()) {
+            vertices.push(vertex);
+            return vertices.len() - 1;
+        }
+
+        // 閾値0では座標のビット列をセルとし、完全一致する頂点のみを併合する
+        let cell = if self.tolerance > 0.0 {
+            let scaled = coordinates.map(|c| (c / self.tolerance).floor());
+            if !scaled.iter().all(|s| s.abs() < MAX_WELD_CELL) {
+                vertices.push(vertex);
+                return vertices.len() - 1;
+            }
+            scaled.map(|s| s as i64)
+        } else {
+            coordinates.map(|c| (c + 0.0).to_bits() as i64)
+        };
+
+        for dx in -1..=1 {
+            for dy in -1..=1 {
+                for dz in -1..=1 {
+                    let neighbor = [
+                        cell[0].saturating_add(dx),
+                        cell[1].saturating_add(dy),
+                        cell[2].saturating_add(dz),
+                    ];
+                    let Some(candidates) = self.cells.get(&neighbor) else {
+                        continue;
+                    };
+                    if let Some(&index) = candidates
+                        .iter()
+                        .find(|&&index| vertices[index].distance_to(&vertex) <= self.tolerance)
+                    {
+                        return index;
+                    }
+                }
+            }
+        }
+
+        vertices.push(vertex);
+        let index = vertices.len() - 1;
+        self.cells.entry(cell).or_default().push(index);
+        index
+    }
+}
+
+/// 座標を `unit` 単位とみなしてSTLファイルを読み込み、メートルに換算する
+pub fn load_stl_scaled(path: &Path, unit: LengthUnit) -> Result<TriangleMesh3D<f64>, StlError> {
+    let mesh = load_stl::<f64>(path)?;
+    scale_mesh(&mesh, unit.meters_per_unit())
+}
+
+/// メートル単位のメッシュを `unit` 単位に換算してSTLファイルとして保存
+pub fn save_stl_in_unit(
+    mesh: &TriangleMesh3D<f64>,
+    path: &Path,
+    unit: LengthUnit,
+) -> Result<(), StlError> {
+    save_stl(&scale_mesh(mesh, 1.0 / unit.meters_per_unit())?, path)
+}
+
+/// 頂点座標を一様に拡大縮小（法線は向きが変わらないのでそのまま）
+fn scale_mesh(mesh: &TriangleMesh3D<f64>, factor: f64) -> Result<TriangleMesh3D<f64>, StlError> {
+    let vertices = mesh
+        .vertices()
+        .iter()
+        .map(|v| Point3D::new(v.x() * factor, v.y() * factor, v.z() * factor))
+        .collect();
+    let scaled = TriangleMesh3D::new(vertices, mesh.indices().to_vec())
+        .map_err(StlError::InvalidTriangle)?;
+    match mesh.normals() {
+        Some(normals) => scaled
+            .with_normals(normals.to_vec())
+            .map_err(StlError::InvalidTriangle),
+        None => Ok(scaled),
+    }
 }
 
 /// ASCII STLファイルとして保存
@@ -268,26 +785,25 @@ fn is_binary_stl(path: &Path) -> Result<bool, StlError> {
     Ok(true)
 }
 
-/// 頂点を追加するか、既存の頂点のインデックスを見つける
-/// 重複する頂点を自動的にマージ
-fn add_or_find_vertex<T: Scalar>(vertices: &mut Vec<Point3D<T>>, vertex: Point3D<T>) -> usize {
-    // Simple linear search for exact matches
-    // TODO: In Phase 2, replace with spatial hash or other optimization
-    for (index, existing_vertex) in vertices.iter().enumerate() {
-        if vertex_equals(*existing_vertex, vertex) {
-            return index;
-        }
+/// `vertex x y z` 行を解析
+fn parse_vertex_line(line: &str) -> Result<Point3D<f64>, StlError> {
+    let parts: Vec<&str> = line.split_whitespace().collect();
+    if parts.len() != 4 {
+        return Err(StlError::InvalidTriangle(format!(
+            "Invalid vertex line: {}",
+            line
+        )));
     }
 
-    // Not found, add new vertex
-    vertices.push(vertex);
-    vertices.len() - 1
-}
-
-/// 頂点が等しいかどうかを判定（浮動小数点比較）
-fn vertex_equals<T: Scalar>(a: Point3D<T>, b: Point3D<T>) -> bool {
-    let tolerance = T::EPSILON;
-    (a.x() - b.x()).abs() <= tolerance
-        && (a.y() - b.y()).abs() <= tolerance
-        && (a.z() - b.z()).abs() <= tolerance
+    let x = parts[1].parse::<f64>().map_err(|_| {
+        StlError::PrecisionConversion(format!("Failed to parse X coordinate: {}", parts[1]))
+    })?;
+    let y = parts[2].parse::<f64>().map_err(|_| {
+        StlError::PrecisionConversion(format!("Failed to parse Y coordinate: {}", parts[2]))
+    })?;
+    let z = parts[3].parse::<f64>().map_err(|_| {
+        StlError::PrecisionConversion(format!("Failed to parse Z coordinate: {}", parts[3]))
+    })?;
+
+    Ok(Point3D::new(x, y, z))
 }
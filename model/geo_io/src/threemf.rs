@@ -0,0 +1,313 @@
+//! 3MF（3D Manufacturing Format）ファイルの読み込み機能
+//!
+//! 3MF は ZIP コンテナで、モデル本体は XML（既定では `3D/3dmodel.model`、
+//! `_rels/.rels` に記載があればその参照先）に格納される。
+//! `<resources>` の `<object>` から `<vertices>` / `<triangles>` と `<components>` を読み、
+//! `<build>` の各 `<item>` を1つのメッシュとして返す。
+//! 座標は `<model unit="…">` の単位からメートルへ換算し、item・component の変換行列を適用する。
+
+use crate::error::IoError;
+use crate::unit::LengthUnit;
+use geo_primitives::{Point3D, TriangleMesh3D};
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+use zip::ZipArchive;
+
+/// ルートモデルの既定パス
+const DEFAULT_MODEL_PATH: &str = "3D/3dmodel.model";
+
+/// ルートモデルを指すリレーションシップの種別
+const MODEL_RELATIONSHIP_TYPE: &str =
+    "http://schemas.microsoft.com/3dmanufacturing/2013/01/3dmodel";
+
+/// component の入れ子の上限（循環参照の検出用）
+const MAX_COMPONENT_DEPTH: usize = 32;
+
+/// 3MF の変換行列（行ベクトル規約の 4x3 行列 `m00 m01 m02 m10 … m32`）
+type Transform = [f64; 12];
+
+const IDENTITY: Transform = [1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0];
+
+/// `<object>` の内容
+#[derive(Debug, Default)]
+struct ObjectDefinition {
+    vertices: Vec<Point3D<f64>>,
+    triangles: Vec<[usize; 3]>,
+    /// 参照する object の id と変換行列
+    components: Vec<(usize, Transform)>,
+}
+
+/// 3MFファイルを読み込み、build item ごとのメッシュを返す（座標はメートル）
+pub fn load_3mf(path: &Path) -> Result<Vec<TriangleMesh3D<f64>>, IoError> {
+    let mut archive = ZipArchive::new(File::open(path)?).map_err(format_error)?;
+
+    let model_path = read_entry(&mut archive, "_rels/.rels")
+        .ok()
+        .and_then(|rels| root_model_path(&rels))
+        .unwrap_or_else(|| DEFAULT_MODEL_PATH.to_string());
+    let xml = read_entry(&mut archive, &model_path)?;
+
+    parse_model(&xml)
+}
+
+fn format_error(err: impl std::error::Error + Send + Sync + 'static) -> IoError {
+    IoError::Format(Box::new(err))
+}
+
+fn read_entry(archive: &mut ZipArchive<File>, name: &str) -> Result<String, IoError> {
+    let mut entry = archive.by_name(name).map_err(format_error)?;
+    let mut content = String::new();
+    entry.read_to_string(&mut content)?;
+    Ok(content)
+}
+
+/// `_rels/.rels` からルートモデルのパスを取得
+fn root_model_path(rels: &str) -> Option<String> {
+    let mut reader = Reader::from_str(rels);
+    loop {
+        match reader.read_event().ok()? {
+            Event::Start(e) | Event::Empty(e)
+                if e.local_name().as_ref() == b"Relationship"
+                    && attribute(&e, "Type").ok()?.as_deref() == Some(MODEL_RELATIONSHIP_TYPE) =>
+            {
+                let target = attribute(&e, "Target").ok()??;
+                return Some(target.trim_start_matches('/').to_string());
+            }
+            Event::Eof => return None,
+            _ => {}
+        }
+    }
+}
+
+/// 属性値を取得（名前空間接頭辞は無視）
+fn attribute(element: &BytesStart, name: &str) -> Result<Option<String>, IoError> {
+    for attr in element.attributes() {
+        let attr = attr.map_err(format_error)?;
+        if attr.key.local_name().as_ref() == name.as_bytes() {
+            let value = attr.unescape_value().map_err(format_error)?;
+            return Ok(Some(value.into_owned()));
+        }
+    }
+    Ok(None)
+}
+
+fn required_attribute(element: &BytesStart, name: &str) -> Result<String, IoError> {
+    attribute(element, name)?.ok_or_else(|| {
+        IoError::Conversion(format!(
+            "<{}> is missing attribute '{}'",
+            String::from_utf8_lossy(element.local_name().as_ref()),
+            name
+        ))
+    })
+}
+
+fn parse_number<V: std::str::FromStr>(value: &str, what: &str) -> Result<V, IoError> {
+    value
+        .trim()
+        .parse()
+        .map_err(|_| IoError::Conversion(format!("invalid {}: {}", what, value)))
+}
+
+fn parse_transform(value: Option<String>) -> Result<Transform, IoError> {
+    let Some(value) = value else {
+        return Ok(IDENTITY);
+    };
+    let numbers: Vec<f64> = value
+        .split_whitespace()
+        .map(|token| parse_number(token, "transform"))
+        .collect::<Result<_, _>>()?;
+    numbers
+        .try_into()
+        .map_err(|_| IoError::Conversion(format!("transform must have 12 numbers: {}", value)))
+}
+
+/// 変換 `first` の後に `second` を適用する合成変換
+fn compose(first: &Transform, second: &Transform) -> Transform {
+    let mut result = [0.0; 12];
+    for row in 0..4 {
+        for col in 0..3 {
+            let mut value: f64 = (0..3)
+                .map(|k| first[row * 3 + k] * second[k * 3 + col])
+                .sum();
+            if row == 3 {
+                value += second[9 + col];
+            }
+            result[row * 3 + col] = value;
+        }
+    }
+    result
+}
+
+fn apply(transform: &Transform, p: Point3D<f64>) -> Point3D<f64> {
+    let m = transform;
+    Point3D::new(
+        p.x() * m[0] + p.y() * m[3] + p.z() * m[6] + m[9],
+        p.x() * m[1] + p.y() * m[4] + p.z() * m[7] + m[10],
+        p.x() * m[2] + p.y() * m[5] + p.z() * m[8] + m[11],
+    )
+}
+
+/// モデル XML を解析し、build item ごとのメッシュを組み立てる
+fn parse_model(xml: &str) -> Result<Vec<TriangleMesh3D<f64>>, IoError> {
+    let mut reader = Reader::from_str(xml);
+    let mut scale = 1e-3;
+    let mut objects: HashMap<usize, ObjectDefinition> = HashMap::new();
+    let mut current: Option<(usize, ObjectDefinition)> = None;
+    let mut items: Vec<(usize, Transform)> = Vec::new();
+
+    loop {
+        let event = reader.read_event().map_err(format_error)?;
+        let element = match &event {
+            Event::Start(e) | Event::Empty(e) => e,
+            Event::End(e) => {
+                if e.local_name().as_ref() == b"object" {
+                    if let Some((id, object)) = current.take() {
+                        objects.insert(id, object);
+                    }
+                }
+                continue;
+            }
+            Event::Eof => break,
+            _ => continue,
+        };
+
+        match element.local_name().as_ref() {
+            b"model" => {
+                if let Some(unit) = attribute(element, "unit")? {
+                    scale = LengthUnit::from_3mf_name(&unit)
+                        .ok_or_else(|| IoError::Conversion(format!("unsupported unit: {}", unit)))?
+                        .meters_per_unit();
+                }
+            }
+            b"object" => {
+                let id = parse_number(&required_attribute(element, "id")?, "object id")?;
+                if matches!(event, Event::Empty(_)) {
+                    objects.insert(id, ObjectDefinition::default());
+                } else {
+                    current = Some((id, ObjectDefinition::default()));
+                }
+            }
+            b"vertex" => {
+                let object = current_object(&mut current, "vertex")?;
+                let coordinate = |name| -> Result<f64, IoError> {
+                    parse_number(&required_attribute(element, name)?, "vertex coordinate")
+                };
+                object.vertices.push(Point3D::new(
+                    coordinate("x")?,
+                    coordinate("y")?,
+                    coordinate("z")?,
+                ));
+            }
+            b"triangle" => {
+                let object = current_object(&mut current, "triangle")?;
+                let index = |name| -> Result<usize, IoError> {
+                    parse_number(&required_attribute(element, name)?, "triangle index")
+                };
+                object
+                    .triangles
+                    .push([index("v1")?, index("v2")?, index("v3")?]);
+            }
+            b"component" => {
+                let object_id =
+                    parse_number(&required_attribute(element, "objectid")?, "objectid")?;
+                let transform = parse_transform(attribute(element, "transform")?)?;
+                current_object(&mut current, "component")?
+                    .components
+                    .push((object_id, transform));
+            }
+            b"item" => {
+                let object_id =
+                    parse_number(&required_attribute(element, "objectid")?, "objectid")?;
+                items.push((
+                    object_id,
+                    parse_transform(attribute(element, "transform")?)?,
+                ));
+            }
+            _ => {}
+        }
+    }
+
+    // 単位換算は最後に一括で掛ける（変換行列の平行移動成分もモデル単位のため）
+    let to_meters = [
+        scale, 0.0, 0.0, 0.0, scale, 0.0, 0.0, 0.0, scale, 0.0, 0.0, 0.0,
+    ];
+    items
+        .iter()
+        .map(|(object_id, transform)| {
+            let mut vertices = Vec::new();
+            let mut indices = Vec::new();
+            collect_object(
+                &objects,
+                *object_id,
+                &compose(transform, &to_meters),
+                0,
+                &mut vertices,
+                &mut indices,
+            )?;
+            TriangleMesh3D::new(vertices, indices).map_err(IoError::Conversion)
+        })
+        .collect()
+}
+
+fn current_object<'a>(
+    current: &'a mut Option<(usize, ObjectDefinition)>,
+    element: &str,
+) -> Result<&'a mut ObjectDefinition, IoError> {
+    current
+        .as_mut()
+        .map(|(_, object)| object)
+        .ok_or_else(|| IoError::Conversion(format!("<{}> outside of <object>", element)))
+}
+
+/// object（とその component）の三角形を変換して追加
+fn collect_object(
+    objects: &HashMap<usize, ObjectDefinition>,
+    id: usize,
+    transform: &Transform,
+    depth: usize,
+    vertices: &mut Vec<Point3D<f64>>,
+    indices: &mut Vec<[usize; 3]>,
+) -> Result<(), IoError> {
+    if depth > MAX_COMPONENT_DEPTH {
+        return Err(IoError::Conversion(format!(
+            "component nesting too deep at object {}",
+            id
+        )));
+    }
+    let object = objects
+        .get(&id)
+        .ok_or_else(|| IoError::Conversion(format!("unknown object id: {}", id)))?;
+
+    let offset = vertices.len();
+    if let Some(&index) = object
+        .triangles
+        .iter()
+        .flatten()
+        .find(|&&i| i >= object.vertices.len())
+    {
+        return Err(IoError::Conversion(format!(
+            "object {}: vertex index {} out of range (vertex count {})",
+            id,
+            index,
+            object.vertices.len()
+        )));
+    }
+    vertices.extend(object.vertices.iter().map(|&p| apply(transform, p)));
+    indices.extend(object.triangles.iter().map(|t| t.map(|i| i + offset)));
+
+    for (component_id, component_transform) in &object.components {
+        collect_object(
+            objects,
+            *component_id,
+            &compose(component_transform, transform),
+            depth + 1,
+            vertices,
+            indices,
+        )?;
+    }
+    Ok(())
+}
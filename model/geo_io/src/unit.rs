@@ -0,0 +1,48 @@
+//! ファイル座標の長さ単位
+//!
+//! 内部表現はメートル単位。STL は単位情報を持たないため呼び出し側が指定し、
+//! 3MF は `<model unit="…">` の単位名から決まる。
+
+/// ファイル座標の長さ単位
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LengthUnit {
+    /// マイクロメートル
+    Micrometer,
+    /// ミリメートル（多くのCAD・3Dプリンタの既定）
+    Millimeter,
+    /// センチメートル
+    Centimeter,
+    /// メートル（内部表現の単位）
+    Meter,
+    /// インチ
+    Inch,
+    /// フィート
+    Foot,
+}
+
+impl LengthUnit {
+    /// 1単位あたりのメートル数
+    pub fn meters_per_unit(self) -> f64 {
+        match self {
+            LengthUnit::Micrometer => 1e-6,
+            LengthUnit::Millimeter => 1e-3,
+            LengthUnit::Centimeter => 1e-2,
+            LengthUnit::Meter => 1.0,
+            LengthUnit::Inch => 0.0254,
+            LengthUnit::Foot => 0.3048,
+        }
+    }
+
+    /// 3MF の単位名（`micron`, `millimeter` など）から変換（未対応の名前は `None`）
+    pub fn from_3mf_name(name: &str) -> Option<Self> {
+        match name {
+            "micron" => Some(LengthUnit::Micrometer),
+            "millimeter" => Some(LengthUnit::Millimeter),
+            "centimeter" => Some(LengthUnit::Centimeter),
+            "meter" => Some(LengthUnit::Meter),
+            "inch" => Some(LengthUnit::Inch),
+            "foot" => Some(LengthUnit::Foot),
+            _ => None,
+        }
+    }
+}
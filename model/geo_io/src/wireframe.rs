@@ -0,0 +1,274 @@
+//! ワイヤーフレーム（曲線群）のエクスポート
+//!
+//! 曲線を弦高許容誤差でポリライン化し、OBJ（`l` 要素）または DXF（3D POLYLINE）として
+//! 書き出します。
+//!
+//! # 閉曲線
+//! 円・楕円・360度の円弧は閉ポリラインとして扱い、終点を重複して持たずに
+//! OBJ では `l` の末尾に始点インデックスを繰り返し、DXF では閉フラグを立てます。
+//!
+//! # NURBS曲線
+//! 定義域を制御点数に応じて等分したうえで、各区間の中点と弦の中点の距離が
+//! 許容誤差を超える限り二分します。始点と終点が一致する曲線は閉ポリラインになります。
+
+use crate::error::IoError;
+use geo_nurbs::{GeometryUnion, NurbsCurve3D};
+use geo_primitives::{Arc3D, Circle3D, Ellipse3D, Point3D};
+use std::f64::consts::TAU;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+/// `export_curves` が用いる弦高許容誤差
+pub const DEFAULT_CHORD_TOLERANCE: f64 = 1e-3;
+
+/// 閉曲線をポリライン化するときの最小分割数
+const MIN_CLOSED_SEGMENTS: usize = 8;
+
+/// NURBS 曲線を二分するときの最大深さ
+const MAX_NURBS_SUBDIVISION_DEPTH: u32 = 16;
+
+/// ワイヤーフレームの出力形式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WireframeFormat {
+    /// Wavefront OBJ（`v` と `l` 要素）
+    Obj,
+    /// AutoCAD DXF（ASCII、ENTITIES セクションの 3D POLYLINE）
+    Dxf,
+}
+
+/// ポリライン（閉じている場合も終点は始点を重複して持たない）
+#[derive(Debug, Clone, PartialEq)]
+pub struct Polyline {
+    /// 頂点列
+    pub points: Vec<Point3D<f64>>,
+    /// 終点から始点へ戻る閉ポリラインかどうか
+    pub closed: bool,
+}
+
+/// 曲線群をワイヤーフレームとして保存
+///
+/// 各曲線は [`DEFAULT_CHORD_TOLERANCE`] でポリライン化する。
+/// 曲線でない要素（点・平面・メッシュ）が含まれる場合は `IoError::Conversion`。
+pub fn export_curves(
+    curves: &[GeometryUnion<f64>],
+    path: &Path,
+    format: WireframeFormat,
+) -> Result<(), IoError> {
+    let polylines = curves_to_polylines(curves, DEFAULT_CHORD_TOLERANCE)?;
+    export_polylines(&polylines, path, format)
+}
+
+/// 曲線群を弦高許容誤差 `tolerance` でポリライン化
+pub fn curves_to_polylines(
+    curves: &[GeometryUnion<f64>],
+    tolerance: f64,
+) -> Result<Vec<Polyline>, IoError> {
+    if tolerance.is_nan() || tolerance <= 0.0 {
+        return Err(IoError::Conversion(format!(
+            "Chord tolerance must be positive: {}",
+            tolerance
+        )));
+    }
+    curves
+        .iter()
+        .enumerate()
+        .map(|(index, curve)| {
+            curve_to_polyline(curve, tolerance)
+                .ok_or_else(|| IoError::Conversion(format!("Geometry {} is not a curve", index)))
+        })
+        .collect()
+}
+
+/// ポリライン群をワイヤーフレームとして保存
+pub fn export_polylines(
+    polylines: &[Polyline],
+    path: &Path,
+    format: WireframeFormat,
+) -> Result<(), IoError> {
+    let mut file = BufWriter::new(File::create(path)?);
+    match format {
+        WireframeFormat::Obj => write_obj(&mut file, polylines)?,
+        WireframeFormat::Dxf => write_dxf(&mut file, polylines)?,
+    }
+    file.flush()?;
+    Ok(())
+}
+
+/// 1つの曲線をポリライン化（曲線でなければ `None`）
+fn curve_to_polyline(curve: &GeometryUnion<f64>, tolerance: f64) -> Option<Polyline> {
+    let polyline = match curve {
+        GeometryUnion::LineSegment(segment) => Polyline {
+            points: vec![segment.start(), segment.end()],
+            closed: false,
+        },
+        GeometryUnion::Circle(circle) => circle_polyline(circle, tolerance),
+        GeometryUnion::Arc(arc) => arc_polyline(arc, tolerance),
+        GeometryUnion::Ellipse(ellipse) => ellipse_polyline(ellipse, tolerance),
+        GeometryUnion::NurbsCurve(curve) => nurbs_polyline(curve, tolerance),
+        GeometryUnion::Point(_) | GeometryUnion::Plane(_) | GeometryUnion::TriangleMesh(_) => {
+            return None
+        }
+    };
+    Some(polyline)
+}
+
+fn circle_polyline(circle: &Circle3D<f64>, tolerance: f64) -> Polyline {
+    let count = segment_count(circle.radius(), TAU, tolerance).max(MIN_CLOSED_SEGMENTS);
+    Polyline {
+        points: (0..count)
+            .map(|i| circle.point_at_angle(TAU * i as f64 / count as f64))
+            .collect(),
+        closed: true,
+    }
+}
+
+fn arc_polyline(arc: &Arc3D<f64>, tolerance: f64) -> Polyline {
+    if arc.is_full_circle() {
+        let count = segment_count(arc.radius(), TAU, tolerance).max(MIN_CLOSED_SEGMENTS);
+        return Polyline {
+            points: (0..count)
+                .map(|i| arc.point_at_parameter(i as f64 / count as f64))
+                .collect(),
+            closed: true,
+        };
+    }
+    let count = segment_count(arc.radius(), arc.angle_span().to_radians(), tolerance);
+    Polyline {
+        points: (0..=count)
+            .map(|i| arc.point_at_parameter(i as f64 / count as f64))
+            .collect(),
+        closed: false,
+    }
+}
+
+fn ellipse_polyline(ellipse: &Ellipse3D<f64>, tolerance: f64) -> Polyline {
+    // 楕円は長半径の円を短軸方向へ縮めたものなので、同じパラメータ刻みでの
+    // 弦高は長半径の円の弦高を超えない
+    let count = segment_count(ellipse.semi_major_axis(), TAU, tolerance).max(MIN_CLOSED_SEGMENTS);
+    Polyline {
+        points: (0..count)
+            .map(|i| ellipse.point_at_parameter(TAU * i as f64 / count as f64))
+            .collect(),
+        closed: true,
+    }
+}
+
+fn nurbs_polyline(curve: &NurbsCurve3D<f64>, tolerance: f64) -> Polyline {
+    let evaluate = |t: f64| {
+        let p = curve.evaluate_at(t);
+        Point3D::new(p.x(), p.y(), p.z())
+    };
+    let (start, end) = curve.parameter_domain();
+    // 制御点数に比例した初期分割で、弦の中点だけでは見えない S 字などを拾う
+    let initial = curve.num_points().max(2);
+    let params: Vec<f64> = (0..=initial)
+        .map(|i| start + (end - start) * i as f64 / initial as f64)
+        .collect();
+
+    let mut points = vec![evaluate(start)];
+    for span in params.windows(2) {
+        subdivide_nurbs(&evaluate, (span[0], span[1]), tolerance, 0, &mut points);
+    }
+
+    let closed = points.len() > 2 && points[0].distance_to(points.last().unwrap()) <= tolerance;
+    if closed {
+        points.pop();
+    }
+    Polyline { points, closed }
+}
+
+/// 区間 `[t0, t1]` を弦高が `tolerance` 以下になるまで二分し、終点側の点を追加
+fn subdivide_nurbs(
+    evaluate: &impl Fn(f64) -> Point3D<f64>,
+    (t0, t1): (f64, f64),
+    tolerance: f64,
+    depth: u32,
+    points: &mut Vec<Point3D<f64>>,
+) {
+    let p0 = *points.last().unwrap();
+    let p1 = evaluate(t1);
+    let mid = (t0 + t1) / 2.0;
+    let chord_mid = Point3D::new(
+        (p0.x() + p1.x()) / 2.0,
+        (p0.y() + p1.y()) / 2.0,
+        (p0.z() + p1.z()) / 2.0,
+    );
+    if depth < MAX_NURBS_SUBDIVISION_DEPTH && evaluate(mid).distance_to(&chord_mid) > tolerance {
+        subdivide_nurbs(evaluate, (t0, mid), tolerance, depth + 1, points);
+        subdivide_nurbs(evaluate, (mid, t1), tolerance, depth + 1, points);
+    } else {
+        points.push(p1);
+    }
+}
+
+/// 半径 `radius`・角度範囲 `span` の円弧を弦高 `tolerance` 以内に収める分割数
+///
+/// 中心角 θ の弦の弦高は `r (1 - cos(θ/2))` なので、θ ≤ 2 acos(1 - tolerance / r)。
+fn segment_count(radius: f64, span: f64, tolerance: f64) -> usize {
+    if tolerance >= radius {
+        return 1;
+    }
+    let max_angle = 2.0 * (1.0 - tolerance / radius).acos();
+    ((span / max_angle).ceil() as usize).max(1)
+}
+
+fn write_obj<W: Write>(out: &mut W, polylines: &[Polyline]) -> std::io::Result<()> {
+    writeln!(out, "# RedRing wireframe")?;
+    for polyline in polylines {
+        for point in &polyline.points {
+            writeln!(out, "v {} {} {}", point.x(), point.y(), point.z())?;
+        }
+    }
+
+    // OBJ のインデックスは1始まり
+    let mut offset = 1;
+    for polyline in polylines {
+        let count = polyline.points.len();
+        if count >= 2 {
+            write!(out, "l")?;
+            for index in offset..offset + count {
+                write!(out, " {}", index)?;
+            }
+            if polyline.closed {
+                write!(out, " {}", offset)?;
+            }
+            writeln!(out)?;
+        }
+        offset += count;
+    }
+    Ok(())
+}
+
+fn write_dxf<W: Write>(out: &mut W, polylines: &[Polyline]) -> std::io::Result<()> {
+    /// POLYLINE フラグ：閉じている
+    const CLOSED: u32 = 1;
+    /// POLYLINE フラグ：3D ポリライン
+    const POLYLINE_3D: u32 = 8;
+    /// VERTEX フラグ：3D ポリラインの頂点
+    const VERTEX_3D: u32 = 32;
+
+    writeln!(out, "0\nSECTION\n2\nENTITIES")?;
+    for polyline in polylines.iter().filter(|p| p.points.len() >= 2) {
+        let flags = if polyline.closed {
+            POLYLINE_3D | CLOSED
+        } else {
+            POLYLINE_3D
+        };
+        writeln!(out, "0\nPOLYLINE\n8\n0\n66\n1\n10\n0.0\n20\n0.0\n30\n0.0")?;
+        writeln!(out, "70\n{}", flags)?;
+        for point in &polyline.points {
+            writeln!(
+                out,
+                "0\nVERTEX\n8\n0\n10\n{}\n20\n{}\n30\n{}\n70\n{}",
+                point.x(),
+                point.y(),
+                point.z(),
+                VERTEX_3D
+            )?;
+        }
+        writeln!(out, "0\nSEQEND\n8\n0")?;
+    }
+    writeln!(out, "0\nENDSEC\n0\nEOF")?;
+    Ok(())
+}
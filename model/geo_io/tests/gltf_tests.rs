@@ -0,0 +1,206 @@
+//! glTF（GLB）エクスポートのテスト
+
+use geo_io::{gltf, IoError};
+use geo_primitives::triangle_mesh_3d_fixtures::tetrahedron;
+use geo_primitives::{Point3D, TriangleMesh3D, Vector3D};
+use serde_json::Value;
+use tempfile::NamedTempFile;
+
+/// GLB を読み、JSON とバイナリチャンクに分ける
+fn read_glb(path: &std::path::Path) -> (Value, Vec<u8>) {
+    let bytes = std::fs::read(path).unwrap();
+    let word = |offset: usize| u32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap());
+    assert_eq!(&bytes[0..4], b"glTF");
+    assert_eq!(word(4), 2);
+    assert_eq!(word(8) as usize, bytes.len());
+
+    let json_length = word(12) as usize;
+    assert_eq!(&bytes[16..20], b"JSON");
+    assert_eq!(json_length % 4, 0);
+    let document = serde_json::from_slice(&bytes[20..20 + json_length]).unwrap();
+
+    let bin_start = 20 + json_length;
+    let bin_length = word(bin_start) as usize;
+    assert_eq!(&bytes[bin_start + 4..bin_start + 8], b"BIN\0");
+    assert_eq!(bin_length % 4, 0);
+    assert_eq!(bin_start + 8 + bin_length, bytes.len());
+    (document, bytes[bin_start + 8..].to_vec())
+}
+
+/// アクセサの参照する範囲を `f32` / `u32` の列として読む
+fn accessor_words(document: &Value, bin: &[u8], accessor: usize) -> Vec<[u8; 4]> {
+    let view = &document["bufferViews"][document["accessors"][accessor]["bufferView"]
+        .as_u64()
+        .unwrap() as usize];
+    let offset = view["byteOffset"].as_u64().unwrap() as usize;
+    let length = view["byteLength"].as_u64().unwrap() as usize;
+    bin[offset..offset + length]
+        .chunks_exact(4)
+        .map(|word| word.try_into().unwrap())
+        .collect()
+}
+
+fn floats(document: &Value, bin: &[u8], accessor: usize) -> Vec<f32> {
+    accessor_words(document, bin, accessor)
+        .into_iter()
+        .map(f32::from_le_bytes)
+        .collect()
+}
+
+#[test]
+fn test_export_glb_structure_and_data() {
+    let square = TriangleMesh3D::new(
+        vec![
+            Point3D::new(-1.0, -1.0, 5.0),
+            Point3D::new(1.0, -1.0, 5.0),
+            Point3D::new(1.0, 1.0, 5.0),
+            Point3D::new(-1.0, 1.0, 5.0),
+        ],
+        vec![[0, 1, 2], [0, 2, 3]],
+    )
+    .unwrap();
+    let meshes = vec![tetrahedron(), square];
+
+    let temp_file = NamedTempFile::new().unwrap();
+    gltf::export_glb(&meshes, temp_file.path()).unwrap();
+    let (document, bin) = read_glb(temp_file.path());
+
+    assert_eq!(document["asset"]["version"], "2.0");
+    assert_eq!(document["scenes"][0]["nodes"], serde_json::json!([0, 1]));
+    assert_eq!(document["nodes"][1]["mesh"], 1);
+    assert_eq!(
+        document["buffers"][0]["byteLength"].as_u64().unwrap() as usize,
+        bin.len()
+    );
+
+    for (mesh_index, mesh) in meshes.iter().enumerate() {
+        let primitive = &document["meshes"][mesh_index]["primitives"][0];
+        let attributes = &primitive["attributes"];
+        assert!(attributes.get("COLOR_0").is_none());
+
+        let position = attributes["POSITION"].as_u64().unwrap() as usize;
+        let accessor = &document["accessors"][position];
+        assert_eq!(
+            accessor["count"].as_u64().unwrap() as usize,
+            mesh.vertex_count()
+        );
+        assert_eq!(accessor["type"], "VEC3");
+        let positions = floats(&document, &bin, position);
+        let expected: Vec<f32> = mesh
+            .vertices()
+            .iter()
+            .flat_map(|vertex| [vertex.x() as f32, vertex.y() as f32, vertex.z() as f32])
+            .collect();
+        assert_eq!(positions, expected);
+
+        // 法線は単位長
+        let normals = floats(
+            &document,
+            &bin,
+            attributes["NORMAL"].as_u64().unwrap() as usize,
+        );
+        assert_eq!(normals.len(), mesh.vertex_count() * 3);
+        for normal in normals.chunks_exact(3) {
+            let length =
+                (normal[0] * normal[0] + normal[1] * normal[1] + normal[2] * normal[2]).sqrt();
+            assert!((length - 1.0).abs() < 1e-6);
+        }
+
+        let indices: Vec<usize> = accessor_words(
+            &document,
+            &bin,
+            primitive["indices"].as_u64().unwrap() as usize,
+        )
+        .into_iter()
+        .map(|word| u32::from_le_bytes(word) as usize)
+        .collect();
+        let expected: Vec<usize> = mesh.indices().iter().flatten().copied().collect();
+        assert_eq!(indices, expected);
+    }
+
+    // 2つ目のメッシュ（z = 5 の正方形）の bounds と法線
+    let attributes = &document["meshes"][1]["primitives"][0]["attributes"];
+    let position = &document["accessors"][attributes["POSITION"].as_u64().unwrap() as usize];
+    assert_eq!(position["min"], serde_json::json!([-1.0, -1.0, 5.0]));
+    assert_eq!(position["max"], serde_json::json!([1.0, 1.0, 5.0]));
+    let normals = floats(
+        &document,
+        &bin,
+        attributes["NORMAL"].as_u64().unwrap() as usize,
+    );
+    for normal in normals.chunks_exact(3) {
+        assert_eq!(normal, [0.0, 0.0, 1.0]);
+    }
+}
+
+#[test]
+fn test_export_glb_with_vertex_colors() {
+    let mesh = tetrahedron();
+    let colors = [
+        [1.0, 0.0, 0.0, 1.0],
+        [0.0, 1.0, 0.0, 1.0],
+        [0.0, 0.0, 1.0, 0.5],
+        [1.0, 1.0, 1.0, 1.0],
+    ];
+
+    let temp_file = NamedTempFile::new().unwrap();
+    gltf::export_glb_with_colors(&[mesh], &[Some(&colors)], temp_file.path()).unwrap();
+    let (document, bin) = read_glb(temp_file.path());
+
+    let color = document["meshes"][0]["primitives"][0]["attributes"]["COLOR_0"]
+        .as_u64()
+        .unwrap() as usize;
+    assert_eq!(document["accessors"][color]["type"], "VEC4");
+    assert_eq!(document["accessors"][color]["count"], 4);
+    assert_eq!(floats(&document, &bin, color), colors.concat());
+}
+
+#[test]
+fn test_export_glb_normalizes_stored_normals() {
+    let temp_file = NamedTempFile::new().unwrap();
+    let mesh = tetrahedron();
+    let normals = vec![
+        Vector3D::new(3.0, 0.0, 0.0),
+        Vector3D::new(0.0, 0.5, 0.0),
+        Vector3D::new(0.0, 0.0, 0.0),
+        Vector3D::new(1.0, 1.0, 1.0),
+    ];
+    let mesh = mesh.with_normals(normals).unwrap();
+    gltf::export_glb(&[mesh], temp_file.path()).unwrap();
+
+    let (document, bin) = read_glb(temp_file.path());
+    let normal = document["meshes"][0]["primitives"][0]["attributes"]["NORMAL"]
+        .as_u64()
+        .unwrap() as usize;
+    let normals = floats(&document, &bin, normal);
+    let expected = 1.0 / 3.0f32.sqrt();
+    // 長さゼロの法線は +Z に置き換える
+    let expected = [
+        1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, expected, expected, expected,
+    ];
+    for (actual, expected) in normals.iter().zip(expected) {
+        assert!((actual - expected).abs() < 1e-6, "{normals:?}");
+    }
+}
+
+#[test]
+fn test_export_glb_errors() {
+    let temp_file = NamedTempFile::new().unwrap();
+
+    // メッシュが1つもない
+    let result = gltf::export_glb(&[], temp_file.path());
+    assert!(matches!(result, Err(IoError::Conversion(_))));
+
+    // 三角形のないメッシュ
+    let result = gltf::export_glb(&[TriangleMesh3D::empty()], temp_file.path());
+    assert!(matches!(result, Err(IoError::Conversion(_))));
+
+    // 頂点カラーの数が頂点数と一致しない
+    let colors = [[1.0, 0.0, 0.0, 1.0]];
+    let result = gltf::export_glb_with_colors(&[tetrahedron()], &[Some(&colors)], temp_file.path());
+    assert!(matches!(result, Err(IoError::Conversion(_))));
+
+    // 頂点カラーの指定数がメッシュ数と一致しない
+    let result = gltf::export_glb_with_colors(&[tetrahedron()], &[], temp_file.path());
+    assert!(matches!(result, Err(IoError::Conversion(_))));
+}
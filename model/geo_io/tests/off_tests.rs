@@ -0,0 +1,142 @@
+//! OFFローダーのテスト
+
+use geo_io::{off, OffError};
+use geo_primitives::{Point3D, TriangleMesh3D};
+use std::io::Write;
+use tempfile::NamedTempFile;
+
+fn write_temp(content: &str) -> NamedTempFile {
+    let mut temp_file = NamedTempFile::new().unwrap();
+    temp_file.write_all(content.as_bytes()).unwrap();
+    temp_file
+}
+
+#[test]
+fn test_off_roundtrip() {
+    let original_mesh = TriangleMesh3D::new(
+        vec![
+            Point3D::new(0.0, 0.0, 0.0),
+            Point3D::new(1.5, 0.0, 0.0),
+            Point3D::new(0.0, 2.0, 0.0),
+            Point3D::new(0.0, 0.0, -0.25),
+        ],
+        vec![[0, 2, 1], [0, 1, 3], [0, 3, 2], [1, 2, 3]],
+    )
+    .unwrap();
+
+    let temp_file = NamedTempFile::new().unwrap();
+    off::save_off(&original_mesh, temp_file.path()).unwrap();
+    let loaded_mesh: TriangleMesh3D<f64> = off::load_off(temp_file.path()).unwrap();
+
+    assert_eq!(loaded_mesh.vertices(), original_mesh.vertices());
+    assert_eq!(loaded_mesh.indices(), original_mesh.indices());
+}
+
+#[test]
+fn test_off_polygon_fan_triangulation() {
+    // コメント・空行、キーワードと同じ行の要素数、4角形・5角形の面
+    let content = r#"OFF 6 2 0
+# 頂点
+0 0 0
+1 0 0
+1 1 0
+
+0 1 0
+2 0.5 0
+2 1.5 0  # 末尾コメント
+4 0 1 2 3
+5 1 4 5 2 3 0.8 0.2 0.2
+"#;
+    let temp_file = write_temp(content);
+    let mesh: TriangleMesh3D<f64> = off::load_off(temp_file.path()).unwrap();
+
+    assert_eq!(mesh.vertex_count(), 6);
+    assert_eq!(
+        mesh.indices(),
+        &[[0, 1, 2], [0, 2, 3], [1, 4, 5], [1, 5, 2], [1, 2, 3]]
+    );
+}
+
+#[test]
+fn test_coff_vertex_colors() {
+    let content = "COFF\n3 1 0\n0 0 0 255 0 0\n1 0 0 0 255 0 128\n0 1 0 0.0 0.0 1.0 0.5\n3 0 1 2\n";
+    let temp_file = write_temp(content);
+
+    let data = off::load_off_with_colors::<f64>(temp_file.path()).unwrap();
+    let colors = data.vertex_colors.unwrap();
+    assert_eq!(colors[0], [1.0, 0.0, 0.0, 1.0]);
+    assert_eq!(colors[1][1], 1.0);
+    assert!((colors[1][3] - 128.0 / 255.0).abs() < 1e-6);
+    assert_eq!(colors[2], [0.0, 0.0, 1.0, 0.5]);
+    assert_eq!(data.mesh.triangle_count(), 1);
+
+    // load_off は色を読み飛ばす
+    let mesh: TriangleMesh3D<f64> = off::load_off(temp_file.path()).unwrap();
+    assert_eq!(mesh.vertex(2), Some(Point3D::new(0.0, 1.0, 0.0)));
+
+    // 通常の OFF では色は None
+    let plain = write_temp("OFF\n3 1 0\n0 0 0\n1 0 0\n0 1 0\n3 0 1 2\n");
+    let data = off::load_off_with_colors::<f64>(plain.path()).unwrap();
+    assert!(data.vertex_colors.is_none());
+}
+
+#[test]
+fn test_off_invalid_input() {
+    let cases = [
+        ("PLY\n3 1 0\n", "header"),
+        ("OFF\n3\n", "header"),
+        ("OFF\n3 1 0\n0 0 0\n1 0 0\n", "vertex"),
+        ("OFF\n3 1 0\n0 0 0\n1 0 x\n0 1 0\n3 0 1 2\n", "vertex"),
+        ("OFF\n3 1 0\n0 0 0\n1 0 0\n0 1 0\n3 0 1 3\n", "face"),
+        ("OFF\n3 1 0\n0 0 0\n1 0 0\n0 1 0\n2 0 1\n", "face"),
+        ("OFF\n3 2 0\n0 0 0\n1 0 0\n0 1 0\n3 0 1 2\n", "face"),
+        ("OFF\n3 1 0\n0 0 0\n1 0 0\n0 1 0\n4 0 1 2\n", "face"),
+    ];
+    for (content, kind) in cases {
+        let temp_file = write_temp(content);
+        let result = off::load_off::<f64>(temp_file.path());
+        let matches = matches!(
+            (&result, kind),
+            (Err(OffError::InvalidHeader(_)), "header")
+                | (Err(OffError::InvalidVertex(_)), "vertex")
+                | (Err(OffError::InvalidFace(_)), "face")
+        );
+        assert!(matches, "{content:?}: {result:?}");
+    }
+}
+
+#[test]
+fn test_off_absurd_element_counts() {
+    // ヘッダの要素数が巨大でも、確保で落ちずに要素不足のエラーになる
+    for count in ["18446744073709551615", "1000000000000"] {
+        let temp_file = write_temp(&format!("OFF\n{count} 1 0\n0 0 0\n"));
+        assert!(matches!(
+            off::load_off::<f64>(temp_file.path()),
+            Err(OffError::InvalidVertex(_))
+        ));
+
+        let temp_file = write_temp(&format!("COFF\n{count} 1 0\n0 0 0 255 0 0 255\n"));
+        assert!(matches!(
+            off::load_off_with_colors::<f64>(temp_file.path()),
+            Err(OffError::InvalidVertex(_))
+        ));
+
+        let temp_file = write_temp(&format!("OFF\n3 {count} 0\n0 0 0\n1 0 0\n0 1 0\n3 0 1 2\n"));
+        assert!(matches!(
+            off::load_off::<f64>(temp_file.path()),
+            Err(OffError::InvalidFace(_))
+        ));
+    }
+}
+
+#[test]
+fn test_coff_without_colors() {
+    // load_off はカラーを読み飛ばすので読み込めるが、カラー付きの読み込みはエラー
+    let temp_file = write_temp("COFF\n3 1 0\n0 0 0\n1 0 0\n0 1 0\n3 0 1 2\n");
+    let mesh = off::load_off::<f64>(temp_file.path()).unwrap();
+    assert_eq!(mesh.triangle_count(), 1);
+    assert!(matches!(
+        off::load_off_with_colors::<f64>(temp_file.path()),
+        Err(OffError::InvalidVertex(_))
+    ));
+}
@@ -1,8 +1,10 @@
 //! STLローダーのテスト
 
-use geo_io::stl;
+use geo_io::unit::LengthUnit;
+use geo_io::{stl, StlError};
 use geo_primitives::{Point3D, TriangleMesh3D};
 use std::io::Write;
+use std::ops::ControlFlow;
 use tempfile::NamedTempFile;
 
 #[test]
@@ -124,3 +126,441 @@ endsolid test
     assert_eq!(mesh.triangle_count(), 1);
     assert_eq!(mesh.vertex_count(), 3);
 }
+
+/// 三角形ストリップ状の Binary STL を書き出す（隣接三角形は頂点を共有）
+fn write_binary_strip(triangle_count: usize) -> NamedTempFile {
+    let mut bytes = vec![0u8; 80];
+    bytes.extend_from_slice(&(triangle_count as u32).to_le_bytes());
+    let vertex = |i: usize| [(i / 2) as f32, (i % 2) as f32, 0.0f32];
+    for t in 0..triangle_count {
+        bytes.extend_from_slice(&[0u8; 12]);
+        for corner in [vertex(t), vertex(t + 1), vertex(t + 2)] {
+            for value in corner {
+                bytes.extend_from_slice(&value.to_le_bytes());
+            }
+        }
+        bytes.extend_from_slice(&[0u8; 2]);
+    }
+
+    let mut temp_file = NamedTempFile::new().unwrap();
+    temp_file.write_all(&bytes).unwrap();
+    temp_file
+}
+
+#[test]
+fn test_streaming_binary_stl_reports_exact_progress() {
+    let triangle_count = stl::STREAMING_CHUNK_TRIANGLES * 2 + 10;
+    let temp_file = write_binary_strip(triangle_count);
+
+    let mut reports = Vec::new();
+    let mesh = stl::load_stl_streaming(temp_file.path(), |current, total| {
+        reports.push((current, total));
+    })
+    .unwrap();
+
+    assert_eq!(mesh.triangle_count(), triangle_count);
+    assert_eq!(mesh.vertex_count(), triangle_count + 2); // 共有頂点はマージされる
+    assert_eq!(
+        reports,
+        vec![
+            (0, triangle_count),
+            (stl::STREAMING_CHUNK_TRIANGLES, triangle_count),
+            (stl::STREAMING_CHUNK_TRIANGLES * 2, triangle_count),
+            (triangle_count, triangle_count),
+        ]
+    );
+
+    let triangle = mesh.triangle(3).unwrap();
+    assert_eq!(triangle.vertex_a(), Point3D::new(1.0, 1.0, 0.0));
+}
+
+#[test]
+fn test_streaming_matches_regular_loader() {
+    let temp_file = write_binary_strip(50);
+    let expected: TriangleMesh3D<f64> = stl::load_binary_stl(temp_file.path()).unwrap();
+    let streamed = stl::load_stl_streaming(temp_file.path(), |_, _| {}).unwrap();
+    assert_eq!(streamed.vertices(), expected.vertices());
+    assert_eq!(streamed.indices(), expected.indices());
+}
+
+#[test]
+fn test_streaming_ascii_stl() {
+    let original = TriangleMesh3D::new(
+        vec![
+            Point3D::new(0.0, 0.0, 0.0),
+            Point3D::new(1.0, 0.0, 0.0),
+            Point3D::new(1.0, 1.0, 0.0),
+            Point3D::new(0.0, 1.0, 0.0),
+        ],
+        vec![[0, 1, 2], [0, 2, 3]],
+    )
+    .unwrap();
+    let temp_file = NamedTempFile::new().unwrap();
+    stl::save_ascii_stl(&original, temp_file.path()).unwrap();
+
+    let mut reports = Vec::new();
+    let mesh = stl::load_stl_streaming(temp_file.path(), |current, total| {
+        reports.push((current, total));
+    })
+    .unwrap();
+
+    assert_eq!(mesh.triangle_count(), 2);
+    assert_eq!(mesh.vertex_count(), 4);
+    // ASCII は総数不明のため開始時は 0、完了時に確定値
+    assert_eq!(reports, vec![(0, 0), (2, 2)]);
+}
+
+#[test]
+fn test_streaming_cancel() {
+    let temp_file = write_binary_strip(stl::STREAMING_CHUNK_TRIANGLES * 3);
+
+    let mut calls = 0;
+    let result = stl::load_stl_streaming_with_control(temp_file.path(), |current, _| {
+        calls += 1;
+        if current > 0 {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    });
+
+    assert!(matches!(result, Err(StlError::Cancelled)));
+    assert_eq!(calls, 2);
+}
+
+#[test]
+fn test_streaming_rejects_truncated_binary() {
+    let temp_file = write_binary_strip(10);
+    let file = temp_file.as_file();
+    file.set_len(84 + 50 * 5).unwrap();
+
+    let result = stl::load_stl_streaming(temp_file.path(), |_, _| {});
+    assert!(matches!(result, Err(StlError::InvalidFacetCount(_))));
+}
+
+/// NaN座標・面積ゼロ・法線不正・近接頂点を含む壊れたASCII STL
+const BROKEN_ASCII_STL: &str = r#"solid broken
+  facet normal 0 0 1
+    outer loop
+      vertex 0.0 0.0 0.0
+      vertex 1.0 0.0 0.0
+      vertex 0.0 1.0 0.0
+    endloop
+  endfacet
+  facet normal 0 0 0
+    outer loop
+      vertex 1.0000000001 0.0 0.0
+      vertex 1.0 1.0 0.0
+      vertex 0.0 1.0000000001 0.0
+    endloop
+  endfacet
+  facet normal 0 0 1
+    outer loop
+      vertex 0.0 0.0 0.0
+      vertex nan 0.0 0.0
+      vertex 0.0 1.0 0.0
+    endloop
+  endfacet
+  facet normal 0 0 1
+    outer loop
+      vertex 0.0 0.0 0.0
+      vertex 2.0 0.0 0.0
+      vertex 3.0 0.0 0.0
+    endloop
+  endfacet
+endsolid broken
+"#;
+
+#[test]
+fn test_load_stl_repaired_default_options() {
+    let mut temp_file = NamedTempFile::new().unwrap();
+    temp_file.write_all(BROKEN_ASCII_STL.as_bytes()).unwrap();
+
+    let (mesh, report) =
+        stl::load_stl_repaired(temp_file.path(), stl::RepairOptions::default()).unwrap();
+
+    assert_eq!(report.triangles_read, 4);
+    assert_eq!(report.non_finite_removed, 1);
+    assert_eq!(report.degenerate_removed, 1);
+    // 2枚目の法線はゼロベクトル
+    assert_eq!(report.normals_recomputed, 1);
+    // NaN除去後の9頂点のうち、近接する (1,0,0)・(0,1,0) と原点の重複が併合される
+    assert_eq!(report.vertices_welded, 3);
+
+    // 正方形の2三角形が残り、一直線上の三角形だけが使う頂点は取り除かれる
+    assert_eq!(mesh.triangle_count(), 2);
+    assert_eq!(mesh.vertex_count(), 4);
+    let normals = mesh.normals().unwrap();
+    assert_eq!(normals.len(), 4);
+    for normal in normals {
+        assert!((normal.z() - 1.0).abs() < 1e-12);
+    }
+}
+
+#[test]
+fn test_load_stl_repaired_without_repairs() {
+    let mut temp_file = NamedTempFile::new().unwrap();
+    temp_file.write_all(BROKEN_ASCII_STL.as_bytes()).unwrap();
+
+    let options = stl::RepairOptions {
+        remove_non_finite: false,
+        remove_degenerate: false,
+        recompute_normals: false,
+        weld_vertices: false,
+        ..stl::RepairOptions::default()
+    };
+    let (mesh, report) = stl::load_stl_repaired(temp_file.path(), options).unwrap();
+
+    assert_eq!(
+        report,
+        stl::RepairReport {
+            triangles_read: 4,
+            ..stl::RepairReport::default()
+        }
+    );
+    assert_eq!(mesh.triangle_count(), 4);
+    assert_eq!(mesh.vertex_count(), 12);
+    assert!(mesh.normals().is_none());
+}
+
+#[test]
+fn test_weld_keeps_non_finite_and_huge_vertices_separate() {
+    let content = r#"solid extreme
+  facet normal 0 0 1
+    outer loop
+      vertex 0.0 0.0 0.0
+      vertex inf 0.0 0.0
+      vertex 0.0 1.0 0.0
+    endloop
+  endfacet
+  facet normal 0 0 1
+    outer loop
+      vertex 0.0 0.0 0.0
+      vertex inf 0.0 0.0
+      vertex 1e300 -1e300 0.0
+    endloop
+  endfacet
+endsolid extreme
+"#;
+    let mut temp_file = NamedTempFile::new().unwrap();
+    temp_file.write_all(content.as_bytes()).unwrap();
+
+    // NaN/Inf 除去と面積ゼロ除去を無効にして weld だけを通す
+    let options = stl::RepairOptions {
+        remove_non_finite: false,
+        remove_degenerate: false,
+        recompute_normals: false,
+        ..stl::RepairOptions::default()
+    };
+    let (mesh, report) = stl::load_stl_repaired(temp_file.path(), options).unwrap();
+
+    // 原点だけが併合され、Inf と巨大座標の頂点は独立のまま残る
+    assert_eq!(report.vertices_welded, 1);
+    assert_eq!(mesh.triangle_count(), 2);
+    assert_eq!(mesh.vertex_count(), 5);
+
+    let (mesh, report) =
+        stl::load_stl_repaired(temp_file.path(), stl::RepairOptions::default()).unwrap();
+    assert_eq!(report.non_finite_removed, 2);
+    assert_eq!(mesh.triangle_count(), 0);
+}
+
+#[test]
+fn test_load_stl_with_options_skips_degenerate() {
+    let mut temp_file = NamedTempFile::new().unwrap();
+    temp_file.write_all(BROKEN_ASCII_STL.as_bytes()).unwrap();
+
+    // NaN座標と一直線上の三角形を読み飛ばす（近接頂点は併合しない）
+    let (mesh, skipped) =
+        stl::load_stl_with_options(temp_file.path(), stl::LoadStlOptions::default()).unwrap();
+    assert_eq!(skipped, 2);
+    assert_eq!(mesh.triangle_count(), 2);
+    assert_eq!(mesh.vertex_count(), 6);
+    assert!(mesh.normals().is_none());
+
+    // 閾値を面積 0.5 にすると直角二等辺三角形（面積 0.5）も読み飛ばす
+    let options = stl::LoadStlOptions {
+        degenerate_area_eps: 0.5,
+        ..stl::LoadStlOptions::default()
+    };
+    let (mesh, skipped) = stl::load_stl_with_options(temp_file.path(), options).unwrap();
+    assert_eq!(skipped, 4);
+    assert!(mesh.is_empty());
+
+    // 無効にすると全て読み込む
+    let options = stl::LoadStlOptions {
+        skip_degenerate: false,
+        ..stl::LoadStlOptions::default()
+    };
+    let (mesh, skipped) = stl::load_stl_with_options(temp_file.path(), options).unwrap();
+    assert_eq!(skipped, 0);
+    assert_eq!(mesh.triangle_count(), 4);
+}
+
+#[test]
+fn test_load_stl_repaired_binary_flipped_normal() {
+    // 正方形の2三角形の Binary STL（2枚目の保存法線は裏向き）
+    let mut bytes = vec![0u8; 80];
+    bytes.extend_from_slice(&2u32.to_le_bytes());
+    let facets: [([f32; 3], [[f32; 3]; 3]); 2] = [
+        (
+            [0.0, 0.0, 1.0],
+            [[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [1.0, 1.0, 0.0]],
+        ),
+        (
+            [0.0, 0.0, -1.0],
+            [[0.0, 0.0, 0.0], [1.0, 1.0, 0.0], [0.0, 1.0, 0.0]],
+        ),
+    ];
+    for (normal, vertices) in facets {
+        for value in normal.iter().chain(vertices.iter().flatten()) {
+            bytes.extend_from_slice(&value.to_le_bytes());
+        }
+        bytes.extend_from_slice(&[0u8; 2]);
+    }
+    let mut temp_file = NamedTempFile::new().unwrap();
+    temp_file.write_all(&bytes).unwrap();
+
+    let options = stl::RepairOptions {
+        weld_tolerance: 0.0,
+        ..stl::RepairOptions::default()
+    };
+    let (mesh, report) = stl::load_stl_repaired(temp_file.path(), options).unwrap();
+    assert_eq!(report.normals_recomputed, 1);
+    assert_eq!(report.vertices_welded, 2);
+    assert_eq!(mesh.vertex_count(), 4);
+    assert_eq!(mesh.triangle_count(), 2);
+}
+
+#[test]
+fn test_stl_unit_scaling() {
+    // ミリメートルで書かれたSTL
+    let content = r#"solid mm
+  facet normal 0 0 1
+    outer loop
+      vertex 0 0 0
+      vertex 25.4 0 0
+      vertex 0 10 0
+    endloop
+  endfacet
+endsolid mm"#;
+    let mut temp_file = NamedTempFile::new().unwrap();
+    temp_file.write_all(content.as_bytes()).unwrap();
+
+    let mesh = stl::load_stl_scaled(temp_file.path(), LengthUnit::Millimeter).unwrap();
+    let triangle = mesh.triangle(0).unwrap();
+    assert!((triangle.vertex_b().x() - 0.0254).abs() < 1e-15);
+    assert!((triangle.vertex_c().y() - 0.01).abs() < 1e-15);
+
+    // メートル → インチで書き出して読み直すと 1 インチ
+    let output = NamedTempFile::new().unwrap();
+    stl::save_stl_in_unit(&mesh, output.path(), LengthUnit::Inch).unwrap();
+    let inches: TriangleMesh3D<f64> = stl::load_stl(output.path()).unwrap();
+    assert!((inches.triangle(0).unwrap().vertex_b().x() - 1.0).abs() < 1e-12);
+
+    // 往復で元のメートル座標に戻る
+    let meters = stl::load_stl_scaled(output.path(), LengthUnit::Inch).unwrap();
+    assert!((meters.triangle(0).unwrap().vertex_c().y() - 0.01).abs() < 1e-15);
+
+    assert_eq!(LengthUnit::Meter.meters_per_unit(), 1.0);
+    assert_eq!(LengthUnit::Centimeter.meters_per_unit(), 0.01);
+    assert_eq!(LengthUnit::Foot.meters_per_unit(), 0.3048);
+
+    // 3MF と同じ単位の定義を共有する
+    assert_eq!(
+        LengthUnit::from_3mf_name("micron"),
+        Some(LengthUnit::Micrometer)
+    );
+    assert_eq!(LengthUnit::from_3mf_name("inch"), Some(LengthUnit::Inch));
+    assert_eq!(LengthUnit::from_3mf_name("yard"), None);
+}
+
+/// 1枚の三角形を `x` だけずらして書いた ASCII STL のファセット
+fn ascii_facet(x: f64) -> String {
+    format!(
+        "  facet normal 0 0 1\n    outer loop\n      vertex {x} 0 0\n      vertex {} 0 0\n      vertex {x} 1 0\n    endloop\n  endfacet\n",
+        x + 1.0
+    )
+}
+
+#[test]
+fn test_load_stl_multi_separates_solids() {
+    // 名前付き2ブロック（2三角形・1三角形）、名前なし1ブロック、空のブロック
+    let content = format!(
+        "solid bracket left\n{}{}endsolid bracket left\nsolid\n{}endsolid\nsolid empty\nendsolid empty\nsolid bolt\n{}endsolid bolt\n",
+        ascii_facet(0.0),
+        ascii_facet(2.0),
+        ascii_facet(4.0),
+        ascii_facet(6.0),
+    );
+    let mut temp_file = NamedTempFile::new().unwrap();
+    temp_file.write_all(content.as_bytes()).unwrap();
+
+    let solids = stl::load_stl_multi(temp_file.path()).unwrap();
+    let names: Vec<&str> = solids.iter().map(|(name, _)| name.as_str()).collect();
+    assert_eq!(names, ["bracket left", "solid_2", "bolt"]);
+    let counts: Vec<usize> = solids
+        .iter()
+        .map(|(_, mesh)| mesh.triangle_count())
+        .collect();
+    assert_eq!(counts, [2, 1, 1]);
+    // 各メッシュは自分のブロックの頂点だけを持つ
+    assert_eq!(solids[2].1.vertex_count(), 3);
+    assert_eq!(
+        solids[2].1.triangle(0).unwrap().vertex_a(),
+        Point3D::new(6.0, 0.0, 0.0)
+    );
+
+    // 単一メッシュ版は全ブロックを結合する
+    let merged: TriangleMesh3D<f64> = stl::load_stl(temp_file.path()).unwrap();
+    assert_eq!(merged.triangle_count(), 4);
+    let streamed = stl::load_stl_streaming(temp_file.path(), |_, _| {}).unwrap();
+    assert_eq!(streamed.triangle_count(), 4);
+    let (repaired, _) =
+        stl::load_stl_repaired(temp_file.path(), stl::RepairOptions::default()).unwrap();
+    assert_eq!(repaired.triangle_count(), 4);
+}
+
+#[test]
+fn test_load_stl_merges_all_solids_of_multi() {
+    let content = format!(
+        "solid a\n{}{}endsolid a\nsolid b\n{}endsolid b\n",
+        ascii_facet(0.0),
+        ascii_facet(1.0),
+        ascii_facet(2.0),
+    );
+    let mut temp_file = NamedTempFile::new().unwrap();
+    temp_file.write_all(content.as_bytes()).unwrap();
+
+    // 単一メッシュ版の三角形は、ソリッドごとの三角形をファイル順に並べたものと一致する
+    let corners = |mesh: &TriangleMesh3D<f64>| -> Vec<[Point3D<f64>; 3]> {
+        (0..mesh.triangle_count())
+            .map(|i| {
+                let triangle = mesh.triangle(i).unwrap();
+                [
+                    triangle.vertex_a(),
+                    triangle.vertex_b(),
+                    triangle.vertex_c(),
+                ]
+            })
+            .collect()
+    };
+    let grouped: Vec<[Point3D<f64>; 3]> = stl::load_stl_multi(temp_file.path())
+        .unwrap()
+        .iter()
+        .flat_map(|(_, mesh)| corners(mesh))
+        .collect();
+    let merged: TriangleMesh3D<f64> = stl::load_stl(temp_file.path()).unwrap();
+    assert_eq!(corners(&merged), grouped);
+    // 隣接するソリッド間で共有される頂点は結合後に1つになる
+    assert_eq!(merged.vertex_count(), 7);
+}
+
+#[test]
+fn test_load_stl_multi_binary_is_single_solid() {
+    let temp_file = write_binary_strip(5);
+    let solids = stl::load_stl_multi(temp_file.path()).unwrap();
+    assert_eq!(solids.len(), 1);
+    assert_eq!(solids[0].0, "solid_1");
+    assert_eq!(solids[0].1.triangle_count(), 5);
+}
@@ -0,0 +1,154 @@
+//! 3MFローダーのテスト
+
+use geo_io::{threemf, IoError};
+use geo_primitives::Point3D;
+use std::io::Write;
+use tempfile::NamedTempFile;
+use zip::write::SimpleFileOptions;
+use zip::ZipWriter;
+
+/// (パス, 内容) の組から3MF（ZIP）ファイルを作成
+fn write_3mf(entries: &[(&str, &str)]) -> NamedTempFile {
+    let temp_file = NamedTempFile::new().unwrap();
+    let mut writer = ZipWriter::new(temp_file.reopen().unwrap());
+    for (name, content) in entries {
+        writer
+            .start_file(*name, SimpleFileOptions::default())
+            .unwrap();
+        writer.write_all(content.as_bytes()).unwrap();
+    }
+    writer.finish().unwrap();
+    temp_file
+}
+
+const TETRAHEDRON: &str = r#"<mesh>
+  <vertices>
+    <vertex x="0" y="0" z="0" />
+    <vertex x="10" y="0" z="0" />
+    <vertex x="0" y="10" z="0" />
+    <vertex x="0" y="0" z="10" />
+  </vertices>
+  <triangles>
+    <triangle v1="0" v2="2" v3="1" />
+    <triangle v1="0" v2="1" v3="3" />
+    <triangle v1="0" v2="3" v3="2" />
+    <triangle v1="1" v2="2" v3="3" pid="1" p1="0" />
+  </triangles>
+</mesh>"#;
+
+fn model(unit: &str, resources: &str, build: &str) -> String {
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<model unit="{unit}" xml:lang="en-US" xmlns="http://schemas.microsoft.com/3dmanufacturing/core/2015/02">
+  <resources>{resources}</resources>
+  <build>{build}</build>
+</model>"#
+    )
+}
+
+fn assert_point(actual: Option<Point3D<f64>>, expected: (f64, f64, f64)) {
+    let actual = actual.unwrap();
+    let (x, y, z) = expected;
+    assert!(
+        (actual.x() - x).abs() < 1e-12
+            && (actual.y() - y).abs() < 1e-12
+            && (actual.z() - z).abs() < 1e-12,
+        "{actual:?} != {expected:?}"
+    );
+}
+
+#[test]
+fn test_3mf_multiple_objects_with_units_and_transforms() {
+    let resources = format!(
+        r#"<object id="1" type="model">{TETRAHEDRON}</object>
+<object id="2" type="model">
+  <components>
+    <component objectid="1" transform="1 0 0 0 1 0 0 0 1 100 0 0" />
+  </components>
+</object>"#
+    );
+    // item 1 はそのまま、item 2 は component で x+100 した後に z 軸周り90°回転して z+5
+    let build = r#"<item objectid="1" />
+<item objectid="2" transform="0 1 0 -1 0 0 0 0 1 0 0 5" />"#;
+    let file = write_3mf(&[("3D/3dmodel.model", &model("millimeter", &resources, build))]);
+
+    let meshes = threemf::load_3mf(file.path()).unwrap();
+    assert_eq!(meshes.len(), 2);
+    assert_eq!(meshes[0].triangle_count(), 4);
+    assert_eq!(meshes[0].indices()[0], [0, 2, 1]);
+    // ミリメートル → メートル
+    assert_point(meshes[0].vertex(1), (0.01, 0.0, 0.0));
+
+    // (10, 0, 0) → (110, 0, 0) → (0, 110, 5) mm
+    assert_point(meshes[1].vertex(1), (0.0, 0.11, 0.005));
+    assert_point(meshes[1].vertex(2), (-0.01, 0.1, 0.005));
+}
+
+#[test]
+fn test_3mf_root_model_from_relationships() {
+    let rels = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">
+  <Relationship Target="/3D/part.model" Id="rel0" Type="http://schemas.microsoft.com/3dmanufacturing/2013/01/3dmodel" />
+</Relationships>"#;
+    let resources = format!(r#"<object id="7">{TETRAHEDRON}</object>"#);
+    let file = write_3mf(&[
+        ("_rels/.rels", rels),
+        (
+            "3D/part.model",
+            &model("inch", &resources, r#"<item objectid="7" />"#),
+        ),
+    ]);
+
+    let meshes = threemf::load_3mf(file.path()).unwrap();
+    assert_eq!(meshes.len(), 1);
+    assert_point(meshes[0].vertex(3), (0.0, 0.0, 0.254));
+}
+
+#[test]
+fn test_3mf_invalid_input() {
+    let resources = format!(r#"<object id="1">{TETRAHEDRON}</object>"#);
+    let cases = [
+        // モデルXMLがない
+        write_3mf(&[("3D/other.model", "<model />")]),
+        // 未知のobject参照
+        write_3mf(&[(
+            "3D/3dmodel.model",
+            &model("millimeter", &resources, r#"<item objectid="9" />"#),
+        )]),
+        // 未対応の単位
+        write_3mf(&[(
+            "3D/3dmodel.model",
+            &model("parsec", &resources, r#"<item objectid="1" />"#),
+        )]),
+        // 範囲外の頂点インデックス
+        write_3mf(&[(
+            "3D/3dmodel.model",
+            &model(
+                "millimeter",
+                r#"<object id="1"><mesh><vertices><vertex x="0" y="0" z="0" /></vertices>
+<triangles><triangle v1="0" v2="1" v3="2" /></triangles></mesh></object>"#,
+                r#"<item objectid="1" />"#,
+            ),
+        )]),
+        // 自己参照するcomponent
+        write_3mf(&[(
+            "3D/3dmodel.model",
+            &model(
+                "millimeter",
+                r#"<object id="1"><components><component objectid="1" /></components></object>"#,
+                r#"<item objectid="1" />"#,
+            ),
+        )]),
+    ];
+    for file in &cases {
+        assert!(threemf::load_3mf(file.path()).is_err());
+    }
+
+    // ZIPでないファイル
+    let mut plain = NamedTempFile::new().unwrap();
+    plain.write_all(b"solid not a zip").unwrap();
+    assert!(matches!(
+        threemf::load_3mf(plain.path()),
+        Err(IoError::Format(_))
+    ));
+}
@@ -0,0 +1,206 @@
+//! ワイヤーフレームエクスポートのテスト
+
+use geo_foundation::Angle;
+use geo_io::wireframe::{self, Polyline, WireframeFormat};
+use geo_io::IoError;
+use geo_nurbs::{GeometryUnion, NurbsCurve3D};
+use geo_primitives::{Arc3D, Circle3D, Direction3D, Ellipse3D, LineSegment3D, Point3D};
+use tempfile::NamedTempFile;
+
+fn export_to_string(curves: &[GeometryUnion<f64>], format: WireframeFormat) -> String {
+    let temp_file = NamedTempFile::new().unwrap();
+    wireframe::export_curves(curves, temp_file.path(), format).unwrap();
+    std::fs::read_to_string(temp_file.path()).unwrap()
+}
+
+fn line_elements(obj: &str) -> Vec<Vec<usize>> {
+    obj.lines()
+        .filter_map(|line| line.strip_prefix("l "))
+        .map(|rest| {
+            rest.split_whitespace()
+                .map(|i| i.parse().unwrap())
+                .collect()
+        })
+        .collect()
+}
+
+#[test]
+fn test_obj_line_segment_and_closed_circle() {
+    let segment =
+        LineSegment3D::new(Point3D::new(0.0, 0.0, 0.0), Point3D::new(1.0, 2.0, 3.0)).unwrap();
+    let circle = Circle3D::new_xy_plane(Point3D::new(5.0, 0.0, 0.0), 2.0).unwrap();
+    let obj = export_to_string(&[segment.into(), circle.into()], WireframeFormat::Obj);
+
+    let vertex_count = obj.lines().filter(|line| line.starts_with("v ")).count();
+    let lines = line_elements(&obj);
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[0], vec![1, 2]);
+
+    // 円は終点を重複させず、`l` の末尾で始点へ戻る
+    let circle_line = &lines[1];
+    assert_eq!(circle_line.first(), Some(&3));
+    assert_eq!(circle_line.last(), Some(&3));
+    assert_eq!(circle_line.len(), vertex_count - 2 + 1);
+}
+
+#[test]
+fn test_polylines_respect_chord_tolerance() {
+    let radius = 10.0;
+    let tolerance = 0.01;
+    let circle = Circle3D::new_xy_plane(Point3D::origin(), radius).unwrap();
+    let polylines = wireframe::curves_to_polylines(&[circle.clone().into()], tolerance).unwrap();
+    let points = &polylines[0].points;
+    assert!(polylines[0].closed);
+
+    // 各辺の中点と円との距離（弦高）が許容誤差以内
+    for (i, a) in points.iter().enumerate() {
+        let b = points[(i + 1) % points.len()];
+        let mid = Point3D::new((a.x() + b.x()) / 2.0, (a.y() + b.y()) / 2.0, 0.0);
+        let sagitta = radius - mid.distance_to(&Point3D::origin());
+        assert!(sagitta <= tolerance + 1e-12);
+    }
+
+    // 許容誤差を緩めると点数が減る
+    let coarse = wireframe::curves_to_polylines(&[circle.into()], 0.5).unwrap();
+    assert!(coarse[0].points.len() < points.len());
+}
+
+#[test]
+fn test_open_arc_and_ellipse() {
+    let arc = Arc3D::xy_arc(
+        Point3D::origin(),
+        1.0,
+        Angle::from_degrees(0.0),
+        Angle::from_degrees(90.0),
+    )
+    .unwrap();
+    let ellipse = Ellipse3D::xy_aligned(Point3D::origin(), 3.0, 1.0).unwrap();
+    let polylines = wireframe::curves_to_polylines(&[arc.into(), ellipse.into()], 1e-3).unwrap();
+
+    let arc_points = &polylines[0].points;
+    assert!(!polylines[0].closed);
+    assert!(arc_points[0].distance_to(&Point3D::new(1.0, 0.0, 0.0)) < 1e-12);
+    assert!(
+        arc_points
+            .last()
+            .unwrap()
+            .distance_to(&Point3D::new(0.0, 1.0, 0.0))
+            < 1e-12
+    );
+
+    assert!(polylines[1].closed);
+    for p in &polylines[1].points {
+        let value = (p.x() / 3.0).powi(2) + p.y().powi(2);
+        assert!((value - 1.0).abs() < 1e-12);
+    }
+}
+
+#[test]
+fn test_nurbs_curve_polyline() {
+    // 重み √2/2 の2次有理曲線は四分円（半径 5）
+    let w = std::f64::consts::FRAC_1_SQRT_2;
+    let quarter = NurbsCurve3D::new(
+        vec![
+            Point3D::new(5.0, 0.0, 0.0).to_analysis_vector3(),
+            Point3D::new(5.0, 5.0, 0.0).to_analysis_vector3(),
+            Point3D::new(0.0, 5.0, 0.0).to_analysis_vector3(),
+        ],
+        Some(vec![1.0, w, 1.0]),
+        vec![0.0, 0.0, 0.0, 1.0, 1.0, 1.0],
+        2,
+    )
+    .unwrap();
+    let tolerance = 1e-3;
+    let polylines = wireframe::curves_to_polylines(&[quarter.into()], tolerance).unwrap();
+    let points = &polylines[0].points;
+    assert!(!polylines[0].closed);
+    assert!(points[0].distance_to(&Point3D::new(5.0, 0.0, 0.0)) < 1e-12);
+    assert!(
+        points
+            .last()
+            .unwrap()
+            .distance_to(&Point3D::new(0.0, 5.0, 0.0))
+            < 1e-12
+    );
+
+    // 各辺の中点の弦高が許容誤差以内
+    for pair in points.windows(2) {
+        let mid = Point3D::new(
+            (pair[0].x() + pair[1].x()) / 2.0,
+            (pair[0].y() + pair[1].y()) / 2.0,
+            0.0,
+        );
+        assert!(5.0 - mid.distance_to(&Point3D::origin()) <= tolerance + 1e-12);
+    }
+}
+
+#[test]
+fn test_full_circle_arc_is_closed() {
+    let arc = Arc3D::new(
+        Point3D::origin(),
+        1.0,
+        Direction3D::positive_z(),
+        Direction3D::positive_x(),
+        Angle::from_degrees(0.0),
+        Angle::from_degrees(360.0),
+    )
+    .unwrap();
+    let polylines = wireframe::curves_to_polylines(&[arc.into()], 1e-3).unwrap();
+    let points = &polylines[0].points;
+    assert!(polylines[0].closed);
+    assert!(points[0].distance_to(points.last().unwrap()) > 1e-3);
+}
+
+#[test]
+fn test_dxf_polyline_flags() {
+    let segment =
+        LineSegment3D::new(Point3D::new(0.0, 0.0, 0.0), Point3D::new(1.0, 0.0, 0.0)).unwrap();
+    let circle = Circle3D::new_xy_plane(Point3D::origin(), 1.0).unwrap();
+    let dxf = export_to_string(&[segment.into(), circle.into()], WireframeFormat::Dxf);
+    let lines: Vec<&str> = dxf.lines().collect();
+    assert_eq!(lines.len() % 2, 0);
+
+    // (グループコード, 値) の組から POLYLINE ごとのフラグ（コード70）と頂点数を集める
+    let mut polylines: Vec<(u32, usize)> = Vec::new();
+    let mut in_header = false;
+    for pair in lines.chunks(2) {
+        match (pair[0], pair[1]) {
+            ("0", "POLYLINE") => {
+                polylines.push((0, 0));
+                in_header = true;
+            }
+            ("0", "VERTEX") => {
+                in_header = false;
+                polylines.last_mut().unwrap().1 += 1;
+            }
+            ("70", value) if in_header => polylines.last_mut().unwrap().0 = value.parse().unwrap(),
+            _ => {}
+        }
+    }
+    assert_eq!(polylines.len(), 2);
+    assert_eq!(polylines[0], (8, 2));
+    assert_eq!(polylines[1].0, 9);
+    assert!(polylines[1].1 >= 8);
+    assert_eq!(lines.iter().filter(|l| **l == "SEQEND").count(), 2);
+    assert_eq!(lines.last(), Some(&"EOF"));
+}
+
+#[test]
+fn test_export_polylines_and_non_curve_error() {
+    let polyline = Polyline {
+        points: vec![
+            Point3D::new(0.0, 0.0, 0.0),
+            Point3D::new(1.0, 0.0, 0.0),
+            Point3D::new(1.0, 1.0, 0.0),
+        ],
+        closed: true,
+    };
+    let temp_file = NamedTempFile::new().unwrap();
+    wireframe::export_polylines(&[polyline], temp_file.path(), WireframeFormat::Obj).unwrap();
+    let obj = std::fs::read_to_string(temp_file.path()).unwrap();
+    assert_eq!(line_elements(&obj), vec![vec![1, 2, 3, 1]]);
+
+    let point: GeometryUnion<f64> = Point3D::origin().into();
+    let result = wireframe::export_curves(&[point], temp_file.path(), WireframeFormat::Obj);
+    assert!(matches!(result, Err(IoError::Conversion(_))));
+}
@@ -105,49 +105,170 @@ pub fn basis_derivatives<T: Scalar>(
     knots: &KnotVector<T>,
     derivative_order: usize,
 ) -> Vec<Vec<T>> {
-    let n = derivative_order;
     let p = degree;
+    let order = derivative_order.min(p);
+
+    // ndu[j][r]: 上三角に基底関数値、下三角にノット差を格納（NURBS Book A2.3）
+    let mut ndu = vec![vec![T::ZERO; p + 1]; p + 1];
+    let mut left = vec![T::ZERO; p + 1];
+    let mut right = vec![T::ZERO; p + 1];
+    ndu[0][0] = T::ONE;
+    for j in 1..=p {
+        left[j] = t - knots[span + 1 - j];
+        right[j] = knots[span + j] - t;
+        let mut saved = T::ZERO;
+        for r in 0..j {
+            ndu[j][r] = right[r + 1] + left[j - r];
+            let temp = ndu[r][j - 1] / ndu[j][r];
+            ndu[r][j] = saved + right[r + 1] * temp;
+            saved = left[j - r] * temp;
+        }
+        ndu[j][j] = saved;
+    }
 
-    // 結果の配列を初期化
-    let mut derivatives = vec![vec![T::ZERO; p + 1]; n + 1];
+    let mut derivatives = vec![vec![T::ZERO; p + 1]; derivative_order + 1];
+    for j in 0..=p {
+        derivatives[0][j] = ndu[j][p];
+    }
 
-    // 0次導関数（基底関数そのもの）
-    derivatives[0] = basis_functions(span, degree, t, knots);
+    // 係数配列を2行交互に使って k 次導関数を計算
+    let mut coefficients = vec![vec![T::ZERO; p + 1]; 2];
+    for r in 0..=p {
+        let (mut s1, mut s2) = (0, 1);
+        coefficients[0][0] = T::ONE;
+        for k in 1..=order {
+            let pk = p - k;
+            let mut value = T::ZERO;
+            if r >= k {
+                coefficients[s2][0] = coefficients[s1][0] / ndu[pk + 1][r - k];
+                value = coefficients[s2][0] * ndu[r - k][pk];
+            }
+            let j1 = if r + 1 >= k { 1 } else { k - r };
+            let j2 = if r <= pk { k - 1 } else { p - r };
+            for j in j1..=j2 {
+                let index = r + j - k;
+                coefficients[s2][j] =
+                    (coefficients[s1][j] - coefficients[s1][j - 1]) / ndu[pk + 1][index];
+                value += coefficients[s2][j] * ndu[index][pk];
+            }
+            if r <= pk {
+                coefficients[s2][k] = -coefficients[s1][k - 1] / ndu[pk + 1][r];
+                value += coefficients[s2][k] * ndu[r][pk];
+            }
+            derivatives[k][r] = value;
+            std::mem::swap(&mut s1, &mut s2);
+        }
+    }
 
-    if n == 0 {
-        return derivatives;
+    // 係数 p! / (p - k)! を掛ける（次数を超える導関数は0のまま）
+    let mut factor = T::from_usize(p);
+    for (k, row) in derivatives.iter_mut().enumerate().take(order + 1).skip(1) {
+        for value in row {
+            *value *= factor;
+        }
+        factor *= T::from_usize(p - k);
     }
 
-    // 高次導関数の計算
-    for k in 1..=n {
-        for i in 0..=p {
-            let left_index = span - p + i;
-            let right_index = left_index + 1;
+    derivatives
+}
 
-            let mut left_term = T::ZERO;
-            let mut right_term = T::ZERO;
+/// 1つのパラメータでの非ゼロ基底関数とその導関数（疎な表現）
+///
+/// 次数 p の B-スプライン基底はスパン内で `p + 1` 個だけが非ゼロなので、
+/// その先頭インデックスと値だけを持つ。
+#[derive(Debug, Clone, PartialEq)]
+pub struct SparseBasis<T: Scalar> {
+    /// 非ゼロ基底関数を含むノットスパン
+    pub span: usize,
+    /// `derivatives[k][j]` は `N_{first_index() + j, degree}` の k 次導関数（`derivatives[0]` が基底関数値）
+    pub derivatives: Vec<Vec<T>>,
+}
 
-            // 左側の項
-            if left_index + p < knots.len() {
-                let denom = knots[left_index + p] - knots[left_index];
-                if !denom.is_zero() && i > 0 {
-                    left_term = T::from_usize(p) * derivatives[k - 1][i - 1] / denom;
-                }
-            }
+impl<T: Scalar> SparseBasis<T> {
+    /// 非ゼロ基底関数の先頭インデックス（= span - degree）
+    #[must_use]
+    pub fn first_index(&self) -> usize {
+        self.span + 1 - self.derivatives[0].len()
+    }
 
-            // 右側の項
-            if right_index + p < knots.len() {
-                let denom = knots[right_index + p] - knots[right_index];
-                if !denom.is_zero() && i < p {
-                    right_term = T::from_usize(p) * derivatives[k - 1][i + 1] / denom;
-                }
+    /// 非ゼロ基底関数の値（長さ = degree + 1）
+    #[must_use]
+    pub fn values(&self) -> &[T] {
+        &self.derivatives[0]
+    }
+
+    /// 基底関数 `index` の k 次導関数（スパン外の基底や次数を超える導関数は0）
+    #[must_use]
+    pub fn get(&self, index: usize, k: usize) -> T {
+        let first = self.first_index();
+        self.derivatives
+            .get(k)
+            .and_then(|row| row.get(index.checked_sub(first)?))
+            .copied()
+            .unwrap_or(T::ZERO)
+    }
+}
+
+/// 多数のパラメータでの非ゼロ基底関数を一括で計算
+///
+/// [`basis_derivatives_batch`] の `derivative_order = 0` 版。
+#[must_use]
+pub fn basis_functions_batch<T: Scalar>(
+    knots: &KnotVector<T>,
+    degree: usize,
+    params: &[T],
+) -> Vec<SparseBasis<T>> {
+    basis_derivatives_batch(knots, degree, params, 0)
+}
+
+/// 多数のパラメータでの非ゼロ基底関数と `derivative_order` 次までの導関数を一括で計算
+///
+/// 結果は `params` と同じ順で、各要素は単点版の [`find_knot_span`] と
+/// [`basis_derivatives`] の結果に一致する。
+/// テッセレーションのようにパラメータが単調に並ぶ場合、直前のスパンとその次のスパンを
+/// 先に調べることで二分探索をほぼ省き、同じパラメータが続く場合は前の結果を再利用する。
+///
+/// [`find_knot_span`]: crate::knot::find_knot_span
+#[must_use]
+pub fn basis_derivatives_batch<T: Scalar>(
+    knots: &KnotVector<T>,
+    degree: usize,
+    params: &[T],
+    derivative_order: usize,
+) -> Vec<SparseBasis<T>> {
+    let mut results: Vec<SparseBasis<T>> = Vec::with_capacity(params.len());
+    let mut previous: Option<(T, usize)> = None;
+    for &t in params {
+        let span = match previous {
+            Some((previous_t, _)) if previous_t == t => {
+                let repeated = results[results.len() - 1].clone();
+                results.push(repeated);
+                continue;
             }
+            Some((_, span)) => cached_span(t, knots, degree, span),
+            None => crate::knot::find_knot_span(t, knots, degree),
+        };
+        results.push(SparseBasis {
+            span,
+            derivatives: basis_derivatives(span, degree, t, knots, derivative_order),
+        });
+        previous = Some((t, span));
+    }
+    results
+}
 
-            derivatives[k][i] = left_term - right_term;
+/// 直前のスパン `hint` とその次のスパンを先に調べ、外れたら二分探索でスパンを求める
+///
+/// 有効定義域の内部では `knots[span] <= t < knots[span + 1]` が成り立つスパンは
+/// [`find_knot_span`](crate::knot::find_knot_span) の結果と一致する（長さ0のスパンは条件を満たさない）。
+fn cached_span<T: Scalar>(t: T, knots: &[T], degree: usize, hint: usize) -> usize {
+    let last = knots.len() - degree - 2;
+    for span in [hint, hint + 1] {
+        if span >= degree && span <= last && knots[span] <= t && t < knots[span + 1] {
+            return span;
         }
     }
-
-    derivatives
+    crate::knot::find_knot_span(t, knots, degree)
 }
 
 /// 有理基底関数（NURBS基底関数）を計算
@@ -266,6 +387,77 @@ pub fn rational_basis_derivatives<T: Scalar>(
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_basis_derivatives_match_finite_differences() {
+        let knots = vec![0.0, 0.0, 0.0, 0.0, 0.5, 1.0, 1.0, 1.0, 1.0];
+        let h = 1e-5;
+        for &t in &[0.2, 0.3, 0.75] {
+            let span = crate::knot::find_knot_span(t, &knots, 3);
+            let derivatives = basis_derivatives(span, 3, t, &knots, 4);
+            let before = basis_functions(span, 3, t - h, &knots);
+            let center = basis_functions(span, 3, t, &knots);
+            let after = basis_functions(span, 3, t + h, &knots);
+
+            for i in 0..=3 {
+                assert!((derivatives[0][i] - center[i]).abs() < 1e-12);
+                let first = (after[i] - before[i]) / (2.0 * h);
+                let second = (after[i] - 2.0 * center[i] + before[i]) / (h * h);
+                assert!((derivatives[1][i] - first).abs() < 1e-6);
+                assert!((derivatives[2][i] - second).abs() < 1e-3);
+                // 次数を超える導関数は0
+                assert!(derivatives[4][i].abs() < f64::EPSILON);
+            }
+            // 基底関数の和は常に1なので、導関数の和は0
+            assert!(derivatives[1].iter().sum::<f64>().abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_batch_matches_single_point_evaluation() {
+        // 内部ノットの重複（0.5 が2重）を含む3次のノットベクトル
+        let knots = vec![0.0, 0.0, 0.0, 0.0, 0.25, 0.5, 0.5, 0.8, 1.0, 1.0, 1.0, 1.0];
+        let degree = 3;
+        // 昇順（スパン境界・端点を含む）、同じ値の連続、逆順の飛び
+        let mut params: Vec<f64> = (0..=40).map(|i| f64::from(i) / 40.0).collect();
+        params.extend([0.5, 0.5, 0.8, 0.1, 0.0, 1.0, 0.3, 0.9999]);
+
+        let batch = basis_derivatives_batch(&knots, degree, &params, 2);
+        assert_eq!(batch.len(), params.len());
+        for (&t, sparse) in params.iter().zip(&batch) {
+            let span = crate::knot::find_knot_span(t, &knots, degree);
+            assert_eq!(sparse.span, span, "t = {t}");
+            assert_eq!(sparse.first_index(), span - degree);
+            assert_eq!(
+                sparse.derivatives,
+                basis_derivatives(span, degree, t, &knots, 2)
+            );
+            assert!((sparse.values().iter().sum::<f64>() - 1.0).abs() < 1e-12);
+        }
+
+        // 導関数なしの版は値だけを持つ
+        let values = basis_functions_batch(&knots, degree, &params);
+        for (sparse, full) in values.iter().zip(&batch) {
+            assert_eq!(sparse.derivatives.len(), 1);
+            assert_eq!(sparse.values(), full.values());
+        }
+    }
+
+    #[test]
+    fn test_sparse_basis_lookup() {
+        let knots = vec![0.0, 0.0, 0.0, 0.5, 1.0, 1.0, 1.0];
+        let batch = basis_derivatives_batch(&knots, 2, &[0.75], 1);
+        let sparse = &batch[0];
+        // t = 0.75 はスパン3、非ゼロ基底は N_1, N_2, N_3
+        assert_eq!(sparse.first_index(), 1);
+        assert!(sparse.get(0, 0).abs() < f64::EPSILON);
+        assert!((sparse.get(3, 0) - 0.25).abs() < 1e-12);
+        assert!((sparse.get(3, 1) - 2.0).abs() < 1e-12);
+        // スパン外・次数外は0
+        assert!(sparse.get(9, 0).abs() < f64::EPSILON);
+        assert!(sparse.get(2, 2).abs() < f64::EPSILON);
+        assert!(basis_functions_batch(&knots, 2, &[]).is_empty());
+    }
+
     #[test]
     fn test_basis_function_degree_0() {
         let knots = vec![0.0, 1.0, 2.0, 3.0];
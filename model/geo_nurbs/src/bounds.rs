@@ -0,0 +1,266 @@
+//! NURBS曲線・曲面のバウンディングボックス（`Bounded3D`）
+//!
+//! 曲線は制御点の凸包ではなく、導関数の零点（各軸の極値）から求めた
+//! タイトなボックスを返す（[`NurbsCurve3D::tight_bbox`]）。
+//!
+//! 曲面は制御点のボックスを返す（重みが正なら曲面は制御点の凸包に含まれるため
+//! 保守的だが、角の制御点を通る曲面では一致する）。
+
+use crate::{NurbsCurve3D, NurbsSurface3D, Scalar};
+use analysis::linalg::vector::Vector3;
+use geo_foundation::Bounded3D;
+use geo_primitives::{BBox3D, Point3D};
+
+/// 導関数の符号変化を探す1つのノットスパンあたりのサンプル数
+const SAMPLES_PER_SPAN: usize = 16;
+
+/// 導関数の零点を求めるニュートン法（二分法で保護）の最大反復回数
+const MAX_ROOT_ITERATIONS: usize = 60;
+
+/// ベクトルの第 `axis` 成分（0: x, 1: y, 2: z）
+fn component<T: Scalar>(v: &Vector3<T>, axis: usize) -> T {
+    match axis {
+        0 => v.x(),
+        1 => v.y(),
+        _ => v.z(),
+    }
+}
+
+/// 点列を囲むボックス（空なら原点の大きさ0のボックス）
+fn points_box<T: Scalar>(points: impl IntoIterator<Item = Vector3<T>>) -> BBox3D<T> {
+    let points: Vec<Point3D<T>> = points
+        .into_iter()
+        .map(|v| Point3D::new(v.x(), v.y(), v.z()))
+        .collect();
+    BBox3D::from_points(&points).unwrap_or_else(|| BBox3D::from_point(Point3D::origin()))
+}
+
+impl<T: Scalar> NurbsCurve3D<T> {
+    /// 曲線上の点だけから求めたタイトなバウンディングボックス
+    ///
+    /// 各座標軸について1階微分の成分がゼロになるパラメータ（極値）を求め、
+    /// その点と定義域の端点・内部ノット上の点を囲むボックスを返す。
+    /// 極値は各ノットスパンをサンプリングして導関数の符号変化を探し、
+    /// 二分法で保護したニュートン法で詰める。直線区間や変曲のない区間は
+    /// 導関数の符号が変わらないため、端点だけが候補になる。
+    #[must_use]
+    pub fn tight_bbox(&self) -> BBox3D<T> {
+        let breakpoints = self.span_breakpoints();
+        let mut candidates: Vec<Vector3<T>> =
+            breakpoints.iter().map(|&t| self.evaluate_at(t)).collect();
+
+        for span in breakpoints.windows(2) {
+            let (start, end) = (span[0], span[1]);
+            let params: Vec<T> = (0..=SAMPLES_PER_SPAN)
+                .map(|i| start + (end - start) * T::from_usize(i) / T::from_usize(SAMPLES_PER_SPAN))
+                .collect();
+            let derivatives: Vec<Vector3<T>> =
+                params.iter().map(|&t| self.derivative_at(t)).collect();
+
+            for axis in 0..3 {
+                for i in 0..SAMPLES_PER_SPAN {
+                    let (d0, d1) = (
+                        component(&derivatives[i], axis),
+                        component(&derivatives[i + 1], axis),
+                    );
+                    if d0.is_zero() {
+                        candidates.push(self.evaluate_at(params[i]));
+                    } else if d0 * d1 < T::ZERO {
+                        let t = self.derivative_root(axis, params[i], params[i + 1], d0);
+                        candidates.push(self.evaluate_at(t));
+                    }
+                }
+            }
+        }
+
+        points_box(candidates)
+    }
+
+    /// 定義域の端点と内部ノット（重複なし、昇順）
+    pub(crate) fn span_breakpoints(&self) -> Vec<T> {
+        let (t_min, t_max) = self.parameter_domain();
+        let mut breakpoints = vec![t_min];
+        breakpoints.extend(
+            self.knot_vector()
+                .iter()
+                .copied()
+                .filter(|&knot| knot > t_min && knot < t_max),
+        );
+        breakpoints.push(t_max);
+        breakpoints.dedup();
+        breakpoints
+    }
+
+    /// 導関数の第 `axis` 成分の `[lo, hi]` 内の零点（`lo` での値は `f_lo`、`hi` と異符号）
+    fn derivative_root(&self, axis: usize, mut lo: T, mut hi: T, f_lo: T) -> T {
+        let lo_is_negative = f_lo < T::ZERO;
+        let mut t = (lo + hi) / T::from_f64(2.0);
+        for _ in 0..MAX_ROOT_ITERATIONS {
+            let f = component(&self.derivative_at(t), axis);
+            if f.is_zero() {
+                break;
+            }
+            // 零点を挟む区間を更新
+            if (f < T::ZERO) == lo_is_negative {
+                lo = t;
+            } else {
+                hi = t;
+            }
+            if hi - lo <= T::EPSILON * (T::ONE + hi.abs()) {
+                break;
+            }
+
+            // ニュートン法の更新が区間外なら二分法
+            let df = component(&self.second_derivative_at(t), axis);
+            let newton = t - f / df;
+            t = if df.is_zero() || newton <= lo || newton >= hi {
+                (lo + hi) / T::from_f64(2.0)
+            } else {
+                newton
+            };
+        }
+        t
+    }
+}
+
+impl<T: Scalar> Bounded3D<T> for NurbsCurve3D<T> {
+    type BBox = BBox3D<T>;
+
+    /// [`NurbsCurve3D::tight_bbox`] と同じ
+    fn bbox(&self) -> BBox3D<T> {
+        self.tight_bbox()
+    }
+}
+
+impl<T: Scalar> Bounded3D<T> for NurbsSurface3D<T> {
+    type BBox = BBox3D<T>;
+
+    /// 制御点のボックス（曲面を必ず含む保守的な値）
+    fn bbox(&self) -> BBox3D<T> {
+        let (u_count, v_count) = self.grid_size();
+        points_box((0..u_count).flat_map(|u| (0..v_count).map(move |v| self.control_point(u, v))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 中心 (1, 1)、半径1の有理2次の半円（(2, 1) から (0, 1) まで、上側）
+    fn upper_half_circle() -> NurbsCurve3D<f64> {
+        let w = std::f64::consts::FRAC_1_SQRT_2;
+        let control_points = vec![
+            Vector3::new(2.0, 1.0, 0.0),
+            Vector3::new(2.0, 2.0, 0.0),
+            Vector3::new(1.0, 2.0, 0.0),
+            Vector3::new(0.0, 2.0, 0.0),
+            Vector3::new(0.0, 1.0, 0.0),
+        ];
+        NurbsCurve3D::new(
+            control_points,
+            Some(vec![1.0, w, 1.0, w, 1.0]),
+            vec![0.0, 0.0, 0.0, 0.5, 0.5, 1.0, 1.0, 1.0],
+            2,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_rational_arc_bbox_is_exact() {
+        let bbox = upper_half_circle().bbox();
+        let (min, max) = (bbox.min(), bbox.max());
+        for (actual, expected) in [
+            (min.x(), 0.0),
+            (min.y(), 1.0),
+            (max.x(), 2.0),
+            (max.y(), 2.0),
+            (min.z(), 0.0),
+            (max.z(), 0.0),
+        ] {
+            assert!((actual - expected).abs() < 1e-12, "{actual} != {expected}");
+        }
+    }
+
+    #[test]
+    fn test_bezier_bbox_is_tighter_than_hull() {
+        // 2次ベジェの頂点は t = 0.5 で y = 0.5（制御点の y は 1 まで）
+        let curve = NurbsCurve3D::from_bezier(&[
+            Point3D::new(0.0, 0.0, 0.0),
+            Point3D::new(1.0, 1.0, 0.0),
+            Point3D::new(2.0, 0.0, 0.0),
+        ])
+        .unwrap();
+        let bbox = curve.bbox();
+        assert!((bbox.max().y() - 0.5).abs() < 1e-12);
+        assert!((bbox.max().x() - 2.0).abs() < 1e-12);
+        assert!(bbox.min().y().abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_polyline_uses_vertices() {
+        // 1次（折れ線）：導関数は各区間で一定なので、ノット上の頂点だけで決まる
+        let control_points = vec![
+            Vector3::new(0.0, 0.0, 0.0),
+            Vector3::new(2.0, 3.0, -1.0),
+            Vector3::new(4.0, -1.0, 0.0),
+        ];
+        let curve =
+            NurbsCurve3D::new(control_points, None, vec![0.0, 0.0, 0.4, 1.0, 1.0], 1).unwrap();
+        let bbox = curve.tight_bbox();
+        assert_eq!(bbox.min(), Point3D::new(0.0, -1.0, -1.0));
+        assert_eq!(bbox.max(), Point3D::new(4.0, 3.0, 0.0));
+    }
+
+    #[test]
+    fn test_cubic_with_inflection_matches_dense_sampling() {
+        // S字の3次ベジェ（y は1つの極大と1つの極小を持つ）
+        let curve = NurbsCurve3D::from_bezier(&[
+            Point3D::new(0.0, 0.0, 0.0),
+            Point3D::new(1.0, 3.0, 1.0),
+            Point3D::new(2.0, -3.0, 1.0),
+            Point3D::new(3.0, 0.0, 0.0),
+        ])
+        .unwrap();
+        let bbox = curve.tight_bbox();
+
+        let samples: Vec<_> = (0..=100_000)
+            .map(|i| curve.evaluate_at(f64::from(i) / 100_000.0))
+            .collect();
+        let extreme = |f: fn(&Vector3<f64>) -> f64| {
+            samples
+                .iter()
+                .map(f)
+                .fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), v| {
+                    (lo.min(v), hi.max(v))
+                })
+        };
+        let (y_min, y_max) = extreme(Vector3::y);
+        let (z_min, z_max) = extreme(Vector3::z);
+        assert!((bbox.max().y() - y_max).abs() < 1e-9);
+        assert!((bbox.min().y() - y_min).abs() < 1e-9);
+        assert!((bbox.max().z() - z_max).abs() < 1e-9);
+        assert!(bbox.min().z().abs() < 1e-12 && z_min.abs() < 1e-12);
+        // 制御点の凸包（y ∈ [-3, 3]）より小さい
+        assert!(bbox.max().y() < 1.0 && bbox.min().y() > -1.0);
+    }
+
+    #[test]
+    fn test_surface_bbox_contains_surface() {
+        let control_points = vec![
+            vec![Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 2.0, 1.0)],
+            vec![Vector3::new(3.0, 0.0, 1.0), Vector3::new(3.0, 2.0, 0.0)],
+        ];
+        let knots = vec![0.0, 0.0, 1.0, 1.0];
+        let surface =
+            NurbsSurface3D::new(control_points, None, knots.clone(), knots, 1, 1).unwrap();
+        let bbox = surface.bbox();
+        assert_eq!(bbox.min(), Point3D::new(0.0, 0.0, 0.0));
+        assert_eq!(bbox.max(), Point3D::new(3.0, 2.0, 1.0));
+        for i in 0..=4 {
+            for j in 0..=4 {
+                let p = surface.evaluate_at(f64::from(i) / 4.0, f64::from(j) / 4.0);
+                assert!(bbox.contains_point(&Point3D::new(p.x(), p.y(), p.z())));
+            }
+        }
+    }
+}
@@ -3,18 +3,9 @@
 //! Non-Uniform Rational B-Spline 2D curves の基本実装です。
 //! フラット配列による高効率メモリ配置で制御点、重み、ノットベクトルを管理します。
 
-use crate::{KnotVector, NurbsError, Result, Scalar};
+use crate::{KnotVector, NurbsError, Result, Scalar, WeightStorage};
 use analysis::linalg::vector::Vector2;
 
-/// 重み配列の効率的管理（2D曲線用）
-#[derive(Debug, Clone)]
-pub enum WeightStorage<T: Scalar> {
-    /// 非有理曲線（全重み = 1.0）
-    Uniform,
-    /// 有理曲線（個別重み）
-    Individual(Vec<T>),
-}
-
 /// NURBS曲線 - 2次元（Foundation パターン準拠・ジェネリック）
 ///
 /// # 特徴
@@ -98,9 +89,10 @@ impl<T: Scalar> NurbsCurve2D<T> {
                 }
             }
 
-            WeightStorage::Individual(weight_vec)
+            // 全て1.0なら非有理として多項式の評価経路を使う
+            WeightStorage::from_weights(weight_vec)
         } else {
-            WeightStorage::Uniform
+            WeightStorage::Uniform(T::ONE)
         };
 
         // ノットベクトルの検証
@@ -132,10 +124,13 @@ impl<T: Scalar> NurbsCurve2D<T> {
     /// 重み取得
     #[must_use]
     pub fn weight(&self, index: usize) -> T {
-        match &self.weights {
-            WeightStorage::Uniform => T::ONE,
-            WeightStorage::Individual(weights) => weights[index],
-        }
+        self.weights.get_weight(index)
+    }
+
+    /// 有理曲線（1.0 以外の重みを含む）かどうか
+    #[must_use]
+    pub fn is_rational(&self) -> bool {
+        self.weights.is_rational()
     }
 
     /// 制御点数を取得
@@ -200,15 +195,26 @@ impl<T: Scalar> NurbsCurve2D<T> {
     }
 
     /// 指定パラメータでの1次導関数を計算
+    ///
+    /// 基底関数の導関数から解析的に求める（差分の刻み幅に依存しないため `f32` でも精度が保たれる）。
+    /// 重み付き制御点の和 `A` と重み `w` から `C' = (A' - w' C) / w`。
     pub fn derivative_at(&self, t: T) -> Vector2<T> {
-        let h = T::from_f64(1e-8);
-        let p1 = self.evaluate_at(t - h);
-        let p2 = self.evaluate_at(t + h);
-
-        let dx = (p2.x() - p1.x()) / (h + h);
-        let dy = (p2.y() - p1.y()) / (h + h);
+        let span = crate::knot::find_knot_span(t, &self.knot_vector, self.degree);
+        let basis = crate::basis::basis_derivatives(span, self.degree, t, &self.knot_vector, 1);
 
-        Vector2::new(dx, dy)
+        let (mut weighted, mut weighted_derivative) = (Vector2::zero(), Vector2::zero());
+        let (mut weight_sum, mut weight_derivative) = (T::ZERO, T::ZERO);
+        for (i, (&value, &slope)) in basis[0].iter().zip(&basis[1]).enumerate() {
+            let control_index = span - self.degree + i;
+            let weight = self.weight(control_index);
+            let control_point = self.control_point(control_index);
+            weighted = weighted + control_point * (value * weight);
+            weighted_derivative = weighted_derivative + control_point * (slope * weight);
+            weight_sum += value * weight;
+            weight_derivative += slope * weight;
+        }
+        let point = weighted / weight_sum;
+        (weighted_derivative - point * weight_derivative) / weight_sum
     }
 
     /// 曲線の長さを近似計算
@@ -239,6 +245,29 @@ impl<T: Scalar> NurbsCurve2D<T> {
         total_length
     }
 
+    /// パラメータ区間 `[u0, u1]` の部分曲線を抽出
+    ///
+    /// 両端でノット挿入して重複度を次数まで上げ、区間外の制御点を除去する
+    /// （[`crate::NurbsCurve3D::trim`] の2D版）。
+    ///
+    /// # Errors
+    /// `u0 < u1` でない場合や、区間がパラメータ定義域からはみ出す場合
+    pub fn trim(&self, u0: T, u1: T) -> Result<Self> {
+        let control_points = (0..self.num_points)
+            .map(|i| self.control_point(i))
+            .collect();
+        let weights = (0..self.num_points).map(|i| self.weight(i)).collect();
+        let (control_points, weights, knots) = crate::transform::KnotInsertion::trim_range(
+            control_points,
+            weights,
+            self.knot_vector.clone(),
+            self.degree,
+            (u0, u1),
+            crate::transform::KnotInsertion::insert_knot_2d,
+        )?;
+        Self::new(&control_points, Some(weights), knots, self.degree)
+    }
+
     /// B-スプライン基底関数を計算
     fn compute_basis_functions(&self, t: T, span: usize) -> Vec<T> {
         let mut basis = vec![T::ZERO; self.degree + 1];
@@ -313,6 +342,35 @@ mod tests {
         assert!((end_point.y() - 0.0).abs() < 1e-10);
     }
 
+    #[test]
+    fn test_f32_derivative_of_rational_arc() {
+        // 重み 1/√2 の2次有理ベジエで表した単位四分円（f32 では差分近似が破綻する）
+        let control_points = vec![
+            Vector2::new(1.0_f32, 0.0),
+            Vector2::new(1.0, 1.0),
+            Vector2::new(0.0, 1.0),
+        ];
+        let weights = Some(vec![1.0, std::f32::consts::FRAC_1_SQRT_2, 1.0]);
+        let knot_vector = vec![0.0, 0.0, 0.0, 1.0, 1.0, 1.0];
+        let curve: crate::NurbsCurve2Df =
+            NurbsCurve2D::new(&control_points, weights, knot_vector, 2).unwrap();
+        assert!(curve.is_rational());
+
+        // 端点の接線は 2 (w₁/w₀)(P₁ - P₀)
+        let start = curve.derivative_at(0.0);
+        assert!(start.x().abs() < 1e-6);
+        assert!((start.y() - std::f32::consts::SQRT_2).abs() < 1e-6);
+
+        // 円周上では接線が位置ベクトルに直交する
+        for i in 0..=10 {
+            #[allow(clippy::cast_precision_loss)]
+            let t = i as f32 / 10.0;
+            let point = curve.evaluate_at(t);
+            let derivative = curve.derivative_at(t);
+            assert!((point.dot(&derivative) / derivative.norm()).abs() < 1e-5);
+        }
+    }
+
     #[test]
     fn test_approximate_length() {
         let control_points = vec![
@@ -3,17 +3,10 @@
 //! Non-Uniform Rational B-Spline 3D curves の基本実装です。
 //! フラット配列による高効率メモリ配置で制御点、重み、ノットベクトルを管理します。
 
-use crate::{KnotVector, NurbsError, Result, Scalar};
-use analysis::linalg::vector::Vector3;
-
-/// 重み配列の効率的管理（3D曲線用）
-#[derive(Debug, Clone)]
-pub enum WeightStorage<T: Scalar> {
-    /// 非有理曲線（全重み = 1.0）
-    Uniform,
-    /// 有理曲線（個別重み）
-    Individual(Vec<T>),
-}
+use crate::{KnotVector, NurbsError, Result, Scalar, WeightStorage};
+use analysis::linalg::{matrix::Matrix4x4, vector::Vector3};
+use geo_foundation::{GeometryPrimitive, PrimitiveKind};
+use geo_primitives::Point3D;
 
 /// NURBS曲線 - 3次元（メモリ最適化版）
 ///
@@ -26,7 +19,7 @@ pub enum WeightStorage<T: Scalar> {
 /// # メモリ構造
 /// - 座標: `[x0,y0,z0, x1,y1,z1, x2,y2,z2, ...]` (フラット配列)
 /// - インデックス: `i * 3 + coord_offset`
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct NurbsCurve3D<T: Scalar> {
     /// フラット座標配列 [x0,y0,z0, x1,y1,z1, ...]
     coordinates: Vec<T>,
@@ -99,9 +92,10 @@ impl<T: Scalar> NurbsCurve3D<T> {
                 }
             }
 
-            WeightStorage::Individual(weight_vec)
+            // 全て1.0なら非有理として多項式の評価経路を使う
+            WeightStorage::from_weights(weight_vec)
         } else {
-            WeightStorage::Uniform
+            WeightStorage::Uniform(T::ONE)
         };
 
         // ノットベクトルの検証
@@ -116,6 +110,34 @@ impl<T: Scalar> NurbsCurve3D<T> {
         })
     }
 
+    /// ベジエ曲線を等価なNURBS曲線へ変換
+    ///
+    /// 次数 `n = 制御点数 - 1` のベジエ曲線は、両端を `n + 1` 重にした
+    /// クランプドノットベクトル `[0, …, 0, 1, …, 1]` 上のB-スプラインと一致する
+    /// （このとき基底関数はベルンシュタイン基底になる）。重みはすべて1（非有理）。
+    ///
+    /// # Errors
+    /// 制御点が空の場合
+    pub fn from_bezier(control_points: &[Point3D<T>]) -> Result<Self> {
+        if control_points.is_empty() {
+            return Err(NurbsError::InsufficientControlPoints {
+                actual: 0,
+                required: 1,
+                degree: 0,
+            });
+        }
+
+        let degree = control_points.len() - 1;
+        let mut knot_vector = vec![T::ZERO; degree + 1];
+        knot_vector.extend(std::iter::repeat_n(T::ONE, degree + 1));
+
+        let points = control_points
+            .iter()
+            .map(|point| Vector3::new(point.x(), point.y(), point.z()))
+            .collect();
+        Self::new(points, None, knot_vector, degree)
+    }
+
     /// 制御点アクセス用インデックス計算
     #[inline]
     fn control_point_index(&self, index: usize) -> usize {
@@ -137,10 +159,13 @@ impl<T: Scalar> NurbsCurve3D<T> {
     /// 重み取得
     #[must_use]
     pub fn weight(&self, index: usize) -> T {
-        match &self.weights {
-            WeightStorage::Uniform => T::ONE,
-            WeightStorage::Individual(weights) => weights[index],
-        }
+        self.weights.get_weight(index)
+    }
+
+    /// 有理曲線（1.0 以外の重みを含む）かどうか
+    #[must_use]
+    pub fn is_rational(&self) -> bool {
+        self.weights.is_rational()
     }
 
     /// 制御点数を取得
@@ -184,6 +209,16 @@ impl<T: Scalar> NurbsCurve3D<T> {
         let span = crate::knot::find_knot_span(t, &self.knot_vector, self.degree);
         let basis = self.compute_basis_functions(t, span);
 
+        // 非有理なら基底関数の和が1なので、重みと除算を省いた多項式として評価する
+        if !self.is_rational() {
+            return basis
+                .iter()
+                .enumerate()
+                .fold(Vector3::zero(), |point, (i, &basis_value)| {
+                    point + self.control_point(span - self.degree + i) * basis_value
+                });
+        }
+
         let mut numerator_x = T::ZERO;
         let mut numerator_y = T::ZERO;
         let mut numerator_z = T::ZERO;
@@ -212,15 +247,87 @@ impl<T: Scalar> NurbsCurve3D<T> {
 
     /// 指定パラメータでの1次導関数を計算
     pub fn derivative_at(&self, t: T) -> Vector3<T> {
-        let h = T::from_f64(1e-8);
-        let p1 = self.evaluate_at(t - h);
-        let p2 = self.evaluate_at(t + h);
+        self.derivatives_at(t, 1)[1]
+    }
 
-        let dx = (p2.x() - p1.x()) / (h + h);
-        let dy = (p2.y() - p1.y()) / (h + h);
-        let dz = (p2.z() - p1.z()) / (h + h);
+    /// 指定パラメータでの2次導関数を計算
+    pub fn second_derivative_at(&self, t: T) -> Vector3<T> {
+        self.derivatives_at(t, 2)[2]
+    }
 
-        Vector3::new(dx, dy, dz)
+    /// 指定パラメータでの曲率 `|C'×C''| / |C'|³`
+    ///
+    /// 直線部（`C''` が `C'` と平行またはゼロ）や `C'` がゼロの特異点では0を返す。
+    pub fn curvature(&self, t: T) -> T {
+        let derivatives = self.derivatives_at(t, 2);
+        let (first, second) = (derivatives[1], derivatives[2]);
+        let speed = first.norm();
+        let cross = first.cross(&second).norm();
+
+        // C' と C'' のなす角の正弦が許容誤差以下なら直線とみなす
+        let tolerance = T::from_f64(crate::constants::DEFAULT_TOLERANCE);
+        if speed <= tolerance || cross <= tolerance * speed * second.norm() {
+            return T::ZERO;
+        }
+        cross / (speed * speed * speed)
+    }
+
+    /// 指定パラメータでの曲率半径（曲率の逆数）
+    ///
+    /// 曲率が0の直線部では `T::INFINITY` を返す。
+    pub fn radius_of_curvature(&self, t: T) -> T {
+        let curvature = self.curvature(t);
+        if curvature.is_zero() {
+            T::INFINITY
+        } else {
+            T::ONE / curvature
+        }
+    }
+
+    /// 0次から `order` 次までの導関数を計算（有理曲線の商の微分）
+    ///
+    /// 重み付き制御点の導関数 `A⁽ᵏ⁾` と重みの導関数 `w⁽ᵏ⁾` から
+    /// `C⁽ᵏ⁾ = (A⁽ᵏ⁾ - Σᵢ₌₁ᵏ C(k,i) w⁽ⁱ⁾ C⁽ᵏ⁻ⁱ⁾) / w` で求める。
+    fn derivatives_at(&self, t: T, order: usize) -> Vec<Vector3<T>> {
+        let span = crate::knot::find_knot_span(t, &self.knot_vector, self.degree);
+        let basis = crate::basis::basis_derivatives(span, self.degree, t, &self.knot_vector, order);
+
+        // 非有理なら C⁽ᵏ⁾ = Σ N⁽ᵏ⁾ P で、商の微分は不要
+        if !self.is_rational() {
+            return basis
+                .iter()
+                .map(|row| {
+                    row.iter()
+                        .enumerate()
+                        .fold(Vector3::zero(), |derivative, (i, &value)| {
+                            derivative + self.control_point(span - self.degree + i) * value
+                        })
+                })
+                .collect();
+        }
+
+        let mut weighted = vec![Vector3::zero(); order + 1];
+        let mut weight_derivatives = vec![T::ZERO; order + 1];
+        for (k, row) in basis.iter().enumerate() {
+            for (i, &value) in row.iter().enumerate() {
+                let control_index = span - self.degree + i;
+                let weight = self.weight(control_index);
+                weighted[k] = weighted[k] + self.control_point(control_index) * (value * weight);
+                weight_derivatives[k] += value * weight;
+            }
+        }
+
+        let mut derivatives: Vec<Vector3<T>> = Vec::with_capacity(order + 1);
+        for k in 0..=order {
+            let mut numerator = weighted[k];
+            let mut binomial = T::ONE;
+            for i in 1..=k {
+                binomial = binomial * T::from_usize(k + 1 - i) / T::from_usize(i);
+                numerator = numerator - derivatives[k - i] * (binomial * weight_derivatives[i]);
+            }
+            derivatives.push(numerator / weight_derivatives[0]);
+        }
+        derivatives
     }
 
     /// 指定パラメータでの接線ベクトルを計算（正規化済み）
@@ -256,6 +363,51 @@ impl<T: Scalar> NurbsCurve3D<T> {
         total_length
     }
 
+    /// パラメータ区間 `[u0, u1]` の部分曲線を抽出
+    ///
+    /// 両端でノット挿入して重複度を次数まで上げると、`C(u0)` と `C(u1)` がそれぞれ
+    /// 制御点に一致する。その間の制御点と、両端を `次数 + 1` 重にしたノットベクトルで
+    /// 部分曲線を構成する（パラメータは元曲線のものをそのまま引き継ぐ）。
+    ///
+    /// # Errors
+    /// `u0 < u1` でない場合や、区間がパラメータ定義域からはみ出す場合
+    pub fn trim(&self, u0: T, u1: T) -> Result<Self> {
+        let control_points = (0..self.num_points)
+            .map(|i| self.control_point(i))
+            .collect();
+        let weights = (0..self.num_points).map(|i| self.weight(i)).collect();
+        let (control_points, weights, knots) = crate::transform::KnotInsertion::trim_range(
+            control_points,
+            weights,
+            self.knot_vector.clone(),
+            self.degree,
+            (u0, u1),
+            crate::transform::KnotInsertion::insert_knot_3d,
+        )?;
+        Self::new(control_points, Some(weights), knots, self.degree)
+    }
+
+    /// 制御点をアフィン行列で変換した曲線
+    ///
+    /// NURBS はアフィン変換に対して不変なので、制御点だけを変換すれば
+    /// 曲線上の各点を変換したものと一致する（重みとノットはそのまま）。
+    #[must_use]
+    pub fn transformed(&self, matrix: &Matrix4x4<T>) -> Self {
+        let coordinates = (0..self.num_points)
+            .flat_map(|i| {
+                let p = matrix.transform_point_3d(&self.control_point(i));
+                [p.x(), p.y(), p.z()]
+            })
+            .collect();
+        Self {
+            coordinates,
+            weights: self.weights.clone(),
+            knot_vector: self.knot_vector.clone(),
+            degree: self.degree,
+            num_points: self.num_points,
+        }
+    }
+
     /// B-スプライン基底関数を計算
     fn compute_basis_functions(&self, t: T, span: usize) -> Vec<T> {
         let mut basis = vec![T::ZERO; self.degree + 1];
@@ -281,10 +433,59 @@ impl<T: Scalar> NurbsCurve3D<T> {
     }
 }
 
+impl<T: Scalar> GeometryPrimitive for NurbsCurve3D<T> {
+    fn primitive_kind(&self) -> PrimitiveKind {
+        PrimitiveKind::NurbsCurve
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_from_bezier_matches_bernstein_form() {
+        let bezier = [
+            Point3D::new(0.0, 0.0, 0.0),
+            Point3D::new(1.0, 3.0, 0.5),
+            Point3D::new(4.0, 3.0, -1.0),
+            Point3D::new(5.0, -1.0, 2.0),
+        ];
+        let curve = NurbsCurve3D::from_bezier(&bezier).unwrap();
+        assert_eq!(curve.degree(), 3);
+        assert_eq!(curve.num_points(), 4);
+        assert_eq!(curve.parameter_domain(), (0.0, 1.0));
+
+        for i in 0..=20 {
+            let t = f64::from(i) / 20.0;
+            let u = 1.0 - t;
+            let weights = [u * u * u, 3.0 * u * u * t, 3.0 * u * t * t, t * t * t];
+            let expected = bezier.iter().zip(weights).fold([0.0; 3], |acc, (p, w)| {
+                [acc[0] + p.x() * w, acc[1] + p.y() * w, acc[2] + p.z() * w]
+            });
+
+            let actual = curve.evaluate_at(t);
+            assert!((actual.x() - expected[0]).abs() < 1e-12);
+            assert!((actual.y() - expected[1]).abs() < 1e-12);
+            assert!((actual.z() - expected[2]).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_from_bezier_degenerate_inputs() {
+        assert!(NurbsCurve3D::<f64>::from_bezier(&[]).is_err());
+
+        // 2点のベジエは線分
+        let line =
+            NurbsCurve3D::from_bezier(&[Point3D::new(0.0, 0.0, 0.0), Point3D::new(2.0, 4.0, 6.0)])
+                .unwrap();
+        assert_eq!(line.degree(), 1);
+        let mid = line.evaluate_at(0.5);
+        assert!((mid.x() - 1.0).abs() < 1e-12);
+        assert!((mid.y() - 2.0).abs() < 1e-12);
+        assert!((mid.z() - 3.0).abs() < 1e-12);
+    }
+
     #[test]
     fn test_nurbs_curve_3d_creation() {
         let control_points = vec![
@@ -352,6 +553,214 @@ mod tests {
         assert!(tangent.z().abs() < 0.1);
     }
 
+    #[test]
+    fn test_curvature_of_rational_circle_arc() {
+        // 重み 1/√2 の2次有理ベジエで表した半径2の四分円
+        let control_points = vec![
+            Vector3::new(2.0, 0.0, 0.0),
+            Vector3::new(2.0, 2.0, 0.0),
+            Vector3::new(0.0, 2.0, 0.0),
+        ];
+        let weights = Some(vec![1.0, std::f64::consts::FRAC_1_SQRT_2, 1.0]);
+        let knot_vector = vec![0.0, 0.0, 0.0, 1.0, 1.0, 1.0];
+        let curve = NurbsCurve3D::new(control_points, weights, knot_vector, 2).unwrap();
+
+        for i in 0..=10 {
+            let t = f64::from(i) / 10.0;
+            assert!((curve.curvature(t) - 0.5).abs() < 1e-10);
+            assert!((curve.radius_of_curvature(t) - 2.0).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_derivatives_match_finite_differences() {
+        let control_points = vec![
+            Vector3::new(0.0, 0.0, 0.0),
+            Vector3::new(1.0, 2.0, 0.5),
+            Vector3::new(3.0, 3.0, 1.0),
+            Vector3::new(5.0, 1.0, 0.5),
+            Vector3::new(6.0, -1.0, 2.0),
+        ];
+        let weights = Some(vec![1.0, 0.5, 2.0, 1.0, 1.5]);
+        let knot_vector = vec![0.0, 0.0, 0.0, 0.0, 0.4, 1.0, 1.0, 1.0, 1.0];
+        let curve = NurbsCurve3D::new(control_points, weights, knot_vector, 3).unwrap();
+
+        let h = 1e-5;
+        for &t in &[0.1, 0.3, 0.7] {
+            let before = curve.evaluate_at(t - h);
+            let center = curve.evaluate_at(t);
+            let after = curve.evaluate_at(t + h);
+            let first = (after - before) / (2.0 * h);
+            let second = (after - center * 2.0 + before) / (h * h);
+            assert!((curve.derivative_at(t) - first).norm() < 1e-6);
+            assert!((curve.second_derivative_at(t) - second).norm() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn test_is_rational() {
+        let control_points = vec![
+            Vector3::new(0.0, 0.0, 0.0),
+            Vector3::new(1.0, 1.0, 0.0),
+            Vector3::new(2.0, 0.0, 1.0),
+        ];
+        let knot_vector = vec![0.0, 0.0, 0.0, 1.0, 1.0, 1.0];
+        let uniform =
+            NurbsCurve3D::new(control_points.clone(), None, knot_vector.clone(), 2).unwrap();
+        let all_one = NurbsCurve3D::new(
+            control_points.clone(),
+            Some(vec![1.0, 1.0, 1.0]),
+            knot_vector.clone(),
+            2,
+        )
+        .unwrap();
+        let rational =
+            NurbsCurve3D::new(control_points, Some(vec![1.0, 2.0, 1.0]), knot_vector, 2).unwrap();
+
+        assert!(!uniform.is_rational());
+        assert!(!all_one.is_rational());
+        assert_eq!(all_one.weights(), &WeightStorage::Uniform(1.0));
+        assert!(rational.is_rational());
+    }
+
+    #[test]
+    fn test_polynomial_path_matches_rational_path() {
+        let control_points = vec![
+            Vector3::new(0.0, 0.0, 0.0),
+            Vector3::new(1.0, 2.0, 0.5),
+            Vector3::new(3.0, 3.0, 1.0),
+            Vector3::new(5.0, 1.0, 0.5),
+        ];
+        let knot_vector = vec![0.0, 0.0, 0.0, 0.0, 1.0, 1.0, 1.0, 1.0];
+        let polynomial =
+            NurbsCurve3D::new(control_points.clone(), None, knot_vector.clone(), 3).unwrap();
+        // 重みを全て2倍しても曲線は変わらないが、有理曲線として評価される
+        let rational =
+            NurbsCurve3D::new(control_points, Some(vec![2.0; 4]), knot_vector, 3).unwrap();
+        assert!(rational.is_rational());
+
+        for i in 0..=10 {
+            let t = f64::from(i) / 10.0;
+            assert!((polynomial.evaluate_at(t) - rational.evaluate_at(t)).norm() < 1e-12);
+            assert!((polynomial.derivative_at(t) - rational.derivative_at(t)).norm() < 1e-12);
+            assert!(
+                (polynomial.second_derivative_at(t) - rational.second_derivative_at(t)).norm()
+                    < 1e-10
+            );
+        }
+    }
+
+    #[test]
+    fn test_f32_curve_matches_f64() {
+        let points = [[2.0, 0.0, 0.0], [2.0, 2.0, 0.0], [0.0, 2.0, 1.0]];
+        let weights = [1.0, std::f64::consts::FRAC_1_SQRT_2, 1.0];
+        let knots = [0.0, 0.0, 0.0, 1.0, 1.0, 1.0];
+
+        let curve_f64 = NurbsCurve3D::new(
+            points
+                .iter()
+                .map(|p| Vector3::new(p[0], p[1], p[2]))
+                .collect(),
+            Some(weights.to_vec()),
+            knots.to_vec(),
+            2,
+        )
+        .unwrap();
+        #[allow(clippy::cast_possible_truncation)]
+        let curve_f32: crate::NurbsCurve3Df = NurbsCurve3D::new(
+            points
+                .iter()
+                .map(|p| Vector3::new(p[0] as f32, p[1] as f32, p[2] as f32))
+                .collect(),
+            Some(weights.iter().map(|&w| w as f32).collect()),
+            knots.iter().map(|&k| k as f32).collect(),
+            2,
+        )
+        .unwrap();
+
+        for i in 0..=10 {
+            let t = f64::from(i) / 10.0;
+            #[allow(clippy::cast_possible_truncation)]
+            let t32 = t as f32;
+            let pairs = [
+                (curve_f32.evaluate_at(t32), curve_f64.evaluate_at(t)),
+                (curve_f32.derivative_at(t32), curve_f64.derivative_at(t)),
+            ];
+            for (single, double) in pairs {
+                let error = (f64::from(single.x()) - double.x()).abs()
+                    + (f64::from(single.y()) - double.y()).abs()
+                    + (f64::from(single.z()) - double.z()).abs();
+                assert!(error < 1e-5, "{error}");
+            }
+            assert!((f64::from(curve_f32.curvature(t32)) - curve_f64.curvature(t)).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_trim_matches_original_segment() {
+        let control_points = vec![
+            Vector3::new(0.0, 0.0, 0.0),
+            Vector3::new(1.0, 2.0, 0.5),
+            Vector3::new(3.0, 3.0, 1.0),
+            Vector3::new(5.0, 1.0, 0.5),
+            Vector3::new(6.0, -1.0, 2.0),
+        ];
+        let weights = Some(vec![1.0, 0.5, 2.0, 1.0, 1.5]);
+        let knot_vector = vec![0.0, 0.0, 0.0, 0.0, 0.4, 1.0, 1.0, 1.0, 1.0];
+        let curve = NurbsCurve3D::new(control_points, weights, knot_vector, 3).unwrap();
+
+        // 既存ノット 0.4 をまたぐ区間、既存ノットで始まる区間、定義域の端を含む区間
+        for (u0, u1) in [(0.2, 0.7), (0.4, 0.9), (0.0, 0.3), (0.55, 1.0), (0.0, 1.0)] {
+            let trimmed = curve.trim(u0, u1).unwrap();
+            assert_eq!(trimmed.degree(), 3);
+            assert_eq!(trimmed.parameter_domain(), (u0, u1));
+            assert!((trimmed.evaluate_at(u0) - curve.evaluate_at(u0)).norm() < 1e-12);
+            assert!((trimmed.evaluate_at(u1) - curve.evaluate_at(u1)).norm() < 1e-12);
+            for i in 0..=20 {
+                let t = u0 + (u1 - u0) * f64::from(i) / 20.0;
+                assert!((trimmed.evaluate_at(t) - curve.evaluate_at(t)).norm() < 1e-12);
+                assert!((trimmed.derivative_at(t) - curve.derivative_at(t)).norm() < 1e-9);
+            }
+        }
+
+        // 全区間のトリムは元の曲線そのもの
+        let whole = curve.trim(0.0, 1.0).unwrap();
+        assert_eq!(whole.num_points(), curve.num_points());
+        assert_eq!(whole.knot_vector(), curve.knot_vector());
+    }
+
+    #[test]
+    fn test_trim_rejects_invalid_interval() {
+        let curve = NurbsCurve3D::from_bezier(&[
+            Point3D::new(0.0, 0.0, 0.0),
+            Point3D::new(1.0, 1.0, 0.0),
+            Point3D::new(2.0, 0.0, 0.0),
+        ])
+        .unwrap();
+        assert!(curve.trim(0.6, 0.4).is_err());
+        assert!(curve.trim(0.5, 0.5).is_err());
+        assert!(curve.trim(-0.1, 0.5).is_err());
+        assert!(curve.trim(0.5, 1.1).is_err());
+    }
+
+    #[test]
+    fn test_straight_line_has_zero_curvature() {
+        let control_points = vec![
+            Vector3::new(0.0, 0.0, 0.0),
+            Vector3::new(1.0, 1.0, 1.0),
+            Vector3::new(3.0, 3.0, 3.0),
+            Vector3::new(4.0, 4.0, 4.0),
+        ];
+        let knot_vector = vec![0.0, 0.0, 0.0, 0.0, 1.0, 1.0, 1.0, 1.0];
+        let curve = NurbsCurve3D::new(control_points, None, knot_vector, 3).unwrap();
+
+        for i in 0..=10 {
+            let t = f64::from(i) / 10.0;
+            assert!(curve.curvature(t).abs() < f64::EPSILON);
+            assert!(curve.radius_of_curvature(t).is_infinite());
+        }
+    }
+
     #[test]
     fn test_approximate_length_3d() {
         let control_points = vec![
@@ -369,4 +778,27 @@ mod tests {
         // 直線に近い曲線なので長さは約2.0
         assert!((length - 2.0).abs() < 0.1);
     }
+
+    #[test]
+    fn test_transformed_matches_transformed_points() {
+        let control_points = vec![
+            Vector3::new(0.0, 0.0, 0.0),
+            Vector3::new(1.0, 2.0, 0.0),
+            Vector3::new(3.0, 2.0, 1.0),
+        ];
+        let knot_vector = vec![0.0, 0.0, 0.0, 1.0, 1.0, 1.0];
+        let curve =
+            NurbsCurve3D::new(control_points, Some(vec![1.0, 2.0, 1.0]), knot_vector, 2).unwrap();
+        let matrix = Matrix4x4::translation(1.0, -2.0, 3.0)
+            * Matrix4x4::rotation_axis(&Vector3::new(0.0, 0.0, 1.0), 0.7)
+            * Matrix4x4::scale(2.0, 1.0, 0.5);
+
+        let transformed = curve.transformed(&matrix);
+        for i in 0..=10 {
+            let t = f64::from(i) / 10.0;
+            let expected = matrix.transform_point_3d(&curve.evaluate_at(t));
+            let actual = transformed.evaluate_at(t);
+            assert!((actual - expected).norm() < 1e-12);
+        }
+    }
 }
@@ -0,0 +1,370 @@
+//! 点列からのNURBS曲線フィッティング
+//!
+//! 点列を通過する曲線を構築する global interpolation と、制御点数を抑えた
+//! 最小二乗近似を提供します。パラメータ化は弦長（chord-length）で行い、
+//! 基底関数の係数行列を `analysis::linalg::solver` で解いて制御点を求めます。
+
+use crate::basis::basis_functions;
+use crate::curve_3d::NurbsCurve3D;
+use crate::knot::{find_knot_span, KnotVector};
+use crate::Scalar;
+use analysis::linalg::solver::LUSolver;
+use analysis::linalg::vector::Vector3;
+use geo_primitives::Point3D;
+
+/// 連立方程式の特異判定に使う許容誤差
+const SOLVER_TOLERANCE: f64 = 1e-14;
+
+impl NurbsCurve3D<f64> {
+    /// 点列を通過するNURBS曲線を構築（global interpolation）
+    ///
+    /// 点列を弦長でパラメータ化し、平均化法で求めたクランプドノットベクトル上で
+    /// `N(u_k) P = Q_k` を解く。結果は非有理（重み1）で、点 `k` をパラメータ `u_k` で通過する。
+    ///
+    /// 点が `degree + 1` 未満の場合、`degree` が0の場合、
+    /// 連続する重複点などで係数行列が特異になる場合は `None` を返す。
+    #[must_use]
+    pub fn interpolate(points: &[Point3D<f64>], degree: usize) -> Option<NurbsCurve3D<f64>> {
+        if degree == 0 || points.len() < degree + 1 {
+            return None;
+        }
+
+        let parameters = chord_length_parameters(points)?;
+        let knots = averaged_knot_vector(&parameters, degree);
+        let matrix: Vec<Vec<f64>> = parameters
+            .iter()
+            .map(|&u| basis_row(u, degree, &knots, points.len()))
+            .collect();
+
+        let solver = LUSolver::new(SOLVER_TOLERANCE);
+        let decomposition = solver.decompose(&matrix).ok()?;
+        let mut axes = [Vec::new(), Vec::new(), Vec::new()];
+        for (axis, solution) in axes.iter_mut().enumerate() {
+            let rhs: Vec<f64> = points.iter().map(|p| coordinate(p, axis)).collect();
+            *solution = solver.solve_with_decomposition(&decomposition, &rhs).ok()?;
+        }
+
+        let control_points = (0..points.len())
+            .map(|i| Vector3::new(axes[0][i], axes[1][i], axes[2][i]))
+            .collect();
+        NurbsCurve3D::new(control_points, None, knots, degree).ok()
+    }
+
+    /// 点列を `num_ctrl` 個の制御点で最小二乗近似
+    ///
+    /// 両端の制御点を始点・終点に固定し、内部の制御点を正規方程式
+    /// `(NᵀN) P = R` で求める。ノットは点列パラメータから
+    /// 各スパンに点がほぼ均等に入るよう配置する。
+    ///
+    /// `num_ctrl` が点数と等しい場合は [`NurbsCurve3D::interpolate`] と同じ結果になる。
+    /// `degree` が0、`num_ctrl < degree + 1`、`num_ctrl` が点数を超える場合、
+    /// 連続する重複点などで方程式が特異になる場合は `None` を返す。
+    #[must_use]
+    pub fn approximate(
+        points: &[Point3D<f64>],
+        num_ctrl: usize,
+        degree: usize,
+    ) -> Option<NurbsCurve3D<f64>> {
+        Self::approximate_with_error(points, num_ctrl, degree).map(|(curve, _)| curve)
+    }
+
+    /// 最小二乗近似し、近似誤差（最大偏差）も返す
+    ///
+    /// 誤差は各点と、その点に割り当てたパラメータでの曲線上の点との距離の最大値。
+    #[must_use]
+    pub fn approximate_with_error(
+        points: &[Point3D<f64>],
+        num_ctrl: usize,
+        degree: usize,
+    ) -> Option<(NurbsCurve3D<f64>, f64)> {
+        if degree == 0 || num_ctrl < degree + 1 || num_ctrl > points.len() {
+            return None;
+        }
+
+        let parameters = chord_length_parameters(points)?;
+        let curve = if num_ctrl == points.len() {
+            Self::interpolate(points, degree)?
+        } else {
+            least_squares_curve(points, &parameters, num_ctrl, degree)?
+        };
+
+        let max_error = points
+            .iter()
+            .zip(&parameters)
+            .map(|(point, &u)| {
+                let value = curve.evaluate_at(u);
+                let target = Vector3::new(point.x(), point.y(), point.z());
+                (value - target).norm()
+            })
+            .fold(0.0, f64::max);
+        Some((curve, max_error))
+    }
+}
+
+/// 端点固定の最小二乗近似（NURBS Book A9.7 相当）
+fn least_squares_curve(
+    points: &[Point3D<f64>],
+    parameters: &[f64],
+    num_ctrl: usize,
+    degree: usize,
+) -> Option<NurbsCurve3D<f64>> {
+    let m = points.len() - 1;
+    let n = num_ctrl - 1;
+    let knots = approximation_knot_vector(parameters, n, degree);
+    let first = points[0];
+    let last = points[m];
+
+    // 内部点の基底関数行（内部制御点 1..n-1 の列のみ）と端点寄与を除いた残差
+    let rows: Vec<Vec<f64>> = parameters[1..m]
+        .iter()
+        .map(|&u| basis_row(u, degree, &knots, num_ctrl))
+        .collect();
+    let residuals: Vec<[f64; 3]> = rows
+        .iter()
+        .zip(&points[1..m])
+        .map(|(row, point)| {
+            let (n0, nn) = (row[0], row[n]);
+            std::array::from_fn(|axis| {
+                coordinate(point, axis)
+                    - n0 * coordinate(&first, axis)
+                    - nn * coordinate(&last, axis)
+            })
+        })
+        .collect();
+
+    let unknowns = n - 1;
+    let mut control_points = Vec::with_capacity(num_ctrl);
+    control_points.push(Vector3::new(first.x(), first.y(), first.z()));
+    if unknowns > 0 {
+        let normal: Vec<Vec<f64>> = (1..n)
+            .map(|i| {
+                (1..n)
+                    .map(|j| rows.iter().map(|row| row[i] * row[j]).sum())
+                    .collect()
+            })
+            .collect();
+
+        let solver = LUSolver::new(SOLVER_TOLERANCE);
+        let decomposition = solver.decompose(&normal).ok()?;
+        let mut axes = [Vec::new(), Vec::new(), Vec::new()];
+        for (axis, solution) in axes.iter_mut().enumerate() {
+            let rhs: Vec<f64> = (1..n)
+                .map(|i| {
+                    rows.iter()
+                        .zip(&residuals)
+                        .map(|(row, residual)| row[i] * residual[axis])
+                        .sum()
+                })
+                .collect();
+            *solution = solver.solve_with_decomposition(&decomposition, &rhs).ok()?;
+        }
+        control_points
+            .extend((0..unknowns).map(|i| Vector3::new(axes[0][i], axes[1][i], axes[2][i])));
+    }
+    control_points.push(Vector3::new(last.x(), last.y(), last.z()));
+
+    NurbsCurve3D::new(control_points, None, knots, degree).ok()
+}
+
+fn coordinate(point: &Point3D<f64>, axis: usize) -> f64 {
+    match axis {
+        0 => point.x(),
+        1 => point.y(),
+        _ => point.z(),
+    }
+}
+
+/// 弦長パラメータ化（0 から 1 へ単調増加、重複点があれば `None`）
+fn chord_length_parameters(points: &[Point3D<f64>]) -> Option<Vec<f64>> {
+    let lengths: Vec<f64> = points.windows(2).map(|w| w[0].distance_to(&w[1])).collect();
+    let total: f64 = lengths.iter().sum();
+    if lengths.iter().any(|&d| d <= f64::EPSILON * total) {
+        return None;
+    }
+
+    let mut parameters = Vec::with_capacity(points.len());
+    let mut accumulated = 0.0;
+    parameters.push(0.0);
+    for length in &lengths[..lengths.len() - 1] {
+        accumulated += length;
+        parameters.push(accumulated / total);
+    }
+    parameters.push(1.0);
+    Some(parameters)
+}
+
+/// 平均化法によるクランプドノットベクトル
+///
+/// 内部ノット `u_{j+p} = (1/p) Σ_{i=j}^{j+p-1} ū_i`（j = 1..n-p）
+fn averaged_knot_vector(parameters: &[f64], degree: usize) -> KnotVector<f64> {
+    let n = parameters.len() - 1;
+    let mut knots = vec![0.0; degree + 1];
+    for j in 1..=n - degree {
+        let sum: f64 = parameters[j..j + degree].iter().sum();
+        knots.push(sum / f64::from_usize(degree));
+    }
+    knots.extend(std::iter::repeat_n(1.0, degree + 1));
+    knots
+}
+
+/// 最小二乗近似用のクランプドノットベクトル
+///
+/// `d = (m + 1) / (n - p + 1)` とし、内部ノットを
+/// `u_{p+j} = (1 - α) ū_{i-1} + α ū_i`（`i = ⌊j d⌋`, `α = j d - i`）で配置する。
+fn approximation_knot_vector(parameters: &[f64], n: usize, degree: usize) -> KnotVector<f64> {
+    let m = parameters.len() - 1;
+    let d = f64::from_usize(m + 1) / f64::from_usize(n - degree + 1);
+    let mut knots = vec![0.0; degree + 1];
+    for j in 1..=n - degree {
+        let position = f64::from_usize(j) * d;
+        let i = position.floor();
+        let alpha = position - i;
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let i = i as usize;
+        knots.push((1.0 - alpha) * parameters[i - 1] + alpha * parameters[i]);
+    }
+    knots.extend(std::iter::repeat_n(1.0, degree + 1));
+    knots
+}
+
+/// パラメータ `u` での全基底関数値（係数行列の1行）
+fn basis_row(u: f64, degree: usize, knots: &KnotVector<f64>, num_points: usize) -> Vec<f64> {
+    let span = find_knot_span(u, knots, degree);
+    let mut row = vec![0.0; num_points];
+    for (i, value) in basis_functions(span, degree, u, knots)
+        .into_iter()
+        .enumerate()
+    {
+        row[span - degree + i] = value;
+    }
+    row
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sketch_points() -> Vec<Point3D<f64>> {
+        vec![
+            Point3D::new(0.0, 0.0, 0.0),
+            Point3D::new(1.0, 2.0, 0.5),
+            Point3D::new(3.0, 3.0, 1.0),
+            Point3D::new(5.0, 1.0, 0.5),
+            Point3D::new(6.0, -1.0, 2.0),
+            Point3D::new(8.0, 0.0, 3.0),
+        ]
+    }
+
+    #[test]
+    fn test_interpolate_passes_through_points() {
+        let points = sketch_points();
+        for degree in 1..=3 {
+            let curve = NurbsCurve3D::interpolate(&points, degree).unwrap();
+            assert_eq!(curve.degree(), degree);
+            assert_eq!(curve.num_points(), points.len());
+
+            let parameters = chord_length_parameters(&points).unwrap();
+            for (point, &u) in points.iter().zip(&parameters) {
+                let value = curve.evaluate_at(u);
+                assert!((value.x() - point.x()).abs() < 1e-9);
+                assert!((value.y() - point.y()).abs() < 1e-9);
+                assert!((value.z() - point.z()).abs() < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn test_interpolate_collinear_points_stays_on_line() {
+        let points: Vec<Point3D<f64>> = [0.0, 1.0, 1.5, 4.0, 5.0]
+            .iter()
+            .map(|&s| Point3D::new(s, 2.0 * s, -s))
+            .collect();
+        let curve = NurbsCurve3D::interpolate(&points, 3).unwrap();
+        for i in 0..=20 {
+            let value = curve.evaluate_at(f64::from(i) / 20.0);
+            assert!((value.y() - 2.0 * value.x()).abs() < 1e-9);
+            assert!((value.z() + value.x()).abs() < 1e-9);
+        }
+    }
+
+    fn scan_points(count: usize) -> Vec<Point3D<f64>> {
+        (0..count)
+            .map(|i| {
+                let s = f64::from_usize(i) / f64::from_usize(count - 1);
+                let angle = s * std::f64::consts::PI;
+                Point3D::new(angle.cos() * 4.0, angle.sin() * 4.0, s * 2.0)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_approximate_fixes_endpoints_and_fits_closely() {
+        let points = scan_points(50);
+        let (curve, error) = NurbsCurve3D::approximate_with_error(&points, 8, 3).unwrap();
+        assert_eq!(curve.num_points(), 8);
+        assert_eq!(curve.degree(), 3);
+
+        let start = curve.evaluate_at(0.0);
+        let end = curve.evaluate_at(1.0);
+        assert!((start.x() - points[0].x()).abs() < 1e-12);
+        assert!((start.y() - points[0].y()).abs() < 1e-12);
+        assert!((end.x() - points[49].x()).abs() < 1e-12);
+        assert!((end.z() - points[49].z()).abs() < 1e-12);
+
+        // 滑らかな螺旋なので8制御点で十分に近似できる
+        assert!(error < 1e-2, "error = {error}");
+
+        // 制御点を増やすと誤差は小さくなる
+        let (_, finer) = NurbsCurve3D::approximate_with_error(&points, 16, 3).unwrap();
+        assert!(finer < error);
+    }
+
+    #[test]
+    fn test_approximate_polynomial_and_line() {
+        // 3次多項式曲線は3次・4制御点で表現できる形状なので、弦長パラメータ化と
+        // 元のパラメータのずれによる小さな誤差だけが残る
+        let points: Vec<Point3D<f64>> = (0..30)
+            .map(|i| {
+                let t = f64::from(i) / 29.0;
+                Point3D::new(t, t * t, t * t * t)
+            })
+            .collect();
+        let (_, error) = NurbsCurve3D::approximate_with_error(&points, 4, 3).unwrap();
+        assert!(error < 0.05, "error = {error}");
+
+        // 直線は1次・2制御点で誤差0
+        let line: Vec<Point3D<f64>> = (0..10)
+            .map(|i| Point3D::new(f64::from(i), 2.0 * f64::from(i), 0.0))
+            .collect();
+        let (curve, error) = NurbsCurve3D::approximate_with_error(&line, 2, 1).unwrap();
+        assert_eq!(curve.num_points(), 2);
+        assert!(error < 1e-12);
+    }
+
+    #[test]
+    fn test_approximate_rejects_invalid_input() {
+        let points = scan_points(10);
+        assert!(NurbsCurve3D::approximate(&points, 3, 3).is_none());
+        assert!(NurbsCurve3D::approximate(&points, 11, 3).is_none());
+        assert!(NurbsCurve3D::approximate(&points, 5, 0).is_none());
+
+        // 制御点数 = 点数なら補間と一致
+        let (_, error) = NurbsCurve3D::approximate_with_error(&points, 10, 3).unwrap();
+        assert!(error < 1e-9);
+    }
+
+    #[test]
+    fn test_interpolate_rejects_invalid_input() {
+        let points = sketch_points();
+        assert!(NurbsCurve3D::interpolate(&points[..3], 3).is_none());
+        assert!(NurbsCurve3D::interpolate(&points, 0).is_none());
+        assert!(NurbsCurve3D::interpolate(&[], 3).is_none());
+
+        // 連続する重複点
+        let duplicated = vec![points[0], points[1], points[1], points[2]];
+        assert!(NurbsCurve3D::interpolate(&duplicated, 3).is_none());
+
+        // ちょうど degree + 1 点ならベジエと同じ形で補間できる
+        assert!(NurbsCurve3D::interpolate(&points[..4], 3).is_some());
+    }
+}
@@ -0,0 +1,212 @@
+//! 異種 3D プリミティブを1つの型で保持する `GeometryUnion`
+//!
+//! シーングラフのノードなど、種類の異なる形状をまとめて扱う場面向け。
+//! 境界ボックス取得と行列変換を各プリミティブの実装に委譲して提供する。
+//! `geo_primitives` のプリミティブと NURBS 曲線の両方を保持するため、両方が見える
+//! 本クレートに置く。
+
+use crate::{NurbsCurve3D, Scalar};
+use analysis::linalg::matrix::Matrix4x4;
+use geo_foundation::{
+    AnalysisTransform3D, Bounded3D, GeometryPrimitive, PrimitiveKind, TransformError,
+};
+use geo_primitives::{
+    arc_3d_transform, circle_3d_transform, plane_3d_transform, Arc3D, BBox3D, Circle3D, Ellipse3D,
+    LineSegment3D, Plane3D, Point3D, TriangleMesh3D,
+};
+
+/// 異種 3D プリミティブの列挙
+#[derive(Debug, Clone, PartialEq)]
+pub enum GeometryUnion<T: Scalar> {
+    /// 点
+    Point(Point3D<T>),
+    /// 線分
+    LineSegment(LineSegment3D<T>),
+    /// 円
+    Circle(Circle3D<T>),
+    /// 円弧
+    Arc(Arc3D<T>),
+    /// 楕円
+    Ellipse(Ellipse3D<T>),
+    /// 無限平面
+    Plane(Plane3D<T>),
+    /// 三角形メッシュ
+    TriangleMesh(TriangleMesh3D<T>),
+    /// NURBS 曲線
+    NurbsCurve(NurbsCurve3D<T>),
+}
+
+impl<T: Scalar> GeometryUnion<T> {
+    /// 境界ボックスを取得
+    ///
+    /// 各プリミティブの [`Bounded3D`] によるタイトなボックス。
+    /// 無限平面と空のメッシュは境界を持たないため `None`。
+    pub fn bounding_box(&self) -> Option<BBox3D<T>> {
+        match self {
+            Self::Point(p) => Some(p.bbox()),
+            Self::LineSegment(segment) => Some(segment.bbox()),
+            Self::Circle(circle) => Some(circle.bbox()),
+            Self::Arc(arc) => Some(arc.bbox()),
+            Self::Ellipse(ellipse) => Some(ellipse.bbox()),
+            Self::Plane(_) => None,
+            Self::TriangleMesh(mesh) if mesh.vertices().is_empty() => None,
+            Self::TriangleMesh(mesh) => Some(mesh.bbox()),
+            Self::NurbsCurve(curve) => Some(curve.bbox()),
+        }
+    }
+
+    /// 4x4 行列による変換を適用した新しい形状を返す
+    ///
+    /// 各プリミティブの行列変換に委譲し、そのエラーをそのまま返す。
+    /// NURBS 曲線は制御点をアフィン変換する（[`NurbsCurve3D::transformed`]）。
+    ///
+    /// # Errors
+    /// 円・円弧は非一様スケールで `NonUniformScale`、円・円弧・平面は特異な行列で
+    /// `SingularTransform` になる。
+    pub fn transform(&self, matrix: &Matrix4x4<T>) -> Result<Self, TransformError> {
+        Ok(match self {
+            Self::Point(p) => Self::Point(Point3D::from_analysis_vector3(
+                matrix.transform_point_3d(&p.to_analysis_vector3()),
+            )),
+            Self::LineSegment(segment) => Self::LineSegment(segment.transform_point_matrix(matrix)),
+            Self::Circle(circle) => Self::Circle(
+                circle_3d_transform::analysis_transform::transform_circle_3d(circle, matrix)?,
+            ),
+            Self::Arc(arc) => Self::Arc(arc_3d_transform::analysis_transform::transform_arc_3d(
+                arc, matrix,
+            )?),
+            Self::Ellipse(ellipse) => Self::Ellipse(ellipse.transform_point_matrix(matrix)),
+            Self::Plane(plane) => Self::Plane(
+                plane_3d_transform::analysis_transform::transform_plane_3d(plane, matrix)?,
+            ),
+            Self::TriangleMesh(mesh) => Self::TriangleMesh(mesh.transform_point_matrix(matrix)),
+            Self::NurbsCurve(curve) => Self::NurbsCurve(curve.transformed(matrix)),
+        })
+    }
+}
+
+impl<T: Scalar> GeometryPrimitive for GeometryUnion<T> {
+    fn primitive_kind(&self) -> PrimitiveKind {
+        match self {
+            Self::Point(p) => GeometryPrimitive::primitive_kind(p),
+            Self::LineSegment(segment) => GeometryPrimitive::primitive_kind(segment),
+            Self::Circle(circle) => GeometryPrimitive::primitive_kind(circle),
+            Self::Arc(arc) => GeometryPrimitive::primitive_kind(arc),
+            Self::Ellipse(ellipse) => GeometryPrimitive::primitive_kind(ellipse),
+            Self::Plane(plane) => GeometryPrimitive::primitive_kind(plane),
+            Self::TriangleMesh(mesh) => GeometryPrimitive::primitive_kind(mesh),
+            Self::NurbsCurve(curve) => GeometryPrimitive::primitive_kind(curve),
+        }
+    }
+}
+
+macro_rules! impl_from_primitive {
+    ($($variant:ident($ty:ident)),* $(,)?) => {
+        $(
+            impl<T: Scalar> From<$ty<T>> for GeometryUnion<T> {
+                fn from(value: $ty<T>) -> Self {
+                    Self::$variant(value)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_primitive!(
+    Point(Point3D),
+    LineSegment(LineSegment3D),
+    Circle(Circle3D),
+    Arc(Arc3D),
+    Ellipse(Ellipse3D),
+    Plane(Plane3D),
+    TriangleMesh(TriangleMesh3D),
+    NurbsCurve(NurbsCurve3D),
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use analysis::linalg::vector::Vector3;
+    use geo_primitives::{Direction3D, Vector3D};
+
+    #[test]
+    fn test_bounding_box_and_transform() {
+        let ellipse = Ellipse3D::new(
+            Point3D::new(1.0, 0.0, 0.0),
+            2.0,
+            1.0,
+            Vector3D::new(0.0, 0.0, 1.0),
+            Vector3D::new(1.0, 0.0, 0.0),
+        )
+        .unwrap();
+        let mesh = TriangleMesh3D::new(
+            vec![
+                Point3D::new(0.0, 0.0, 0.0),
+                Point3D::new(1.0, 0.0, 0.0),
+                Point3D::new(0.0, 1.0, 1.0),
+            ],
+            vec![[0, 1, 2]],
+        )
+        .unwrap();
+        let shapes: Vec<GeometryUnion<f64>> =
+            vec![ellipse.into(), mesh.into(), Plane3D::xy_plane(0.0).into()];
+        assert_eq!(shapes[0].primitive_kind(), PrimitiveKind::Ellipse);
+
+        let bbox = shapes[0].bounding_box().unwrap();
+        assert_eq!(bbox.min(), Point3D::new(-1.0, -1.0, 0.0));
+        assert_eq!(bbox.max(), Point3D::new(3.0, 1.0, 0.0));
+        assert!(shapes[2].bounding_box().is_none());
+
+        let moved = shapes[1]
+            .transform(&Matrix4x4::translation(1.0, 2.0, 3.0))
+            .unwrap();
+        let bbox = moved.bounding_box().unwrap();
+        assert_eq!(bbox.min(), Point3D::new(1.0, 2.0, 3.0));
+        assert_eq!(bbox.max(), Point3D::new(2.0, 3.0, 4.0));
+    }
+
+    #[test]
+    fn test_transform_errors_propagate() {
+        let circle: GeometryUnion<f64> =
+            Circle3D::new(Point3D::origin(), Direction3D::positive_z(), 1.0)
+                .unwrap()
+                .into();
+        assert!(matches!(
+            circle.transform(&Matrix4x4::scale(2.0, 1.0, 1.0)),
+            Err(TransformError::NonUniformScale(_))
+        ));
+
+        let plane: GeometryUnion<f64> = Plane3D::xy_plane(0.0).into();
+        assert!(matches!(
+            plane.transform(&Matrix4x4::scale(1.0, 1.0, 0.0)),
+            Err(TransformError::SingularTransform(_))
+        ));
+    }
+
+    #[test]
+    fn test_nurbs_curve_variant() {
+        let curve = NurbsCurve3D::new(
+            vec![
+                Vector3::new(0.0, 0.0, 0.0),
+                Vector3::new(1.0, 2.0, 0.0),
+                Vector3::new(2.0, 0.0, 0.0),
+            ],
+            None,
+            vec![0.0, 0.0, 0.0, 1.0, 1.0, 1.0],
+            2,
+        )
+        .unwrap();
+        let shape: GeometryUnion<f64> = curve.into();
+        assert_eq!(shape.primitive_kind(), PrimitiveKind::NurbsCurve);
+
+        // 2次ベジエの頂点は y = 1
+        let bbox = shape.bounding_box().unwrap();
+        assert!((bbox.max().y() - 1.0).abs() < 1e-10);
+
+        // 非一様スケールもそのまま制御点に適用される
+        let scaled = shape.transform(&Matrix4x4::scale(1.0, 3.0, 1.0)).unwrap();
+        let bbox = scaled.bounding_box().unwrap();
+        assert!((bbox.max().y() - 3.0).abs() < 1e-10);
+        assert!((bbox.max().x() - 2.0).abs() < 1e-10);
+    }
+}
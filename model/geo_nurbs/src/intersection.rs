@@ -0,0 +1,605 @@
+//! 平面NURBS曲線同士の交点
+//!
+//! 両曲線を再帰的に2分割し、制御点のバウンディングボックス（重みが正なら曲線は
+//! 制御点の凸包に含まれる）が交わる組だけを探索する。両方の区間が許容誤差以内で
+//! 平坦になったら、弦同士の最近点を初期値にニュートン法で `A(s) = B(t)` を解く。
+//! 一方の区間全体が他方の曲線から許容誤差以内にあれば重なりとみなし、それ以上細分しない
+//! （重なり区間を許容誤差まで細分すると区間の組が指数的に増える）。
+//!
+//! 接する交点の近傍や重なる区間では許容誤差以内の候補が続けて見つかるため、
+//! 間で曲線同士が許容誤差以内に沿っている候補をひとまとめにし、まとまりごとに
+//! - 曲線の端点を離れた位置に2つ以上含む（重なり）: 重なり区間の両端
+//! - それ以外（横切る・接する）: 距離が最小の1点
+//!
+//! を交点とする。
+
+use crate::{NurbsCurve2D, Scalar};
+use analysis::linalg::vector::Vector2;
+use geo_primitives::Point2D;
+
+/// 再帰的細分の深さの上限
+const MAX_SUBDIVISION_DEPTH: usize = 40;
+
+/// 交点を精緻化するニュートン法の反復回数の上限
+const NEWTON_ITERATIONS: usize = 32;
+
+/// 点を曲線へ射影するニュートン法の反復回数の上限
+const PROJECTION_ITERATIONS: usize = 16;
+
+/// 射影の初期値を探すサンプル数（制御点1つあたり）
+const PROJECTION_SAMPLES_PER_POINT: usize = 8;
+
+/// 隣り合う候補の間で曲線同士の距離を確かめるサンプル数
+const LINK_SAMPLES: usize = 4;
+
+/// 区間が他方の区間に沿っているかを確かめるサンプル数（両端を含む）
+const OVERLAP_SAMPLES: usize = 5;
+
+/// 交点候補
+#[derive(Debug, Clone, Copy)]
+struct Candidate {
+    /// 曲線Aのパラメータ
+    s: f64,
+    /// 曲線Bのパラメータ
+    t: f64,
+    /// `|A(s) - B(t)|`
+    distance: f64,
+    /// どちらかの曲線の端点か
+    endpoint: bool,
+}
+
+/// 2本の平面NURBS曲線の交点
+///
+/// `(曲線Aのパラメータ, 曲線Bのパラメータ, 交点)` を曲線Aのパラメータ順に返す。
+/// 交点は曲線A上の点で、曲線Bとの距離は `tolerance` 以内。
+/// 接する交点は1点にまとめ、重なる区間はその両端を返す。
+#[must_use]
+pub fn intersect_curves(
+    a: &NurbsCurve2D<f64>,
+    b: &NurbsCurve2D<f64>,
+    tolerance: f64,
+) -> Vec<(f64, f64, Point2D<f64>)> {
+    let mut candidates = endpoint_contacts(a, b, tolerance);
+    subdivide(a, b, a, b, tolerance, 0, &mut candidates);
+    candidates.sort_by(|x, y| x.s.total_cmp(&y.s));
+
+    let mut representatives = Vec::new();
+    let mut group_start = 0;
+    for i in 1..=candidates.len() {
+        if i < candidates.len() && linked(a, b, &candidates[i - 1], &candidates[i], tolerance) {
+            continue;
+        }
+        representatives.extend(group_representatives(
+            a,
+            &candidates[group_start..i],
+            tolerance,
+        ));
+        group_start = i;
+    }
+
+    // 閉曲線の始点と終点のように、別のまとまりが同じ点を指すことがある
+    let mut intersections: Vec<(f64, f64, Point2D<f64>)> = Vec::new();
+    for candidate in representatives {
+        let point = a.evaluate_at(candidate.s);
+        let point = Point2D::new(point.x(), point.y());
+        if intersections
+            .iter()
+            .all(|(_, _, existing)| existing.distance_to(&point) > tolerance)
+        {
+            intersections.push((candidate.s, candidate.t, point));
+        }
+    }
+    intersections
+}
+
+/// 一方の曲線の端点が他方の曲線上にある接触
+///
+/// 重なり区間は通常どちらかの曲線の端点で終わるため、その両端を正確に得るのに使う。
+fn endpoint_contacts(
+    a: &NurbsCurve2D<f64>,
+    b: &NurbsCurve2D<f64>,
+    tolerance: f64,
+) -> Vec<Candidate> {
+    let (a_start, a_end) = a.parameter_domain();
+    let (b_start, b_end) = b.parameter_domain();
+    let mut contacts = Vec::new();
+    for s in [a_start, a_end] {
+        let t = closest_parameter(b, a.evaluate_at(s));
+        contacts.push(candidate(a, b, s, t, true));
+    }
+    for t in [b_start, b_end] {
+        let s = closest_parameter(a, b.evaluate_at(t));
+        contacts.push(candidate(a, b, s, t, true));
+    }
+    contacts.retain(|contact| contact.distance <= tolerance);
+    contacts
+}
+
+/// 区間 `segment_a`, `segment_b` の組を細分しながら交点候補を集める
+fn subdivide(
+    a: &NurbsCurve2D<f64>,
+    b: &NurbsCurve2D<f64>,
+    segment_a: &NurbsCurve2D<f64>,
+    segment_b: &NurbsCurve2D<f64>,
+    tolerance: f64,
+    depth: usize,
+    candidates: &mut Vec<Candidate>,
+) {
+    let (min_a, max_a) = control_box(segment_a);
+    let (min_b, max_b) = control_box(segment_b);
+    if min_a.x() > max_b.x() + tolerance
+        || min_b.x() > max_a.x() + tolerance
+        || min_a.y() > max_b.y() + tolerance
+        || min_b.y() > max_a.y() + tolerance
+        || separated_by_fat_line(segment_a, segment_b, tolerance)
+        || separated_by_fat_line(segment_b, segment_a, tolerance)
+    {
+        return;
+    }
+
+    // 重なり区間：区間の両端を候補とし、間は候補のまとめ処理でつなぐ
+    if let Some(ends) = overlap_ends(a, b, segment_a, segment_b, tolerance) {
+        candidates.extend(ends);
+        return;
+    }
+
+    let flat_a = flatness(segment_a) <= tolerance;
+    let flat_b = flatness(segment_b) <= tolerance;
+    if (flat_a && flat_b) || depth >= MAX_SUBDIVISION_DEPTH {
+        if let Some(candidate) = refine(a, b, segment_a, segment_b, tolerance) {
+            candidates.push(candidate);
+        }
+        return;
+    }
+
+    let halves_a = if flat_a {
+        vec![segment_a.clone()]
+    } else {
+        halve(segment_a)
+    };
+    let halves_b = if flat_b {
+        vec![segment_b.clone()]
+    } else {
+        halve(segment_b)
+    };
+    for half_a in &halves_a {
+        for half_b in &halves_b {
+            subdivide(a, b, half_a, half_b, tolerance, depth + 1, candidates);
+        }
+    }
+}
+
+/// 一方の区間が他方の曲線から `tolerance` 以内に収まっていれば、その区間の両端の候補
+///
+/// 相手を区間ではなく曲線全体とするので、重なり区間の中で端点だけを共有する区間の組も
+/// 細分を打ち切れる。許容誤差より短い区間は横切る交点の近傍でも収まるため、その場合は
+/// 細分を続ける。
+fn overlap_ends(
+    a: &NurbsCurve2D<f64>,
+    b: &NurbsCurve2D<f64>,
+    segment_a: &NurbsCurve2D<f64>,
+    segment_b: &NurbsCurve2D<f64>,
+    tolerance: f64,
+) -> Option<[Candidate; 2]> {
+    let length = |segment: &NurbsCurve2D<f64>| {
+        (segment.control_point(0) - segment.control_point(segment.num_points() - 1)).norm()
+    };
+    if length(segment_a) <= tolerance || length(segment_b) <= tolerance {
+        return None;
+    }
+
+    if lies_along(segment_a, b, tolerance) {
+        let (s0, s1) = segment_a.parameter_domain();
+        let t = |s: f64| closest_parameter(b, a.evaluate_at(s));
+        return Some([s0, s1].map(|s| candidate(a, b, s, t(s), false)));
+    }
+    if lies_along(segment_b, a, tolerance) {
+        let (t0, t1) = segment_b.parameter_domain();
+        let s = |t: f64| closest_parameter(a, b.evaluate_at(t));
+        return Some([t0, t1].map(|t| candidate(a, b, s(t), t, false)));
+    }
+    None
+}
+
+/// `inner` 上のサンプル点がすべて `outer` から `tolerance` 以内にあるか
+fn lies_along(inner: &NurbsCurve2D<f64>, outer: &NurbsCurve2D<f64>, tolerance: f64) -> bool {
+    let (start, end) = inner.parameter_domain();
+    (0..OVERLAP_SAMPLES).all(|i| {
+        let ratio = f64::from_usize(i) / f64::from_usize(OVERLAP_SAMPLES - 1);
+        let point = inner.evaluate_at(start + (end - start) * ratio);
+        let t = closest_parameter(outer, point);
+        (point - outer.evaluate_at(t)).norm() <= tolerance
+    })
+}
+
+/// 平坦な区間の組について、弦同士の最近点を初期値にニュートン法で交点を求める
+///
+/// 接線が平行（接する・重なる）でニュートン法が進めない場合は、それまでで最も
+/// 近い点を使う。距離が `tolerance` を超えれば候補にしない。
+fn refine(
+    a: &NurbsCurve2D<f64>,
+    b: &NurbsCurve2D<f64>,
+    segment_a: &NurbsCurve2D<f64>,
+    segment_b: &NurbsCurve2D<f64>,
+    tolerance: f64,
+) -> Option<Candidate> {
+    let (alpha, beta) = closest_chord_parameters(
+        segment_a.control_point(0),
+        segment_a.control_point(segment_a.num_points() - 1),
+        segment_b.control_point(0),
+        segment_b.control_point(segment_b.num_points() - 1),
+    );
+    let (s0, s1) = segment_a.parameter_domain();
+    let (t0, t1) = segment_b.parameter_domain();
+    let (a_start, a_end) = a.parameter_domain();
+    let (b_start, b_end) = b.parameter_domain();
+
+    let mut s = s0 + (s1 - s0) * alpha;
+    let mut t = t0 + (t1 - t0) * beta;
+    let mut best = candidate(a, b, s, t, false);
+    for _ in 0..NEWTON_ITERATIONS {
+        // A(s) - B(t) + A'(s) Δs - B'(t) Δt = 0
+        let residual = a.evaluate_at(s) - b.evaluate_at(t);
+        let (tangent_a, tangent_b) = (a.derivative_at(s), b.derivative_at(t));
+        let determinant = tangent_b.cross(&tangent_a);
+        if determinant.abs() <= f64::EPSILON * tangent_a.norm() * tangent_b.norm() {
+            break;
+        }
+        let step_s = residual.cross(&tangent_b) / determinant;
+        let step_t = residual.cross(&tangent_a) / determinant;
+        s = (s + step_s).clamp(a_start, a_end);
+        t = (t + step_t).clamp(b_start, b_end);
+
+        let next = candidate(a, b, s, t, false);
+        if next.distance < best.distance {
+            best = next;
+        }
+        if step_s.abs() <= f64::EPSILON * (a_end - a_start)
+            && step_t.abs() <= f64::EPSILON * (b_end - b_start)
+        {
+            break;
+        }
+    }
+    (best.distance <= tolerance).then_some(best)
+}
+
+/// 隣り合う候補が同じ交点（接する近傍や重なり区間）に属するか
+///
+/// 両候補が同じ点を指すか、間で曲線A上の点が曲線Bから `tolerance` 以内に
+/// とどまっていれば同じまとまりとみなす。
+fn linked(
+    a: &NurbsCurve2D<f64>,
+    b: &NurbsCurve2D<f64>,
+    first: &Candidate,
+    second: &Candidate,
+    tolerance: f64,
+) -> bool {
+    if (a.evaluate_at(first.s) - a.evaluate_at(second.s)).norm() <= tolerance {
+        return true;
+    }
+    (1..=LINK_SAMPLES).all(|i| {
+        let ratio = f64::from_usize(i) / f64::from_usize(LINK_SAMPLES + 1);
+        let s = first.s + (second.s - first.s) * ratio;
+        let point = a.evaluate_at(s);
+        let t = project(b, point, first.t + (second.t - first.t) * ratio);
+        (point - b.evaluate_at(t)).norm() <= tolerance
+    })
+}
+
+/// まとまりを代表する交点
+fn group_representatives(
+    a: &NurbsCurve2D<f64>,
+    group: &[Candidate],
+    tolerance: f64,
+) -> Vec<Candidate> {
+    let closest = |candidates: &mut dyn Iterator<Item = &Candidate>| {
+        candidates
+            .min_by(|x, y| x.distance.total_cmp(&y.distance))
+            .copied()
+    };
+
+    // 離れた2つ以上の端点を含むなら重なり区間（候補は曲線Aのパラメータ順）
+    let mut endpoints = group.iter().filter(|candidate| candidate.endpoint);
+    let (first, last) = (endpoints.next(), endpoints.next_back());
+    if let (Some(first), Some(last)) = (first, last) {
+        if (a.evaluate_at(first.s) - a.evaluate_at(last.s)).norm() > tolerance {
+            return vec![*first, *last];
+        }
+    }
+
+    // 端点での交点は端点そのものを優先する
+    closest(&mut group.iter().filter(|candidate| candidate.endpoint))
+        .or_else(|| closest(&mut group.iter()))
+        .into_iter()
+        .collect()
+}
+
+fn candidate(
+    a: &NurbsCurve2D<f64>,
+    b: &NurbsCurve2D<f64>,
+    s: f64,
+    t: f64,
+    endpoint: bool,
+) -> Candidate {
+    Candidate {
+        s,
+        t,
+        distance: (a.evaluate_at(s) - b.evaluate_at(t)).norm(),
+        endpoint,
+    }
+}
+
+/// 曲線上で `point` に最も近い点のパラメータ（サンプリングで初期値を選んで射影する）
+fn closest_parameter(curve: &NurbsCurve2D<f64>, point: Vector2<f64>) -> f64 {
+    let (start, end) = curve.parameter_domain();
+    let samples = PROJECTION_SAMPLES_PER_POINT * curve.num_points();
+    let initial = (0..=samples)
+        .map(|i| start + (end - start) * f64::from_usize(i) / f64::from_usize(samples))
+        .min_by(|&x, &y| {
+            let distance = |t: f64| (curve.evaluate_at(t) - point).norm_squared();
+            distance(x).total_cmp(&distance(y))
+        })
+        .unwrap_or(start);
+    project(curve, point, initial)
+}
+
+/// `initial` から始めるガウス・ニュートン法による点の曲線への射影（定義域内に制限）
+fn project(curve: &NurbsCurve2D<f64>, point: Vector2<f64>, initial: f64) -> f64 {
+    let (start, end) = curve.parameter_domain();
+    let mut t = initial.clamp(start, end);
+    for _ in 0..PROJECTION_ITERATIONS {
+        let derivative = curve.derivative_at(t);
+        let speed_squared = derivative.norm_squared();
+        if speed_squared <= 0.0 {
+            break;
+        }
+        let step = (point - curve.evaluate_at(t)).dot(&derivative) / speed_squared;
+        t = (t + step).clamp(start, end);
+        if step.abs() <= f64::EPSILON * (end - start) {
+            break;
+        }
+    }
+    t
+}
+
+/// 制御点のバウンディングボックス（最小点, 最大点）
+fn control_box(segment: &NurbsCurve2D<f64>) -> (Vector2<f64>, Vector2<f64>) {
+    (1..segment.num_points()).fold(
+        (segment.control_point(0), segment.control_point(0)),
+        |(min, max), i| {
+            let point = segment.control_point(i);
+            (min.min(&point), max.max(&point))
+        },
+    )
+}
+
+/// 両端を結ぶ弦から制御点までの最大距離（曲線の弦からのずれの上界）
+fn flatness(segment: &NurbsCurve2D<f64>) -> f64 {
+    let start = segment.control_point(0);
+    let chord = segment.control_point(segment.num_points() - 1) - start;
+    let length = chord.norm();
+    (1..segment.num_points())
+        .map(|i| {
+            let offset = segment.control_point(i) - start;
+            if length > 0.0 {
+                chord.cross(&offset).abs() / length
+            } else {
+                offset.norm()
+            }
+        })
+        .fold(0.0, f64::max)
+}
+
+/// `other` の制御点がすべて `segment` の fat line（弦に平行で制御点を含む帯）の
+/// 片側に `tolerance` より離れてあるか
+///
+/// 斜めの区間では軸平行なボックスより強く枝刈りできる。
+fn separated_by_fat_line(
+    segment: &NurbsCurve2D<f64>,
+    other: &NurbsCurve2D<f64>,
+    tolerance: f64,
+) -> bool {
+    let start = segment.control_point(0);
+    let chord = segment.control_point(segment.num_points() - 1) - start;
+    let length = chord.norm();
+    if length <= 0.0 {
+        return false;
+    }
+    let signed_distance = |point: Vector2<f64>| chord.cross(&(point - start)) / length;
+    let (low, high) = (0..segment.num_points())
+        .map(|i| signed_distance(segment.control_point(i)))
+        .fold((0.0, 0.0), |(low, high), distance| {
+            (f64::min(low, distance), f64::max(high, distance))
+        });
+    let distances: Vec<f64> = (0..other.num_points())
+        .map(|i| signed_distance(other.control_point(i)))
+        .collect();
+    distances
+        .iter()
+        .all(|&distance| distance > high + tolerance)
+        || distances.iter().all(|&distance| distance < low - tolerance)
+}
+
+/// 区間を中央のパラメータで2分割する（分割できなければそのまま返す）
+fn halve(segment: &NurbsCurve2D<f64>) -> Vec<NurbsCurve2D<f64>> {
+    let (start, end) = segment.parameter_domain();
+    let middle = f64::midpoint(start, end);
+    match (segment.trim(start, middle), segment.trim(middle, end)) {
+        (Ok(left), Ok(right)) => vec![left, right],
+        _ => vec![segment.clone()],
+    }
+}
+
+/// 線分 `p0p1` と `q0q1` の最近点の線分上の位置（それぞれ [0, 1]）
+///
+/// 平行な場合は `p0` に対応する位置を選ぶ。
+#[allow(clippy::many_single_char_names)] // 数学記号は標準的
+fn closest_chord_parameters(
+    p0: Vector2<f64>,
+    p1: Vector2<f64>,
+    q0: Vector2<f64>,
+    q1: Vector2<f64>,
+) -> (f64, f64) {
+    let (d1, d2, r) = (p1 - p0, q1 - q0, p0 - q0);
+    let (a, e, f) = (d1.dot(&d1), d2.dot(&d2), d2.dot(&r));
+    if a <= f64::EPSILON && e <= f64::EPSILON {
+        return (0.0, 0.0);
+    }
+    if a <= f64::EPSILON {
+        return (0.0, (f / e).clamp(0.0, 1.0));
+    }
+    let c = d1.dot(&r);
+    if e <= f64::EPSILON {
+        return ((-c / a).clamp(0.0, 1.0), 0.0);
+    }
+
+    let b = d1.dot(&d2);
+    let denominator = a * e - b * b;
+    let s = if denominator > f64::EPSILON * a * e {
+        ((b * f - c * e) / denominator).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    let t = (b * s + f) / e;
+    if t < 0.0 {
+        ((-c / a).clamp(0.0, 1.0), 0.0)
+    } else if t > 1.0 {
+        (((b - c) / a).clamp(0.0, 1.0), 1.0)
+    } else {
+        (s, t)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f64::consts::FRAC_1_SQRT_2;
+
+    const TOLERANCE: f64 = 1e-9;
+
+    fn curve(points: &[(f64, f64)], weights: Option<Vec<f64>>) -> NurbsCurve2D<f64> {
+        let degree = points.len() - 1;
+        let mut knots = vec![0.0; degree + 1];
+        knots.extend(vec![1.0; degree + 1]);
+        let points: Vec<Vector2<f64>> = points.iter().map(|&(x, y)| Vector2::new(x, y)).collect();
+        NurbsCurve2D::new(&points, weights, knots, degree).unwrap()
+    }
+
+    /// 原点中心の単位円の第1象限（重み 1/√2 の2次有理ベジエ）
+    fn quarter_circle() -> NurbsCurve2D<f64> {
+        curve(
+            &[(1.0, 0.0), (1.0, 1.0), (0.0, 1.0)],
+            Some(vec![1.0, FRAC_1_SQRT_2, 1.0]),
+        )
+    }
+
+    fn assert_intersection(actual: (f64, f64, Point2D<f64>), expected: (f64, f64, f64, f64)) {
+        let (s, t, point) = actual;
+        assert!((s - expected.0).abs() < 1e-8, "{actual:?}");
+        assert!((t - expected.1).abs() < 1e-8, "{actual:?}");
+        assert!((point.x() - expected.2).abs() < 1e-8, "{actual:?}");
+        assert!((point.y() - expected.3).abs() < 1e-8, "{actual:?}");
+    }
+
+    #[test]
+    fn test_parabola_and_line_cross_twice() {
+        // y = x²（x ∈ [-1, 1], s = (x + 1) / 2）と y = 1/4
+        let parabola = curve(&[(-1.0, 1.0), (0.0, -1.0), (1.0, 1.0)], None);
+        let line = curve(&[(-1.0, 0.25), (1.0, 0.25)], None);
+
+        let intersections = intersect_curves(&parabola, &line, TOLERANCE);
+        assert_eq!(intersections.len(), 2);
+        assert_intersection(intersections[0], (0.25, 0.25, -0.5, 0.25));
+        assert_intersection(intersections[1], (0.75, 0.75, 0.5, 0.25));
+
+        // 曲線を入れ替えるとパラメータも入れ替わる
+        let swapped = intersect_curves(&line, &parabola, TOLERANCE);
+        assert_eq!(swapped.len(), 2);
+        assert_intersection(swapped[0], (0.25, 0.25, -0.5, 0.25));
+    }
+
+    #[test]
+    fn test_rational_arc_and_diagonal() {
+        let arc = quarter_circle();
+        let diagonal = curve(&[(0.0, 0.0), (1.0, 1.0)], None);
+
+        let intersections = intersect_curves(&arc, &diagonal, TOLERANCE);
+        assert_eq!(intersections.len(), 1);
+        let (s, t, point) = intersections[0];
+        assert!((s - 0.5).abs() < 1e-8);
+        assert!((t - FRAC_1_SQRT_2).abs() < 1e-8);
+        assert!((point.x() - FRAC_1_SQRT_2).abs() < 1e-8);
+        assert!((point.y() - FRAC_1_SQRT_2).abs() < 1e-8);
+    }
+
+    #[test]
+    fn test_arcs_meeting_at_endpoints() {
+        // 中心 (1, 1) の単位円の左下1/4も (1, 0) と (0, 1) を結ぶ
+        let arc = quarter_circle();
+        let other = curve(
+            &[(1.0, 0.0), (0.0, 0.0), (0.0, 1.0)],
+            Some(vec![1.0, FRAC_1_SQRT_2, 1.0]),
+        );
+
+        let intersections = intersect_curves(&arc, &other, TOLERANCE);
+        assert_eq!(intersections.len(), 2);
+        assert_intersection(intersections[0], (0.0, 0.0, 1.0, 0.0));
+        assert_intersection(intersections[1], (1.0, 1.0, 0.0, 1.0));
+    }
+
+    #[test]
+    fn test_tangent_contact_is_single_point() {
+        let parabola = curve(&[(-1.0, 1.0), (0.0, -1.0), (1.0, 1.0)], None);
+        let axis = curve(&[(-1.0, 0.0), (1.0, 0.0)], None);
+
+        let intersections = intersect_curves(&parabola, &axis, TOLERANCE);
+        assert_eq!(intersections.len(), 1);
+        let (s, t, point) = intersections[0];
+        // 接点では許容誤差 ε に対しパラメータが √ε 程度までしか定まらない
+        assert!((s - 0.5).abs() < 1e-4);
+        assert!((t - 0.5).abs() < 1e-4);
+        assert!(point.x().abs() < 1e-4);
+        assert!(point.y().abs() <= TOLERANCE);
+    }
+
+    #[test]
+    fn test_overlapping_curves_return_overlap_ends() {
+        let first = curve(&[(0.0, 0.0), (2.0, 0.0)], None);
+        let second = curve(&[(1.0, 0.0), (3.0, 0.0)], None);
+        let intersections = intersect_curves(&first, &second, TOLERANCE);
+        assert_eq!(intersections.len(), 2);
+        assert_intersection(intersections[0], (0.5, 0.0, 1.0, 0.0));
+        assert_intersection(intersections[1], (1.0, 0.5, 2.0, 0.0));
+
+        // 円弧とその一部（重なりは細分の途中で検出するため、細かい許容誤差でも速い）
+        let arc = quarter_circle();
+        let part = arc.trim(0.25, 0.75).unwrap();
+        let intersections = intersect_curves(&arc, &part, TOLERANCE);
+        assert_eq!(intersections.len(), 2);
+        assert!((intersections[0].0 - 0.25).abs() < 1e-8);
+        assert!((intersections[0].1 - 0.25).abs() < 1e-8);
+        assert!((intersections[1].0 - 0.75).abs() < 1e-8);
+        assert!((intersections[1].1 - 0.75).abs() < 1e-8);
+
+        // 互いにはみ出した円弧同士（パラメータは元の円弧のものを引き継ぐ）
+        let first = arc.trim(0.0, 0.6).unwrap();
+        let second = arc.trim(0.4, 1.0).unwrap();
+        let intersections = intersect_curves(&first, &second, TOLERANCE);
+        assert_eq!(intersections.len(), 2);
+        assert!((intersections[0].0 - 0.4).abs() < 1e-8);
+        assert!((intersections[0].1 - 0.4).abs() < 1e-8);
+        assert!((intersections[1].0 - 0.6).abs() < 1e-8);
+        assert!((intersections[1].1 - 0.6).abs() < 1e-8);
+    }
+
+    #[test]
+    fn test_disjoint_curves() {
+        let arc = quarter_circle();
+        let line = curve(&[(2.0, 0.0), (2.0, 2.0)], None);
+        assert!(intersect_curves(&arc, &line, TOLERANCE).is_empty());
+
+        // ボックスは重なるが交わらない
+        let chord = curve(&[(0.9, 0.0), (0.0, 0.9)], None);
+        assert!(intersect_curves(&arc, &chord, TOLERANCE).is_empty());
+    }
+}
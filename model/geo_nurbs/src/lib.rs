@@ -10,8 +10,13 @@
 
 // NURBS実装モジュール
 pub mod basis;
+pub mod bounds;
 pub mod curve_2d;
 pub mod curve_3d;
+pub mod fitting;
+pub mod geometry_union;
+pub mod intersection;
+mod parametric;
 pub mod surface;
 pub mod transform;
 
@@ -23,15 +28,34 @@ pub mod weight_storage;
 pub use analysis::Scalar;
 
 // 主要な型を再エクスポート
-pub use basis::{basis_function, basis_functions, rational_basis_functions};
+pub use basis::{
+    basis_derivatives_batch, basis_function, basis_functions, basis_functions_batch,
+    rational_basis_functions, SparseBasis,
+};
 pub use curve_2d::NurbsCurve2D;
 pub use curve_3d::NurbsCurve3D;
 pub use error::{NurbsError, Result};
+pub use geometry_union::GeometryUnion;
+pub use intersection::intersect_curves;
 pub use knot::{validate_knot_vector, KnotVector};
 pub use surface::NurbsSurface3D;
 pub use transform::{CurveSplitting, DegreeElevation, KnotInsertion};
 pub use weight_storage::WeightStorage;
 
+// 便利な型エイリアス（f = f32, d = f64）
+/// f32 版 [`NurbsCurve2D`]
+pub type NurbsCurve2Df = NurbsCurve2D<f32>;
+/// f64 版 [`NurbsCurve2D`]
+pub type NurbsCurve2Dd = NurbsCurve2D<f64>;
+/// f32 版 [`NurbsCurve3D`]
+pub type NurbsCurve3Df = NurbsCurve3D<f32>;
+/// f64 版 [`NurbsCurve3D`]
+pub type NurbsCurve3Dd = NurbsCurve3D<f64>;
+/// f32 版 [`NurbsSurface3D`]
+pub type NurbsSurface3Df = NurbsSurface3D<f32>;
+/// f64 版 [`NurbsSurface3D`]
+pub type NurbsSurface3Dd = NurbsSurface3D<f64>;
+
 /// NURBS関連の定数
 pub mod constants {
     /// デフォルトの数値許容誤差
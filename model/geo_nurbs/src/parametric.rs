@@ -0,0 +1,102 @@
+//! NURBS曲線の `ParametricCurve3D` 実装
+//!
+//! パラメータはノットの有効定義域（[`NurbsCurve3D::parameter_domain`]）。
+//! 長さは速さ `|C'(t)|` を各ノットスパンで Gauss-Legendre 求積して合計する
+//! （スパン内では曲線が滑らかなので、スパン境界で区切れば高精度に積分できる）。
+
+use crate::{NurbsCurve3D, Scalar};
+use analysis::numerics::composite_gauss_legendre;
+use geo_foundation::ParametricCurve3D;
+use geo_primitives::{Point3D, Vector3D};
+
+/// 長さの積分で使う Gauss-Legendre 則の点数
+const LENGTH_QUADRATURE_POINTS: usize = 10;
+
+/// 長さの積分で1つのノットスパンを分ける数
+const LENGTH_INTERVALS_PER_SPAN: usize = 4;
+
+impl<T: Scalar> ParametricCurve3D<T> for NurbsCurve3D<T> {
+    type Point = Point3D<T>;
+    type Vector = Vector3D<T>;
+
+    fn point_at(&self, t: T) -> Point3D<T> {
+        let p = self.evaluate_at(t);
+        Point3D::new(p.x(), p.y(), p.z())
+    }
+
+    fn tangent_at(&self, t: T) -> Vector3D<T> {
+        let d = self.derivative_at(t);
+        Vector3D::new(d.x(), d.y(), d.z())
+    }
+
+    fn domain(&self) -> (T, T) {
+        self.parameter_domain()
+    }
+
+    fn length(&self) -> T {
+        let total: f64 = self
+            .span_breakpoints()
+            .windows(2)
+            .map(|span| {
+                composite_gauss_legendre(
+                    |t| self.derivative_at(T::from_f64(t)).norm().to_f64(),
+                    span[0].to_f64(),
+                    span[1].to_f64(),
+                    LENGTH_QUADRATURE_POINTS,
+                    LENGTH_INTERVALS_PER_SPAN,
+                )
+            })
+            .sum();
+        T::from_f64(total)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use analysis::linalg::vector::Vector3;
+
+    #[test]
+    fn test_straight_bezier_length_is_distance() {
+        // 制御点が一直線上の3次ベジェ（速さは一定でない）
+        let curve = NurbsCurve3D::from_bezier(&[
+            Point3D::new(0.0, 0.0, 0.0),
+            Point3D::new(0.5, 1.0, 1.0),
+            Point3D::new(2.5, 5.0, 5.0),
+            Point3D::new(3.0, 6.0, 6.0),
+        ])
+        .unwrap();
+        assert_eq!(ParametricCurve3D::domain(&curve), (0.0, 1.0));
+        assert!((ParametricCurve3D::length(&curve) - 9.0).abs() < 1e-12);
+        assert_eq!(
+            ParametricCurve3D::point_at(&curve, 1.0),
+            Point3D::new(3.0, 6.0, 6.0)
+        );
+        let tangent = ParametricCurve3D::tangent_at(&curve, 0.0);
+        assert!((tangent - Vector3D::new(1.5, 3.0, 3.0)).length() < 1e-12);
+    }
+
+    #[test]
+    fn test_rational_half_circle_length() {
+        // 中心 (1, 1)、半径1の有理2次の半円（ノット [0, 0, 0, 0.5, 0.5, 2, 2, 2]）
+        let w = std::f64::consts::FRAC_1_SQRT_2;
+        let curve = NurbsCurve3D::new(
+            vec![
+                Vector3::new(2.0, 1.0, 0.0),
+                Vector3::new(2.0, 2.0, 0.0),
+                Vector3::new(1.0, 2.0, 0.0),
+                Vector3::new(0.0, 2.0, 0.0),
+                Vector3::new(0.0, 1.0, 0.0),
+            ],
+            Some(vec![1.0, w, 1.0, w, 1.0]),
+            vec![0.0, 0.0, 0.0, 0.5, 0.5, 2.0, 2.0, 2.0],
+            2,
+        )
+        .unwrap();
+        assert_eq!(ParametricCurve3D::domain(&curve), (0.0, 2.0));
+        let length = ParametricCurve3D::length(&curve);
+        assert!((length - std::f64::consts::PI).abs() < 1e-8, "{length}");
+        let end = ParametricCurve3D::point_at(&curve, 2.0);
+        assert!((end.x()).abs() < 1e-12 && (end.y() - 1.0).abs() < 1e-12);
+    }
+}
@@ -3,18 +3,9 @@
 //! Non-Uniform Rational B-Spline surfaces の基本実装です。
 //! 制御点の2次元グリッド、重み、2方向のノットベクトルを使用して自由形状曲面を表現します。
 
-use crate::{KnotVector, NurbsError, Result, Scalar};
+use crate::{KnotVector, NurbsCurve3D, NurbsError, Result, Scalar, WeightStorage};
 use analysis::linalg::vector::Vector3;
 
-/// 重み配列の効率的管理
-#[derive(Debug, Clone)]
-pub enum WeightStorage<T: Scalar> {
-    /// 非有理サーフェス（全重み = 1.0）
-    Uniform,
-    /// 有理サーフェス（個別重み）- フラット配列
-    Individual(Vec<T>),
-}
-
 /// NURBSサーフェス - 3次元（メモリ最適化版）
 ///
 /// # 特徴
@@ -131,9 +122,10 @@ impl<T: Scalar> NurbsSurface3D<T> {
                     flat_weights.push(weight);
                 }
             }
-            WeightStorage::Individual(flat_weights)
+            // 全て1.0なら非有理として多項式の評価経路を使う（フラット配列、u方向優先）
+            WeightStorage::from_weights(flat_weights)
         } else {
-            WeightStorage::Uniform
+            WeightStorage::Uniform(T::ONE)
         };
 
         // ノットベクトルの検証
@@ -182,10 +174,13 @@ impl<T: Scalar> NurbsSurface3D<T> {
     /// 重み取得
     #[must_use]
     pub fn weight(&self, u: usize, v: usize) -> T {
-        match &self.weights {
-            WeightStorage::Uniform => T::ONE,
-            WeightStorage::Individual(weights) => weights[self.weight_index(u, v)],
-        }
+        self.weights.get_weight(self.weight_index(u, v))
+    }
+
+    /// 有理サーフェス（1.0 以外の重みを含む）かどうか
+    #[must_use]
+    pub fn is_rational(&self) -> bool {
+        self.weights.is_rational()
     }
 
     /// フラット座標配列への参照を取得
@@ -339,6 +334,79 @@ impl<T: Scalar> NurbsSurface3D<T> {
             .unwrap_or_else(|_| Vector3::zero())
     }
 
+    /// u を固定したアイソパラメトリック曲線（v方向の曲線）を抽出
+    ///
+    /// 各列 j について、u方向の基底関数で同次座標 (w·P, w) を混合して
+    /// 新しい制御点と重みを作る。曲線は v方向のノットベクトルと次数を引き継ぎ、
+    /// `curve.evaluate_at(v) == surface.evaluate_at(u, v)` を満たす。
+    ///
+    /// # Errors
+    /// 曲線の構築に失敗した場合
+    pub fn iso_curve_u(&self, u: T) -> Result<NurbsCurve3D<T>> {
+        let span = crate::knot::find_knot_span(u, &self.u_knots, self.u_degree);
+        let basis = self.compute_u_basis_functions(u, span);
+        let first = span - self.u_degree;
+
+        self.build_iso_curve(
+            self.v_count,
+            |j, k| (self.control_point(first + k, j), self.weight(first + k, j)),
+            &basis,
+            self.v_knots.clone(),
+            self.v_degree,
+        )
+    }
+
+    /// v を固定したアイソパラメトリック曲線（u方向の曲線）を抽出
+    ///
+    /// [`iso_curve_u`](Self::iso_curve_u) と対称に、各行 i を v方向の基底関数で混合する。
+    /// 曲線は u方向のノットベクトルと次数を引き継ぐ。
+    ///
+    /// # Errors
+    /// 曲線の構築に失敗した場合
+    pub fn iso_curve_v(&self, v: T) -> Result<NurbsCurve3D<T>> {
+        let span = crate::knot::find_knot_span(v, &self.v_knots, self.v_degree);
+        let basis = self.compute_v_basis_functions(v, span);
+        let first = span - self.v_degree;
+
+        self.build_iso_curve(
+            self.u_count,
+            |i, k| (self.control_point(i, first + k), self.weight(i, first + k)),
+            &basis,
+            self.u_knots.clone(),
+            self.u_degree,
+        )
+    }
+
+    /// 基底関数で制御点列を混合してアイソパラメトリック曲線を構築
+    ///
+    /// `entry(index, k)` は曲線の制御点 `index` に寄与する k 番目の制御点と重みを返す。
+    fn build_iso_curve(
+        &self,
+        count: usize,
+        entry: impl Fn(usize, usize) -> (Vector3<T>, T),
+        basis: &[T],
+        knots: KnotVector<T>,
+        degree: usize,
+    ) -> Result<NurbsCurve3D<T>> {
+        let mut points = Vec::with_capacity(count);
+        let mut weights = Vec::with_capacity(count);
+
+        for index in 0..count {
+            let mut numerator = Vector3::zero();
+            let mut weight = T::ZERO;
+            for (k, &n) in basis.iter().enumerate() {
+                let (point, w) = entry(index, k);
+                numerator = numerator + point * (n * w);
+                weight += n * w;
+            }
+            points.push(numerator / weight);
+            weights.push(weight);
+        }
+
+        let weights = self.is_rational().then_some(weights);
+        NurbsCurve3D::new(points, weights, knots, degree)
+    }
+
     /// サーフェスの面積を近似計算
     ///
     /// # 引数
@@ -503,4 +571,75 @@ mod tests {
         assert!((point.y() - 0.5).abs() < 1e-10);
         assert!((point.z() - 0.0).abs() < 1e-10);
     }
+
+    /// 3x4 制御点、次数 (2, 2)、重み付きの曲面
+    fn rational_surface() -> NurbsSurface3D<f64> {
+        let control_points = (0..3)
+            .map(|i| {
+                (0..4)
+                    .map(|j| {
+                        let (x, y) = (f64::from(i), f64::from(j));
+                        Vector3::new(x, y, (x * 0.7 - y * 0.4).sin() + 0.3 * x * y)
+                    })
+                    .collect()
+            })
+            .collect();
+        let weights = vec![
+            vec![1.0, 0.8, 1.5, 1.0],
+            vec![0.6, 1.2, 1.0, 2.0],
+            vec![1.0, 1.0, 0.7, 1.3],
+        ];
+        let u_knots = vec![0.0, 0.0, 0.0, 1.0, 1.0, 1.0];
+        let v_knots = vec![0.0, 0.0, 0.0, 0.4, 1.0, 1.0, 1.0];
+
+        NurbsSurface3D::new(control_points, Some(weights), u_knots, v_knots, 2, 2).unwrap()
+    }
+
+    fn assert_close(a: Vector3<f64>, b: Vector3<f64>) {
+        assert!((a - b).norm() < 1e-10, "{a:?} != {b:?}");
+    }
+
+    #[test]
+    fn test_iso_curve_u_matches_surface() {
+        let surface = rational_surface();
+        for &u in &[0.0, 0.25, 0.5, 1.0] {
+            let curve = surface.iso_curve_u(u).unwrap();
+            assert_eq!(curve.num_points(), 4);
+            assert_eq!(curve.degree(), surface.v_degree());
+            for k in 0..=10 {
+                let v = f64::from(k) / 10.0;
+                assert_close(curve.evaluate_at(v), surface.evaluate_at(u, v));
+            }
+        }
+    }
+
+    #[test]
+    fn test_iso_curve_v_matches_surface() {
+        let surface = rational_surface();
+        for &v in &[0.0, 0.3, 0.4, 0.75, 1.0] {
+            let curve = surface.iso_curve_v(v).unwrap();
+            assert_eq!(curve.num_points(), 3);
+            assert_eq!(curve.degree(), surface.u_degree());
+            for k in 0..=10 {
+                let u = f64::from(k) / 10.0;
+                assert_close(curve.evaluate_at(u), surface.evaluate_at(u, v));
+            }
+        }
+    }
+
+    #[test]
+    fn test_iso_curve_of_non_rational_surface_is_non_rational() {
+        let control_points = vec![
+            vec![Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 1.0, 0.0)],
+            vec![Vector3::new(1.0, 0.0, 0.0), Vector3::new(1.0, 1.0, 1.0)],
+        ];
+        let knots = vec![0.0, 0.0, 1.0, 1.0];
+        let surface =
+            NurbsSurface3D::new(control_points, None, knots.clone(), knots, 1, 1).unwrap();
+
+        let curve = surface.iso_curve_u(0.5).unwrap();
+        assert!(!curve.is_rational());
+        assert_eq!(curve.weights(), &WeightStorage::Uniform(1.0));
+        assert_close(curve.evaluate_at(1.0), Vector3::new(0.5, 1.0, 0.5));
+    }
 }
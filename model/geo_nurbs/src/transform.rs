@@ -13,6 +13,9 @@ pub type KnotInsertResult2D<T> = Result<(Vec<Vector2<T>>, Vec<T>, KnotVector<T>)
 /// ノット挿入結果の型エイリアス（3D用）
 pub type KnotInsertResult3D<T> = Result<(Vec<Vector3<T>>, Vec<T>, KnotVector<T>), NurbsError>;
 
+/// ノット挿入・部分曲線抽出結果の型エイリアス（制御点の型 `P` で次元を共通化）
+pub(crate) type CurveDataResult<P, T> = Result<(Vec<P>, Vec<T>, KnotVector<T>), NurbsError>;
+
 /// 次数上昇結果の型エイリアス（2D用）
 pub type DegreeElevateResult2D<T> =
     Result<(Vec<Vector2<T>>, Vec<T>, KnotVector<T>, usize), NurbsError>;
@@ -108,9 +111,12 @@ impl KnotInsertion {
             let old_weight = weights[i];
             let prev_weight = weights[i - 1];
 
-            let new_x = (T::ONE - alpha) * prev_point.x() + alpha * old_point.x();
-            let new_y = (T::ONE - alpha) * prev_point.y() + alpha * old_point.y();
+            // 有理曲線では同次座標 (wP, w) で内分する
             let new_weight = (T::ONE - alpha) * prev_weight + alpha * old_weight;
+            let prev_ratio = (T::ONE - alpha) * prev_weight / new_weight;
+            let old_ratio = alpha * old_weight / new_weight;
+            let new_x = prev_ratio * prev_point.x() + old_ratio * old_point.x();
+            let new_y = prev_ratio * prev_point.y() + old_ratio * old_point.y();
 
             new_control_points.push(Vector2::new(new_x, new_y));
             new_weights.push(new_weight);
@@ -174,10 +180,13 @@ impl KnotInsertion {
             let old_weight = weights[i];
             let prev_weight = weights[i - 1];
 
-            let new_x = (T::ONE - alpha) * prev_point.x() + alpha * old_point.x();
-            let new_y = (T::ONE - alpha) * prev_point.y() + alpha * old_point.y();
-            let new_z = (T::ONE - alpha) * prev_point.z() + alpha * old_point.z();
+            // 有理曲線では同次座標 (wP, w) で内分する
             let new_weight = (T::ONE - alpha) * prev_weight + alpha * old_weight;
+            let prev_ratio = (T::ONE - alpha) * prev_weight / new_weight;
+            let old_ratio = alpha * old_weight / new_weight;
+            let new_x = prev_ratio * prev_point.x() + old_ratio * old_point.x();
+            let new_y = prev_ratio * prev_point.y() + old_ratio * old_point.y();
+            let new_z = prev_ratio * prev_point.z() + old_ratio * old_point.z();
 
             new_control_points.push(Vector3::new(new_x, new_y, new_z));
             new_weights.push(new_weight);
@@ -191,6 +200,81 @@ impl KnotInsertion {
 
         Ok((new_control_points, new_weights, new_knots))
     }
+
+    /// パラメータ区間 `[u0, u1]` の部分曲線の制御点・重み・ノットベクトル
+    ///
+    /// 両端で `insert`（[`Self::insert_knot_2d`] など）によりノット挿入して重複度を次数まで
+    /// 上げると、`C(u0)` と `C(u1)` がそれぞれ制御点に一致する。その間の制御点と、両端を
+    /// `次数 + 1` 重にしたノットベクトルを返す（パラメータは元曲線のものをそのまま引き継ぐ）。
+    ///
+    /// # Errors
+    /// `u0 < u1` でない場合、区間がパラメータ定義域からはみ出す場合、ノット挿入に失敗した場合
+    pub(crate) fn trim_range<T, P, F>(
+        control_points: Vec<P>,
+        weights: Vec<T>,
+        knots: KnotVector<T>,
+        degree: usize,
+        (u0, u1): (T, T),
+        insert: F,
+    ) -> CurveDataResult<P, T>
+    where
+        T: Scalar,
+        P: Copy,
+        F: Fn(
+            &[P],
+            &[T],
+            &KnotVector<T>,
+            usize,
+            T,
+        ) -> Result<(Vec<P>, Vec<T>, KnotVector<T>), NurbsError>,
+    {
+        let (t_min, t_max) = crate::knot::get_parameter_domain(&knots, degree);
+        for parameter in [u0, u1] {
+            if parameter < t_min || parameter > t_max {
+                return Err(NurbsError::ParameterOutOfRange {
+                    parameter: parameter.to_f64(),
+                    min: t_min.to_f64(),
+                    max: t_max.to_f64(),
+                });
+            }
+        }
+        if u0 >= u1 {
+            return Err(NurbsError::ParameterOutOfRange {
+                parameter: u0.to_f64(),
+                min: t_min.to_f64(),
+                max: u1.to_f64(),
+            });
+        }
+
+        let p = degree;
+        let (mut control_points, mut weights, mut knots) = (control_points, weights, knots);
+        for u in [u0, u1] {
+            let multiplicity = knots.iter().filter(|&&knot| knot == u).count();
+            for _ in multiplicity..p {
+                (control_points, weights, knots) = insert(&control_points, &weights, &knots, p, u)?;
+            }
+        }
+
+        // u0 の最後の p 個のノットの直前の制御点が C(u0)、u1 の最初のノットの直前が C(u1)
+        let start = knots
+            .iter()
+            .rposition(|&knot| knot == u0)
+            .map_or(0, |last| last - p);
+        let knot_end = knots
+            .iter()
+            .position(|&knot| knot == u1)
+            .map_or(knots.len() - 1, |first| first + p);
+        let end = knot_end - p - 1;
+
+        let mut trimmed_knots = knots[start..=knot_end].to_vec();
+        trimmed_knots[0] = u0;
+        trimmed_knots[knot_end - start] = u1;
+        Ok((
+            control_points[start..=end].to_vec(),
+            weights[start..=end].to_vec(),
+            trimmed_knots,
+        ))
+    }
 }
 
 /// NURBS曲線の次数上昇
@@ -66,6 +66,23 @@ impl<T: Scalar> WeightStorage<T> {
         }
     }
 
+    /// 有理（1.0 以外の重みを含む）かどうか判定
+    ///
+    /// 非有理なら重みによる除算を省いた多項式（B-スプライン）として評価できる。
+    pub fn is_rational(&self) -> bool {
+        !self.is_non_rational()
+    }
+
+    /// 重み配列から作成（全て1.0なら非有理の `Uniform(1.0)` にまとめる）
+    #[must_use]
+    pub fn from_weights(weights: Vec<T>) -> Self {
+        if weights.iter().all(|&w| w == T::ONE) {
+            WeightStorage::Uniform(T::ONE)
+        } else {
+            WeightStorage::Individual(weights)
+        }
+    }
+
     /// Uniform重みからIndividualに変換（指定したサイズで）
     #[must_use]
     pub fn to_individual(&self, num_points: usize) -> WeightStorage<T> {
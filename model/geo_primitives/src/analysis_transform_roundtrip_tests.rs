@@ -0,0 +1,143 @@
+//! Analysis Matrix4x4 変換の往復テスト
+//!
+//! 各プリミティブを行列で変換し、逆行列で戻すと元に戻ることを許容誤差込みで確認する。
+//! Point3D は AnalysisTransform3D を実装していないため、各プリミティブが内部で使う
+//! `Matrix4x4::transform_point_3d` で検証する。
+
+#[cfg(test)]
+mod tests {
+    use crate::{Circle3D, Direction3D, LineSegment3D, Point3D, Vector3D};
+    use analysis::linalg::{matrix::Matrix4x4, vector::Vector3};
+    use geo_foundation::{AnalysisTransform3D, AnalysisTransformVector3D};
+
+    const TOLERANCE: f64 = 1e-10;
+
+    /// 回転・均等スケール・平行移動の合成（円を円のまま写す）
+    fn similarity() -> Matrix4x4<f64> {
+        let axis = Vector3::new(1.0 / 3.0, 2.0 / 3.0, 2.0 / 3.0);
+        Matrix4x4::translation(1.0, -2.0, 3.0)
+            * Matrix4x4::rotation_axis_3d(axis, 0.7)
+            * Matrix4x4::scale(2.0, 2.0, 2.0)
+    }
+
+    /// 回転・非均等スケール・平行移動の合成
+    fn affine() -> Matrix4x4<f64> {
+        let axis = Vector3::new(0.0, 0.6, 0.8);
+        Matrix4x4::translation(-4.0, 0.5, 2.0)
+            * Matrix4x4::rotation_axis_3d(axis, -1.2)
+            * Matrix4x4::scale(2.0, 0.5, 3.0)
+    }
+
+    fn inverse(matrix: &Matrix4x4<f64>) -> Matrix4x4<f64> {
+        matrix
+            .inverse_3d()
+            .expect("affine matrix should be invertible")
+    }
+
+    fn transform_point(matrix: &Matrix4x4<f64>, point: Point3D<f64>) -> Point3D<f64> {
+        Point3D::from_analysis_vector3(matrix.transform_point_3d(&point.to_analysis_vector3()))
+    }
+
+    fn assert_point_eq(actual: Point3D<f64>, expected: Point3D<f64>) {
+        assert!(
+            actual.distance_to(&expected) < TOLERANCE,
+            "{actual:?} != {expected:?}"
+        );
+    }
+
+    fn assert_vector_eq(actual: Vector3D<f64>, expected: Vector3D<f64>) {
+        assert!(
+            (actual - expected).length() < TOLERANCE,
+            "{actual:?} != {expected:?}"
+        );
+    }
+
+    #[test]
+    fn test_inverse_matrix_is_identity() {
+        for matrix in [similarity(), affine()] {
+            let product = inverse(&matrix) * matrix;
+            let identity = Matrix4x4::<f64>::identity();
+            for row in 0..4 {
+                for col in 0..4 {
+                    assert!((product.get(row, col) - identity.get(row, col)).abs() < TOLERANCE);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_point_roundtrip() {
+        let point = Point3D::new(1.5, -2.25, 4.0);
+        for matrix in [similarity(), affine()] {
+            let moved = transform_point(&matrix, point);
+            assert!(moved.distance_to(&point) > 1.0);
+            assert_point_eq(transform_point(&inverse(&matrix), moved), point);
+        }
+    }
+
+    #[test]
+    fn test_vector_roundtrip() {
+        let vector = Vector3D::new(-3.0, 0.5, 2.0);
+        for matrix in [similarity(), affine()] {
+            let moved = vector.transform_vector_matrix(&matrix);
+            let restored = moved.transform_vector_matrix(&inverse(&matrix));
+            assert_vector_eq(restored, vector);
+        }
+    }
+
+    #[test]
+    fn test_direction_roundtrip() {
+        let direction = Direction3D::new(1.0, -2.0, 2.0).unwrap();
+        for matrix in [similarity(), affine()] {
+            let moved = direction.transform_point_matrix(&matrix);
+            assert!((moved.length() - 1.0).abs() < TOLERANCE);
+            let restored = moved.transform_point_matrix(&inverse(&matrix));
+            assert_vector_eq(restored.as_vector(), direction.as_vector());
+        }
+    }
+
+    #[test]
+    fn test_direction_is_translation_invariant() {
+        let direction = Direction3D::new(0.0, 3.0, 4.0).unwrap();
+        let translation = Vector3::new(10.0, -20.0, 30.0);
+
+        let translated = direction.translate_analysis(&translation).unwrap();
+        assert_vector_eq(translated.as_vector(), direction.as_vector());
+
+        let matrix = Matrix4x4::translation(10.0, -20.0, 30.0);
+        let transformed = direction.transform_point_matrix(&matrix);
+        assert_vector_eq(transformed.as_vector(), direction.as_vector());
+    }
+
+    #[test]
+    fn test_line_segment_roundtrip() {
+        let segment =
+            LineSegment3D::new(Point3D::new(0.0, 1.0, 2.0), Point3D::new(-3.0, 4.0, 0.5)).unwrap();
+        for matrix in [similarity(), affine()] {
+            let moved = segment.transform_point_matrix(&matrix);
+            assert_point_eq(moved.start(), transform_point(&matrix, segment.start()));
+            let restored = moved.transform_point_matrix(&inverse(&matrix));
+            assert_point_eq(restored.start(), segment.start());
+            assert_point_eq(restored.end(), segment.end());
+        }
+    }
+
+    #[test]
+    fn test_circle_roundtrip() {
+        let circle = Circle3D::new(
+            Point3D::new(2.0, -1.0, 0.5),
+            Direction3D::new(1.0, 1.0, 0.0).unwrap(),
+            1.5,
+        )
+        .unwrap();
+        let matrix = similarity();
+
+        let moved = circle.transform_point_matrix(&matrix);
+        assert!((moved.radius() - 3.0).abs() < TOLERANCE);
+
+        let restored = moved.transform_point_matrix(&inverse(&matrix));
+        assert_point_eq(restored.center(), circle.center());
+        assert!((restored.radius() - circle.radius()).abs() < TOLERANCE);
+        assert_vector_eq(restored.normal().as_vector(), circle.normal().as_vector());
+    }
+}
@@ -157,6 +157,31 @@ impl<T: Scalar> Arc2D<T> {
         let span = self.angular_span();
         (span - T::TAU).abs() < T::EPSILON
     }
+
+    /// 指定角度が円弧の範囲内にあるかを判定（2πをまたぐ範囲・全周に対応）
+    pub fn contains_angle(&self, angle: Angle<T>) -> bool {
+        Angle::contains_angle(self.start_angle, self.end_angle, angle)
+    }
+
+    /// 角度 `angle` の位置で円弧を2つに分割
+    ///
+    /// 開始側（開始角〜分割角）と終了側（分割角〜終了角）の円弧を返す。
+    /// 分割角が円弧の範囲外、または端点と一致する場合は `None`。
+    pub fn split_at(&self, angle: Angle<T>) -> Option<(Self, Self)> {
+        let offset = (angle - self.start_angle).normalized_positive();
+        if !self.contains_angle(angle)
+            || offset.to_radians() <= T::ZERO
+            || offset.to_radians() >= self.angular_span()
+        {
+            return None;
+        }
+
+        let split = self.start_angle + offset;
+        Some((
+            Self::new(self.circle, self.start_angle, split)?,
+            Self::new(self.circle, split, self.end_angle)?,
+        ))
+    }
 }
 
 // ============================================================================
@@ -273,4 +298,30 @@ mod tests {
         let arc = Arc2D::from_center_radius(center, radius, start, end).unwrap();
         assert!(arc.is_full_circle());
     }
+
+    #[test]
+    fn test_contains_and_split_across_zero() {
+        // 350° 〜 10°（開始 > 終了）
+        let arc = Arc2D::from_center_radius(
+            Point2D::new(0.0, 0.0),
+            1.0,
+            Angle::from_degrees(350.0),
+            Angle::from_degrees(10.0),
+        )
+        .unwrap();
+        assert!(arc.contains_angle(Angle::from_degrees(0.0)));
+        assert!(!arc.contains_angle(Angle::from_degrees(90.0)));
+
+        let (first, second) = arc.split_at(Angle::from_degrees(0.0)).unwrap();
+        assert!((first.angular_span() - 10f64.to_radians()).abs() < 1e-10);
+        assert!((second.angular_span() - 10f64.to_radians()).abs() < 1e-10);
+        assert!((first.end_point().x() - 1.0).abs() < 1e-10);
+        assert!((second.start_point().x() - 1.0).abs() < 1e-10);
+        assert_eq!(second.end_angle(), arc.end_angle());
+
+        // 範囲外・端点では分割しない
+        assert!(arc.split_at(Angle::from_degrees(180.0)).is_none());
+        assert!(arc.split_at(Angle::from_degrees(350.0)).is_none());
+        assert!(arc.split_at(Angle::from_degrees(10.0)).is_none());
+    }
 }
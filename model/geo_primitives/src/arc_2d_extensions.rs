@@ -92,19 +92,7 @@ impl<T: Scalar> Arc2D<T> {
             || self.angle_span().to_radians() <= Angle::<T>::tolerance()
     }
 
-    /// 指定角度が円弧の範囲内にあるかを判定
-    pub fn contains_angle(&self, angle: Angle<T>) -> bool {
-        let normalized_angle = self.normalize_angle(angle);
-        let normalized_start = self.normalize_angle(self.start_angle());
-        let normalized_end = self.normalize_angle(self.end_angle());
-
-        if normalized_start <= normalized_end {
-            normalized_angle >= normalized_start && normalized_angle <= normalized_end
-        } else {
-            // 0度をまたぐ場合
-            normalized_angle >= normalized_start || normalized_angle <= normalized_end
-        }
-    }
+    // contains_angle は arc_2d.rs で実装（2πをまたぐ範囲に対応）
 
     // ========================================================================
     // Extension Geometric Methods
@@ -128,33 +128,47 @@ impl<T: Scalar> Arc3D<T> {
 
     // === 角度と検証メソッド ===
 
-    /// 角度が円弧の範囲内にあるかチェック
+    /// 角度が円弧の範囲内にあるかチェック（2πをまたぐ範囲・全周に対応）
     pub fn contains_angle(&self, angle: Angle<T>) -> bool {
-        let normalized_angle = self.normalize_angle(angle);
-        let start = self.normalize_angle(self.start_angle());
-        let end = self.normalize_angle(self.end_angle());
-
-        if start.to_radians() <= end.to_radians() {
-            normalized_angle.to_radians() >= start.to_radians()
-                && normalized_angle.to_radians() <= end.to_radians()
-        } else {
-            // 0度をまたぐ場合
-            normalized_angle.to_radians() >= start.to_radians()
-                || normalized_angle.to_radians() <= end.to_radians()
-        }
+        Angle::contains_angle(self.start_angle(), self.end_angle(), angle)
     }
 
-    /// 角度を [0, 2π] の範囲に正規化
+    /// 角度を [0, 2π) の範囲に正規化
     pub fn normalize_angle(&self, angle: Angle<T>) -> Angle<T> {
-        let two_pi = Angle::from_radians(T::from_f64(2.0) * T::PI);
-        let mut normalized = angle;
-        while normalized.to_radians() < T::ZERO {
-            normalized += two_pi;
-        }
-        while normalized >= two_pi {
-            normalized -= two_pi;
+        angle.normalized_positive()
+    }
+
+    /// 角度 `angle` の位置で円弧を2つに分割
+    ///
+    /// 開始側（開始角〜分割角）と終了側（分割角〜終了角）の円弧を返す。
+    /// 分割角が円弧の範囲外、または端点と一致する場合は `None`。
+    pub fn split_at(&self, angle: Angle<T>) -> Option<(Self, Self)> {
+        let offset = (angle - self.start_angle()).normalized_positive();
+        if !self.contains_angle(angle)
+            || offset.to_radians() <= T::ZERO
+            || offset.to_radians() >= self.angle_span().to_radians()
+        {
+            return None;
         }
-        normalized
+
+        let split = self.start_angle() + offset;
+        let first = Self::new(
+            self.center(),
+            self.radius(),
+            self.normal(),
+            self.start_direction(),
+            self.start_angle(),
+            split,
+        )?;
+        let second = Self::new(
+            self.center(),
+            self.radius(),
+            self.normal(),
+            self.start_direction(),
+            split,
+            self.end_angle(),
+        )?;
+        Some((first, second))
     }
 
     // === 内部ヘルパーメソッド ===
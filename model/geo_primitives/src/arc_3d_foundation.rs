@@ -114,4 +114,45 @@ mod tests {
         assert!(arc1.tolerant_eq(&arc2, tolerance));
         assert!(!arc1.tolerant_eq(&arc3, tolerance));
     }
+
+    #[test]
+    fn test_contains_angle_across_zero_and_full_circle() {
+        let center = Point3D::new(0.0, 0.0, 0.0);
+        let deg = geo_foundation::Angle::from_degrees;
+
+        // 350° 〜 10°（0°をまたぐ）
+        let arc = Arc3D::xy_arc(center, 1.0, deg(350.0), deg(10.0)).unwrap();
+        assert!(arc.contains_angle(deg(0.0)));
+        assert!(arc.contains_angle(deg(-5.0)));
+        assert!(!arc.contains_angle(deg(180.0)));
+
+        // 全周の円弧は全ての角度を含む
+        let full = Arc3D::xy_arc(center, 1.0, deg(0.0), deg(360.0)).unwrap();
+        assert!(full.contains_angle(deg(90.0)));
+        assert!(full.contains_angle(deg(270.0)));
+    }
+
+    #[test]
+    fn test_split_at() {
+        let center = Point3D::new(0.0, 0.0, 0.0);
+        let deg = geo_foundation::Angle::from_degrees;
+
+        let arc = Arc3D::xy_arc(center, 2.0, deg(300.0), deg(60.0)).unwrap();
+        let (first, second) = arc.split_at(deg(-30.0)).unwrap();
+        assert!((first.angle_span().to_degrees() - 30.0).abs() < 1e-10);
+        assert!((second.angle_span().to_degrees() - 90.0).abs() < 1e-10);
+        assert!(first.end_point().distance_to(&second.start_point()) < 1e-10);
+        assert!(second.end_point().distance_to(&arc.end_point()) < 1e-10);
+        let total = first.arc_length() + second.arc_length();
+        assert!((total - arc.arc_length()).abs() < 1e-10);
+
+        // 全周の円弧も分割できる
+        let full = Arc3D::xy_arc(center, 1.0, deg(0.0), deg(360.0)).unwrap();
+        let (first, second) = full.split_at(deg(90.0)).unwrap();
+        assert!((first.angle_span().to_degrees() - 90.0).abs() < 1e-10);
+        assert!((second.angle_span().to_degrees() - 270.0).abs() < 1e-10);
+
+        assert!(arc.split_at(deg(180.0)).is_none());
+        assert!(arc.split_at(deg(300.0)).is_none());
+    }
 }
@@ -6,17 +6,25 @@
 
 use crate::{Angle, Arc3D, Direction3D, Point3D};
 use analysis::linalg::{matrix::Matrix4x4, vector::Vector3};
-use geo_foundation::{AnalysisTransform3D, Scalar, TransformError};
+use geo_foundation::{
+    check_uniform_scale, tolerance_migration::DefaultTolerances, AnalysisTransform3D, Scalar,
+    TransformError,
+};
 
 /// Arc3D用Analysis Matrix4x4変換モジュール
 pub mod analysis_transform {
     use super::*;
 
     /// Arc3Dの4x4行列変換（円弧構造保持）
+    ///
+    /// 半径は `check_uniform_scale` で求めた一様スケール倍率で拡縮する。
+    /// 特異な行列は `SingularTransform`、円弧が楕円弧になる非一様な行列は `NonUniformScale`。
     pub fn transform_arc_3d<T: Scalar>(
         arc: &Arc3D<T>,
         matrix: &Matrix4x4<T>,
     ) -> Result<Arc3D<T>, TransformError> {
+        let scale_factor = check_uniform_scale(matrix, DefaultTolerances::distance::<T>())?;
+
         // 中心点の変換
         let center_vec = Vector3::new(arc.center().x(), arc.center().y(), arc.center().z());
         let transformed_center_vec = matrix.transform_point_3d(&center_vec);
@@ -60,11 +68,6 @@ pub mod analysis_transform {
             )
         })?;
 
-        // スケール因子の計算（均等スケールを前提）
-        // Matrix4x4からX軸スケールを取得
-        let unit_x = Vector3::new(T::ONE, T::ZERO, T::ZERO);
-        let transformed_x = matrix.transform_vector_3d(&unit_x);
-        let scale_factor = transformed_x.norm();
         let transformed_radius = arc.radius() * scale_factor;
 
         if transformed_radius <= T::ZERO {
@@ -182,14 +185,7 @@ impl<T: Scalar> AnalysisTransform3D<T> for Arc3D<T> {
             ));
         }
 
-        // 非均等スケールの場合は警告（円弧が楕円弧に変形）
-        let uniform_scale = scale_x == scale_y && scale_y == scale_z;
-        if !uniform_scale {
-            return Err(TransformError::InvalidGeometry(
-                "Non-uniform scaling would distort Arc3D into ellipse".to_string(),
-            ));
-        }
-
+        // 非均等スケールは円弧が楕円弧に変形するため、変換時に NonUniformScale になる
         let center_point = center.center();
         let to_origin = analysis_transform::translation_matrix_3d(
             -center_point.x(),
@@ -235,15 +231,10 @@ impl<T: Scalar> AnalysisTransform3D<T> for Arc3D<T> {
             result = result.rotate_analysis(center, axis, angle)?;
         }
 
-        // スケール（均等スケールのみ許可）
+        // スケール（均等スケールのみ許可、非均等なら NonUniformScale）
         if let Some((sx, sy, sz)) = scale {
-            if sx != sy || sy != sz {
-                return Err(TransformError::InvalidGeometry(
-                    "Arc3D requires uniform scaling".to_string(),
-                ));
-            }
             let center = result.clone(); // 現在の円弧を中心として使用
-            result = result.uniform_scale_analysis(&center, sx)?;
+            result = result.scale_analysis(&center, sx, sy, sz)?;
         }
 
         Ok(result)
@@ -375,8 +366,8 @@ mod tests {
 
         assert!(result.is_err());
         match result.unwrap_err() {
-            TransformError::InvalidGeometry(_) => {}
-            _ => panic!("Expected InvalidGeometry error for non-uniform scaling"),
+            TransformError::NonUniformScale(_) => {}
+            _ => panic!("Expected NonUniformScale error for non-uniform scaling"),
         }
     }
 
@@ -468,8 +459,8 @@ mod tests {
 
         assert!(result.is_err());
         match result.unwrap_err() {
-            TransformError::InvalidGeometry(_) => {}
-            _ => panic!("Expected InvalidGeometry error for non-uniform scaling"),
+            TransformError::NonUniformScale(_) => {}
+            _ => panic!("Expected NonUniformScale error for non-uniform scaling"),
         }
     }
 }
@@ -0,0 +1,267 @@
+//! 有界な3Dプリミティブの Bounded3D（タイトな軸平行バウンディングボックス）実装
+//!
+//! 円・楕円とその弧は `center + U·cosθ + V·sinθ` の形で表し、各座標軸の極値を
+//! とる角度 `atan2(V_k, U_k)`（とその反対側）のうち弧の範囲に入るものと端点から
+//! 正確なボックスを求める。無限平面・直線・レイ、軸方向に無限な円柱面・円錐面は
+//! 有界でないため実装しない。
+
+use crate::{
+    Arc3D, BBox3D, Circle3D, ConicalSolid3D, CylindricalSolid3D, Direction3D, Ellipse3D,
+    EllipseArc3D, EllipsoidalSurface3D, LineSegment3D, Point3D, SphericalSolid3D,
+    SphericalSurface3D, TorusSolid3D, TorusSurface3D, Triangle3D, TriangleMesh3D, Vector3D,
+};
+use geo_foundation::{Bounded3D, Scalar};
+
+/// `center` を中心に各軸へ `half` ずつ広げたボックス
+fn centered_box<T: Scalar>(center: Point3D<T>, half: [T; 3]) -> BBox3D<T> {
+    BBox3D::new(
+        Point3D::new(
+            center.x() - half[0],
+            center.y() - half[1],
+            center.z() - half[2],
+        ),
+        Point3D::new(
+            center.x() + half[0],
+            center.y() + half[1],
+            center.z() + half[2],
+        ),
+    )
+}
+
+/// 点列を囲むボックス（空なら原点の大きさ0のボックス）
+fn points_box<T: Scalar>(points: &[Point3D<T>]) -> BBox3D<T> {
+    BBox3D::from_points(points).unwrap_or_else(|| BBox3D::from_point(Point3D::origin()))
+}
+
+/// 法線 `normal` の平面上の半径 `radius` の円の各軸方向の半幅 `r·sqrt(1 - n_k²)`
+fn circle_half_extents<T: Scalar>(normal: Direction3D<T>, radius: T) -> [T; 3] {
+    let n = normal.as_vector();
+    [n.x(), n.y(), n.z()].map(|c| radius * (T::ONE - c * c).max(T::ZERO).sqrt())
+}
+
+/// `center + u·cosθ + v·sinθ`（θ ∈ [start, start + span]）のボックス
+///
+/// `span` が 2π 以上なら全周として各軸の半幅 `sqrt(u_k² + v_k²)` を使う。
+fn conic_arc_box<T: Scalar>(
+    center: Point3D<T>,
+    u: Vector3D<T>,
+    v: Vector3D<T>,
+    start: T,
+    span: T,
+) -> BBox3D<T> {
+    let (u, v) = ([u.x(), u.y(), u.z()], [v.x(), v.y(), v.z()]);
+    if span >= T::TAU {
+        let half = [0, 1, 2].map(|k| (u[k] * u[k] + v[k] * v[k]).sqrt());
+        return centered_box(center, half);
+    }
+
+    let point_at = |theta: T| {
+        let (sin, cos) = (theta.sin(), theta.cos());
+        Point3D::new(
+            center.x() + u[0] * cos + v[0] * sin,
+            center.y() + u[1] * cos + v[1] * sin,
+            center.z() + u[2] * cos + v[2] * sin,
+        )
+    };
+
+    let mut points = vec![point_at(start), point_at(start + span)];
+    for k in 0..3 {
+        let extreme = v[k].atan2(u[k]);
+        for theta in [extreme, extreme + T::PI] {
+            // start からの反時計回りの角度差を [0, 2π) に正規化
+            let diff = theta - start;
+            let offset = diff - T::TAU * (diff / T::TAU).floor();
+            if offset <= span {
+                points.push(point_at(start + offset));
+            }
+        }
+    }
+    points_box(&points)
+}
+
+impl<T: Scalar> Bounded3D<T> for Point3D<T> {
+    type BBox = BBox3D<T>;
+
+    fn bbox(&self) -> BBox3D<T> {
+        BBox3D::from_point(*self)
+    }
+}
+
+impl<T: Scalar> Bounded3D<T> for BBox3D<T> {
+    type BBox = BBox3D<T>;
+
+    fn bbox(&self) -> BBox3D<T> {
+        *self
+    }
+}
+
+impl<T: Scalar> Bounded3D<T> for LineSegment3D<T> {
+    type BBox = BBox3D<T>;
+
+    fn bbox(&self) -> BBox3D<T> {
+        points_box(&[self.start(), self.end()])
+    }
+}
+
+impl<T: Scalar> Bounded3D<T> for Triangle3D<T> {
+    type BBox = BBox3D<T>;
+
+    fn bbox(&self) -> BBox3D<T> {
+        points_box(&[self.vertex_a(), self.vertex_b(), self.vertex_c()])
+    }
+}
+
+/// 空のメッシュは原点の大きさ0のボックス
+impl<T: Scalar> Bounded3D<T> for TriangleMesh3D<T> {
+    type BBox = BBox3D<T>;
+
+    fn bbox(&self) -> BBox3D<T> {
+        points_box(self.vertices())
+    }
+}
+
+impl<T: Scalar> Bounded3D<T> for Circle3D<T> {
+    type BBox = BBox3D<T>;
+
+    fn bbox(&self) -> BBox3D<T> {
+        centered_box(
+            self.center(),
+            circle_half_extents(self.normal(), self.radius()),
+        )
+    }
+}
+
+impl<T: Scalar> Bounded3D<T> for Arc3D<T> {
+    type BBox = BBox3D<T>;
+
+    fn bbox(&self) -> BBox3D<T> {
+        // 角度は開始方向を基準に、法線まわり反時計回りに測る
+        let u = self.start_direction().as_vector();
+        let v = self.normal().as_vector().cross(&u);
+        conic_arc_box(
+            self.center(),
+            u * self.radius(),
+            v * self.radius(),
+            self.start_angle().to_radians(),
+            self.angle_span().to_radians(),
+        )
+    }
+}
+
+impl<T: Scalar> Bounded3D<T> for Ellipse3D<T> {
+    type BBox = BBox3D<T>;
+
+    fn bbox(&self) -> BBox3D<T> {
+        conic_arc_box(
+            self.center(),
+            self.major_axis_direction().as_vector() * self.semi_major_axis(),
+            self.minor_axis_direction().as_vector() * self.semi_minor_axis(),
+            T::ZERO,
+            T::TAU,
+        )
+    }
+}
+
+impl<T: Scalar> Bounded3D<T> for EllipseArc3D<T> {
+    type BBox = BBox3D<T>;
+
+    fn bbox(&self) -> BBox3D<T> {
+        conic_arc_box(
+            self.center(),
+            self.major_axis_direction().as_vector() * self.semi_major(),
+            self.minor_axis_direction().as_vector() * self.semi_minor(),
+            self.start_angle().to_radians(),
+            self.angle_span(),
+        )
+    }
+}
+
+impl<T: Scalar> Bounded3D<T> for ConicalSolid3D<T> {
+    type BBox = BBox3D<T>;
+
+    /// 底面の円のボックスと頂点を合わせたもの
+    fn bbox(&self) -> BBox3D<T> {
+        let base = centered_box(
+            self.center(),
+            circle_half_extents(self.axis(), self.radius()),
+        );
+        base.union(&BBox3D::from_point(self.apex()))
+    }
+}
+
+impl<T: Scalar> Bounded3D<T> for EllipsoidalSurface3D<T> {
+    type BBox = BBox3D<T>;
+
+    /// 各軸方向の半幅は `sqrt(Σ (r_i · e_i,k)²)`（e_i は楕円体の主軸）
+    fn bbox(&self) -> BBox3D<T> {
+        let axes = [
+            self.ref_direction().as_vector() * self.a_radius(),
+            self.derived_y_axis().as_vector() * self.b_radius(),
+            self.axis().as_vector() * self.c_radius(),
+        ];
+        let half = [0, 1, 2].map(|k| {
+            axes.iter()
+                .map(|axis| {
+                    let c = [axis.x(), axis.y(), axis.z()][k];
+                    c * c
+                })
+                .fold(T::ZERO, |sum, c| sum + c)
+                .sqrt()
+        });
+        centered_box(self.center(), half)
+    }
+}
+
+/// トーラスの半幅：中心円（半径 R）の半幅に管の半径 r を加えたもの
+fn torus_box<T: Scalar>(
+    origin: Point3D<T>,
+    z_axis: Direction3D<T>,
+    major_radius: T,
+    minor_radius: T,
+) -> BBox3D<T> {
+    let half = circle_half_extents(z_axis, major_radius).map(|h| h + minor_radius);
+    centered_box(origin, half)
+}
+
+impl<T: Scalar> Bounded3D<T> for TorusSurface3D<T> {
+    type BBox = BBox3D<T>;
+
+    fn bbox(&self) -> BBox3D<T> {
+        torus_box(
+            self.origin(),
+            self.z_axis(),
+            self.major_radius(),
+            self.minor_radius(),
+        )
+    }
+}
+
+impl<T: Scalar> Bounded3D<T> for TorusSolid3D<T> {
+    type BBox = BBox3D<T>;
+
+    fn bbox(&self) -> BBox3D<T> {
+        torus_box(
+            *self.origin(),
+            *self.z_axis(),
+            self.major_radius(),
+            self.minor_radius(),
+        )
+    }
+}
+
+/// 型自身の `bounding_box` が既に正確なもの
+macro_rules! impl_bounded_by_inherent {
+    ($($ty:ident),* $(,)?) => {
+        $(
+            impl<T: Scalar> Bounded3D<T> for $ty<T> {
+                type BBox = BBox3D<T>;
+
+                fn bbox(&self) -> BBox3D<T> {
+                    $ty::bounding_box(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_bounded_by_inherent!(CylindricalSolid3D, SphericalSolid3D, SphericalSurface3D);
@@ -0,0 +1,117 @@
+//! Bounded3D（タイトなバウンディングボックス）のテスト
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        Arc3D, BBox3D, Circle3D, ConicalSolid3D, Direction3D, Ellipse3D, EllipseArc3D,
+        EllipsoidalSurface3D, Point3D, TorusSurface3D, Vector3D,
+    };
+    use geo_foundation::{Angle, Bounded3D};
+
+    fn assert_bbox(bbox: BBox3D<f64>, min: [f64; 3], max: [f64; 3]) {
+        let (lo, hi) = (bbox.min(), bbox.max());
+        for (actual, expected) in [lo.x(), lo.y(), lo.z(), hi.x(), hi.y(), hi.z()]
+            .into_iter()
+            .zip(min.into_iter().chain(max))
+        {
+            assert!(
+                (actual - expected).abs() < 1e-12,
+                "bbox {:?} != {:?}..{:?}",
+                bbox,
+                min,
+                max
+            );
+        }
+    }
+
+    #[test]
+    fn test_arc_bbox_is_tighter_than_circle() {
+        // 45°〜135° の円弧：y の最大は 90° で 1、y の最小は端点の sin45°
+        let arc = Arc3D::xy_arc(
+            Point3D::origin(),
+            1.0,
+            Angle::from_degrees(45.0),
+            Angle::from_degrees(135.0),
+        )
+        .unwrap();
+        let h = std::f64::consts::FRAC_1_SQRT_2;
+        assert_bbox(arc.bbox(), [-h, h, 0.0], [h, 1.0, 0.0]);
+    }
+
+    #[test]
+    fn test_arc_crossing_zero_angle() {
+        // 開始方向 +y、法線 -x：θ は +y から -z へ向かう（y-z 平面）
+        let arc = Arc3D::new(
+            Point3D::new(1.0, 0.0, 0.0),
+            2.0,
+            Direction3D::negative_x(),
+            Direction3D::positive_y(),
+            Angle::from_degrees(300.0),
+            Angle::from_degrees(420.0),
+        )
+        .unwrap();
+        let bbox: BBox3D<f64> = arc.bbox();
+        // 角度範囲は 120° で θ=0（+y 方向、y=2）を含む
+        assert!((bbox.max().y() - 2.0).abs() < 1e-12);
+        assert!(bbox.width().abs() < 1e-12);
+        for i in 0..=32 {
+            let p = arc.point_at_parameter(i as f64 / 32.0);
+            assert!(bbox.contains_point(&p));
+        }
+    }
+
+    #[test]
+    fn test_full_circle_and_ellipse() {
+        let circle =
+            Circle3D::new(Point3D::new(1.0, 2.0, 3.0), Direction3D::positive_x(), 2.0).unwrap();
+        assert_bbox(circle.bbox(), [1.0, 0.0, 1.0], [1.0, 4.0, 5.0]);
+
+        let ellipse = Ellipse3D::xy_aligned(Point3D::origin(), 3.0, 1.0).unwrap();
+        assert_bbox(ellipse.bbox(), [-3.0, -1.0, 0.0], [3.0, 1.0, 0.0]);
+    }
+
+    #[test]
+    fn test_ellipse_arc_bbox() {
+        let ellipse = Ellipse3D::xy_aligned(Point3D::origin(), 3.0, 1.0).unwrap();
+        let arc = EllipseArc3D::new(ellipse, Angle::from_degrees(0.0), Angle::from_degrees(90.0));
+        assert_bbox(arc.bbox(), [0.0, 0.0, 0.0], [3.0, 1.0, 0.0]);
+    }
+
+    #[test]
+    fn test_surfaces_and_solids() {
+        let torus = TorusSurface3D::new(
+            Point3D::origin(),
+            Direction3D::positive_z(),
+            Direction3D::positive_x(),
+            3.0,
+            1.0,
+        )
+        .unwrap();
+        assert_bbox(torus.bbox(), [-4.0, -4.0, -1.0], [4.0, 4.0, 1.0]);
+
+        let cone = ConicalSolid3D::new(
+            Point3D::origin(),
+            Vector3D::new(0.0, 0.0, 1.0),
+            Vector3D::new(1.0, 0.0, 0.0),
+            2.0,
+            5.0,
+        )
+        .unwrap();
+        assert_bbox(cone.bbox(), [-2.0, -2.0, 0.0], [2.0, 2.0, 5.0]);
+
+        let ellipsoid = EllipsoidalSurface3D::new(
+            Point3D::new(1.0, 0.0, 0.0),
+            Vector3D::new(1.0, 0.0, 0.0),
+            Vector3D::new(0.0, 1.0, 0.0),
+            3.0,
+            2.0,
+            1.0,
+        )
+        .unwrap();
+        // 軸が x、参照方向が y なので a は y 方向、c は x 方向
+        let bbox: BBox3D<f64> = ellipsoid.bbox();
+        assert!((bbox.width() - 2.0).abs() < 1e-12);
+        assert!((bbox.height() - 6.0).abs() < 1e-12);
+        assert!((bbox.depth() - 4.0).abs() < 1e-12);
+    }
+}
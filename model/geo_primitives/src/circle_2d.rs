@@ -14,8 +14,11 @@ pub struct Circle2D<T: Scalar> {
 
 impl<T: Scalar> Circle2D<T> {
     /// 新しい円を作成
+    ///
+    /// 半径が正でない場合や、中心・半径に NaN / 無限大が含まれる場合は `None`
     pub fn new(center: Point2D<T>, radius: T) -> Option<Self> {
-        if radius > T::ZERO {
+        let finite = center.x().is_finite() && center.y().is_finite() && radius.is_finite();
+        if finite && radius > T::ZERO {
             Some(Self { center, radius })
         } else {
             None
@@ -0,0 +1,202 @@
+//! Circle2D 交点計算Foundation実装
+//!
+//! 無限直線との交点（0/1/2点）を直線パラメータで求め、線分ではパラメータ範囲で絞り込む。
+//! 円同士は中心間距離を半径の和・差と比べて交わり方を分類する。
+
+use crate::{Circle2D, InfiniteLine2D, LineSegment2D, Point2D, Vector2D};
+use geo_foundation::{tolerance_migration::DefaultTolerances, MultipleIntersection, Scalar};
+
+/// 2つの円の交わり方
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CircleCircleIntersection<T: Scalar> {
+    /// 互いの外側にあって交わらない
+    Separate,
+    /// 一方が他方の内側にあって交わらない
+    Contained,
+    /// 外接（接点）
+    ExternalTangent(Point2D<T>),
+    /// 内接（接点）
+    InternalTangent(Point2D<T>),
+    /// 2点で交わる（自分の中心から相手の中心へ向かって左側、右側の順）
+    TwoPoints(Point2D<T>, Point2D<T>),
+    /// 同心で半径が異なる
+    Concentric,
+    /// 同一の円
+    Coincident,
+}
+
+impl<T: Scalar> CircleCircleIntersection<T> {
+    /// 円周同士が共有点を持つかどうか
+    pub fn is_intersecting(&self) -> bool {
+        matches!(
+            self,
+            Self::ExternalTangent(_)
+                | Self::InternalTangent(_)
+                | Self::TwoPoints(_, _)
+                | Self::Coincident
+        )
+    }
+
+    /// 交点（接点は1点、同一の円では空）
+    pub fn points(&self) -> Vec<Point2D<T>> {
+        match *self {
+            Self::ExternalTangent(point) | Self::InternalTangent(point) => vec![point],
+            Self::TwoPoints(first, second) => vec![first, second],
+            _ => Vec::new(),
+        }
+    }
+}
+
+impl<T: Scalar> Circle2D<T> {
+    /// 無限直線との交点（デフォルト許容誤差）
+    pub fn intersect_line(&self, line: &InfiniteLine2D<T>) -> Vec<Point2D<T>> {
+        self.intersect_line_with_tolerance(line, DefaultTolerances::distance::<T>())
+    }
+
+    /// 無限直線との交点（距離許容誤差を指定）
+    ///
+    /// 中心と直線の距離が半径と `tolerance` 以内で一致する場合は接するものとし、
+    /// 中心から直線への垂線の足を1点だけ返す。交点は直線の向きの順に並ぶ。
+    pub fn intersect_line_with_tolerance(
+        &self,
+        line: &InfiniteLine2D<T>,
+        tolerance: T,
+    ) -> Vec<Point2D<T>> {
+        self.line_parameters(line, tolerance)
+            .into_iter()
+            .map(|t| line.point_at_parameter(t))
+            .collect()
+    }
+
+    /// 線分との交点（デフォルト許容誤差）
+    pub fn intersect(&self, segment: &LineSegment2D<T>) -> Vec<Point2D<T>> {
+        self.intersect_with_tolerance(segment, DefaultTolerances::distance::<T>())
+    }
+
+    /// 線分との交点（距離許容誤差を指定）
+    ///
+    /// 直線との交点のうち、線分の範囲に `tolerance` 込みで収まるものを始点側から順に返す。
+    /// 端点から `tolerance` 以内の交点は端点そのものに吸着させる。
+    pub fn intersect_with_tolerance(
+        &self,
+        segment: &LineSegment2D<T>,
+        tolerance: T,
+    ) -> Vec<Point2D<T>> {
+        let start = segment.start_parameter();
+        let end = segment.end_parameter();
+        self.line_parameters(segment.line(), tolerance)
+            .into_iter()
+            .filter(|&t| t >= start - tolerance && t <= end + tolerance)
+            .map(|t| {
+                if (t - start).abs() <= tolerance {
+                    segment.start_point()
+                } else if (t - end).abs() <= tolerance {
+                    segment.end_point()
+                } else {
+                    segment.line().point_at_parameter(t)
+                }
+            })
+            .collect()
+    }
+
+    /// 他の円との交わり方（デフォルト許容誤差）
+    pub fn intersect_circle(&self, other: &Circle2D<T>) -> CircleCircleIntersection<T> {
+        self.intersect_circle_with_tolerance(other, DefaultTolerances::distance::<T>())
+    }
+
+    /// 他の円との交わり方（距離許容誤差を指定）
+    ///
+    /// 中心間距離が半径の和・差と `tolerance` 以内で一致すれば外接・内接とする。
+    pub fn intersect_circle_with_tolerance(
+        &self,
+        other: &Circle2D<T>,
+        tolerance: T,
+    ) -> CircleCircleIntersection<T> {
+        let (r1, r2) = (self.radius(), other.radius());
+        let between = Vector2D::from_points(self.center(), other.center());
+        let distance = between.length();
+
+        if distance <= tolerance {
+            return if (r1 - r2).abs() <= tolerance {
+                CircleCircleIntersection::Coincident
+            } else {
+                CircleCircleIntersection::Concentric
+            };
+        }
+
+        let sum = r1 + r2;
+        let difference = (r1 - r2).abs();
+        if distance > sum + tolerance {
+            return CircleCircleIntersection::Separate;
+        }
+        if distance < difference - tolerance {
+            return CircleCircleIntersection::Contained;
+        }
+
+        // 交点を結ぶ弦と中心線の交点までの、自分の中心からの距離（符号付き）
+        let unit = between / distance;
+        let along = (distance * distance + r1 * r1 - r2 * r2) / (distance + distance);
+        if (distance - sum).abs() <= tolerance {
+            // 接点は中心線上（許容誤差分のずれは自分の円周へ寄せる）
+            return CircleCircleIntersection::ExternalTangent(self.center() + unit * r1);
+        }
+        if (distance - difference).abs() <= tolerance {
+            let toward = if along < T::ZERO { -r1 } else { r1 };
+            return CircleCircleIntersection::InternalTangent(self.center() + unit * toward);
+        }
+
+        let half_chord = (r1 * r1 - along * along).max(T::ZERO).sqrt();
+        let base = self.center() + unit * along;
+        let left = Vector2D::new(-unit.y(), unit.x()) * half_chord;
+        CircleCircleIntersection::TwoPoints(base + left, base - left)
+    }
+
+    /// 直線上の交点のパラメータ（昇順、接する場合は1つ）
+    fn line_parameters(&self, line: &InfiniteLine2D<T>, tolerance: T) -> Vec<T> {
+        // 中心から直線への垂線の足のパラメータと、中心からの距離
+        let foot = line.parameter_for_point(&self.center());
+        let distance = Vector2D::from_points(line.point_at_parameter(foot), self.center()).length();
+        let radius = self.radius();
+
+        if distance > radius + tolerance {
+            Vec::new()
+        } else if (distance - radius).abs() <= tolerance {
+            vec![foot]
+        } else {
+            let half_chord = (radius * radius - distance * distance).sqrt();
+            vec![foot - half_chord, foot + half_chord]
+        }
+    }
+}
+
+// ============================================================================
+// MultipleIntersection Implementations
+// ============================================================================
+
+// Circle2D vs InfiniteLine2D
+impl<T: Scalar> MultipleIntersection<T, InfiniteLine2D<T>> for Circle2D<T> {
+    type Point = Point2D<T>;
+
+    fn intersections_with(&self, other: &InfiniteLine2D<T>, tolerance: T) -> Vec<Self::Point> {
+        self.intersect_line_with_tolerance(other, tolerance)
+    }
+}
+
+// Circle2D vs Circle2D（同一の円では空）
+impl<T: Scalar> MultipleIntersection<T, Circle2D<T>> for Circle2D<T> {
+    type Point = Point2D<T>;
+
+    fn intersections_with(&self, other: &Circle2D<T>, tolerance: T) -> Vec<Self::Point> {
+        self.intersect_circle_with_tolerance(other, tolerance)
+            .points()
+    }
+}
+
+// Circle2D vs LineSegment2D
+impl<T: Scalar> MultipleIntersection<T, LineSegment2D<T>> for Circle2D<T> {
+    type Point = Point2D<T>;
+
+    fn intersections_with(&self, other: &LineSegment2D<T>, tolerance: T) -> Vec<Self::Point> {
+        self.intersect_with_tolerance(other, tolerance)
+    }
+}
@@ -0,0 +1,168 @@
+//! Circle2D 交点計算のテスト
+
+use crate::{Circle2D, CircleCircleIntersection, InfiniteLine2D, LineSegment2D, Point2D, Vector2D};
+use geo_foundation::MultipleIntersection;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unit_circle() -> Circle2D<f64> {
+        Circle2D::new(Point2D::new(0.0, 0.0), 1.0).unwrap()
+    }
+
+    fn segment(x0: f64, y0: f64, x1: f64, y1: f64) -> LineSegment2D<f64> {
+        LineSegment2D::new(Point2D::new(x0, y0), Point2D::new(x1, y1)).unwrap()
+    }
+
+    fn assert_point(actual: Point2D<f64>, x: f64, y: f64) {
+        assert!(
+            actual.distance_to(&Point2D::new(x, y)) < 1e-12,
+            "{actual:?} != ({x}, {y})"
+        );
+    }
+
+    #[test]
+    fn test_segment_through_circle() {
+        let points = unit_circle().intersect(&segment(-2.0, 0.0, 2.0, 0.0));
+        assert_eq!(points.len(), 2);
+        // 線分の始点側から順に並ぶ
+        assert_point(points[0], -1.0, 0.0);
+        assert_point(points[1], 1.0, 0.0);
+
+        let reversed = unit_circle().intersect(&segment(2.0, 0.0, -2.0, 0.0));
+        assert_point(reversed[0], 1.0, 0.0);
+    }
+
+    #[test]
+    fn test_segment_range_filter() {
+        let circle = unit_circle();
+        // 片方の交点だけが線分上
+        let points = circle.intersect(&segment(0.0, 0.0, 0.0, 3.0));
+        assert_eq!(points.len(), 1);
+        assert_point(points[0], 0.0, 1.0);
+
+        // 円の内側に収まる線分・円から離れた線分は交点なし
+        assert!(circle.intersect(&segment(-0.5, 0.0, 0.5, 0.0)).is_empty());
+        assert!(circle.intersect(&segment(2.0, 0.0, 3.0, 0.0)).is_empty());
+
+        // 端点がちょうど円周上なら端点そのもの
+        let end = Point2D::new(0.6, 0.8);
+        let points = circle.intersect(&LineSegment2D::new(Point2D::new(0.0, 0.0), end).unwrap());
+        assert_eq!(points, vec![end]);
+    }
+
+    #[test]
+    fn test_tangent_gives_single_point() {
+        let circle = unit_circle();
+        let points = circle.intersect(&segment(-2.0, 1.0, 2.0, 1.0));
+        assert_eq!(points.len(), 1);
+        assert_point(points[0], 0.0, 1.0);
+
+        // 許容誤差内で円周から外れていても接点として1点にまとめる
+        let points =
+            circle.intersect_with_tolerance(&segment(-2.0, 1.0 + 1e-7, 2.0, 1.0 + 1e-7), 1e-6);
+        assert_eq!(points.len(), 1);
+        let points =
+            circle.intersect_with_tolerance(&segment(-2.0, 1.0 - 1e-7, 2.0, 1.0 - 1e-7), 1e-6);
+        assert_eq!(points.len(), 1);
+
+        // 許容誤差を超えて離れていれば交点なし
+        assert!(circle
+            .intersect_with_tolerance(&segment(-2.0, 1.0 + 1e-5, 2.0, 1.0 + 1e-5), 1e-6)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_infinite_line() {
+        let circle = Circle2D::new(Point2D::new(1.0, 1.0), 2.0).unwrap();
+        let line = InfiniteLine2D::new(Point2D::new(10.0, 1.0), Vector2D::new(-1.0, 0.0)).unwrap();
+
+        // 直線の向きの順に並ぶ
+        let points = circle.intersect_line(&line);
+        assert_eq!(points.len(), 2);
+        assert_point(points[0], 3.0, 1.0);
+        assert_point(points[1], -1.0, 1.0);
+
+        let via_trait = circle.intersections_with(&line, 1e-10);
+        assert_eq!(via_trait, points);
+    }
+
+    fn circle(x: f64, y: f64, r: f64) -> Circle2D<f64> {
+        Circle2D::new(Point2D::new(x, y), r).unwrap()
+    }
+
+    #[test]
+    fn test_circles_crossing_at_two_points() {
+        // 3-4-5 の関係で交点は (3, ±4)
+        let result = circle(0.0, 0.0, 5.0).intersect_circle(&circle(6.0, 0.0, 5.0));
+        let CircleCircleIntersection::TwoPoints(left, right) = result else {
+            panic!("expected two points: {result:?}");
+        };
+        // 中心線 (+X 方向) の左側が先
+        assert_point(left, 3.0, 4.0);
+        assert_point(right, 3.0, -4.0);
+        assert!(result.is_intersecting());
+
+        // 交点はどちらの円周上にもある
+        let a = circle(0.3, -1.2, 2.0);
+        let b = circle(1.9, 0.4, 1.3);
+        let points = a.intersections_with(&b, 1e-10);
+        assert_eq!(points.len(), 2);
+        for p in points {
+            assert!((p.distance_to(&a.center()) - 2.0).abs() < 1e-12);
+            assert!((p.distance_to(&b.center()) - 1.3).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_tangent_circles() {
+        let unit = unit_circle();
+        match unit.intersect_circle(&circle(0.0, 3.0, 2.0)) {
+            CircleCircleIntersection::ExternalTangent(p) => assert_point(p, 0.0, 1.0),
+            other => panic!("expected external tangent: {other:?}"),
+        }
+
+        // 内接は大小どちらの円から見ても同じ接点
+        let large = circle(1.0, 0.0, 2.0);
+        match unit.intersect_circle(&large) {
+            CircleCircleIntersection::InternalTangent(p) => assert_point(p, -1.0, 0.0),
+            other => panic!("expected internal tangent: {other:?}"),
+        }
+        match large.intersect_circle(&unit) {
+            CircleCircleIntersection::InternalTangent(p) => assert_point(p, -1.0, 0.0),
+            other => panic!("expected internal tangent: {other:?}"),
+        }
+
+        // 許容誤差内の隙間は接するものとする
+        let nearly = circle(2.0 + 1e-7, 0.0, 1.0);
+        assert_eq!(
+            unit.intersect_circle_with_tolerance(&nearly, 1e-6)
+                .points()
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_non_intersecting_circles() {
+        let unit = unit_circle();
+        assert_eq!(
+            unit.intersect_circle(&circle(3.0, 0.0, 1.0)),
+            CircleCircleIntersection::Separate
+        );
+        assert_eq!(
+            unit.intersect_circle(&circle(0.2, 0.0, 3.0)),
+            CircleCircleIntersection::Contained
+        );
+        assert_eq!(
+            unit.intersect_circle(&circle(0.0, 0.0, 2.0)),
+            CircleCircleIntersection::Concentric
+        );
+        let same = unit.intersect_circle(&unit_circle());
+        assert_eq!(same, CircleCircleIntersection::Coincident);
+        assert!(same.is_intersecting());
+        assert!(same.points().is_empty());
+        assert!(!CircleCircleIntersection::<f64>::Separate.is_intersecting());
+    }
+}
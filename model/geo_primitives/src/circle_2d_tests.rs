@@ -38,6 +38,11 @@ fn test_circle2d_invalid_creation() {
 
     // ゼロ半径
     assert!(Circle2D::new(center, 0.0).is_none());
+
+    // 非有限の半径・中心
+    assert!(Circle2D::new(center, f64::NAN).is_none());
+    assert!(Circle2D::new(center, f64::INFINITY).is_none());
+    assert!(Circle2D::new(Point2D::new(f64::NAN, 0.0), 1.0).is_none());
 }
 
 /// 単位円テスト
@@ -25,9 +25,13 @@ impl<T: Scalar> Circle3D<T> {
     ///
     /// # 戻り値
     /// * `Some(Circle3D)` - 有効な円が作成できた場合
-    /// * `None` - 半径が0以下の場合
+    /// * `None` - 半径が0以下の場合、または中心・半径に NaN / 無限大が含まれる場合
     pub fn new(center: Point3D<T>, normal: Direction3D<T>, radius: T) -> Option<Self> {
-        if radius <= T::ZERO {
+        let finite = center.x().is_finite()
+            && center.y().is_finite()
+            && center.z().is_finite()
+            && radius.is_finite();
+        if !finite || radius <= T::ZERO {
             return None;
         }
 
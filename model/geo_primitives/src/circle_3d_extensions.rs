@@ -1,9 +1,21 @@
 //! Circle3D Extensions - Advanced geometric operations and calculations
 //!
-//! 3次元円の拡張メソッド：軸取得、点の計算、距離計算、平面基底計算など
-
-use crate::{Circle3D, Direction3D, Point3D, Vector3D};
-use geo_foundation::{tolerance_migration::DefaultTolerances, Scalar};
+//! 3次元円の拡張メソッド：軸取得、点の計算、距離計算、平面基底計算、変換による楕円化など
+
+use crate::{Circle3D, Direction3D, Ellipse3D, Point3D, Vector3D};
+use analysis::linalg::vector::Vector3;
+use geo_foundation::{tolerance_migration::DefaultTolerances, Scalar, Transform3D, TransformError};
+
+/// 円に変換を適用した結果
+///
+/// 一様スケール（相似変換）なら円のまま、非一様スケールなら楕円になる。
+#[derive(Debug, Clone, PartialEq)]
+pub enum CircleTransformResult<T: Scalar> {
+    /// 円のまま
+    Circle(Circle3D<T>),
+    /// 楕円に変化
+    Ellipse(Ellipse3D<T>),
+}
 
 impl<T: Scalar> Circle3D<T> {
     /// 円平面のU軸（基準軸）を取得
@@ -245,4 +257,69 @@ impl<T: Scalar> Circle3D<T> {
             self.center().z() + self.radius() * normalized_direction.z(),
         )
     }
+
+    /// 変換を適用し、結果を円または楕円として返す
+    ///
+    /// 円周の像 `c' + r·cosθ·A·u + r·sinθ·A·v`（A は変換の線形部分）の主軸を
+    /// 2x2 Gram行列の固有分解から求め、長短半径が等しければ円、異なれば楕円とする。
+    /// 円平面が線分や点に潰れる場合は `TransformError::SingularTransform`。
+    pub fn transform_to_ellipse(
+        &self,
+        transform: &Transform3D<T>,
+    ) -> Result<CircleTransformResult<T>, TransformError> {
+        let tolerance = DefaultTolerances::distance::<T>();
+        let to_analysis = |v: Vector3D<T>| Vector3::new(v.x(), v.y(), v.z());
+        let from_analysis = |v: Vector3<T>| Vector3D::new(v.x(), v.y(), v.z());
+
+        let center = self.center();
+        let center =
+            from_analysis(transform.apply_point(&Vector3::new(center.x(), center.y(), center.z())));
+        let (u, v) = self.get_plane_basis();
+        let a = from_analysis(transform.apply_vector(&to_analysis(u))) * self.radius();
+        let b = from_analysis(transform.apply_vector(&to_analysis(v))) * self.radius();
+
+        // 変換後の平面法線（基底の向きが法線と逆なら反転して向きを保つ）
+        let cross = a.cross(&b);
+        if cross.length() <= tolerance * (a.length_squared() + b.length_squared()) {
+            return Err(TransformError::SingularTransform(
+                "circle collapses onto a line or point".to_string(),
+            ));
+        }
+        let normal = if u.cross(&v).dot(&self.normal().as_vector()) < T::ZERO {
+            -cross
+        } else {
+            cross
+        };
+
+        // Gram行列 [[a·a, a·b], [a·b, b·b]] の固有値が半径の2乗、主軸角 θ で長軸方向が決まる
+        let aa = a.length_squared();
+        let bb = b.length_squared();
+        let ab = a.dot(&b);
+        let half = T::ONE / (T::ONE + T::ONE);
+        let mean = (aa + bb) * half;
+        let deviation = (((aa - bb) * half) * ((aa - bb) * half) + ab * ab).sqrt();
+        let semi_major = (mean + deviation).sqrt();
+        let semi_minor = (mean - deviation).max(T::ZERO).sqrt();
+
+        if semi_major - semi_minor <= tolerance * semi_major {
+            let normal = Direction3D::from_vector(normal).ok_or_else(|| {
+                TransformError::InvalidGeometry("zero normal after transform".to_string())
+            })?;
+            let circle = Circle3D::new(Point3D::from_vector(center), normal, semi_major)
+                .ok_or_else(|| TransformError::InvalidGeometry("invalid circle".to_string()))?;
+            return Ok(CircleTransformResult::Circle(circle));
+        }
+
+        let theta = (ab + ab).atan2(aa - bb) * half;
+        let major_dir = a * theta.cos() + b * theta.sin();
+        Ellipse3D::new(
+            Point3D::from_vector(center),
+            semi_major,
+            semi_minor,
+            normal,
+            major_dir,
+        )
+        .map(CircleTransformResult::Ellipse)
+        .ok_or_else(|| TransformError::InvalidGeometry("invalid ellipse".to_string()))
+    }
 }
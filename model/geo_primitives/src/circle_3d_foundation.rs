@@ -1,8 +1,8 @@
 //! Circle3D の Foundation トレイト実装
 
-use crate::{BBox3D, Circle3D};
+use crate::{BBox3D, Circle3D, Point3D};
 use geo_foundation::{
-    extension_foundation::ExtensionFoundation, PrimitiveKind, Scalar, TolerantEq,
+    extension_foundation::ExtensionFoundation, PointDistance, PrimitiveKind, Scalar, TolerantEq,
 };
 
 // ============================================================================
@@ -89,6 +89,31 @@ impl<T: Scalar> TolerantEq<T> for Circle3D<T> {
     }
 }
 
+impl<T: Scalar> PointDistance<T> for Circle3D<T> {
+    type Point2D = Point3D<T>;
+
+    /// 円周までの最短距離
+    fn distance_to_point(&self, point: &Point3D<T>) -> T {
+        self.distance_to_circle(point)
+    }
+
+    /// 点が円板（円周を含む）上にあるか
+    fn contains_point(&self, point: &Point3D<T>, tolerance: T) -> bool {
+        self.point_on_plane(point, tolerance)
+            && self.distance_to_center(point) <= self.radius() + tolerance
+    }
+
+    /// 点が円周上にあるか
+    fn point_on_boundary(&self, point: &Point3D<T>, tolerance: T) -> bool {
+        self.distance_to_circle(point) <= tolerance
+    }
+
+    /// 円周上の最近点（軸上の点では円周上の任意の点）
+    fn closest_point(&self, point: &Point3D<T>) -> Point3D<T> {
+        self.closest_point_on_circle(point)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -124,4 +149,26 @@ mod tests {
         assert!(circle1.tolerant_eq(&circle2, tolerance));
         assert!(!circle1.tolerant_eq(&circle3, tolerance));
     }
+
+    #[test]
+    fn test_point_distance() {
+        let circle = Circle3D::new_xy_plane(Point3D::new(0.0, 0.0, 0.0), 2.0).unwrap();
+
+        // 平面外の点：円周上の最近点までの3D距離
+        let (distance, closest) = circle.distance_to_with_closest(&Point3D::new(5.0, 0.0, 4.0));
+        assert!((distance - 5.0).abs() < 1e-10);
+        assert!(closest.distance_to(&Point3D::new(2.0, 0.0, 0.0)) < 1e-10);
+
+        // 軸上の点：どの円周上の点も等距離
+        let on_axis = Point3D::new(0.0, 0.0, 1.5);
+        let (distance, closest) = circle.distance_to_with_closest(&on_axis);
+        assert!((distance - 2.5).abs() < 1e-10);
+        assert!((closest.distance_to(&on_axis) - distance).abs() < 1e-10);
+
+        // 円板の内部は含むが境界ではない
+        let inside = Point3D::new(0.5, 0.5, 0.0);
+        assert!(circle.contains_point(&inside, 1e-10));
+        assert!(!circle.point_on_boundary(&inside, 1e-10));
+        assert!(circle.point_on_boundary(&Point3D::new(0.0, -2.0, 0.0), 1e-10));
+    }
 }
@@ -4,8 +4,10 @@
 
 #[cfg(test)]
 mod tests {
-    use crate::{Circle3D, Direction3D, Point3D, Vector3D};
+    use crate::{Circle3D, CircleTransformResult, Direction3D, Point3D, Vector3D};
+    use analysis::linalg::vector::Vector3;
     use geo_foundation::tolerance_migration::DefaultTolerances;
+    use geo_foundation::{Angle, Transform3D, TransformError};
 
     // テスト用のf64型別名
     type TestScalar = f64;
@@ -30,6 +32,11 @@ mod tests {
         // 無効な半径での作成失敗
         assert!(Circle3D::new(center, normal, 0.0).is_none());
         assert!(Circle3D::new(center, normal, -1.0).is_none());
+
+        // 非有限の半径・中心での作成失敗
+        assert!(Circle3D::new(center, normal, f64::NAN).is_none());
+        assert!(Circle3D::new(center, normal, f64::INFINITY).is_none());
+        assert!(Circle3D::new(Point3D::new(0.0, f64::INFINITY, 0.0), normal, 1.0).is_none());
     }
 
     #[test]
@@ -161,4 +168,72 @@ mod tests {
             assert_approx_eq(distance, radius, 1e-10);
         }
     }
+
+    #[test]
+    fn test_transform_to_ellipse_uniform_stays_circle() {
+        let circle = Circle3D::new_xy_plane(Point3D::new(1.0, 0.0, 0.0), 2.0).unwrap();
+        let transform = Transform3D::identity()
+            .rotate(&Vector3::new(1.0, 0.0, 0.0), Angle::from_degrees(90.0))
+            .unwrap()
+            .uniform_scale(1.5)
+            .translate(&Vector3::new(0.0, 0.0, 4.0));
+
+        let CircleTransformResult::Circle(result) =
+            circle.transform_to_ellipse(&transform).unwrap()
+        else {
+            panic!("uniform scale should keep a circle");
+        };
+        assert_approx_eq(result.radius(), 3.0, 1e-10);
+        assert!(result.center().distance_to(&Point3D::new(1.5, 0.0, 4.0)) < 1e-10);
+        // z軸の法線は x軸周り90度回転で -y 方向へ
+        assert_approx_eq(result.normal().y(), -1.0, 1e-10);
+
+        // 法線方向だけのスケールは円を変えない
+        let squash = Transform3D::identity().scale(1.0, 1.0, 5.0);
+        assert!(matches!(
+            circle.transform_to_ellipse(&squash),
+            Ok(CircleTransformResult::Circle(_))
+        ));
+    }
+
+    #[test]
+    fn test_transform_to_ellipse_non_uniform() {
+        let circle = Circle3D::new_xy_plane(Point3D::origin(), 1.0).unwrap();
+        // x方向に3倍してから z軸周りに90度回転：長軸は y方向
+        let transform = Transform3D::identity()
+            .scale(3.0, 1.0, 1.0)
+            .rotate(&Vector3::new(0.0, 0.0, 1.0), Angle::from_degrees(90.0))
+            .unwrap();
+
+        let CircleTransformResult::Ellipse(ellipse) =
+            circle.transform_to_ellipse(&transform).unwrap()
+        else {
+            panic!("non-uniform scale should produce an ellipse");
+        };
+        assert_approx_eq(ellipse.semi_major_axis(), 3.0, 1e-10);
+        assert_approx_eq(ellipse.semi_minor_axis(), 1.0, 1e-10);
+        assert_approx_eq(ellipse.major_axis_direction().y().abs(), 1.0, 1e-10);
+        assert_approx_eq(ellipse.normal().z(), 1.0, 1e-10);
+
+        // 楕円上の点は元の円周上の点の像と一致する
+        for i in 0..8 {
+            let angle = i as f64 * std::f64::consts::FRAC_PI_4;
+            let point = circle.point_at_angle(angle);
+            let mapped = transform.apply_point(&Vector3::new(point.x(), point.y(), point.z()));
+            let mapped = Point3D::new(mapped.x(), mapped.y(), mapped.z());
+            let on_ellipse = (mapped.x() / ellipse.semi_minor_axis()).powi(2)
+                + (mapped.y() / ellipse.semi_major_axis()).powi(2);
+            assert_approx_eq(on_ellipse, 1.0, 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_transform_to_ellipse_collapsed_plane() {
+        let circle = Circle3D::new_xy_plane(Point3D::origin(), 1.0).unwrap();
+        let flatten = Transform3D::identity().scale(1.0, 0.0, 1.0);
+        assert!(matches!(
+            circle.transform_to_ellipse(&flatten),
+            Err(TransformError::SingularTransform(_))
+        ));
+    }
 }
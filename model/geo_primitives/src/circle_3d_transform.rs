@@ -5,7 +5,10 @@
 
 use crate::{Circle3D, Direction3D, Point3D, Vector3D};
 use analysis::linalg::{matrix::Matrix4x4, vector::Vector3};
-use geo_foundation::{AnalysisTransform3D, Angle, Scalar, TransformError};
+use geo_foundation::{
+    check_uniform_scale, tolerance_migration::DefaultTolerances, AnalysisTransform3D, Angle,
+    Scalar, TransformError,
+};
 
 /// Circle3D用Analysis Matrix/Vector変換モジュール
 pub mod analysis_transform {
@@ -14,11 +17,14 @@ pub mod analysis_transform {
     /// 単一円の行列変換
     ///
     /// Matrix4x4による中心点・法線ベクトル・半径の統合変換
-    /// 半径のスケーリングはmatrixからスケール成分を抽出して適用
+    /// 半径は `check_uniform_scale` で求めた一様スケール倍率で拡縮する。
+    /// 特異な行列は `SingularTransform`、円が楕円になる非一様な行列は `NonUniformScale`。
     pub fn transform_circle_3d<T: Scalar>(
         circle: &Circle3D<T>,
         matrix: &Matrix4x4<T>,
     ) -> Result<Circle3D<T>, TransformError> {
+        let scale_factor = check_uniform_scale(matrix, DefaultTolerances::distance::<T>())?;
+
         // 中心点の変換
         let center_vec: Vector3<T> = Vector3::new(
             circle.center().x(),
@@ -53,17 +59,6 @@ pub mod analysis_transform {
             TransformError::InvalidGeometry("Failed to normalize normal vector".to_string())
         })?;
 
-        // 半径のスケール変換（X軸ベースでスケール係数を計算）
-        let unit_x = Vector3::new(T::ONE, T::ZERO, T::ZERO);
-        let scaled_x = matrix.transform_vector_3d(&unit_x);
-        let scale_factor = scaled_x.norm();
-
-        if scale_factor <= T::ZERO {
-            return Err(TransformError::InvalidGeometry(
-                "Invalid scale factor for radius".to_string(),
-            ));
-        }
-
         let new_radius = circle.radius() * scale_factor;
 
         Circle3D::new(new_center, new_normal, new_radius).ok_or_else(|| {
@@ -180,14 +175,9 @@ impl<T: Scalar> AnalysisTransform3D<T> for Circle3D<T> {
         scale_y: T,
         scale_z: T,
     ) -> Result<Self::Output, TransformError> {
-        // 円の非均等スケールは楕円になるため、均等スケールのみ許可
-        if !((scale_x - scale_y).abs() < T::EPSILON && (scale_y - scale_z).abs() < T::EPSILON) {
-            return Err(TransformError::InvalidGeometry(
-                "Non-uniform scale not supported for Circle3D".to_string(),
-            ));
-        }
-
-        let matrix = analysis_transform::uniform_scale_matrix_3d(&center.center(), scale_x)?;
+        // 円の非均等スケールは楕円になるため、変換時に NonUniformScale になる
+        let matrix =
+            analysis_transform::scale_matrix_3d(&center.center(), scale_x, scale_y, scale_z)?;
         analysis_transform::transform_circle_3d(self, &matrix)
     }
 
@@ -220,18 +210,13 @@ impl<T: Scalar> AnalysisTransform3D<T> for Circle3D<T> {
             result = result.rotate_analysis(center, axis, angle)?;
         }
 
-        // スケール（均等スケールのみ）
+        // スケール（均等スケールのみ、非均等なら NonUniformScale）
         if let Some((sx, sy, sz)) = scale {
-            if !((sx - sy).abs() < T::EPSILON && (sy - sz).abs() < T::EPSILON) {
-                return Err(TransformError::InvalidGeometry(
-                    "Non-uniform scale not supported for Circle3D".to_string(),
-                ));
-            }
             let center_circle = Circle3D::new(result.center(), result.normal(), T::ONE)
                 .ok_or_else(|| {
                     TransformError::InvalidGeometry("Failed to create center circle".to_string())
                 })?;
-            result = result.uniform_scale_analysis(&center_circle, sx)?;
+            result = result.scale_analysis(&center_circle, sx, sy, sz)?;
         }
 
         Ok(result)
@@ -500,8 +485,44 @@ mod tests {
 
         assert!(result.is_err());
         match result {
-            Err(TransformError::InvalidGeometry(_)) => {}
-            _ => panic!("Expected InvalidGeometry error for non-uniform scale"),
+            Err(TransformError::NonUniformScale(_)) => {}
+            _ => panic!("Expected NonUniformScale error for non-uniform scale"),
         }
+
+        // 任意の行列でも同様（X軸だけのスケールから半径を決めない）
+        let stretched = Matrix4x4::scale_3d(&Vector3::new(1.0_f64, 3.0, 1.0));
+        assert!(matches!(
+            analysis_transform::transform_circle_3d(&circle, &stretched),
+            Err(TransformError::NonUniformScale(_))
+        ));
+    }
+
+    #[test]
+    fn test_matrix_transform_guards() {
+        let circle = Circle3D::new(
+            Point3D::new(1.0_f64, 2.0, 3.0),
+            Direction3D::from_vector(Vector3D::unit_z()).unwrap(),
+            2.0,
+        )
+        .unwrap();
+
+        // 回転＋一様スケールでは半径がスケール倍率で拡縮される
+        let rotation = Matrix4x4::rotation_axis(&Vector3::new(1.0_f64, 0.0, 0.0), 0.6);
+        let similarity = rotation * Matrix4x4::uniform_scale_3d(2.5);
+        let transformed = analysis_transform::transform_circle_3d(&circle, &similarity).unwrap();
+        assert!((transformed.radius() - 5.0).abs() < 1e-10);
+
+        // 小さな一様スケールは特異扱いしない
+        let tiny =
+            analysis_transform::transform_circle_3d(&circle, &Matrix4x4::uniform_scale_3d(1e-4))
+                .unwrap();
+        assert!((tiny.radius() - 2e-4).abs() < 1e-16);
+
+        // 潰れる行列は特異
+        let flattened = Matrix4x4::scale_3d(&Vector3::new(1.0_f64, 1.0, 0.0));
+        assert!(matches!(
+            analysis_transform::transform_circle_3d(&circle, &flattened),
+            Err(TransformError::SingularTransform(_))
+        ));
     }
 }
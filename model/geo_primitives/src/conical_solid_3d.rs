@@ -243,13 +243,21 @@ impl<T: Scalar> ConicalSolid3D<T> {
         T::PI * self.radius() * self.radius() * self.height() / T::from_f64(3.0)
     }
 
+    /// 円錐の側面積を計算（底面を含まない）
+    ///
+    /// # Returns
+    /// 側面積 S = π × r × √(r² + h²)
+    pub fn lateral_area(&self) -> T {
+        let slant_height = (self.radius() * self.radius() + self.height() * self.height()).sqrt();
+        T::PI * self.radius() * slant_height
+    }
+
     /// 円錐の表面積を計算（底面含む）
     ///
     /// # Returns
     /// 表面積 S = π × r × (r + √(r² + h²))
     pub fn surface_area(&self) -> T {
-        let slant_height = (self.radius() * self.radius() + self.height() * self.height()).sqrt();
-        T::PI * self.radius() * (self.radius() + slant_height)
+        T::PI * self.radius() * self.radius() + self.lateral_area()
     }
 
     /// 円錐の境界ボックスを計算
@@ -3,6 +3,7 @@
 //! 円錐ソリッドの高度な幾何計算と解析機能
 
 use crate::{ConicalSolid3D, Plane3D, Point3D, Vector3D};
+use analysis::geometry::{frustum_lateral_area, frustum_volume};
 use geo_foundation::Scalar;
 
 // ============================================================================
@@ -174,6 +175,30 @@ impl<T: Scalar> ConicalSolid3D<T> {
         Some(radius)
     }
 
+    /// 底面から指定高さで切った切頂円錐の体積
+    ///
+    /// # Arguments
+    /// * `cut_height` - 底面からの切断高さ（0 < cut_height ≤ height）
+    ///
+    /// # Returns
+    /// 切頂円錐の体積（範囲外の場合は None）
+    pub fn truncated_volume(&self, cut_height: T) -> Option<T> {
+        let top_radius = self.radius_at_height(cut_height)?;
+        frustum_volume(self.radius(), top_radius, cut_height)
+    }
+
+    /// 底面から指定高さで切った切頂円錐の側面積
+    ///
+    /// # Arguments
+    /// * `cut_height` - 底面からの切断高さ（0 < cut_height ≤ height）
+    ///
+    /// # Returns
+    /// 切頂円錐の側面積（範囲外の場合は None）
+    pub fn truncated_lateral_area(&self, cut_height: T) -> Option<T> {
+        let top_radius = self.radius_at_height(cut_height)?;
+        frustum_lateral_area(self.radius(), top_radius, cut_height)
+    }
+
     /// 円錐の軸線を取得
     ///
     /// # Returns
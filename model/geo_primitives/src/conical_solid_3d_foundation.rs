@@ -168,4 +168,28 @@ mod tests {
         let negative_height = ConicalSolid3D::new_standard(Point3D::new(0.0, 0.0, 0.0), 5.0, -1.0);
         assert!(negative_height.is_none());
     }
+
+    #[test]
+    fn test_areas_and_truncated_measures() {
+        // 半径 3・高さ 4 の円錐（母線長 5）
+        let cone = ConicalSolid3D::new_at_origin(3.0, 4.0).unwrap();
+        let pi = std::f64::consts::PI;
+        assert_relative_eq!(cone.lateral_area(), pi * 3.0 * 5.0, epsilon = 1e-10);
+        assert_relative_eq!(cone.surface_area(), pi * 3.0 * 8.0, epsilon = 1e-10);
+
+        // 高さの半分で切る：上面半径 1.5
+        let truncated = cone.truncated_volume(2.0).unwrap();
+        assert_relative_eq!(truncated, cone.volume() * 7.0 / 8.0, epsilon = 1e-10);
+        let lateral = cone.truncated_lateral_area(2.0).unwrap();
+        assert_relative_eq!(lateral, cone.lateral_area() * 3.0 / 4.0, epsilon = 1e-10);
+
+        // 頂点で切ると元の円錐
+        assert_relative_eq!(
+            cone.truncated_volume(4.0).unwrap(),
+            cone.volume(),
+            epsilon = 1e-10
+        );
+        assert!(cone.truncated_volume(0.0).is_none());
+        assert!(cone.truncated_volume(5.0).is_none());
+    }
 }
@@ -16,7 +16,7 @@
 //! - semi_angle: 半頂角（ラジアン）
 
 use crate::{BBox3D, Direction3D, Point3D, Vector3D};
-use geo_foundation::Scalar;
+use geo_foundation::{tolerance_migration::DefaultTolerances, Angle, Scalar};
 
 /// 3次元円錐サーフェス（STEP準拠のCore実装）
 ///
@@ -205,6 +205,11 @@ impl<T: Scalar> ConicalSurface3D<T> {
         self.semi_angle
     }
 
+    /// 半頂角を Angle として取得
+    pub fn half_angle(&self) -> Angle<T> {
+        Angle::from_radians(self.semi_angle)
+    }
+
     /// Y軸方向を計算（派生軸）
     ///
     /// STEP標準：Y = Z × X（右手系）
@@ -306,10 +311,19 @@ impl<T: Scalar> ConicalSurface3D<T> {
         Direction3D::from_vector(normal)
     }
 
+    /// 半頂角が許容誤差以下で、実質的に円筒へ退化しているか
+    ///
+    /// この場合 `apex()` は数値的に無限遠となり、展開などの頂点基準の計算は行えない。
+    pub fn is_cylinder_degenerate(&self) -> bool {
+        self.semi_angle.tan() <= DefaultTolerances::angle::<T>()
+    }
+
     /// 頂点（apex）の位置を計算
     ///
     /// # Returns
     /// 円錐の頂点位置
+    ///
+    /// 円筒へ退化している場合（`is_cylinder_degenerate`）は非常に遠い点となる。
     pub fn apex(&self) -> Point3D<T> {
         let distance_to_apex = -self.radius / self.semi_angle.tan();
         let axis_vec = self.axis.as_vector();
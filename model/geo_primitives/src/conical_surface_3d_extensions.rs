@@ -2,8 +2,8 @@
 //!
 //! 基本機能を超えた高度な幾何操作、解析機能、CAD/CAM用途に特化した機能
 
-use crate::{ConicalSurface3D, Direction3D, Point3D, Vector3D};
-use geo_foundation::Scalar;
+use crate::{ConicalSurface3D, Direction3D, Point2D, Point3D, Vector3D};
+use geo_foundation::{Angle, Scalar};
 
 impl<T: Scalar> ConicalSurface3D<T> {
     // ========================================================================
@@ -294,6 +294,62 @@ impl<T: Scalar> ConicalSurface3D<T> {
             gaussian_curvature_constant: T::ZERO, // 常に0
         }
     }
+
+    // ========================================================================
+    // 展開（板金展開用）
+    // ========================================================================
+
+    /// 展開図の扇形の中心角
+    ///
+    /// 母線長 `s` の円周 `2π·s·sin(半頂角)` を半径 `s` の円弧とするため、
+    /// 中心角は `2π·sin(半頂角)` となる。
+    pub fn development_angle(&self) -> Angle<T> {
+        Angle::from_radians(T::TAU * self.semi_angle().sin())
+    }
+
+    /// サーフェス上の点 (u, v) を展開図上の点へ写す
+    ///
+    /// 展開図は頂点を原点とし、u = 0 の母線を +X 軸に置く。点は母線長（頂点からの距離）を
+    /// 半径、`u·sin(半頂角)` を角度とする極座標に対応する。頂点を越えた側（半径が負）や
+    /// 円筒に退化している場合は `None`。
+    pub fn unroll_point(&self, u: T, v: T) -> Option<Point2D<T>> {
+        let radius = self.radius_at_v(v);
+        if self.is_cylinder_degenerate() || radius < T::ZERO {
+            return None;
+        }
+        let slant = radius / self.semi_angle().sin();
+        let theta = u * self.semi_angle().sin();
+        Some(Point2D::new(slant * theta.cos(), slant * theta.sin()))
+    }
+
+    /// 軸方向範囲 [min_v, max_v] の円錐（切頂円錐を含む）を展開した輪郭
+    ///
+    /// `max_v` 側の円弧を u = 0 → 2π の順に、続いて `min_v` 側の円弧を逆順に並べた閉じた
+    /// 多角形（始点は繰り返さない）。各円弧は `segments` 分割で `segments + 1` 点。
+    /// 一方の端が頂点に達している場合、その円弧は頂点1点になる。
+    /// 範囲が不正・頂点を越える・円筒に退化している場合は `None`。
+    pub fn unroll(&self, min_v: T, max_v: T, segments: usize) -> Option<Vec<Point2D<T>>> {
+        if segments == 0 || min_v >= max_v {
+            return None;
+        }
+        let arc = |v: T| -> Option<Vec<Point2D<T>>> {
+            if self.radius_at_v(v) <= T::ZERO {
+                return Some(vec![self.unroll_point(T::ZERO, v)?]);
+            }
+            (0..=segments)
+                .map(|i| {
+                    let u = T::TAU * T::from_usize(i) / T::from_usize(segments);
+                    self.unroll_point(u, v)
+                })
+                .collect()
+        };
+
+        let mut outline = arc(max_v)?;
+        let mut inner = arc(min_v)?;
+        inner.reverse();
+        outline.extend(inner);
+        Some(outline)
+    }
 }
 
 // ========================================================================
@@ -331,3 +387,85 @@ pub struct SurfaceQualityMetrics<T: Scalar> {
     pub has_singularities: bool,
     pub gaussian_curvature_constant: T,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f64::consts::{FRAC_PI_6, PI};
+
+    /// 基準半径1、半頂角30°（sin = 0.5）の円錐
+    fn cone() -> ConicalSurface3D<f64> {
+        ConicalSurface3D::new_at_origin(1.0, FRAC_PI_6).unwrap()
+    }
+
+    #[test]
+    fn test_half_angle_and_apex() {
+        let cone = cone();
+        assert!((cone.half_angle().to_degrees() - 30.0).abs() < 1e-10);
+        assert!(!cone.is_cylinder_degenerate());
+        // 頂点は半径が0になる v = -1/tan(30°)
+        let apex_v = -1.0 / FRAC_PI_6.tan();
+        assert!((cone.apex().z() - apex_v).abs() < 1e-10);
+        assert!(cone.radius_at_v(apex_v).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_development_preserves_lengths() {
+        let cone = cone();
+        // 扇形の中心角 = 2π·sin(30°) = π
+        assert!((cone.development_angle().to_radians() - PI).abs() < 1e-10);
+
+        // 母線長 = 半径 / sin(30°) = 2
+        let start = cone.unroll_point(0.0, 0.0).unwrap();
+        let end = cone.unroll_point(2.0 * PI, 0.0).unwrap();
+        assert!(start.distance_to(&Point2D::new(2.0, 0.0)) < 1e-10);
+        assert!(end.distance_to(&Point2D::new(-2.0, 0.0)) < 1e-10);
+
+        // 母線に沿った距離は展開後も保たれる
+        let along_3d = cone
+            .point_at_uv(1.0, 0.0)
+            .distance_to(&cone.point_at_uv(1.0, 1.5));
+        let along_2d = cone
+            .unroll_point(1.0, 0.0)
+            .unwrap()
+            .distance_to(&cone.unroll_point(1.0, 1.5).unwrap());
+        assert!((along_3d - along_2d).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_unroll_full_and_truncated_cone() {
+        let cone = cone();
+        let apex_v = -1.0 / FRAC_PI_6.tan();
+
+        // 頂点まで：外周 9 点 + 頂点
+        let full = cone.unroll(apex_v, 0.0, 8).unwrap();
+        assert_eq!(full.len(), 10);
+        assert!(full[9].distance_to(&Point2D::origin()) < 1e-10);
+
+        // 切頂円錐：外周・内周それぞれ 5 点
+        let truncated = cone.unroll(0.0, 1.0, 4).unwrap();
+        assert_eq!(truncated.len(), 10);
+        let outer = cone.radius_at_v(1.0) / 0.5;
+        assert!(truncated[..5]
+            .iter()
+            .all(|p| (p.distance_to(&Point2D::origin()) - outer).abs() < 1e-10));
+        assert!(truncated[5..]
+            .iter()
+            .all(|p| (p.distance_to(&Point2D::origin()) - 2.0).abs() < 1e-10));
+    }
+
+    #[test]
+    fn test_unroll_guards() {
+        let cone = cone();
+        assert!(cone.unroll(1.0, 0.0, 4).is_none());
+        assert!(cone.unroll(0.0, 1.0, 0).is_none());
+        // 頂点を越える範囲
+        assert!(cone.unroll(-5.0, 1.0, 4).is_none());
+
+        // 円筒に退化した円錐は展開しない
+        let nearly_cylinder = ConicalSurface3D::new_at_origin(1.0, 1e-12).unwrap();
+        assert!(nearly_cylinder.is_cylinder_degenerate());
+        assert!(nearly_cylinder.unroll(0.0, 1.0, 4).is_none());
+        assert!(nearly_cylinder.unroll_point(0.0, 0.0).is_none());
+    }
+}
@@ -4,13 +4,30 @@
 //! Core機能は direction_2d.rs を参照
 
 use crate::Direction2D;
-use geo_foundation::Scalar;
+use geo_foundation::{Angle, Scalar};
 
 // ============================================================================
 // Extension Methods (Coreにない新機能のみ)
 // ============================================================================
 
 impl<T: Scalar> Direction2D<T> {
+    /// X軸から反時計回りに `angle` の方向を作成
+    pub fn from_angle(angle: Angle<T>) -> Self {
+        Self::from_angle_radians(angle.to_radians())
+    }
+
+    /// X軸から反時計回りに測った角度（-π, π]
+    pub fn angle(&self) -> Angle<T> {
+        Angle::from_radians(self.to_angle_radians())
+    }
+
+    /// 反時計回りに `angle` だけ回転した方向（結果も正規化される）
+    ///
+    /// Deref 先の `Vector2D::rotate` より優先され、`Direction2D` を返す。
+    pub fn rotate(&self, angle: Angle<T>) -> Self {
+        self.rotated_by_angle(angle.to_radians())
+    }
+
     /// 角度から方向を作成（ラジアン）
     pub fn from_angle_radians(angle: T) -> Self {
         let x = angle.cos();
@@ -127,4 +127,35 @@ mod tests {
         // ユーザーは内積計算を気にする必要がない！
         // 直感的な角度指定で判定可能
     }
+
+    #[test]
+    fn test_angle_typed_construction_and_rotation() {
+        use geo_foundation::Angle;
+
+        let dir = Direction2D::<TestType>::from_angle(Angle::from_degrees(150.0));
+        assert!((dir.angle().to_degrees() - 150.0).abs() < 1e-12);
+        assert!((dir.x() + 3f64.sqrt() / 2.0).abs() < 1e-15);
+
+        // 180°をまたぐ回転は (-π, π] に折り返す
+        let rotated = dir.rotate(Angle::from_degrees(60.0));
+        assert!((rotated.angle().to_degrees() + 150.0).abs() < 1e-12);
+        let back = rotated.rotate(Angle::from_degrees(-60.0));
+        assert!((back.x() - dir.x()).abs() < 1e-15 && (back.y() - dir.y()).abs() < 1e-15);
+    }
+
+    #[test]
+    fn test_rotation_keeps_unit_length() {
+        use geo_foundation::Angle;
+
+        // 半端な角度の回転を繰り返しても長さ1のまま（誤差が蓄積しない）
+        let step = Angle::from_radians(0.123_456_789);
+        let mut dir = Direction2D::<TestType>::from_angle(Angle::from_degrees(33.0));
+        for _ in 0..10_000 {
+            dir = dir.rotate(step);
+            assert!((dir.as_vector().length() - 1.0).abs() < 1e-14);
+        }
+        let expected = 33f64.to_radians() + 0.123_456_789 * 10_000.0;
+        let expected = Direction2D::from_angle_radians(expected);
+        assert!(dir.angle_between(&expected) < 1e-9);
+    }
 }
@@ -17,14 +17,12 @@ impl<T: Scalar> Direction3D<T> {
     // ========================================================================
 
     /// ベクトルから方向を作成（正規化）
+    ///
+    /// ゼロベクトルや NaN / 無限大を含むベクトルからは作成できず `None`
     pub fn from_vector(vector: Vector3D<T>) -> Option<Self> {
-        let len = vector.length();
-        if len <= T::ZERO {
-            None
-        } else {
-            let normalized = vector.normalize();
-            Some(Self { vector: normalized })
-        }
+        vector
+            .try_normalize()
+            .map(|normalized| Self { vector: normalized })
     }
 
     /// X、Y、Z成分から方向を作成
@@ -73,7 +73,28 @@ impl<T: Scalar> Direction3D<T> {
 
     /// Z軸からの仰角を取得（0 = Z軸方向、π/2 = XY平面）
     pub fn elevation_angle(&self) -> Angle<T> {
-        Angle::from_radians(self.z().acos())
+        // 丸め誤差で |z| が1をわずかに超えても NaN にならないようにする
+        Angle::from_radians(self.z().clamp(-T::ONE, T::ONE).acos())
+    }
+
+    /// 球面座標の (方位角, 仰角)
+    ///
+    /// 方位角は XY 平面への射影の X 軸からの角度 (-π, π]（Z軸方向では0）、
+    /// 仰角は `elevation_angle` と同じく Z 軸からの角度 [0, π]。
+    /// [`Direction3D::from_azimuth_elevation`] で元の方向に戻る。
+    pub fn azimuth_elevation(&self) -> (Angle<T>, Angle<T>) {
+        (self.azimuth_angle(), self.elevation_angle())
+    }
+
+    /// 球面座標の (方位角, 仰角) から方向を作成（仰角は Z 軸からの角度）
+    pub fn from_azimuth_elevation(azimuth: Angle<T>, elevation: Angle<T>) -> Self {
+        let (azimuth, elevation) = (azimuth.to_radians(), elevation.to_radians());
+        Self::new(
+            elevation.sin() * azimuth.cos(),
+            elevation.sin() * azimuth.sin(),
+            elevation.cos(),
+        )
+        .unwrap_or_else(Self::positive_z)
     }
 
     /// 他の方向との角度差を計算（3D版）
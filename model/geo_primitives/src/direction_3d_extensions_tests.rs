@@ -133,4 +133,33 @@ mod tests {
         assert!((dir1.y() - dir2.y()).abs() < TestType::EPSILON);
         assert!((dir1.z() - dir2.z()).abs() < TestType::EPSILON);
     }
+
+    #[test]
+    fn test_azimuth_elevation_round_trip() {
+        use geo_foundation::Angle;
+
+        let dir = Direction3D::new(-1.0, -1.0, 2.0_f64.sqrt()).unwrap();
+        let (azimuth, elevation) = dir.azimuth_elevation();
+        assert!((azimuth.to_degrees() + 135.0).abs() < 1e-12);
+        assert!((elevation.to_degrees() - 45.0).abs() < 1e-12);
+
+        let back = Direction3D::from_azimuth_elevation(azimuth, elevation);
+        assert!(back.angle_between_radians(&dir) < 1e-12);
+
+        // Z軸方向・-Z軸方向（方位角は0、仰角は0とπ）
+        let (azimuth, elevation) = Direction3D::<TestType>::positive_z().azimuth_elevation();
+        assert_eq!(azimuth.to_radians(), 0.0);
+        assert_eq!(elevation.to_radians(), 0.0);
+        let (_, elevation) = Direction3D::<TestType>::negative_z().azimuth_elevation();
+        assert!((elevation.to_radians() - std::f64::consts::PI).abs() < 1e-15);
+
+        // 構築した方向は常に正規化されている
+        for (azimuth, elevation) in [(0.0, 0.0), (37.0, 91.0), (-170.0, 179.0), (400.0, -30.0)] {
+            let dir = Direction3D::<TestType>::from_azimuth_elevation(
+                Angle::from_degrees(azimuth),
+                Angle::from_degrees(elevation),
+            );
+            assert!((dir.length() - 1.0).abs() < 1e-15);
+        }
+    }
 }
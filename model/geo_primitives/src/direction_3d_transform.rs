@@ -93,7 +93,7 @@ impl<T: Scalar> AnalysisTransform3D<T> for Direction3D<T> {
 
     fn transform_point_matrix(&self, matrix: &Matrix4x4<T>) -> Self {
         // Direction3Dは点ではないので、ベクトル変換を使用
-        analysis_transform::transform_direction_3d(self, matrix).unwrap_or_else(|_| *self)
+        analysis_transform::transform_direction_3d(self, matrix).unwrap_or(*self)
         // エラー時は元の方向を维持
     }
 
@@ -195,17 +195,9 @@ impl<T: Scalar> EllipseArc2D<T> {
     // Helper Methods
     // ========================================================================
 
-    /// 角度が楕円弧の範囲内にあるかを判定
+    /// 角度が楕円弧の範囲内にあるかを判定（2πをまたぐ範囲・全周に対応）
     pub fn angle_in_range(&self, angle: T) -> bool {
-        let start_rad = self.start_angle.to_radians();
-        let end_rad = self.end_angle.to_radians();
-
-        if start_rad <= end_rad {
-            angle >= start_rad && angle <= end_rad
-        } else {
-            // 角度が0を跨ぐ場合
-            angle >= start_rad || angle <= end_rad
-        }
+        Angle::contains_angle(self.start_angle, self.end_angle, Angle::from_radians(angle))
     }
 
     /// 点が楕円弧の角度範囲内にあるかを判定
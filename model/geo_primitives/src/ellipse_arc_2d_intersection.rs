@@ -0,0 +1,262 @@
+//! EllipseArc2D 交点計算Foundation実装
+//!
+//! 楕円をパラメトリック表現 E(θ) = c + R(φ)(a cos θ, b sin θ) で扱い、交点のパラメータ θ を
+//! 求めてから弧の角度範囲で絞り込む。角度範囲の判定は [`EllipseArc2D::angle_in_range`] により
+//! 2πをまたぐ弧にも対応する。
+//!
+//! - 線分: 楕円を単位円へ写す座標系では直線のままなので、2次方程式を解く
+//! - 円: 距離関数 |E(θ) - c'| - r' の符号変化を全周のサンプリングで探し二分法で解く。
+//!   接する場合は符号が変わらないため、距離関数の極値を黄金分割探索で求めて判定する
+
+use crate::{Circle2D, EllipseArc2D, LineSegment2D, Point2D, Vector2D};
+use geo_foundation::{tolerance_migration::DefaultTolerances, Angle, MultipleIntersection, Scalar};
+
+/// 円との交点探索の全周サンプル数の下限・上限
+const MIN_CIRCLE_SAMPLES: usize = 64;
+const MAX_CIRCLE_SAMPLES: usize = 4096;
+
+/// 二分法・黄金分割探索の反復回数
+const REFINE_ITERATIONS: usize = 100;
+
+impl<T: Scalar> EllipseArc2D<T> {
+    /// 線分との交点（デフォルト許容誤差）
+    pub fn intersect_segment(&self, segment: &LineSegment2D<T>) -> Vec<Point2D<T>> {
+        self.intersect_segment_with_tolerance(segment, DefaultTolerances::distance::<T>())
+    }
+
+    /// 線分との交点（距離許容誤差を指定）
+    ///
+    /// 交点は弧の開始側から順に並ぶ。接する場合は1点にまとめ、
+    /// 線分・弧の端点から `tolerance` 以内の交点は端点そのものに吸着させる。
+    pub fn intersect_segment_with_tolerance(
+        &self,
+        segment: &LineSegment2D<T>,
+        tolerance: T,
+    ) -> Vec<Point2D<T>> {
+        // 楕円を単位円へ写す座標系での線分 S + t D（t ∈ [0, 1]）
+        let start = self.unit_circle_coordinates(segment.start_point());
+        let direction = self.unit_circle_coordinates(segment.end_point()) - start;
+        let direction_squared = direction.dot(&direction);
+
+        // この座標系でのずれは長半軸倍までしか拡大されない
+        let unit_tolerance = tolerance / self.semi_major();
+        let foot = -start.dot(&direction) / direction_squared;
+        let distance = (start + direction * foot).length();
+        let parameters = if distance > T::ONE + unit_tolerance {
+            Vec::new()
+        } else if (distance - T::ONE).abs() <= unit_tolerance {
+            vec![foot]
+        } else {
+            let half_chord = (T::ONE - distance * distance).sqrt() / direction_squared.sqrt();
+            vec![foot - half_chord, foot + half_chord]
+        };
+
+        let length = segment.length();
+        let candidates = parameters.into_iter().filter_map(|t| {
+            let offset = t * length;
+            if offset < -tolerance || offset > length + tolerance {
+                return None;
+            }
+            let point = if offset.abs() <= tolerance {
+                segment.start_point()
+            } else if (offset - length).abs() <= tolerance {
+                segment.end_point()
+            } else {
+                segment.point_at_normalized_parameter(t)
+            };
+            let on_circle = start + direction * t;
+            Some((on_circle.y().atan2(on_circle.x()), point))
+        });
+        self.filter_to_arc(candidates, tolerance)
+    }
+
+    /// 円との交点（デフォルト許容誤差）
+    pub fn intersect_circle(&self, circle: &Circle2D<T>) -> Vec<Point2D<T>> {
+        self.intersect_circle_with_tolerance(circle, DefaultTolerances::distance::<T>())
+    }
+
+    /// 円との交点（距離許容誤差を指定）
+    ///
+    /// 交点は弧の開始側から順に並ぶ。楕円と円が接する（距離関数の極値が `tolerance` 以内）
+    /// 場合は接点を1点だけ返す。楕円が円と一致する場合は交点を列挙できないため空を返す。
+    pub fn intersect_circle_with_tolerance(
+        &self,
+        circle: &Circle2D<T>,
+        tolerance: T,
+    ) -> Vec<Point2D<T>> {
+        let ellipse = *self.ellipse();
+        let radius = circle.radius();
+        let center = circle.center();
+        if ellipse.center().distance_to(&center) <= tolerance
+            && (ellipse.semi_major() - radius).abs() <= tolerance
+            && (ellipse.semi_minor() - radius).abs() <= tolerance
+        {
+            return Vec::new();
+        }
+
+        // 楕円上の点から円周までの符号付き距離（円の外側で正）
+        let gap = |theta: T| ellipse.point_at_parameter(theta).distance_to(&center) - radius;
+
+        // 隣り合うサンプル間の弧長が半径より十分短くなるように分割する
+        let samples = (T::from_f64(8.0) * ellipse.semi_major() / radius)
+            .ceil()
+            .to_f64()
+            .clamp(MIN_CIRCLE_SAMPLES as f64, MAX_CIRCLE_SAMPLES as f64)
+            as usize;
+        let step = T::TAU / T::from_usize(samples);
+        let thetas: Vec<T> = (0..=samples).map(|i| step * T::from_usize(i)).collect();
+        let values: Vec<T> = thetas.iter().map(|&theta| gap(theta)).collect();
+
+        let mut roots = Vec::new();
+        for i in 0..samples {
+            let (v0, v1) = (values[i], values[i + 1]);
+            if v0 == T::ZERO {
+                roots.push(thetas[i]);
+            } else if (v0 < T::ZERO) != (v1 < T::ZERO) && v1 != T::ZERO {
+                roots.push(bisect(&gap, thetas[i], thetas[i + 1]));
+            }
+        }
+
+        // 符号が変わらないまま円周に近づく箇所（接する、またはサンプル間で2回交わる）
+        for i in 0..samples {
+            let previous = values[(i + samples - 1) % samples];
+            let (current, next) = (values[i], values[i + 1]);
+            let same_sign = (previous > T::ZERO) == (current > T::ZERO)
+                && (current > T::ZERO) == (next > T::ZERO);
+            if !same_sign || current.abs() > previous.abs() || current.abs() > next.abs() {
+                continue;
+            }
+            let (low, high) = (thetas[i] - step, thetas[i] + step);
+            let sign = if current > T::ZERO { T::ONE } else { -T::ONE };
+            let extremum = golden_section_min(|theta| gap(theta) * sign, low, high);
+            let closest = gap(extremum);
+            if closest.abs() <= tolerance {
+                roots.push(extremum);
+            } else if closest * sign < T::ZERO {
+                roots.push(bisect(&gap, low, extremum));
+                roots.push(bisect(&gap, extremum, high));
+            }
+        }
+
+        let candidates = roots
+            .into_iter()
+            .map(|theta| (theta, ellipse.point_at_parameter(theta)));
+        self.filter_to_arc(candidates, tolerance)
+    }
+
+    /// 楕円を原点中心の単位円へ写す座標系での位置
+    fn unit_circle_coordinates(&self, point: Point2D<T>) -> Vector2D<T> {
+        let offset = Vector2D::from_points(self.center(), point);
+        let ellipse = self.ellipse();
+        Vector2D::new(
+            offset.dot(&ellipse.major_axis_direction()) / ellipse.semi_major(),
+            offset.dot(&ellipse.minor_axis_direction()) / ellipse.semi_minor(),
+        )
+    }
+
+    /// 楕円上の交点候補（パラメータ θ と座標）を弧の範囲で絞り込み、開始側から並べる
+    ///
+    /// 範囲外でも弧の端点から `tolerance` 以内なら端点として採用する。
+    /// `tolerance` 以内で重なる候補は1点にまとめる。
+    fn filter_to_arc(
+        &self,
+        candidates: impl Iterator<Item = (T, Point2D<T>)>,
+        tolerance: T,
+    ) -> Vec<Point2D<T>> {
+        let (start, end) = (self.start_point(), self.end_point());
+        // 開始角度から反時計回りに測った角度（終了角度が開始角度より小さければ2πをまたぐ）
+        let offset_from_start = |angle: Angle<T>| {
+            (angle - self.start_angle())
+                .normalized_positive()
+                .to_radians()
+        };
+        let span = if self.angle_span().to_radians() >= T::TAU {
+            T::TAU
+        } else {
+            offset_from_start(self.end_angle())
+        };
+        let mut accepted: Vec<(T, Point2D<T>)> = Vec::new();
+        for (theta, point) in candidates {
+            let (offset, point) = if point.distance_to(&start) <= tolerance {
+                (T::ZERO, start)
+            } else if point.distance_to(&end) <= tolerance {
+                (span, end)
+            } else if self.angle_in_range(theta) {
+                (offset_from_start(Angle::from_radians(theta)), point)
+            } else {
+                continue;
+            };
+            if accepted
+                .iter()
+                .all(|(_, existing)| existing.distance_to(&point) > tolerance)
+            {
+                accepted.push((offset, point));
+            }
+        }
+        accepted.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+        accepted.into_iter().map(|(_, point)| point).collect()
+    }
+}
+
+/// `f(low)` と `f(high)` の符号が異なる区間で根を二分法により求める
+fn bisect<T: Scalar>(f: &impl Fn(T) -> T, mut low: T, mut high: T) -> T {
+    let low_negative = f(low) < T::ZERO;
+    for _ in 0..REFINE_ITERATIONS {
+        let middle = (low + high) / (T::ONE + T::ONE);
+        if middle <= low || middle >= high {
+            break;
+        }
+        if (f(middle) < T::ZERO) == low_negative {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+    (low + high) / (T::ONE + T::ONE)
+}
+
+/// 区間内で単峰な関数の最小点を黄金分割探索で求める
+fn golden_section_min<T: Scalar>(f: impl Fn(T) -> T, mut low: T, mut high: T) -> T {
+    let ratio = T::from_f64(0.618_033_988_749_894_9);
+    let mut left = high - (high - low) * ratio;
+    let mut right = low + (high - low) * ratio;
+    let (mut f_left, mut f_right) = (f(left), f(right));
+    for _ in 0..REFINE_ITERATIONS {
+        if f_left < f_right {
+            high = right;
+            right = left;
+            f_right = f_left;
+            left = high - (high - low) * ratio;
+            f_left = f(left);
+        } else {
+            low = left;
+            left = right;
+            f_left = f_right;
+            right = low + (high - low) * ratio;
+            f_right = f(right);
+        }
+    }
+    (low + high) / (T::ONE + T::ONE)
+}
+
+// ============================================================================
+// MultipleIntersection Implementations
+// ============================================================================
+
+// EllipseArc2D vs LineSegment2D
+impl<T: Scalar> MultipleIntersection<T, LineSegment2D<T>> for EllipseArc2D<T> {
+    type Point = Point2D<T>;
+
+    fn intersections_with(&self, other: &LineSegment2D<T>, tolerance: T) -> Vec<Self::Point> {
+        self.intersect_segment_with_tolerance(other, tolerance)
+    }
+}
+
+// EllipseArc2D vs Circle2D
+impl<T: Scalar> MultipleIntersection<T, Circle2D<T>> for EllipseArc2D<T> {
+    type Point = Point2D<T>;
+
+    fn intersections_with(&self, other: &Circle2D<T>, tolerance: T) -> Vec<Self::Point> {
+        self.intersect_circle_with_tolerance(other, tolerance)
+    }
+}
@@ -0,0 +1,156 @@
+//! EllipseArc2D 交点計算のテスト
+
+use crate::{Circle2D, Ellipse2D, EllipseArc2D, LineSegment2D, Point2D};
+use geo_foundation::{Angle, MultipleIntersection};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 中心 (1, 2)、長半軸 4、短半軸 2 の楕円弧（角度は度で指定）
+    fn arc(rotation: f64, start_degrees: f64, end_degrees: f64) -> EllipseArc2D<f64> {
+        let ellipse = Ellipse2D::new(Point2D::new(1.0, 2.0), 4.0, 2.0, rotation).unwrap();
+        EllipseArc2D::new(
+            ellipse,
+            Angle::from_degrees(start_degrees),
+            Angle::from_degrees(end_degrees),
+        )
+    }
+
+    fn segment(x0: f64, y0: f64, x1: f64, y1: f64) -> LineSegment2D<f64> {
+        LineSegment2D::new(Point2D::new(x0, y0), Point2D::new(x1, y1)).unwrap()
+    }
+
+    fn assert_point(actual: Point2D<f64>, x: f64, y: f64) {
+        assert!(
+            actual.distance_to(&Point2D::new(x, y)) < 1e-9,
+            "{actual:?} != ({x}, {y})"
+        );
+    }
+
+    fn assert_on_ellipse(arc: &EllipseArc2D<f64>, point: Point2D<f64>) {
+        assert!(arc.ellipse().on_boundary(&point, 1e-9), "{point:?}");
+    }
+
+    #[test]
+    fn test_segment_through_full_ellipse() {
+        let full = arc(0.0, 0.0, 360.0);
+        // 長軸に沿った線分は長軸の両端で交わる（弧の開始側から順）
+        let points = full.intersect_segment(&segment(-10.0, 2.0, 10.0, 2.0));
+        assert_eq!(points.len(), 2);
+        assert_point(points[0], 5.0, 2.0);
+        assert_point(points[1], -3.0, 2.0);
+
+        // 短軸の端で接する線分は1点
+        let points = full.intersect_segment(&segment(-10.0, 4.0, 10.0, 4.0));
+        assert_eq!(points.len(), 1);
+        assert_point(points[0], 1.0, 4.0);
+
+        // 楕円の内側に収まる線分は交点なし
+        assert!(full
+            .intersect_segment(&segment(0.0, 2.0, 2.0, 2.0))
+            .is_empty());
+    }
+
+    #[test]
+    fn test_segment_with_rotated_ellipse() {
+        let rotated = arc(0.5, 0.0, 360.0);
+        let line = segment(-6.0, -3.0, 8.0, 7.0);
+        let points = rotated.intersections_with(&line, 1e-10);
+        assert_eq!(points.len(), 2);
+        for point in points {
+            assert_on_ellipse(&rotated, point);
+            assert!(line.contains_point(&point, 1e-9));
+        }
+    }
+
+    #[test]
+    fn test_arc_range_crossing_two_pi() {
+        // 300° から 60° まで（0° をまたぐ右側の弧）
+        let right = arc(0.0, 300.0, 60.0);
+        let horizontal = segment(-10.0, 2.0, 10.0, 2.0);
+        let points = right.intersect_segment(&horizontal);
+        assert_eq!(points.len(), 1);
+        assert_point(points[0], 5.0, 2.0);
+
+        // 60° から 300° まで（左側の弧）
+        let left = arc(0.0, 60.0, 300.0);
+        let points = left.intersect_segment(&horizontal);
+        assert_eq!(points.len(), 1);
+        assert_point(points[0], -3.0, 2.0);
+
+        // 0° をまたぐ弧では交点を開始側から並べる（330° → 30° の順）
+        let vertical = segment(
+            1.0 + 4.0 * 30f64.to_radians().cos(),
+            -10.0,
+            1.0 + 4.0 * 30f64.to_radians().cos(),
+            10.0,
+        );
+        let points = right.intersect_segment(&vertical);
+        assert_eq!(points.len(), 2);
+        assert!(points[0].y() < 2.0 && points[1].y() > 2.0);
+    }
+
+    #[test]
+    fn test_arc_endpoint_snaps() {
+        // 弧の終点をちょうど通る線分は終点そのものを返す
+        let quarter = arc(0.0, 0.0, 90.0);
+        let points =
+            quarter.intersect_segment_with_tolerance(&segment(-10.0, 4.0, 10.0, 4.0), 1e-9);
+        assert_eq!(points, vec![quarter.end_point()]);
+    }
+
+    #[test]
+    fn test_circle_intersections() {
+        let full = arc(0.0, 0.0, 360.0);
+
+        // 楕円の中心を中心とする半径 3 の円とは4点で交わる
+        let circle = Circle2D::new(Point2D::new(1.0, 2.0), 3.0).unwrap();
+        let points = full.intersect_circle(&circle);
+        assert_eq!(points.len(), 4);
+        for point in &points {
+            assert_on_ellipse(&full, *point);
+            assert!((point.distance_to(&circle.center()) - 3.0).abs() < 1e-9);
+        }
+
+        // 上半分の弧では2点
+        let upper = arc(0.0, 0.0, 180.0);
+        let points = upper.intersect_circle(&circle);
+        assert_eq!(points.len(), 2);
+        assert!(points.iter().all(|p| p.y() > 2.0));
+        // 開始側（右側）から順
+        assert!(points[0].x() > points[1].x());
+    }
+
+    #[test]
+    fn test_circle_tangent_and_disjoint() {
+        let full = arc(0.3, 0.0, 360.0);
+
+        // 長軸の端で外接する円は1点
+        let tip = full.ellipse().point_at_parameter(0.0);
+        let outward = full.ellipse().major_axis_direction();
+        let tangent = Circle2D::new(tip + outward * 1.5, 1.5).unwrap();
+        let points = full.intersect_circle_with_tolerance(&tangent, 1e-7);
+        assert_eq!(points.len(), 1);
+        assert_point(points[0], tip.x(), tip.y());
+
+        // 内側に収まる円・遠く離れた円は交点なし
+        let inner = Circle2D::new(Point2D::new(1.0, 2.0), 1.0).unwrap();
+        assert!(full.intersect_circle(&inner).is_empty());
+        let far = Circle2D::new(Point2D::new(30.0, 2.0), 1.0).unwrap();
+        assert!(full.intersect_circle(&far).is_empty());
+    }
+
+    #[test]
+    fn test_small_circle_crossing_between_samples() {
+        // 楕円に比べて小さい円でも2点とも求める
+        let full = arc(0.0, 0.0, 360.0);
+        let top = Point2D::new(1.0, 4.0);
+        let small = Circle2D::new(Point2D::new(top.x(), top.y() + 0.01), 0.02).unwrap();
+        let points = full.intersect_circle(&small);
+        assert_eq!(points.len(), 2);
+        for point in points {
+            assert_on_ellipse(&full, point);
+        }
+    }
+}
@@ -113,6 +113,42 @@ impl<T: Scalar> EllipseArc3D<T> {
             .point_at_angle(Angle::from_radians(current_angle))
     }
 
+    /// 弧パラメータ（楕円の離心角）`t` における点を取得
+    ///
+    /// 点は `center + a·cos(t)·長軸 + b·sin(t)·短軸`。`t` が弧の角度範囲外なら
+    /// 近い方の端点の角度に丸める。
+    pub fn point_at(&self, t: Angle<T>) -> Point3D<T> {
+        self.ellipse.point_at_angle(self.clamp_to_range(t))
+    }
+
+    /// 弧パラメータ `t` における接線ベクトル（`t` による微分、正規化なし）
+    ///
+    /// `-a·sin(t)·長軸 + b·cos(t)·短軸` で、長さは離心率に応じて変化する。
+    /// 向きは開始角から終了角へ進む方向。範囲外の `t` は `point_at` と同様に丸める。
+    pub fn tangent_at(&self, t: Angle<T>) -> Vector3D<T> {
+        self.ellipse
+            .tangent_at_parameter(self.clamp_to_range(t).to_radians())
+    }
+
+    /// 角度が弧の範囲内にあるか（2πをまたぐ範囲・全周に対応）
+    pub fn contains_angle(&self, angle: Angle<T>) -> bool {
+        Angle::contains_angle(self.start_angle, self.end_angle, angle)
+    }
+
+    /// 範囲外の角度を近い方の端点の角度に丸める
+    fn clamp_to_range(&self, angle: Angle<T>) -> Angle<T> {
+        if self.contains_angle(angle) {
+            return angle;
+        }
+        let to_start = self.start_angle.shortest_diff(&angle).to_radians().abs();
+        let to_end = self.end_angle.shortest_diff(&angle).to_radians().abs();
+        if to_start <= to_end {
+            self.start_angle
+        } else {
+            self.end_angle
+        }
+    }
+
     /// 弧の中点を取得
     pub fn midpoint(&self) -> Point3D<T> {
         self.point_at_parameter(T::from_f64(0.5))
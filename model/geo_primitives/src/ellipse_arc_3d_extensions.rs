@@ -223,23 +223,9 @@ impl<T: Scalar> EllipseArc3D<T> {
 
     /// 楕円弧の角度を正規化
     pub fn normalize_angles(&self) -> Self {
-        let mut start_rad = self.start_angle().to_radians();
-        let mut end_rad = self.end_angle().to_radians();
-
         // 角度を [0, 2π) の範囲に正規化
-        while start_rad < T::ZERO {
-            start_rad += T::TAU;
-        }
-        while start_rad >= T::TAU {
-            start_rad -= T::TAU;
-        }
-
-        while end_rad < T::ZERO {
-            end_rad += T::TAU;
-        }
-        while end_rad >= T::TAU {
-            end_rad -= T::TAU;
-        }
+        let start_rad = self.start_angle().normalized_positive().to_radians();
+        let mut end_rad = self.end_angle().normalized_positive().to_radians();
 
         // 終了角度が開始角度より小さい場合は一周分を追加
         if end_rad < start_rad {
@@ -168,4 +168,47 @@ mod tests {
         assert!(bbox.min().z().is_finite());
         assert!(bbox.max().z().is_finite());
     }
+
+    #[test]
+    fn test_point_and_tangent_at() {
+        let ellipse_arc = create_test_ellipse_arc(); // 中心 (2, 3, 1)、a = 4、b = 2、45度-135度
+
+        let top = ellipse_arc.point_at(Angle::from_degrees(90.0));
+        assert!(top.distance_to(&Point3D::new(2.0, 5.0, 1.0)) < 1e-10);
+
+        // 接線は離心角での微分：90度で (-a, 0, 0)、45度で (-a/√2, b/√2, 0)
+        let tangent = ellipse_arc.tangent_at(Angle::from_degrees(90.0));
+        assert!((tangent - Vector3D::new(-4.0, 0.0, 0.0)).length() < 1e-10);
+        let tangent = ellipse_arc.tangent_at(Angle::from_degrees(45.0));
+        let half_sqrt2 = std::f64::consts::FRAC_1_SQRT_2;
+        assert!(
+            (tangent - Vector3D::new(-4.0 * half_sqrt2, 2.0 * half_sqrt2, 0.0)).length() < 1e-10
+        );
+
+        // 接線は数値微分と一致する
+        let t = Angle::from_degrees(100.0);
+        let h = 1e-6;
+        let ahead = ellipse_arc.point_at(t + Angle::from_radians(h));
+        let behind = ellipse_arc.point_at(t - Angle::from_radians(h));
+        let numeric = Vector3D::from_points(&behind, &ahead) * (0.5 / h);
+        assert!((numeric - ellipse_arc.tangent_at(t)).length() < 1e-6);
+    }
+
+    #[test]
+    fn test_point_at_clamps_outside_range() {
+        let ellipse_arc = create_test_ellipse_arc();
+        assert!(!ellipse_arc.contains_angle(Angle::from_degrees(200.0)));
+
+        let clamped_end = ellipse_arc.point_at(Angle::from_degrees(170.0));
+        assert!(clamped_end.distance_to(&ellipse_arc.end_point()) < 1e-10);
+        let clamped_start = ellipse_arc.point_at(Angle::from_degrees(-10.0));
+        assert!(clamped_start.distance_to(&ellipse_arc.start_point()) < 1e-10);
+
+        // 0度をまたぐ弧（330度〜30度）
+        let wrapped =
+            ellipse_arc.with_angles(Angle::from_degrees(330.0), Angle::from_degrees(30.0));
+        assert!(wrapped.contains_angle(Angle::from_degrees(0.0)));
+        let right = wrapped.point_at(Angle::from_degrees(0.0));
+        assert!(right.distance_to(&Point3D::new(6.0, 3.0, 1.0)) < 1e-10);
+    }
 }
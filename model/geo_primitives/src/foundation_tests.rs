@@ -65,3 +65,34 @@ fn test_f32_compatibility() {
     let normalized = vector.normalize();
     assert!((normalized.length() - 1.0f32).abs() < f32::EPSILON);
 }
+
+/// 異種プリミティブを GeometryPrimitive として分類・集計するテスト
+#[test]
+fn test_geometry_primitive_classification() {
+    use crate::{Circle3D, InfiniteLine3D, Point3D, SphericalSolid3D, Vector3D};
+    use geo_foundation::{count_by_kind, DimensionClass, GeometryPrimitive, PrimitiveKind};
+
+    let origin = Point3D::new(0.0, 0.0, 0.0);
+    let primitives: Vec<Box<dyn GeometryPrimitive>> = vec![
+        Box::new(origin),
+        Box::new(Point2D::new(1.0, 2.0)),
+        Box::new(InfiniteLine3D::new(origin, Vector3D::new(0.0, 0.0, 1.0)).unwrap()),
+        Box::new(Circle3D::new_xy_plane(origin, 1.0).unwrap()),
+        Box::new(SphericalSolid3D::new_at_origin(2.0).unwrap()),
+    ];
+
+    let curves = primitives
+        .iter()
+        .filter(|p| p.dimension_class() == DimensionClass::One)
+        .count();
+    assert_eq!(curves, 1);
+    assert_eq!(primitives[3].dimension_class(), DimensionClass::Two);
+    assert_eq!(primitives[4].dimension_class(), DimensionClass::Three);
+
+    let counts = count_by_kind(primitives.iter().map(|p| p.as_ref()));
+    assert_eq!(counts.get(&PrimitiveKind::Point), Some(&2));
+    assert_eq!(counts.get(&PrimitiveKind::Line), Some(&1));
+    assert_eq!(counts.get(&PrimitiveKind::Circle), Some(&1));
+    assert_eq!(counts.get(&PrimitiveKind::SphericalSolid), Some(&1));
+    assert_eq!(counts.get(&PrimitiveKind::Triangle), None);
+}
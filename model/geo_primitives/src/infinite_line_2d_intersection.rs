@@ -0,0 +1,90 @@
+//! InfiniteLine2D 交点計算Foundation実装
+//!
+//! 2本の無限直線の交点を連立方程式（Matrix2x2 + Cramerの公式）で求める
+
+use crate::{InfiniteLine2D, Point2D};
+use analysis::linalg::{solver::CramerSolver, Matrix2x2, Vector2};
+use geo_foundation::{tolerance_migration::DefaultTolerances, BasicIntersection, Scalar};
+
+/// 2本の無限直線の交差結果
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LineIntersection2D<T: Scalar> {
+    /// 1点で交わる
+    Point(Point2D<T>),
+    /// 平行で交わらない
+    Parallel,
+    /// 同一直線（無数の共有点）
+    Coincident,
+}
+
+impl<T: Scalar> LineIntersection2D<T> {
+    /// 交点を取得（1点で交わる場合のみ）
+    pub fn point(&self) -> Option<Point2D<T>> {
+        match self {
+            Self::Point(point) => Some(*point),
+            _ => None,
+        }
+    }
+}
+
+impl<T: Scalar> InfiniteLine2D<T> {
+    /// 他の直線との交差を判定（デフォルト許容誤差）
+    pub fn intersect(&self, other: &InfiniteLine2D<T>) -> LineIntersection2D<T> {
+        self.intersect_with_tolerance(
+            other,
+            DefaultTolerances::angle::<T>(),
+            DefaultTolerances::distance::<T>(),
+        )
+    }
+
+    /// 他の直線との交差を判定（カスタム許容誤差）
+    ///
+    /// 単位方向ベクトルの外積（交差角の正弦）が `angle_tolerance` 以下なら平行とみなし、
+    /// さらに相手の基準点が `distance_tolerance` 以内にあれば同一直線とする。
+    pub fn intersect_with_tolerance(
+        &self,
+        other: &InfiniteLine2D<T>,
+        angle_tolerance: T,
+        distance_tolerance: T,
+    ) -> LineIntersection2D<T> {
+        let d1 = self.direction();
+        let d2 = other.direction();
+
+        if d1.cross(&d2).abs() <= angle_tolerance {
+            return if self.contains_point(&other.point(), distance_tolerance) {
+                LineIntersection2D::Coincident
+            } else {
+                LineIntersection2D::Parallel
+            };
+        }
+
+        // P1 + t1 * D1 = P2 + t2 * D2
+        // → | D1.x  -D2.x | |t1|   | P2.x - P1.x |
+        //   | D1.y  -D2.y | |t2| = | P2.y - P1.y |
+        let matrix = Matrix2x2::new(d1.x(), -d2.x(), d1.y(), -d2.y());
+        let rhs = Vector2::new(
+            other.point().x() - self.point().x(),
+            other.point().y() - self.point().y(),
+        );
+
+        match CramerSolver::new(T::EPSILON).solve_2x2(&matrix, &rhs) {
+            Ok(parameters) => LineIntersection2D::Point(self.point_at_parameter(parameters.x())),
+            // 外積判定を通過していれば特異にはならないが、念のため平行扱い
+            Err(_) => LineIntersection2D::Parallel,
+        }
+    }
+}
+
+// ============================================================================
+// BasicIntersection Implementations
+// ============================================================================
+
+// InfiniteLine2D vs InfiniteLine2D（1点で交わる場合のみ）
+impl<T: Scalar> BasicIntersection<T, InfiniteLine2D<T>> for InfiniteLine2D<T> {
+    type Point = Point2D<T>;
+
+    fn intersection_with(&self, other: &InfiniteLine2D<T>, tolerance: T) -> Option<Self::Point> {
+        self.intersect_with_tolerance(other, DefaultTolerances::angle::<T>(), tolerance)
+            .point()
+    }
+}
@@ -0,0 +1,62 @@
+//! InfiniteLine2D 交点計算のテスト
+
+use crate::infinite_line_2d_intersection::LineIntersection2D;
+use crate::{InfiniteLine2D, Point2D, Vector2D};
+use geo_foundation::BasicIntersection;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_intersect_crossing_lines() {
+        let line1 = InfiniteLine2D::horizontal(1.0_f64);
+        let line2 = InfiniteLine2D::vertical(2.0_f64);
+
+        match line1.intersect(&line2) {
+            LineIntersection2D::Point(p) => {
+                assert!((p.x() - 2.0).abs() < 1e-12);
+                assert!((p.y() - 1.0).abs() < 1e-12);
+            }
+            other => panic!("expected point, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_intersect_oblique_lines() {
+        let line1 =
+            InfiniteLine2D::from_two_points(Point2D::new(0.0_f64, 0.0), Point2D::new(2.0, 2.0))
+                .unwrap();
+        let line2 =
+            InfiniteLine2D::from_two_points(Point2D::new(0.0_f64, 4.0), Point2D::new(4.0, 0.0))
+                .unwrap();
+
+        let p = line1.intersect(&line2).point().unwrap();
+        assert!((p.x() - 2.0).abs() < 1e-12);
+        assert!((p.y() - 2.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_intersect_parallel_and_coincident() {
+        let line1 = InfiniteLine2D::horizontal(0.0_f64);
+        let line2 = InfiniteLine2D::horizontal(1.0_f64);
+        assert_eq!(line1.intersect(&line2), LineIntersection2D::Parallel);
+
+        // 逆向き・別の基準点でも同一直線と判定
+        let line3 =
+            InfiniteLine2D::new(Point2D::new(5.0_f64, 0.0), Vector2D::new(-1.0, 0.0)).unwrap();
+        assert_eq!(line1.intersect(&line3), LineIntersection2D::Coincident);
+    }
+
+    #[test]
+    fn test_basic_intersection_trait() {
+        let line1 = InfiniteLine2D::horizontal(3.0_f64);
+        let line2 = InfiniteLine2D::vertical(-1.0_f64);
+        let p = line1.intersection_with(&line2, 1e-10).unwrap();
+        assert!((p.x() + 1.0).abs() < 1e-12);
+        assert!((p.y() - 3.0).abs() < 1e-12);
+
+        let parallel = InfiniteLine2D::horizontal(4.0_f64);
+        assert!(line1.intersection_with(&parallel, 1e-10).is_none());
+    }
+}
@@ -0,0 +1,76 @@
+//! InfiniteLine3D の境界ボックスによる切り取りのテスト
+
+use crate::{BBox3D, InfiniteLine3D, LineSegment3D, Point3D, Vector3D};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unit_box() -> BBox3D<f64> {
+        BBox3D::new(Point3D::new(0.0, 0.0, 0.0), Point3D::new(1.0, 1.0, 1.0))
+    }
+
+    fn assert_point(actual: Point3D<f64>, expected: [f64; 3]) {
+        assert!(
+            actual.distance_to(&Point3D::new(expected[0], expected[1], expected[2])) < 1e-12,
+            "{actual:?} != {expected:?}"
+        );
+    }
+
+    #[test]
+    fn test_clip_axis_parallel_line() {
+        let line = InfiniteLine3D::new(Point3D::new(-5.0, 0.5, 0.5), Vector3D::new(1.0, 0.0, 0.0))
+            .unwrap();
+        let segment = line.clip_to_bbox(&unit_box()).unwrap();
+        assert_point(segment.start(), [0.0, 0.5, 0.5]);
+        assert_point(segment.end(), [1.0, 0.5, 0.5]);
+    }
+
+    #[test]
+    fn test_clip_keeps_line_direction() {
+        // 逆向き・斜めの直線でも線分は直線の向きに沿う
+        let line =
+            InfiniteLine3D::new(Point3D::new(2.0, 2.0, 2.0), Vector3D::new(-1.0, -1.0, -1.0))
+                .unwrap();
+        let segment = line.clip_to_bbox(&unit_box()).unwrap();
+        assert_point(segment.start(), [1.0, 1.0, 1.0]);
+        assert_point(segment.end(), [0.0, 0.0, 0.0]);
+        assert!(segment.direction().dot(&Vector3D::new(-1.0, -1.0, -1.0)) > 0.0);
+    }
+
+    #[test]
+    fn test_clip_misses_box() {
+        // 平行な軸でスラブの外
+        let parallel =
+            InfiniteLine3D::new(Point3D::new(0.5, 2.0, 0.5), Vector3D::new(1.0, 0.0, 0.0)).unwrap();
+        assert!(parallel.clip_to_bbox(&unit_box()).is_none());
+
+        // 斜めにボックスの脇を通過
+        let oblique =
+            InfiniteLine3D::new(Point3D::new(0.0, 3.0, 0.5), Vector3D::new(1.0, -1.0, 0.0))
+                .unwrap();
+        assert!(oblique.clip_to_bbox(&unit_box()).is_none());
+
+        // 角に1点でだけ接する
+        let touching =
+            InfiniteLine3D::new(Point3D::new(0.0, 2.0, 0.0), Vector3D::new(1.0, -1.0, 0.0))
+                .unwrap();
+        assert!(touching.clip_to_bbox(&unit_box()).is_none());
+    }
+
+    #[test]
+    fn test_line_segment_to_infinite_line_round_trip() {
+        let segment = LineSegment3D::new(
+            Point3D::new(0.25, 0.25, -1.0),
+            Point3D::new(0.25, 0.25, 3.0),
+        )
+        .unwrap();
+        let line = segment.to_infinite_line();
+        assert!(line.contains_point(&segment.start(), 1e-12));
+        assert!(line.contains_point(&segment.end(), 1e-12));
+
+        let clipped = line.clip_to_bbox(&unit_box()).unwrap();
+        assert_point(clipped.start(), [0.25, 0.25, 0.0]);
+        assert_point(clipped.end(), [0.25, 0.25, 1.0]);
+    }
+}
@@ -2,7 +2,9 @@
 //!
 //! Extension Foundation パターンに基づく InfiniteLine3D の拡張実装
 
-use crate::{Direction3D, InfiniteLine3D, Point2D, Point3D, Vector2D, Vector3D};
+use crate::{
+    BBox3D, Direction3D, InfiniteLine3D, LineSegment3D, Point2D, Point3D, Vector2D, Vector3D,
+};
 use geo_foundation::Scalar;
 
 // ============================================================================
@@ -278,6 +280,48 @@ impl<T: Scalar> InfiniteLine3D<T> {
 
         to_other_point.dot(&direction_cross).abs() / cross_length
     }
+
+    // ========================================================================
+    // Clipping (Extension)
+    // ========================================================================
+
+    /// 境界ボックスで切り取った線分を取得（スラブ法）
+    ///
+    /// 各軸のスラブ（min〜max の区間）に入る・出るパラメータを求め、その共通区間を線分とする。
+    /// 直線がボックスと交わらない場合、または角・辺に1点でだけ接する場合は `None`。
+    pub fn clip_to_bbox(&self, bbox: &BBox3D<T>) -> Option<LineSegment3D<T>> {
+        let origin = self.point();
+        let direction = self.direction();
+        let (min, max) = (bbox.min(), bbox.max());
+
+        let mut t_enter = -T::INFINITY;
+        let mut t_exit = T::INFINITY;
+        for (o, d, lo, hi) in [
+            (origin.x(), direction.x(), min.x(), max.x()),
+            (origin.y(), direction.y(), min.y(), max.y()),
+            (origin.z(), direction.z(), min.z(), max.z()),
+        ] {
+            if d.abs() <= T::EPSILON {
+                // 軸に平行：スラブの外なら交わらない
+                if o < lo || o > hi {
+                    return None;
+                }
+                continue;
+            }
+            let (t0, t1) = ((lo - o) / d, (hi - o) / d);
+            let (t_near, t_far) = if t0 <= t1 { (t0, t1) } else { (t1, t0) };
+            t_enter = t_enter.max(t_near);
+            t_exit = t_exit.min(t_far);
+            if t_enter > t_exit {
+                return None;
+            }
+        }
+
+        LineSegment3D::new(
+            self.point_at_parameter(t_enter),
+            self.point_at_parameter(t_exit),
+        )
+    }
 }
 
 /// 3次元直線間の関係を表す列挙型
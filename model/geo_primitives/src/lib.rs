@@ -12,6 +12,7 @@ pub use geo_foundation::TransformError;
 pub mod arc_3d;
 pub mod arc_3d_extensions; // Arc3D の拡張機能 (Extension)
 pub mod arc_3d_foundation; // Arc3D のFoundation実装
+pub mod arc_3d_transform; // Arc3D のAnalysisTransform実装
 pub mod bbox_3d; // BBox3D の新実装
 pub mod bbox_3d_extensions; // BBox3D の拡張機能 (Extension)
 pub mod bbox_3d_foundation; // BBox3D のFoundation実装
@@ -19,6 +20,7 @@ pub mod circle_3d; // Circle3D の新実装
 pub mod circle_3d_extensions; // Circle3D の拡張機能 (Extension)
 pub mod circle_3d_foundation; // Circle3D のFoundation実装
 pub mod circle_3d_tests; // Circle3D のテスト
+pub mod circle_3d_transform; // Circle3D のAnalysisTransform実装
 pub mod conical_solid_3d; // ConicalSolid3D の新実装 (Core) - 完全ハイブリッドモデラー対応
 pub mod conical_solid_3d_extensions; // ConicalSolid3D の拡張機能 (Extension)
 pub mod conical_solid_3d_foundation; // ConicalSolid3D のFoundation実装
@@ -41,8 +43,10 @@ pub mod cylindrical_surface_3d_foundation; // CylindricalSurface3D のFoundation
 pub mod cylindrical_surface_3d_tests; // CylindricalSurface3D のテスト
 pub mod direction_3d; // Direction3D の新実装 (Core)
 pub mod direction_3d_extensions;
+pub mod direction_3d_transform; // Direction3D のAnalysisTransform実装
 pub mod ellipse_3d; // Ellipse3D の新実装 (Core)
 pub mod ellipse_3d_extensions; // Ellipse3D の拡張機能 (Extension)
+pub mod ellipse_3d_transform; // Ellipse3D のAnalysisTransform実装
 pub mod ellipse_arc_3d; // EllipseArc3D の実装 (Core)
 pub mod ellipse_arc_3d_extensions; // EllipseArc3D の拡張機能 (Extension)
 pub mod ellipse_arc_3d_tests; // EllipseArc3D のテスト
@@ -51,6 +55,7 @@ pub mod infinite_line_3d; // InfiniteLine3D の新実装
 pub mod infinite_line_3d_extensions; // InfiniteLine3D の拡張機能 (Extension)
 pub mod line_segment_3d; // LineSegment3D の新実装 (Core)
 pub mod line_segment_3d_extensions; // LineSegment3D の拡張機能 (Extension)
+pub mod line_segment_3d_transform; // LineSegment3D のAnalysisTransform実装
 pub mod plane_3d; // Plane3D の新実装 (Core)
 pub mod plane_3d_extensions; // Plane3D の拡張機能 (Extension)
 pub mod plane_3d_foundation; // Plane3D のFoundation実装
@@ -58,6 +63,7 @@ pub mod plane_3d_foundation; // Plane3D のFoundation実装
 #[cfg(test)]
 pub mod plane_3d_tests; // Plane3D のテスト
                         // 削除: plane_coordinate_systemはPlane3Dに統合済み
+pub mod plane_3d_transform; // Plane3D のAnalysisTransform実装
 pub mod point_3d; // Point3D の新実装 (Core)
 pub mod point_3d_core_traits; // Point3D の Core traits 実装
 pub mod point_3d_extensions; // Point3D の拡張機能 (Extension)
@@ -67,8 +73,10 @@ pub mod ray_3d_extensions; // Ray3D の拡張機能 (Extension)
 pub mod ray_3d_foundation; // Ray3D のFoundation実装
 pub mod spherical_solid_3d; // SphericalSolid3D の新実装 (Core) - 完全ハイブリッドモデラー対応
 pub mod spherical_solid_3d_foundation; // SphericalSolid3D のFoundation実装
+pub mod spherical_solid_3d_transform; // SphericalSolid3D のAnalysisTransform実装
 pub mod spherical_surface_3d; // SphericalSurface3D の新実装 (Core) - 完全ハイブリッドモデラー対応
 pub mod spherical_surface_3d_foundation; // SphericalSurface3D のFoundation実装
+pub mod spherical_surface_3d_transform; // SphericalSurface3D のAnalysisTransform実装
 pub mod torus_solid_3d; // TorusSolid3D の新実装 (Core) - 3D CAM 固体加工対応
 pub mod torus_solid_3d_extensions; // TorusSolid3D の拡張機能 (Extension)
 pub mod torus_solid_3d_foundation; // TorusSolid3D のFoundation実装
@@ -78,6 +86,8 @@ pub mod torus_surface_3d_foundation; // TorusSurface3D のFoundation実装
 pub mod triangle_3d; // Triangle3D の新実装 (Core)
 pub mod triangle_3d_foundation; // Triangle3D のFoundation実装
 pub mod triangle_mesh_3d; // TriangleMesh3D の新実装 (Core)
+#[cfg(feature = "test-fixtures")]
+pub mod triangle_mesh_3d_fixtures; // 他クレートのテスト共通の TriangleMesh3D フィクスチャ
 pub mod triangle_mesh_3d_foundation; // TriangleMesh3D のFoundation実装
 pub mod triangle_mesh_3d_transform; // TriangleMesh3D のAnalysisTransform実装
 
@@ -86,9 +96,24 @@ pub mod vector_3d; // Vector3D の新実装
 #[cfg(test)]
 pub mod vector_3d_extensions; // Vector3D の拡張機能 (Extension)
 pub mod vector_3d_foundation; // Vector3D のFoundation実装
+pub mod vector_3d_transform; // Vector3D のAnalysisTransform実装
+
+// 分類情報（GeometryPrimitive）の一括実装
+mod primitive_classification;
+
+// 点・ベクトル・方向の許容誤差付き等価比較
+mod tolerant_eq;
+
+// 有界な3Dプリミティブのタイトなバウンディングボックス（Bounded3D）
+mod bounded_3d;
+
+// 曲線系3Dプリミティブのパラメトリック曲線インターフェース（ParametricCurve3D）
+mod parametric_curve_3d;
 
 // Transform テストモジュール
 #[cfg(test)]
+mod analysis_transform_roundtrip_tests;
+#[cfg(test)]
 pub mod ray_3d_tests;
 #[cfg(test)]
 pub mod triangle_3d_tests;
@@ -96,25 +121,32 @@ pub mod triangle_3d_tests;
 pub mod triangle_mesh_3d_tests;
 
 // Vector3D テストモジュール
+#[cfg(test)]
+mod vector_3d_transform_tests;
 // pub mod vector_3d_transform_safe_tests; // 削除済み
 
 // 2D プリミティブ
 // Arc2D関連（ジェネリック実装完了により再有効化）
-// pub mod arc_2d; // Arc2D の新実装 (Core) - 依存関係エラーのため一時無効化
+// pub mod arc_2d_extensions; // Arc2D 拡張群 - 旧API依存のため一時無効化
 // pub mod arc_2d_collision; // Arc2D 衝突検出・距離計算Foundation実装 - Arc2D依存のため一時無効化
+pub mod arc_2d; // Arc2D の新実装 (Core)
 pub mod bbox_2d; // BBox2D の新実装 (Core)
 pub mod bbox_2d_extensions; // BBox2D の拡張機能 (Extension)
 pub mod circle_2d; // Circle2D の新実装 (Core)
+pub mod circle_2d_intersection; // Circle2D の交点計算Foundation実装
 pub mod circle_2d_metrics; // Circle2D 計量演算
 pub mod direction_2d; // Direction2D の新実装 (Core)
 pub mod direction_2d_extensions;
 pub mod ellipse_2d; // Ellipse2D の実装 (新traitsシステム対応)
 pub mod ellipse_arc_2d; // EllipseArc2D の実装 (Core)
 pub mod ellipse_arc_2d_extensions; // EllipseArc2D の拡張機能 (Extension)
+pub mod ellipse_arc_2d_intersection; // EllipseArc2D の交点計算Foundation実装
 pub mod infinite_line_2d; // InfiniteLine2D の新実装
 pub mod infinite_line_2d_extensions; // InfiniteLine2D の拡張機能 (Extension)
+pub mod infinite_line_2d_intersection; // InfiniteLine2D の交点計算Foundation実装
 pub mod line_segment_2d; // LineSegment2D の新実装 (Core)
 pub mod line_segment_2d_extensions; // LineSegment2D の拡張機能 (Extension)
+pub mod line_segment_2d_intersection; // LineSegment2D の交点計算Foundation実装
 pub mod point_2d; // Point2D の新実装
 pub mod point_2d_core_traits; // Point2D の Core traits 実装
 pub mod point_2d_extensions; // Point2D の拡張機能 (Extension)
@@ -149,14 +181,32 @@ mod direction_2d_extensions_tests;
 mod direction_3d_extensions_tests;
 // mod ellipse_arc_2d_tests; // 未実装Transform機能のため無効化
 #[cfg(test)]
+mod circle_2d_intersection_tests;
+#[cfg(test)]
+mod ellipse_arc_2d_intersection_tests;
+#[cfg(test)]
 mod foundation_tests;
+#[cfg(test)]
+mod infinite_line_2d_intersection_tests;
+#[cfg(test)]
+mod line_segment_2d_intersection_tests;
 // mod infinite_line_2d_tests; // 未実装Transform機能のため無効化
 // mod infinite_line_3d_tests; // 未実装Transform機能のため無効化
 #[cfg(test)]
+mod bounded_3d_tests;
+#[cfg(test)]
+mod infinite_line_3d_clip_tests;
+#[cfg(test)]
+mod parametric_curve_3d_tests;
+#[cfg(test)]
 mod point_2d_tests;
+#[cfg(test)]
+mod tolerant_eq_tests;
 // pub mod point_2d_transform_safe_tests; // 削除済み
 // mod ray_2d_tests; // 未実装Transform機能のため無効化
 #[cfg(test)]
+mod ray_2d_reflection_tests;
+#[cfg(test)]
 mod vector_2d_tests; // Foundation traitの動作確認テスト
 
 // Vector2D テストモジュール
@@ -183,6 +233,7 @@ pub use geo_foundation::extensions::{
 pub use arc_3d::Arc3D;
 pub use bbox_3d::BBox3D;
 pub use circle_3d::Circle3D;
+pub use circle_3d_extensions::CircleTransformResult;
 pub use conical_solid_3d::{Cone3D, ConicalSolid3D}; // 新式円錐ソリッド + 互換エイリアス
 pub use conical_surface_3d::{ConeRim3D, ConicalSurface3D}; // 新式円錐サーフェス + 互換エイリアス
 pub use cylindrical_solid_3d::CylindricalSolid3D; // 新式ソリッド
@@ -207,14 +258,17 @@ pub use triangle_mesh_3d::TriangleMesh3D;
 pub use vector_3d::Vector3D;
 
 // 2D プリミティブ
-// pub use arc_2d::Arc2D; // 一時無効化
 pub use crate::bbox_2d::BBox2D;
+pub use arc_2d::Arc2D;
 pub use circle_2d::Circle2D;
+pub use circle_2d_intersection::CircleCircleIntersection;
 pub use direction_2d::Direction2D;
 pub use ellipse_2d::Ellipse2D;
 pub use ellipse_arc_2d::EllipseArc2D; // 楕円弧
 pub use infinite_line_2d::InfiniteLine2D;
+pub use infinite_line_2d_intersection::LineIntersection2D;
 pub use line_segment_2d::LineSegment2D;
+pub use line_segment_2d_intersection::SegmentIntersection;
 pub use point_2d::Point2D;
 pub use ray_2d::Ray2D;
 pub use triangle_2d::Triangle2D;
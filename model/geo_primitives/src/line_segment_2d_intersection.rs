@@ -0,0 +1,159 @@
+//! LineSegment2D 交点計算Foundation実装
+//!
+//! 線分同士の交差（交点・共線重なり）をパラメータ範囲 [0, 1] で判定する
+
+use crate::{LineSegment2D, Point2D, Vector2D};
+use geo_foundation::{tolerance_migration::DefaultTolerances, BasicIntersection, Scalar};
+
+/// 2本の線分の交差結果
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SegmentIntersection<T: Scalar> {
+    /// 交差しない
+    None,
+    /// 1点で交わる（端点同士の接触を含む）
+    Point(Point2D<T>),
+    /// 共線で重なる区間
+    Overlap(LineSegment2D<T>),
+}
+
+impl<T: Scalar> SegmentIntersection<T> {
+    /// 交差があるかどうか
+    pub fn is_intersecting(&self) -> bool {
+        !matches!(self, Self::None)
+    }
+
+    /// 交点を取得（1点で交わる場合のみ）
+    pub fn point(&self) -> Option<Point2D<T>> {
+        match self {
+            Self::Point(point) => Some(*point),
+            _ => None,
+        }
+    }
+}
+
+impl<T: Scalar> LineSegment2D<T> {
+    /// 他の線分との交差を判定（デフォルト許容誤差）
+    pub fn intersect_segment(&self, other: &LineSegment2D<T>) -> SegmentIntersection<T> {
+        self.intersect_segment_with_tolerance(other, DefaultTolerances::distance::<T>())
+    }
+
+    /// 他の線分との交差を判定（距離許容誤差を指定）
+    ///
+    /// 交点が両線分のパラメータ範囲 `[0, 1]` に `tolerance` 込みで収まれば `Point` を返す。
+    /// 交点が端点から `tolerance` 以内の場合は端点そのものに吸着させるため、
+    /// 端点同士の接触は共有端点の座標で返る。
+    pub fn intersect_segment_with_tolerance(
+        &self,
+        other: &LineSegment2D<T>,
+        tolerance: T,
+    ) -> SegmentIntersection<T> {
+        let a = self.start_point();
+        let b = self.end_point();
+        let c = other.start_point();
+        let d = other.end_point();
+
+        let r = Vector2D::from_points(a, b);
+        let s = Vector2D::from_points(c, d);
+        let ac = Vector2D::from_points(a, c);
+        let r_length = r.length();
+        let s_length = s.length();
+        let denominator = r.cross(&s);
+
+        // 単位方向の外積（交差角の正弦）で平行判定
+        if denominator.abs() <= DefaultTolerances::angle::<T>() * r_length * s_length {
+            return Self::collinear_overlap(a, r, c, d, tolerance);
+        }
+
+        // a + t * r = c + u * s
+        let t = ac.cross(&s) / denominator;
+        let u = ac.cross(&r) / denominator;
+        let t_tolerance = tolerance / r_length;
+        let u_tolerance = tolerance / s_length;
+
+        let in_range = |value: T, param_tolerance: T| {
+            value >= -param_tolerance && value <= T::ONE + param_tolerance
+        };
+        if !in_range(t, t_tolerance) || !in_range(u, u_tolerance) {
+            return SegmentIntersection::None;
+        }
+
+        // 端点付近の交点は端点に吸着（接触ケースの座標を安定させる）
+        let point = if t.abs() <= t_tolerance {
+            a
+        } else if (t - T::ONE).abs() <= t_tolerance {
+            b
+        } else if u.abs() <= u_tolerance {
+            c
+        } else if (u - T::ONE).abs() <= u_tolerance {
+            d
+        } else {
+            a + r * t
+        };
+
+        SegmentIntersection::Point(point)
+    }
+
+    /// 平行な線分の共線重なりを判定
+    fn collinear_overlap(
+        a: Point2D<T>,
+        r: Vector2D<T>,
+        c: Point2D<T>,
+        d: Point2D<T>,
+        tolerance: T,
+    ) -> SegmentIntersection<T> {
+        let r_length_squared = r.length_squared();
+        let r_length = r_length_squared.sqrt();
+
+        // 相手の始点が直線上にない場合は単なる平行
+        let ac = Vector2D::from_points(a, c);
+        if ac.cross(&r).abs() / r_length > tolerance {
+            return SegmentIntersection::None;
+        }
+
+        // 相手の端点を自分のパラメータ空間に投影し、[0, 1] と重なる区間を求める
+        let t0 = ac.dot(&r) / r_length_squared;
+        let t1 = Vector2D::from_points(a, d).dot(&r) / r_length_squared;
+        let (t_min, t_max) = if t0 <= t1 { (t0, t1) } else { (t1, t0) };
+        let low = t_min.max(T::ZERO);
+        let high = t_max.min(T::ONE);
+        let param_tolerance = tolerance / r_length;
+
+        if high < low - param_tolerance {
+            return SegmentIntersection::None;
+        }
+        if high - low <= param_tolerance {
+            // 端点同士が接するだけの場合は1点
+            let t = (low + high) / (T::ONE + T::ONE);
+            let point = if t <= param_tolerance {
+                a
+            } else if t >= T::ONE - param_tolerance {
+                a + r
+            } else {
+                a + r * t
+            };
+            return SegmentIntersection::Point(point);
+        }
+
+        match LineSegment2D::new(a + r * low, a + r * high) {
+            Some(segment) => SegmentIntersection::Overlap(segment),
+            None => SegmentIntersection::Point(a + r * low),
+        }
+    }
+}
+
+// ============================================================================
+// BasicIntersection Implementations
+// ============================================================================
+
+// LineSegment2D vs LineSegment2D（重なる場合は重なり区間の始点）
+impl<T: Scalar> BasicIntersection<T, LineSegment2D<T>> for LineSegment2D<T> {
+    type Point = Point2D<T>;
+
+    fn intersection_with(&self, other: &LineSegment2D<T>, tolerance: T) -> Option<Self::Point> {
+        match self.intersect_segment_with_tolerance(other, tolerance) {
+            SegmentIntersection::None => None,
+            SegmentIntersection::Point(point) => Some(point),
+            SegmentIntersection::Overlap(segment) => Some(segment.start_point()),
+        }
+    }
+}
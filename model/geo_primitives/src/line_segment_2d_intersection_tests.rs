@@ -0,0 +1,78 @@
+//! LineSegment2D 交点計算のテスト
+
+use crate::line_segment_2d_intersection::SegmentIntersection;
+use crate::{LineSegment2D, Point2D};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segment(x0: f64, y0: f64, x1: f64, y1: f64) -> LineSegment2D<f64> {
+        LineSegment2D::new(Point2D::new(x0, y0), Point2D::new(x1, y1)).unwrap()
+    }
+
+    #[test]
+    fn test_crossing_segments() {
+        let s1 = segment(0.0, 0.0, 4.0, 0.0);
+        let s2 = segment(2.0, -1.0, 2.0, 1.0);
+
+        let p = s1.intersect_segment(&s2).point().unwrap();
+        assert!((p.x() - 2.0).abs() < 1e-12);
+        assert!(p.y().abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_lines_cross_outside_segments() {
+        let s1 = segment(0.0, 0.0, 4.0, 0.0);
+        let s2 = segment(5.0, -1.0, 5.0, 1.0);
+        assert_eq!(s1.intersect_segment(&s2), SegmentIntersection::None);
+
+        // 平行だが共線でない
+        let s3 = segment(0.0, 1.0, 4.0, 1.0);
+        assert_eq!(s1.intersect_segment(&s3), SegmentIntersection::None);
+    }
+
+    #[test]
+    fn test_endpoint_contact() {
+        // L字に端点が一致
+        let s1 = segment(0.0, 0.0, 1.0, 0.0);
+        let s2 = segment(1.0, 0.0, 1.0, 1.0);
+        assert_eq!(
+            s1.intersect_segment(&s2),
+            SegmentIntersection::Point(Point2D::new(1.0, 0.0))
+        );
+
+        // 許容誤差内で端点が僅かに離れている
+        let s3 = segment(1.0 + 1e-9, 0.0, 2.0, 1.0);
+        assert_eq!(
+            s1.intersect_segment_with_tolerance(&s3, 1e-6),
+            SegmentIntersection::Point(Point2D::new(1.0, 0.0))
+        );
+
+        // 共線で端点のみ接触
+        let s4 = segment(1.0, 0.0, 3.0, 0.0);
+        assert_eq!(
+            s1.intersect_segment(&s4),
+            SegmentIntersection::Point(Point2D::new(1.0, 0.0))
+        );
+    }
+
+    #[test]
+    fn test_collinear_overlap() {
+        let s1 = segment(0.0, 0.0, 4.0, 0.0);
+        // 逆向きで部分的に重なる
+        let s2 = segment(6.0, 0.0, 2.0, 0.0);
+
+        match s1.intersect_segment(&s2) {
+            SegmentIntersection::Overlap(overlap) => {
+                assert!((overlap.start_point().x() - 2.0).abs() < 1e-12);
+                assert!((overlap.end_point().x() - 4.0).abs() < 1e-12);
+            }
+            other => panic!("expected overlap, got {:?}", other),
+        }
+
+        // 共線だが離れている
+        let s3 = segment(5.0, 0.0, 6.0, 0.0);
+        assert_eq!(s1.intersect_segment(&s3), SegmentIntersection::None);
+    }
+}
@@ -3,8 +3,8 @@
 //! Foundation統一システムに基づくLineSegment3Dの拡張機能
 //! Core機能は line_segment_3d.rs を参照
 
-use crate::{BBox3D, LineSegment3D, Point3D, Vector3D};
-use geo_foundation::{core_foundation::*, Scalar};
+use crate::{BBox3D, InfiniteLine3D, LineSegment3D, Point3D, Vector3D};
+use geo_foundation::{core_foundation::*, PointDistance, Scalar};
 
 // ============================================================================
 // Core trait implementations
@@ -154,6 +154,11 @@ impl<T: Scalar> LineSegment3D<T> {
         self.point_at_parameter(ratio)
     }
 
+    /// 線分を含む無限直線を取得
+    pub fn to_infinite_line(&self) -> InfiniteLine3D<T> {
+        self.line().clone()
+    }
+
     /// 線分を等分割する点を取得
     pub fn subdivide(&self, segments: usize) -> Vec<Point3D<T>> {
         if segments == 0 {
@@ -178,3 +183,53 @@ impl<T: Scalar> BasicMetrics<T> for LineSegment3D<T> {
         Some(self.length())
     }
 }
+
+impl<T: Scalar> PointDistance<T> for LineSegment3D<T> {
+    type Point2D = Point3D<T>;
+
+    /// 点までの最短距離（射影が範囲外なら端点まで）
+    fn distance_to_point(&self, point: &Point3D<T>) -> T {
+        LineSegment3D::distance_to_point(self, point)
+    }
+
+    /// 点が線分上にあるか
+    fn contains_point(&self, point: &Point3D<T>, tolerance: T) -> bool {
+        LineSegment3D::contains_point(self, point, tolerance)
+    }
+
+    /// 点が端点上にあるか
+    fn point_on_boundary(&self, point: &Point3D<T>, tolerance: T) -> bool {
+        point.distance_to(&self.start()) <= tolerance || point.distance_to(&self.end()) <= tolerance
+    }
+
+    /// 線分上の最近点（射影を線分範囲にクランプ）
+    fn closest_point(&self, point: &Point3D<T>) -> Point3D<T> {
+        self.project_point(point)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_point_distance_clamps_to_segment() {
+        let segment =
+            LineSegment3D::new(Point3D::new(0.0, 0.0, 0.0), Point3D::new(4.0, 0.0, 0.0)).unwrap();
+
+        // 射影が範囲内
+        let (distance, closest) = segment.distance_to_with_closest(&Point3D::new(1.0, 3.0, 0.0));
+        assert!((distance - 3.0).abs() < 1e-10);
+        assert!(closest.distance_to(&Point3D::new(1.0, 0.0, 0.0)) < 1e-10);
+
+        // 射影が範囲外：端点にクランプ
+        let (distance, closest) = segment.distance_to_with_closest(&Point3D::new(7.0, 0.0, 4.0));
+        assert!((distance - 5.0).abs() < 1e-10);
+        assert!(closest.distance_to(&segment.end()) < 1e-10);
+        let (_, closest) = segment.distance_to_with_closest(&Point3D::new(-1.0, 1.0, 0.0));
+        assert!(closest.distance_to(&segment.start()) < 1e-10);
+
+        assert!(segment.point_on_boundary(&Point3D::new(4.0, 0.0, 0.0), 1e-10));
+        assert!(!segment.point_on_boundary(&Point3D::new(2.0, 0.0, 0.0), 1e-10));
+    }
+}
@@ -0,0 +1,102 @@
+//! 曲線系3Dプリミティブの ParametricCurve3D 実装
+//!
+//! パラメータは線分が始点からの距離、円弧・楕円弧が角度（ラジアン）。
+//! 楕円弧の長さは閉じた式がないため、速さ `|tangent_at(t)|` を区間分割した
+//! Gauss-Legendre 求積で積分する。
+
+use crate::{Arc3D, EllipseArc3D, LineSegment3D, Point3D, Vector3D};
+use analysis::numerics::composite_gauss_legendre;
+use geo_foundation::{ParametricCurve3D, Scalar};
+
+/// 楕円弧の長さの積分で使う Gauss-Legendre 則の点数
+const LENGTH_QUADRATURE_POINTS: usize = 10;
+
+/// 楕円弧の長さの積分で定義域を分ける数（全周あたり）
+const LENGTH_INTERVALS_PER_TURN: usize = 16;
+
+impl<T: Scalar> ParametricCurve3D<T> for LineSegment3D<T> {
+    type Point = Point3D<T>;
+    type Vector = Vector3D<T>;
+
+    /// 始点から距離 `t` の点
+    fn point_at(&self, t: T) -> Point3D<T> {
+        self.start() + self.direction() * t
+    }
+
+    /// 単位方向ベクトル（距離パラメータなので長さ1）
+    fn tangent_at(&self, _t: T) -> Vector3D<T> {
+        self.direction()
+    }
+
+    fn domain(&self) -> (T, T) {
+        (T::ZERO, LineSegment3D::length(self))
+    }
+
+    fn length(&self) -> T {
+        LineSegment3D::length(self)
+    }
+}
+
+impl<T: Scalar> ParametricCurve3D<T> for Arc3D<T> {
+    type Point = Point3D<T>;
+    type Vector = Vector3D<T>;
+
+    /// 角度 `t`（ラジアン）の点
+    fn point_at(&self, t: T) -> Point3D<T> {
+        self.point_at_angle(t)
+    }
+
+    /// `r(-sin t · u + cos t · v)`（u は開始方向、v = 法線 × u）
+    fn tangent_at(&self, t: T) -> Vector3D<T> {
+        let u = self.start_direction().as_vector();
+        let v = self.normal().as_vector().cross(&u);
+        (v * t.cos() - u * t.sin()) * self.radius()
+    }
+
+    fn domain(&self) -> (T, T) {
+        let start = self.start_angle().to_radians();
+        (start, start + self.angle_span().to_radians())
+    }
+
+    fn length(&self) -> T {
+        self.arc_length()
+    }
+}
+
+impl<T: Scalar> ParametricCurve3D<T> for EllipseArc3D<T> {
+    type Point = Point3D<T>;
+    type Vector = Vector3D<T>;
+
+    /// 離心角 `t`（ラジアン）の点（弧の範囲への丸めは行わない）
+    fn point_at(&self, t: T) -> Point3D<T> {
+        self.ellipse().point_at_parameter(t)
+    }
+
+    fn tangent_at(&self, t: T) -> Vector3D<T> {
+        self.ellipse().tangent_at_parameter(t)
+    }
+
+    fn domain(&self) -> (T, T) {
+        let start = self.start_angle().to_radians();
+        (start, start + self.angle_span())
+    }
+
+    fn length(&self) -> T {
+        let (start, end) = ParametricCurve3D::domain(self);
+        let intervals = (T::from_usize(LENGTH_INTERVALS_PER_TURN) * (end - start) / T::TAU)
+            .ceil()
+            .to_f64()
+            .max(1.0) as usize;
+        T::from_f64(composite_gauss_legendre(
+            |t| {
+                ParametricCurve3D::tangent_at(self, T::from_f64(t))
+                    .length()
+                    .to_f64()
+            },
+            start.to_f64(),
+            end.to_f64(),
+            LENGTH_QUADRATURE_POINTS,
+            intervals,
+        ))
+    }
+}
@@ -0,0 +1,130 @@
+//! ParametricCurve3D（曲線の統一インターフェース）のテスト
+
+use crate::{Arc3D, Ellipse3D, EllipseArc3D, LineSegment3D, Point3D, Vector3D};
+use geo_foundation::{Angle, ParametricCurve3D, ParametricGeometry};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_point(actual: Point3D<f64>, expected: Point3D<f64>) {
+        assert!(
+            actual.distance_to(&expected) < 1e-12,
+            "{actual:?} != {expected:?}"
+        );
+    }
+
+    /// 曲線種に依存しない折れ線近似の長さ
+    fn polyline_length<C>(curve: &C, count: usize) -> f64
+    where
+        C: ParametricCurve3D<f64, Point = Point3D<f64>>,
+    {
+        curve
+            .sample_uniform(count)
+            .windows(2)
+            .map(|pair| pair[0].distance_to(&pair[1]))
+            .sum()
+    }
+
+    /// 始点・終点が定義域の両端に対応する
+    fn assert_endpoints<C>(curve: &C, start: Point3D<f64>, end: Point3D<f64>)
+    where
+        C: ParametricCurve3D<f64, Point = Point3D<f64>>,
+    {
+        let (t0, t1) = curve.domain();
+        assert!(t0 < t1);
+        assert_point(curve.point_at(t0), start);
+        assert_point(curve.point_at(t1), end);
+    }
+
+    #[test]
+    fn test_line_segment_uses_distance() {
+        let segment =
+            LineSegment3D::new(Point3D::new(1.0, 0.0, 0.0), Point3D::new(1.0, 3.0, 4.0)).unwrap();
+        assert_eq!(ParametricCurve3D::domain(&segment), (0.0, 5.0));
+        assert_endpoints(&segment, segment.start(), segment.end());
+        assert_point(
+            ParametricCurve3D::point_at(&segment, 2.5),
+            Point3D::new(1.0, 1.5, 2.0),
+        );
+        assert!((ParametricCurve3D::tangent_at(&segment, 1.0).length() - 1.0).abs() < 1e-12);
+
+        let samples = segment.sample_uniform(3);
+        assert_eq!(samples.len(), 3);
+        assert_point(samples[1], segment.midpoint());
+        assert!(segment.sample_uniform(0).is_empty());
+        assert_eq!(segment.sample_uniform(1), vec![segment.start()]);
+    }
+
+    #[test]
+    fn test_arc_uses_angle_across_two_pi() {
+        // 300° から 60° まで（0° をまたぐ）
+        let arc = Arc3D::xy_arc(
+            Point3D::origin(),
+            2.0,
+            Angle::from_degrees(300.0),
+            Angle::from_degrees(60.0),
+        )
+        .unwrap();
+        let (t0, t1) = ParametricCurve3D::domain(&arc);
+        assert!((t0 - 300f64.to_radians()).abs() < 1e-12);
+        assert!((t1 - 420f64.to_radians()).abs() < 1e-12);
+        assert_endpoints(&arc, arc.start_point(), arc.end_point());
+
+        // 0° では +X 上、接線は +Y 向きで長さは半径
+        let middle = (t0 + t1) / 2.0;
+        assert_point(arc.point_at(middle), Point3D::new(2.0, 0.0, 0.0));
+        let tangent = ParametricCurve3D::tangent_at(&arc, middle);
+        assert!((tangent - Vector3D::new(0.0, 2.0, 0.0)).length() < 1e-12);
+
+        let length = ParametricCurve3D::length(&arc);
+        assert!((length - 2.0 * 120f64.to_radians()).abs() < 1e-12);
+        assert!((polyline_length(&arc, 200) - length).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_ellipse_arc_length_by_quadrature() {
+        let ellipse = Ellipse3D::new(
+            Point3D::new(1.0, 2.0, 3.0),
+            3.0,
+            1.0,
+            Vector3D::unit_z(),
+            Vector3D::unit_x(),
+        )
+        .unwrap();
+
+        // 全周はラマヌジャンの近似式と一致する（a = 3, b = 1 では相対誤差 1e-5 未満）
+        let full = EllipseArc3D::new(
+            ellipse.clone(),
+            Angle::from_degrees(0.0),
+            Angle::from_degrees(360.0),
+        );
+        let h = ((3.0_f64 - 1.0) / (3.0 + 1.0)).powi(2);
+        let ramanujan =
+            std::f64::consts::PI * 4.0 * (1.0 + 3.0 * h / (10.0 + (4.0 - 3.0 * h).sqrt()));
+        let length = ParametricCurve3D::length(&full);
+        assert!((length - ramanujan).abs() / ramanujan < 1e-5);
+        assert!((polyline_length(&full, 2000) - length).abs() < 1e-4);
+
+        // 1/4 周は全周の 1/4（対称性）
+        let quarter = EllipseArc3D::new(
+            ellipse,
+            Angle::from_degrees(90.0),
+            Angle::from_degrees(180.0),
+        );
+        assert!((ParametricCurve3D::length(&quarter) - length / 4.0).abs() < 1e-10);
+        assert_endpoints(&quarter, quarter.start_point(), quarter.end_point());
+    }
+
+    #[test]
+    fn test_parametric_geometry_uses_domain() {
+        let segment = LineSegment3D::new(Point3D::origin(), Point3D::new(3.0, 4.0, 0.0)).unwrap();
+        // 線分の定義域は (0, 5)
+        assert_eq!(segment.normalize_parameter(2.5), 0.5);
+        assert_eq!(segment.denormalize_parameter(0.2), 1.0);
+        assert!(segment.is_parameter_valid(5.0));
+        assert!(!segment.is_parameter_valid(-0.1));
+        assert_eq!(segment.clamp_parameter(7.0), 5.0);
+        assert_eq!(segment.clamp_parameter(-1.0), 0.0);
+    }
+}
@@ -1,8 +1,8 @@
 //! Plane3D の Foundation トレイト実装
 
-use crate::{BBox3D, Plane3D};
+use crate::{BBox3D, Plane3D, Point3D};
 use geo_foundation::{
-    extension_foundation::ExtensionFoundation, PrimitiveKind, Scalar, TolerantEq,
+    extension_foundation::ExtensionFoundation, PointDistance, PrimitiveKind, Scalar, TolerantEq,
 };
 
 // ============================================================================
@@ -65,6 +65,30 @@ impl<T: Scalar> TolerantEq<T> for Plane3D<T> {
     }
 }
 
+impl<T: Scalar> PointDistance<T> for Plane3D<T> {
+    type Point2D = Point3D<T>;
+
+    /// 点までの距離（符号なし）
+    fn distance_to_point(&self, point: &Point3D<T>) -> T {
+        Plane3D::distance_to_point(self, *point).abs()
+    }
+
+    /// 点が平面上にあるか
+    fn contains_point(&self, point: &Point3D<T>, tolerance: T) -> bool {
+        Plane3D::contains_point(self, *point, tolerance)
+    }
+
+    /// 無限平面には縁がないため、常に `false`
+    fn point_on_boundary(&self, _point: &Point3D<T>, _tolerance: T) -> bool {
+        false
+    }
+
+    /// 平面への正射影
+    fn closest_point(&self, point: &Point3D<T>) -> Point3D<T> {
+        self.project_point(*point)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -132,4 +156,21 @@ mod tests {
         // 異なる平面は等しくない
         assert!(!plane1.tolerant_eq(&plane2, tolerance));
     }
+
+    #[test]
+    fn test_point_distance() {
+        let plane = Plane3D::from_point_and_normal(
+            Point3D::new(0.0, 0.0, 1.0),
+            Vector3D::new(0.0, 0.0, 1.0),
+        )
+        .unwrap();
+        // 平面の裏側の点でも距離は正
+        let below = Point3D::new(2.0, -3.0, -1.0);
+        let (distance, closest) = plane.distance_to_with_closest(&below);
+        assert!((distance - 2.0).abs() < 1e-10);
+        assert!(closest.distance_to(&Point3D::new(2.0, -3.0, 1.0)) < 1e-10);
+        assert!(PointDistance::contains_point(&plane, &closest, 1e-10));
+        assert!(!plane.point_on_boundary(&below, 1e-10));
+        assert!(!plane.point_on_boundary(&closest, 1e-10));
+    }
 }
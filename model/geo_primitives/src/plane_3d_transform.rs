@@ -6,7 +6,10 @@
 
 use crate::{Plane3D, Point3D, Vector3D};
 use analysis::linalg::{matrix::Matrix4x4, vector::Vector3};
-use geo_foundation::{AnalysisTransform3D, Angle, Scalar, TransformError};
+use geo_foundation::{
+    check_non_singular, tolerance_migration::DefaultTolerances, AnalysisTransform3D, Angle, Scalar,
+    TransformError,
+};
 
 /// Plane3D用Analysis Matrix4x4変換モジュール
 pub mod analysis_transform {
@@ -35,21 +38,24 @@ pub mod analysis_transform {
 
     /// 平面の行列変換（Matrix4x4）
     ///
-    /// 平面上の点と法線ベクトルをMatrix変換し、新しい平面を構築
+    /// 平面上の点を変換し、法線は逆転置行列で変換して新しい平面を構築する。
+    /// 非一様スケールでも変換後の平面に垂直な法線になる。特異な行列は `SingularTransform`。
     pub fn transform_plane_3d<T: Scalar>(
         plane: &Plane3D<T>,
         matrix: &Matrix4x4<T>,
     ) -> Result<Plane3D<T>, TransformError> {
+        check_non_singular(matrix, DefaultTolerances::distance::<T>())?;
+
         // 平面上の点を変換
         let point_vec = point_to_analysis_vector(plane.point());
         let transformed_point_vec = matrix.transform_point_3d(&point_vec);
         let new_point = analysis_vector_to_point(transformed_point_vec);
 
-        // 法線ベクトルを変換（通常のベクトル変換を使用）
-        // 注意: 完全に正確な法線変換には逆転置行列が必要ですが、
-        // 単純化のため通常の変換を使用します
-        let normal_vec = vector_to_analysis_vector(plane.normal());
-        let transformed_normal_vec = matrix.transform_vector_3d(&normal_vec);
+        // 法線ベクトルを逆転置行列で変換
+        let normal_vec = vector_to_analysis_vector(plane.normal().as_vector());
+        let transformed_normal_vec = matrix
+            .transform_normal_3d(&normal_vec)
+            .map_err(TransformError::SingularTransform)?;
         let new_normal_vector = analysis_vector_to_vector(transformed_normal_vec);
 
         // 変換後の平面を構築
@@ -167,7 +173,7 @@ impl<T: Scalar> AnalysisTransform3D<T> for Plane3D<T> {
             self.point().y() + translation.y(),
             self.point().z() + translation.z(),
         );
-        Plane3D::from_point_and_normal(new_point, self.normal())
+        Plane3D::from_point_and_normal(new_point, self.normal().as_vector())
             .ok_or_else(|| TransformError::InvalidGeometry("Normal vector became zero".to_string()))
     }
 
@@ -393,4 +399,34 @@ mod tests {
         let result = plane.rotate_analysis(&center_plane, &zero_axis, angle);
         assert!(matches!(result, Err(TransformError::InvalidRotation(_))));
     }
+
+    #[test]
+    fn test_non_uniform_scale_normal() {
+        // 法線 (1, 1, 0) の平面を x 方向に 2 倍すると、法線は逆転置で (1, 2, 0) 方向になる
+        let plane = Plane3D::from_point_and_normal(
+            Point3D::new(1.0_f64, 0.0, 0.0),
+            Vector3D::new(1.0, 1.0, 0.0),
+        )
+        .unwrap();
+        let matrix = Matrix4x4::scale(2.0, 1.0, 1.0);
+
+        let result = analysis_transform::transform_plane_3d(&plane, &matrix).unwrap();
+        let expected = Vector3D::new(1.0, 2.0, 0.0).normalize();
+        assert!((result.normal().x() - expected.x()).abs() < 1e-10);
+        assert!((result.normal().y() - expected.y()).abs() < 1e-10);
+        assert!(result.normal().z().abs() < 1e-10);
+
+        // 変換後の点 (2, 0, 0) と (0, 1, 0) は変換後の平面上にある
+        assert!(result.distance_to_point(Point3D::new(2.0, 0.0, 0.0)).abs() < 1e-10);
+        assert!(result.distance_to_point(Point3D::new(0.0, 1.0, 0.0)).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_singular_matrix_error() {
+        let plane = create_test_plane();
+        let flattened = Matrix4x4::scale(1.0, 1.0, 0.0);
+
+        let result = analysis_transform::transform_plane_3d(&plane, &flattened);
+        assert!(matches!(result, Err(TransformError::SingularTransform(_))));
+    }
 }
@@ -41,13 +41,38 @@ impl<T: Scalar> Point3D<T> {
     // Interpolation Methods (Extension)
     // ========================================================================
 
-    /// 線形補間
+    /// 線形補間（t が [0, 1] の外では外挿）
     pub fn lerp(&self, other: &Self, t: T) -> Self {
-        Point3D::new(
-            self.x() + t * (other.x() - self.x()),
-            self.y() + t * (other.y() - self.y()),
-            self.z() + t * (other.z() - self.z()),
-        )
+        *self + (*other - *self) * t
+    }
+
+    /// 3点の重心座標補間 `u·a + v·b + w·c`
+    ///
+    /// 重みの和が1でない場合も正規化せずそのまま適用する
+    /// （正規化が必要なら `barycentric_interp_normalized` を使用）。
+    pub fn barycentric_interp(a: &Self, b: &Self, c: &Self, weights: (T, T, T)) -> Self {
+        let (u, v, w) = weights;
+        Self::from_vector(a.to_vector() * u + b.to_vector() * v + c.to_vector() * w)
+    }
+
+    /// 重みの和で正規化した重心座標補間（和がゼロの場合は `None`）
+    pub fn barycentric_interp_normalized(
+        a: &Self,
+        b: &Self,
+        c: &Self,
+        weights: (T, T, T),
+    ) -> Option<Self> {
+        let (u, v, w) = weights;
+        let sum = u + v + w;
+        if sum.abs() <= T::EPSILON {
+            return None;
+        }
+        Some(Self::barycentric_interp(
+            a,
+            b,
+            c,
+            (u / sum, v / sum, w / sum),
+        ))
     }
 
     /// 中点計算
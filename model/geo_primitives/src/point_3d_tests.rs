@@ -141,5 +141,31 @@ mod tests {
         assert_eq!(quarter.x(), 1.0);
         assert_eq!(quarter.y(), 1.5);
         assert_eq!(quarter.z(), 2.0);
+
+        // 範囲外は外挿
+        assert_eq!(point1.lerp(&point2, 1.5), Point3D::new(6.0, 9.0, 12.0));
+        assert_eq!(point1.lerp(&point2, -0.5), Point3D::new(-2.0, -3.0, -4.0));
+    }
+
+    #[test]
+    fn test_barycentric_interpolation() {
+        let a = Point3D::new(0.0, 0.0, 0.0);
+        let b = Point3D::new(3.0, 0.0, 0.0);
+        let c = Point3D::new(0.0, 3.0, 6.0);
+
+        // 頂点と重心
+        assert_eq!(Point3D::barycentric_interp(&a, &b, &c, (0.0, 1.0, 0.0)), b);
+        let centroid = Point3D::barycentric_interp(&a, &b, &c, (1.0 / 3.0, 1.0 / 3.0, 1.0 / 3.0));
+        assert!(centroid.distance_to(&Point3D::centroid_3(&a, &b, &c)) < 1e-12);
+
+        // 和が2の重みは正規化すると (0.25, 0.25, 0.5) と同じ
+        let normalized =
+            Point3D::barycentric_interp_normalized(&a, &b, &c, (0.5, 0.5, 1.0)).unwrap();
+        let expected = Point3D::barycentric_interp(&a, &b, &c, (0.25, 0.25, 0.5));
+        assert!(normalized.distance_to(&expected) < 1e-12);
+        assert_eq!(
+            Point3D::barycentric_interp_normalized(&a, &b, &c, (1.0, -1.0, 0.0)),
+            None
+        );
     }
 }
@@ -0,0 +1,84 @@
+//! 各プリミティブの GeometryPrimitive（分類情報）実装
+//!
+//! ExtensionFoundation を実装している型はその種別をそのまま使い、
+//! それ以外の型（2D プリミティブ、直線・楕円系など）はここで種別を定める。
+
+use crate::{
+    Arc2D, Arc3D, BBox2D, BBox3D, Circle2D, Circle3D, ConicalSolid3D, ConicalSurface3D,
+    CylindricalSolid3D, CylindricalSurface3D, Direction2D, Direction3D, Ellipse2D, Ellipse3D,
+    EllipseArc2D, EllipseArc3D, EllipsoidalSurface3D, InfiniteLine2D, InfiniteLine3D,
+    LineSegment2D, LineSegment3D, Plane3D, Point2D, Point3D, Ray2D, Ray3D, SphericalSolid3D,
+    SphericalSurface3D, TorusSolid3D, TorusSurface3D, Triangle2D, Triangle3D, TriangleMesh3D,
+    Vector2D, Vector3D,
+};
+use geo_foundation::extension_foundation::ExtensionFoundation;
+use geo_foundation::{GeometryPrimitive, PrimitiveKind, Scalar};
+
+/// ExtensionFoundation の種別を GeometryPrimitive として公開
+macro_rules! impl_from_foundation {
+    ($($ty:ident),* $(,)?) => {
+        $(
+            impl<T: Scalar> GeometryPrimitive for $ty<T> {
+                fn primitive_kind(&self) -> PrimitiveKind {
+                    ExtensionFoundation::<T>::primitive_kind(self)
+                }
+            }
+        )*
+    };
+}
+
+/// 種別を直接指定して GeometryPrimitive を実装
+macro_rules! impl_with_kind {
+    ($($ty:ident => $kind:ident),* $(,)?) => {
+        $(
+            impl<T: Scalar> GeometryPrimitive for $ty<T> {
+                fn primitive_kind(&self) -> PrimitiveKind {
+                    PrimitiveKind::$kind
+                }
+            }
+        )*
+    };
+}
+
+impl_from_foundation!(
+    Arc3D,
+    BBox3D,
+    Circle3D,
+    ConicalSolid3D,
+    ConicalSurface3D,
+    CylindricalSolid3D,
+    CylindricalSurface3D,
+    Plane3D,
+    Point3D,
+    Ray3D,
+    SphericalSolid3D,
+    SphericalSurface3D,
+    TorusSolid3D,
+    TorusSurface3D,
+    Triangle3D,
+    TriangleMesh3D,
+    Vector3D,
+);
+
+impl_with_kind!(
+    // 3D
+    Direction3D => Vector,
+    Ellipse3D => Ellipse,
+    EllipseArc3D => Arc,
+    EllipsoidalSurface3D => EllipsoidalSurface,
+    InfiniteLine3D => Line,
+    LineSegment3D => LineSegment,
+    // 2D
+    Arc2D => Arc,
+    BBox2D => BBox,
+    Circle2D => Circle,
+    Direction2D => Vector,
+    Ellipse2D => Ellipse,
+    EllipseArc2D => Arc,
+    InfiniteLine2D => Line,
+    LineSegment2D => LineSegment,
+    Point2D => Point,
+    Ray2D => Ray,
+    Triangle2D => Triangle,
+    Vector2D => Vector,
+);
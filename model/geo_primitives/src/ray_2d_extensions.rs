@@ -87,12 +87,21 @@ impl<T: Scalar> Ray2D<T> {
         }
     }
 
+    /// 直線で反射した Ray を計算
+    ///
+    /// 直線との交点を新しい起点、直線の法線で鏡映した方向を新しい方向とする。
+    /// 直線に平行、または直線が Ray の後方にある場合は None
+    pub fn reflect(&self, line: &InfiniteLine2D<T>) -> Option<Self> {
+        let hit = self.intersection_with_line(line)?;
+        Self::new(hit, self.direction().as_vector().reflect(line.normal()))
+    }
+
     // === 変換操作（Extension で提供） ===
 
     /// Ray を回転
     pub fn rotate(&self, center: &Point2D<T>, angle: Angle<T>) -> Self {
         let rotated_origin = self.origin().rotate_around(center, angle);
-        let rotated_direction = self.direction().rotate(angle);
+        let rotated_direction = self.direction().rotate(angle).as_vector();
         Self::new(rotated_origin, rotated_direction).unwrap()
     }
 
@@ -236,6 +245,11 @@ impl<T: Scalar> Ray2D<T> {
         Self::new(reflected_origin, reflected_direction).unwrap()
     }
 
+    /// 起点を方向に沿って距離 `t` だけ進めた Ray（負なら後退）
+    pub fn advance(&self, t: T) -> Self {
+        self.with_origin(self.point_at_parameter(t))
+    }
+
     /// Ray の方向を新しい方向に設定
     pub fn with_direction(&self, new_direction: Vector2D<T>) -> Option<Self> {
         Self::new(self.origin(), new_direction)
@@ -0,0 +1,41 @@
+//! Ray2D の前進・直線での反射のテスト
+
+use crate::{InfiniteLine2D, Point2D, Ray2D, Vector2D};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_advance() {
+        let ray = Ray2D::new(Point2D::new(1.0_f64, 1.0), Vector2D::new(3.0, 4.0)).unwrap();
+        let advanced = ray.advance(5.0);
+        assert!(advanced.origin().distance_to(&Point2D::new(4.0, 5.0)) < 1e-12);
+        assert_eq!(advanced.direction(), ray.direction());
+    }
+
+    #[test]
+    fn test_reflect_on_line() {
+        // y=0 の直線へ斜めに入射
+        let ray = Ray2D::new(Point2D::new(-1.0_f64, 1.0), Vector2D::new(1.0, -1.0)).unwrap();
+        let reflected = ray.reflect(&InfiniteLine2D::horizontal(0.0)).unwrap();
+
+        assert!(reflected.origin().distance_to(&Point2D::new(0.0, 0.0)) < 1e-12);
+        let expected = 1.0 / 2.0_f64.sqrt();
+        assert!((reflected.direction().x() - expected).abs() < 1e-12);
+        assert!((reflected.direction().y() - expected).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_reflect_without_hit() {
+        let line = InfiniteLine2D::horizontal(0.0_f64);
+
+        // 直線に平行
+        let parallel = Ray2D::new(Point2D::new(0.0, 1.0), Vector2D::new(1.0, 0.0)).unwrap();
+        assert!(parallel.reflect(&line).is_none());
+
+        // 直線から遠ざかる
+        let away = Ray2D::new(Point2D::new(0.0, 1.0), Vector2D::new(1.0, 1.0)).unwrap();
+        assert!(away.reflect(&line).is_none());
+    }
+}
@@ -3,7 +3,7 @@
 //! Foundation統一システムに基づくRay3Dの拡張機能
 //! Core機能は ray_3d.rs を参照
 
-use crate::{BBox3D, InfiniteLine3D, Point3D, Ray3D, Vector3D};
+use crate::{BBox3D, InfiniteLine3D, Plane3D, Point3D, Ray3D, Vector3D};
 use geo_foundation::Scalar;
 
 // ============================================================================
@@ -182,6 +182,11 @@ impl<T: Scalar> Ray3D<T> {
         Self::new(new_origin, self.direction().as_vector()).unwrap()
     }
 
+    /// 起点を方向に沿って距離 `t` だけ進めた Ray（負なら後退）
+    pub fn advance(&self, t: T) -> Self {
+        self.with_origin(self.point_at_parameter(t))
+    }
+
     /// 指定した長さで切断してLineSegment3Dに変換
     pub fn to_line_segment(&self, length: T) -> crate::LineSegment3D<T> {
         let end_point = self.point_at_parameter(length);
@@ -237,6 +242,17 @@ impl<T: Scalar> Ray3D<T> {
         }
     }
 
+    /// 平面で反射した Ray を計算
+    ///
+    /// 平面との交点を新しい起点、平面で鏡映した方向を新しい方向とする。
+    ///
+    /// # 戻り値
+    /// 平面に平行、または平面が Ray の後方にある場合は None
+    pub fn reflect(&self, plane: &Plane3D<T>) -> Option<Self> {
+        let hit = self.intersect_plane(&plane.point(), &plane.normal().as_vector())?;
+        Self::new(hit, plane.mirror_vector(&self.direction_vector()))
+    }
+
     /// 球との交点を計算
     ///
     /// # 引数
@@ -1,6 +1,6 @@
 //! Ray3D テストモジュール
 
-use crate::{Point3D, Ray3D, Vector3D};
+use crate::{Plane3D, Point3D, Ray3D, Vector3D};
 use geo_foundation::Scalar;
 
 // BasicTransformの実装を有効にするため
@@ -218,4 +218,43 @@ mod tests {
         assert!((point.y() - 1.0).abs() < 1e-10);
         assert!((point.z() - 1.0).abs() < 1e-10);
     }
+
+    #[test]
+    fn test_advance() {
+        let ray = Ray3D::new(Point3D::new(1.0, 0.0, 0.0), Vector3D::new(0.0, 0.0, 2.0)).unwrap();
+
+        let advanced = ray.advance(3.0);
+        assert_eq!(advanced.origin(), Point3D::new(1.0, 0.0, 3.0));
+        assert_eq!(advanced.direction(), ray.direction());
+
+        let back = ray.advance(-1.0);
+        assert_eq!(back.origin(), Point3D::new(1.0, 0.0, -1.0));
+    }
+
+    #[test]
+    fn test_reflect_on_plane() {
+        // z=0 平面へ斜めに入射
+        let ray = Ray3D::new(Point3D::new(-1.0, 0.0, 1.0), Vector3D::new(1.0, 0.0, -1.0)).unwrap();
+        let reflected = ray.reflect(&Plane3D::xy_plane(0.0)).unwrap();
+
+        assert!(reflected.origin().distance_to(&Point3D::new(0.0, 0.0, 0.0)) < 1e-12);
+        let expected = 1.0 / 2.0_f64.sqrt();
+        assert!((reflected.direction().x() - expected).abs() < 1e-12);
+        assert!(reflected.direction().y().abs() < 1e-12);
+        assert!((reflected.direction().z() - expected).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_reflect_without_hit() {
+        let plane = Plane3D::xy_plane(0.0);
+
+        // 平面に平行
+        let parallel =
+            Ray3D::new(Point3D::new(0.0, 0.0, 1.0), Vector3D::new(1.0, 0.0, 0.0)).unwrap();
+        assert!(parallel.reflect(&plane).is_none());
+
+        // 平面から遠ざかる
+        let away = Ray3D::new(Point3D::new(0.0, 0.0, 1.0), Vector3D::new(0.0, 0.0, 1.0)).unwrap();
+        assert!(away.reflect(&plane).is_none());
+    }
 }
@@ -6,7 +6,10 @@
 
 use crate::{Point3D, SphericalSolid3D, Vector3D};
 use analysis::linalg::{matrix::Matrix4x4, vector::Vector3};
-use geo_foundation::{AnalysisTransform3D, Angle, Scalar, TransformError};
+use geo_foundation::{
+    check_uniform_scale, tolerance_migration::DefaultTolerances, AnalysisTransform3D, Angle,
+    Scalar, TransformError,
+};
 
 /// SphericalSolid3D用Analysis Matrix4x4変換モジュール
 pub mod analysis_transform {
@@ -35,10 +38,14 @@ pub mod analysis_transform {
     /// 球ソリッドの行列変換（Matrix4x4）
     ///
     /// 球の中心点、軸方向、参照方向をMatrix変換し、新しい球ソリッドを構築
+    /// 半径は `check_uniform_scale` で求めた一様スケール倍率で拡縮する。
+    /// 特異な行列は `SingularTransform`、球が楕円体になる非一様な行列は `NonUniformScale`。
     pub fn transform_spherical_solid_3d<T: Scalar>(
         spherical_solid: &SphericalSolid3D<T>,
         matrix: &Matrix4x4<T>,
     ) -> Result<SphericalSolid3D<T>, TransformError> {
+        let scale_factor = check_uniform_scale(matrix, DefaultTolerances::distance::<T>())?;
+
         // 中心点を変換
         let center_vec = point_to_analysis_vector(spherical_solid.center());
         let transformed_center_vec = matrix.transform_point_3d(&center_vec);
@@ -54,19 +61,6 @@ pub mod analysis_transform {
         let transformed_ref_dir_vec = matrix.transform_vector_3d(&ref_dir_vec);
         let new_ref_direction_vector = analysis_vector_to_vector(transformed_ref_dir_vec);
 
-        // スケール倍率を計算（半径の変換に使用）
-        // 球の場合は均等スケールを想定するため、任意の軸のスケール倍率を使用
-        let original_axis_length = spherical_solid.axis().as_vector().length();
-        let transformed_axis_length = new_axis_vector.length();
-
-        if transformed_axis_length.is_zero() {
-            return Err(TransformError::InvalidGeometry(
-                "Transformed axis vector is zero".to_string(),
-            ));
-        }
-
-        let scale_factor = transformed_axis_length / original_axis_length;
-
         // 新しい半径を計算（スケール変換を考慮）
         let new_radius = spherical_solid.radius() * scale_factor;
 
@@ -211,7 +205,7 @@ impl<T: Scalar> AnalysisTransform3D<T> for SphericalSolid3D<T> {
         angle: Self::Angle,
     ) -> Result<Self::Output, TransformError> {
         let matrix = analysis_transform::rotation_matrix(&center.center(), axis, angle)?;
-        Ok(self.transform_point_matrix(&matrix))
+        analysis_transform::transform_spherical_solid_3d(self, &matrix)
     }
 
     /// スケール変換（中心点指定）
@@ -223,7 +217,7 @@ impl<T: Scalar> AnalysisTransform3D<T> for SphericalSolid3D<T> {
         scale_z: T,
     ) -> Result<Self::Output, TransformError> {
         let matrix = analysis_transform::scale_matrix(&center.center(), scale_x, scale_y, scale_z)?;
-        Ok(self.transform_point_matrix(&matrix))
+        analysis_transform::transform_spherical_solid_3d(self, &matrix)
     }
 
     /// 均等スケール変換
@@ -265,7 +259,7 @@ impl<T: Scalar> AnalysisTransform3D<T> for SphericalSolid3D<T> {
             matrix = trans_mat * matrix;
         }
 
-        Ok(self.transform_point_matrix(&matrix))
+        analysis_transform::transform_spherical_solid_3d(self, &matrix)
     }
 
     /// 複合変換（均等スケール版）
@@ -343,23 +337,36 @@ mod tests {
     fn test_analysis_scale() {
         let sphere = create_test_spherical_solid();
         let center_sphere = SphericalSolid3D::new_at_origin(1.0).unwrap();
-        let scale_x = 2.0;
-        let scale_y = 3.0;
-        let scale_z = 4.0;
 
-        let result = sphere
-            .scale_analysis(&center_sphere, scale_x, scale_y, scale_z)
-            .unwrap();
+        // 非均等スケールでは球が楕円体になるため NonUniformScale
+        let result = sphere.scale_analysis(&center_sphere, 2.0, 3.0, 4.0);
+        assert!(matches!(result, Err(TransformError::NonUniformScale(_))));
+    }
 
-        // 中心点がスケールされることを確認（原点なので変わらない）
-        let expected_center = Point3D::new(0.0, 0.0, 0.0);
-        assert!((result.center().x() - expected_center.x()).abs() < f64::EPSILON);
-        assert!((result.center().y() - expected_center.y()).abs() < f64::EPSILON);
-        assert!((result.center().z() - expected_center.z()).abs() < f64::EPSILON);
+    #[test]
+    fn test_matrix_transform_guards() {
+        let sphere = create_test_spherical_solid();
 
-        // 球の半径がスケールされることを確認
-        // 球の軸がZ方向なので、軸のスケール倍率（scale_z）が半径に影響
-        assert!((result.radius() - sphere.radius() * scale_z).abs() < f64::EPSILON);
+        // 回転＋一様スケールでは半径がスケール倍率で拡縮される
+        let rotation = Matrix4x4::rotation_axis(&Vector3::new(1.0_f64, 0.0, 0.0), 0.6);
+        let similarity = rotation * Matrix4x4::uniform_scale_3d(2.5);
+        let transformed =
+            analysis_transform::transform_spherical_solid_3d(&sphere, &similarity).unwrap();
+        assert!((transformed.radius() - 5.0).abs() < 1e-10);
+
+        // 任意の非一様行列（軸方向以外の伸縮）も NonUniformScale
+        let stretched = Matrix4x4::scale_3d(&Vector3::new(3.0_f64, 1.0, 1.0));
+        assert!(matches!(
+            analysis_transform::transform_spherical_solid_3d(&sphere, &stretched),
+            Err(TransformError::NonUniformScale(_))
+        ));
+
+        // 潰れる行列は特異
+        let flattened = Matrix4x4::scale_3d(&Vector3::new(1.0_f64, 1.0, 0.0));
+        assert!(matches!(
+            analysis_transform::transform_spherical_solid_3d(&sphere, &flattened),
+            Err(TransformError::SingularTransform(_))
+        ));
     }
 
     #[test]
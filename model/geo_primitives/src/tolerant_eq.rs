@@ -0,0 +1,62 @@
+//! 点・ベクトル・方向の許容誤差付き等価比較
+//!
+//! 点とベクトルは差の長さ（距離）、方向は2つの向きのなす角で比較する。
+//! コンテキストに相対許容誤差があれば、点は原点からの距離、ベクトルは長さに応じて
+//! 距離許容誤差を広げる（方向は角度なので絶対許容誤差のまま）。
+//! Point3D / Vector3D の TolerantEq は各 `*_foundation.rs` にあり、
+//! ここでは残りの型の TolerantEq と、全型の ContextTolerantEq を実装する。
+
+use crate::{Direction3D, Point2D, Point3D, Vector2D, Vector3D};
+use geo_foundation::{ContextTolerantEq, GeometryContext, Scalar, TolerantEq};
+
+impl<T: Scalar> TolerantEq<T> for Point2D<T> {
+    fn tolerant_eq(&self, other: &Self, tolerance: T) -> bool {
+        self.distance_to(other) <= tolerance
+    }
+}
+
+impl<T: Scalar> TolerantEq<T> for Vector2D<T> {
+    fn tolerant_eq(&self, other: &Self, tolerance: T) -> bool {
+        (*self - *other).length() <= tolerance
+    }
+}
+
+impl<T: Scalar> TolerantEq<T> for Direction3D<T> {
+    /// `tolerance` は角度（ラジアン）として扱う
+    fn tolerant_eq(&self, other: &Self, tolerance: T) -> bool {
+        let (a, b) = (self.as_vector(), other.as_vector());
+        // acos より微小角で安定な atan2(|a×b|, a·b)
+        let angle = a.cross(&b).length().atan2(a.dot(&b));
+        angle <= tolerance
+    }
+}
+
+/// 距離許容誤差で比較する型（`$magnitude` は相対許容誤差の基準にする大きさ）
+macro_rules! impl_distance_context_eq {
+    ($($ty:ident => $magnitude:ident),* $(,)?) => {
+        $(
+            impl<T: Scalar> ContextTolerantEq<T> for $ty<T> {
+                fn context_tolerance(context: &GeometryContext<T>) -> T {
+                    context.tolerances.distance_tolerance
+                }
+
+                fn context_magnitude(&self) -> T {
+                    self.$magnitude()
+                }
+            }
+        )*
+    };
+}
+
+impl_distance_context_eq!(
+    Point2D => norm,
+    Point3D => norm,
+    Vector2D => length,
+    Vector3D => length,
+);
+
+impl<T: Scalar> ContextTolerantEq<T> for Direction3D<T> {
+    fn context_tolerance(context: &GeometryContext<T>) -> T {
+        context.tolerances.angle_tolerance
+    }
+}
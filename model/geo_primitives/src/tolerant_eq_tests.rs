@@ -0,0 +1,90 @@
+//! 点・ベクトル・方向の TolerantEq / ContextTolerantEq のテスト
+
+#[cfg(test)]
+mod tests {
+    use crate::{Direction3D, Point2D, Point3D, Vector2D, Vector3D};
+    use geo_foundation::{ContextTolerantEq, GeometryContext, TolerantEq};
+
+    /// 標準コンテキスト（距離 1e-6、角度 1e-4）
+    fn context() -> GeometryContext<f64> {
+        GeometryContext::standard()
+    }
+
+    #[test]
+    fn test_point2d() {
+        let p = Point2D::new(1.0, 2.0);
+        assert!(p.tolerant_eq(&Point2D::new(1.0 + 5e-7, 2.0), 1e-6));
+        assert!(!p.tolerant_eq(&Point2D::new(1.0 + 2e-6, 2.0), 1e-6));
+
+        assert!(p.context_eq(&Point2D::new(1.0, 2.0 - 5e-7), &context()));
+        assert!(!p.context_eq(&Point2D::new(1.0, 2.0 - 2e-6), &context()));
+    }
+
+    #[test]
+    fn test_point3d() {
+        let p = Point3D::new(1.0, 2.0, 3.0);
+        assert!(p.context_eq(&Point3D::new(1.0, 2.0, 3.0 + 5e-7), &context()));
+        assert!(!p.context_eq(&Point3D::new(1.0, 2.0, 3.0 + 2e-6), &context()));
+
+        // 緩いコンテキストでは同じ差が許容される
+        let relaxed = GeometryContext::relaxed();
+        assert!(p.context_eq(&Point3D::new(1.0, 2.0, 3.0 + 2e-6), &relaxed));
+    }
+
+    #[test]
+    fn test_vector2d() {
+        let v = Vector2D::new(3.0, -4.0);
+        assert!(v.context_eq(&Vector2D::new(3.0 + 3e-7, -4.0 - 3e-7), &context()));
+        assert!(!v.context_eq(&Vector2D::new(3.0, -4.0 - 2e-6), &context()));
+    }
+
+    #[test]
+    fn test_vector3d() {
+        let v = Vector3D::new(1.0, 0.0, -2.0);
+        assert!(v.context_eq(&Vector3D::new(1.0, 5e-7, -2.0), &context()));
+        assert!(!v.context_eq(&Vector3D::new(1.0, 2e-6, -2.0), &context()));
+    }
+
+    #[test]
+    fn test_direction3d_compares_angle() {
+        let d = Direction3D::positive_x();
+        let tilted = |angle: f64| Direction3D::new(angle.cos(), angle.sin(), 0.0).unwrap();
+
+        assert!(d.tolerant_eq(&tilted(5e-5), 1e-4));
+        assert!(!d.tolerant_eq(&tilted(2e-4), 1e-4));
+
+        // 角度許容誤差（1e-4）を使い、距離許容誤差（1e-6）では判定しない
+        assert!(d.context_eq(&tilted(5e-5), &context()));
+        assert!(!d.context_eq(&tilted(2e-4), &context()));
+
+        // 逆向きは等価でない
+        assert!(!d.tolerant_eq(&Direction3D::negative_x(), 1e-4));
+    }
+
+    #[test]
+    fn test_relative_tolerance_scales_with_magnitude() {
+        // 原点から 1e7 離れた点では 1e-3 の差も丸め誤差の範囲
+        let p = Point3D::new(1e7, 2e7, 0.0);
+        let q = Point3D::new(1e7 + 1e-3, 2e7, 0.0);
+        assert!(!p.context_eq(&q, &context()));
+
+        let relative = GeometryContext::new(
+            geo_foundation::ToleranceSettings::standard().with_relative_tolerance(1e-10),
+        );
+        assert!(p.context_eq(&q, &relative));
+        assert!(!p.context_eq(&Point3D::new(1e7 + 1e-2, 2e7, 0.0), &relative));
+
+        // 原点付近では絶対許容誤差が効く
+        let near = Point2D::new(1.0, 2.0);
+        assert!(near.context_eq(&Point2D::new(1.0 + 5e-7, 2.0), &relative));
+        assert!(!near.context_eq(&Point2D::new(1.0 + 2e-6, 2.0), &relative));
+
+        let v = Vector3D::new(0.0, 5e6, 0.0);
+        assert!(v.context_eq(&Vector3D::new(2e-4, 5e6, 0.0), &relative));
+        assert!(!v.context_eq(&Vector3D::new(2e-4, 5e6, 0.0), &context()));
+
+        // 方向は角度で比較するので相対許容誤差の影響を受けない
+        let tilted = Direction3D::new(2e-4_f64.cos(), 2e-4_f64.sin(), 0.0).unwrap();
+        assert!(!Direction3D::positive_x().context_eq(&tilted, &relative));
+    }
+}
@@ -9,7 +9,6 @@
 
 use crate::{Direction3D, Point3D, TorusSurface3D, Vector3D};
 use geo_foundation::Scalar;
-use std::f64::consts::PI;
 
 /// STEP AP214 準拠のトーラス固体
 ///
@@ -146,22 +145,16 @@ impl<T: Scalar> TorusSolid3D<T> {
 
     /// 体積を計算
     ///
-    /// トーラス固体の体積 = 2π²R²r (R=主半径, r=副半径)
+    /// トーラス固体の体積 = 2π²Rr² (R=主半径, r=副半径)
     pub fn volume(&self) -> T {
-        let two = T::from_f64(2.0);
-        let pi_squared = T::from_f64(PI * PI);
-
-        two * pi_squared * self.major_radius * self.major_radius * self.minor_radius
+        T::from_f64(2.0) * T::PI * T::PI * self.major_radius * self.minor_radius * self.minor_radius
     }
 
     /// 表面積を計算
     ///
     /// トーラス固体の表面積 = 4π²Rr (R=主半径, r=副半径)
     pub fn surface_area(&self) -> T {
-        let four = T::from_f64(4.0);
-        let pi_squared = T::from_f64(PI * PI);
-
-        four * pi_squared * self.major_radius * self.minor_radius
+        T::from_f64(4.0) * T::PI * T::PI * self.major_radius * self.minor_radius
     }
 
     /// 点が固体内部にあるかを判定
@@ -119,9 +119,12 @@ mod tests {
         let torus = TorusSolid3D::standard(3.0, 1.0).unwrap();
         let volume = torus.measure().unwrap();
 
-        // 期待される体積: 2π²R²r = 2π² × 3² × 1 ≈ 177.65
-        let expected = 2.0 * std::f64::consts::PI.powi(2) * 3.0_f64.powi(2) * 1.0;
+        // 期待される体積（パップスの定理）: 2π²Rr² = 2π² × 3 × 1² ≈ 59.22
+        let expected = 2.0 * std::f64::consts::PI.powi(2) * 3.0 * 1.0_f64.powi(2);
         assert!((volume - expected).abs() < 1e-10);
+
+        let area = 4.0 * std::f64::consts::PI.powi(2) * 3.0 * 1.0;
+        assert!((torus.surface_area() - area).abs() < 1e-10);
     }
 
     #[test]
@@ -2,7 +2,7 @@
 //!
 //! Foundation統一システムに基づくTriangle2Dの必須機能のみ
 
-use crate::{Point2D, Vector2D};
+use crate::{Circle2D, Point2D, Vector2D};
 use geo_foundation::Scalar;
 
 /// 2次元三角形（Core実装）
@@ -10,7 +10,7 @@ use geo_foundation::Scalar;
 /// Core機能のみ：
 /// - 基本構築・検証
 /// - アクセサメソッド
-/// - 基本的な幾何プロパティ（面積、外心、重心）
+/// - 基本的な幾何プロパティ（面積、重心、外心、内心、垂心）
 /// - 辺の長さ計算
 #[derive(Debug, Clone, PartialEq)]
 pub struct Triangle2D<T: Scalar> {
@@ -138,29 +138,26 @@ impl<T: Scalar> Triangle2D<T> {
     }
 
     /// 外心計算（外接円の中心）
+    ///
+    /// 辺AB・ACの垂直二等分線の交点。桁落ちを避けるため頂点Aを原点とした座標で解く。
+    /// 3点が一直線上（退化）の場合は `None`。
     pub fn circumcenter(&self) -> Option<Point2D<T>> {
-        let ax = self.vertex_a.x();
-        let ay = self.vertex_a.y();
-        let bx = self.vertex_b.x();
-        let by = self.vertex_b.y();
-        let cx = self.vertex_c.x();
-        let cy = self.vertex_c.y();
+        let b = self.edge_ab();
+        let c = Vector2D::from_points(self.vertex_a, self.vertex_c);
 
         // 行列式計算
-        let d = (ax * (by - cy) + bx * (cy - ay) + cx * (ay - by)) * (T::ONE + T::ONE);
+        let d = (b.x() * c.y() - b.y() * c.x()) * (T::ONE + T::ONE);
         if d.abs() < T::from_f64(1e-10) {
             return None; // 退化した三角形
         }
 
-        // 外心座標計算
-        let a_sq = ax * ax + ay * ay;
-        let b_sq = bx * bx + by * by;
-        let c_sq = cx * cx + cy * cy;
-
-        let ux = (a_sq * (by - cy) + b_sq * (cy - ay) + c_sq * (ay - by)) / d;
-        let uy = (a_sq * (cx - bx) + b_sq * (ax - cx) + c_sq * (bx - ax)) / d;
+        // 外心座標計算（頂点Aからの相対位置）
+        let b_sq = b.dot(&b);
+        let c_sq = c.dot(&c);
+        let ux = (c.y() * b_sq - b.y() * c_sq) / d;
+        let uy = (b.x() * c_sq - c.x() * b_sq) / d;
 
-        Some(Point2D::new(ux, uy))
+        Some(self.vertex_a + Vector2D::new(ux, uy))
     }
 
     /// 外接円の半径
@@ -170,9 +167,16 @@ impl<T: Scalar> Triangle2D<T> {
         Some(distance_a)
     }
 
+    /// 外接円（退化した三角形では `None`）
+    pub fn circumcircle(&self) -> Option<Circle2D<T>> {
+        let center = self.circumcenter()?;
+        Circle2D::new(center, center.distance_to(&self.vertex_a))
+    }
+
     /// 内心計算（内接円の中心）
     pub fn incenter(&self) -> Point2D<T> {
-        let [a, b, c] = self.edge_lengths();
+        // 各頂点の重みは対辺の長さ
+        let [c, a, b] = self.edge_lengths();
         let perimeter = a + b + c;
 
         // 辺長による重み付き重心
@@ -191,6 +195,23 @@ impl<T: Scalar> Triangle2D<T> {
         (area * (T::ONE + T::ONE)) / perimeter
     }
 
+    /// 内接円（半径が正にならない場合は `None`）
+    pub fn incircle(&self) -> Option<Circle2D<T>> {
+        Circle2D::new(self.incenter(), self.inradius())
+    }
+
+    /// 垂心計算（3本の垂線の交点）
+    ///
+    /// オイラー線の関係 H = A + B + C - 2O から求める。退化した三角形では `None`。
+    pub fn orthocenter(&self) -> Option<Point2D<T>> {
+        let circumcenter = self.circumcenter()?;
+        let two = T::ONE + T::ONE;
+        Some(Point2D::new(
+            self.vertex_a.x() + self.vertex_b.x() + self.vertex_c.x() - two * circumcenter.x(),
+            self.vertex_a.y() + self.vertex_b.y() + self.vertex_c.y() - two * circumcenter.y(),
+        ))
+    }
+
     // ========================================================================
     // Core Query Methods
     // ========================================================================
@@ -347,4 +368,49 @@ mod tests {
         assert!((circumcenter.x() - centroid.x()).abs() < 1e-10);
         assert!((circumcenter.y() - centroid.y()).abs() < 1e-10);
     }
+
+    #[test]
+    fn test_triangle_centers() {
+        // 直角三角形（直角は頂点A、辺長 3, 4, 5）
+        let triangle = Triangle2D::new(
+            Point2D::new(1.0, 2.0),
+            Point2D::new(4.0, 2.0),
+            Point2D::new(1.0, 6.0),
+        )
+        .unwrap();
+
+        // 外心は斜辺の中点、垂心は直角の頂点
+        let circumcenter = triangle.circumcenter().unwrap();
+        assert!(circumcenter.distance_to(&Point2D::new(2.5, 4.0)) < 1e-12);
+        let orthocenter = triangle.orthocenter().unwrap();
+        assert!(orthocenter.distance_to(&Point2D::new(1.0, 2.0)) < 1e-12);
+
+        // 内接円の半径は (3 + 4 - 5) / 2 = 1
+        let incenter = triangle.incenter();
+        assert!(incenter.distance_to(&Point2D::new(2.0, 3.0)) < 1e-12);
+
+        let circumcircle = triangle.circumcircle().unwrap();
+        assert!((circumcircle.radius() - 2.5).abs() < 1e-12);
+        let incircle = triangle.incircle().unwrap();
+        assert!((incircle.radius() - 1.0).abs() < 1e-12);
+        assert_eq!(incircle.center(), incenter);
+    }
+
+    #[test]
+    fn test_orthocenter_of_obtuse_triangle() {
+        // 鈍角三角形の垂心は外側にあり、各頂点からの垂線上にある
+        let triangle = Triangle2D::new(
+            Point2D::new(0.0, 0.0),
+            Point2D::new(4.0, 0.0),
+            Point2D::new(5.0, 1.0),
+        )
+        .unwrap();
+        let h = triangle.orthocenter().unwrap();
+        assert!(!triangle.contains_point(&h));
+
+        let ah = Vector2D::from_points(triangle.vertex_a(), h);
+        let bh = Vector2D::from_points(triangle.vertex_b(), h);
+        assert!(ah.dot(&triangle.edge_bc()).abs() < 1e-9);
+        assert!(bh.dot(&triangle.edge_ca()).abs() < 1e-9);
+    }
 }
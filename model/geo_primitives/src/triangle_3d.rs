@@ -12,6 +12,7 @@ use geo_foundation::Scalar;
 /// - アクセサメソッド
 /// - 基本的な幾何プロパティ（面積、法線）
 /// - 重心計算
+/// - 品質指標（内角、アスペクト比）
 #[derive(Debug, Clone, PartialEq)]
 pub struct Triangle3D<T: Scalar> {
     vertex_a: Point3D<T>,
@@ -136,6 +137,57 @@ impl<T: Scalar> Triangle3D<T> {
         ab_length + bc_length + ca_length
     }
 
+    // ========================================================================
+    // Quality Metrics
+    // ========================================================================
+
+    /// 辺の長さ [|AB|, |BC|, |CA|]
+    pub fn edge_lengths(&self) -> [T; 3] {
+        [
+            self.edge_ab().length(),
+            self.edge_bc().length(),
+            self.edge_ca().length(),
+        ]
+    }
+
+    /// 頂点A・B・Cの内角（ラジアン）
+    pub fn angles(&self) -> [T; 3] {
+        // atan2(|u×v|, u·v) は acos より鋭角・鈍角の端でも精度が落ちにくい
+        let angle = |u: Vector3D<T>, v: Vector3D<T>| u.cross(&v).length().atan2(u.dot(&v));
+        let [a, b, c] = self.vertices();
+        [
+            angle(self.edge_ab(), Vector3D::from_points(&a, &c)),
+            angle(Vector3D::from_points(&b, &a), self.edge_bc()),
+            angle(self.edge_ca(), Vector3D::from_points(&c, &b)),
+        ]
+    }
+
+    /// 最小内角（ラジアン）
+    pub fn min_angle(&self) -> T {
+        let [a, b, c] = self.angles();
+        a.min(b).min(c)
+    }
+
+    /// 最大内角（ラジアン）
+    pub fn max_angle(&self) -> T {
+        let [a, b, c] = self.angles();
+        a.max(b).max(c)
+    }
+
+    /// アスペクト比（外接円半径 / 内接円半径の2倍）
+    ///
+    /// 正三角形で1、細長いほど大きくなる。面積が0なら無限大。
+    pub fn aspect_ratio(&self) -> T {
+        let [ab, bc, ca] = self.edge_lengths();
+        let area = self.area();
+        if area <= T::ZERO {
+            return T::INFINITY;
+        }
+        // R = abc / 4A, r = A / s（s は半周長）より R / 2r = abc·s / 8A²
+        let semi_perimeter = (ab + bc + ca) / T::from_f64(2.0);
+        ab * bc * ca * semi_perimeter / (T::from_f64(8.0) * area * area)
+    }
+
     // ========================================================================
     // Core Validation Methods
     // ========================================================================
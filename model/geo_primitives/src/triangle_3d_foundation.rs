@@ -1,8 +1,8 @@
 //! Triangle3D の Foundation トレイト実装
 
-use crate::{BBox3D, Triangle3D};
+use crate::{BBox3D, Point3D, Triangle3D, Vector3D};
 use geo_foundation::{
-    extension_foundation::ExtensionFoundation, PrimitiveKind, Scalar, TolerantEq,
+    extension_foundation::ExtensionFoundation, PointDistance, PrimitiveKind, Scalar, TolerantEq,
 };
 
 // ============================================================================
@@ -69,6 +69,94 @@ impl<T: Scalar> TolerantEq<T> for Triangle3D<T> {
     }
 }
 
+impl<T: Scalar> PointDistance<T> for Triangle3D<T> {
+    type Point2D = Point3D<T>;
+
+    /// 三角形（内部を含む）までの最短距離
+    fn distance_to_point(&self, point: &Point3D<T>) -> T {
+        point.distance_to(&self.closest_point(point))
+    }
+
+    /// 点が三角形上（内部を含む）にあるか
+    fn contains_point(&self, point: &Point3D<T>, tolerance: T) -> bool {
+        self.distance_to_point(point) <= tolerance
+    }
+
+    /// 点が3辺のいずれかの上にあるか
+    fn point_on_boundary(&self, point: &Point3D<T>, tolerance: T) -> bool {
+        let [a, b, c] = self.vertices();
+        [(a, b), (b, c), (c, a)]
+            .into_iter()
+            .any(|(start, end)| point.distance_to(&closest_on_edge(start, end, point)) <= tolerance)
+    }
+
+    /// 三角形上の最近点
+    ///
+    /// 頂点・辺・面のどのボロノイ領域に点の射影が入るかで場合分けし、
+    /// 面の外に出る射影は辺または頂点にクランプする。
+    fn closest_point(&self, point: &Point3D<T>) -> Point3D<T> {
+        let [a, b, c] = self.vertices();
+        let ab = Vector3D::from_points(&a, &b);
+        let ac = Vector3D::from_points(&a, &c);
+
+        let ap = Vector3D::from_points(&a, point);
+        let d1 = ab.dot(&ap);
+        let d2 = ac.dot(&ap);
+        if d1 <= T::ZERO && d2 <= T::ZERO {
+            return a;
+        }
+
+        let bp = Vector3D::from_points(&b, point);
+        let d3 = ab.dot(&bp);
+        let d4 = ac.dot(&bp);
+        if d3 >= T::ZERO && d4 <= d3 {
+            return b;
+        }
+
+        let vc = d1 * d4 - d3 * d2;
+        if vc <= T::ZERO && d1 >= T::ZERO && d3 <= T::ZERO {
+            return a + ab * (d1 / (d1 - d3));
+        }
+
+        let cp = Vector3D::from_points(&c, point);
+        let d5 = ab.dot(&cp);
+        let d6 = ac.dot(&cp);
+        if d6 >= T::ZERO && d5 <= d6 {
+            return c;
+        }
+
+        let vb = d5 * d2 - d1 * d6;
+        if vb <= T::ZERO && d2 >= T::ZERO && d6 <= T::ZERO {
+            return a + ac * (d2 / (d2 - d6));
+        }
+
+        let va = d3 * d6 - d5 * d4;
+        if va <= T::ZERO && d4 - d3 >= T::ZERO && d5 - d6 >= T::ZERO {
+            let bc = Vector3D::from_points(&b, &c);
+            return b + bc * ((d4 - d3) / ((d4 - d3) + (d5 - d6)));
+        }
+
+        // 面の内部：重心座標で射影点を求める
+        let denom = T::ONE / (va + vb + vc);
+        a + ab * (vb * denom) + ac * (vc * denom)
+    }
+}
+
+/// 辺 [start, end] 上の最近点
+fn closest_on_edge<T: Scalar>(
+    start: Point3D<T>,
+    end: Point3D<T>,
+    point: &Point3D<T>,
+) -> Point3D<T> {
+    let edge = Vector3D::from_points(&start, &end);
+    let length_squared = edge.length_squared();
+    if length_squared <= T::ZERO {
+        return start;
+    }
+    let t = Vector3D::from_points(&start, point).dot(&edge) / length_squared;
+    start + edge * t.max(T::ZERO).min(T::ONE)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -122,4 +210,50 @@ mod tests {
         assert!(triangle1.tolerant_eq(&triangle2, tolerance));
         assert!(!triangle1.tolerant_eq(&triangle3, tolerance));
     }
+
+    fn right_triangle() -> Triangle3D<f64> {
+        Triangle3D::new(
+            Point3D::new(0.0, 0.0, 0.0),
+            Point3D::new(4.0, 0.0, 0.0),
+            Point3D::new(0.0, 4.0, 0.0),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_point_distance_regions() {
+        let triangle = right_triangle();
+        let cases = [
+            // 面の内部に射影される点
+            ((1.0, 1.0, 3.0), (1.0, 1.0, 0.0)),
+            // 頂点領域
+            ((-1.0, -2.0, 0.0), (0.0, 0.0, 0.0)),
+            ((6.0, -1.0, 1.0), (4.0, 0.0, 0.0)),
+            ((-1.0, 7.0, 0.0), (0.0, 4.0, 0.0)),
+            // 辺領域
+            ((2.0, -3.0, 0.0), (2.0, 0.0, 0.0)),
+            ((-2.0, 1.0, 2.0), (0.0, 1.0, 0.0)),
+            ((3.0, 3.0, 0.0), (2.0, 2.0, 0.0)),
+        ];
+        for (point, expected) in cases {
+            let point = Point3D::new(point.0, point.1, point.2);
+            let expected = Point3D::new(expected.0, expected.1, expected.2);
+            let (distance, closest) = triangle.distance_to_with_closest(&point);
+            assert!(
+                closest.distance_to(&expected) < 1e-10,
+                "{point:?} -> {closest:?}"
+            );
+            assert!((distance - point.distance_to(&expected)).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_point_distance_containment() {
+        let triangle = right_triangle();
+        let inside = Point3D::new(1.0, 1.0, 0.0);
+        assert!(triangle.contains_point(&inside, 1e-10));
+        assert!(!triangle.point_on_boundary(&inside, 1e-10));
+        assert!(triangle.point_on_boundary(&Point3D::new(2.0, 2.0, 0.0), 1e-10));
+        assert!(!triangle.contains_point(&Point3D::new(1.0, 1.0, 0.1), 1e-10));
+    }
 }
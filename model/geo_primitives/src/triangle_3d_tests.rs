@@ -151,6 +151,59 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_triangle_angles_and_edge_lengths() {
+        let a = Point3D::new(0.0, 0.0, 0.0);
+        let b = Point3D::new(3.0, 0.0, 0.0);
+        let c = Point3D::new(0.0, 4.0, 0.0);
+        let triangle = Triangle3D::new(a, b, c).unwrap();
+
+        assert_eq!(triangle.edge_lengths(), [3.0, 5.0, 4.0]);
+
+        let [angle_a, angle_b, angle_c] = triangle.angles();
+        assert!((angle_a - std::f64::consts::FRAC_PI_2).abs() < 1e-12);
+        assert!((angle_b - (4.0f64 / 3.0).atan()).abs() < 1e-12);
+        assert!((angle_a + angle_b + angle_c - std::f64::consts::PI).abs() < 1e-12);
+        assert_eq!(triangle.max_angle(), angle_a);
+        assert_eq!(triangle.min_angle(), angle_c);
+    }
+
+    #[test]
+    fn test_triangle_aspect_ratio() {
+        // 正三角形は1
+        let equilateral = Triangle3D::new(
+            Point3D::new(0.0, 0.0, 0.0),
+            Point3D::new(1.0, 0.0, 0.0),
+            Point3D::new(0.5, 3.0f64.sqrt() / 2.0, 0.0),
+        )
+        .unwrap();
+        assert!((equilateral.aspect_ratio() - 1.0).abs() < 1e-12);
+        let third = std::f64::consts::FRAC_PI_3;
+        assert!((equilateral.min_angle() - third).abs() < 1e-12);
+        assert!((equilateral.max_angle() - third).abs() < 1e-12);
+
+        // 直角二等辺三角形：R = √2/2, r = 1 - √2/2
+        let right = Triangle3D::new(
+            Point3D::new(0.0, 0.0, 0.0),
+            Point3D::new(1.0, 0.0, 0.0),
+            Point3D::new(0.0, 1.0, 0.0),
+        )
+        .unwrap();
+        let half_sqrt2 = std::f64::consts::FRAC_1_SQRT_2;
+        let expected = half_sqrt2 / (2.0 * (1.0 - half_sqrt2));
+        assert!((right.aspect_ratio() - expected).abs() < 1e-12);
+
+        // 細長い三角形ほど大きい
+        let sliver = Triangle3D::new(
+            Point3D::new(0.0, 0.0, 0.0),
+            Point3D::new(10.0, 0.0, 0.0),
+            Point3D::new(5.0, 0.1, 0.0),
+        )
+        .unwrap();
+        assert!(sliver.aspect_ratio() > 10.0);
+        assert!(sliver.max_angle() > 3.0);
+    }
+
     #[test]
     fn test_triangle_display() {
         let a = Point3D::new(0.0, 0.0, 0.0);
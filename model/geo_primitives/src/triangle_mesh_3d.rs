@@ -55,6 +55,19 @@ impl<T: Scalar> TriangleMesh3D<T> {
         })
     }
 
+    /// 頂点法線を設定したメッシュを返す（法線数は頂点数と一致する必要がある）
+    pub fn with_normals(mut self, normals: Vec<Vector3D<T>>) -> Result<Self, String> {
+        if normals.len() != self.vertices.len() {
+            return Err(format!(
+                "Normal count {} does not match vertex count {}",
+                normals.len(),
+                self.vertices.len()
+            ));
+        }
+        self.normals = Some(normals);
+        Ok(self)
+    }
+
     /// 空のメッシュを作成
     pub fn empty() -> Self {
         Self {
@@ -0,0 +1,21 @@
+//! 他クレートのテストで共通に使う TriangleMesh3D のフィクスチャ
+//!
+//! `test-fixtures` フィーチャーで有効になる。利用側は dev-dependencies でこのフィーチャーを指定する。
+
+use crate::{Point3D, TriangleMesh3D};
+
+/// 原点と各軸上の単位点を頂点とする四面体（外向き、閉じた多様体）
+///
+/// 各頂点に向きの異なる3面が接する。
+pub fn tetrahedron() -> TriangleMesh3D<f64> {
+    TriangleMesh3D::new(
+        vec![
+            Point3D::new(0.0, 0.0, 0.0),
+            Point3D::new(1.0, 0.0, 0.0),
+            Point3D::new(0.0, 1.0, 0.0),
+            Point3D::new(0.0, 0.0, 1.0),
+        ],
+        vec![[0, 2, 1], [0, 1, 3], [0, 3, 2], [1, 2, 3]],
+    )
+    .unwrap()
+}
@@ -1,6 +1,6 @@
 //! TriangleMesh3D のテスト
 
-use crate::{Point3D, TriangleMesh3D};
+use crate::{Point3D, TriangleMesh3D, Vector3D};
 
 #[cfg(test)]
 mod tests {
@@ -149,6 +149,25 @@ mod tests {
         assert_eq!(mesh.degenerate_triangle_count(), 0);
     }
 
+    #[test]
+    fn test_with_normals() {
+        let vertices = vec![
+            Point3D::new(0.0, 0.0, 0.0),
+            Point3D::new(1.0, 0.0, 0.0),
+            Point3D::new(0.0, 1.0, 0.0),
+        ];
+        let mesh = TriangleMesh3D::new(vertices, vec![[0, 1, 2]]).unwrap();
+        assert!(mesh.normals().is_none());
+
+        let up = Vector3D::new(0.0, 0.0, 1.0);
+        let with_normals = mesh.clone().with_normals(vec![up; 3]).unwrap();
+        assert_eq!(with_normals.normals(), Some(&[up, up, up][..]));
+        assert!(with_normals.is_valid());
+
+        // 頂点数と一致しない法線は拒否
+        assert!(mesh.with_normals(vec![up; 2]).is_err());
+    }
+
     #[test]
     fn test_mesh_display() {
         let vertices = vec![
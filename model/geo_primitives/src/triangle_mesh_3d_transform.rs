@@ -278,6 +278,28 @@ mod tests {
         assert_eq!(result.triangle_count(), mesh.triangle_count());
     }
 
+    #[test]
+    fn test_apply_transform3d() {
+        let mesh = create_test_mesh();
+        let transform = geo_foundation::Transform3D::identity()
+            .uniform_scale(2.0)
+            .translate(&Vector3::new(1.0, 0.0, 0.0));
+
+        let result = mesh.apply_transform(&transform);
+        assert_eq!(
+            result.vertices(),
+            mesh.transform_point_matrix(transform.matrix()).vertices()
+        );
+        assert_relative_eq!(result.vertices()[3].x(), 3.0); // 1.0 * 2.0 + 1.0
+        assert_relative_eq!(result.vertices()[3].z(), 2.0); // 1.0 * 2.0
+
+        // 逆変換で元に戻る
+        let restored = result.apply_transform(&transform.inverse().unwrap());
+        for (restored, original) in restored.vertices().iter().zip(mesh.vertices()) {
+            assert!(restored.distance_to(original) < 1e-10);
+        }
+    }
+
     #[test]
     fn test_transform_multiple_meshes() {
         let mesh1 = create_test_mesh();
@@ -3,7 +3,7 @@
 //! Core Foundation パターンに基づく Vector2D の拡張機能
 //! 基本機能は vector_2d.rs を参照
 
-use crate::{Point2D, Vector2D};
+use crate::{Direction2D, Point2D, Vector2D};
 use geo_foundation::{Angle, Scalar};
 
 impl<T: Scalar> Vector2D<T> {
@@ -60,14 +60,20 @@ impl<T: Scalar> Vector2D<T> {
     // Extension Normalization Methods
     // ========================================================================
 
-    /// 正規化を試行（ゼロベクトルの場合はNoneを返す）
+    /// 正規化を試行（ゼロベクトル・非有限成分を含むベクトルの場合はNoneを返す）
+    ///
+    /// 最大成分で割ってから長さを求めるため、二乗がオーバーフローする大きな成分でも正規化できる。
     pub fn try_normalize(&self) -> Option<Self> {
-        let len = self.length();
-        if len <= T::ZERO {
-            None
-        } else {
-            Some(Self::new(self.x() / len, self.y() / len))
+        if !(self.x().is_finite() && self.y().is_finite()) {
+            return None;
+        }
+        let scale = self.x().abs().max(self.y().abs());
+        if scale == T::ZERO {
+            return None;
         }
+        let scaled = Self::new(self.x() / scale, self.y() / scale);
+        let len = scaled.length();
+        Some(Self::new(scaled.x() / len, scaled.y() / len))
     }
 
     /// ベクトルを指定長さにスケール
@@ -100,11 +106,11 @@ impl<T: Scalar> Vector2D<T> {
         Self::new(self.y(), -self.x())
     }
 
-    /// ベクトルを反射（指定ベクトル方向の法線で反射）
-    pub fn reflect(&self, normal: &Self) -> Self {
-        let normal_unit = normal.normalize();
+    /// ベクトルを反射（指定法線の直線で反射、`v - 2(v·n)n`）
+    pub fn reflect(&self, normal: Direction2D<T>) -> Self {
+        let normal = normal.as_vector();
         let two = T::ONE + T::ONE;
-        *self - normal_unit * (self.dot(&normal_unit) * two)
+        *self - normal * (self.dot(&normal) * two)
     }
 
     /// ベクトルを反転（-selfと同じ）
@@ -140,7 +146,23 @@ impl<T: Scalar> Vector2D<T> {
         )
     }
 
-    /// 投影ベクトルを計算（このベクトルをotherに投影）
+    /// 2つの単位ベクトル間で球面線形補間（角度が小さい場合は線形補間）
+    pub fn slerp(&self, other: &Self, t: T) -> Self {
+        let dot = self.dot(other).clamp(-T::ONE, T::ONE);
+        let angle = dot.acos();
+
+        if angle.abs() < T::EPSILON {
+            return self.lerp(other, t);
+        }
+
+        let sin_angle = angle.sin();
+        let factor1 = ((T::ONE - t) * angle).sin() / sin_angle;
+        let factor2 = (t * angle).sin() / sin_angle;
+
+        *self * factor1 + *other * factor2
+    }
+
+    /// 投影ベクトルを計算（このベクトルをotherに投影、otherがゼロベクトルならゼロベクトル）
     pub fn project_onto(&self, other: &Self) -> Self {
         let other_len_sq = other.length_squared();
         if other_len_sq <= T::ZERO {
@@ -150,7 +172,7 @@ impl<T: Scalar> Vector2D<T> {
         }
     }
 
-    /// 拒絶ベクトルを計算（投影の残り）
+    /// 拒絶ベクトルを計算（投影の残り、otherに垂直な成分）
     pub fn reject_from(&self, other: &Self) -> Self {
         *self - self.project_onto(other)
     }
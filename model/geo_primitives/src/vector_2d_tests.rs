@@ -1,6 +1,6 @@
 //! Vector2D のテスト
 
-use crate::{Point2D, Vector2D};
+use crate::{Direction2D, Point2D, Vector2D};
 use geo_foundation::Angle;
 use std::f64;
 
@@ -105,6 +105,14 @@ fn test_try_normalize() {
 
     let zero = Vector2D::<f64>::zero();
     assert!(zero.try_normalize().is_none());
+
+    // 非有限成分を含むベクトル
+    assert!(Vector2D::new(f64::NAN, 1.0).try_normalize().is_none());
+    assert!(Vector2D::new(f64::INFINITY, 1.0).try_normalize().is_none());
+
+    // 二乗がオーバーフローする大きな成分でも正規化できる
+    let large = Vector2D::new(3e200, 4e200).try_normalize().unwrap();
+    assert!((large.x() - 0.6f64).abs() < 1e-12 && (large.y() - 0.8f64).abs() < 1e-12);
 }
 
 /// 長さ指定テスト
@@ -222,9 +230,9 @@ fn test_rotation() {
 #[test]
 fn test_reflection() {
     let v = Vector2D::new(1.0, 1.0);
-    let normal = Vector2D::new(1.0, 0.0); // X軸法線
+    let normal = Direction2D::positive_x(); // X軸法線
 
-    let reflected = v.reflect(&normal);
+    let reflected = v.reflect(normal);
     assert!((reflected.x() - (-1.0f64)).abs() < f64::EPSILON);
     assert!((reflected.y() - 1.0f64).abs() < f64::EPSILON);
 }
@@ -244,6 +252,14 @@ fn test_lerp() {
 
     let end = v1.lerp(&v2, 1.0);
     assert_eq!(end, v2);
+
+    // 単位ベクトル間の球面補間は長さを保つ
+    let x = Vector2D::new(1.0, 0.0);
+    let y = Vector2D::new(0.0, 1.0);
+    let half = x.slerp(&y, 0.5);
+    let expected = f64::consts::FRAC_1_SQRT_2;
+    assert!((half.x() - expected).abs() < 1e-12);
+    assert!((half.y() - expected).abs() < 1e-12);
 }
 
 /// 投影・拒絶テスト
@@ -266,6 +282,11 @@ fn test_projection() {
     let diff_y: f64 = sum.y() - v.y();
     assert!(diff_x.abs() < f64::EPSILON);
     assert!(diff_y.abs() < f64::EPSILON);
+
+    // ゼロベクトルへの投影はゼロ、拒絶は元のベクトル
+    let zero = Vector2D::zero();
+    assert_eq!(v.project_onto(&zero), zero);
+    assert_eq!(v.reject_from(&zero), v);
 }
 
 /// 成分演算テスト
@@ -106,6 +106,23 @@ impl<T: Scalar> Vector3D<T> {
         }
     }
 
+    /// 正規化を試行
+    ///
+    /// ゼロベクトル、または成分に NaN / 無限大を含む場合は `None`。
+    /// 最大成分で割ってから長さを求めるため、二乗がオーバーフローする大きな成分でも正規化できる。
+    pub fn try_normalize(&self) -> Option<Self> {
+        if !(self.x.is_finite() && self.y.is_finite() && self.z.is_finite()) {
+            return None;
+        }
+        let scale = self.x.abs().max(self.y.abs()).max(self.z.abs());
+        if scale == T::ZERO {
+            return None;
+        }
+        let scaled = Self::new(self.x / scale, self.y / scale, self.z / scale);
+        let len = scaled.length();
+        Some(Self::new(scaled.x / len, scaled.y / len, scaled.z / len))
+    }
+
     /// 内積
     pub fn dot(&self, other: &Self) -> T {
         self.x * other.x + self.y * other.y + self.z * other.z
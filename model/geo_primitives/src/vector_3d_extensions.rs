@@ -3,7 +3,7 @@
 //! Core Foundation パターンに基づく Vector3D の拡張機能
 //! transform機能は vector_3d_transform.rs を参照
 
-use crate::Vector3D;
+use crate::{Direction3D, Vector3D};
 use geo_foundation::Scalar;
 
 // ============================================================================
@@ -89,13 +89,13 @@ impl<T: Scalar> Vector3D<T> {
 
     /// ベクトルの投影
     ///
-    /// selfをotherベクトルに投影したベクトルを返す
+    /// selfをotherベクトルに投影したベクトル `(self·other / other·other) other` を返す
     ///
     /// # 引数
     /// * `other` - 投影先のベクトル
     ///
     /// # 戻り値
-    /// 投影されたベクトル
+    /// 投影されたベクトル（otherがゼロベクトルの場合はゼロベクトル）
     pub fn project_onto(&self, other: &Self) -> Self {
         let other_length_sq = other.length_squared();
         if other_length_sq <= T::ZERO {
@@ -105,18 +105,32 @@ impl<T: Scalar> Vector3D<T> {
         }
     }
 
+    /// ベクトルの直交成分
+    ///
+    /// selfからotherへの投影を除いた、otherに垂直な成分を返す
+    ///
+    /// # 引数
+    /// * `other` - 基準となるベクトル
+    ///
+    /// # 戻り値
+    /// 直交成分（otherがゼロベクトルの場合はself）
+    pub fn reject_from(&self, other: &Self) -> Self {
+        *self - self.project_onto(other)
+    }
+
     /// ベクトルの反射
     ///
-    /// 指定された法線ベクトルで反射したベクトルを返す
+    /// 指定された法線の面で反射したベクトル `v - 2(v·n)n` を返す
     ///
     /// # 引数
-    /// * `normal` - 反射面の法線ベクトル（正規化済みであることを想定）
+    /// * `normal` - 反射面の法線方向
     ///
     /// # 戻り値
     /// 反射されたベクトル
-    pub fn reflect(&self, normal: &Self) -> Self {
+    pub fn reflect(&self, normal: Direction3D<T>) -> Self {
+        let normal = normal.as_vector();
         let two = T::ONE + T::ONE;
-        *self - *normal * (two * self.dot(normal))
+        *self - normal * (two * self.dot(&normal))
     }
 
     // ========================================================================
@@ -1,6 +1,6 @@
 //! Vector3D のテスト
 
-use crate::{Point3D, Vector3D};
+use crate::{Direction3D, Point3D, Vector3D};
 
 #[cfg(test)]
 mod tests {
@@ -55,6 +55,32 @@ mod tests {
         assert!(normalized_zero.length() <= f64::EPSILON);
     }
 
+    #[test]
+    fn test_vector3d_try_normalize() {
+        let normalized = Vector3D::new(0.0_f64, 3.0, 4.0).try_normalize().unwrap();
+        assert!((normalized.y() - 0.6).abs() < 1e-10);
+        assert!((normalized.z() - 0.8).abs() < 1e-10);
+
+        // ゼロベクトル・非有限成分を含むベクトルは正規化できない
+        assert!(Vector3D::<f64>::zero().try_normalize().is_none());
+        assert!(Vector3D::new(1.0, f64::NAN, 0.0).try_normalize().is_none());
+        assert!(Vector3D::new(f64::NEG_INFINITY, 0.0, 0.0)
+            .try_normalize()
+            .is_none());
+
+        // 二乗がオーバーフローする大きな成分・アンダーフローする小さな成分でも正規化できる
+        let large = Vector3D::new(3e200_f64, 4e200, 0.0)
+            .try_normalize()
+            .unwrap();
+        assert!((large.x() - 0.6).abs() < 1e-12 && (large.y() - 0.8).abs() < 1e-12);
+        let tiny = Vector3D::new(0.0_f64, 0.0, 1e-300).try_normalize().unwrap();
+        assert_eq!(tiny.z(), 1.0);
+
+        // 方向も同様に作成できない
+        assert!(Direction3D::from_vector(Vector3D::new(1.0, f64::NAN, 0.0)).is_none());
+        assert!(Direction3D::new(f64::INFINITY, 0.0, 0.0).is_none());
+    }
+
     #[test]
     fn test_vector3d_operations() {
         let v1 = Vector3D::new(1.0, 2.0, 3.0);
@@ -221,4 +247,32 @@ mod tests {
         let normalized = v.normalize();
         assert!((normalized.length() - 1.0_f64).abs() < 1e-10);
     }
+
+    #[test]
+    fn test_lerp_reflect_project() {
+        let a = Vector3D::<f64>::new(0.0, 2.0, 4.0);
+        let b = Vector3D::new(2.0, 0.0, -4.0);
+        assert_eq!(a.lerp(&b, 0.5), Vector3D::new(1.0, 1.0, 0.0));
+        assert_eq!(a.lerp(&b, 0.0), a);
+        assert_eq!(a.lerp(&b, 1.0), b);
+
+        // 床（+Z法線）での反射は Z 成分のみ反転
+        let velocity = Vector3D::<f64>::new(1.0, -2.0, -3.0);
+        let reflected = velocity.reflect(Direction3D::positive_z());
+        assert_eq!(reflected, Vector3D::new(1.0, -2.0, 3.0));
+        assert!((reflected.length() - velocity.length()).abs() < 1e-12);
+
+        // 射影と直交成分の和は元のベクトルで、直交成分は基準に垂直
+        let onto = Vector3D::new(2.0, 2.0, 0.0);
+        let projected = velocity.project_onto(&onto);
+        let rejected = velocity.reject_from(&onto);
+        assert!((projected - Vector3D::new(-0.5, -0.5, 0.0)).length() < 1e-12);
+        assert!(rejected.dot(&onto).abs() < 1e-12);
+        assert!((projected + rejected - velocity).length() < 1e-12);
+
+        // ゼロベクトルへの射影はゼロ、直交成分は元のベクトル
+        let zero = Vector3D::zero();
+        assert_eq!(velocity.project_onto(&zero), zero);
+        assert_eq!(velocity.reject_from(&zero), velocity);
+    }
 }
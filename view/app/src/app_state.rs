@@ -2,6 +2,8 @@ use crate::app_renderer::AppRenderer;
 use crate::graphic::{init_graphic, Graphic};
 use crate::mouse_input::MouseInput;
 use crate::stl_loader;
+use geo_algorithms::MeshEdges;
+use geo_primitives::{BBox3D, TriangleMesh3D, Vector3D};
 use stage::{DraftStage, MeshStage, OutlineStage, ShadingStage};
 use std::path::Path;
 use std::sync::Arc;
@@ -14,6 +16,8 @@ pub struct AppState {
     pub renderer: AppRenderer,
     pub camera: Camera,
     pub mouse_input: MouseInput,
+    /// 読み込み済みメッシュの辺の隣接情報（輪郭線ステージが視点ごとの絞り込みに使う）
+    pub mesh_edges: Option<Arc<MeshEdges>>,
 }
 
 impl AppState {
@@ -27,6 +31,7 @@ impl AppState {
             renderer,
             camera: Camera::new(),
             mouse_input: MouseInput::new(),
+            mesh_edges: None,
         }
     }
 
@@ -51,11 +56,16 @@ impl AppState {
     }
 
     pub fn set_stage_outline(&mut self) {
-        let stage = Box::new(OutlineStage::new(
+        let mut stage = Box::new(OutlineStage::new(
             &self.graphic.device,
             self.graphic.config.format,
         ));
+        if let Some(mesh_edges) = &self.mesh_edges {
+            stage.set_mesh_edges(mesh_edges.clone());
+        }
         self.renderer.set_stage(stage);
+        // 現在の視点で輪郭線を抽出する
+        self.update_camera_uniforms();
     }
 
     pub fn set_stage_shading(&mut self) {
@@ -71,17 +81,19 @@ impl AppState {
         tracing::info!("STLファイル読み込み開始: {:?}", path);
 
         // STLファイルを読み込み、レンダリング用データに変換
-        let (vertices, indices, _bounds) = stl_loader::load_stl_for_rendering(path)?;
+        let stl_data = stl_loader::load_stl_for_rendering(path)?;
 
-        // カメラを標準CAD視点に設定（固定値）
+        // 標準CAD視点からメッシュ全体が収まる距離に合わせる
         self.camera.reset_to_standard_cad_view();
+        self.fit_camera_to_mesh(&stl_data.mesh);
 
         // メッシュステージを作成してSTLデータを設定
         let mut mesh_stage = Box::new(MeshStage::new(
             &self.graphic.device,
             self.graphic.config.format,
         ));
-        mesh_stage.set_mesh_data(&self.graphic.device, vertices, indices);
+        self.mesh_edges = Some(Arc::new(MeshEdges::new(&stl_data.mesh)));
+        mesh_stage.set_mesh(&self.graphic.device, stl_data.mesh);
 
         self.renderer.set_stage(mesh_stage);
 
@@ -98,17 +110,19 @@ impl AppState {
         let sample_path = std::env::temp_dir().join("redring_sample.stl");
 
         // サンプルSTLファイルを作成して読み込み
-        let (vertices, indices, _bounds) = stl_loader::create_sample_stl_with_bounds(&sample_path)?;
+        let stl_data = stl_loader::create_sample_stl_with_bounds(&sample_path)?;
 
-        // カメラを標準CAD視点に設定（固定値）
+        // 標準CAD視点からメッシュ全体が収まる距離に合わせる
         self.camera.reset_to_standard_cad_view();
+        self.fit_camera_to_mesh(&stl_data.mesh);
 
         // メッシュステージを作成してSTLデータを設定
         let mut mesh_stage = Box::new(MeshStage::new(
             &self.graphic.device,
             self.graphic.config.format,
         ));
-        mesh_stage.set_mesh_data(&self.graphic.device, vertices, indices);
+        self.mesh_edges = Some(Arc::new(MeshEdges::new(&stl_data.mesh)));
+        mesh_stage.set_mesh(&self.graphic.device, stl_data.mesh);
 
         self.renderer.set_stage(mesh_stage);
 
@@ -118,6 +132,14 @@ impl AppState {
         Ok(())
     }
 
+    /// メッシュの境界ボックスが画面に収まるようカメラを合わせる（空なら何もしない）
+    fn fit_camera_to_mesh(&mut self, mesh: &TriangleMesh3D<f64>) {
+        if let Some((min, max)) = mesh.bounding_box() {
+            let aspect = self.graphic.config.width as f32 / self.graphic.config.height as f32;
+            self.camera.fit_to_bbox(&BBox3D::new(min, max), aspect);
+        }
+    }
+
     /// カメラをリセット
     pub fn reset_camera(&mut self) {
         self.camera.reset();
@@ -265,6 +287,26 @@ impl AppState {
             .downcast_mut::<MeshStage>()
         {
             mesh_stage.update_camera(&self.graphic.queue, view_matrix, projection_matrix);
+        } else if let Some(outline_stage) = self
+            .renderer
+            .get_stage_mut()
+            .as_any_mut()
+            .downcast_mut::<OutlineStage>()
+        {
+            // シルエットは視線方向で変わるため、カメラが動くたびに絞り込み直す
+            let view_dir = self.camera.target - self.camera.eye_position();
+            let view_dir = Vector3D::new(
+                view_dir.x() as f64,
+                view_dir.y() as f64,
+                view_dir.z() as f64,
+            );
+            outline_stage.update_edges(
+                &self.graphic.device,
+                &self.graphic.queue,
+                &view_dir,
+                view_matrix,
+                projection_matrix,
+            );
         }
     }
 }
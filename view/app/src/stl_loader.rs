@@ -3,30 +3,28 @@
 //! STLファイルを読み込んでメッシュデータをGPU用の形式に変換します。
 //! MVVMアーキテクチャに準拠し、ViewModelレイヤー経由でSTLデータを処理します。
 
-use render::vertex_3d::{convert_vertex_data_to_mesh_vertices, MeshVertex};
 use std::path::Path;
 use viewmodel::stl_loader::{create_and_load_sample_stl, load_stl_mesh, StlMeshData};
 
 /// STL読み込み結果の型エイリアス（複雑性軽減のため）
-type StlLoadResult =
-    Result<(Vec<MeshVertex>, Vec<u32>, ([f32; 3], [f32; 3])), Box<dyn std::error::Error>>;
+type StlLoadResult = Result<StlMeshData, Box<dyn std::error::Error>>;
 
-/// STLファイルを読み込み、レンダリング用の頂点データと境界ボックスに変換
+/// STLファイルを読み込み、レンダリング用のメッシュデータと境界ボックスに変換
 /// MVVM準拠: ViewModel経由でModel層にアクセス
+///
+/// 頂点データはメッシュステージがシェーディングモードに合わせて作るため、
+/// 元メッシュを含めてそのまま返す。
 pub fn load_stl_for_rendering(path: &Path) -> StlLoadResult {
     // ViewModelレイヤー経由でSTLデータを取得・変換
     let stl_data: StlMeshData = load_stl_mesh(path)?;
 
-    // ViewModel→Render層の変換
-    let render_vertices = convert_vertex_data_to_mesh_vertices(&stl_data.vertices);
-
     tracing::info!(
-        "STL→レンダリング変換完了: {} MeshVertex, {} インデックス",
-        render_vertices.len(),
-        stl_data.indices.len()
+        "STL→レンダリング変換完了: {} 頂点, {} 三角形",
+        stl_data.mesh.vertex_count(),
+        stl_data.mesh.triangle_count()
     );
 
-    Ok((render_vertices, stl_data.indices, stl_data.bounds))
+    Ok(stl_data)
 }
 
 /// サンプルSTLファイルを作成
@@ -40,12 +38,7 @@ pub fn create_sample_stl(path: &Path) -> Result<(), Box<dyn std::error::Error>>
 /// MVVM準拠: ViewModelレイヤー経由でデータ処理
 pub fn create_sample_stl_with_bounds(path: &Path) -> StlLoadResult {
     // ViewModelレイヤー経由でサンプル作成・読み込み
-    let stl_data = create_and_load_sample_stl(path)?;
-
-    // ViewModel→Render層の変換
-    let render_vertices = convert_vertex_data_to_mesh_vertices(&stl_data.vertices);
-
-    Ok((render_vertices, stl_data.indices, stl_data.bounds))
+    create_and_load_sample_stl(path)
 }
 
 #[cfg(test)]
@@ -0,0 +1,37 @@
+// 線分バッチ描画用シェーダー
+// 頂点カラー版（vs_vertex_color）と、バッチごとのUniform色版（vs_uniform_color）を持つ
+
+struct LineUniforms {
+    view_proj: mat4x4<f32>,
+    // 色ごとのバッチで使う線の色（頂点カラー版では未使用）
+    color: vec4<f32>,
+}
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) color: vec4<f32>,
+}
+
+@group(0) @binding(0)
+var<uniform> uniforms: LineUniforms;
+
+@vertex
+fn vs_vertex_color(@location(0) position: vec3<f32>, @location(1) color: vec4<f32>) -> VertexOutput {
+    var out: VertexOutput;
+    out.clip_position = uniforms.view_proj * vec4<f32>(position, 1.0);
+    out.color = color;
+    return out;
+}
+
+@vertex
+fn vs_uniform_color(@location(0) position: vec3<f32>) -> VertexOutput {
+    var out: VertexOutput;
+    out.clip_position = uniforms.view_proj * vec4<f32>(position, 1.0);
+    out.color = uniforms.color;
+    return out;
+}
+
+@fragment
+fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
+    return input.color;
+}
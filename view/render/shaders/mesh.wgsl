@@ -15,6 +15,14 @@ struct VertexOutput {
 struct Uniforms {
     view_proj: mat4x4<f32>,
     model: mat4x4<f32>,
+    // x: シェーディングモード（0: スムース, 1: フラット）
+    shading: vec4<u32>,
+    // クリップ平面 ax + by + cz + d = 0 の (a, b, c, d)（ワールド座標）
+    clip_plane: vec4<f32>,
+    // 切断面の塗りつぶし色
+    cap_color: vec4<f32>,
+    // x: クリップ有効, y: キャッピング有効
+    clip: vec4<u32>,
 }
 
 @group(0) @binding(0)
@@ -37,10 +45,22 @@ fn vs_main(input: VertexInput) -> VertexOutput {
     return out;
 }
 
-@fragment
-fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
+// 平面の裏側にあるフラグメントか
+fn is_clipped(world_position: vec3<f32>) -> bool {
+    return uniforms.clip.x == 1u
+        && dot(uniforms.clip_plane.xyz, world_position) + uniforms.clip_plane.w < 0.0;
+}
+
+fn shade(input: VertexOutput) -> vec4<f32> {
     // 法線を正規化
-    let normal = normalize(input.world_normal);
+    var normal = normalize(input.world_normal);
+
+    // フラットモードでは画面空間の位置微分から面法線を求める
+    let face_normal = normalize(cross(dpdx(input.world_position), dpdy(input.world_position)));
+    if (uniforms.shading.x == 1u) {
+        // 頂点法線と同じ側を向くように揃える
+        normal = select(face_normal, -face_normal, dot(face_normal, normal) < 0.0);
+    }
 
     // シンプルな拡散ライティング（固定ライト方向）
     let light_dir = normalize(vec3<f32>(1.0, 1.0, 1.0));
@@ -54,3 +74,31 @@ fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
 
     return vec4<f32>(final_color, 1.0);
 }
+
+@fragment
+fn fs_main(input: VertexOutput, @builtin(front_facing) front_facing: bool) -> @location(0) vec4<f32> {
+    if (is_clipped(input.world_position)) {
+        discard;
+    }
+
+    // 画面空間微分は一様な制御フローで求める必要があるため、先に陰影を計算する
+    let color = shade(input);
+
+    // 断面から見える裏面を単色で塗り、切断面が埋まって見えるようにする。
+    // 深度テストで手前の表面に隠れた裏面はここまで来ないので、断面色になるのは
+    // クリップで開いた部分から見える裏面だけ
+    if (uniforms.clip.y == 1u && !front_facing) {
+        return uniforms.cap_color;
+    }
+
+    return color;
+}
+
+@fragment
+fn fs_wireframe(input: VertexOutput) -> @location(0) vec4<f32> {
+    if (is_clipped(input.world_position)) {
+        discard;
+    }
+
+    return shade(input);
+}
@@ -0,0 +1,64 @@
+// 点群描画用シェーダー
+// 各点をインスタンスとして、スクリーン上の正方形（円形に切り抜き）を描画する
+
+struct PointInput {
+    @location(0) position: vec3<f32>,
+    @location(1) color: vec4<f32>,
+}
+
+struct PointUniforms {
+    view_proj: mat4x4<f32>,
+    // x: 点サイズ（ピクセル）, y: 遠近減衰（0: 無効, 1: 有効）, z: 減衰の基準距離
+    params: vec4<f32>,
+    // xy: ビューポートサイズ（ピクセル）
+    viewport: vec4<f32>,
+}
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) color: vec4<f32>,
+    // 点の中心からの相対位置（-1〜1）
+    @location(1) offset: vec2<f32>,
+}
+
+@group(0) @binding(0)
+var<uniform> uniforms: PointUniforms;
+
+// 正方形を構成する2枚の三角形の角
+const CORNERS = array<vec2<f32>, 6>(
+    vec2<f32>(-1.0, -1.0),
+    vec2<f32>(1.0, -1.0),
+    vec2<f32>(1.0, 1.0),
+    vec2<f32>(-1.0, -1.0),
+    vec2<f32>(1.0, 1.0),
+    vec2<f32>(-1.0, 1.0),
+);
+
+@vertex
+fn vs_main(@builtin(vertex_index) vertex_index: u32, point: PointInput) -> VertexOutput {
+    var out: VertexOutput;
+    let corner = CORNERS[vertex_index];
+    let center = uniforms.view_proj * vec4<f32>(point.position, 1.0);
+
+    // ピクセル単位の半径を NDC に換算し、w を掛けて透視除算後も同じ大きさにする
+    var radius = uniforms.params.x * 0.5;
+    if (uniforms.params.y > 0.5) {
+        // 遠近減衰：基準距離より遠い点ほど小さく描く
+        radius = radius * uniforms.params.z / max(center.w, 1.0e-6);
+    }
+    let ndc_offset = corner * radius * 2.0 / uniforms.viewport.xy;
+
+    out.clip_position = center + vec4<f32>(ndc_offset * center.w, 0.0, 0.0);
+    out.color = point.color;
+    out.offset = corner;
+    return out;
+}
+
+@fragment
+fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
+    // 正方形の角を落として円形の点にする
+    if (dot(input.offset, input.offset) > 1.0) {
+        discard;
+    }
+    return input.color;
+}
@@ -1,11 +0,0 @@
-// render/shaders/wireframe.wgsl
-
-@vertex
-fn vs_main(@location(0) position: vec3<f32>) -> @builtin(position) vec4<f32> {
-    return vec4<f32>(position, 1.0);
-}
-
-@fragment
-fn fs_main() -> @location(0) vec4<f32> {
-    return vec4<f32>(1.0, 1.0, 1.0, 1.0); // 白色
-}
\ No newline at end of file
@@ -0,0 +1,52 @@
+// ワイヤーフレームオーバーレイ用シェーダー
+// ソリッド描画の上にメッシュのエッジを重ねて描画する
+
+struct VertexInput {
+    @location(0) position: vec3<f32>,
+    @location(1) normal: vec3<f32>,
+}
+
+struct Uniforms {
+    view_proj: mat4x4<f32>,
+    model: mat4x4<f32>,
+    shading: vec4<u32>,
+    clip_plane: vec4<f32>,
+    cap_color: vec4<f32>,
+    clip: vec4<u32>,
+}
+
+struct OverlayUniforms {
+    color: vec4<f32>,
+}
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) world_position: vec3<f32>,
+}
+
+@group(0) @binding(0)
+var<uniform> uniforms: Uniforms;
+
+@group(1) @binding(0)
+var<uniform> overlay: OverlayUniforms;
+
+@vertex
+fn vs_main(input: VertexInput) -> VertexOutput {
+    var out: VertexOutput;
+    let world_position = uniforms.model * vec4<f32>(input.position, 1.0);
+    out.world_position = world_position.xyz;
+    // ソリッド面とのZファイティングはパイプラインの深度バイアスで避ける
+    out.clip_position = uniforms.view_proj * world_position;
+
+    return out;
+}
+
+@fragment
+fn fs_main(input: VertexOutput) -> @location(0) vec4<f32> {
+    // 断面表示中は平面の裏側のエッジも描かない
+    if (uniforms.clip.x == 1u
+        && dot(uniforms.clip_plane.xyz, input.world_position) + uniforms.clip_plane.w < 0.0) {
+        discard;
+    }
+    return overlay.color;
+}
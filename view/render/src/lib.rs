@@ -2,10 +2,12 @@ pub mod device;
 pub mod mesh;
 pub mod mesh_convert;
 pub mod pipeline;
+pub mod point_cloud;
 pub mod render_2d;
 pub mod render_3d;
 pub mod shader;
 pub mod surface;
+pub mod text;
 pub mod vertex_2d;
 pub mod vertex_3d;
 pub mod wireframe;
@@ -1,14 +1,260 @@
 use crate::shader;
 use crate::vertex_3d::MeshVertex;
+use crate::wireframe::{draw_wireframe_overlay, WireframeOverlayResources, WireframeOverlayStyle};
 use bytemuck::{Pod, Zeroable};
+use std::collections::HashMap;
 use wgpu::util::DeviceExt;
 
+pub use viewmodel::mesh_converter::ShadingMode;
+
 /// メッシュレンダリング用のUniform構造体（簡略版）
 #[repr(C)]
 #[derive(Copy, Clone, Debug, Pod, Zeroable)]
 pub struct MeshUniforms {
     pub view_proj: [[f32; 4]; 4], // ビュー・プロジェクション行列
     pub model: [[f32; 4]; 4],     // モデル行列
+    pub shading: [u32; 4],        // x: シェーディングモード（0: スムース, 1: フラット）
+    pub clip_plane: [f32; 4],     // クリップ平面 ax + by + cz + d = 0 の (a, b, c, d)
+    pub cap_color: [f32; 4],      // 切断面の塗りつぶし色
+    pub clip: [u32; 4],           // x: クリップ有効, y: キャッピング有効
+}
+
+/// シェーダーに渡すシェーディングモードの値
+fn shading_mode_to_uniform(mode: ShadingMode) -> [u32; 4] {
+    match mode {
+        ShadingMode::Smooth => [0, 0, 0, 0],
+        ShadingMode::Flat => [1, 0, 0, 0],
+    }
+}
+
+/// メッシュ描画で使う深度バッファの形式
+pub const DEPTH_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Depth32Float;
+
+/// ソリッド描画の深度ステート（手前の面だけを残し、深度を書き込む）
+///
+/// 断面のキャッピングは裏面を塗るため、深度テストがないと手前の表面に隠れている
+/// 裏面まで断面色で上書きしてしまう。
+fn solid_depth_state() -> wgpu::DepthStencilState {
+    wgpu::DepthStencilState {
+        format: DEPTH_FORMAT,
+        depth_write_enabled: true,
+        depth_compare: wgpu::CompareFunction::Less,
+        stencil: wgpu::StencilState::default(),
+        bias: wgpu::DepthBiasState::default(),
+    }
+}
+
+/// カラーターゲットと同じ大きさの深度テクスチャ
+struct DepthTarget {
+    view: wgpu::TextureView,
+    size: (u32, u32),
+}
+
+impl DepthTarget {
+    fn new(device: &wgpu::Device, (width, height): (u32, u32)) -> Self {
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("Mesh Depth Texture"),
+            size: wgpu::Extent3d {
+                width,
+                height,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: DEPTH_FORMAT,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+            view_formats: &[],
+        });
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+        Self {
+            view,
+            size: (width, height),
+        }
+    }
+}
+
+/// 断面表示の設定
+///
+/// 平面の方程式はワールド座標で、`ax + by + cz + d < 0`（平面の裏側）のフラグメントを破棄する。
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ClipSettings {
+    /// クリップ平面の係数 (a, b, c, d)。`None` ならクリップしない
+    pub plane: Option<[f32; 4]>,
+    /// 切断面を埋める色。`None` ならキャッピングしない
+    pub cap_color: Option<[f32; 4]>,
+}
+
+impl ClipSettings {
+    /// シェーダーに渡す (clip_plane, cap_color, clip) の値
+    fn to_uniform(self) -> ([f32; 4], [f32; 4], [u32; 4]) {
+        let enabled = self.plane.is_some();
+        let capping = enabled && self.cap_color.is_some();
+        (
+            self.plane.unwrap_or([0.0; 4]),
+            self.cap_color.unwrap_or([0.0; 4]),
+            [enabled as u32, capping as u32, 0, 0],
+        )
+    }
+}
+
+/// 面カリングの設定（表とみなす巻き順と、捨てる面）
+///
+/// 既定は両面描画（カリングなし、反時計回りが表）。向きが統一されていないメッシュは
+/// 両面描画で確認し、向きを揃えたメッシュは裏面カリングで描画負荷を減らす。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CullMode {
+    /// 捨てる面。`None` ならカリングしない（両面描画）
+    pub face: Option<wgpu::Face>,
+    /// 表とみなす頂点の巻き順
+    pub front_face: wgpu::FrontFace,
+}
+
+impl CullMode {
+    /// カリングしない（両面描画）
+    pub const NONE: Self = Self {
+        face: None,
+        front_face: wgpu::FrontFace::Ccw,
+    };
+
+    /// 反時計回り（CCW）を表として裏面を捨てる
+    pub const BACK_CCW: Self = Self {
+        face: Some(wgpu::Face::Back),
+        front_face: wgpu::FrontFace::Ccw,
+    };
+
+    /// 時計回り（CW）を表として裏面を捨てる
+    pub const BACK_CW: Self = Self {
+        face: Some(wgpu::Face::Back),
+        front_face: wgpu::FrontFace::Cw,
+    };
+
+    /// カリングが有効かどうか
+    pub fn is_culling(&self) -> bool {
+        self.face.is_some()
+    }
+
+    /// この設定でのラスタライズステート
+    fn primitive_state(self, polygon_mode: wgpu::PolygonMode) -> wgpu::PrimitiveState {
+        wgpu::PrimitiveState {
+            topology: wgpu::PrimitiveTopology::TriangleList,
+            strip_index_format: None,
+            front_face: self.front_face,
+            cull_mode: self.face,
+            polygon_mode,
+            unclipped_depth: false,
+            conservative: false,
+        }
+    }
+}
+
+impl Default for CullMode {
+    fn default() -> Self {
+        Self::NONE
+    }
+}
+
+/// 1つのカリング設定に対応するパイプラインの組
+struct MeshPipelines {
+    solid: wgpu::RenderPipeline,
+    wireframe: wgpu::RenderPipeline,
+}
+
+/// カリング設定ごとのメッシュ描画パイプラインのキャッシュ
+///
+/// ラスタライズステートはパイプライン作成時に固定されるため、まだ使っていない
+/// 設定に切り替えたときだけパイプラインを作り、以後は作成済みのものを再利用する。
+struct MeshPipelineCache {
+    shader: wgpu::ShaderModule,
+    layout: wgpu::PipelineLayout,
+    format: wgpu::TextureFormat,
+    pipelines: HashMap<CullMode, MeshPipelines>,
+}
+
+impl MeshPipelineCache {
+    fn new(
+        device: &wgpu::Device,
+        format: wgpu::TextureFormat,
+        bind_group_layout: &wgpu::BindGroupLayout,
+    ) -> Self {
+        let shader = shader::mesh_shader(device);
+        let layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Mesh Render Pipeline Layout"),
+            bind_group_layouts: &[bind_group_layout],
+            push_constant_ranges: &[],
+        });
+        Self {
+            shader,
+            layout,
+            format,
+            pipelines: HashMap::new(),
+        }
+    }
+
+    /// `mode` のパイプラインを用意する（作成済みなら何もしない）
+    fn prepare(&mut self, device: &wgpu::Device, mode: CullMode) {
+        if self.pipelines.contains_key(&mode) {
+            return;
+        }
+        let pipelines = MeshPipelines {
+            solid: self.create_pipeline(
+                device,
+                "Mesh Render Pipeline",
+                "fs_main",
+                mode.primitive_state(wgpu::PolygonMode::Fill),
+            ),
+            // ワイヤーフレーム用のパイプライン（ポリゴンモードをLineに変更）
+            wireframe: self.create_pipeline(
+                device,
+                "Mesh Wireframe Pipeline",
+                "fs_wireframe",
+                mode.primitive_state(wgpu::PolygonMode::Line),
+            ),
+        };
+        self.pipelines.insert(mode, pipelines);
+    }
+
+    fn get(&self, mode: CullMode) -> Option<&MeshPipelines> {
+        self.pipelines.get(&mode)
+    }
+
+    fn create_pipeline(
+        &self,
+        device: &wgpu::Device,
+        label: &str,
+        fragment_entry_point: &str,
+        primitive: wgpu::PrimitiveState,
+    ) -> wgpu::RenderPipeline {
+        device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some(label),
+            layout: Some(&self.layout),
+            vertex: wgpu::VertexState {
+                module: &self.shader,
+                entry_point: Some("vs_main"),
+                buffers: &[MeshVertex::desc()],
+                compilation_options: wgpu::PipelineCompilationOptions::default(),
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &self.shader,
+                entry_point: Some(fragment_entry_point),
+                targets: &[Some(wgpu::ColorTargetState {
+                    format: self.format,
+                    blend: Some(wgpu::BlendState::REPLACE),
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+                compilation_options: wgpu::PipelineCompilationOptions::default(),
+            }),
+            primitive,
+            depth_stencil: Some(solid_depth_state()),
+            multisample: wgpu::MultisampleState {
+                count: 1,
+                mask: !0,
+                alpha_to_coverage_enabled: false,
+            },
+            multiview: None,
+            cache: None,
+        })
+    }
 }
 
 impl Default for MeshUniforms {
@@ -26,14 +272,17 @@ impl Default for MeshUniforms {
                 [0.0, 0.0, 1.0, 0.0],
                 [0.0, 0.0, 0.0, 1.0],
             ],
+            shading: shading_mode_to_uniform(ShadingMode::default()),
+            clip_plane: [0.0; 4],
+            cap_color: [0.0; 4],
+            clip: [0; 4],
         }
     }
 }
 
 /// メッシュレンダリングリソース
 pub struct MeshResources {
-    pub render_pipeline: wgpu::RenderPipeline,
-    pub wireframe_pipeline: wgpu::RenderPipeline,
+    pipeline_cache: MeshPipelineCache,
     pub uniform_buffer: wgpu::Buffer,
     pub bind_group_layout: wgpu::BindGroupLayout,
     pub bind_group: wgpu::BindGroup,
@@ -41,12 +290,17 @@ pub struct MeshResources {
     pub index_buffer: Option<wgpu::Buffer>,
     pub index_count: u32,
     pub wireframe_mode: bool,
+    pub shading_mode: ShadingMode,
+    pub wireframe_overlay: WireframeOverlayResources,
+    pub wireframe_overlay_enabled: bool,
+    pub clip_settings: ClipSettings,
+    /// 面カリング設定（`set_cull_mode` で対応するパイプラインを用意してから切り替える）
+    cull_mode: CullMode,
+    depth_target: Option<DepthTarget>,
 }
 
 impl MeshResources {
     pub fn new(device: &wgpu::Device, format: wgpu::TextureFormat) -> Self {
-        let shader = shader::mesh_shader(device);
-
         // Uniform bind group layout
         let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
             entries: &[wgpu::BindGroupLayoutEntry {
@@ -80,95 +334,15 @@ impl MeshResources {
             label: Some("mesh_bind_group"),
         });
 
-        // Render pipeline layout
-        let render_pipeline_layout =
-            device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
-                label: Some("Mesh Render Pipeline Layout"),
-                bind_group_layouts: &[&bind_group_layout],
-                push_constant_ranges: &[],
-            });
-
-        // Render pipeline
-        let render_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
-            label: Some("Mesh Render Pipeline"),
-            layout: Some(&render_pipeline_layout),
-            vertex: wgpu::VertexState {
-                module: &shader,
-                entry_point: Some("vs_main"),
-                buffers: &[MeshVertex::desc()],
-                compilation_options: wgpu::PipelineCompilationOptions::default(),
-            },
-            fragment: Some(wgpu::FragmentState {
-                module: &shader,
-                entry_point: Some("fs_main"),
-                targets: &[Some(wgpu::ColorTargetState {
-                    format,
-                    blend: Some(wgpu::BlendState::REPLACE),
-                    write_mask: wgpu::ColorWrites::ALL,
-                })],
-                compilation_options: wgpu::PipelineCompilationOptions::default(),
-            }),
-            primitive: wgpu::PrimitiveState {
-                topology: wgpu::PrimitiveTopology::TriangleList,
-                strip_index_format: None,
-                front_face: wgpu::FrontFace::Ccw,
-                cull_mode: None, // カリング無効（両面表示）
-                polygon_mode: wgpu::PolygonMode::Fill,
-                unclipped_depth: false,
-                conservative: false,
-            },
-            depth_stencil: None, // 深度バッファ準備後に有効化
-            multisample: wgpu::MultisampleState {
-                count: 1,
-                mask: !0,
-                alpha_to_coverage_enabled: false,
-            },
-            multiview: None,
-            cache: None,
-        });
+        // 既定のカリング設定（両面描画）のパイプラインを作成しておく
+        let mut pipeline_cache = MeshPipelineCache::new(device, format, &bind_group_layout);
+        pipeline_cache.prepare(device, CullMode::default());
 
-        // ワイヤーフレーム用のパイプライン（ポリゴンモードをLineに変更）
-        let wireframe_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
-            label: Some("Mesh Wireframe Pipeline"),
-            layout: Some(&render_pipeline_layout),
-            vertex: wgpu::VertexState {
-                module: &shader,
-                entry_point: Some("vs_main"),
-                buffers: &[MeshVertex::desc()],
-                compilation_options: wgpu::PipelineCompilationOptions::default(),
-            },
-            fragment: Some(wgpu::FragmentState {
-                module: &shader,
-                entry_point: Some("fs_main"),
-                targets: &[Some(wgpu::ColorTargetState {
-                    format,
-                    blend: Some(wgpu::BlendState::REPLACE),
-                    write_mask: wgpu::ColorWrites::ALL,
-                })],
-                compilation_options: wgpu::PipelineCompilationOptions::default(),
-            }),
-            primitive: wgpu::PrimitiveState {
-                topology: wgpu::PrimitiveTopology::TriangleList,
-                strip_index_format: None,
-                front_face: wgpu::FrontFace::Ccw,
-                cull_mode: None,                       // カリング無効（両面表示）
-                polygon_mode: wgpu::PolygonMode::Line, // ワイヤーフレーム
-                unclipped_depth: false,
-                conservative: false,
-            },
-            depth_stencil: None,
-            multisample: wgpu::MultisampleState {
-                count: 1,
-                mask: !0,
-                alpha_to_coverage_enabled: false,
-            },
-            multiview: None,
-            cache: None,
-        });
+        // ソリッド描画に重ねるワイヤーフレームオーバーレイ
+        let wireframe_overlay = WireframeOverlayResources::new(device, format, &bind_group_layout);
 
         Self {
-            render_pipeline,
-            wireframe_pipeline,
+            pipeline_cache,
             uniform_buffer,
             bind_group_layout,
             bind_group,
@@ -176,7 +350,28 @@ impl MeshResources {
             index_buffer: None,
             index_count: 0,
             wireframe_mode: false,
+            shading_mode: ShadingMode::default(),
+            wireframe_overlay,
+            wireframe_overlay_enabled: false,
+            clip_settings: ClipSettings::default(),
+            cull_mode: CullMode::default(),
+            depth_target: None,
+        }
+    }
+
+    /// 描画先と同じ大きさの深度バッファを用意し、そのビューを返す
+    ///
+    /// 大きさが変わったときだけ作り直す。レンダーパスの深度アタッチメントに使う。
+    pub fn prepare_depth(&mut self, device: &wgpu::Device, size: (u32, u32)) -> &wgpu::TextureView {
+        let size = (size.0.max(1), size.1.max(1));
+        if self.depth_target.as_ref().map(|target| target.size) != Some(size) {
+            self.depth_target = Some(DepthTarget::new(device, size));
         }
+        &self
+            .depth_target
+            .as_ref()
+            .expect("depth target was just prepared")
+            .view
     }
 
     /// Uniformバッファを更新
@@ -194,6 +389,7 @@ impl MeshResources {
         // ビュー・プロジェクション行列を計算
         let view_proj = multiply_matrices(proj_matrix, view_matrix);
 
+        let (clip_plane, cap_color, clip) = self.clip_settings.to_uniform();
         let uniforms = MeshUniforms {
             view_proj,
             model: [
@@ -202,6 +398,10 @@ impl MeshResources {
                 [0.0, 0.0, 1.0, 0.0],
                 [0.0, 0.0, 0.0, 1.0],
             ],
+            shading: shading_mode_to_uniform(self.shading_mode),
+            clip_plane,
+            cap_color,
+            clip,
         };
 
         self.update_uniforms(queue, &uniforms);
@@ -248,15 +448,108 @@ impl MeshResources {
         self.wireframe_mode
     }
 
+    /// シェーディングモードを設定（実行時に切替可能）
+    ///
+    /// フラットモードではフラグメントシェーダーで面法線を求めるため、
+    /// スムース用の共有頂点データのままでもファセット表示になる。
+    /// スムースモードは頂点法線をそのまま補間するので、平均頂点法線の頂点データを
+    /// `update_mesh_data` で渡しておく必要がある（`MeshStage` が元メッシュから作り直す）。
+    pub fn set_shading_mode(&mut self, queue: &wgpu::Queue, mode: ShadingMode) {
+        self.shading_mode = mode;
+
+        let offset = std::mem::offset_of!(MeshUniforms, shading) as wgpu::BufferAddress;
+        queue.write_buffer(
+            &self.uniform_buffer,
+            offset,
+            bytemuck::cast_slice(&shading_mode_to_uniform(mode)),
+        );
+    }
+
+    /// 現在のシェーディングモード
+    pub fn shading_mode(&self) -> ShadingMode {
+        self.shading_mode
+    }
+
+    /// 断面表示のクリップ平面を設定（`None` で解除）
+    ///
+    /// メッシュ自体は変更せず、描画時に平面の裏側のフラグメントを破棄する。
+    pub fn set_clip_plane(&mut self, queue: &wgpu::Queue, plane: Option<[f32; 4]>) {
+        self.clip_settings.plane = plane;
+        self.write_clip_settings(queue);
+    }
+
+    /// 切断面のキャッピング色を設定（`None` でキャッピングしない）
+    ///
+    /// クリップで開いた断面から見える裏面を単色で塗り、切断面が埋まって見えるようにする。
+    /// 深度テストで手前の表面に隠れた裏面は描かれないので、断面色になるのは開口部から
+    /// 見える裏面だけ。
+    pub fn set_clip_capping(&mut self, queue: &wgpu::Queue, cap_color: Option<[f32; 4]>) {
+        self.clip_settings.cap_color = cap_color;
+        self.write_clip_settings(queue);
+    }
+
+    /// 現在の断面表示の設定
+    pub fn clip_settings(&self) -> ClipSettings {
+        self.clip_settings
+    }
+
+    fn write_clip_settings(&self, queue: &wgpu::Queue) {
+        let (clip_plane, cap_color, clip) = self.clip_settings.to_uniform();
+        let offset = std::mem::offset_of!(MeshUniforms, clip_plane) as wgpu::BufferAddress;
+        let mut data = Vec::with_capacity(48);
+        data.extend_from_slice(bytemuck::cast_slice(&clip_plane));
+        data.extend_from_slice(bytemuck::cast_slice(&cap_color));
+        data.extend_from_slice(bytemuck::cast_slice(&clip));
+        queue.write_buffer(&self.uniform_buffer, offset, &data);
+    }
+
+    /// 面カリングの設定を切り替え（実行時に切替可能）
+    ///
+    /// カリングはパイプラインのラスタライズステートなので、初めて使う設定のときだけ
+    /// パイプラインを作成し、以後はキャッシュから再利用する。
+    /// 切断面のキャッピングは裏面を塗るため、裏面カリング中は表示されない。
+    pub fn set_cull_mode(&mut self, device: &wgpu::Device, mode: CullMode) {
+        self.pipeline_cache.prepare(device, mode);
+        self.cull_mode = mode;
+    }
+
+    /// 現在の面カリングの設定
+    pub fn cull_mode(&self) -> CullMode {
+        self.cull_mode
+    }
+
+    /// ワイヤーフレームオーバーレイの有効・無効を設定
+    pub fn set_wireframe_overlay(&mut self, enabled: bool) {
+        self.wireframe_overlay_enabled = enabled;
+    }
+
+    /// ワイヤーフレームオーバーレイが有効かどうか
+    pub fn is_wireframe_overlay(&self) -> bool {
+        self.wireframe_overlay_enabled
+    }
+
+    /// ワイヤーフレームオーバーレイのエッジ色を設定
+    pub fn set_wireframe_overlay_style(
+        &mut self,
+        queue: &wgpu::Queue,
+        style: WireframeOverlayStyle,
+    ) {
+        self.wireframe_overlay.set_style(queue, style);
+    }
+
     /// メッシュをレンダリング
     pub fn render<'a>(&'a self, render_pass: &mut wgpu::RenderPass<'a>) {
+        // set_cull_mode で作成済みなので、現在のカリング設定のパイプラインは必ずある
+        let Some(pipelines) = self.pipeline_cache.get(self.cull_mode) else {
+            return;
+        };
         if let (Some(vertex_buffer), Some(index_buffer)) = (&self.vertex_buffer, &self.index_buffer)
         {
             // ワイヤーフレームモードに応じてパイプラインを選択
             let pipeline = if self.wireframe_mode {
-                &self.wireframe_pipeline
+                &pipelines.wireframe
             } else {
-                &self.render_pipeline
+                &pipelines.solid
             };
 
             render_pass.set_pipeline(pipeline);
@@ -264,12 +557,24 @@ impl MeshResources {
             render_pass.set_vertex_buffer(0, vertex_buffer.slice(..));
             render_pass.set_index_buffer(index_buffer.slice(..), wgpu::IndexFormat::Uint32);
             render_pass.draw_indexed(0..self.index_count, 0, 0..1);
+
+            // シェーディングパスの後にエッジを重ねる（ワイヤーフレームモード時は不要）
+            if self.wireframe_overlay_enabled && !self.wireframe_mode {
+                draw_wireframe_overlay(
+                    render_pass,
+                    &self.wireframe_overlay,
+                    &self.bind_group,
+                    vertex_buffer,
+                    index_buffer,
+                    self.index_count,
+                );
+            }
         }
     }
 }
 
 /// 4x4行列の乗算
-fn multiply_matrices(a: [[f32; 4]; 4], b: [[f32; 4]; 4]) -> [[f32; 4]; 4] {
+pub fn multiply_matrices(a: [[f32; 4]; 4], b: [[f32; 4]; 4]) -> [[f32; 4]; 4] {
     let mut result = [[0.0; 4]; 4];
 
     for i in 0..4 {
@@ -282,3 +587,72 @@ fn multiply_matrices(a: [[f32; 4]; 4], b: [[f32; 4]; 4]) -> [[f32; 4]; 4] {
 
     result
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mesh_uniforms_match_shader_layout() {
+        // WGSL の Uniforms は mat4x4 × 2 + vec4<u32> + vec4<f32> × 2 + vec4<u32> = 192 バイト
+        assert_eq!(std::mem::size_of::<MeshUniforms>(), 192);
+        assert_eq!(std::mem::offset_of!(MeshUniforms, shading), 128);
+        assert_eq!(std::mem::offset_of!(MeshUniforms, clip_plane), 144);
+        assert_eq!(std::mem::offset_of!(MeshUniforms, cap_color), 160);
+        assert_eq!(std::mem::offset_of!(MeshUniforms, clip), 176);
+    }
+
+    #[test]
+    fn test_clip_settings_uniform_values() {
+        let (_, _, clip) = ClipSettings::default().to_uniform();
+        assert_eq!(clip, [0, 0, 0, 0]);
+
+        // 平面なしのキャッピング色は無効
+        let capping_only = ClipSettings {
+            plane: None,
+            cap_color: Some([1.0, 0.0, 0.0, 1.0]),
+        };
+        assert_eq!(capping_only.to_uniform().2, [0, 0, 0, 0]);
+
+        let settings = ClipSettings {
+            plane: Some([0.0, 0.0, 1.0, -0.5]),
+            cap_color: Some([1.0, 0.0, 0.0, 1.0]),
+        };
+        let (plane, color, clip) = settings.to_uniform();
+        assert_eq!(plane, [0.0, 0.0, 1.0, -0.5]);
+        assert_eq!(color, [1.0, 0.0, 0.0, 1.0]);
+        assert_eq!(clip, [1, 1, 0, 0]);
+    }
+
+    #[test]
+    fn test_solid_depth_state() {
+        // 手前の面が残り、隠れた裏面がキャッピング色で上書きされないように深度を書き込む
+        let state = solid_depth_state();
+        assert_eq!(state.format, DEPTH_FORMAT);
+        assert!(state.depth_write_enabled);
+        assert_eq!(state.depth_compare, wgpu::CompareFunction::Less);
+    }
+
+    #[test]
+    fn test_cull_mode_primitive_state() {
+        // 既定は従来どおり両面描画
+        let state = CullMode::default().primitive_state(wgpu::PolygonMode::Fill);
+        assert_eq!(state.cull_mode, None);
+        assert_eq!(state.front_face, wgpu::FrontFace::Ccw);
+        assert!(!CullMode::default().is_culling());
+
+        let state = CullMode::BACK_CW.primitive_state(wgpu::PolygonMode::Line);
+        assert_eq!(state.cull_mode, Some(wgpu::Face::Back));
+        assert_eq!(state.front_face, wgpu::FrontFace::Cw);
+        assert_eq!(state.polygon_mode, wgpu::PolygonMode::Line);
+        assert!(CullMode::BACK_CCW.is_culling());
+        assert_ne!(CullMode::BACK_CCW, CullMode::BACK_CW);
+    }
+
+    #[test]
+    fn test_shading_mode_uniform_values() {
+        assert_eq!(shading_mode_to_uniform(ShadingMode::Smooth)[0], 0);
+        assert_eq!(shading_mode_to_uniform(ShadingMode::Flat)[0], 1);
+        assert_eq!(MeshUniforms::default().shading[0], 1);
+    }
+}
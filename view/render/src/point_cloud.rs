@@ -0,0 +1,308 @@
+//! point_cloud - 点群描画パイプライン
+//!
+//! スキャンデータや法線推定結果などの点群を、指定したピクセルサイズの円形の点として描画する。
+//! wgpu の PointList は1ピクセル固定のため、各点をインスタンスとして頂点シェーダーで
+//! スクリーン上の正方形（6頂点）に展開し、フラグメントシェーダーで円形に切り抜く。
+
+use crate::mesh::multiply_matrices;
+use crate::shader::point_cloud_shader;
+use bytemuck::{Pod, Zeroable};
+use viewmodel::mesh_converter::{GpuPointCloud, PointVertexData};
+use wgpu::util::DeviceExt;
+use wgpu::vertex_attr_array;
+
+/// 1点を描画する正方形の頂点数（三角形2枚）
+const VERTICES_PER_POINT: u32 = 6;
+
+/// 点群の頂点型（位置 + RGBAカラー）
+///
+/// 1点が1インスタンスに対応する。
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Pod, Zeroable)]
+pub struct PointVertex {
+    pub position: [f32; 3],
+    pub color: [f32; 4],
+}
+
+impl PointVertex {
+    /// viewmodelのPointVertexDataから変換
+    pub fn from_point_vertex_data(data: &PointVertexData) -> Self {
+        Self {
+            position: data.position,
+            color: data.color,
+        }
+    }
+
+    pub fn desc<'a>() -> wgpu::VertexBufferLayout<'a> {
+        const ATTRIBUTES: &[wgpu::VertexAttribute] = &vertex_attr_array![
+            0 => Float32x3, // position @location(0)
+            1 => Float32x4, // color @location(1)
+        ];
+
+        wgpu::VertexBufferLayout {
+            array_stride: std::mem::size_of::<PointVertex>() as wgpu::BufferAddress,
+            step_mode: wgpu::VertexStepMode::Instance,
+            attributes: ATTRIBUTES,
+        }
+    }
+}
+
+/// 遠近による点サイズの減衰
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub enum PointSizeAttenuation {
+    /// 距離に関わらず同じピクセルサイズ
+    #[default]
+    None,
+    /// クリップ空間の w（視点からの奥行き）が `reference_distance` の点を指定サイズとし、
+    /// 遠い点ほど距離に反比例して小さく描く
+    Perspective { reference_distance: f32 },
+}
+
+/// 点群の表示スタイル
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct PointCloudStyle {
+    /// 点の直径（ピクセル）
+    pub point_size: f32,
+    /// 遠近による点サイズの減衰
+    pub attenuation: PointSizeAttenuation,
+}
+
+impl Default for PointCloudStyle {
+    fn default() -> Self {
+        Self {
+            point_size: 3.0,
+            attenuation: PointSizeAttenuation::None,
+        }
+    }
+}
+
+/// 点群描画用Uniform（シェーダーの PointUniforms と同じレイアウト）
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Pod, Zeroable)]
+pub struct PointCloudUniforms {
+    pub view_proj: [[f32; 4]; 4],
+    /// x: 点サイズ, y: 遠近減衰（0: 無効, 1: 有効）, z: 減衰の基準距離
+    pub params: [f32; 4],
+    /// xy: ビューポートサイズ（ピクセル）
+    pub viewport: [f32; 4],
+}
+
+impl PointCloudUniforms {
+    fn new(view_proj: [[f32; 4]; 4], style: &PointCloudStyle, viewport: [f32; 2]) -> Self {
+        let (attenuation, reference_distance) = match style.attenuation {
+            PointSizeAttenuation::None => (0.0, 1.0),
+            PointSizeAttenuation::Perspective { reference_distance } => (1.0, reference_distance),
+        };
+        Self {
+            view_proj,
+            params: [style.point_size, attenuation, reference_distance, 0.0],
+            viewport: [viewport[0].max(1.0), viewport[1].max(1.0), 0.0, 0.0],
+        }
+    }
+}
+
+/// 点群レンダリングリソース
+pub struct PointCloudResources {
+    pub pipeline: wgpu::RenderPipeline,
+    pub uniform_buffer: wgpu::Buffer,
+    pub bind_group: wgpu::BindGroup,
+    pub instance_buffer: Option<wgpu::Buffer>,
+    pub point_count: u32,
+    pub style: PointCloudStyle,
+    view_proj: [[f32; 4]; 4],
+    viewport: [f32; 2],
+}
+
+impl PointCloudResources {
+    /// 点群描画パイプラインを作成
+    ///
+    /// `viewport` は点サイズをピクセルからクリップ空間へ換算するための描画先のサイズ。
+    pub fn new(device: &wgpu::Device, format: wgpu::TextureFormat, viewport: [f32; 2]) -> Self {
+        let shader = point_cloud_shader(device);
+        let style = PointCloudStyle::default();
+        let view_proj = [
+            [1.0, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ];
+
+        let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            entries: &[wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::VERTEX,
+                ty: wgpu::BindingType::Buffer {
+                    ty: wgpu::BufferBindingType::Uniform,
+                    has_dynamic_offset: false,
+                    min_binding_size: None,
+                },
+                count: None,
+            }],
+            label: Some("point_cloud_bind_group_layout"),
+        });
+
+        let uniform_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Point Cloud Uniform Buffer"),
+            contents: bytemuck::cast_slice(&[PointCloudUniforms::new(view_proj, &style, viewport)]),
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+        });
+
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            layout: &bind_group_layout,
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: uniform_buffer.as_entire_binding(),
+            }],
+            label: Some("point_cloud_bind_group"),
+        });
+
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Point Cloud Pipeline Layout"),
+            bind_group_layouts: &[&bind_group_layout],
+            push_constant_ranges: &[],
+        });
+
+        let pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Point Cloud Pipeline"),
+            layout: Some(&pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: Some("vs_main"),
+                buffers: &[PointVertex::desc()],
+                compilation_options: wgpu::PipelineCompilationOptions::default(),
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: Some("fs_main"),
+                targets: &[Some(wgpu::ColorTargetState {
+                    format,
+                    blend: Some(wgpu::BlendState::ALPHA_BLENDING),
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+                compilation_options: wgpu::PipelineCompilationOptions::default(),
+            }),
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleList,
+                cull_mode: None,
+                ..Default::default()
+            },
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState::default(),
+            multiview: None,
+            cache: None,
+        });
+
+        Self {
+            pipeline,
+            uniform_buffer,
+            bind_group,
+            instance_buffer: None,
+            point_count: 0,
+            style,
+            view_proj,
+            viewport,
+        }
+    }
+
+    /// 点群データを更新（空の点群なら描画しない）
+    pub fn update_points(&mut self, device: &wgpu::Device, cloud: &GpuPointCloud) {
+        let points: Vec<PointVertex> = cloud
+            .vertices
+            .iter()
+            .map(PointVertex::from_point_vertex_data)
+            .collect();
+
+        self.instance_buffer = (!points.is_empty()).then(|| {
+            device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("Point Cloud Instance Buffer"),
+                contents: bytemuck::cast_slice(&points),
+                usage: wgpu::BufferUsages::VERTEX,
+            })
+        });
+        self.point_count = points.len() as u32;
+    }
+
+    /// カメラ行列を更新
+    pub fn update_camera(
+        &mut self,
+        queue: &wgpu::Queue,
+        view_matrix: [[f32; 4]; 4],
+        proj_matrix: [[f32; 4]; 4],
+    ) {
+        self.view_proj = multiply_matrices(proj_matrix, view_matrix);
+        self.write_uniforms(queue);
+    }
+
+    /// 点サイズ・遠近減衰を設定
+    pub fn set_style(&mut self, queue: &wgpu::Queue, style: PointCloudStyle) {
+        self.style = style;
+        self.write_uniforms(queue);
+    }
+
+    /// 描画先のサイズ（ピクセル）を設定（ウィンドウのリサイズ時に呼ぶ）
+    pub fn set_viewport_size(&mut self, queue: &wgpu::Queue, width: f32, height: f32) {
+        self.viewport = [width, height];
+        self.write_uniforms(queue);
+    }
+
+    fn write_uniforms(&self, queue: &wgpu::Queue) {
+        let uniforms = PointCloudUniforms::new(self.view_proj, &self.style, self.viewport);
+        queue.write_buffer(&self.uniform_buffer, 0, bytemuck::cast_slice(&[uniforms]));
+    }
+
+    /// 点群をレンダリング
+    pub fn render<'a>(&'a self, render_pass: &mut wgpu::RenderPass<'a>) {
+        if let Some(instance_buffer) = &self.instance_buffer {
+            render_pass.set_pipeline(&self.pipeline);
+            render_pass.set_bind_group(0, &self.bind_group, &[]);
+            render_pass.set_vertex_buffer(0, instance_buffer.slice(..));
+            render_pass.draw(0..VERTICES_PER_POINT, 0..self.point_count);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_point_cloud_layouts_match_shader() {
+        // WGSL の PointUniforms は mat4x4 + vec4 × 2 = 96 バイト
+        assert_eq!(std::mem::size_of::<PointCloudUniforms>(), 96);
+        assert_eq!(std::mem::offset_of!(PointCloudUniforms, params), 64);
+        assert_eq!(std::mem::offset_of!(PointCloudUniforms, viewport), 80);
+        // 位置 vec3 + カラー vec4
+        assert_eq!(std::mem::size_of::<PointVertex>(), 28);
+    }
+
+    #[test]
+    fn test_point_cloud_uniform_values() {
+        let identity = [[0.0; 4]; 4];
+        let uniforms =
+            PointCloudUniforms::new(identity, &PointCloudStyle::default(), [800.0, 600.0]);
+        assert_eq!(uniforms.params[..2], [3.0, 0.0]);
+        assert_eq!(uniforms.viewport[..2], [800.0, 600.0]);
+
+        let style = PointCloudStyle {
+            point_size: 5.0,
+            attenuation: PointSizeAttenuation::Perspective {
+                reference_distance: 10.0,
+            },
+        };
+        // 最小化などでサイズ0になってもゼロ除算しない
+        let uniforms = PointCloudUniforms::new(identity, &style, [0.0, 0.0]);
+        assert_eq!(uniforms.params[..3], [5.0, 1.0, 10.0]);
+        assert_eq!(uniforms.viewport[..2], [1.0, 1.0]);
+    }
+
+    #[test]
+    fn test_point_vertex_from_data() {
+        let data = PointVertexData {
+            position: [1.0, 2.0, 3.0],
+            color: [0.5, 0.5, 1.0, 1.0],
+        };
+        let vertex = PointVertex::from_point_vertex_data(&data);
+        assert_eq!(vertex.position, data.position);
+        assert_eq!(vertex.color, data.color);
+    }
+}
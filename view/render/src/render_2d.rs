@@ -1,6 +1,8 @@
 use crate::shader::render_2d_shader;
 use crate::vertex_2d::Vertex2D;
+use crate::wireframe::{GpuLineBatch, LineBatchResources, LineColorMode};
 use std::sync::Arc;
+use viewmodel::mesh_converter::LineVertexData;
 use wgpu::util::DeviceExt;
 use wgpu::{Buffer, RenderPipeline};
 
@@ -90,3 +92,358 @@ pub fn draw_render_2d<'a>(
     pass.set_vertex_buffer(0, vertex_buffer.slice(..));
     pass.draw(0..vertex_count, 0..1);
 }
+
+// ============================================================================
+// 2D作図用グリッド
+// ============================================================================
+
+/// 細分線の画面上の最小間隔（ピクセル）。これより細かくなるとレベルを切り替える
+const MIN_MINOR_SPACING_PIXELS: f32 = 8.0;
+/// 主グリッド線を引く細分線の間隔（10本ごと）
+const MAJOR_LINE_EVERY: i64 = 10;
+
+/// グリッドを描画する2Dビュー（ワールド座標とピクセルの対応）
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct GridView {
+    /// 画面中心のワールド座標
+    pub center: [f32; 2],
+    /// ワールド1単位あたりのピクセル数（ズーム倍率）
+    pub pixels_per_unit: f32,
+    /// ビューポートのサイズ（ピクセル）
+    pub viewport_size: [f32; 2],
+}
+
+impl Default for GridView {
+    fn default() -> Self {
+        Self {
+            center: [0.0, 0.0],
+            pixels_per_unit: 100.0,
+            viewport_size: [800.0, 600.0],
+        }
+    }
+}
+
+impl GridView {
+    /// 表示範囲（ワールド座標の min, max）
+    pub fn visible_bounds(&self) -> ([f32; 2], [f32; 2]) {
+        let half_width = self.viewport_size[0] * 0.5 / self.pixels_per_unit;
+        let half_height = self.viewport_size[1] * 0.5 / self.pixels_per_unit;
+        (
+            [self.center[0] - half_width, self.center[1] - half_height],
+            [self.center[0] + half_width, self.center[1] + half_height],
+        )
+    }
+
+    /// ワールド座標をNDCに変換
+    pub fn world_to_ndc(&self, world: [f32; 2]) -> [f32; 2] {
+        [
+            (world[0] - self.center[0]) * self.pixels_per_unit * 2.0 / self.viewport_size[0],
+            (world[1] - self.center[1]) * self.pixels_per_unit * 2.0 / self.viewport_size[1],
+        ]
+    }
+}
+
+/// グリッドの色と線の太さ
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct GridStyle {
+    /// 細分線（minor）の色
+    pub minor_color: wgpu::Color,
+    /// 主グリッド線（major）の色
+    pub major_color: wgpu::Color,
+    /// 細分線の太さ（ピクセル）
+    pub minor_width: f32,
+    /// 主グリッド線の太さ（ピクセル）
+    pub major_width: f32,
+    /// 原点軸（X軸: 赤 / Y軸: 緑）を強調描画するか
+    pub show_axes: bool,
+    /// 原点軸の太さ（ピクセル）
+    pub axis_width: f32,
+}
+
+impl GridStyle {
+    /// 主グリッド線の色から、細分線を半透明にしたスタイルを作成
+    pub fn from_color(color: wgpu::Color) -> Self {
+        Self {
+            minor_color: wgpu::Color {
+                a: color.a * 0.4,
+                ..color
+            },
+            major_color: color,
+            minor_width: 1.0,
+            major_width: 2.0,
+            show_axes: true,
+            axis_width: 2.0,
+        }
+    }
+}
+
+impl Default for GridStyle {
+    fn default() -> Self {
+        Self::from_color(wgpu::Color {
+            r: 0.5,
+            g: 0.5,
+            b: 0.5,
+            a: 1.0,
+        })
+    }
+}
+
+/// X軸（赤）の色
+const X_AXIS_COLOR: [f32; 4] = [0.9, 0.2, 0.2, 1.0];
+/// Y軸（緑）の色
+const Y_AXIS_COLOR: [f32; 4] = [0.2, 0.8, 0.2, 1.0];
+
+fn color_to_array(color: wgpu::Color) -> [f32; 4] {
+    [
+        color.r as f32,
+        color.g as f32,
+        color.b as f32,
+        color.a as f32,
+    ]
+}
+
+/// ズームに応じた細分線の間隔を求める
+///
+/// 基準間隔を10倍単位で切り替え、画面上の間隔が
+/// [`MIN_MINOR_SPACING_PIXELS`] 以上となる最小の間隔を返す。
+/// 拡大しても基準間隔より細かくはしない。
+pub fn grid_level_spacing(base_spacing: f32, pixels_per_unit: f32) -> f32 {
+    let pixel_spacing = base_spacing * pixels_per_unit;
+    let level = (MIN_MINOR_SPACING_PIXELS / pixel_spacing)
+        .log10()
+        .ceil()
+        .max(0.0);
+    base_spacing * 10f32.powi(level as i32)
+}
+
+/// グリッド線の線分バッチ（NDC）を生成
+///
+/// 線の太さは1ピクセルずつずらした平行線の本数で表す（太さは整数ピクセルに丸める）。
+pub fn build_grid_batch(base_spacing: f32, view: &GridView, style: &GridStyle) -> GpuLineBatch {
+    let mut batch = GpuLineBatch::new();
+    if base_spacing <= 0.0 || view.pixels_per_unit <= 0.0 {
+        return batch;
+    }
+
+    let spacing = grid_level_spacing(base_spacing, view.pixels_per_unit);
+    let (min, max) = view.visible_bounds();
+    let minor_color = color_to_array(style.minor_color);
+    let major_color = color_to_array(style.major_color);
+
+    // axis = 0: 縦線（x = 一定）, axis = 1: 横線（y = 一定）
+    for axis in 0..2 {
+        let first = (min[axis] / spacing).floor() as i64;
+        let last = (max[axis] / spacing).ceil() as i64;
+        // 細分線 → 主グリッド線 → 原点軸の順に重ねる
+        let mut minor_lines = Vec::new();
+        let mut major_lines = Vec::new();
+        let mut origin_line = None;
+        for i in first..=last {
+            let value = i as f32 * spacing;
+            if i == 0 && style.show_axes {
+                origin_line = Some(value);
+            } else if i % MAJOR_LINE_EVERY == 0 {
+                major_lines.push(value);
+            } else {
+                minor_lines.push(value);
+            }
+        }
+
+        for value in minor_lines {
+            push_grid_line(
+                &mut batch,
+                view,
+                axis,
+                value,
+                style.minor_width,
+                minor_color,
+            );
+        }
+        for value in major_lines {
+            push_grid_line(
+                &mut batch,
+                view,
+                axis,
+                value,
+                style.major_width,
+                major_color,
+            );
+        }
+        if let Some(value) = origin_line {
+            // 縦線（x = 0）は Y軸、横線（y = 0）は X軸
+            let color = if axis == 0 {
+                Y_AXIS_COLOR
+            } else {
+                X_AXIS_COLOR
+            };
+            push_grid_line(&mut batch, view, axis, value, style.axis_width, color);
+        }
+    }
+
+    batch
+}
+
+/// 指定ピクセル幅のグリッド線を、1ピクセル間隔の平行な線分として追加
+fn push_grid_line(
+    batch: &mut GpuLineBatch,
+    view: &GridView,
+    axis: usize,
+    value: f32,
+    width_pixels: f32,
+    color: [f32; 4],
+) {
+    let center = view.world_to_ndc([value, value])[axis];
+    let pixel = 2.0 / view.viewport_size[axis];
+    let count = width_pixels.round().max(1.0) as usize;
+    // 線に沿った方向は画面全体を覆う
+    let along = 1 - axis;
+
+    for k in 0..count {
+        let offset = (k as f32 - (count - 1) as f32 * 0.5) * pixel;
+        for extent in [-1.0, 1.0] {
+            let mut position = [0.0; 3];
+            position[axis] = center + offset;
+            position[along] = extent;
+            batch.vertices.push(LineVertexData { position, color });
+        }
+    }
+}
+
+/// 2D作図用の背景グリッド描画リソース
+///
+/// グリッド線は [`LineBatchResources`] でまとめて描画する（頂点はNDCで生成する）。
+pub struct Grid2dResources {
+    pub lines: LineBatchResources,
+    /// 基準間隔（[`Grid2dResources::draw_grid`] を呼ぶまでは 0 でグリッドを生成しない）
+    pub base_spacing: f32,
+    pub view: GridView,
+    pub style: GridStyle,
+}
+
+pub fn create_grid_2d_resources(
+    device: &wgpu::Device,
+    format: wgpu::TextureFormat,
+) -> Grid2dResources {
+    Grid2dResources {
+        lines: LineBatchResources::new(device, format, LineColorMode::PerVertex),
+        base_spacing: 0.0,
+        view: GridView::default(),
+        style: GridStyle::default(),
+    }
+}
+
+impl Grid2dResources {
+    /// ビュー（パン・ズーム）を設定
+    ///
+    /// 頂点はビューに依存するため、反映には [`Grid2dResources::rebuild`] が必要。
+    pub fn set_view(&mut self, view: GridView) {
+        self.view = view;
+    }
+
+    /// 色・太さ・原点軸表示を設定
+    ///
+    /// 反映には [`Grid2dResources::rebuild`] が必要。
+    pub fn set_style(&mut self, style: GridStyle) {
+        self.style = style;
+    }
+
+    /// 基準間隔と主グリッド線の色を指定してグリッドの頂点を生成
+    ///
+    /// 細分線は主グリッド線の色を半透明にしたもの。太さや原点軸の設定は
+    /// 現在のスタイルを引き継ぐ。
+    pub fn draw_grid(&mut self, device: &wgpu::Device, spacing: f32, color: wgpu::Color) {
+        let from_color = GridStyle::from_color(color);
+        self.style.minor_color = from_color.minor_color;
+        self.style.major_color = from_color.major_color;
+        self.base_spacing = spacing;
+        self.rebuild(device);
+    }
+
+    /// 現在の基準間隔・ビュー・スタイルでグリッドの頂点を作り直す
+    pub fn rebuild(&mut self, device: &wgpu::Device) {
+        let batch = build_grid_batch(self.base_spacing, &self.view, &self.style);
+        self.lines.update_batches(device, &[batch]);
+    }
+
+    /// 生成済みのグリッドを描画
+    pub fn render<'a>(&'a self, pass: &mut wgpu::RenderPass<'a>) {
+        self.lines.render(pass);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grid_level_changes_every_ten_times() {
+        // 100px/単位: 0.1 間隔でも 10px あるが、基準間隔より細かくはしない
+        assert!((grid_level_spacing(1.0, 100.0) - 1.0).abs() < 1e-6);
+        // 10px/単位: 1.0 間隔がそのまま使える
+        assert!((grid_level_spacing(1.0, 10.0) - 1.0).abs() < 1e-6);
+        // 1px/単位: 10.0 間隔に切り替わる
+        assert!((grid_level_spacing(1.0, 1.0) - 10.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_grid_lines_cover_view_with_axes() {
+        let view = GridView {
+            center: [0.0, 0.0],
+            pixels_per_unit: 10.0,
+            viewport_size: [200.0, 100.0],
+        };
+        // 全て1ピクセル幅なら1本のグリッド線が1線分
+        let style = GridStyle {
+            major_width: 1.0,
+            axis_width: 1.0,
+            ..GridStyle::default()
+        };
+        let batch = build_grid_batch(1.0, &view, &style);
+
+        // 表示範囲 x: -10..10（21本）, y: -5..5（11本）
+        assert_eq!(batch.segment_count(), 21 + 11);
+        assert!(batch.vertices.iter().any(|v| v.color == X_AXIS_COLOR));
+        assert!(batch.vertices.iter().any(|v| v.color == Y_AXIS_COLOR));
+        // 線は画面の端から端まで
+        assert!(batch
+            .vertices
+            .chunks(2)
+            .all(|pair| pair[0].position[1] == -1.0 || pair[0].position[0] == -1.0));
+
+        let hidden_axes = GridStyle {
+            show_axes: false,
+            ..style
+        };
+        let batch = build_grid_batch(1.0, &view, &hidden_axes);
+        assert!(!batch.vertices.iter().any(|v| v.color == X_AXIS_COLOR));
+    }
+
+    #[test]
+    fn test_grid_major_lines_are_wider() {
+        let view = GridView {
+            center: [0.0, 0.0],
+            pixels_per_unit: 10.0,
+            viewport_size: [400.0, 400.0],
+        };
+        let style = GridStyle::default();
+        let batch = build_grid_batch(1.0, &view, &style);
+        let major = color_to_array(style.major_color);
+        let minor = color_to_array(style.minor_color);
+
+        // 1本目のグリッド線を構成する平行線の本数（= ピクセル幅）
+        let lines_of = |color: [f32; 4]| {
+            let segments: Vec<_> = batch
+                .vertices
+                .chunks(2)
+                .filter(|pair| pair[0].color == color)
+                .collect();
+            let first = segments[0][0].position[0];
+            segments
+                .iter()
+                .take_while(|pair| (pair[0].position[0] - first).abs() < 0.02)
+                .count()
+        };
+        assert_eq!(lines_of(minor), 1);
+        assert_eq!(lines_of(major), 2);
+    }
+}
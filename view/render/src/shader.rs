@@ -7,13 +7,6 @@ pub fn render_2d_shader(device: &wgpu::Device) -> wgpu::ShaderModule {
     })
 }
 
-pub fn wireframe_shader(device: &wgpu::Device) -> wgpu::ShaderModule {
-    device.create_shader_module(wgpu::ShaderModuleDescriptor {
-        label: Some("Wireframe Shader"),
-        source: wgpu::ShaderSource::Wgsl(include_str!("../shaders/wireframe.wgsl").into()),
-    })
-}
-
 pub fn render_3d_shader(device: &Device) -> wgpu::ShaderModule {
     device.create_shader_module(wgpu::ShaderModuleDescriptor {
         label: Some("Render 3D Shader"),
@@ -27,3 +20,24 @@ pub fn mesh_shader(device: &Device) -> wgpu::ShaderModule {
         source: wgpu::ShaderSource::Wgsl(include_str!("../shaders/mesh.wgsl").into()),
     })
 }
+
+pub fn wireframe_overlay_shader(device: &Device) -> wgpu::ShaderModule {
+    device.create_shader_module(wgpu::ShaderModuleDescriptor {
+        label: Some("Wireframe Overlay Shader"),
+        source: wgpu::ShaderSource::Wgsl(include_str!("../shaders/wireframe_overlay.wgsl").into()),
+    })
+}
+
+pub fn point_cloud_shader(device: &Device) -> wgpu::ShaderModule {
+    device.create_shader_module(wgpu::ShaderModuleDescriptor {
+        label: Some("Point Cloud Shader"),
+        source: wgpu::ShaderSource::Wgsl(include_str!("../shaders/point_cloud.wgsl").into()),
+    })
+}
+
+pub fn line_batch_shader(device: &Device) -> wgpu::ShaderModule {
+    device.create_shader_module(wgpu::ShaderModuleDescriptor {
+        label: Some("Line Batch Shader"),
+        source: wgpu::ShaderSource::Wgsl(include_str!("../shaders/line_batch.wgsl").into()),
+    })
+}
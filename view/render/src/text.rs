@@ -0,0 +1,192 @@
+//! text - 文字列ラベル描画の委譲インターフェース
+//!
+//! render 自体はフォントのラスタライズを持たないため、寸法値などの文字列は
+//! [`TextRenderer`] を実装したテキストレンダラに委譲して描画する。
+//! 組み込みの [`StrokeTextRenderer`] は数字・小数点・符号を7セグメント風の線分で描き、
+//! 寸法値の表示に使える。
+
+use crate::render_2d::GridView;
+use crate::wireframe::{GpuLineBatch, LineBatchResources, LineColorMode};
+use viewmodel::mesh_converter::LineVertexData;
+use wgpu::Device;
+
+/// 2D作図画面に描く文字列ラベル
+#[derive(Clone, Debug, PartialEq)]
+pub struct TextLabel {
+    /// 表示する文字列
+    pub text: String,
+    /// 文字列の中心位置（ワールド座標）
+    pub position: [f32; 2],
+    /// ベースラインの向き（X軸からの角度、ラジアン）
+    pub angle: f32,
+    /// 文字の高さ（ワールド単位）
+    pub height: f32,
+    /// 文字色（RGBA）
+    pub color: [f32; 4],
+}
+
+/// 文字列ラベルを描画するレンダラ
+pub trait TextRenderer {
+    /// ラベル群を描画用に準備（ラベルまたはビューが変わったときに呼ぶ）
+    ///
+    /// `view` はラベルのワールド座標を画面へ対応付けるための2Dビュー。
+    fn prepare(&mut self, device: &Device, labels: &[TextLabel], view: &GridView);
+
+    /// 準備したラベルを現在のレンダーパスに描画
+    fn render<'a>(&'a self, pass: &mut wgpu::RenderPass<'a>);
+}
+
+/// 文字の幅に対する字間（文字高さ比）
+const GLYPH_SPACING: f32 = 0.2;
+
+/// 7セグメント風の線分で数字・小数点・符号を描くテキストレンダラ
+///
+/// フォントを持たずに寸法値を表示するためのもので、対応しない文字は空白として扱う。
+/// 線分は [`LineBatchResources`] で描画する。
+pub struct StrokeTextRenderer {
+    lines: LineBatchResources,
+}
+
+impl StrokeTextRenderer {
+    pub fn new(device: &Device, format: wgpu::TextureFormat) -> Self {
+        Self {
+            lines: LineBatchResources::new(device, format, LineColorMode::PerVertex),
+        }
+    }
+}
+
+impl TextRenderer for StrokeTextRenderer {
+    fn prepare(&mut self, device: &Device, labels: &[TextLabel], view: &GridView) {
+        let mut batch = GpuLineBatch::new();
+        for label in labels {
+            for (start, end) in label_segments(label) {
+                for point in [start, end] {
+                    let [x, y] = view.world_to_ndc(point);
+                    batch.vertices.push(LineVertexData {
+                        position: [x, y, 0.0],
+                        color: label.color,
+                    });
+                }
+            }
+        }
+        self.lines.update_batches(device, &[batch]);
+    }
+
+    fn render<'a>(&'a self, pass: &mut wgpu::RenderPass<'a>) {
+        self.lines.render(pass);
+    }
+}
+
+/// ラベルの文字を線分（ワールド座標）に展開
+///
+/// 文字列全体の中心が `position` に来るよう並べ、`angle` だけ回転する。
+pub fn label_segments(label: &TextLabel) -> Vec<([f32; 2], [f32; 2])> {
+    let glyphs: Vec<(f32, &[Stroke])> = label.text.chars().map(glyph).collect();
+    let width = glyphs.iter().map(|(w, _)| w).sum::<f32>()
+        + GLYPH_SPACING * glyphs.len().saturating_sub(1) as f32;
+
+    let (sin, cos) = label.angle.sin_cos();
+    let place = |[x, y]: [f32; 2], offset: f32| {
+        // 文字セル座標（高さ1、下端0）→ 中心基準 → 回転・拡大・平行移動
+        let local = [
+            (x + offset - width * 0.5) * label.height,
+            (y - 0.5) * label.height,
+        ];
+        [
+            label.position[0] + local[0] * cos - local[1] * sin,
+            label.position[1] + local[0] * sin + local[1] * cos,
+        ]
+    };
+
+    let mut segments = Vec::new();
+    let mut offset = 0.0;
+    for (glyph_width, strokes) in glyphs {
+        segments.extend(
+            strokes
+                .iter()
+                .map(|&(start, end)| (place(start, offset), place(end, offset))),
+        );
+        offset += glyph_width + GLYPH_SPACING;
+    }
+    segments
+}
+
+/// 文字セル内の線分（x は 0〜文字幅、y は 0〜1）
+type Stroke = ([f32; 2], [f32; 2]);
+
+const SEG_A: Stroke = ([0.0, 1.0], [0.5, 1.0]);
+const SEG_B: Stroke = ([0.5, 1.0], [0.5, 0.5]);
+const SEG_C: Stroke = ([0.5, 0.5], [0.5, 0.0]);
+const SEG_D: Stroke = ([0.0, 0.0], [0.5, 0.0]);
+const SEG_E: Stroke = ([0.0, 0.0], [0.0, 0.5]);
+const SEG_F: Stroke = ([0.0, 0.5], [0.0, 1.0]);
+const SEG_G: Stroke = ([0.0, 0.5], [0.5, 0.5]);
+
+/// 文字の幅と線分（対応しない文字は線分なし）
+fn glyph(c: char) -> (f32, &'static [Stroke]) {
+    const DIGIT_WIDTH: f32 = 0.5;
+    match c {
+        '0' => (DIGIT_WIDTH, &[SEG_A, SEG_B, SEG_C, SEG_D, SEG_E, SEG_F]),
+        '1' => (DIGIT_WIDTH, &[SEG_B, SEG_C]),
+        '2' => (DIGIT_WIDTH, &[SEG_A, SEG_B, SEG_G, SEG_E, SEG_D]),
+        '3' => (DIGIT_WIDTH, &[SEG_A, SEG_B, SEG_G, SEG_C, SEG_D]),
+        '4' => (DIGIT_WIDTH, &[SEG_F, SEG_G, SEG_B, SEG_C]),
+        '5' => (DIGIT_WIDTH, &[SEG_A, SEG_F, SEG_G, SEG_C, SEG_D]),
+        '6' => (DIGIT_WIDTH, &[SEG_A, SEG_F, SEG_G, SEG_E, SEG_C, SEG_D]),
+        '7' => (DIGIT_WIDTH, &[SEG_A, SEG_B, SEG_C]),
+        '8' => (
+            DIGIT_WIDTH,
+            &[SEG_A, SEG_B, SEG_C, SEG_D, SEG_E, SEG_F, SEG_G],
+        ),
+        '9' => (DIGIT_WIDTH, &[SEG_A, SEG_B, SEG_C, SEG_D, SEG_F, SEG_G]),
+        '-' => (DIGIT_WIDTH, &[SEG_G]),
+        '+' => (DIGIT_WIDTH, &[SEG_G, ([0.25, 0.25], [0.25, 0.75])]),
+        '.' | ',' => (0.1, &[([0.05, 0.0], [0.05, 0.1])]),
+        _ => (DIGIT_WIDTH, &[]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn label(text: &str, angle: f32) -> TextLabel {
+        TextLabel {
+            text: text.to_string(),
+            position: [10.0, 5.0],
+            angle,
+            height: 2.0,
+            color: [1.0; 4],
+        }
+    }
+
+    #[test]
+    fn test_label_segments_are_centered() {
+        // "1" は右側の縦線2本。幅 0.5 × 高さ 2 → x は中心から +0.5
+        let segments = label_segments(&label("1", 0.0));
+        assert_eq!(segments.len(), 2);
+        assert_eq!(segments[0], ([10.5, 6.0], [10.5, 5.0]));
+        assert_eq!(segments[1], ([10.5, 5.0], [10.5, 4.0]));
+
+        // 文字数分の線分を持ち、全体の中心が position
+        let segments = label_segments(&label("8.8", 0.0));
+        assert_eq!(segments.len(), 7 + 1 + 7);
+        let (min_x, max_x) = segments
+            .iter()
+            .flat_map(|(a, b)| [a[0], b[0]])
+            .fold((f32::MAX, f32::MIN), |(lo, hi), x| (lo.min(x), hi.max(x)));
+        assert!(((min_x + max_x) * 0.5 - 10.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_label_segments_follow_angle() {
+        // 90度回転：ベースラインが +Y 方向、文字の上は -X 方向
+        let segments = label_segments(&label("-", std::f32::consts::FRAC_PI_2));
+        let ([x0, y0], [x1, y1]) = segments[0];
+        assert!((x0 - 10.0).abs() < 1e-5 && (x1 - 10.0).abs() < 1e-5);
+        assert!(((y1 - y0) - 1.0).abs() < 1e-5);
+
+        // 対応しない文字は線分を持たない
+        assert!(label_segments(&label("mm", 0.0)).is_empty());
+    }
+}
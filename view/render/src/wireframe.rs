@@ -1,7 +1,12 @@
-use crate::shader::wireframe_shader;
+use crate::mesh::{multiply_matrices, DEPTH_FORMAT};
+use crate::shader::{line_batch_shader, wireframe_overlay_shader};
+use crate::vertex_3d::MeshVertex;
+use viewmodel::mesh_converter::LineVertexData;
 use wgpu::util::DeviceExt;
 use wgpu::{Buffer, Device, RenderPipeline};
 
+pub use viewmodel::mesh_converter::{GpuLineBatch, LineColorMode};
+
 #[repr(C)]
 #[derive(Copy, Clone, bytemuck::Pod, bytemuck::Zeroable)]
 pub struct VertexWireframe {
@@ -22,85 +27,539 @@ impl VertexWireframe {
     }
 }
 
-pub struct WireframeResources {
+/// ワイヤーフレームオーバーレイの表示スタイル
+///
+/// 線の太さは wgpu の線描画では1ピクセル固定のため指定できない。
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct WireframeOverlayStyle {
+    /// エッジ色（RGBA）
+    pub color: [f32; 4],
+}
+
+impl Default for WireframeOverlayStyle {
+    fn default() -> Self {
+        Self {
+            color: [0.0, 0.0, 0.0, 1.0],
+        }
+    }
+}
+
+/// オーバーレイ用Uniform（シェーダーの OverlayUniforms と同じレイアウト）
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+struct WireframeOverlayUniforms {
+    color: [f32; 4],
+}
+
+impl From<&WireframeOverlayStyle> for WireframeOverlayUniforms {
+    fn from(style: &WireframeOverlayStyle) -> Self {
+        Self { color: style.color }
+    }
+}
+
+/// オーバーレイのエッジをソリッド面より手前に寄せる深度バイアス（ポリゴンオフセット相当）
+///
+/// 負の値でカメラ側へ寄せる。傾いた面ほど深度の変化が大きいため傾き項も使う。
+const OVERLAY_DEPTH_BIAS: wgpu::DepthBiasState = wgpu::DepthBiasState {
+    constant: -2,
+    slope_scale: -1.0,
+    clamp: 0.0,
+};
+
+/// オーバーレイの深度ステート
+///
+/// メッシュの深度バッファを読むだけで書き込まず、ソリッド面に隠れたエッジは描かない。
+fn overlay_depth_state() -> wgpu::DepthStencilState {
+    wgpu::DepthStencilState {
+        format: DEPTH_FORMAT,
+        depth_write_enabled: false,
+        depth_compare: wgpu::CompareFunction::LessEqual,
+        stencil: wgpu::StencilState::default(),
+        bias: OVERLAY_DEPTH_BIAS,
+    }
+}
+
+/// ソリッド描画の上にエッジを重ねるワイヤーフレームオーバーレイ
+///
+/// メッシュの頂点・インデックスバッファとカメラのバインドグループ（group 0）を共有し、
+/// シェーディングパスの後に同じレンダーパス内で描画する。深度バッファもメッシュと共有し、
+/// 深度バイアスで同じ面上のエッジだけをソリッドの手前に出す。
+pub struct WireframeOverlayResources {
     pub pipeline: wgpu::RenderPipeline,
-    pub vertex_buffer: wgpu::Buffer,
-    pub vertex_count: u32,
-}
-
-pub fn create_wireframe_resources(
-    device: &Device,
-    format: wgpu::TextureFormat,
-) -> WireframeResources {
-    let vertices: &[f32] = &[-0.5, 0.0, 0.0, 0.5, 0.0, 0.0, 0.0, -0.5, 0.0, 0.0, 0.5, 0.0];
-
-    let vertex_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
-        label: Some("Wireframe Vertex Buffer"),
-        contents: bytemuck::cast_slice(vertices),
-        usage: wgpu::BufferUsages::VERTEX,
-    });
-
-    let shader = wireframe_shader(device);
-
-    let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
-        label: Some("Wireframe Pipeline Layout"),
-        bind_group_layouts: &[],
-        push_constant_ranges: &[],
-    });
-
-    let pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
-        label: Some("Wireframe Pipeline"),
-        layout: Some(&pipeline_layout),
-        vertex: wgpu::VertexState {
-            module: &shader,
-            entry_point: Some("vs_main"),
-            buffers: &[wgpu::VertexBufferLayout {
-                array_stride: std::mem::size_of::<[f32; 3]>() as u64,
-                step_mode: wgpu::VertexStepMode::Vertex,
-                attributes: &[wgpu::VertexAttribute {
-                    format: wgpu::VertexFormat::Float32x3,
-                    offset: 0,
-                    shader_location: 0,
+    pub style_buffer: wgpu::Buffer,
+    pub style_bind_group: wgpu::BindGroup,
+    pub style: WireframeOverlayStyle,
+}
+
+impl WireframeOverlayResources {
+    /// オーバーレイ用パイプラインを作成
+    ///
+    /// `camera_bind_group_layout` はメッシュ描画と同じカメラUniformのレイアウト。
+    pub fn new(
+        device: &Device,
+        format: wgpu::TextureFormat,
+        camera_bind_group_layout: &wgpu::BindGroupLayout,
+    ) -> Self {
+        let shader = wireframe_overlay_shader(device);
+        let style = WireframeOverlayStyle::default();
+
+        let style_bind_group_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                entries: &[wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::VERTEX_FRAGMENT,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
                 }],
+                label: Some("wireframe_overlay_bind_group_layout"),
+            });
+
+        let style_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("Wireframe Overlay Uniform Buffer"),
+            contents: bytemuck::cast_slice(&[WireframeOverlayUniforms::from(&style)]),
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+        });
+
+        let style_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            layout: &style_bind_group_layout,
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: style_buffer.as_entire_binding(),
             }],
-            compilation_options: wgpu::PipelineCompilationOptions::default(),
-        },
-        fragment: Some(wgpu::FragmentState {
-            module: &shader,
-            entry_point: Some("fs_main"),
-            targets: &[Some(wgpu::ColorTargetState {
-                format,
-                blend: Some(wgpu::BlendState::REPLACE),
-                write_mask: wgpu::ColorWrites::ALL,
-            })],
-            compilation_options: wgpu::PipelineCompilationOptions::default(),
-        }),
-        primitive: wgpu::PrimitiveState {
-            topology: wgpu::PrimitiveTopology::LineList,
-            ..Default::default()
-        },
-        depth_stencil: None,
-        multisample: wgpu::MultisampleState::default(),
-        multiview: None,
-        cache: None,
-    });
-
-    let vertex_count = (vertices.len() / 3) as u32;
-
-    WireframeResources {
-        pipeline,
-        vertex_buffer,
-        vertex_count,
-    }
-}
-
-pub fn draw_wireframe<'a>(
+            label: Some("wireframe_overlay_bind_group"),
+        });
+
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Wireframe Overlay Pipeline Layout"),
+            bind_group_layouts: &[camera_bind_group_layout, &style_bind_group_layout],
+            push_constant_ranges: &[],
+        });
+
+        let pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Wireframe Overlay Pipeline"),
+            layout: Some(&pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: Some("vs_main"),
+                buffers: &[MeshVertex::desc()],
+                compilation_options: wgpu::PipelineCompilationOptions::default(),
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: Some("fs_main"),
+                targets: &[Some(wgpu::ColorTargetState {
+                    format,
+                    blend: Some(wgpu::BlendState::ALPHA_BLENDING),
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+                compilation_options: wgpu::PipelineCompilationOptions::default(),
+            }),
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleList,
+                strip_index_format: None,
+                front_face: wgpu::FrontFace::Ccw,
+                cull_mode: None,
+                polygon_mode: wgpu::PolygonMode::Line, // エッジのみ描画
+                unclipped_depth: false,
+                conservative: false,
+            },
+            depth_stencil: Some(overlay_depth_state()),
+            multisample: wgpu::MultisampleState::default(),
+            multiview: None,
+            cache: None,
+        });
+
+        Self {
+            pipeline,
+            style_buffer,
+            style_bind_group,
+            style,
+        }
+    }
+
+    /// エッジ色を更新
+    pub fn set_style(&mut self, queue: &wgpu::Queue, style: WireframeOverlayStyle) {
+        self.style = style;
+        queue.write_buffer(
+            &self.style_buffer,
+            0,
+            bytemuck::cast_slice(&[WireframeOverlayUniforms::from(&style)]),
+        );
+    }
+}
+
+/// メッシュのインデックスバッファを使ってワイヤーフレームオーバーレイを描画
+pub fn draw_wireframe_overlay<'a>(
     pass: &mut wgpu::RenderPass<'a>,
-    pipeline: &'a RenderPipeline,
+    overlay: &'a WireframeOverlayResources,
+    camera_bind_group: &'a wgpu::BindGroup,
     vertex_buffer: &'a Buffer,
-    vertex_count: u32,
+    index_buffer: &'a Buffer,
+    index_count: u32,
 ) {
-    pass.set_pipeline(pipeline);
+    pass.set_pipeline(&overlay.pipeline);
+    pass.set_bind_group(0, camera_bind_group, &[]);
+    pass.set_bind_group(1, &overlay.style_bind_group, &[]);
     pass.set_vertex_buffer(0, vertex_buffer.slice(..));
-    pass.draw(0..vertex_count, 0..1);
+    pass.set_index_buffer(index_buffer.slice(..), wgpu::IndexFormat::Uint32);
+    pass.draw_indexed(0..index_count, 0, 0..1);
+}
+
+/// 線分バッチの頂点型（位置 + RGBAカラー、LineList）
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct LineBatchVertex {
+    pub position: [f32; 3],
+    pub color: [f32; 4],
+}
+
+impl LineBatchVertex {
+    /// viewmodelのLineVertexDataから変換
+    pub fn from_line_vertex_data(data: &LineVertexData) -> Self {
+        Self {
+            position: data.position,
+            color: data.color,
+        }
+    }
+
+    pub fn desc<'a>() -> wgpu::VertexBufferLayout<'a> {
+        const ATTRIBUTES: &[wgpu::VertexAttribute] = &wgpu::vertex_attr_array![
+            0 => Float32x3, // position @location(0)
+            1 => Float32x4, // color @location(1)
+        ];
+
+        wgpu::VertexBufferLayout {
+            array_stride: std::mem::size_of::<LineBatchVertex>() as wgpu::BufferAddress,
+            step_mode: wgpu::VertexStepMode::Vertex,
+            attributes: ATTRIBUTES,
+        }
+    }
+}
+
+/// 線分バッチ用Uniform（シェーダーの LineUniforms と同じレイアウト）
+#[repr(C)]
+#[derive(Copy, Clone, Debug, bytemuck::Pod, bytemuck::Zeroable)]
+struct LineBatchUniforms {
+    view_proj: [[f32; 4]; 4],
+    color: [f32; 4],
+}
+
+/// 1回のドローコールで描く頂点バッファとそのUniform
+struct LineBatchDraw {
+    vertex_buffer: Buffer,
+    vertex_count: u32,
+    uniform_buffer: Buffer,
+    bind_group: wgpu::BindGroup,
+}
+
+/// ドローコールごとの頂点データ（頂点カラー版は頂点に色を持ち、色ごと版は位置のみ）
+enum LineBatchPayload {
+    VertexColor(Vec<LineBatchVertex>),
+    UniformColor([f32; 4], Vec<VertexWireframe>),
+}
+
+/// バッチ群を色の扱いに応じたドローコール単位に並べ替える
+fn line_batch_payloads(batches: &[GpuLineBatch], mode: LineColorMode) -> Vec<LineBatchPayload> {
+    let mut merged = GpuLineBatch::new();
+    for batch in batches {
+        merged.append(batch);
+    }
+    if merged.is_empty() {
+        return Vec::new();
+    }
+
+    match mode {
+        LineColorMode::PerVertex => vec![LineBatchPayload::VertexColor(
+            merged
+                .vertices
+                .iter()
+                .map(LineBatchVertex::from_line_vertex_data)
+                .collect(),
+        )],
+        LineColorMode::PerColor => merged
+            .split_by_color()
+            .into_iter()
+            .map(|(color, batch)| {
+                let positions = batch
+                    .vertices
+                    .iter()
+                    .map(|v| VertexWireframe {
+                        position: v.position,
+                    })
+                    .collect();
+                LineBatchPayload::UniformColor(color, positions)
+            })
+            .collect(),
+    }
+}
+
+/// 大量の線分（グリッド・ワイヤーフレーム・デバッグ線）をまとめて描画するリソース
+///
+/// - `LineColorMode::PerVertex`: 全バッチを1つの頂点バッファにまとめ、ドローコール1回
+/// - `LineColorMode::PerColor`: 色ごとに位置のみの頂点バッファを作り、色数分のドローコール
+///   （頂点サイズが小さく、色の種類が少ない場合に有利）
+pub struct LineBatchResources {
+    vertex_color_pipeline: RenderPipeline,
+    uniform_color_pipeline: RenderPipeline,
+    bind_group_layout: wgpu::BindGroupLayout,
+    color_mode: LineColorMode,
+    /// 現在の `draws` を作成したときの色の扱い
+    draws_mode: LineColorMode,
+    draws: Vec<LineBatchDraw>,
+    view_proj: [[f32; 4]; 4],
+}
+
+impl LineBatchResources {
+    pub fn new(device: &Device, format: wgpu::TextureFormat, color_mode: LineColorMode) -> Self {
+        let shader = line_batch_shader(device);
+
+        let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            entries: &[wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::VERTEX,
+                ty: wgpu::BindingType::Buffer {
+                    ty: wgpu::BufferBindingType::Uniform,
+                    has_dynamic_offset: false,
+                    min_binding_size: None,
+                },
+                count: None,
+            }],
+            label: Some("line_batch_bind_group_layout"),
+        });
+
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Line Batch Pipeline Layout"),
+            bind_group_layouts: &[&bind_group_layout],
+            push_constant_ranges: &[],
+        });
+
+        let create_pipeline = |label, entry_point, buffer| {
+            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                label: Some(label),
+                layout: Some(&pipeline_layout),
+                vertex: wgpu::VertexState {
+                    module: &shader,
+                    entry_point: Some(entry_point),
+                    buffers: &[buffer],
+                    compilation_options: wgpu::PipelineCompilationOptions::default(),
+                },
+                fragment: Some(wgpu::FragmentState {
+                    module: &shader,
+                    entry_point: Some("fs_main"),
+                    targets: &[Some(wgpu::ColorTargetState {
+                        format,
+                        blend: Some(wgpu::BlendState::ALPHA_BLENDING),
+                        write_mask: wgpu::ColorWrites::ALL,
+                    })],
+                    compilation_options: wgpu::PipelineCompilationOptions::default(),
+                }),
+                primitive: wgpu::PrimitiveState {
+                    topology: wgpu::PrimitiveTopology::LineList,
+                    ..Default::default()
+                },
+                depth_stencil: None,
+                multisample: wgpu::MultisampleState::default(),
+                multiview: None,
+                cache: None,
+            })
+        };
+
+        let vertex_color_pipeline = create_pipeline(
+            "Line Batch Vertex Color Pipeline",
+            "vs_vertex_color",
+            LineBatchVertex::desc(),
+        );
+        let uniform_color_pipeline = create_pipeline(
+            "Line Batch Uniform Color Pipeline",
+            "vs_uniform_color",
+            VertexWireframe::desc(),
+        );
+
+        Self {
+            vertex_color_pipeline,
+            uniform_color_pipeline,
+            bind_group_layout,
+            color_mode,
+            draws_mode: color_mode,
+            draws: Vec::new(),
+            view_proj: [
+                [1.0, 0.0, 0.0, 0.0],
+                [0.0, 1.0, 0.0, 0.0],
+                [0.0, 0.0, 1.0, 0.0],
+                [0.0, 0.0, 0.0, 1.0],
+            ],
+        }
+    }
+
+    /// 色の扱い
+    pub fn color_mode(&self) -> LineColorMode {
+        self.color_mode
+    }
+
+    /// 色の扱いを変更（次の `update_batches` から反映）
+    pub fn set_color_mode(&mut self, color_mode: LineColorMode) {
+        self.color_mode = color_mode;
+    }
+
+    /// 描画する線分バッチを置き換える
+    pub fn update_batches(&mut self, device: &Device, batches: &[GpuLineBatch]) {
+        self.draws_mode = self.color_mode;
+        self.draws = line_batch_payloads(batches, self.color_mode)
+            .into_iter()
+            .map(|payload| {
+                let (contents, vertex_count, color): (&[u8], usize, [f32; 4]) = match &payload {
+                    LineBatchPayload::VertexColor(vertices) => {
+                        (bytemuck::cast_slice(vertices), vertices.len(), [1.0; 4])
+                    }
+                    LineBatchPayload::UniformColor(color, positions) => {
+                        (bytemuck::cast_slice(positions), positions.len(), *color)
+                    }
+                };
+                let vertex_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                    label: Some("Line Batch Vertex Buffer"),
+                    contents,
+                    usage: wgpu::BufferUsages::VERTEX,
+                });
+                let uniform_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                    label: Some("Line Batch Uniform Buffer"),
+                    contents: bytemuck::cast_slice(&[LineBatchUniforms {
+                        view_proj: self.view_proj,
+                        color,
+                    }]),
+                    usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+                });
+                let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+                    layout: &self.bind_group_layout,
+                    entries: &[wgpu::BindGroupEntry {
+                        binding: 0,
+                        resource: uniform_buffer.as_entire_binding(),
+                    }],
+                    label: Some("line_batch_bind_group"),
+                });
+                LineBatchDraw {
+                    vertex_buffer,
+                    vertex_count: vertex_count as u32,
+                    uniform_buffer,
+                    bind_group,
+                }
+            })
+            .collect();
+    }
+
+    /// カメラ行列を更新
+    pub fn update_camera(
+        &mut self,
+        queue: &wgpu::Queue,
+        view_matrix: [[f32; 4]; 4],
+        proj_matrix: [[f32; 4]; 4],
+    ) {
+        self.view_proj = multiply_matrices(proj_matrix, view_matrix);
+        for draw in &self.draws {
+            // 色は変えずに view_proj（先頭）だけ書き換える
+            queue.write_buffer(
+                &draw.uniform_buffer,
+                0,
+                bytemuck::cast_slice(&[self.view_proj]),
+            );
+        }
+    }
+
+    /// ドローコール数（頂点カラーなら最大1、色ごとなら色数）
+    pub fn draw_call_count(&self) -> usize {
+        self.draws.len()
+    }
+
+    /// 全バッチを描画
+    pub fn render<'a>(&'a self, pass: &mut wgpu::RenderPass<'a>) {
+        let pipeline = match self.draws_mode {
+            LineColorMode::PerVertex => &self.vertex_color_pipeline,
+            LineColorMode::PerColor => &self.uniform_color_pipeline,
+        };
+        pass.set_pipeline(pipeline);
+        for draw in &self.draws {
+            pass.set_bind_group(0, &draw.bind_group, &[]);
+            pass.set_vertex_buffer(0, draw.vertex_buffer.slice(..));
+            pass.draw(0..draw.vertex_count, 0..1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_overlay_uniforms_match_shader_layout() {
+        // WGSL の OverlayUniforms は vec4 = 16 バイト
+        assert_eq!(std::mem::size_of::<WireframeOverlayUniforms>(), 16);
+
+        let style = WireframeOverlayStyle {
+            color: [1.0, 0.0, 0.0, 1.0],
+        };
+        let uniforms = WireframeOverlayUniforms::from(&style);
+        assert_eq!(uniforms.color, [1.0, 0.0, 0.0, 1.0]);
+    }
+
+    #[test]
+    fn test_overlay_depth_state() {
+        // メッシュの深度バッファで隠れたエッジを捨て、深度は書き込まない
+        let state = overlay_depth_state();
+        assert_eq!(state.format, DEPTH_FORMAT);
+        assert!(!state.depth_write_enabled);
+        assert_eq!(state.depth_compare, wgpu::CompareFunction::LessEqual);
+        // カメラ側へ寄せるバイアス
+        assert!(state.bias.is_enabled());
+        assert!(state.bias.constant < 0 && state.bias.slope_scale < 0.0);
+    }
+
+    #[test]
+    fn test_line_batch_uniforms_match_shader_layout() {
+        // WGSL の LineUniforms は mat4x4 + vec4 = 80 バイト
+        assert_eq!(std::mem::size_of::<LineBatchUniforms>(), 80);
+        assert_eq!(std::mem::size_of::<LineBatchVertex>(), 28);
+    }
+
+    #[test]
+    fn test_line_batch_payloads_by_color_mode() {
+        let red = [1.0, 0.0, 0.0, 1.0];
+        let blue = [0.0, 0.0, 1.0, 1.0];
+        let segment = |x: f32, color| GpuLineBatch {
+            vertices: vec![
+                LineVertexData {
+                    position: [x, 0.0, 0.0],
+                    color,
+                },
+                LineVertexData {
+                    position: [x, 1.0, 0.0],
+                    color,
+                },
+            ],
+        };
+        let batches = [segment(0.0, red), segment(1.0, blue), segment(2.0, red)];
+
+        // 頂点カラー：全線分が1回のドローコールにまとまる
+        let payloads = line_batch_payloads(&batches, LineColorMode::PerVertex);
+        assert_eq!(payloads.len(), 1);
+        let LineBatchPayload::VertexColor(vertices) = &payloads[0] else {
+            panic!("expected vertex color payload");
+        };
+        assert_eq!(vertices.len(), 6);
+        assert_eq!(vertices[2].color, blue);
+
+        // 色ごと：赤2本と青1本の2回のドローコール
+        let payloads = line_batch_payloads(&batches, LineColorMode::PerColor);
+        assert_eq!(payloads.len(), 2);
+        let LineBatchPayload::UniformColor(color, positions) = &payloads[0] else {
+            panic!("expected uniform color payload");
+        };
+        assert_eq!(*color, red);
+        assert_eq!(positions.len(), 4);
+        assert_eq!(positions[2].position, [2.0, 0.0, 0.0]);
+
+        assert!(line_batch_payloads(&[], LineColorMode::PerColor).is_empty());
+    }
 }
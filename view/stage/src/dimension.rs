@@ -0,0 +1,228 @@
+//! 2点間の寸法線（リニア寸法）の作図
+//!
+//! 寸法線・補助線・矢印を線分として生成し、寸法値（2点間距離）の文字列と
+//! その表示位置を求める。作図は XY 平面で行い、長さは3D距離で測る。
+
+use geo_primitives::{Point3D, Vector3D};
+
+/// 矢印の開き（矢印の長さに対する片側の幅の比）
+const ARROW_HALF_WIDTH_RATIO: f64 = 1.0 / 3.0;
+
+/// 寸法線の表示設定（長さはすべてワールド単位）
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct DimensionStyle {
+    /// 矢印の長さ
+    pub arrow_size: f64,
+    /// 測定点と補助線の始まりとの隙間
+    pub extension_gap: f64,
+    /// 補助線を寸法線より先へ延ばす長さ
+    pub extension_overshoot: f64,
+    /// 寸法値の文字の高さ
+    pub text_height: f64,
+    /// 寸法値の小数点以下の桁数
+    pub precision: usize,
+    /// 線と文字の色（RGBA）
+    pub color: [f32; 4],
+}
+
+impl Default for DimensionStyle {
+    fn default() -> Self {
+        Self {
+            arrow_size: 0.2,
+            extension_gap: 0.05,
+            extension_overshoot: 0.1,
+            text_height: 0.2,
+            precision: 2,
+            color: [1.0, 0.85, 0.2, 1.0],
+        }
+    }
+}
+
+/// 2点間のリニア寸法
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct LinearDimension {
+    pub start: Point3D<f64>,
+    pub end: Point3D<f64>,
+    /// 測定点から寸法線までの距離（負なら反対側）
+    pub offset: f64,
+    /// 寸法線をずらす向き（`None` なら XY 平面で start→end の左側）
+    ///
+    /// 測定方向の成分は取り除いて使う。
+    pub offset_direction: Option<Vector3D<f64>>,
+}
+
+/// 寸法の描画要素
+#[derive(Clone, Debug, PartialEq)]
+pub struct DimensionGeometry {
+    /// 寸法線・補助線・矢印の線分
+    pub segments: Vec<(Point3D<f64>, Point3D<f64>)>,
+    /// 寸法値の文字列
+    pub text: String,
+    /// 寸法値の中心位置（寸法線の中点から外側へ文字高さの分だけ離れた位置）
+    pub text_position: Point3D<f64>,
+    /// 寸法値のベースラインの角度（ラジアン、文字が逆さにならないよう -90°〜90°）
+    pub text_angle: f64,
+}
+
+impl LinearDimension {
+    /// 既定の向き（XY 平面で start→end の左側）にずらした寸法
+    pub fn new(start: Point3D<f64>, end: Point3D<f64>, offset: f64) -> Self {
+        Self {
+            start,
+            end,
+            offset,
+            offset_direction: None,
+        }
+    }
+
+    /// 寸法線をずらす向きを指定
+    pub fn with_offset_direction(mut self, direction: Vector3D<f64>) -> Self {
+        self.offset_direction = Some(direction);
+        self
+    }
+
+    /// 測定値（2点間距離）
+    pub fn measured_length(&self) -> f64 {
+        self.start.distance_to(&self.end)
+    }
+
+    /// 寸法値の文字列
+    pub fn text(&self, style: &DimensionStyle) -> String {
+        format!("{:.*}", style.precision, self.measured_length())
+    }
+
+    /// 寸法線の単位法線（寸法線をずらす向き、測定方向に垂直）
+    fn normal(&self, along: &Vector3D<f64>) -> Vector3D<f64> {
+        let perpendicular = |v: Vector3D<f64>| {
+            let v = v - *along * v.dot(along);
+            (v.length() > f64::EPSILON).then(|| v.normalize())
+        };
+        self.offset_direction
+            .and_then(perpendicular)
+            .or_else(|| perpendicular(Vector3D::new(-along.y(), along.x(), 0.0)))
+            // 測定方向が Z 軸に平行な場合
+            .unwrap_or_else(|| Vector3D::new(1.0, 0.0, 0.0))
+    }
+
+    /// 寸法線・補助線・矢印と寸法値の配置を求める（2点が一致する場合は `None`）
+    pub fn geometry(&self, style: &DimensionStyle) -> Option<DimensionGeometry> {
+        let length = self.measured_length();
+        if length <= f64::EPSILON {
+            return None;
+        }
+        let along = Vector3D::from_points(&self.start, &self.end) / length;
+        // 測定点から寸法線へ向かう向き
+        let outward = self.normal(&along) * if self.offset < 0.0 { -1.0 } else { 1.0 };
+        let distance = self.offset.abs();
+
+        let dim_start = self.start + outward * distance;
+        let dim_end = self.end + outward * distance;
+        let mut segments = vec![(dim_start, dim_end)];
+
+        // 補助線（測定点が寸法線に近すぎる場合は引かない）
+        if distance > style.extension_gap {
+            let overshoot = outward * style.extension_overshoot;
+            for (point, dim_point) in [(self.start, dim_start), (self.end, dim_end)] {
+                segments.push((point + outward * style.extension_gap, dim_point + overshoot));
+            }
+        }
+
+        // 寸法線の両端に内側から外向きの開いた矢印
+        let half_width = outward * (style.arrow_size * ARROW_HALF_WIDTH_RATIO);
+        for (tip, inward) in [(dim_start, along), (dim_end, -along)] {
+            let back = tip + inward * style.arrow_size;
+            segments.push((tip, back + half_width));
+            segments.push((tip, back - half_width));
+        }
+
+        let mut text_angle = along.y().atan2(along.x());
+        if text_angle > std::f64::consts::FRAC_PI_2 {
+            text_angle -= std::f64::consts::PI;
+        } else if text_angle <= -std::f64::consts::FRAC_PI_2 {
+            text_angle += std::f64::consts::PI;
+        }
+
+        Some(DimensionGeometry {
+            segments,
+            text: self.text(style),
+            text_position: dim_start.midpoint(&dim_end) + outward * style.text_height,
+            text_angle,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_point(actual: Point3D<f64>, expected: [f64; 3]) {
+        assert!(
+            actual.distance_to(&Point3D::new(expected[0], expected[1], expected[2])) < 1e-12,
+            "{actual:?} != {expected:?}"
+        );
+    }
+
+    #[test]
+    fn test_horizontal_dimension_layout() {
+        let style = DimensionStyle::default();
+        let dimension = LinearDimension::new(
+            Point3D::new(0.0, 0.0, 0.0),
+            Point3D::new(3.0, 0.0, 0.0),
+            1.0,
+        );
+        let geometry = dimension.geometry(&style).unwrap();
+
+        assert_eq!(geometry.text, "3.00");
+        // 既定の向きは左側（+Y）
+        let (dim_start, dim_end) = geometry.segments[0];
+        assert_point(dim_start, [0.0, 1.0, 0.0]);
+        assert_point(dim_end, [3.0, 1.0, 0.0]);
+
+        // 補助線は測定点から隙間を空けて寸法線の先まで
+        let (ext_from, ext_to) = geometry.segments[1];
+        assert_point(ext_from, [0.0, style.extension_gap, 0.0]);
+        assert_point(ext_to, [0.0, 1.0 + style.extension_overshoot, 0.0]);
+
+        // 寸法線1本 + 補助線2本 + 矢印2本×2
+        assert_eq!(geometry.segments.len(), 7);
+        let (tip, wing) = geometry.segments[3];
+        assert_point(tip, [0.0, 1.0, 0.0]);
+        assert!((wing.x() - style.arrow_size).abs() < 1e-12);
+
+        assert_point(geometry.text_position, [1.5, 1.0 + style.text_height, 0.0]);
+        assert!(geometry.text_angle.abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_negative_offset_and_custom_direction() {
+        let style = DimensionStyle {
+            precision: 1,
+            ..DimensionStyle::default()
+        };
+        let a = Point3D::new(0.0, 0.0, 0.0);
+        let b = Point3D::new(0.0, 2.0, 0.0);
+
+        // 負のオフセットは右側（+Y 方向の測定では +X）
+        let geometry = LinearDimension::new(a, b, -0.5).geometry(&style).unwrap();
+        assert_point(geometry.segments[0].0, [0.5, 0.0, 0.0]);
+        assert_eq!(geometry.text, "2.0");
+        // 真上向きの寸法は範囲（-90°〜90°）の上端の +90°
+        assert!((geometry.text_angle - std::f64::consts::FRAC_PI_2).abs() < 1e-12);
+
+        // 斜めの向きを指定しても測定方向の成分は取り除かれる
+        let geometry = LinearDimension::new(a, b, 1.0)
+            .with_offset_direction(Vector3D::new(0.0, 1.0, 1.0))
+            .geometry(&style)
+            .unwrap();
+        assert_point(geometry.segments[0].0, [0.0, 0.0, 1.0]);
+        assert_point(geometry.segments[0].1, [0.0, 2.0, 1.0]);
+    }
+
+    #[test]
+    fn test_degenerate_dimension() {
+        let p = Point3D::new(1.0, 1.0, 0.0);
+        assert!(LinearDimension::new(p, p, 1.0)
+            .geometry(&DimensionStyle::default())
+            .is_none());
+    }
+}
@@ -7,24 +7,147 @@ use wgpu::{
     TextureView,
 };
 
+use crate::dimension::{DimensionStyle, LinearDimension};
 use crate::render_stage::RenderStage;
 
-use render::render_2d::{draw_render_2d, Render2dResources};
+use geo_primitives::{Point3D, Vector3D};
+use render::render_2d::{
+    create_grid_2d_resources, draw_render_2d, Grid2dResources, GridStyle, GridView,
+    Render2dResources,
+};
+use render::text::{StrokeTextRenderer, TextLabel, TextRenderer};
 use render::vertex_2d::Vertex2D;
+use render::wireframe::{GpuLineBatch, LineBatchResources, LineColorMode};
 
 pub struct DraftStage {
     resources: Render2dResources,
+    grid: Grid2dResources,
     frame_count: u64,
+    dimensions: Vec<LinearDimension>,
+    dimension_style: DimensionStyle,
+    dimension_lines: LineBatchResources,
+    dimension_labels: Vec<TextLabel>,
+    text_renderer: Box<dyn TextRenderer>,
 }
 
 impl DraftStage {
     pub fn new(device: &wgpu::Device, format: wgpu::TextureFormat) -> Self {
         let resources =
             render::render_2d::create_render_2d_resources(&Arc::new(device.clone()), format);
+        let grid = create_grid_2d_resources(device, format);
+        let dimension_lines = LineBatchResources::new(device, format, LineColorMode::PerVertex);
+        let text_renderer = Box::new(StrokeTextRenderer::new(device, format));
         Self {
             resources,
+            grid,
             frame_count: 0,
+            dimensions: Vec::new(),
+            dimension_style: DimensionStyle::default(),
+            dimension_lines,
+            dimension_labels: Vec::new(),
+            text_renderer,
+        }
+    }
+
+    /// 背景グリッドを生成（基準間隔はズームに応じて10倍単位で切り替わる）
+    pub fn draw_grid(&mut self, spacing: f32, color: wgpu::Color) {
+        let device = self.resources.device.clone();
+        self.grid.draw_grid(&device, spacing, color);
+    }
+
+    /// グリッドのビュー（パン・ズーム）を設定
+    pub fn set_grid_view(&mut self, view: GridView) {
+        self.grid.set_view(view);
+        // グリッドと寸法線の頂点はビューに合わせてNDCで生成しているため作り直す
+        let device = self.resources.device.clone();
+        self.grid.rebuild(&device);
+        self.rebuild_dimensions();
+    }
+
+    /// グリッドの線の太さ・原点軸表示を設定
+    pub fn set_grid_style(&mut self, style: GridStyle) {
+        self.grid.set_style(style);
+        let device = self.resources.device.clone();
+        self.grid.rebuild(&device);
+    }
+
+    /// 2点間の寸法線を追加（寸法線は XY 平面で a→b の左側へ `offset` だけずらす）
+    ///
+    /// 寸法値は2点間距離。文字列はテキストレンダラ（既定は [`StrokeTextRenderer`]、
+    /// [`DraftStage::set_text_renderer`] で差し替え可能）で描画する。
+    pub fn add_linear_dimension(&mut self, a: Point3D<f64>, b: Point3D<f64>, offset: f64) {
+        self.dimensions.push(LinearDimension::new(a, b, offset));
+        self.rebuild_dimensions();
+    }
+
+    /// 寸法線をずらす向きを指定して2点間の寸法線を追加
+    pub fn add_linear_dimension_with_direction(
+        &mut self,
+        a: Point3D<f64>,
+        b: Point3D<f64>,
+        offset: f64,
+        direction: Vector3D<f64>,
+    ) {
+        self.dimensions
+            .push(LinearDimension::new(a, b, offset).with_offset_direction(direction));
+        self.rebuild_dimensions();
+    }
+
+    /// 全寸法線を削除
+    pub fn clear_dimensions(&mut self) {
+        self.dimensions.clear();
+        self.rebuild_dimensions();
+    }
+
+    /// 寸法線の矢印サイズ・補助線・文字高さ・色を設定（既存の寸法線にも反映）
+    pub fn set_dimension_style(&mut self, style: DimensionStyle) {
+        self.dimension_style = style;
+        self.rebuild_dimensions();
+    }
+
+    /// 寸法値の描画を委譲するテキストレンダラを設定（既定は [`StrokeTextRenderer`]）
+    pub fn set_text_renderer(&mut self, mut renderer: Box<dyn TextRenderer>) {
+        let device = self.resources.device.clone();
+        renderer.prepare(&device, &self.dimension_labels, &self.grid.view);
+        self.text_renderer = renderer;
+    }
+
+    /// 描画する寸法値のラベル
+    pub fn dimension_labels(&self) -> &[TextLabel] {
+        &self.dimension_labels
+    }
+
+    /// 寸法線の頂点バッファと寸法値のラベルを作り直す
+    fn rebuild_dimensions(&mut self) {
+        let view = self.grid.view;
+        let style = self.dimension_style;
+        let to_ndc = |p: &Point3D<f64>| {
+            let [x, y] = view.world_to_ndc([p.x() as f32, p.y() as f32]);
+            Point3D::new(x as f64, y as f64, 0.0)
+        };
+
+        let mut batch = GpuLineBatch::new();
+        self.dimension_labels.clear();
+        for geometry in self.dimensions.iter().filter_map(|d| d.geometry(&style)) {
+            for (start, end) in &geometry.segments {
+                batch.push_segment(&to_ndc(start), &to_ndc(end), style.color);
+            }
+            self.dimension_labels.push(TextLabel {
+                text: geometry.text,
+                position: [
+                    geometry.text_position.x() as f32,
+                    geometry.text_position.y() as f32,
+                ],
+                angle: geometry.text_angle as f32,
+                height: style.text_height as f32,
+                color: style.color,
+            });
         }
+
+        let device = self.resources.device.clone();
+        self.dimension_lines.update_batches(&device, &[batch]);
+        self.text_renderer
+            .prepare(&device, &self.dimension_labels, &view);
     }
 }
 
@@ -55,12 +178,19 @@ impl RenderStage for DraftStage {
 
         let mut render_pass = encoder.begin_render_pass(&render_pass_desc);
 
+        // 背景グリッドを先に描画
+        self.grid.render(&mut render_pass);
+
         draw_render_2d(
             &mut render_pass,
             &self.resources.pipeline,
             &self.resources.vertex_buffer,
             self.resources.vertex_count,
         );
+
+        // 寸法線と寸法値は作図要素の上に重ねる
+        self.dimension_lines.render(&mut render_pass);
+        self.text_renderer.render(&mut render_pass);
     }
 
     fn update(&mut self) {
@@ -1,9 +1,11 @@
+pub mod dimension;
 pub mod draft;
 pub mod mesh_stage;
 pub mod outline;
 pub mod render_stage;
 pub mod shading;
 
+pub use dimension::{DimensionStyle, LinearDimension};
 pub use draft::DraftStage;
 pub use mesh_stage::MeshStage;
 pub use outline::OutlineStage;
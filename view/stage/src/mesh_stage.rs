@@ -2,7 +2,13 @@
 //!
 //! STLファイルから読み込んだ3Dメッシュをレンダリングするステージです。
 
-use render::{mesh::MeshResources, vertex_3d::MeshVertex};
+use geo_primitives::{Plane3D, TriangleMesh3D};
+use render::{
+    mesh::{ClipSettings, CullMode, MeshResources, ShadingMode},
+    vertex_3d::{convert_vertex_data_to_mesh_vertices, MeshVertex},
+    wireframe::WireframeOverlayStyle,
+};
+use viewmodel::mesh_converter::triangle_mesh_to_vertices_for_shading;
 use wgpu::{CommandEncoder, Device, TextureFormat, TextureView};
 
 use crate::RenderStage;
@@ -10,6 +16,10 @@ use crate::RenderStage;
 /// メッシュレンダリングステージ
 pub struct MeshStage {
     resources: MeshResources,
+    /// 描画先の大きさに合わせて深度バッファを作り直すためのデバイス
+    device: Device,
+    /// シェーディングモード切替時に頂点データを作り直すための元メッシュ
+    source_mesh: Option<TriangleMesh3D<f64>>,
 }
 
 impl MeshStage {
@@ -17,10 +27,26 @@ impl MeshStage {
     pub fn new(device: &Device, format: TextureFormat) -> Self {
         let resources = MeshResources::new(device, format);
 
-        Self { resources }
+        Self {
+            resources,
+            device: device.clone(),
+            source_mesh: None,
+        }
+    }
+
+    /// メッシュを設定し、現在のシェーディングモードの頂点データで描画する
+    ///
+    /// メッシュを保持するので、以後のシェーディングモード切替で法線が作り直される。
+    pub fn set_mesh(&mut self, device: &Device, mesh: TriangleMesh3D<f64>) {
+        let (vertices, indices) = shading_vertices(&mesh, self.resources.shading_mode());
+        self.set_mesh_data(device, vertices, indices);
+        self.source_mesh = Some(mesh);
     }
 
     /// メッシュデータを設定
+    ///
+    /// 頂点データを直接渡した場合は元メッシュを持たないため、シェーディングモードを
+    /// 切り替えても頂点法線は変わらない（メッシュからは `set_mesh` を使う）。
     pub fn set_mesh_data(&mut self, device: &Device, vertices: Vec<MeshVertex>, indices: Vec<u32>) {
         tracing::info!(
             "メッシュデータ設定: {} 頂点, {} インデックス",
@@ -30,6 +56,7 @@ impl MeshStage {
 
         // リソースにメッシュデータを更新
         self.resources.update_mesh_data(device, &vertices, &indices);
+        self.source_mesh = None;
     }
 
     /// カメラ行列を更新
@@ -52,10 +79,76 @@ impl MeshStage {
     pub fn is_wireframe(&self) -> bool {
         self.resources.is_wireframe()
     }
+
+    /// シェーディングモード（フラット/スムース）を切り替え
+    ///
+    /// 元メッシュがあれば、モードに合った法線（面法線の複製／平均頂点法線）で
+    /// 頂点バッファを作り直す。
+    pub fn set_shading_mode(&mut self, device: &Device, queue: &wgpu::Queue, mode: ShadingMode) {
+        if mode != self.resources.shading_mode() {
+            if let Some(mesh) = &self.source_mesh {
+                let (vertices, indices) = shading_vertices(mesh, mode);
+                self.resources.update_mesh_data(device, &vertices, &indices);
+            }
+        }
+        self.resources.set_shading_mode(queue, mode);
+    }
+
+    /// 現在のシェーディングモード
+    pub fn shading_mode(&self) -> ShadingMode {
+        self.resources.shading_mode()
+    }
+
+    /// 断面表示のクリップ平面を設定（`None` で解除）
+    ///
+    /// 平面の法線側を表示し、裏側のフラグメントを描画時に破棄する。メッシュは変更しない。
+    pub fn set_clip_plane(&mut self, queue: &wgpu::Queue, plane: Option<Plane3D<f64>>) {
+        self.resources
+            .set_clip_plane(queue, plane.as_ref().map(plane_equation));
+    }
+
+    /// 切断面を単色で埋める色を設定（`None` でキャッピングしない）
+    pub fn set_clip_capping(&mut self, queue: &wgpu::Queue, cap_color: Option<[f32; 4]>) {
+        self.resources.set_clip_capping(queue, cap_color);
+    }
+
+    /// 現在の断面表示の設定
+    pub fn clip_settings(&self) -> ClipSettings {
+        self.resources.clip_settings()
+    }
+
+    /// 面カリング（両面描画・裏面カリングと表の巻き順）を切り替え
+    pub fn set_cull_mode(&mut self, device: &Device, mode: CullMode) {
+        self.resources.set_cull_mode(device, mode);
+    }
+
+    /// 現在の面カリングの設定
+    pub fn cull_mode(&self) -> CullMode {
+        self.resources.cull_mode()
+    }
+
+    /// ワイヤーフレームオーバーレイが有効かどうか
+    pub fn is_wireframe_overlay(&self) -> bool {
+        self.resources.is_wireframe_overlay()
+    }
+
+    /// ワイヤーフレームオーバーレイのエッジ色を設定
+    pub fn set_wireframe_overlay_style(
+        &mut self,
+        queue: &wgpu::Queue,
+        style: WireframeOverlayStyle,
+    ) {
+        self.resources.set_wireframe_overlay_style(queue, style);
+    }
 }
 
 impl RenderStage for MeshStage {
     fn render(&mut self, encoder: &mut CommandEncoder, view: &TextureView) {
+        let target_size = view.texture().size();
+        let depth_view = self
+            .resources
+            .prepare_depth(&self.device, (target_size.width, target_size.height));
+
         let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
             label: Some("Mesh Render Pass"),
             color_attachments: &[Some(wgpu::RenderPassColorAttachment {
@@ -72,7 +165,14 @@ impl RenderStage for MeshStage {
                     store: wgpu::StoreOp::Store,
                 },
             })],
-            depth_stencil_attachment: None,
+            depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
+                view: depth_view,
+                depth_ops: Some(wgpu::Operations {
+                    load: wgpu::LoadOp::Clear(1.0),
+                    store: wgpu::StoreOp::Discard,
+                }),
+                stencil_ops: None,
+            }),
             timestamp_writes: None,
             occlusion_query_set: None,
         });
@@ -81,6 +181,10 @@ impl RenderStage for MeshStage {
         self.resources.render(&mut render_pass);
     }
 
+    fn set_wireframe_overlay(&mut self, enabled: bool) {
+        self.resources.set_wireframe_overlay(enabled);
+    }
+
     fn update(&mut self) {
         // 必要に応じてアニメーション更新等を実装
     }
@@ -89,3 +193,58 @@ impl RenderStage for MeshStage {
         self
     }
 }
+
+/// シェーディングモードに応じた描画用の頂点データとインデックス
+fn shading_vertices(mesh: &TriangleMesh3D<f64>, mode: ShadingMode) -> (Vec<MeshVertex>, Vec<u32>) {
+    let (vertices, indices) = triangle_mesh_to_vertices_for_shading(mesh, mode);
+    (convert_vertex_data_to_mesh_vertices(&vertices), indices)
+}
+
+/// 平面をシェーダー用の方程式係数 (a, b, c, d)（ax + by + cz + d = 0）に変換
+fn plane_equation(plane: &Plane3D<f64>) -> [f32; 4] {
+    let (a, b, c, d) = plane.equation_coefficients();
+    [a as f32, b as f32, c as f32, d as f32]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use geo_primitives::triangle_mesh_3d_fixtures::tetrahedron;
+    use geo_primitives::{Point3D, Vector3D};
+
+    #[test]
+    fn test_shading_vertices_differ_between_modes() {
+        let mesh = tetrahedron();
+        let (flat, flat_indices) = shading_vertices(&mesh, ShadingMode::Flat);
+        let (smooth, smooth_indices) = shading_vertices(&mesh, ShadingMode::Smooth);
+
+        // フラットは三角形ごとに頂点を複製、スムースは共有頂点のまま
+        assert_eq!(flat.len(), 12);
+        assert_eq!(smooth.len(), 4);
+        assert_eq!(flat_indices.len(), smooth_indices.len());
+
+        // 同じ位置の頂点でも、フラットは面法線・スムースは平均法線で異なる
+        let corner = flat_indices[0] as usize;
+        let shared = smooth_indices[0] as usize;
+        assert_eq!(flat[corner].position, smooth[shared].position);
+        assert_ne!(flat[corner].normal, smooth[shared].normal);
+
+        // フラットでは同じ三角形の3頂点が同じ法線を持つ
+        let face: Vec<[f32; 3]> = flat_indices[..3]
+            .iter()
+            .map(|&i| flat[i as usize].normal)
+            .collect();
+        assert!(face.iter().all(|normal| *normal == face[0]));
+    }
+
+    #[test]
+    fn test_plane_equation() {
+        let plane = Plane3D::from_point_and_normal(
+            Point3D::new(0.0, 0.0, 2.0),
+            Vector3D::new(0.0, 0.0, 3.0),
+        )
+        .unwrap();
+        // 法線側 (z > 2) が正
+        assert_eq!(plane_equation(&plane), [0.0, 0.0, 1.0, -2.0]);
+    }
+}
@@ -1,20 +1,98 @@
+use std::sync::Arc;
+
+use geo_algorithms::{EdgeSegment, FeatureEdgeOptions, MeshEdges};
+use geo_primitives::Vector3D;
 use wgpu::{
     CommandEncoder, LoadOp, Operations, RenderPassColorAttachment, RenderPassDescriptor, StoreOp,
     TextureView,
 };
 
 use crate::render_stage::RenderStage;
-use render::wireframe::{draw_wireframe, WireframeResources};
+use render::wireframe::{GpuLineBatch, LineBatchResources, LineColorMode};
+
+/// 輪郭線の色
+const OUTLINE_COLOR: [f32; 4] = [1.0, 1.0, 1.0, 1.0];
 
+/// 線画（技術図面風）表示ステージ
+///
+/// メッシュのシルエットエッジと、必要に応じてフィーチャーエッジを線で描く。
 pub struct OutlineStage {
-    resources: WireframeResources,
+    lines: LineBatchResources,
+    /// フィーチャーエッジの抽出設定（`None` ならシルエットのみ）
+    feature_edges: Option<FeatureEdgeOptions>,
+    /// 表示中のメッシュの辺の隣接情報（メッシュごとに一度だけ計算）
+    mesh_edges: Option<Arc<MeshEdges>>,
+    /// 直前に絞り込んだ視線方向と輪郭線（視線方向が変わらなければ投影だけをやり直す）
+    selected: Option<(Vector3D<f64>, Vec<EdgeSegment>)>,
 }
 
 impl OutlineStage {
     pub fn new(device: &wgpu::Device, format: wgpu::TextureFormat) -> Self {
-        let resources = render::wireframe::create_wireframe_resources(device, format);
-        Self { resources }
+        // 単色なので位置のみの頂点バッファで描く
+        let lines = LineBatchResources::new(device, format, LineColorMode::PerColor);
+        Self {
+            lines,
+            feature_edges: Some(FeatureEdgeOptions::default()),
+            mesh_edges: None,
+            selected: None,
+        }
+    }
+
+    /// 輪郭線を抽出するメッシュの辺の隣接情報を設定（次回の `update_edges` から反映）
+    pub fn set_mesh_edges(&mut self, mesh_edges: Arc<MeshEdges>) {
+        self.mesh_edges = Some(mesh_edges);
+        self.selected = None;
+    }
+
+    /// フィーチャーエッジの抽出設定を変更（次回の `update_edges` から反映）
+    pub fn set_feature_edge_options(&mut self, options: Option<FeatureEdgeOptions>) {
+        self.feature_edges = options;
+        self.selected = None;
+    }
+
+    /// 現在のフィーチャーエッジ抽出設定
+    pub fn feature_edge_options(&self) -> Option<FeatureEdgeOptions> {
+        self.feature_edges
+    }
+
+    /// 視線方向に応じて輪郭線を絞り込み、カメラ行列を更新する
+    ///
+    /// 行列は `MeshStage::update_camera` と同じビュー・プロジェクション行列。
+    /// シルエットは視点によって変わるため、カメラ操作のたびに呼び出す。
+    /// 隣接情報は前計算済みで、辺の選別と頂点バッファの作成は視線方向が変わったときだけ
+    /// やり直す（パン・ズームではカメラ行列だけを更新する）。
+    pub fn update_edges(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        view_dir: &Vector3D<f64>,
+        view_matrix: [[f32; 4]; 4],
+        proj_matrix: [[f32; 4]; 4],
+    ) {
+        let Some(mesh_edges) = &self.mesh_edges else {
+            return;
+        };
+        if self
+            .selected
+            .as_ref()
+            .is_none_or(|(selected_dir, _)| selected_dir != view_dir)
+        {
+            let edges = mesh_edges.outline_edges(view_dir, self.feature_edges.as_ref());
+            tracing::debug!("輪郭線更新: {} 本抽出", edges.len());
+            self.lines.update_batches(device, &[edge_batch(&edges)]);
+            self.selected = Some((*view_dir, edges));
+        }
+        self.lines.update_camera(queue, view_matrix, proj_matrix);
+    }
+}
+
+/// 辺を輪郭線の色の線分バッチにする（投影はシェーダーで行う）
+fn edge_batch(edges: &[EdgeSegment]) -> GpuLineBatch {
+    let mut batch = GpuLineBatch::new();
+    for (start, end) in edges {
+        batch.push_segment(start, end, OUTLINE_COLOR);
     }
+    batch
 }
 
 impl RenderStage for OutlineStage {
@@ -44,15 +122,30 @@ impl RenderStage for OutlineStage {
 
         let mut render_pass = encoder.begin_render_pass(&render_pass_desc);
 
-        draw_wireframe(
-            &mut render_pass,
-            &self.resources.pipeline,
-            &self.resources.vertex_buffer,
-            self.resources.vertex_count,
-        );
+        self.lines.render(&mut render_pass);
     }
 
     fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use geo_primitives::Point3D;
+
+    #[test]
+    fn test_edge_batch() {
+        let edges = [
+            (Point3D::new(0.0, 0.0, 0.0), Point3D::new(1.0, 0.5, 0.0)),
+            (Point3D::new(0.2, 0.4, 0.6), Point3D::new(-1.0, -1.0, 0.0)),
+        ];
+        let batch = edge_batch(&edges);
+        assert_eq!(batch.segment_count(), 2);
+        // 頂点はワールド座標のまま（投影しない）
+        assert_eq!(batch.vertices[2].position, [0.2, 0.4, 0.6]);
+        assert!(batch.vertices.iter().all(|v| v.color == OUTLINE_COLOR));
+        assert!(edge_batch(&[]).is_empty());
+    }
+}
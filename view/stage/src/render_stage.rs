@@ -6,6 +6,9 @@ pub trait RenderStage {
     /// 状態更新（デフォルトは空）
     fn update(&mut self) {}
 
+    /// ソリッド描画に重ねるワイヤーフレームオーバーレイの切替（デフォルトは未対応で無視）
+    fn set_wireframe_overlay(&mut self, _enabled: bool) {}
+
     /// Anyトレイトへのダウンキャスト用
     fn as_any_mut(&mut self) -> &mut dyn Any;
 }
@@ -0,0 +1,209 @@
+//! curvature_comb - 曲線の曲率コーム描画データ生成
+//!
+//! 曲線上のサンプル点から主法線方向へ曲率に比例した長さの「歯」を伸ばし、
+//! 歯の先端を結んだ包絡線とあわせてライン描画用のデータを生成します。
+//!
+//! ## 向きとスケール
+//! - 歯は曲率中心と**反対側**（曲線の凸側）へ伸びる。長さは `曲率 × scale`
+//! - `scale` を負にすると曲率中心の側（凹側）へ伸びる
+//! - 平面曲線では変曲点を境に歯の向きが反転するため、凹凸の切り替わりが読み取れる
+//! - 曲率0の点（直線部・変曲点）では歯の長さは0で、先端はサンプル点と一致する
+//!
+//! ベジエ曲線は `NurbsCurve3D::from_bezier` で変換してから渡す。
+
+use geo_nurbs::NurbsCurve3D;
+use geo_primitives::{Point3D, Vector3D};
+
+/// 曲率コームを生成できる曲線
+pub trait CombCurve {
+    /// パラメータ範囲
+    fn parameter_domain(&self) -> (f64, f64);
+
+    /// パラメータ `t` での曲線上の点
+    fn point_at(&self, t: f64) -> Point3D<f64>;
+
+    /// パラメータ `t` での1次・2次導関数
+    fn derivatives_at(&self, t: f64) -> (Vector3D<f64>, Vector3D<f64>);
+
+    /// パラメータ `t` での曲率（直線部・特異点では0）
+    fn curvature_at(&self, t: f64) -> f64;
+}
+
+impl CombCurve for NurbsCurve3D<f64> {
+    fn parameter_domain(&self) -> (f64, f64) {
+        NurbsCurve3D::parameter_domain(self)
+    }
+
+    fn point_at(&self, t: f64) -> Point3D<f64> {
+        let point = self.evaluate_at(t);
+        Point3D::new(point.x(), point.y(), point.z())
+    }
+
+    fn derivatives_at(&self, t: f64) -> (Vector3D<f64>, Vector3D<f64>) {
+        let first = self.derivative_at(t);
+        let second = self.second_derivative_at(t);
+        (
+            Vector3D::new(first.x(), first.y(), first.z()),
+            Vector3D::new(second.x(), second.y(), second.z()),
+        )
+    }
+
+    fn curvature_at(&self, t: f64) -> f64 {
+        self.curvature(t)
+    }
+}
+
+/// 曲率コームの描画データ
+#[derive(Debug, Clone, Default)]
+pub struct CurvatureComb {
+    /// 歯（サンプル点 → 先端）の線分
+    pub teeth: Vec<(Point3D<f64>, Point3D<f64>)>,
+    /// 歯の先端を順に結んだ包絡線の点列
+    pub envelope: Vec<Point3D<f64>>,
+    /// 各サンプル点での曲率（符号なし）
+    pub curvatures: Vec<f64>,
+}
+
+impl CurvatureComb {
+    /// 歯と包絡線をまとめたライン描画用の線分リスト
+    pub fn line_segments(&self) -> Vec<(Point3D<f64>, Point3D<f64>)> {
+        let mut segments = self.teeth.clone();
+        segments.extend(self.envelope.windows(2).map(|pair| (pair[0], pair[1])));
+        segments
+    }
+
+    /// サンプル中の最大曲率（スケール自動調整用）
+    pub fn max_curvature(&self) -> f64 {
+        self.curvatures.iter().copied().fold(0.0, f64::max)
+    }
+}
+
+/// 曲率コームを生成
+///
+/// パラメータ範囲を `samples` 点（両端を含む、最低2点）で等分し、
+/// 各点で曲率（[`CombCurve::curvature_at`]）と主法線を求めて歯を作る。
+/// 向きとスケールの扱いはモジュールのドキュメントを参照。
+pub fn build_curvature_comb<C: CombCurve>(curve: &C, samples: usize, scale: f64) -> CurvatureComb {
+    let samples = samples.max(2);
+    let (start, end) = curve.parameter_domain();
+
+    let mut comb = CurvatureComb {
+        teeth: Vec::with_capacity(samples),
+        envelope: Vec::with_capacity(samples),
+        curvatures: Vec::with_capacity(samples),
+    };
+    for i in 0..samples {
+        let t = start + (end - start) * i as f64 / (samples - 1) as f64;
+        let point = curve.point_at(t);
+        let curvature = curve.curvature_at(t);
+        let normal = if curvature > 0.0 {
+            let (first, second) = curve.derivatives_at(t);
+            principal_normal(&first, &second)
+        } else {
+            Vector3D::zero()
+        };
+
+        // 主法線は曲率中心を向くので、凸側へ伸ばすために反転する
+        let length = -curvature * scale;
+        let tip = Point3D::new(
+            point.x() + normal.x() * length,
+            point.y() + normal.y() * length,
+            point.z() + normal.z() * length,
+        );
+
+        comb.teeth.push((point, tip));
+        comb.envelope.push(tip);
+        comb.curvatures.push(curvature);
+    }
+    comb
+}
+
+/// 単位主法線（`C''` から接線成分を除いた向き）
+fn principal_normal(first: &Vector3D<f64>, second: &Vector3D<f64>) -> Vector3D<f64> {
+    let tangent = first.normalize();
+    let along = second.dot(&tangent);
+    Vector3D::new(
+        second.x() - tangent.x() * along,
+        second.y() - tangent.y() * along,
+        second.z() - tangent.z() * along,
+    )
+    .normalize()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use analysis::linalg::vector::Vector3;
+
+    /// 原点中心・半径2の四分円（第1象限）
+    fn quarter_circle() -> NurbsCurve3D<f64> {
+        NurbsCurve3D::new(
+            vec![
+                Vector3::new(2.0, 0.0, 0.0),
+                Vector3::new(2.0, 2.0, 0.0),
+                Vector3::new(0.0, 2.0, 0.0),
+            ],
+            Some(vec![1.0, std::f64::consts::FRAC_1_SQRT_2, 1.0]),
+            vec![0.0, 0.0, 0.0, 1.0, 1.0, 1.0],
+            2,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_circle_comb_has_constant_length_pointing_outward() {
+        let comb = build_curvature_comb(&quarter_circle(), 9, 2.0);
+        assert_eq!(comb.teeth.len(), 9);
+        assert_eq!(comb.envelope.len(), 9);
+
+        for (base, tip) in &comb.teeth {
+            // 曲率 0.5 × scale 2.0 = 長さ1、中心から離れる向き
+            assert!((base.distance_to(tip) - 1.0).abs() < 1e-9);
+            assert!((tip.distance_to(&Point3D::origin()) - 3.0).abs() < 1e-9);
+        }
+        assert!((comb.max_curvature() - 0.5).abs() < 1e-9);
+
+        // 負のスケールでは中心側へ伸びる
+        let inward = build_curvature_comb(&quarter_circle(), 5, -2.0);
+        for (_, tip) in &inward.teeth {
+            assert!((tip.distance_to(&Point3D::origin()) - 1.0).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_straight_bezier_has_flat_comb() {
+        let line = NurbsCurve3D::from_bezier(&[
+            Point3D::new(0.0, 0.0, 0.0),
+            Point3D::new(1.0, 1.0, 0.0),
+            Point3D::new(3.0, 3.0, 0.0),
+        ])
+        .unwrap();
+        let comb = build_curvature_comb(&line, 5, 10.0);
+        for (base, tip) in &comb.teeth {
+            assert_eq!(base, tip);
+        }
+        assert_eq!(comb.max_curvature(), 0.0);
+    }
+
+    #[test]
+    fn test_s_curve_flips_side_at_inflection() {
+        // S字の3次ベジエ：前半と後半で凸側が反対になる
+        let s_curve = NurbsCurve3D::from_bezier(&[
+            Point3D::new(0.0, 0.0, 0.0),
+            Point3D::new(1.0, 2.0, 0.0),
+            Point3D::new(2.0, -2.0, 0.0),
+            Point3D::new(3.0, 0.0, 0.0),
+        ])
+        .unwrap();
+        let comb = build_curvature_comb(&s_curve, 11, 1.0);
+
+        let offset_y = |index: usize| {
+            let (base, tip) = comb.teeth[index];
+            tip.y() - base.y()
+        };
+        assert!(offset_y(1) * offset_y(9) < 0.0);
+
+        // 線分数 = 歯 + 包絡線の区間
+        assert_eq!(comb.line_segments().len(), 11 + 10);
+    }
+}
@@ -5,12 +5,18 @@
 //! View層（render）で使用可能な形式に変換することに特化しています。
 //!
 //! ## 主要機能
-//! - メッシュデータ変換（Model → GPU形式）
+//! - メッシュデータ・点群・線分バッチ変換（Model → GPU形式）
 //! - STL読み込み・変換統合
 //! - 境界ボックス計算・変換
+//! - 曲率コーム描画データ生成
+//! - メッシュの原点中心・単位スケールへの自動フィット
+//! - NURBS曲面の三角形メッシュ化（テッセレーション）
 
+pub mod curvature_comb;
 pub mod mesh_converter;
+pub mod mesh_fit;
 pub mod stl_loader;
+pub mod surface_tessellation;
 
 /// テスト用の関数（削除予定）
 pub fn add(left: u64, right: u64) -> u64 {
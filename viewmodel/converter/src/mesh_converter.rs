@@ -1,10 +1,10 @@
-//! mesh_converter - TriangleMesh3D から GPU用頂点データへの変換
+//! mesh_converter - TriangleMesh3D・点群・線分から GPU用頂点データへの変換
 //!
 //! MVVMアーキテクチャにおけるViewModel層の責務として、
 //! geo_primitives の具体型を使用して TriangleMesh3D を GPU レンダリング用の頂点データに変換します。
 
 // 具体型はgeo_primitivesから
-use geo_primitives::{TriangleMesh3D, Vector3D};
+use geo_primitives::{Point3D, TriangleMesh3D, Vector3D};
 
 /// GPU用頂点データ（renderクレートのMeshVertexと同じ構造）
 #[repr(C)]
@@ -20,6 +20,16 @@ impl VertexData {
     }
 }
 
+/// メッシュの陰影付け方式
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum ShadingMode {
+    /// 面法線を各三角形の頂点に複製（ファセットが見える）
+    #[default]
+    Flat,
+    /// 共有頂点ごとに隣接面の法線を平均（滑らかな陰影）
+    Smooth,
+}
+
 /// CCWと法線の整合性を検証（簡易版）
 fn validate_ccw_normal_consistency(
     edge1: &Vector3D<f64>,
@@ -99,6 +109,543 @@ pub fn triangle_mesh_to_vertices_with_indices(
     (vertices, indices)
 }
 
+/// TriangleMesh3D を平均頂点法線つきのGPU用頂点データとインデックスに変換
+///
+/// メッシュの共有頂点をそのまま使い、各頂点の法線は隣接三角形の面法線を
+/// 面積で重み付けして平均します（スムースシェーディング用）。
+pub fn triangle_mesh_to_smooth_vertices_with_indices(
+    mesh: &TriangleMesh3D<f64>,
+) -> (Vec<VertexData>, Vec<u32>) {
+    let mesh_vertices = mesh.vertices();
+    let mut accumulated = vec![Vector3D::new(0.0, 0.0, 0.0); mesh_vertices.len()];
+
+    for triangle in mesh.indices() {
+        let [a, b, c] = triangle.map(|i| mesh_vertices[i]);
+        let edge1 = Vector3D::from_points(&a, &b);
+        let edge2 = Vector3D::from_points(&a, &c);
+
+        // 正規化前の外積の長さは三角形面積の2倍なので、そのまま面積重みになる
+        let weighted_normal = edge1.cross(&edge2);
+        for &i in triangle {
+            accumulated[i] = accumulated[i] + weighted_normal;
+        }
+    }
+
+    let vertices = mesh_vertices
+        .iter()
+        .zip(&accumulated)
+        .map(|(point, normal)| {
+            let normal = if normal.is_zero() {
+                *normal
+            } else {
+                normal.normalize()
+            };
+            VertexData::new(
+                [point.x() as f32, point.y() as f32, point.z() as f32],
+                [normal.x() as f32, normal.y() as f32, normal.z() as f32],
+            )
+        })
+        .collect();
+
+    let indices = mesh
+        .indices()
+        .iter()
+        .flat_map(|triangle| triangle.map(|i| i as u32))
+        .collect();
+
+    (vertices, indices)
+}
+
+/// シェーディングモードに応じた頂点データとインデックスに変換
+///
+/// - `ShadingMode::Flat`: 面法線を各三角形の頂点に複製
+/// - `ShadingMode::Smooth`: 共有頂点に平均頂点法線を設定
+pub fn triangle_mesh_to_vertices_for_shading(
+    mesh: &TriangleMesh3D<f64>,
+    mode: ShadingMode,
+) -> (Vec<VertexData>, Vec<u32>) {
+    match mode {
+        ShadingMode::Flat => triangle_mesh_to_vertices_with_indices(mesh),
+        ShadingMode::Smooth => triangle_mesh_to_smooth_vertices_with_indices(mesh),
+    }
+}
+
+// ============================================================================
+// 頂点キャッシュ最適化（Tom Forsyth: Linear-Speed Vertex Cache Optimisation）
+// ============================================================================
+
+/// 最適化時に想定するLRU頂点キャッシュのサイズ
+const VERTEX_CACHE_SIZE: usize = 32;
+/// キャッシュ位置によるスコアの減衰指数
+const CACHE_DECAY_POWER: f32 = 1.5;
+/// 直前の三角形で使用された頂点のスコア
+const LAST_TRIANGLE_SCORE: f32 = 0.75;
+/// 残り三角形数（価数）によるブーストの係数
+const VALENCE_BOOST_SCALE: f32 = 2.0;
+/// 残り三角形数（価数）によるブーストの指数
+const VALENCE_BOOST_POWER: f32 = 0.5;
+
+/// 頂点のスコアを計算（キャッシュ位置と残り三角形数から）
+fn vertex_cache_score(cache_position: Option<usize>, remaining_triangles: u32) -> f32 {
+    if remaining_triangles == 0 {
+        // もう使われない頂点は選択の対象外
+        return -1.0;
+    }
+
+    let cache_score = match cache_position {
+        None => 0.0,
+        // 直前の三角形の頂点は順序に関わらず同じスコア
+        Some(position) if position < 3 => LAST_TRIANGLE_SCORE,
+        Some(position) => {
+            let scaler = 1.0 / (VERTEX_CACHE_SIZE - 3) as f32;
+            (1.0 - (position - 3) as f32 * scaler).powf(CACHE_DECAY_POWER)
+        }
+    };
+
+    // 残り三角形が少ない頂点を優先して早く使い切る
+    let valence_boost =
+        VALENCE_BOOST_SCALE * (remaining_triangles as f32).powf(-VALENCE_BOOST_POWER);
+
+    cache_score + valence_boost
+}
+
+/// GPUの頂点キャッシュヒット率が上がるように三角形の順序を並べ替える
+///
+/// Tom Forsyth の線形時間アルゴリズムにより、キャッシュ内の頂点を共有する三角形を
+/// 優先的に出力します。三角形の集合と各三角形の頂点の巡回順（CCW）は保持されます。
+/// インデックス数が3の倍数でない場合は何もしません。
+pub fn optimize_vertex_cache(indices: &mut Vec<u32>) {
+    if indices.is_empty() || !indices.len().is_multiple_of(3) {
+        return;
+    }
+
+    let triangle_count = indices.len() / 3;
+    let vertex_count = indices.iter().max().map_or(0, |&max| max as usize + 1);
+
+    // 頂点 → 隣接三角形の表（CSR形式）
+    let mut remaining = vec![0u32; vertex_count];
+    for &index in indices.iter() {
+        remaining[index as usize] += 1;
+    }
+    let mut offsets = vec![0usize; vertex_count + 1];
+    for v in 0..vertex_count {
+        offsets[v + 1] = offsets[v] + remaining[v] as usize;
+    }
+    let mut adjacency = vec![0usize; indices.len()];
+    let mut fill = offsets.clone();
+    for (i, &index) in indices.iter().enumerate() {
+        let v = index as usize;
+        adjacency[fill[v]] = i / 3;
+        fill[v] += 1;
+    }
+
+    let mut cache_position: Vec<Option<usize>> = vec![None; vertex_count];
+    let mut vertex_score: Vec<f32> = (0..vertex_count)
+        .map(|v| vertex_cache_score(None, remaining[v]))
+        .collect();
+    let mut triangle_score: Vec<f32> = (0..triangle_count)
+        .map(|t| {
+            indices[t * 3..t * 3 + 3]
+                .iter()
+                .map(|&v| vertex_score[v as usize])
+                .sum()
+        })
+        .collect();
+    let mut emitted = vec![false; triangle_count];
+
+    let mut output = Vec::with_capacity(indices.len());
+    let mut cache: Vec<u32> = Vec::with_capacity(VERTEX_CACHE_SIZE + 3);
+    let mut best_triangle = best_unemitted_triangle(&triangle_score, &emitted);
+    // 線形探索の再開位置（出力済みの三角形を何度も走査しない）
+    let mut scan_start = 0;
+
+    while let Some(triangle) = best_triangle {
+        emitted[triangle] = true;
+        let corners = [
+            indices[triangle * 3],
+            indices[triangle * 3 + 1],
+            indices[triangle * 3 + 2],
+        ];
+        output.extend_from_slice(&corners);
+
+        // 使用した頂点の残り三角形を減らす
+        for &v in &corners {
+            remaining[v as usize] -= 1;
+        }
+
+        // LRUキャッシュを更新（今回の頂点を先頭へ）
+        let mut new_cache: Vec<u32> = corners.to_vec();
+        new_cache.extend(cache.iter().copied().filter(|v| !corners.contains(v)));
+        for &evicted in new_cache.iter().skip(VERTEX_CACHE_SIZE) {
+            cache_position[evicted as usize] = None;
+        }
+        new_cache.truncate(VERTEX_CACHE_SIZE);
+
+        // 追い出された頂点のスコアを更新
+        for &v in cache.iter() {
+            if cache_position[v as usize].is_none() {
+                update_vertex_score(
+                    v as usize,
+                    &cache_position,
+                    &remaining,
+                    &offsets,
+                    &adjacency,
+                    &emitted,
+                    &mut vertex_score,
+                    &mut triangle_score,
+                    indices,
+                );
+            }
+        }
+
+        // キャッシュ内の頂点のスコアを更新し、次の候補三角形を探す
+        best_triangle = None;
+        let mut best_score = -1.0f32;
+        for (position, &v) in new_cache.iter().enumerate() {
+            cache_position[v as usize] = Some(position);
+        }
+        for &v in new_cache.iter() {
+            update_vertex_score(
+                v as usize,
+                &cache_position,
+                &remaining,
+                &offsets,
+                &adjacency,
+                &emitted,
+                &mut vertex_score,
+                &mut triangle_score,
+                indices,
+            );
+        }
+        for &v in new_cache.iter() {
+            let v = v as usize;
+            for &t in &adjacency[offsets[v]..offsets[v + 1]] {
+                if !emitted[t] && triangle_score[t] > best_score {
+                    best_score = triangle_score[t];
+                    best_triangle = Some(t);
+                }
+            }
+        }
+        cache = new_cache;
+
+        // キャッシュ周辺に候補がない場合は未出力の三角形から探す
+        if best_triangle.is_none() {
+            while scan_start < triangle_count && emitted[scan_start] {
+                scan_start += 1;
+            }
+            best_triangle =
+                best_unemitted_triangle(&triangle_score[scan_start..], &emitted[scan_start..])
+                    .map(|t| t + scan_start);
+        }
+    }
+
+    *indices = output;
+}
+
+/// 未出力の三角形のうちスコアが最大のものを返す
+fn best_unemitted_triangle(triangle_score: &[f32], emitted: &[bool]) -> Option<usize> {
+    let mut best: Option<(usize, f32)> = None;
+    for (t, (&score, &done)) in triangle_score.iter().zip(emitted).enumerate() {
+        if !done && best.is_none_or(|(_, best_score)| score > best_score) {
+            best = Some((t, score));
+        }
+    }
+    best.map(|(t, _)| t)
+}
+
+/// 頂点スコアを再計算し、差分を隣接する未出力三角形のスコアに反映する
+#[allow(clippy::too_many_arguments)]
+fn update_vertex_score(
+    vertex: usize,
+    cache_position: &[Option<usize>],
+    remaining: &[u32],
+    offsets: &[usize],
+    adjacency: &[usize],
+    emitted: &[bool],
+    vertex_score: &mut [f32],
+    triangle_score: &mut [f32],
+    indices: &[u32],
+) {
+    let new_score = vertex_cache_score(cache_position[vertex], remaining[vertex]);
+    let delta = new_score - vertex_score[vertex];
+    if delta == 0.0 {
+        return;
+    }
+    vertex_score[vertex] = new_score;
+
+    for &t in &adjacency[offsets[vertex]..offsets[vertex + 1]] {
+        if !emitted[t] {
+            triangle_score[t] = indices[t * 3..t * 3 + 3]
+                .iter()
+                .map(|&v| vertex_score[v as usize])
+                .sum();
+        }
+    }
+}
+
+/// FIFO頂点キャッシュを模擬して ACMR（三角形あたりの平均キャッシュミス数）を計算
+///
+/// 値が小さいほどキャッシュ効率が良く、理論下限はおよそ 0.5 です。
+pub fn average_cache_miss_ratio(indices: &[u32], cache_size: usize) -> f32 {
+    let triangle_count = indices.len() / 3;
+    if triangle_count == 0 || cache_size == 0 {
+        return 0.0;
+    }
+
+    let mut cache: std::collections::VecDeque<u32> =
+        std::collections::VecDeque::with_capacity(cache_size);
+    let mut misses = 0usize;
+    for &index in &indices[..triangle_count * 3] {
+        if !cache.contains(&index) {
+            misses += 1;
+            if cache.len() == cache_size {
+                cache.pop_front();
+            }
+            cache.push_back(index);
+        }
+    }
+
+    misses as f32 / triangle_count as f32
+}
+
+// ============================================================================
+// 頂点フェッチ最適化
+// ============================================================================
+
+/// インデックスが最初に参照する順序に基づく頂点の再配置表を作成
+///
+/// 戻り値 `remap[old] = new` は旧頂点番号から新頂点番号への対応です。
+/// インデックスから参照されない頂点は参照済み頂点の後ろに元の順序で配置されます。
+///
+/// # Panics
+///
+/// `indices` に `vertex_count` 以上の頂点番号が含まれる場合。
+pub fn build_vertex_fetch_remap(indices: &[u32], vertex_count: usize) -> Vec<u32> {
+    const UNASSIGNED: u32 = u32::MAX;
+
+    let mut remap = vec![UNASSIGNED; vertex_count];
+    let mut next = 0u32;
+    for &index in indices {
+        let slot = &mut remap[index as usize];
+        if *slot == UNASSIGNED {
+            *slot = next;
+            next += 1;
+        }
+    }
+    for slot in remap.iter_mut().filter(|slot| **slot == UNASSIGNED) {
+        *slot = next;
+        next += 1;
+    }
+
+    remap
+}
+
+/// 再配置表に従って頂点属性配列を並べ替えた配列を返す
+///
+/// 法線・UV・カラーなどを別配列で保持している場合に、
+/// [`build_vertex_fetch_remap`] の結果を各配列へ適用するために使用します。
+/// `remap` が `attributes` と同じ長さの置換（`0..len` の各番号をちょうど1回ずつ含む）で
+/// ない場合は `None` を返します。
+pub fn remap_vertex_attribute<T: Clone>(attributes: &[T], remap: &[u32]) -> Option<Vec<T>> {
+    if attributes.len() != remap.len() {
+        return None;
+    }
+
+    let mut reordered: Vec<Option<T>> = vec![None; attributes.len()];
+    for (attribute, &new) in attributes.iter().zip(remap) {
+        let slot = reordered.get_mut(new as usize)?;
+        if slot.is_some() {
+            return None;
+        }
+        *slot = Some(attribute.clone());
+    }
+    // 長さが等しく重複もないので、すべての位置が埋まっている
+    reordered.into_iter().collect()
+}
+
+/// 頂点をインデックスのアクセス順に並べ替え、インデックスを張り直す
+///
+/// 頂点キャッシュ最適化（[`optimize_vertex_cache`]）の後に適用すると、
+/// 頂点フェッチのメモリアクセスが連続的になります。頂点型は任意で、
+/// 位置・法線・UV・カラーなどの付随属性は頂点と一緒に並べ替えられます。
+///
+/// # Panics
+///
+/// `indices` に `vertices.len()` 以上の頂点番号が含まれる場合。
+pub fn optimize_vertex_fetch<V: Clone>(vertices: &mut Vec<V>, indices: &mut [u32]) {
+    let remap = build_vertex_fetch_remap(indices, vertices.len());
+
+    for index in indices.iter_mut() {
+        *index = remap[*index as usize];
+    }
+    *vertices = remap_vertex_attribute(vertices, &remap)
+        .expect("build_vertex_fetch_remap always returns a permutation");
+}
+
+// ============================================================================
+// 点群変換
+// ============================================================================
+
+/// カラーが指定されていない点に使う既定色（白）
+pub const DEFAULT_POINT_COLOR: [f32; 4] = [1.0, 1.0, 1.0, 1.0];
+
+/// GPU用の点群頂点データ（renderクレートのPointVertexと同じ構造）
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct PointVertexData {
+    pub position: [f32; 3],
+    /// RGBA（各成分 0.0〜1.0）
+    pub color: [f32; 4],
+}
+
+/// GPUへ転送する点群データ
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GpuPointCloud {
+    pub vertices: Vec<PointVertexData>,
+}
+
+impl GpuPointCloud {
+    /// 点数
+    pub fn len(&self) -> usize {
+        self.vertices.len()
+    }
+
+    /// 点を持たないかどうか
+    pub fn is_empty(&self) -> bool {
+        self.vertices.is_empty()
+    }
+}
+
+/// 点群と各点のカラーをGPU用の点群データに変換
+///
+/// `colors` の要素数が1なら全点に同じ色を使う。それ以外で `colors` が
+/// 点数より少ない場合、不足分は [`DEFAULT_POINT_COLOR`] になる。
+pub fn convert_point_cloud(points: &[Point3D<f64>], colors: &[[f32; 4]]) -> GpuPointCloud {
+    let color_at = |i: usize| match colors {
+        [uniform] => *uniform,
+        _ => colors.get(i).copied().unwrap_or(DEFAULT_POINT_COLOR),
+    };
+
+    let vertices = points
+        .iter()
+        .enumerate()
+        .map(|(i, point)| PointVertexData {
+            position: [point.x() as f32, point.y() as f32, point.z() as f32],
+            color: color_at(i),
+        })
+        .collect();
+
+    GpuPointCloud { vertices }
+}
+
+// ============================================================================
+// 線分バッチ
+// ============================================================================
+
+/// GPU用の線分頂点データ（renderクレートのLineBatchVertexと同じ構造）
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct LineVertexData {
+    pub position: [f32; 3],
+    /// RGBA（各成分 0.0〜1.0）
+    pub color: [f32; 4],
+}
+
+/// 線分バッチの色の扱い
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum LineColorMode {
+    /// 頂点カラー：全線分を1つの頂点バッファにまとめ、1回のドローコールで描画
+    #[default]
+    PerVertex,
+    /// 色ごとにバッチを分け、色はUniformで指定（頂点は位置のみ、色数分のドローコール）
+    PerColor,
+}
+
+/// 複数の線分・ポリラインを1つの頂点列（LineList）にまとめたバッチ
+///
+/// 頂点は2つずつ1本の線分を表す。
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GpuLineBatch {
+    pub vertices: Vec<LineVertexData>,
+}
+
+impl GpuLineBatch {
+    /// 空のバッチ
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 線分を追加
+    pub fn push_segment(&mut self, start: &Point3D<f64>, end: &Point3D<f64>, color: [f32; 4]) {
+        for point in [start, end] {
+            self.vertices.push(LineVertexData {
+                position: [point.x() as f32, point.y() as f32, point.z() as f32],
+                color,
+            });
+        }
+    }
+
+    /// ポリラインを線分に展開して追加（`closed` なら終点から始点へ戻る線分も追加）
+    pub fn push_polyline(&mut self, points: &[Point3D<f64>], closed: bool, color: [f32; 4]) {
+        for pair in points.windows(2) {
+            self.push_segment(&pair[0], &pair[1], color);
+        }
+        if closed && points.len() > 2 {
+            self.push_segment(&points[points.len() - 1], &points[0], color);
+        }
+    }
+
+    /// 別のバッチの線分を末尾に追加
+    pub fn append(&mut self, other: &GpuLineBatch) {
+        self.vertices.extend_from_slice(&other.vertices);
+    }
+
+    /// 線分の本数
+    pub fn segment_count(&self) -> usize {
+        self.vertices.len() / 2
+    }
+
+    /// 線分を持たないかどうか
+    pub fn is_empty(&self) -> bool {
+        self.vertices.is_empty()
+    }
+
+    /// 線分を色ごとのバッチに分ける（色は最初に現れた順）
+    ///
+    /// [`LineColorMode::PerColor`] での描画に使う。線分の色は始点の色で判定する。
+    pub fn split_by_color(&self) -> Vec<([f32; 4], GpuLineBatch)> {
+        let mut groups: Vec<([f32; 4], GpuLineBatch)> = Vec::new();
+        for segment in self.vertices.chunks_exact(2) {
+            let color = segment[0].color;
+            let group = match groups.iter().position(|(c, _)| same_color(c, &color)) {
+                Some(index) => &mut groups[index].1,
+                None => {
+                    groups.push((color, GpuLineBatch::new()));
+                    &mut groups.last_mut().expect("just pushed").1
+                }
+            };
+            group.vertices.extend_from_slice(segment);
+        }
+        groups
+    }
+}
+
+/// 色の一致判定（ビット単位、-0.0 と 0.0 は区別する）
+fn same_color(a: &[f32; 4], b: &[f32; 4]) -> bool {
+    a.iter().zip(b).all(|(x, y)| x.to_bits() == y.to_bits())
+}
+
+/// 線分群を1色のバッチにまとめる
+pub fn batch_lines(segments: &[(Point3D<f64>, Point3D<f64>)], color: [f32; 4]) -> GpuLineBatch {
+    let mut batch = GpuLineBatch {
+        vertices: Vec::with_capacity(segments.len() * 2),
+    };
+    for (start, end) in segments {
+        batch.push_segment(start, end, color);
+    }
+    batch
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -137,4 +684,230 @@ mod tests {
         assert_eq!(gpu_indices.len(), 3);
         assert_eq!(gpu_indices, vec![0, 1, 2]);
     }
+
+    /// 三角形を巡回順を保ったまま正規化した集合に変換
+    fn normalized_triangles(indices: &[u32]) -> Vec<[u32; 3]> {
+        let mut triangles: Vec<[u32; 3]> = indices
+            .chunks(3)
+            .map(|t| {
+                let min = (0..3).min_by_key(|&i| t[i]).unwrap();
+                [t[min], t[(min + 1) % 3], t[(min + 2) % 3]]
+            })
+            .collect();
+        triangles.sort();
+        triangles
+    }
+
+    /// n×n 格子の三角形インデックスを列優先（キャッシュに不利な順序）で生成
+    fn grid_indices(n: u32) -> Vec<u32> {
+        let mut indices = Vec::new();
+        for x in 0..n {
+            for y in 0..n {
+                let v0 = y * (n + 1) + x;
+                let v1 = v0 + 1;
+                let v2 = v0 + n + 1;
+                let v3 = v2 + 1;
+                indices.extend_from_slice(&[v0, v1, v2, v1, v3, v2]);
+            }
+        }
+        indices
+    }
+
+    #[test]
+    fn test_optimize_vertex_cache_preserves_topology() {
+        let original = grid_indices(16);
+        let mut optimized = original.clone();
+        optimize_vertex_cache(&mut optimized);
+
+        assert_eq!(optimized.len(), original.len());
+        assert_eq!(
+            normalized_triangles(&optimized),
+            normalized_triangles(&original)
+        );
+    }
+
+    #[test]
+    fn test_optimize_vertex_cache_improves_acmr() {
+        let original = grid_indices(32);
+        let mut optimized = original.clone();
+        optimize_vertex_cache(&mut optimized);
+
+        let before = average_cache_miss_ratio(&original, 16);
+        let after = average_cache_miss_ratio(&optimized, 16);
+        assert!(after < before, "ACMR: before={before}, after={after}");
+    }
+
+    #[test]
+    fn test_optimize_vertex_cache_ignores_invalid_input() {
+        let mut empty: Vec<u32> = Vec::new();
+        optimize_vertex_cache(&mut empty);
+        assert!(empty.is_empty());
+
+        let mut partial = vec![0, 1, 2, 3];
+        optimize_vertex_cache(&mut partial);
+        assert_eq!(partial, vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_optimize_vertex_fetch_orders_by_first_use() {
+        let mut vertices = vec![
+            VertexData::new([0.0, 0.0, 0.0], [0.0, 0.0, 1.0]),
+            VertexData::new([1.0, 0.0, 0.0], [0.0, 0.0, 1.0]),
+            VertexData::new([0.0, 1.0, 0.0], [0.0, 0.0, 1.0]),
+            VertexData::new([1.0, 1.0, 0.0], [0.0, 0.0, 1.0]),
+            VertexData::new([9.0, 9.0, 9.0], [0.0, 0.0, 1.0]),
+        ];
+        let original_vertices = vertices.clone();
+        let original_indices = vec![3, 2, 1, 1, 2, 0];
+        let mut indices = original_indices.clone();
+
+        optimize_vertex_fetch(&mut vertices, &mut indices);
+
+        assert_eq!(indices, vec![0, 1, 2, 2, 1, 3]);
+        // 各インデックスが同じ位置の頂点を参照していること
+        for (old, new) in original_indices.iter().zip(&indices) {
+            assert_eq!(
+                original_vertices[*old as usize].position,
+                vertices[*new as usize].position
+            );
+        }
+        // 未参照頂点は末尾に残る
+        assert_eq!(vertices.len(), 5);
+        assert_eq!(vertices[4].position, [9.0, 9.0, 9.0]);
+    }
+
+    #[test]
+    fn test_remap_vertex_attribute_for_separate_arrays() {
+        let indices = vec![2, 0, 1];
+        let remap = build_vertex_fetch_remap(&indices, 3);
+        assert_eq!(remap, vec![1, 2, 0]);
+
+        let uvs = vec![[0.0f32, 0.0], [1.0, 0.0], [0.0, 1.0]];
+        let remapped = remap_vertex_attribute(&uvs, &remap).unwrap();
+        assert_eq!(remapped, vec![[0.0, 1.0], [0.0, 0.0], [1.0, 0.0]]);
+
+        // 長さ違い・範囲外・重複を含む再配置表は拒否する
+        assert!(remap_vertex_attribute(&uvs, &[0, 1]).is_none());
+        assert!(remap_vertex_attribute(&uvs, &[0, 1, 3]).is_none());
+        assert!(remap_vertex_attribute(&uvs, &[0, 1, 1]).is_none());
+    }
+
+    /// 原点の角を共有する3面（-X, -Y, -Z に向く）の四面体の一部
+    fn corner_mesh() -> TriangleMesh3D<f64> {
+        let vertices = vec![
+            Point3D::new(0.0, 0.0, 0.0),
+            Point3D::new(1.0, 0.0, 0.0),
+            Point3D::new(0.0, 1.0, 0.0),
+            Point3D::new(0.0, 0.0, 1.0),
+        ];
+        let indices = vec![[0, 2, 1], [0, 1, 3], [0, 3, 2]];
+        TriangleMesh3D::new(vertices, indices).unwrap()
+    }
+
+    #[test]
+    fn test_smooth_vertices_average_normals() {
+        let mesh = corner_mesh();
+        let (vertices, indices) = triangle_mesh_to_smooth_vertices_with_indices(&mesh);
+
+        assert_eq!(vertices.len(), 4);
+        assert_eq!(indices, vec![0, 2, 1, 0, 1, 3, 0, 3, 2]);
+
+        // 原点は -X, -Y, -Z の3面に共有されるので対角方向に平均される
+        let expected = -1.0 / 3.0f32.sqrt();
+        for component in vertices[0].normal {
+            assert!((component - expected).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_vertices_for_shading_mode() {
+        let mesh = corner_mesh();
+
+        let (flat_vertices, flat_indices) =
+            triangle_mesh_to_vertices_for_shading(&mesh, ShadingMode::Flat);
+        assert_eq!(flat_vertices.len(), 9);
+        assert_eq!(flat_indices.len(), 9);
+        // フラットでは同じ三角形の頂点が同じ面法線を持つ
+        assert_eq!(flat_vertices[0].normal, flat_vertices[1].normal);
+        assert_eq!(flat_vertices[0].normal, flat_vertices[2].normal);
+
+        let (smooth_vertices, smooth_indices) =
+            triangle_mesh_to_vertices_for_shading(&mesh, ShadingMode::Smooth);
+        assert_eq!(smooth_vertices.len(), 4);
+        assert_eq!(smooth_indices.len(), 9);
+    }
+
+    #[test]
+    fn test_convert_point_cloud_colors() {
+        let points = vec![
+            Point3D::new(0.0, 0.0, 0.0),
+            Point3D::new(1.0, 2.0, 3.0),
+            Point3D::new(-1.0, 0.5, 0.0),
+        ];
+        let red = [1.0, 0.0, 0.0, 1.0];
+        let green = [0.0, 1.0, 0.0, 1.0];
+
+        let cloud = convert_point_cloud(&points, &[red, green]);
+        assert_eq!(cloud.len(), 3);
+        assert_eq!(cloud.vertices[1].position, [1.0, 2.0, 3.0]);
+        assert_eq!(cloud.vertices[0].color, red);
+        assert_eq!(cloud.vertices[1].color, green);
+        // カラーが足りない点は既定色
+        assert_eq!(cloud.vertices[2].color, DEFAULT_POINT_COLOR);
+
+        // 1色だけなら全点に適用
+        let uniform = convert_point_cloud(&points, &[green]);
+        assert!(uniform.vertices.iter().all(|v| v.color == green));
+
+        assert!(convert_point_cloud(&[], &[]).is_empty());
+    }
+
+    #[test]
+    fn test_batch_lines_and_polylines() {
+        let red = [1.0, 0.0, 0.0, 1.0];
+        let blue = [0.0, 0.0, 1.0, 1.0];
+        let segments = vec![
+            (Point3D::new(0.0, 0.0, 0.0), Point3D::new(1.0, 0.0, 0.0)),
+            (Point3D::new(0.0, 1.0, 0.0), Point3D::new(1.0, 1.0, 0.0)),
+        ];
+        let mut batch = batch_lines(&segments, red);
+        assert_eq!(batch.segment_count(), 2);
+        assert_eq!(batch.vertices[3].position, [1.0, 1.0, 0.0]);
+
+        // 閉じた三角形は3本の線分
+        let triangle = [
+            Point3D::new(0.0, 0.0, 1.0),
+            Point3D::new(1.0, 0.0, 1.0),
+            Point3D::new(0.0, 1.0, 1.0),
+        ];
+        batch.push_polyline(&triangle, true, blue);
+        assert_eq!(batch.segment_count(), 5);
+        assert_eq!(batch.vertices[8].position, [0.0, 1.0, 1.0]);
+        assert_eq!(batch.vertices[9].position, [0.0, 0.0, 1.0]);
+
+        // 開いたポリラインは閉じない
+        let mut open = GpuLineBatch::new();
+        open.push_polyline(&triangle, false, blue);
+        assert_eq!(open.segment_count(), 2);
+    }
+
+    #[test]
+    fn test_split_line_batch_by_color() {
+        let red = [1.0, 0.0, 0.0, 1.0];
+        let blue = [0.0, 0.0, 1.0, 1.0];
+        let a = Point3D::new(0.0, 0.0, 0.0);
+        let b = Point3D::new(1.0, 0.0, 0.0);
+
+        let mut batch = batch_lines(&[(a, b)], red);
+        batch.append(&batch_lines(&[(b, a), (a, b)], blue));
+        batch.push_segment(&b, &a, red);
+
+        let groups = batch.split_by_color();
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[0].0, red);
+        assert_eq!(groups[0].1.segment_count(), 2);
+        assert_eq!(groups[1].0, blue);
+        assert_eq!(groups[1].1.segment_count(), 2);
+        assert!(GpuLineBatch::new().split_by_color().is_empty());
+    }
 }
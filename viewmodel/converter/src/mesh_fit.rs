@@ -0,0 +1,134 @@
+//! mesh_fit - メッシュのビューへの自動フィット
+//!
+//! 未知スケールのメッシュを、バウンディングボックス中心が原点・最大辺長が1
+//! （`[-0.5, 0.5]` に収まる）となるよう正規化します。
+//! 戻り値の `Transform3D` の逆変換で元の座標へ戻せます。
+
+use geo_foundation::Transform3D;
+use geo_primitives::{Point3D, TriangleMesh3D, Vector3D};
+
+/// メッシュの全頂点に相似変換（回転・一様スケール・平行移動）を適用
+///
+/// 頂点法線は方向として変換し直す。相似変換では面の向きとの関係が保たれるため、
+/// 非一様スケールなどの一般のアフィン変換は想定しない。
+pub fn apply_to_mesh(transform: &Transform3D<f64>, mesh: &mut TriangleMesh3D<f64>) {
+    let vertices = mesh
+        .vertices()
+        .iter()
+        .map(|v| Point3D::from_analysis_vector3(transform.apply_point(&v.to_analysis_vector3())))
+        .collect();
+    let mut transformed = TriangleMesh3D::new(vertices, mesh.indices().to_vec())
+        .expect("indices are unchanged and remain valid");
+    if let Some(normals) = mesh.normals() {
+        let normals = normals
+            .iter()
+            .map(|&n| transform.apply_direction(&n.into()).map_or(n, Into::into))
+            .collect();
+        transformed = transformed
+            .with_normals(normals)
+            .expect("vertex count is unchanged");
+    }
+    *mesh = transformed;
+}
+
+/// メッシュを原点中心・単位スケールへ正規化し、適用した変換を返す
+///
+/// バウンディングボックス中心を原点へ移動し、最大辺長で割って `[-0.5, 0.5]` に収める。
+/// 頂点のないメッシュは変更せず恒等変換を、大きさのないメッシュ（全頂点が1点）は
+/// 原点への平行移動のみを返す。
+pub fn normalize_to_unit_cube(mesh: &mut TriangleMesh3D<f64>) -> Transform3D<f64> {
+    let Some((min, max)) = mesh.bounding_box() else {
+        return Transform3D::identity();
+    };
+
+    let extent = (max.x() - min.x())
+        .max(max.y() - min.y())
+        .max(max.z() - min.z());
+    let scale = if extent > 0.0 { 1.0 / extent } else { 1.0 };
+    let center = Vector3D::new(
+        (min.x() + max.x()) * 0.5,
+        (min.y() + max.y()) * 0.5,
+        (min.z() + max.z()) * 0.5,
+    );
+
+    let transform = Transform3D::identity()
+        .translate(&(-center).into())
+        .uniform_scale(scale);
+    apply_to_mesh(&transform, mesh);
+    transform
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use analysis::linalg::vector::Vector3;
+
+    /// [10, 30] × [-5, 5] × [100, 104] の箱の対角に張った2三角形
+    fn offset_mesh() -> TriangleMesh3D<f64> {
+        TriangleMesh3D::new(
+            vec![
+                Point3D::new(10.0, -5.0, 100.0),
+                Point3D::new(30.0, -5.0, 100.0),
+                Point3D::new(30.0, 5.0, 104.0),
+                Point3D::new(10.0, 5.0, 104.0),
+            ],
+            vec![[0, 1, 2], [0, 2, 3]],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_normalize_fits_unit_cube() {
+        let mut mesh = offset_mesh();
+        let transform = normalize_to_unit_cube(&mut mesh);
+        assert!((transform.uniform_scale_factor(1e-12).unwrap() - 1.0 / 20.0).abs() < 1e-15);
+
+        let (min, max) = mesh.bounding_box().unwrap();
+        // 最大辺（x）はちょうど [-0.5, 0.5]、他の軸は中心が原点
+        assert!((min.x() + 0.5).abs() < 1e-12 && (max.x() - 0.5).abs() < 1e-12);
+        assert!((min.y() + 0.25).abs() < 1e-12 && (max.y() - 0.25).abs() < 1e-12);
+        assert!((min.z() + 0.1).abs() < 1e-12 && (max.z() - 0.1).abs() < 1e-12);
+        assert_eq!(mesh.indices(), offset_mesh().indices());
+    }
+
+    #[test]
+    fn test_inverse_restores_original_coordinates() {
+        let original = offset_mesh();
+        let mut mesh = original.clone();
+        let transform = normalize_to_unit_cube(&mut mesh);
+
+        let inverse = transform.inverse().unwrap();
+        apply_to_mesh(&inverse, &mut mesh);
+        for (restored, expected) in mesh.vertices().iter().zip(original.vertices()) {
+            assert!(restored.distance_to(expected) < 1e-12);
+        }
+
+        let point = Vector3::new(1.0, 2.0, 3.0);
+        let round_trip = inverse.apply_point(&transform.apply_point(&point));
+        assert!((round_trip - point).norm() < 1e-12);
+    }
+
+    #[test]
+    fn test_normals_are_preserved() {
+        let up = Vector3D::new(0.0, 0.0, 1.0);
+        let mut mesh = offset_mesh().with_normals(vec![up; 4]).unwrap();
+        normalize_to_unit_cube(&mut mesh);
+        assert_eq!(mesh.normals(), Some(&[up; 4][..]));
+    }
+
+    #[test]
+    fn test_degenerate_meshes() {
+        let mut empty = TriangleMesh3D::empty();
+        assert_eq!(normalize_to_unit_cube(&mut empty), Transform3D::identity());
+
+        // 全頂点が1点：原点へ移動するだけ
+        let mut point =
+            TriangleMesh3D::new(vec![Point3D::new(4.0, 5.0, 6.0); 3], vec![[0, 1, 2]]).unwrap();
+        let transform = normalize_to_unit_cube(&mut point);
+        assert_eq!(transform.uniform_scale_factor(1e-12).unwrap(), 1.0);
+        assert!(point
+            .vertices()
+            .iter()
+            .all(|v| v.distance_to(&Point3D::origin()) < 1e-12));
+    }
+}
@@ -7,6 +7,7 @@ use crate::mesh_converter::{
     triangle_mesh_to_vertices, triangle_mesh_to_vertices_with_indices, VertexData,
 };
 use geo_io::stl;
+use geo_primitives::TriangleMesh3D;
 use std::path::Path;
 
 /// STL読み込み結果
@@ -17,6 +18,8 @@ pub struct StlMeshData {
     pub indices: Vec<u32>,
     /// 境界ボックス（min, max）
     pub bounds: ([f32; 3], [f32; 3]),
+    /// 読み込んだメッシュ（シェーディングモード切替時の頂点データ再生成用）
+    pub mesh: TriangleMesh3D<f64>,
 }
 
 /// STLファイルを読み込み、GPU用データに変換
@@ -53,13 +56,14 @@ pub fn load_stl_mesh(path: &Path) -> Result<StlMeshData, Box<dyn std::error::Err
         vertices,
         indices,
         bounds,
+        mesh,
     })
 }
 
 /// サンプルSTLファイルを作成
 pub fn create_sample_stl_mesh(path: &Path) -> Result<(), Box<dyn std::error::Error>> {
     // Model層（geo_primitives経由）でメッシュを構築
-    use geo_primitives::{Point3D, TriangleMesh3D};
+    use geo_primitives::Point3D;
 
     // シンプルな立方体を作成
     let vertices = vec![
@@ -112,7 +116,7 @@ pub fn create_and_load_sample_stl(path: &Path) -> Result<StlMeshData, Box<dyn st
 }
 
 /// 境界ボックス計算のヘルパー関数
-fn calculate_bounds(mesh: &geo_primitives::TriangleMesh3D<f64>) -> ([f32; 3], [f32; 3]) {
+fn calculate_bounds(mesh: &TriangleMesh3D<f64>) -> ([f32; 3], [f32; 3]) {
     let bounds = mesh.bounding_box();
     if let Some((min_point, max_point)) = bounds {
         let min_bounds = [
@@ -0,0 +1,185 @@
+//! surface_tessellation - NURBS曲面の三角形メッシュ化
+//!
+//! パラメータ空間を格子状にサンプリングして頂点を作り、各四角形セルを
+//! 三角形2枚に分割します。頂点法線は `NurbsSurface3D::normal_at` で求めます。
+//!
+//! ## 分割数の決定
+//! 分割数は [`SurfaceSubdivision`] が決めます。現在は固定分割数の
+//! [`UniformSubdivision`] のみですが、弦高許容誤差から分割数を求める戦略も
+//! このトレイトを実装すれば `tessellate_surface_with` にそのまま渡せます。
+//!
+//! ## 三角形の向き
+//! 三角形は `∂S/∂u × ∂S/∂v` の向きを表とする反時計回りで生成するため、
+//! 面の向きと頂点法線（`normal_at`）が一致します。
+
+use geo_nurbs::NurbsSurface3D;
+use geo_primitives::{Point3D, TriangleMesh3D, Vector3D};
+
+/// 曲面のテッセレーション分割数を決める戦略
+pub trait SurfaceSubdivision {
+    /// u方向・v方向のセル分割数（それぞれ1以上）
+    fn divisions(&self, surface: &NurbsSurface3D<f64>) -> (usize, usize);
+}
+
+/// パラメータ範囲を固定の分割数で等分する戦略
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UniformSubdivision {
+    /// u方向の分割数
+    pub u_div: usize,
+    /// v方向の分割数
+    pub v_div: usize,
+}
+
+impl SurfaceSubdivision for UniformSubdivision {
+    fn divisions(&self, _surface: &NurbsSurface3D<f64>) -> (usize, usize) {
+        (self.u_div.max(1), self.v_div.max(1))
+    }
+}
+
+/// 曲面をu・v方向それぞれ等分割して三角形メッシュを生成
+///
+/// 頂点数は `(u_div + 1) * (v_div + 1)`、三角形数は `2 * u_div * v_div`。
+/// 分割数0は1として扱う。
+pub fn tessellate_surface(
+    surface: &NurbsSurface3D<f64>,
+    u_div: usize,
+    v_div: usize,
+) -> TriangleMesh3D<f64> {
+    tessellate_surface_with(surface, &UniformSubdivision { u_div, v_div })
+}
+
+/// 分割戦略を指定して曲面を三角形メッシュ化
+pub fn tessellate_surface_with<S: SurfaceSubdivision>(
+    surface: &NurbsSurface3D<f64>,
+    subdivision: &S,
+) -> TriangleMesh3D<f64> {
+    let (u_div, v_div) = subdivision.divisions(surface);
+    let (u_div, v_div) = (u_div.max(1), v_div.max(1));
+    let ((u_min, u_max), (v_min, v_max)) = surface.parameter_domain();
+
+    let vertex_count = (u_div + 1) * (v_div + 1);
+    let mut vertices = Vec::with_capacity(vertex_count);
+    let mut normals = Vec::with_capacity(vertex_count);
+    for i in 0..=u_div {
+        let u = u_min + (u_max - u_min) * i as f64 / u_div as f64;
+        for j in 0..=v_div {
+            let v = v_min + (v_max - v_min) * j as f64 / v_div as f64;
+            let point = surface.evaluate_at(u, v);
+            let normal = surface.normal_at(u, v);
+            vertices.push(Point3D::new(point.x(), point.y(), point.z()));
+            normals.push(Vector3D::new(normal.x(), normal.y(), normal.z()));
+        }
+    }
+
+    let index = |i: usize, j: usize| i * (v_div + 1) + j;
+    let mut indices = Vec::with_capacity(2 * u_div * v_div);
+    for i in 0..u_div {
+        for j in 0..v_div {
+            let (p00, p10) = (index(i, j), index(i + 1, j));
+            let (p01, p11) = (index(i, j + 1), index(i + 1, j + 1));
+            indices.push([p00, p10, p11]);
+            indices.push([p00, p11, p01]);
+        }
+    }
+
+    TriangleMesh3D::new(vertices, indices)
+        .expect("grid indices are within the vertex count")
+        .with_normals(normals)
+        .expect("one normal is generated per vertex")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use analysis::linalg::vector::Vector3;
+
+    /// z = 0 上の 2×3 の長方形（双1次）
+    fn flat_surface() -> NurbsSurface3D<f64> {
+        let control_points = vec![
+            vec![Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 3.0, 0.0)],
+            vec![Vector3::new(2.0, 0.0, 0.0), Vector3::new(2.0, 3.0, 0.0)],
+        ];
+        let knots = vec![0.0, 0.0, 1.0, 1.0];
+        NurbsSurface3D::new(control_points, None, knots.clone(), knots, 1, 1).unwrap()
+    }
+
+    /// 半径1の円柱面の四半分（軸はz、u方向が円弧、v方向が高さ2）
+    fn quarter_cylinder() -> NurbsSurface3D<f64> {
+        let arc = [
+            Vector3::new(1.0, 0.0, 0.0),
+            Vector3::new(1.0, 1.0, 0.0),
+            Vector3::new(0.0, 1.0, 0.0),
+        ];
+        let control_points = arc
+            .iter()
+            .map(|p| vec![*p, Vector3::new(p.x(), p.y(), 2.0)])
+            .collect();
+        let w = std::f64::consts::FRAC_1_SQRT_2;
+        let weights = vec![vec![1.0, 1.0], vec![w, w], vec![1.0, 1.0]];
+        NurbsSurface3D::new(
+            control_points,
+            Some(weights),
+            vec![0.0, 0.0, 0.0, 1.0, 1.0, 1.0],
+            vec![0.0, 0.0, 1.0, 1.0],
+            2,
+            1,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_grid_counts() {
+        let mesh = tessellate_surface(&flat_surface(), 4, 3);
+        assert_eq!(mesh.vertex_count(), 5 * 4);
+        assert_eq!(mesh.triangle_count(), 2 * 4 * 3);
+        assert!(mesh.is_valid());
+        assert_eq!(mesh.degenerate_triangle_count(), 0);
+
+        // 分割数0は1として扱う
+        let minimal = tessellate_surface(&flat_surface(), 0, 0);
+        assert_eq!(minimal.vertex_count(), 4);
+        assert_eq!(minimal.triangle_count(), 2);
+    }
+
+    #[test]
+    fn test_flat_surface_orientation_matches_normals() {
+        let mesh = tessellate_surface(&flat_surface(), 2, 2);
+        let normals = mesh.normals().unwrap();
+        for normal in normals {
+            assert!((normal.z() - 1.0).abs() < 1e-6);
+        }
+        for index in 0..mesh.triangle_count() {
+            let [a, b, c] = mesh.triangle_indices(index).unwrap();
+            let (a, b, c) = (mesh.vertices()[a], mesh.vertices()[b], mesh.vertices()[c]);
+            let face = (b - a).cross(&(c - a));
+            assert!(face.z() > 0.0);
+        }
+    }
+
+    #[test]
+    fn test_cylinder_vertices_lie_on_surface() {
+        let mesh = tessellate_surface(&quarter_cylinder(), 8, 2);
+        let normals = mesh.normals().unwrap();
+        for (vertex, normal) in mesh.vertices().iter().zip(normals) {
+            let radius = (vertex.x() * vertex.x() + vertex.y() * vertex.y()).sqrt();
+            assert!((radius - 1.0).abs() < 1e-9);
+            assert!(vertex.z() >= -1e-12 && vertex.z() <= 2.0 + 1e-12);
+            // 法線は半径方向（外向き）
+            assert!(normal.z().abs() < 1e-6);
+            assert!((normal.x() * vertex.x() + normal.y() * vertex.y() - 1.0).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_custom_subdivision_strategy() {
+        struct Fixed;
+        impl SurfaceSubdivision for Fixed {
+            fn divisions(&self, _surface: &NurbsSurface3D<f64>) -> (usize, usize) {
+                (3, 1)
+            }
+        }
+        let mesh = tessellate_surface_with(&quarter_cylinder(), &Fixed);
+        assert_eq!(mesh.vertex_count(), 4 * 2);
+        assert_eq!(mesh.triangle_count(), 6);
+    }
+}
@@ -1,6 +1,11 @@
 use analysis::linalg::{quaternion::Quaternionf, vector::Vec3f};
+use analysis::Angle;
+use geo_primitives::{BBox3D, Point3D};
 use std::f32::consts::PI;
 
+/// 透視投影の縦方向視野角の既定値（度）
+const DEFAULT_FOV_Y_DEGREES: f32 = 45.0;
+
 /// 投影方式の種類
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum ProjectionMode {
@@ -16,6 +21,45 @@ pub enum ProjectionMode {
     Orthographic,
 }
 
+/// 定型視点（図面確認用）
+///
+/// 各視点はカメラを注視点のどちら側に置くかで定義する。
+/// `Front` は回転なし（-Z側から見る、[`Camera::reset_to_front_view`] と同じ向き）。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StandardView {
+    /// 正面（-Z側から）
+    Front,
+    /// 背面（+Z側から）
+    Back,
+    /// 左側面（-X側から）
+    Left,
+    /// 右側面（+X側から）
+    Right,
+    /// 上面（+Y側から見下ろす）
+    Top,
+    /// 下面（-Y側から見上げる）
+    Bottom,
+    /// 等角投影視点（[`Camera::new_isometric`] と同じ向き）
+    Isometric,
+}
+
+impl StandardView {
+    /// 視点に対応するカメラ回転
+    pub fn rotation(self) -> Quaternionf {
+        let x_axis = Vec3f::new(1.0, 0.0, 0.0);
+        let y_axis = Vec3f::new(0.0, 1.0, 0.0);
+        match self {
+            StandardView::Front => Quaternionf::identity(),
+            StandardView::Back => Quaternionf::from_axis_angle(&y_axis, PI),
+            StandardView::Left => Quaternionf::from_axis_angle(&y_axis, -PI * 0.5),
+            StandardView::Right => Quaternionf::from_axis_angle(&y_axis, PI * 0.5),
+            StandardView::Top => Quaternionf::from_axis_angle(&x_axis, -PI * 0.5),
+            StandardView::Bottom => Quaternionf::from_axis_angle(&x_axis, PI * 0.5),
+            StandardView::Isometric => isometric_rotation(),
+        }
+    }
+}
+
 /// 3Dカメラの制御システム
 /// analysisクレートの高品質なクォータニオンとベクトル実装を使用
 #[derive(Debug, Clone)]
@@ -32,6 +76,8 @@ pub struct Camera {
     pub distance: f32,
     /// 投影方式
     pub projection_mode: ProjectionMode,
+    /// 透視投影の縦方向視野角
+    pub fov_y: Angle<f32>,
 }
 
 impl Camera {
@@ -44,6 +90,7 @@ impl Camera {
             target: Vec3f::new(0.0, 0.0, 0.0),
             distance: 5.0,
             projection_mode: ProjectionMode::Perspective,
+            fov_y: Angle::from_degrees(DEFAULT_FOV_Y_DEGREES),
         }
     }
 
@@ -56,33 +103,26 @@ impl Camera {
             target: Vec3f::new(0.0, 0.0, 0.0),
             distance: 5.0,
             projection_mode: ProjectionMode::Orthographic,
+            fov_y: Angle::from_degrees(DEFAULT_FOV_Y_DEGREES),
         }
     }
 
     /// 機械設計CAD用のアイソメトリック視点カメラを作成
     /// X, Y, Z軸が等しく短縮される標準的な等角投影視点
     pub fn new_isometric() -> Self {
-        // アイソメトリック標準角度: X軸 -35.264°, Y軸 45°
-        let x_rotation =
-            Quaternionf::from_axis_angle(&Vec3f::new(1.0, 0.0, 0.0), -35.264_f32.to_radians());
-        let y_rotation =
-            Quaternionf::from_axis_angle(&Vec3f::new(0.0, 1.0, 0.0), 45.0_f32.to_radians());
-        let isometric_rotation = (y_rotation * x_rotation)
-            .normalize()
-            .unwrap_or(Quaternionf::identity());
-
         Self {
             position: Vec3f::new(0.0, 0.0, 5.0),
-            rotation: isometric_rotation,
+            rotation: isometric_rotation(),
             zoom: 1.0,
             target: Vec3f::new(0.0, 0.0, 0.0),
             distance: 5.0,
             projection_mode: ProjectionMode::Orthographic,
+            fov_y: Angle::from_degrees(DEFAULT_FOV_Y_DEGREES),
         }
     }
 
-    /// ビュー行列を計算
-    pub fn view_matrix(&self) -> [[f32; 4]; 4] {
+    /// 回転・注視点・距離から決まるカメラのワールド位置
+    pub fn eye_position(&self) -> Vec3f {
         // 回転行列を取得
         let rotation_matrix = quaternion_to_matrix(&self.rotation);
 
@@ -94,10 +134,25 @@ impl Camera {
             -rotation_matrix[2][2],
         );
 
-        let camera_pos = self.target + forward * self.distance;
+        self.target + forward * self.distance
+    }
+
+    /// カメラ回転から決まる画面上方向（ワールド座標）
+    ///
+    /// 上面・下面視点では視線がワールドのY軸と平行になるため、
+    /// ワールドY軸ではなくこの方向をビュー行列の上方向に使う。
+    pub fn up_vector(&self) -> Vec3f {
+        let rotation_matrix = quaternion_to_matrix(&self.rotation);
+        Vec3f::new(
+            rotation_matrix[1][0],
+            rotation_matrix[1][1],
+            rotation_matrix[1][2],
+        )
+    }
 
-        // ビュー行列を計算
-        look_at(camera_pos, self.target, Vec3f::new(0.0, 1.0, 0.0))
+    /// ビュー行列を計算
+    pub fn view_matrix(&self) -> [[f32; 4]; 4] {
+        look_at(self.eye_position(), self.target, self.up_vector())
     }
 
     /// プロジェクション行列を計算
@@ -108,7 +163,7 @@ impl Camera {
                 let near = (self.distance * 0.01).max(0.001); // 距離の1%、最小0.001
                 let far = (self.distance * 100.0).min(1000.0); // 距離の100倍、最大1000
 
-                perspective(45.0 * PI / 180.0, aspect, near, far)
+                perspective(self.fov_y.to_radians(), aspect, near, far)
             }
             ProjectionMode::Orthographic => {
                 // 平行投影：距離とズームに基づいてサイズを決定
@@ -125,7 +180,27 @@ impl Camera {
         }
     }
 
-    /// 投影モードを切り替え
+    /// 投影モードを切り替え（見かけのスケールを保つ）
+    ///
+    /// 注視点の位置で画面に映る高さが切替前後で一致するよう調整する。
+    /// 透視投影の表示高さは `2·distance·tan(fovy/2)`、平行投影は `distance·zoom`。
+    /// 平行投影→透視投影では距離を、透視投影→平行投影ではズーム係数を合わせる。
+    pub fn set_projection(&mut self, mode: ProjectionMode) {
+        let perspective_height_ratio = 2.0 * (self.fov_y.to_radians() * 0.5).tan();
+        match (self.projection_mode, mode) {
+            (ProjectionMode::Perspective, ProjectionMode::Orthographic) => {
+                self.zoom = perspective_height_ratio;
+            }
+            (ProjectionMode::Orthographic, ProjectionMode::Perspective) => {
+                self.distance = self.distance * self.zoom / perspective_height_ratio;
+                self.zoom = 1.0;
+            }
+            _ => {}
+        }
+        self.set_projection_mode(mode);
+    }
+
+    /// 投影モードを切り替え（距離・ズーム係数はそのまま）
     pub fn set_projection_mode(&mut self, mode: ProjectionMode) {
         self.projection_mode = mode;
         tracing::info!("投影モード変更: {:?}", mode);
@@ -160,11 +235,7 @@ impl Camera {
             rotation_matrix[0][1],
             rotation_matrix[0][2],
         );
-        let up = Vec3f::new(
-            rotation_matrix[1][0],
-            rotation_matrix[1][1],
-            rotation_matrix[1][2],
-        );
+        let up = self.up_vector();
 
         // マウス移動量をカメラ座標系での移動量に変換
         // マウス右移動 = カメラ右軸方向、マウス上移動 = カメラ上軸方向
@@ -175,13 +246,28 @@ impl Camera {
         self.target = self.target + offset;
     }
 
-    /// ズーム操作（距離調整）
+    /// ズーム操作
+    ///
+    /// 透視投影ではカメラ距離を、平行投影ではズーム係数（視錐台の幅）を調整する。
     pub fn zoom(&mut self, delta_x: f32, delta_y: f32) {
         let sensitivity = 0.01; // 感度を上げて分かりやすく
 
         // 斜め移動の合計でズーム量を計算
         let zoom_factor = (delta_x + delta_y) * sensitivity;
 
+        if self.projection_mode == ProjectionMode::Orthographic {
+            // カメラ位置は保ったまま表示幅のみを変える
+            self.zoom = (self.zoom * (1.0 + zoom_factor)).clamp(0.001, 1000.0);
+            tracing::debug!(
+                "平行投影ズーム: delta=({:.2},{:.2}), factor={:.3}, zoom={:.3}",
+                delta_x,
+                delta_y,
+                zoom_factor,
+                self.zoom
+            );
+            return;
+        }
+
         // 距離を調整（最小・最大制限付き）- より広い範囲に拡大
         let new_distance = self.distance * (1.0 + zoom_factor);
         self.distance = new_distance.clamp(0.1, 200.0); // 最大距離を200に拡大
@@ -196,28 +282,9 @@ impl Camera {
     }
 
     /// メッシュの境界ボックスに基づいてカメラを自動調整
+    ///
+    /// 斜め上（30°/45°）からの俯瞰視点にしたうえで [`Self::fit_to_bbox`] で収める。
     pub fn fit_to_mesh(&mut self, min_bounds: Vec3f, max_bounds: Vec3f) {
-        // メッシュの中心を計算
-        let center = (min_bounds + max_bounds) * 0.5;
-        self.target = center;
-
-        // メッシュのサイズを計算
-        let size = max_bounds - min_bounds;
-
-        // メッシュの最大サイズを計算（対角線長さ）
-        let diagonal = (size.x().powi(2) + size.y().powi(2) + size.z().powi(2)).sqrt();
-
-        // 視野角45度、適切なマージンを考慮してカメラ距離を計算
-        // distance = (diagonal * margin) / (2 * tan(fov/2))
-        let fov_rad = 45.0_f32.to_radians();
-        let margin = 2.5; // より余裕を持ったマージン
-        let distance = (diagonal * margin) / (2.0 * (fov_rad / 2.0).tan());
-
-        // 最小距離をdiagonalの0.1倍に設定（非常に小さなオブジェクト対応）
-        let min_distance = (diagonal * 0.1).max(0.01); // 最低0.01単位
-        let max_distance = 100.0; // 最大距離
-        self.distance = distance.clamp(min_distance, max_distance);
-
         // アイソメトリック風の俯瞰視点を設定（オブジェクトが確実に見える角度）
         // X軸回転: 約30度上から見下ろす
         let x_rotation =
@@ -230,36 +297,13 @@ impl Camera {
             .normalize()
             .unwrap_or(Quaternionf::identity());
 
-        tracing::info!(
-            "カメラをメッシュに適応: center={:?}, distance={:.2}, diagonal={:.2}, min_distance={:.4}",
-            [center.x(), center.y(), center.z()],
-            self.distance,
-            diagonal,
-            min_distance
-        );
+        self.fit_to_bbox(&bbox_from_bounds(min_bounds, max_bounds), 1.0);
     }
 
     /// 小さなオブジェクト（マイクロメートル〜ミリメートル）専用のカメラ設定
+    ///
+    /// 真俯瞰に近い角度（60°/30°）にしたうえで [`Self::fit_to_bbox`] で収める。
     pub fn fit_to_small_mesh(&mut self, min_bounds: Vec3f, max_bounds: Vec3f) {
-        // メッシュの中心を計算
-        let center = (min_bounds + max_bounds) * 0.5;
-        self.target = center;
-
-        // メッシュのサイズを計算
-        let size = max_bounds - min_bounds;
-        let diagonal = (size.x().powi(2) + size.y().powi(2) + size.z().powi(2)).sqrt();
-
-        // 小さなオブジェクト専用：対角線の5〜10倍の距離に設定
-        let distance_factor = if diagonal < 0.01 {
-            15.0 // 非常に小さい場合
-        } else if diagonal < 0.1 {
-            10.0 // 小さい場合
-        } else {
-            5.0 // 通常の小オブジェクト
-        };
-
-        self.distance = diagonal * distance_factor;
-
         // 真俯瞰に近い角度でオブジェクトを確実に捉える
         let x_rotation =
             Quaternionf::from_axis_angle(&Vec3f::new(1.0, 0.0, 0.0), -60.0_f32.to_radians());
@@ -269,15 +313,67 @@ impl Camera {
             .normalize()
             .unwrap_or(Quaternionf::identity());
 
+        self.fit_to_bbox(&bbox_from_bounds(min_bounds, max_bounds), 1.0);
+    }
+
+    /// バウンディングボックス全体が画面に収まるよう注視点と距離を調整
+    ///
+    /// 注視点をボックス中心に置き、ボックスの外接球（半径 = 対角線の半分）が
+    /// 縦・横どちらの視野にも収まる距離を求める。縦方向の視野角は [`Self::fov_y`]、
+    /// `aspect` は画面の幅/高さ。視線方向（回転）は変更しない。
+    pub fn fit_to_bbox(&mut self, bbox: &BBox3D<f64>, aspect: f32) {
+        let center = bbox.center();
+        self.target = Vec3f::new(center.x() as f32, center.y() as f32, center.z() as f32);
+
+        let min = bbox.min();
+        let max = bbox.max();
+        let radius = 0.5
+            * ((max.x() - min.x()).powi(2)
+                + (max.y() - min.y()).powi(2)
+                + (max.z() - min.z()).powi(2))
+            .sqrt() as f32;
+        // 点状のボックスでも near/far が破綻しない最小半径
+        let radius = radius.max(1e-6);
+
+        let half_fov_y = (self.fov_y.to_radians() * 0.5).clamp(1e-3, PI * 0.5 - 1e-3);
+        let aspect = aspect.max(1e-3);
+        // 横長でも縦長でも、狭い方の視野角で外接球を収める
+        let half_fov_x = (half_fov_y.tan() * aspect).atan();
+        let half_fov = half_fov_y.min(half_fov_x);
+
+        self.distance = match self.projection_mode {
+            ProjectionMode::Perspective => radius / half_fov.sin(),
+            // 平行投影の表示高さは distance * zoom、表示幅はその aspect 倍
+            ProjectionMode::Orthographic => 2.0 * radius / (self.zoom * aspect.min(1.0)),
+        };
+
         tracing::info!(
-            "小オブジェクト対応カメラ設定: center={:?}, distance={:.4}, diagonal={:.4}, factor={}",
-            [center.x(), center.y(), center.z()],
+            "カメラをバウンディングボックスに適応: center={:?}, distance={:.4}, radius={:.4}, aspect={:.3}",
+            [self.target.x(), self.target.y(), self.target.z()],
             self.distance,
-            diagonal,
-            distance_factor
+            radius,
+            aspect
         );
     }
 
+    /// 定型視点へ瞬時に切り替える
+    ///
+    /// 注視点・距離・ズーム係数は保ち、視線方向だけを変更する。
+    pub fn set_standard_view(&mut self, view: StandardView) {
+        self.rotation = view.rotation();
+        tracing::info!("定型視点に切替: {:?}", view);
+    }
+
+    /// 定型視点への遷移途中のカメラを求める（アニメーション用）
+    ///
+    /// `t` = 0 で現在のカメラ、`t` = 1 で [`set_standard_view`](Self::set_standard_view)
+    /// 後の状態。回転は slerp で補間する。
+    pub fn standard_view_transition(&self, view: StandardView, t: f32) -> Result<Camera, String> {
+        let mut destination = self.clone();
+        destination.rotation = view.rotation();
+        self.slerp_to(&destination, t.clamp(0.0, 1.0))
+    }
+
     /// カメラ状態をリセット
     pub fn reset(&mut self) {
         self.target = Vec3f::new(0.0, 0.0, 0.0);
@@ -396,6 +492,7 @@ impl Camera {
             target: interpolated_target,
             distance: interpolated_distance,
             projection_mode: self.projection_mode, // 投影モードは変更しない
+            fov_y: self.fov_y,
         })
     }
 }
@@ -406,6 +503,23 @@ impl Default for Camera {
     }
 }
 
+/// `fit_to_mesh` 系の min/max 座標から境界ボックスを作る
+fn bbox_from_bounds(min_bounds: Vec3f, max_bounds: Vec3f) -> BBox3D<f64> {
+    let to_point = |v: Vec3f| Point3D::new(f64::from(v.x()), f64::from(v.y()), f64::from(v.z()));
+    BBox3D::new(to_point(min_bounds), to_point(max_bounds))
+}
+
+/// アイソメトリック標準角度（X軸 -35.264°, Y軸 45°）の回転
+fn isometric_rotation() -> Quaternionf {
+    let x_rotation =
+        Quaternionf::from_axis_angle(&Vec3f::new(1.0, 0.0, 0.0), -35.264_f32.to_radians());
+    let y_rotation =
+        Quaternionf::from_axis_angle(&Vec3f::new(0.0, 1.0, 0.0), 45.0_f32.to_radians());
+    (y_rotation * x_rotation)
+        .normalize()
+        .unwrap_or(Quaternionf::identity())
+}
+
 /// analysisクレートのクォータニオンを4x4行列に変換
 fn quaternion_to_matrix(q: &Quaternionf) -> [[f32; 4]; 4] {
     // 正規化されたクォータニオンを使用
@@ -589,10 +703,10 @@ mod tests {
         // ターゲットが中心になっていることを確認
         assert_eq!(camera.target, Vec3f::new(0.0, 0.0, 0.0));
 
-        // 距離が適切に設定されていることを確認（対角線の10倍程度）
-        let diagonal = (3.0_f32 * 0.001_f32.powi(2)).sqrt(); // ≈ 0.00173
-        assert!(camera.distance > diagonal * 5.0); // 最低5倍
-        assert!(camera.distance < diagonal * 20.0); // 最大20倍
+        // 外接球が視野角に接する距離（fit_to_bbox と同じ）
+        let radius = 0.5 * (3.0_f32 * 0.001_f32.powi(2)).sqrt();
+        let expected = radius / (camera.fov_y.to_radians() * 0.5).sin();
+        assert!((camera.distance - expected).abs() < 1e-6);
 
         // 回転が設定されていることを確認
         assert_ne!(camera.rotation, Quaternionf::identity());
@@ -693,4 +807,177 @@ mod tests {
         assert!(camera.distance >= 2.0); // 安全な距離
         assert_eq!(camera.target, Vec3f::new(0.0, 0.0, 0.0)); // 中心
     }
+
+    #[test]
+    fn test_fit_to_bbox() {
+        let bbox = BBox3D::new(Point3D::new(1.0, 2.0, 3.0), Point3D::new(3.0, 4.0, 5.0));
+
+        let mut camera = Camera::new();
+        camera.fit_to_bbox(&bbox, 1.0);
+        assert_eq!(camera.target, Vec3f::new(2.0, 3.0, 4.0));
+        // 外接球半径 √3 が視野角 45° に接する距離
+        let radius = 3.0_f32.sqrt();
+        let expected = radius / (22.5_f32.to_radians()).sin();
+        assert!((camera.distance - expected).abs() < 1e-4);
+
+        // 縦長の画面では横方向の視野が狭くなるため距離が伸びる
+        let mut portrait = Camera::new();
+        portrait.fit_to_bbox(&bbox, 0.5);
+        assert!(portrait.distance > camera.distance);
+        let half_fov_x = ((22.5_f32.to_radians()).tan() * 0.5).atan();
+        assert!((portrait.distance * half_fov_x.sin() - radius).abs() < 1e-4);
+
+        // 横長の画面では縦方向の視野で決まる
+        let mut landscape = Camera::new();
+        landscape.fit_to_bbox(&bbox, 2.0);
+        assert!((landscape.distance - camera.distance).abs() < 1e-4);
+
+        // 平行投影では表示範囲が外接球の直径を覆う
+        let mut ortho = Camera::new_orthographic();
+        ortho.fit_to_bbox(&bbox, 0.5);
+        let height = ortho.distance * ortho.zoom;
+        assert!((height * 0.5 - 2.0 * radius).abs() < 1e-4);
+
+        // 視野角を変えると、フィット距離と投影行列の両方が同じ視野角を使う
+        let mut narrow = Camera::new();
+        narrow.fov_y = Angle::from_degrees(20.0);
+        narrow.fit_to_bbox(&bbox, 1.0);
+        assert!((narrow.distance - radius / 10.0_f32.to_radians().sin()).abs() < 1e-3);
+        let proj = narrow.projection_matrix(1.0);
+        assert!((proj[1][1] - 1.0 / 10.0_f32.to_radians().tan()).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_set_projection_preserves_apparent_scale() {
+        let visible_height = |camera: &Camera| {
+            let proj = camera.projection_matrix(1.0);
+            match camera.projection_mode {
+                // 注視点の奥行きで NDC の高さ 2 に対応するワールド長
+                ProjectionMode::Perspective => 2.0 * camera.distance / proj[1][1],
+                ProjectionMode::Orthographic => 2.0 / proj[1][1],
+            }
+        };
+
+        let mut camera = Camera::new();
+        camera.distance = 12.0;
+        let before = visible_height(&camera);
+
+        camera.set_projection(ProjectionMode::Orthographic);
+        assert_eq!(camera.projection_mode, ProjectionMode::Orthographic);
+        assert!((visible_height(&camera) - before).abs() < 1e-4);
+
+        // 平行投影のズームは距離ではなく表示幅を変える
+        camera.zoom(10.0, 10.0);
+        assert_eq!(camera.distance, 12.0);
+        let zoomed = visible_height(&camera);
+        assert!(zoomed > before);
+
+        camera.set_projection(ProjectionMode::Perspective);
+        assert_eq!(camera.projection_mode, ProjectionMode::Perspective);
+        assert_eq!(camera.zoom, 1.0);
+        assert!((visible_height(&camera) - zoomed).abs() < 1e-3);
+
+        // 同じモードへの切替では何も変えない
+        let distance = camera.distance;
+        camera.set_projection(ProjectionMode::Perspective);
+        assert_eq!(camera.distance, distance);
+    }
+
+    #[test]
+    fn test_set_standard_view() {
+        let target = Vec3f::new(1.0, 2.0, 3.0);
+        let eye_direction = |view: StandardView| {
+            let mut camera = Camera::new();
+            camera.target = target;
+            camera.distance = 4.0;
+            camera.set_standard_view(view);
+            // 注視点と距離は保たれる
+            assert_eq!(camera.target, target);
+            assert_eq!(camera.distance, 4.0);
+            (camera.eye_position() - target) * 0.25
+        };
+        let assert_near = |a: Vec3f, b: Vec3f| assert!((a - b).norm() < 1e-5, "{a:?} != {b:?}");
+
+        assert_near(
+            eye_direction(StandardView::Front),
+            Vec3f::new(0.0, 0.0, -1.0),
+        );
+        assert_near(eye_direction(StandardView::Back), Vec3f::new(0.0, 0.0, 1.0));
+        assert_near(
+            eye_direction(StandardView::Left),
+            Vec3f::new(-1.0, 0.0, 0.0),
+        );
+        assert_near(
+            eye_direction(StandardView::Right),
+            Vec3f::new(1.0, 0.0, 0.0),
+        );
+        assert_near(eye_direction(StandardView::Top), Vec3f::new(0.0, 1.0, 0.0));
+        assert_near(
+            eye_direction(StandardView::Bottom),
+            Vec3f::new(0.0, -1.0, 0.0),
+        );
+
+        // 等角視点は斜め上から見下ろす
+        let iso = eye_direction(StandardView::Isometric);
+        assert!(iso.x() > 0.0 && iso.y() > 0.0 && iso.z() < 0.0);
+        assert_eq!(
+            StandardView::Isometric.rotation(),
+            Camera::new_isometric().rotation
+        );
+    }
+
+    #[test]
+    fn test_top_and_bottom_views_keep_screen_axes() {
+        // ビュー行列の列0が画面右方向、列1が画面上方向
+        let screen_axes = |view: StandardView| {
+            let mut camera = Camera::new();
+            camera.set_standard_view(view);
+            let m = camera.view_matrix();
+            (
+                Vec3f::new(m[0][0], m[1][0], m[2][0]),
+                Vec3f::new(m[0][1], m[1][1], m[2][1]),
+            )
+        };
+        let assert_near = |a: Vec3f, b: Vec3f| assert!((a - b).norm() < 1e-5, "{a:?} != {b:?}");
+
+        // 正面から上下に倒した視点なので、画面右方向は正面と同じ
+        let (front_right, _) = screen_axes(StandardView::Front);
+        let (top_right, top_up) = screen_axes(StandardView::Top);
+        assert_near(top_right, front_right);
+        assert_near(top_up, Vec3f::new(0.0, 0.0, 1.0));
+
+        let (bottom_right, bottom_up) = screen_axes(StandardView::Bottom);
+        assert_near(bottom_right, front_right);
+        assert_near(bottom_up, Vec3f::new(0.0, 0.0, -1.0));
+
+        // 側面視点の上方向はワールドY軸のまま
+        let (_, right_up) = screen_axes(StandardView::Right);
+        assert_near(right_up, Vec3f::new(0.0, 1.0, 0.0));
+    }
+
+    #[test]
+    fn test_standard_view_transition() {
+        let mut camera = Camera::new();
+        camera.target = Vec3f::new(1.0, 0.0, 0.0);
+
+        let start = camera
+            .standard_view_transition(StandardView::Right, 0.0)
+            .unwrap();
+        assert!(start.rotation.dot(&camera.rotation) > 1.0 - 1e-5);
+
+        let middle = camera
+            .standard_view_transition(StandardView::Right, 0.5)
+            .unwrap();
+        let end = camera
+            .standard_view_transition(StandardView::Right, 1.0)
+            .unwrap();
+        assert!(end.rotation.dot(&StandardView::Right.rotation()).abs() > 1.0 - 1e-5);
+        assert_eq!(middle.target, camera.target);
+        assert_eq!(middle.distance, camera.distance);
+
+        // 中間点は 45° 回転した位置（X と -Z の間）にある
+        let direction = middle.eye_position() - middle.target;
+        assert!(direction.x() > 0.0 && direction.z() < 0.0);
+        assert!((direction.x() + direction.z()).abs() < 1e-4);
+    }
 }